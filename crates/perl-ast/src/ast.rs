@@ -243,7 +243,7 @@ impl Node {
 
             NodeKind::Diamond => "(diamond)".to_string(),
 
-            NodeKind::Ellipsis => "(ellipsis)".to_string(),
+            NodeKind::YadaYada => "(yada_yada)".to_string(),
 
             NodeKind::Undef => "(undef)".to_string(),
 
@@ -319,12 +319,22 @@ impl Node {
                 format!("(do {})", block.to_sexp())
             }
 
+            NodeKind::DoWhile { block, condition } => {
+                format!("(do_while {} {})", block.to_sexp(), condition.to_sexp())
+            }
+
+            NodeKind::DoUntil { block, condition } => {
+                format!("(do_until {} {})", block.to_sexp(), condition.to_sexp())
+            }
+
             NodeKind::Try { body, catch_blocks, finally_block } => {
                 let mut parts = vec![format!("(try {})", body.to_sexp())];
 
                 for (var, block) in catch_blocks {
-                    if let Some(v) = var {
-                        parts.push(format!("(catch {} {})", v, block.to_sexp()));
+                    if let Some(Node { kind: NodeKind::Variable { sigil, name }, .. }) =
+                        var.as_deref()
+                    {
+                        parts.push(format!("(catch {}{} {})", sigil, name, block.to_sexp()));
                     } else {
                         parts.push(format!("(catch {})", block.to_sexp()));
                     }
@@ -447,8 +457,8 @@ impl Node {
                         parts.push("()".to_string());
                     }
 
-                    // Add body
-                    parts.push(body.to_sexp());
+                    // Add body (forward declarations have none)
+                    parts.push(body.as_ref().map(|b| b.to_sexp()).unwrap_or_default());
 
                     // Format: (sub name [attrs...] ()(block ...)) - space between name and (), no space between () and block
                     if parts.len() >= 3 && parts[parts.len() - 2] == "()" {
@@ -483,7 +493,9 @@ impl Node {
                     }
 
                     // Add body
-                    parts.push(body.to_sexp());
+                    if let Some(body) = body {
+                        parts.push(body.to_sexp());
+                    }
 
                     format!("(anonymous_subroutine_expression {})", parts.join(""))
                 }
@@ -646,7 +658,11 @@ impl Node {
                 )
             }
 
-            NodeKind::Package { name, block, name_span: _ } => {
+            NodeKind::Package { name, version, block, name_span: _ } => {
+                let name = match version {
+                    Some(version) => format!("{name} {version}"),
+                    None => name.clone(),
+                };
                 if let Some(blk) = block {
                     format!("(package {} {})", name, blk.to_sexp())
                 } else {
@@ -678,7 +694,7 @@ impl Node {
                 format!("({} {})", phase, block.to_sexp())
             }
 
-            NodeKind::DataSection { marker, body } => {
+            NodeKind::DataSection { marker, body, .. } => {
                 if let Some(body_text) = body {
                     format!("(data_section {} \"{}\")", marker, body_text.escape_default())
                 } else {
@@ -686,10 +702,21 @@ impl Node {
                 }
             }
 
-            NodeKind::Class { name, body } => {
+            NodeKind::Class { name, body, .. } => {
                 format!("(class {} {})", name, body.to_sexp())
             }
 
+            NodeKind::Field { variable, attributes, default } => {
+                let mut parts = vec![variable.to_sexp()];
+                if !attributes.is_empty() {
+                    parts.push(format!("(attributes {})", attributes.join(" ")));
+                }
+                if let Some(default) = default {
+                    parts.push(default.to_sexp());
+                }
+                format!("(field_declaration {})", parts.join(" "))
+            }
+
             NodeKind::Format { name, body } => {
                 format!("(format {} {:?})", name, body)
             }
@@ -861,9 +888,16 @@ impl Node {
             // Eval and Do blocks
             NodeKind::Eval { block } => f(block),
             NodeKind::Do { block } => f(block),
+            NodeKind::DoWhile { block, condition } | NodeKind::DoUntil { block, condition } => {
+                f(block);
+                f(condition);
+            }
             NodeKind::Try { body, catch_blocks, finally_block } => {
                 f(body);
-                for (_, catch_body) in catch_blocks {
+                for (var, catch_body) in catch_blocks {
+                    if let Some(var) = var {
+                        f(var);
+                    }
                     f(catch_body);
                 }
                 if let Some(finally) = finally_block {
@@ -898,7 +932,9 @@ impl Node {
                 if let Some(sig) = signature {
                     f(sig);
                 }
-                f(body);
+                if let Some(body) = body {
+                    f(body);
+                }
             }
             NodeKind::Method { signature, body, .. } => {
                 if let Some(sig) = signature {
@@ -950,6 +986,12 @@ impl Node {
             }
             NodeKind::PhaseBlock { block, .. } => f(block),
             NodeKind::Class { body, .. } => f(body),
+            NodeKind::Field { variable, default, .. } => {
+                f(variable);
+                if let Some(default) = default {
+                    f(default);
+                }
+            }
 
             // Error node might have a partial valid tree
             NodeKind::Error { partial, .. } => {
@@ -969,7 +1011,7 @@ impl Node {
             | NodeKind::Glob { .. }
             | NodeKind::Typeglob { .. }
             | NodeKind::Diamond
-            | NodeKind::Ellipsis
+            | NodeKind::YadaYada
             | NodeKind::Undef
             | NodeKind::Use { .. }
             | NodeKind::No { .. }
@@ -1109,9 +1151,16 @@ impl Node {
             // Eval and Do blocks
             NodeKind::Eval { block } => f(block),
             NodeKind::Do { block } => f(block),
+            NodeKind::DoWhile { block, condition } | NodeKind::DoUntil { block, condition } => {
+                f(block);
+                f(condition);
+            }
             NodeKind::Try { body, catch_blocks, finally_block } => {
                 f(body);
-                for (_, catch_body) in catch_blocks {
+                for (var, catch_body) in catch_blocks {
+                    if let Some(var) = var {
+                        f(var);
+                    }
                     f(catch_body);
                 }
                 if let Some(finally) = finally_block {
@@ -1146,7 +1195,9 @@ impl Node {
                 if let Some(sig) = signature {
                     f(sig);
                 }
-                f(body);
+                if let Some(body) = body {
+                    f(body);
+                }
             }
             NodeKind::Method { signature, body, .. } => {
                 if let Some(sig) = signature {
@@ -1198,6 +1249,12 @@ impl Node {
             }
             NodeKind::PhaseBlock { block, .. } => f(block),
             NodeKind::Class { body, .. } => f(body),
+            NodeKind::Field { variable, default, .. } => {
+                f(variable);
+                if let Some(default) = default {
+                    f(default);
+                }
+            }
 
             // Error node might have a partial valid tree
             NodeKind::Error { partial, .. } => {
@@ -1217,7 +1274,7 @@ impl Node {
             | NodeKind::Glob { .. }
             | NodeKind::Typeglob { .. }
             | NodeKind::Diamond
-            | NodeKind::Ellipsis
+            | NodeKind::YadaYada
             | NodeKind::Undef
             | NodeKind::Use { .. }
             | NodeKind::No { .. }
@@ -1263,6 +1320,102 @@ impl Node {
         });
         result
     }
+
+    /// Span of the `__DATA__`/`__END__` section content in a program.
+    ///
+    /// The span starts after the newline that ends the marker line and runs to
+    /// the end of the source; it is empty when nothing follows the marker.
+    /// Returns `None` for non-program nodes and programs without a data section.
+    pub fn data_section(&self) -> Option<SourceLocation> {
+        let NodeKind::Program { statements } = &self.kind else {
+            return None;
+        };
+        statements.iter().rev().find_map(|stmt| match &stmt.kind {
+            NodeKind::DataSection { body_span, .. } => Some(*body_span),
+            _ => None,
+        })
+    }
+
+    /// Lines of a `format` declaration's body, with each picture line paired
+    /// with the argument line that fills its fields.
+    ///
+    /// The remainder of the `format NAME =` line is skipped. Returns `None`
+    /// for nodes other than [`NodeKind::Format`].
+    pub fn format_lines(&self) -> Option<Vec<FormatLine>> {
+        let NodeKind::Format { body, .. } = &self.kind else {
+            return None;
+        };
+        let mut lines = body.split_terminator('\n').skip(1);
+        let mut result = Vec::new();
+        while let Some(line) = lines.next() {
+            if line.starts_with('#') {
+                result.push(FormatLine::Comment(line.to_string()));
+            } else if line.contains(['@', '^']) {
+                let mut arguments = lines.next().unwrap_or_default().to_string();
+                // A `{` on the argument line continues the list until the matching `}`
+                if arguments.trim_start().starts_with('{') {
+                    let mut depth = brace_depth(&arguments);
+                    while depth > 0 {
+                        let Some(next) = lines.next() else { break };
+                        depth += brace_depth(next);
+                        arguments.push('\n');
+                        arguments.push_str(next);
+                    }
+                }
+                result.push(FormatLine::Picture { picture: line.to_string(), arguments });
+            } else {
+                result.push(FormatLine::Literal(line.to_string()));
+            }
+        }
+        Some(result)
+    }
+}
+
+fn brace_depth(line: &str) -> isize {
+    line.chars().fold(0, |depth, c| match c {
+        '{' => depth + 1,
+        '}' => depth - 1,
+        _ => depth,
+    })
+}
+
+/// One logical line of a `format` body, as returned by [`Node::format_lines`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatLine {
+    /// Comment line (`#` in the first column)
+    Comment(String),
+    /// Line without fields, printed as-is
+    Literal(String),
+    /// Line with `@`/`^` fields and the argument list that fills them
+    Picture {
+        /// Picture text containing the fields
+        picture: String,
+        /// Argument line text, including any `{ ... }` continuation lines
+        arguments: String,
+    },
+}
+
+/// An attribute on a subroutine declaration: `:lvalue`, `:method`, `:Foo(args)`.
+///
+/// Attributes are kept apart from the prototype and signature, so
+/// `sub f :lvalue ($x) { ... }` has one attribute and a signature.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attribute {
+    /// Attribute name without the leading colon (e.g. `lvalue`, `prototype`)
+    pub name: String,
+    /// Raw text between the attribute's parentheses, if it has any (e.g. `$$` for `:prototype($$)`)
+    pub args: Option<String>,
+    /// Source span from the name through the closing parenthesis
+    pub location: SourceLocation,
+}
+
+impl std::fmt::Display for Attribute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.args {
+            Some(args) => write!(f, "{}({})", self.name, args),
+            None => f.write_str(&self.name),
+        }
+    }
 }
 
 /// Comprehensive enumeration of all Perl language constructs supported by the parser.
@@ -1397,8 +1550,8 @@ pub enum NodeKind {
     /// Diamond operator for file input in Perl parsing workflow
     Diamond, // <>
 
-    /// Ellipsis operator for Perl parsing workflow
-    Ellipsis, // ...
+    /// Yada-yada statement `...` (compiles, dies with "Unimplemented" when run)
+    YadaYada,
 
     /// Undef value for Perl parsing workflow
     Undef, // undef
@@ -1491,12 +1644,35 @@ pub enum NodeKind {
         block: Box<Node>,
     },
 
+    /// Post-condition loop: `do { ... } while (condition);`
+    ///
+    /// The block always runs once before the condition is tested. It is not a
+    /// loop body, so `next`/`last`/`redo` inside it do not control this loop.
+    DoWhile {
+        /// Block to execute
+        block: Box<Node>,
+        /// Loop condition, tested after each iteration
+        condition: Box<Node>,
+    },
+
+    /// Post-condition loop: `do { ... } until (condition);`
+    ///
+    /// Like [`NodeKind::DoWhile`], but repeats until the condition becomes true.
+    DoUntil {
+        /// Block to execute
+        block: Box<Node>,
+        /// Exit condition, tested after each iteration
+        condition: Box<Node>,
+    },
+
     /// Try-catch-finally for modern exception handling (Syntax::Keyword::Try style)
     Try {
         /// Try block body
         body: Box<Node>,
         /// Catch blocks: (optional exception variable, handler block)
-        catch_blocks: Vec<(Option<String>, Box<Node>)>,
+        ///
+        /// The variable is a [`NodeKind::Variable`] declared in the handler's scope.
+        catch_blocks: Vec<(Option<Box<Node>>, Box<Node>)>,
         /// Optional finally block
         finally_block: Option<Box<Node>>,
     },
@@ -1633,10 +1809,12 @@ pub enum NodeKind {
         prototype: Option<Box<Node>>,
         /// Optional signature node (Perl 5.20+ feature).
         signature: Option<Box<Node>>,
-        /// Attributes attached to the subroutine (`:lvalue`, etc.).
-        attributes: Vec<String>,
+        /// Attributes attached to the subroutine (`:lvalue`, `:Foo(args)`, etc.).
+        attributes: Vec<Attribute>,
         /// The body block of the subroutine.
-        body: Box<Node>,
+        ///
+        /// `None` for forward declarations such as `sub foo;` or `sub foo ($$);`.
+        body: Option<Box<Node>>,
     },
 
     /// Subroutine prototype specification: `sub foo ($;@) { ... }`
@@ -1721,11 +1899,11 @@ pub enum NodeKind {
         args: Vec<Node>,
     },
 
-    /// Indirect object call (legacy syntax): `new Class @args`
+    /// Indirect object call (legacy syntax): `new Class @args`, `print $fh @list`
     IndirectCall {
-        /// Method name
+        /// Method or builtin name
         method: String,
-        /// Object or class
+        /// Object, class, or filehandle (`STDERR`, `$fh`, or a `{EXPR}` block)
         object: Box<Node>,
         /// Arguments
         args: Vec<Node>,
@@ -1805,6 +1983,9 @@ pub enum NodeKind {
         /// - Essential for precise editor interactions
         name_span: SourceLocation,
 
+        /// Optional version, as in `package Foo 1.23;` or `package Foo v1.2.3 { ... }`
+        version: Option<String>,
+
         /// Optional inline block for `package Foo { ... }` declarations.
         block: Option<Box<Node>>,
     },
@@ -1829,9 +2010,10 @@ pub enum NodeKind {
         has_filter_risk: bool,
     },
 
-    /// Phase block for compile/runtime hooks: `BEGIN`, `END`, `CHECK`, `INIT`, `UNITCHECK`
+    /// Phase block for compile/runtime hooks: `BEGIN`, `END`, `CHECK`, `INIT`, `UNITCHECK`,
+    /// and the `ADJUST` phaser of a class
     PhaseBlock {
-        /// Phase name: BEGIN, END, CHECK, INIT, UNITCHECK
+        /// Phase name: BEGIN, END, CHECK, INIT, UNITCHECK, ADJUST
         phase: String,
         /// Source location span of the phase block name for precise navigation
         phase_span: Option<SourceLocation>,
@@ -1845,16 +2027,31 @@ pub enum NodeKind {
         marker: String,
         /// Content following the marker (if any)
         body: Option<String>,
+        /// Source location span of the content; empty at the end of the
+        /// marker when nothing follows it
+        body_span: SourceLocation,
     },
 
     /// Class declaration (Perl 5.38+ with `use feature 'class'`)
     Class {
         /// Class name
         name: String,
+        /// Class attributes (e.g., `isa(Base)`)
+        attributes: Vec<String>,
         /// Class body containing methods and attributes
         body: Box<Node>,
     },
 
+    /// Field declaration inside a class: `field $x :param :reader = 0;`
+    Field {
+        /// Field variable (`$x`, `@items` or `%opts`)
+        variable: Box<Node>,
+        /// Field attributes (e.g., `param`, `reader(get_x)`)
+        attributes: Vec<String>,
+        /// Default value from `=`, `//=` or `||=`
+        default: Option<Box<Node>>,
+    },
+
     /// Format declaration for legacy report generation
     Format {
         /// Format name (defaults to filehandle name)
@@ -1911,7 +2108,7 @@ impl NodeKind {
             NodeKind::Ternary { .. } => "Ternary",
             NodeKind::Unary { .. } => "Unary",
             NodeKind::Diamond => "Diamond",
-            NodeKind::Ellipsis => "Ellipsis",
+            NodeKind::YadaYada => "YadaYada",
             NodeKind::Undef => "Undef",
             NodeKind::Readline { .. } => "Readline",
             NodeKind::Glob { .. } => "Glob",
@@ -1924,6 +2121,8 @@ impl NodeKind {
             NodeKind::Block { .. } => "Block",
             NodeKind::Eval { .. } => "Eval",
             NodeKind::Do { .. } => "Do",
+            NodeKind::DoWhile { .. } => "DoWhile",
+            NodeKind::DoUntil { .. } => "DoUntil",
             NodeKind::Try { .. } => "Try",
             NodeKind::If { .. } => "If",
             NodeKind::LabeledStatement { .. } => "LabeledStatement",
@@ -1959,6 +2158,7 @@ impl NodeKind {
             NodeKind::PhaseBlock { .. } => "PhaseBlock",
             NodeKind::DataSection { .. } => "DataSection",
             NodeKind::Class { .. } => "Class",
+            NodeKind::Field { .. } => "Field",
             NodeKind::Format { .. } => "Format",
             NodeKind::Identifier { .. } => "Identifier",
             NodeKind::Error { .. } => "Error",
@@ -1984,10 +2184,12 @@ impl NodeKind {
         "Default",
         "Diamond",
         "Do",
-        "Ellipsis",
+        "DoUntil",
+        "DoWhile",
         "Error",
         "Eval",
         "ExpressionStatement",
+        "Field",
         "For",
         "Foreach",
         "Format",
@@ -2042,6 +2244,7 @@ impl NodeKind {
         "VariableWithAttributes",
         "When",
         "While",
+        "YadaYada",
     ];
 
     /// Subset of `ALL_KIND_NAMES` that represent synthetic/recovery nodes.
@@ -2112,6 +2315,7 @@ fn format_unary_operator(op: &str) -> String {
         "->@*" => "unary_->@*".to_string(),
         "->%*" => "unary_->%*".to_string(),
         "->$*" => "unary_->$*".to_string(),
+        "->$#*" => "unary_->$#*".to_string(),
         "->&*" => "unary_->&*".to_string(),
         "->**" => "unary_->**".to_string(),
 
@@ -2268,7 +2472,7 @@ mod tests {
             },
             NodeKind::Unary { op: String::new(), operand: Box::new(dummy_node()) },
             NodeKind::Diamond,
-            NodeKind::Ellipsis,
+            NodeKind::YadaYada,
             NodeKind::Undef,
             NodeKind::Readline { filehandle: None },
             NodeKind::Glob { pattern: String::new() },
@@ -2288,6 +2492,8 @@ mod tests {
             NodeKind::Block { statements: vec![] },
             NodeKind::Eval { block: Box::new(dummy_node()) },
             NodeKind::Do { block: Box::new(dummy_node()) },
+            NodeKind::DoWhile { block: Box::new(dummy_node()), condition: Box::new(dummy_node()) },
+            NodeKind::DoUntil { block: Box::new(dummy_node()), condition: Box::new(dummy_node()) },
             NodeKind::Try {
                 body: Box::new(dummy_node()),
                 catch_blocks: vec![],
@@ -2338,7 +2544,7 @@ mod tests {
                 prototype: None,
                 signature: None,
                 attributes: vec![],
-                body: Box::new(dummy_node()),
+                body: Some(Box::new(dummy_node())),
             },
             NodeKind::Prototype { content: String::new() },
             NodeKind::Signature { parameters: vec![] },
@@ -2393,7 +2599,7 @@ mod tests {
                 replace: String::new(),
                 modifiers: String::new(),
             },
-            NodeKind::Package { name: String::new(), name_span: loc, block: None },
+            NodeKind::Package { name: String::new(), name_span: loc, version: None, block: None },
             NodeKind::Use { module: String::new(), args: vec![], has_filter_risk: false },
             NodeKind::No { module: String::new(), args: vec![], has_filter_risk: false },
             NodeKind::PhaseBlock {
@@ -2401,8 +2607,13 @@ mod tests {
                 phase_span: None,
                 block: Box::new(dummy_node()),
             },
-            NodeKind::DataSection { marker: String::new(), body: None },
-            NodeKind::Class { name: String::new(), body: Box::new(dummy_node()) },
+            NodeKind::DataSection { marker: String::new(), body: None, body_span: loc },
+            NodeKind::Class {
+                name: String::new(),
+                attributes: vec![],
+                body: Box::new(dummy_node()),
+            },
+            NodeKind::Field { variable: Box::new(dummy_node()), attributes: vec![], default: None },
             NodeKind::Format { name: String::new(), body: String::new() },
             NodeKind::Identifier { name: String::new() },
             NodeKind::Error {
@@ -8,5 +8,5 @@
 pub mod ast;
 pub mod v2;
 
-pub use ast::{Node, NodeKind};
+pub use ast::{Attribute, FormatLine, Node, NodeKind};
 pub use perl_position_tracking::SourceLocation;
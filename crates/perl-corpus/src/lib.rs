@@ -193,6 +193,10 @@
 //! # }
 //! ```
 //!
+//! [`run_corpus`] does this for a whole directory, honoring the
+//! `expected-error` and `wip` flags, and returns a [`CorpusReport`] with the
+//! outcome counts and the ids of failing sections.
+//!
 //! # Test Case Validation
 //!
 //! Corpus files can include validation flags:
@@ -225,6 +229,8 @@ pub mod glob_expressions;
 pub mod index;
 pub mod lint;
 pub mod meta;
+pub mod runner;
+pub mod shrink;
 pub mod tie_interface;
 
 use anyhow::{Context, Result};
@@ -253,6 +259,8 @@ pub use glob_expressions::{
 };
 use meta::Section;
 use regex::Regex;
+pub use runner::{CorpusReport, SectionOutcome, classify_section, run_corpus, run_sections};
+pub use shrink::shrink;
 use std::collections::HashMap;
 use std::{fs, path::Path};
 pub use tie_interface::{
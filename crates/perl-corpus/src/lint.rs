@@ -212,6 +212,8 @@ pub const KNOWN_FLAGS: &[&str] = &[
     "parser-sensitive",
     "ambiguous",
     "error-node-expected",
+    "expected-error",
+    "wip",
     "experimental",
     "version-gated",
     "slow",
@@ -0,0 +1,153 @@
+//! Run corpus sections through the parser and summarize the outcomes.
+//!
+//! Each section is parsed with error recovery and sorted into one of the
+//! [`SectionOutcome`] buckets. Sections flagged `expected-error` (or
+//! `error-node-expected`) are expected to produce parse errors; sections
+//! flagged `wip` are skipped and only counted.
+
+use std::path::Path;
+
+use anyhow::Result;
+use perl_parser_core::Parser;
+use serde::Serialize;
+
+use crate::meta::Section;
+use crate::parse_dir;
+
+/// How a section's parse result compares with what its flags expect
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum SectionOutcome {
+    /// Parsed without errors, as expected
+    Parsed,
+    /// Flagged as expecting an error, and the parser reported one
+    ExpectedError,
+    /// Parse errors in a section that should parse cleanly
+    UnexpectedError,
+    /// Flagged as expecting an error, but the parser reported none
+    UnexpectedSuccess,
+}
+
+/// Outcome counts for a corpus run, with the ids of failing sections
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct CorpusReport {
+    /// Sections that parsed cleanly as expected
+    pub parsed: usize,
+    /// Sections that errored as their flags expected
+    pub expected_errors: usize,
+    /// Ids of sections that should parse but produced errors
+    pub unexpected_errors: Vec<String>,
+    /// Ids of sections flagged `expected-error` that parsed cleanly
+    pub unexpected_successes: Vec<String>,
+    /// Sections skipped because they are flagged `wip`
+    pub wip: usize,
+}
+
+impl CorpusReport {
+    /// Whether every section behaved as its flags expect
+    pub fn is_ok(&self) -> bool {
+        self.unexpected_errors.is_empty() && self.unexpected_successes.is_empty()
+    }
+
+    /// Number of sections that were parsed (everything except `wip`)
+    pub fn total(&self) -> usize {
+        self.parsed
+            + self.expected_errors
+            + self.unexpected_errors.len()
+            + self.unexpected_successes.len()
+    }
+
+    /// Ids of all sections with an unexpected outcome
+    pub fn failing_ids(&self) -> impl Iterator<Item = &str> {
+        self.unexpected_errors.iter().chain(&self.unexpected_successes).map(String::as_str)
+    }
+
+    fn record(&mut self, section: &Section, outcome: SectionOutcome) {
+        match outcome {
+            SectionOutcome::Parsed => self.parsed += 1,
+            SectionOutcome::ExpectedError => self.expected_errors += 1,
+            SectionOutcome::UnexpectedError => self.unexpected_errors.push(section.id.clone()),
+            SectionOutcome::UnexpectedSuccess => self.unexpected_successes.push(section.id.clone()),
+        }
+    }
+}
+
+/// Parse every section under `dir` and report the outcomes.
+pub fn run_corpus(dir: &Path) -> Result<CorpusReport> {
+    Ok(run_sections(&parse_dir(dir)?))
+}
+
+/// Parse already-loaded sections and report the outcomes.
+pub fn run_sections(sections: &[Section]) -> CorpusReport {
+    let mut report = CorpusReport::default();
+    for section in sections {
+        match classify_section(section) {
+            Some(outcome) => report.record(section, outcome),
+            None => report.wip += 1,
+        }
+    }
+    report
+}
+
+/// Parse one section and classify the result, or `None` for `wip` sections.
+pub fn classify_section(section: &Section) -> Option<SectionOutcome> {
+    if section.has_flag("wip") {
+        return None;
+    }
+
+    let expects_error =
+        section.has_flag("expected-error") || section.has_flag("error-node-expected");
+    let has_errors = Parser::new(&section.body).parse_with_recovery().has_errors();
+
+    Some(match (expects_error, has_errors) {
+        (false, false) => SectionOutcome::Parsed,
+        (true, true) => SectionOutcome::ExpectedError,
+        (false, true) => SectionOutcome::UnexpectedError,
+        (true, false) => SectionOutcome::UnexpectedSuccess,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section(id: &str, flags: &[&str], body: &str) -> Section {
+        Section {
+            id: id.to_string(),
+            title: id.to_string(),
+            file: "inline.txt".to_string(),
+            tags: Vec::new(),
+            perl: None,
+            flags: flags.iter().map(|f| f.to_string()).collect(),
+            body: body.to_string(),
+            line: None,
+        }
+    }
+
+    #[test]
+    fn report_counts_each_bucket() {
+        let sections = vec![
+            section("ok.scalar", &[], "my $x = 1;\n"),
+            section("ok.sub", &[], "sub add { return $_[0] + $_[1]; }\n"),
+            section("err.expected", &["expected-error"], "my $x = ;\n"),
+            section("err.unexpected", &[], "print(1;\n"),
+            section("err.missing", &["expected-error"], "my $y = 2;\n"),
+            section("wip.broken", &["wip"], "my $z = ;\n"),
+        ];
+
+        let report = run_sections(&sections);
+
+        assert_eq!(report.parsed, 2);
+        assert_eq!(report.expected_errors, 1);
+        assert_eq!(report.unexpected_errors, vec!["err.unexpected".to_string()]);
+        assert_eq!(report.unexpected_successes, vec!["err.missing".to_string()]);
+        assert_eq!(report.wip, 1);
+        assert_eq!(report.total(), 5);
+        assert!(!report.is_ok());
+        assert_eq!(report.failing_ids().collect::<Vec<_>>(), vec!["err.unexpected", "err.missing"]);
+    }
+
+    #[test]
+    fn wip_sections_are_not_parsed() {
+        assert_eq!(classify_section(&section("wip.x", &["wip"], "print(1;\n")), None);
+    }
+}
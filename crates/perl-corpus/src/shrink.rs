@@ -0,0 +1,146 @@
+//! Test-case reduction for generated Perl that trips the parser.
+//!
+//! [`shrink`] takes an input that fails some check (typically "the parser
+//! reports an error") and removes lines, then whitespace-separated tokens,
+//! for as long as the check keeps failing. The result is a small reproducer
+//! for triaging fuzz findings from [`crate::codegen`].
+
+/// Upper bound on predicate calls for a single [`shrink`] run.
+const MAX_CHECKS: usize = 10_000;
+
+/// Reduce `code` to a smaller input for which `still_fails` still returns `true`.
+///
+/// Works in passes over chunks of lines and then single tokens, halving the
+/// chunk size each time, and repeats until a full pass removes nothing. The
+/// search is deterministic and makes at most `MAX_CHECKS` calls to
+/// `still_fails`. If `code` itself does not fail, it is returned unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// use perl_corpus::shrink;
+///
+/// let reduced = shrink("my $a = 1;\nBOOM;\nmy $b = 2;\n", |code| code.contains("BOOM"));
+/// assert_eq!(reduced, "BOOM;");
+/// ```
+pub fn shrink(code: &str, still_fails: impl Fn(&str) -> bool) -> String {
+    let mut remaining = MAX_CHECKS;
+    let mut check = |candidate: &str| {
+        if remaining == 0 {
+            return false;
+        }
+        remaining -= 1;
+        still_fails(candidate)
+    };
+
+    if !check(code) {
+        return code.to_string();
+    }
+
+    let mut current = code.to_string();
+    loop {
+        let before = current.len();
+        current = remove_chunks(current, lines, &mut check);
+        current = remove_chunks(current, tokens, &mut check);
+        if current.len() == before {
+            break;
+        }
+    }
+
+    let trimmed = current.trim();
+    if trimmed.len() < current.len() && check(trimmed) {
+        return trimmed.to_string();
+    }
+    current
+}
+
+/// Remove every run of units whose removal keeps the input failing
+///
+/// Tries runs of half the units first, then halves the run length down to a
+/// single unit.
+fn remove_chunks(
+    code: String,
+    split: fn(&str) -> Vec<&str>,
+    check: &mut impl FnMut(&str) -> bool,
+) -> String {
+    let mut units: Vec<String> = split(&code).into_iter().map(str::to_string).collect();
+    let mut size = (units.len() / 2).max(1);
+
+    loop {
+        let mut start = 0;
+        while start < units.len() {
+            let end = (start + size).min(units.len());
+            let candidate = [units[..start].concat(), units[end..].concat()].concat();
+            if check(&candidate) {
+                units.drain(start..end);
+            } else {
+                start = end;
+            }
+        }
+        if size == 1 {
+            break;
+        }
+        size /= 2;
+    }
+
+    units.concat()
+}
+
+/// Lines including their terminators
+fn lines(code: &str) -> Vec<&str> {
+    code.split_inclusive('\n').collect()
+}
+
+/// Whitespace-separated tokens, each keeping the whitespace that follows it
+fn tokens(code: &str) -> Vec<&str> {
+    let mut units = Vec::new();
+    let mut start = 0;
+    let mut in_space = code.starts_with(char::is_whitespace);
+    for (idx, ch) in code.char_indices() {
+        if in_space && !ch.is_whitespace() && idx > start {
+            units.push(&code[start..idx]);
+            start = idx;
+        }
+        in_space = ch.is_whitespace();
+    }
+    if start < code.len() {
+        units.push(&code[start..]);
+    }
+    units
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use perl_parser::Parser;
+
+    fn parse_fails(code: &str) -> bool {
+        Parser::new(code).parse_with_recovery().has_errors()
+    }
+
+    #[test]
+    fn shrinks_to_offending_statement() {
+        let code =
+            "use strict;\nmy $count = 1;\nprint \"count\\n\";\n$total=;\nmy @items = (1, 2, 3);\n";
+        assert!(parse_fails(code));
+
+        assert_eq!(shrink(code, parse_fails), "$total=;");
+    }
+
+    #[test]
+    fn passing_input_is_returned_unchanged() {
+        let code = "my $x = 1;\nprint $x;\n";
+        assert_eq!(shrink(code, parse_fails), code);
+    }
+
+    #[test]
+    fn shrinking_is_deterministic() {
+        let code = "my $a = 1;\nmy $b = (2;\nmy $c = 3;\n";
+        assert_eq!(shrink(code, parse_fails), shrink(code, parse_fails));
+    }
+
+    #[test]
+    fn tokens_keep_trailing_whitespace() {
+        assert_eq!(tokens("  my $x  = 1;\n"), vec!["  ", "my ", "$x  ", "= ", "1;\n"]);
+    }
+}
@@ -214,7 +214,7 @@ fn collect_node_kinds(node: &Node, scopes: &mut HashMap<String, usize>) {
             collect_node_kinds(lhs, scopes);
             collect_node_kinds(rhs, scopes);
         }
-        NodeKind::Subroutine { body, .. } => {
+        NodeKind::Subroutine { body: Some(body), .. } => {
             collect_node_kinds(body, scopes);
         }
         NodeKind::Use { .. } => {
@@ -3,11 +3,14 @@
 //! This module provides AST-based validation for breakpoint locations.
 //! It checks whether a given line number contains executable code or is
 //! a non-executable location like a comment, blank line, or heredoc interior.
+//! Breakpoints on comment and blank lines move down to the next statement;
+//! breakpoints in POD or after `__END__`/`__DATA__` are rejected.
 
 use crate::BreakpointError;
 use perl_parser::Parser;
 use perl_parser::ast::{Node, NodeKind};
 use ropey::Rope;
+use std::collections::BTreeSet;
 
 /// Reason why a breakpoint was rejected or adjusted
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -18,6 +21,10 @@ pub enum ValidationReason {
     CommentLine,
     /// The breakpoint is inside heredoc content
     HeredocInterior,
+    /// The breakpoint is inside a POD block
+    PodBlock,
+    /// The breakpoint is after the `__END__` or `__DATA__` marker
+    AfterEndMarker,
     /// The line number exceeds the file length
     LineOutOfRange,
     /// Unable to parse the source file
@@ -30,6 +37,10 @@ impl std::fmt::Display for ValidationReason {
             ValidationReason::BlankLine => write!(f, "Breakpoint set on blank line"),
             ValidationReason::CommentLine => write!(f, "Breakpoint set on comment or blank line"),
             ValidationReason::HeredocInterior => write!(f, "Breakpoint set inside heredoc content"),
+            ValidationReason::PodBlock => write!(f, "Breakpoint set inside POD documentation"),
+            ValidationReason::AfterEndMarker => {
+                write!(f, "Breakpoint set after __END__ or __DATA__ marker")
+            }
             ValidationReason::LineOutOfRange => write!(f, "Line number exceeds file length"),
             ValidationReason::ParseError => write!(f, "Unable to parse source file"),
         }
@@ -93,6 +104,12 @@ pub struct AstBreakpointValidator {
     rope: Rope,
     /// Original source code
     source: String,
+    /// 0-based lines on which a statement starts
+    statement_lines: BTreeSet<usize>,
+    /// 0-based lines inside POD blocks, including the `=` and `=cut` lines
+    pod_lines: BTreeSet<usize>,
+    /// 0-based line of the `__END__` or `__DATA__` marker
+    end_marker_line: Option<usize>,
 }
 
 impl AstBreakpointValidator {
@@ -109,7 +126,69 @@ impl AstBreakpointValidator {
         let mut parser = Parser::new(source);
         let ast = parser.parse().map_err(|e| BreakpointError::ParseError(format!("{:?}", e)))?;
         let rope = Rope::from_str(source);
-        Ok(Self { ast, rope, source: source.to_string() })
+        let mut validator = Self {
+            ast,
+            rope,
+            source: source.to_string(),
+            statement_lines: BTreeSet::new(),
+            pod_lines: BTreeSet::new(),
+            end_marker_line: None,
+        };
+        validator.scan_non_code_lines();
+        let mut statement_lines = BTreeSet::new();
+        validator.collect_statement_lines(&validator.ast, &mut statement_lines);
+        validator.statement_lines = statement_lines;
+        Ok(validator)
+    }
+
+    /// Record POD blocks and the `__END__`/`__DATA__` marker from the source text
+    fn scan_non_code_lines(&mut self) {
+        let mut in_pod = false;
+        let mut offset = 0;
+        for (index, line) in self.source.split_inclusive('\n').enumerate() {
+            let line_start = offset;
+            offset += line.len();
+            let line = line.trim_end();
+
+            let starts_pod = || {
+                line.starts_with('=')
+                    && line[1..].starts_with(|c: char| c.is_ascii_alphabetic())
+                    && !self.is_inside_heredoc_interior(line_start)
+            };
+            if in_pod || starts_pod() {
+                self.pod_lines.insert(index);
+                in_pod = !line.starts_with("=cut");
+            } else if (line == "__END__" || line == "__DATA__")
+                && !self.is_inside_heredoc_interior(line_start)
+            {
+                self.end_marker_line = Some(index);
+                break;
+            }
+        }
+    }
+
+    /// Record the first line of every statement in `node` and its descendants
+    fn collect_statement_lines(&self, node: &Node, lines: &mut BTreeSet<usize>) {
+        if let NodeKind::Program { statements } | NodeKind::Block { statements } = &node.kind {
+            for statement in statements {
+                if !matches!(statement.kind, NodeKind::DataSection { .. })
+                    && statement.location.start < self.rope.len_bytes()
+                {
+                    lines.insert(self.rope.byte_to_line(statement.location.start));
+                }
+            }
+        }
+        node.for_each_child(|child| self.collect_statement_lines(child, lines));
+    }
+
+    /// First statement-start line (1-based) after `line` that precedes any end marker
+    fn next_statement_line(&self, line: i64) -> Option<i64> {
+        let line_idx = usize::try_from(line).ok()?;
+        self.statement_lines
+            .range(line_idx..)
+            .next()
+            .filter(|&&next| self.end_marker_line.is_none_or(|end| next < end))
+            .map(|&next| next as i64 + 1)
     }
 
     /// Get the line range (start byte, end byte) for a given 1-based line number
@@ -214,14 +293,26 @@ impl BreakpointValidator for AstBreakpointValidator {
             return BreakpointValidation::rejected(line, ValidationReason::LineOutOfRange);
         };
 
-        // Validation 1: Inside heredoc interior
+        let line_idx = (line - 1).max(0) as usize;
+
+        // Validation 1: After __END__/__DATA__, nothing is executed
+        if self.end_marker_line.is_some_and(|end| line_idx >= end) {
+            return BreakpointValidation::rejected(line, ValidationReason::AfterEndMarker);
+        }
+
+        // Validation 2: POD is skipped by the compiler
+        if self.pod_lines.contains(&line_idx) {
+            return BreakpointValidation::rejected(line, ValidationReason::PodBlock);
+        }
+
+        // Validation 3: Inside heredoc interior
         // Check BEFORE comment/blank check because heredoc interior lines have no AST nodes
         // and would otherwise be incorrectly classified as blank/comment lines
         if self.is_inside_heredoc_interior(line_start) {
             return BreakpointValidation::rejected(line, ValidationReason::HeredocInterior);
         }
 
-        // Validation 2: Comment or blank line
+        // Validation 4: Comment or blank line, moved down to the next statement
         if self.is_comment_or_blank_line(line_start, line_end) {
             // Check if the line is truly blank or just a comment
             let line_text = &self.source[line_start..line_end.min(self.source.len())];
@@ -230,7 +321,10 @@ impl BreakpointValidator for AstBreakpointValidator {
             } else {
                 ValidationReason::CommentLine
             };
-            return BreakpointValidation::rejected(line, reason);
+            return match self.next_statement_line(line) {
+                Some(next) => BreakpointValidation::adjusted(next, reason),
+                None => BreakpointValidation::rejected(line, reason),
+            };
         }
 
         // Breakpoint is valid
@@ -238,7 +332,8 @@ impl BreakpointValidator for AstBreakpointValidator {
     }
 
     fn is_executable_line(&self, line: i64) -> bool {
-        self.validate(line).verified
+        let result = self.validate(line);
+        result.verified && result.line == line
     }
 }
 
@@ -264,7 +359,8 @@ mod tests {
         let validator = must(AstBreakpointValidator::new(source));
 
         let result = validator.validate(1);
-        assert!(!result.verified);
+        assert!(result.verified);
+        assert_eq!(result.line, 2);
         assert_eq!(result.reason, Some(ValidationReason::CommentLine));
     }
 
@@ -274,10 +370,68 @@ mod tests {
         let validator = must(AstBreakpointValidator::new(source));
 
         let result = validator.validate(2);
-        assert!(!result.verified);
+        assert!(result.verified);
+        assert_eq!(result.line, 3);
         assert_eq!(result.reason, Some(ValidationReason::BlankLine));
     }
 
+    #[test]
+    fn test_validate_comment_snaps_to_next_statement_in_block() {
+        let source = "sub run {\n    # setup\n\n    my $x = 1;\n    return $x;\n}\n";
+        let validator = must(AstBreakpointValidator::new(source));
+
+        let result = validator.validate(2);
+        assert!(result.verified);
+        assert_eq!(result.line, 4);
+        assert_eq!(
+            result.message.as_deref(),
+            Some("Breakpoint set on comment or blank line, adjusted to line 4")
+        );
+
+        assert!(validator.validate(5).verified);
+        assert_eq!(validator.validate(5).line, 5);
+    }
+
+    #[test]
+    fn test_validate_trailing_comment_without_statement() {
+        let source = "my $x = 1;\n# trailing\n";
+        let validator = must(AstBreakpointValidator::new(source));
+
+        let result = validator.validate(2);
+        assert!(!result.verified);
+        assert_eq!(result.line, 2);
+        assert_eq!(result.reason, Some(ValidationReason::CommentLine));
+    }
+
+    #[test]
+    fn test_validate_pod_block() {
+        let source = "my $x = 1;\n\n=head1 NAME\n\nDemo\n\n=cut\n\nprint $x;\n";
+        let validator = must(AstBreakpointValidator::new(source));
+
+        for line in [3, 5, 7] {
+            let result = validator.validate(line);
+            assert!(!result.verified, "line {line} is POD");
+            assert_eq!(result.reason, Some(ValidationReason::PodBlock));
+        }
+        // A blank line before POD moves past it to the next statement
+        assert_eq!(validator.validate(2).line, 9);
+    }
+
+    #[test]
+    fn test_validate_after_end_marker() {
+        let source = "# setup\nmy $x = 1;\n__END__\nmy $y = 2;\n";
+        let validator = must(AstBreakpointValidator::new(source));
+
+        assert_eq!(validator.validate(1).line, 2);
+        assert!(validator.validate(2).verified);
+        for line in [3, 4] {
+            let result = validator.validate(line);
+            assert!(!result.verified, "line {line} is after __END__");
+            assert_eq!(result.line, line);
+            assert_eq!(result.reason, Some(ValidationReason::AfterEndMarker));
+        }
+    }
+
     #[test]
     fn test_validate_line_out_of_range() {
         let source = "my $x = 1;\n";
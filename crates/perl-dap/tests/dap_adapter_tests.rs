@@ -129,8 +129,13 @@ mod dap_phase2_tests {
             .ok_or_else(|| anyhow::anyhow!("missing breakpoints array"))?;
         assert_eq!(breakpoints.len(), 2);
         assert!(
-            !breakpoints[0].get("verified").and_then(Value::as_bool).unwrap_or(true),
-            "comment line should not be verified"
+            breakpoints[0].get("verified").and_then(Value::as_bool).unwrap_or(false),
+            "comment line should move to the next statement"
+        );
+        assert_eq!(
+            breakpoints[0].get("line").and_then(Value::as_i64),
+            Some(2),
+            "response should echo the adjusted line"
         );
         assert!(
             breakpoints[1].get("verified").and_then(Value::as_bool).unwrap_or(false),
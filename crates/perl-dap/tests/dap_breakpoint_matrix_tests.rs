@@ -59,7 +59,7 @@ fn create_test_file_and_set_breakpoints(
     Ok(breakpoints)
 }
 
-/// AC7.1: Breakpoints on comment-only lines should be rejected
+/// AC7.1: Breakpoints on comment-only lines move down to the next statement
 #[test]
 fn test_breakpoint_on_comment_line() -> Result<()> {
     let source = r#"#!/usr/bin/perl
@@ -70,7 +70,8 @@ my $x = 42;
     let breakpoints = create_test_file_and_set_breakpoints(source, vec![2])?;
 
     assert_eq!(breakpoints.len(), 1);
-    assert!(!breakpoints[0].verified, "Breakpoint on comment line should be unverified");
+    assert!(breakpoints[0].verified, "Breakpoint on comment line should move, not fail");
+    assert_eq!(breakpoints[0].line, 3, "Breakpoint should snap to the next statement");
     let message = breakpoints[0]
         .message
         .as_ref()
@@ -80,7 +81,7 @@ my $x = 42;
     Ok(())
 }
 
-/// AC7.1: Breakpoints on blank lines should be rejected
+/// AC7.1: Breakpoints on blank lines move down to the next statement
 #[test]
 fn test_breakpoint_on_blank_line() -> Result<()> {
     let source = r#"my $x = 42;
@@ -91,7 +92,8 @@ my $y = 100;
     let breakpoints = create_test_file_and_set_breakpoints(source, vec![2])?;
 
     assert_eq!(breakpoints.len(), 1);
-    assert!(!breakpoints[0].verified, "Breakpoint on blank line should be unverified");
+    assert!(breakpoints[0].verified, "Breakpoint on blank line should move, not fail");
+    assert_eq!(breakpoints[0].line, 3, "Breakpoint should snap to the next statement");
     let message = breakpoints[0]
         .message
         .as_ref()
@@ -169,31 +171,16 @@ my $y = 100;
 
     assert_eq!(breakpoints.len(), 7);
 
-    // Line 1: shebang comment - unverified
-    assert!(!breakpoints[0].verified);
-
-    // Line 2: comment - unverified
-    assert!(!breakpoints[1].verified);
-
-    // Line 3: executable - verified
-    assert!(breakpoints[2].verified);
-
-    // Line 4: blank - unverified
-    assert!(!breakpoints[3].verified);
-
-    // Line 5: executable - verified
-    assert!(breakpoints[4].verified);
-
-    // Line 6: comment - unverified
-    assert!(!breakpoints[5].verified);
-
-    // Line 7: executable - verified
-    assert!(breakpoints[6].verified);
+    // Every line resolves to an executable statement: comments and blank lines
+    // (1, 2, 4, 6) move down, statements (3, 5, 7) stay put
+    assert!(breakpoints.iter().all(|bp| bp.verified));
+    let resolved: Vec<i64> = breakpoints.iter().map(|bp| bp.line).collect();
+    assert_eq!(resolved, vec![3, 3, 3, 5, 5, 7, 7]);
 
     Ok(())
 }
 
-/// AC7.1: Whitespace-only lines should be rejected
+/// AC7.1: Whitespace-only lines move down to the next statement
 #[test]
 fn test_breakpoint_on_whitespace_line() -> Result<()> {
     let source = "my $x = 42;\n    \t  \nmy $y = 100;\n";
@@ -201,7 +188,31 @@ fn test_breakpoint_on_whitespace_line() -> Result<()> {
     let breakpoints = create_test_file_and_set_breakpoints(source, vec![2])?;
 
     assert_eq!(breakpoints.len(), 1);
-    assert!(!breakpoints[0].verified, "Breakpoint on whitespace-only line should be unverified");
+    assert!(breakpoints[0].verified, "Breakpoint on whitespace-only line should move");
+    assert_eq!(breakpoints[0].line, 3);
+
+    Ok(())
+}
+
+/// AC7.1: Nothing after `__END__` runs, so breakpoints there are rejected in place
+#[test]
+fn test_breakpoint_after_end_marker() -> Result<()> {
+    let source = "# Comment\nmy $x = 42;\nprint $x;\n__END__\nprint 'never';\n";
+
+    let breakpoints = create_test_file_and_set_breakpoints(source, vec![1, 3, 5])?;
+
+    assert_eq!(breakpoints.len(), 3);
+    assert!(breakpoints[0].verified);
+    assert_eq!(breakpoints[0].line, 2, "comment line should snap down");
+    assert!(breakpoints[1].verified);
+    assert_eq!(breakpoints[1].line, 3, "statement line should stay put");
+    assert!(!breakpoints[2].verified, "line after __END__ should be unverified");
+    assert_eq!(breakpoints[2].line, 5);
+    let message = breakpoints[2]
+        .message
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("Expected breakpoint message"))?;
+    assert!(message.contains("__END__"));
 
     Ok(())
 }
@@ -280,7 +291,7 @@ my $y = 100;
     let breakpoints = store.set_breakpoints(&args2);
 
     assert_eq!(breakpoints.len(), 2);
-    assert!(!breakpoints[0].verified, "Line 1 is comment, should be unverified");
+    assert_eq!(breakpoints[0].line, 2, "Line 1 is comment, should move to line 2");
     assert!(breakpoints[1].verified, "Line 3 is executable, should be verified");
 
     // Verify stored breakpoints
@@ -366,7 +377,8 @@ mod dap_breakpoint_matrix_phase2 {
         let breakpoints = set_breakpoints(&path, &[1, 7, 10, 21]);
 
         assert_eq!(breakpoints.len(), 4);
-        assert!(!breakpoints[0].verified, "shebang line should not be executable");
+        assert!(breakpoints[0].verified, "shebang line should move to the first statement");
+        assert_eq!(breakpoints[0].line, 7);
         assert!(breakpoints[1].verified, "use strict line should be executable");
         assert!(breakpoints[2].verified, "function declaration line should be executable");
         assert!(!breakpoints[3].verified, "EOF comment line should not be executable");
@@ -382,9 +394,9 @@ mod dap_breakpoint_matrix_phase2 {
 
         assert_eq!(breakpoints.len(), 4);
         assert!(breakpoints[0].verified, "BEGIN block header should be executable");
-        assert!(!breakpoints[1].verified, "comment in BEGIN block should be rejected");
+        assert_eq!(breakpoints[1].line, 12, "comment in BEGIN block should move to its statement");
         assert!(breakpoints[2].verified, "END block header should be executable");
-        assert!(!breakpoints[3].verified, "comment in END block should be rejected");
+        assert_eq!(breakpoints[3].line, 30, "comment in END block should move to its statement");
         Ok(())
     }
 
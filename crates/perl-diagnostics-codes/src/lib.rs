@@ -125,6 +125,8 @@ pub enum DiagnosticCode {
     TwoArgOpen,
     /// Implicit return value
     ImplicitReturn,
+    /// Indirect object method call (`new Class`)
+    IndirectObjectSyntax,
 
     // Perl::Critic violations (PC001-PC005)
     /// Perl::Critic brutal (severity 1) violation
@@ -157,6 +159,7 @@ impl DiagnosticCode {
             DiagnosticCode::BarewordFilehandle => "PL400",
             DiagnosticCode::TwoArgOpen => "PL401",
             DiagnosticCode::ImplicitReturn => "PL402",
+            DiagnosticCode::IndirectObjectSyntax => "PL403",
             DiagnosticCode::CriticSeverity1 => "PC001",
             DiagnosticCode::CriticSeverity2 => "PC002",
             DiagnosticCode::CriticSeverity3 => "PC003",
@@ -184,6 +187,7 @@ impl DiagnosticCode {
             DiagnosticCode::BarewordFilehandle => Some("https://docs.perl-lsp.org/errors/PL400"),
             DiagnosticCode::TwoArgOpen => Some("https://docs.perl-lsp.org/errors/PL401"),
             DiagnosticCode::ImplicitReturn => Some("https://docs.perl-lsp.org/errors/PL402"),
+            DiagnosticCode::IndirectObjectSyntax => Some("https://docs.perl-lsp.org/errors/PL403"),
             // Perl::Critic codes don't have centralized documentation
             DiagnosticCode::CriticSeverity1
             | DiagnosticCode::CriticSeverity2
@@ -217,7 +221,8 @@ impl DiagnosticCode {
             | DiagnosticCode::CriticSeverity2 => DiagnosticSeverity::Warning,
 
             // Information/Hints
-            DiagnosticCode::CriticSeverity3
+            DiagnosticCode::IndirectObjectSyntax
+            | DiagnosticCode::CriticSeverity3
             | DiagnosticCode::CriticSeverity4
             | DiagnosticCode::CriticSeverity5 => DiagnosticSeverity::Hint,
         }
@@ -270,6 +275,7 @@ impl DiagnosticCode {
             "PL400" => Some(DiagnosticCode::BarewordFilehandle),
             "PL401" => Some(DiagnosticCode::TwoArgOpen),
             "PL402" => Some(DiagnosticCode::ImplicitReturn),
+            "PL403" => Some(DiagnosticCode::IndirectObjectSyntax),
             "PC001" => Some(DiagnosticCode::CriticSeverity1),
             "PC002" => Some(DiagnosticCode::CriticSeverity2),
             "PC003" => Some(DiagnosticCode::CriticSeverity3),
@@ -327,7 +333,8 @@ impl DiagnosticCode {
 
             DiagnosticCode::BarewordFilehandle
             | DiagnosticCode::TwoArgOpen
-            | DiagnosticCode::ImplicitReturn => DiagnosticCategory::BestPractices,
+            | DiagnosticCode::ImplicitReturn
+            | DiagnosticCode::IndirectObjectSyntax => DiagnosticCategory::BestPractices,
 
             DiagnosticCode::CriticSeverity1
             | DiagnosticCode::CriticSeverity2
@@ -113,6 +113,7 @@
 //! }
 //! ```
 
+use perl_diagnostics_codes::{DiagnosticCode, DiagnosticSeverity};
 use perl_position_tracking::LineIndex;
 use thiserror::Error;
 
@@ -407,6 +408,20 @@ pub enum ParseError {
     UnclosedDelimiter {
         /// The delimiter character that was left unclosed
         delimiter: char,
+        /// Byte position of the unclosed opening delimiter
+        location: usize,
+    },
+
+    /// Closing delimiter with no matching opener
+    ///
+    /// Typical of code mid-edit, such as an extra `}` after a block.
+    /// Recovery strategy: skip the stray delimiter and continue with the next statement.
+    #[error("Unmatched closing delimiter: {delimiter}")]
+    UnmatchedClose {
+        /// The stray closing delimiter
+        delimiter: char,
+        /// Byte position of the stray delimiter
+        location: usize,
     },
 
     /// Invalid regular expression syntax in Perl parsing workflow
@@ -467,7 +482,7 @@ pub struct ParseOutput {
 
     /// All diagnostics (errors and warnings) collected during parsing.
     /// These are ordered by source position.
-    pub diagnostics: Vec<ParseError>,
+    pub diagnostics: Vec<ParseDiagnostic>,
 
     /// Budget consumption during this parse.
     /// Useful for diagnosing pathological inputs.
@@ -496,6 +511,7 @@ impl ParseOutput {
     pub fn with_errors(ast: Node, diagnostics: Vec<ParseError>) -> Self {
         let mut budget_usage = BudgetTracker::new();
         budget_usage.errors_emitted = diagnostics.len();
+        let diagnostics = diagnostics.into_iter().map(ParseDiagnostic::error).collect();
         Self { ast, diagnostics, budget_usage, terminated_early: false }
     }
 
@@ -509,22 +525,118 @@ impl ParseOutput {
         budget_usage: BudgetTracker,
         terminated_early: bool,
     ) -> Self {
+        let diagnostics = diagnostics.into_iter().map(ParseDiagnostic::error).collect();
         Self { ast, diagnostics, budget_usage, terminated_early }
     }
 
+    /// Add a diagnostic, keeping the list ordered by source position.
+    ///
+    /// Diagnostics without a location go last; ties keep insertion order.
+    pub fn push_diagnostic(&mut self, diagnostic: ParseDiagnostic) {
+        let key = diagnostic.location().unwrap_or(usize::MAX);
+        let idx = self.diagnostics.partition_point(|d| d.location().unwrap_or(usize::MAX) <= key);
+        self.diagnostics.insert(idx, diagnostic);
+    }
+
+    /// Iterate over the error-severity diagnostics.
+    pub fn errors(&self) -> impl Iterator<Item = &ParseError> {
+        self.diagnostics.iter().filter(|d| d.is_error()).map(|d| &d.error)
+    }
+
     /// Check if parse completed without any errors.
+    ///
+    /// Warnings and other non-error diagnostics are ignored.
     pub fn is_ok(&self) -> bool {
-        self.diagnostics.is_empty()
+        !self.has_errors()
     }
 
     /// Check if parse had errors.
+    ///
+    /// Only error-severity diagnostics count; warnings do not.
     pub fn has_errors(&self) -> bool {
-        !self.diagnostics.is_empty()
+        self.diagnostics.iter().any(ParseDiagnostic::is_error)
     }
 
-    /// Get the error count.
+    /// Get the error count, excluding non-error diagnostics.
     pub fn error_count(&self) -> usize {
-        self.diagnostics.len()
+        self.errors().count()
+    }
+
+    /// Get the earliest error by source position.
+    ///
+    /// Errors without a location (e.g. [`ParseError::UnexpectedEof`]) sort after
+    /// all located errors; ties keep their original order.
+    pub fn first_error(&self) -> Option<&ParseError> {
+        self.first_error_index().map(|idx| &self.diagnostics[idx].error)
+    }
+
+    /// Collapse into a plain `Result` for callers that don't need recovery.
+    ///
+    /// Returns `Ok(ast)` when no errors were collected, otherwise `Err` with
+    /// the earliest error (see [`ParseOutput::first_error`]).
+    pub fn into_result(mut self) -> Result<Node, ParseError> {
+        match self.first_error_index() {
+            Some(idx) => Err(self.diagnostics.swap_remove(idx).error),
+            None => Ok(self.ast),
+        }
+    }
+
+    fn first_error_index(&self) -> Option<usize> {
+        self.diagnostics
+            .iter()
+            .enumerate()
+            .filter(|(_, diagnostic)| diagnostic.is_error())
+            .min_by_key(|(idx, diagnostic)| (diagnostic.location().unwrap_or(usize::MAX), *idx))
+            .map(|(idx, _)| idx)
+    }
+}
+
+/// A parse issue together with the severity it is reported at.
+///
+/// Lets non-fatal issues (deprecated syntax, style) travel in
+/// [`ParseOutput::diagnostics`] next to real errors without being counted
+/// by [`ParseOutput::has_errors`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseDiagnostic {
+    /// The underlying issue, with its message and location
+    pub error: ParseError,
+    /// Severity the issue is reported at
+    pub severity: DiagnosticSeverity,
+    /// Stable code, when it differs from the error's own
+    /// [`ParseError::diagnostic_code`]
+    pub code: Option<DiagnosticCode>,
+}
+
+impl ParseDiagnostic {
+    /// Wrap a parse error at error severity.
+    pub fn error(error: ParseError) -> Self {
+        Self { error, severity: DiagnosticSeverity::Error, code: None }
+    }
+
+    /// Wrap an issue that should be reported as a warning.
+    pub fn warning(error: ParseError, code: Option<DiagnosticCode>) -> Self {
+        Self { error, severity: DiagnosticSeverity::Warning, code }
+    }
+
+    /// Whether this diagnostic is error severity.
+    pub fn is_error(&self) -> bool {
+        self.severity == DiagnosticSeverity::Error
+    }
+
+    /// Byte location of the issue, if available.
+    pub fn location(&self) -> Option<usize> {
+        self.error.location()
+    }
+
+    /// Code reported for this diagnostic: the explicit code, or the error's own.
+    pub fn diagnostic_code(&self) -> DiagnosticCode {
+        self.code.unwrap_or_else(|| self.error.diagnostic_code())
+    }
+}
+
+impl std::fmt::Display for ParseDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.severity, self.error)
     }
 }
 
@@ -590,10 +702,32 @@ impl ParseError {
         match self {
             ParseError::UnexpectedToken { location, .. } => Some(*location),
             ParseError::SyntaxError { location, .. } => Some(*location),
+            ParseError::UnclosedDelimiter { location, .. } => Some(*location),
+            ParseError::UnmatchedClose { location, .. } => Some(*location),
             _ => None,
         }
     }
 
+    /// Stable diagnostic code reported for this error
+    ///
+    /// Severity, documentation URL, and tags all come from the returned code.
+    pub fn diagnostic_code(&self) -> DiagnosticCode {
+        match self {
+            ParseError::UnexpectedEof | ParseError::UnclosedDelimiter { .. } => {
+                DiagnosticCode::UnexpectedEof
+            }
+            ParseError::UnexpectedToken { .. }
+            | ParseError::SyntaxError { .. }
+            | ParseError::UnmatchedClose { .. }
+            | ParseError::InvalidNumber { .. }
+            | ParseError::InvalidString
+            | ParseError::InvalidRegex { .. } => DiagnosticCode::SyntaxError,
+            ParseError::LexerError { .. }
+            | ParseError::RecursionLimit
+            | ParseError::NestingTooDeep { .. } => DiagnosticCode::ParseError,
+        }
+    }
+
     /// Generate a fix suggestion based on the error type
     pub fn suggestion(&self) -> Option<String> {
         match self {
@@ -610,9 +744,12 @@ impl ParseError {
                 }
                 None
             }
-            ParseError::UnclosedDelimiter { delimiter } => {
+            ParseError::UnclosedDelimiter { delimiter, .. } => {
                 Some(format!("Add closing '{}' to complete the literal", delimiter))
             }
+            ParseError::UnmatchedClose { delimiter, .. } => {
+                Some(format!("Remove the extra '{}' or add its matching opener", delimiter))
+            }
             _ => None,
         }
     }
@@ -758,6 +895,57 @@ mod tests {
         assert_eq!(output.error_count(), 2);
     }
 
+    #[test]
+    fn test_parse_output_into_result_success() {
+        use perl_ast::{Node, NodeKind, SourceLocation};
+
+        let ast = Node::new(
+            NodeKind::Program { statements: vec![] },
+            SourceLocation { start: 0, end: 0 },
+        );
+        let output = ParseOutput::success(ast.clone());
+
+        assert!(output.first_error().is_none());
+        assert_eq!(output.into_result().ok(), Some(ast));
+    }
+
+    #[test]
+    fn test_parse_output_into_result_returns_earliest_error() {
+        use perl_ast::{Node, NodeKind, SourceLocation};
+
+        let ast = Node::new(
+            NodeKind::Program { statements: vec![] },
+            SourceLocation { start: 0, end: 0 },
+        );
+        let errors = vec![
+            ParseError::UnexpectedEof,
+            ParseError::syntax("late error", 20),
+            ParseError::unexpected("identifier", "number", 7),
+            ParseError::syntax("tied error", 7),
+        ];
+        let output = ParseOutput::with_errors(ast, errors);
+
+        let first = output.first_error().map(ParseError::location);
+        assert_eq!(first, Some(Some(7)));
+
+        let error = output.into_result().err();
+        assert!(matches!(error, Some(ParseError::UnexpectedToken { location: 7, .. })));
+    }
+
+    #[test]
+    fn test_parse_output_first_error_without_locations() {
+        use perl_ast::{Node, NodeKind, SourceLocation};
+
+        let ast = Node::new(
+            NodeKind::Program { statements: vec![] },
+            SourceLocation { start: 0, end: 0 },
+        );
+        let output = ParseOutput::with_errors(ast, vec![ParseError::UnexpectedEof]);
+
+        assert!(matches!(output.first_error(), Some(ParseError::UnexpectedEof)));
+        assert!(matches!(output.into_result(), Err(ParseError::UnexpectedEof)));
+    }
+
     #[test]
     fn test_parse_output_finish_preserves_tracker() {
         use perl_ast::{Node, NodeKind, SourceLocation};
@@ -786,6 +974,36 @@ mod tests {
         assert_eq!(output.error_count(), 1);
     }
 
+    #[test]
+    fn test_parse_output_warnings_do_not_count_as_errors() {
+        use perl_ast::{Node, NodeKind, SourceLocation};
+
+        let ast = Node::new(
+            NodeKind::Program { statements: vec![] },
+            SourceLocation { start: 0, end: 0 },
+        );
+        let mut output = ParseOutput::success(ast);
+        output.push_diagnostic(ParseDiagnostic::warning(
+            ParseError::syntax("indirect object syntax", 12),
+            Some(DiagnosticCode::IndirectObjectSyntax),
+        ));
+
+        assert!(output.is_ok());
+        assert!(!output.has_errors());
+        assert_eq!(output.error_count(), 0);
+        assert!(output.first_error().is_none());
+
+        output.push_diagnostic(ParseDiagnostic::error(ParseError::syntax("real error", 3)));
+
+        assert!(output.has_errors());
+        assert_eq!(output.error_count(), 1);
+        assert_eq!(output.diagnostics.len(), 2);
+        assert_eq!(output.diagnostics[0].location(), Some(3));
+        assert_eq!(output.diagnostics[1].severity, DiagnosticSeverity::Warning);
+        assert_eq!(output.diagnostics[1].diagnostic_code(), DiagnosticCode::IndirectObjectSyntax);
+        assert!(matches!(output.into_result(), Err(ParseError::SyntaxError { location: 3, .. })));
+    }
+
     #[test]
     fn test_begin_recovery_checks_budget_first() {
         let budget = ParseBudget { max_recoveries: 0, ..Default::default() };
@@ -512,7 +512,7 @@ impl IncrementalDocument {
                 self.cache_node(left);
                 self.cache_node(right);
             }
-            NodeKind::Subroutine { body, .. } => {
+            NodeKind::Subroutine { body: Some(body), .. } => {
                 self.cache_node(body);
             }
             NodeKind::ExpressionStatement { expression } => {
@@ -5,10 +5,12 @@ use lsp_types::Diagnostic;
 use lsp_types::TextDocumentContentChangeEvent;
 use ropey::Rope;
 use std::ops::Range;
+use std::sync::Arc;
 
 use crate::ast::{Node, NodeKind, SourceLocation};
 use crate::parser::Parser;
 use perl_lexer::{LexerMode, PerlLexer, Token, TokenType};
+use perl_parser_core::pragma_tracker::{PragmaState, PragmaTracker};
 
 pub mod incremental_advanced_reuse;
 pub mod incremental_checkpoint;
@@ -80,9 +82,14 @@ pub struct IncrementalState {
     pub line_index: LineIndex,
     pub lex_checkpoints: Vec<LexCheckpoint>,
     pub parse_checkpoints: Vec<ParseCheckpoint>,
+    /// Materialized program tree; owns its own copy of every top-level statement
     pub ast: Node,
+    /// Top-level statements of `ast`, shared so unchanged ones survive an edit
+    pub top_level: Vec<Arc<Node>>,
     pub tokens: Vec<Token>,
     pub source: String,
+    /// Top-level statements kept (not reparsed) by the last `apply_edits`
+    reused_nodes: usize,
 }
 
 impl IncrementalState {
@@ -91,19 +98,8 @@ impl IncrementalState {
         let line_index = LineIndex::new(&source);
 
         // Parse the initial document
-        let mut parser = Parser::new(&source);
-        let ast = match parser.parse() {
-            Ok(ast) => ast,
-            Err(e) => Node::new(
-                NodeKind::Error {
-                    message: e.to_string(),
-                    expected: vec![],
-                    found: None,
-                    partial: None,
-                },
-                SourceLocation { start: 0, end: source.len() },
-            ),
-        };
+        let ast = parse_document(&source);
+        let top_level = top_level_statements(&ast);
 
         // Get tokens from lexer
         let mut lexer = PerlLexer::new(&source);
@@ -124,7 +120,26 @@ impl IncrementalState {
         let lex_checkpoints = Self::create_lex_checkpoints(&tokens, &line_index);
         let parse_checkpoints = Self::create_parse_checkpoints(&ast);
 
-        Self { rope, line_index, lex_checkpoints, parse_checkpoints, ast, tokens, source }
+        Self {
+            rope,
+            line_index,
+            lex_checkpoints,
+            parse_checkpoints,
+            ast,
+            top_level,
+            tokens,
+            source,
+            reused_nodes: 0,
+        }
+    }
+
+    /// Number of top-level statements the last `apply_edits` reused instead of reparsing
+    ///
+    /// An edit confined to the body of one top-level subroutine reparses only
+    /// that subroutine; every other top-level statement keeps its `Arc` in
+    /// `top_level`. Full reparses report zero.
+    pub fn reused_node_count(&self) -> usize {
+        self.reused_nodes
     }
 
     /// Create lexer checkpoints at safe boundaries
@@ -161,7 +176,9 @@ impl IncrementalState {
                 TokenType::Identifier(_) | TokenType::Number(_) | TokenType::StringLiteral => {
                     LexerMode::ExpectOperator
                 }
-                TokenType::Operator(_) => LexerMode::ExpectTerm,
+                TokenType::Operator(_) | TokenType::Bind | TokenType::NotBind => {
+                    LexerMode::ExpectTerm
+                }
                 _ => mode,
             };
         }
@@ -235,7 +252,7 @@ impl IncrementalState {
                     Self::walk_ast_for_checkpoints(stmt, checkpoints, &mut local_scope, child_id);
                 }
             }
-            NodeKind::Subroutine { body, .. } => {
+            NodeKind::Subroutine { body: Some(body), .. } => {
                 // Subroutine body is a single node (Block), not Vec<Node>
                 let mut local_scope = scope.clone();
                 let child_id = node_id.wrapping_mul(101);
@@ -420,6 +437,14 @@ pub fn apply_edits(state: &mut IncrementalState, edits: &[Edit]) -> Result<Repar
         let reparsed_range = apply_single_edit(state, edit)?;
         let reparsed_bytes = reparsed_range.end - reparsed_range.start;
 
+        // Reparse only the enclosing subroutine when the edit stays inside its body
+        if !reparse_enclosing_sub(state, edit) {
+            state.ast = parse_document(&state.source);
+            state.top_level = top_level_statements(&state.ast);
+            state.reused_nodes = 0;
+        }
+        state.parse_checkpoints = IncrementalState::create_parse_checkpoints(&state.ast);
+
         // If reparsed too much (>20% of doc), might need full parse in future
         // But for now, trust the incremental result
 
@@ -501,10 +526,97 @@ fn apply_single_edit(state: &mut IncrementalState, edit: &Edit) -> Result<Range<
     Ok(checkpoint.byte..last_token_end)
 }
 
-/// Full document reparse fallback
-fn full_reparse(state: &mut IncrementalState) -> Result<ReparseResult> {
-    let mut parser = Parser::new(&state.source);
-    state.ast = match parser.parse() {
+/// Reparse the top-level subroutine whose body contains `edit`, reusing every
+/// other top-level statement
+///
+/// `state.source` already holds the edited text while `state.top_level` still
+/// has the old positions. Returns `false`, leaving the AST untouched, when the
+/// edit is not confined to one subroutine body or the reparsed text is not
+/// a single clean subroutine with the same header.
+fn reparse_enclosing_sub(state: &mut IncrementalState, edit: &Edit) -> bool {
+    let Some(index) = state.top_level.iter().position(|stmt| match &stmt.kind {
+        NodeKind::Subroutine { body: Some(body), .. } => {
+            body.location.start < edit.start_byte && edit.old_end_byte < body.location.end
+        }
+        _ => false,
+    }) else {
+        return false;
+    };
+
+    let old_sub = &state.top_level[index];
+    let delta = edit.new_text.len() as isize - (edit.old_end_byte - edit.start_byte) as isize;
+    let start = old_sub.location.start;
+    let end = (old_sub.location.end as isize + delta) as usize;
+    let Some(text) = state.source.get(start..end) else {
+        return false;
+    };
+
+    // Only top-level `use`/`no` statements before the sub are in scope for it
+    let mut pragmas = PragmaState::default();
+    for stmt in &state.top_level[..index] {
+        PragmaTracker::apply(stmt, &mut pragmas);
+    }
+
+    // The subroutine must still parse on its own, without errors, to the same extent
+    let mut parser = Parser::new(text).with_pragma_state(pragmas);
+    let Ok(Node { kind: NodeKind::Program { mut statements }, .. }) = parser.parse() else {
+        return false;
+    };
+    if statements.len() != 1 || !parser.errors().is_empty() {
+        return false;
+    }
+    let mut new_sub = statements.remove(0);
+    if new_sub.location.start != 0 || new_sub.location.end != text.len() {
+        return false;
+    }
+    shift_locations(&mut new_sub, start as isize);
+
+    let same_header = match (&old_sub.kind, &new_sub.kind) {
+        (
+            NodeKind::Subroutine { name, prototype, signature, attributes, .. },
+            NodeKind::Subroutine {
+                name: new_name,
+                prototype: new_prototype,
+                signature: new_signature,
+                attributes: new_attributes,
+                ..
+            },
+        ) => {
+            name == new_name
+                && prototype == new_prototype
+                && signature == new_signature
+                && attributes == new_attributes
+        }
+        _ => false,
+    };
+    if !same_header {
+        return false;
+    }
+
+    // Patch the materialized tree in place rather than cloning every statement
+    let NodeKind::Program { statements } = &mut state.ast.kind else {
+        return false;
+    };
+    statements[index] = new_sub.clone();
+    state.top_level[index] = Arc::new(new_sub);
+    if delta != 0 {
+        // Sole owners shift in place, so the statements keep their identity
+        for stmt in &mut state.top_level[index + 1..] {
+            shift_locations(Arc::make_mut(stmt), delta);
+        }
+        for stmt in &mut statements[index + 1..] {
+            shift_locations(stmt, delta);
+        }
+        state.ast.location.end = (state.ast.location.end as isize + delta) as usize;
+    }
+    state.reused_nodes = state.top_level.len() - 1;
+    true
+}
+
+/// Parse a whole document, turning a fatal error into an error node
+fn parse_document(source: &str) -> Node {
+    let mut parser = Parser::new(source);
+    match parser.parse() {
         Ok(ast) => ast,
         Err(e) => Node::new(
             NodeKind::Error {
@@ -513,10 +625,50 @@ fn full_reparse(state: &mut IncrementalState) -> Result<ReparseResult> {
                 found: None,
                 partial: None,
             },
-            SourceLocation { start: 0, end: state.source.len() },
+            SourceLocation { start: 0, end: source.len() },
         ),
+    }
+}
+
+/// Shareable copies of a program's top-level statements
+fn top_level_statements(ast: &Node) -> Vec<Arc<Node>> {
+    match &ast.kind {
+        NodeKind::Program { statements } => statements.iter().cloned().map(Arc::new).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Move every location in `node`'s subtree by `delta` bytes
+fn shift_locations(node: &mut Node, delta: isize) {
+    let shift = |location: &mut SourceLocation| {
+        location.start = (location.start as isize + delta) as usize;
+        location.end = (location.end as isize + delta) as usize;
     };
 
+    shift(&mut node.location);
+    match &mut node.kind {
+        NodeKind::Subroutine { name_span, attributes, .. } => {
+            if let Some(span) = name_span {
+                shift(span);
+            }
+            for attribute in attributes {
+                shift(&mut attribute.location);
+            }
+        }
+        NodeKind::Heredoc { body_span: Some(span), .. }
+        | NodeKind::PhaseBlock { phase_span: Some(span), .. }
+        | NodeKind::Package { name_span: span, .. } => shift(span),
+        _ => {}
+    }
+    node.for_each_child_mut(|child| shift_locations(child, delta));
+}
+
+/// Full document reparse fallback
+fn full_reparse(state: &mut IncrementalState) -> Result<ReparseResult> {
+    state.ast = parse_document(&state.source);
+    state.top_level = top_level_statements(&state.ast);
+    state.reused_nodes = 0;
+
     // Re-lex to get tokens
     let mut lexer = PerlLexer::new(&state.source);
     let mut tokens = Vec::new();
@@ -0,0 +1,195 @@
+//! Compile-time keyword tables
+//!
+//! Keywords and quote-like operators are stored in perfect-hash sets built by
+//! `phf`, so classifying an identifier costs one hash and at most one string
+//! comparison regardless of how many keywords exist. Adding a keyword is a
+//! one-line change to the table.
+
+use phf::{Set, phf_set};
+
+/// Length of the longest entry in [`KEYWORDS`] (`UNITCHECK`)
+const MAX_KEYWORD_LEN: usize = 9;
+
+/// Words the lexer emits as [`TokenType::Keyword`](crate::TokenType::Keyword)
+pub(crate) static KEYWORDS: Set<&'static str> = phf_set! {
+    // Quote-like operators
+    "q", "m", "s", "y", "qq", "qw", "qr", "qx", "tr",
+    // Declarations
+    "my", "our", "local", "state", "sub", "method", "class", "format", "package",
+    // Control flow
+    "if", "elsif", "else", "unless", "while", "until", "for", "foreach", "do",
+    "given", "when", "default", "break", "continue", "next", "last", "redo",
+    "goto", "return", "try", "catch", "finally",
+    // Word operators
+    "or", "and", "not", "xor", "cmp",
+    // Named operators and functions
+    "die", "say", "eval", "warn", "print", "undef", "use", "require",
+    // Phase blocks
+    "BEGIN", "CHECK", "INIT", "UNITCHECK", "END",
+};
+
+/// Quote-like operators that are followed by a delimited body
+pub(crate) static QUOTE_OPERATORS: Set<&'static str> = phf_set! {
+    "q", "qq", "qw", "qr", "qx", "m", "s", "tr", "y",
+};
+
+/// Check whether `word` is a Perl keyword
+#[inline(always)]
+pub(crate) fn is_keyword(word: &str) -> bool {
+    // Reject long identifiers before hashing them
+    word.len() <= MAX_KEYWORD_LEN && KEYWORDS.contains(word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hint::black_box;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_every_keyword_is_recognized() {
+        // The list the length-bucketed matcher recognized before the PHF table
+        let expected = [
+            "q",
+            "m",
+            "s",
+            "y",
+            "if",
+            "do",
+            "my",
+            "or",
+            "qq",
+            "qw",
+            "qr",
+            "qx",
+            "tr",
+            "sub",
+            "our",
+            "use",
+            "and",
+            "not",
+            "xor",
+            "die",
+            "say",
+            "for",
+            "try",
+            "END",
+            "cmp",
+            "else",
+            "when",
+            "next",
+            "last",
+            "redo",
+            "goto",
+            "eval",
+            "warn",
+            "INIT",
+            "elsif",
+            "while",
+            "until",
+            "local",
+            "state",
+            "given",
+            "break",
+            "print",
+            "catch",
+            "BEGIN",
+            "CHECK",
+            "class",
+            "undef",
+            "unless",
+            "return",
+            "method",
+            "format",
+            "require",
+            "package",
+            "default",
+            "foreach",
+            "finally",
+            "continue",
+            "UNITCHECK",
+        ];
+        assert_eq!(KEYWORDS.len(), expected.len());
+        for keyword in expected {
+            assert!(is_keyword(keyword), "`{keyword}` should be a keyword");
+        }
+        for keyword in KEYWORDS.iter() {
+            assert!(is_keyword(keyword), "`{keyword}` should be a keyword");
+            assert!(keyword.len() <= MAX_KEYWORD_LEN, "`{keyword}` exceeds MAX_KEYWORD_LEN");
+        }
+        for op in QUOTE_OPERATORS.iter() {
+            assert!(is_keyword(op), "quote operator `{op}` should be a keyword");
+        }
+    }
+
+    #[test]
+    fn test_non_keywords_are_rejected() {
+        let non_keywords = [
+            "",
+            "x",
+            "qz",
+            "If",
+            "MY",
+            "begin",
+            "subs",
+            "printf",
+            "defined",
+            "foreach_",
+            "elsif2",
+            "UNITCHECKS",
+            "continued",
+            "package_name",
+            "some_very_long_identifier_name",
+        ];
+        for word in non_keywords {
+            assert!(!is_keyword(word), "`{word}` should not be a keyword");
+        }
+        for word in ["qq", "tr", "y"] {
+            assert!(QUOTE_OPERATORS.contains(word));
+        }
+        for word in ["my", "sub", "x", "qz"] {
+            assert!(!QUOTE_OPERATORS.contains(word));
+        }
+    }
+
+    /// Lookups cost the same for the first and last keyword in the table
+    ///
+    /// The old length-bucketed scan got slower the further down a bucket a
+    /// word sat; with a perfect hash every lookup is one probe. The bound is
+    /// deliberately loose so the test only catches a return to linear scans.
+    #[test]
+    fn test_lookup_is_constant_time() {
+        const ROUNDS: usize = 20_000;
+
+        fn time_lookups(words: &[&str]) -> Duration {
+            let start = Instant::now();
+            for _ in 0..ROUNDS {
+                for word in words {
+                    black_box(is_keyword(black_box(word)));
+                }
+            }
+            start.elapsed()
+        }
+
+        let mut keywords: Vec<&str> = KEYWORDS.iter().copied().collect();
+        keywords.sort_unstable();
+        let early = vec![keywords[0]; keywords.len()];
+        let late = vec![keywords[keywords.len() - 1]; keywords.len()];
+        let misses = vec!["zzzzzzzzz"; keywords.len()];
+
+        // Warm up, then take the best of a few runs to filter scheduler noise
+        let best = |words: &[&str]| (0..3).map(|_| time_lookups(words)).min().unwrap_or_default();
+        let _ = best(&keywords);
+        let baseline = best(&early).max(Duration::from_micros(1));
+
+        for (label, words) in
+            [("all keywords", &keywords), ("late keyword", &late), ("miss", &misses)]
+        {
+            let elapsed = best(words);
+            assert!(
+                elapsed < baseline * 20,
+                "{label} lookups took {elapsed:?}, baseline {baseline:?}"
+            );
+        }
+    }
+}
@@ -74,6 +74,8 @@
 //!     parse_interpolation: true,  // Parse string interpolation
 //!     track_positions: true,      // Track line/column positions
 //!     max_lookahead: 1024,        // Maximum lookahead for disambiguation
+//!     max_tokens: 1_000_000,      // Token budget before emitting an error
+//!     max_string_length: 65_536,  // Maximum bytes in a single string literal
 //! };
 //!
 //! let mut lexer = PerlLexer::with_config("my $x = 1;", config);
@@ -100,6 +102,15 @@
 //! When limits are exceeded, the lexer emits an `UnknownRest` token preserving
 //! all previously parsed symbols, allowing continued analysis.
 //!
+//! Two further limits are configurable via [`LexerConfig`]:
+//!
+//! - **max_tokens**: total tokens emitted before the lexer stops (default 10,000,000)
+//! - **max_string_length**: maximum bytes in a single string or quote-like
+//!   literal (default 1MB); raise it explicitly for files that embed larger data
+//!
+//! Exceeding either emits a `TokenType::Error` token covering the rest of the
+//! input, after which the lexer only returns `EOF`.
+//!
 //! # Integration with perl-parser
 //!
 //! The lexer is designed to work seamlessly with `perl_parser::Parser`:
@@ -140,11 +151,13 @@
     clippy::uninlined_format_args
 )]
 
-use std::collections::HashSet;
 use std::sync::{Arc, OnceLock};
 
+use perl_regex::RegexValidator;
+
 pub mod checkpoint;
 pub mod error;
+mod keywords;
 pub mod mode;
 mod quote_handler;
 pub mod token;
@@ -154,8 +167,9 @@ pub use checkpoint::{CheckpointCache, Checkpointable, LexerCheckpoint};
 pub use error::{LexerError, Result};
 pub use mode::LexerMode;
 pub use perl_position_tracking::Position;
-pub use token::{StringPart, Token, TokenType};
+pub use token::{StringPart, Token, TokenType, TransliterationModifiers};
 
+use keywords::is_keyword;
 use unicode::{is_perl_identifier_continue, is_perl_identifier_start};
 
 /// Specification for a pending heredoc
@@ -176,6 +190,8 @@ const MAX_HEREDOC_BYTES: usize = 256 * 1024; // 256KB max for heredoc bodies
 const MAX_DELIM_NEST: usize = 128; // Max nesting depth for delimiters
 const MAX_HEREDOC_DEPTH: usize = 100; // Max nesting depth for heredocs
 const HEREDOC_TIMEOUT_MS: u64 = 5000; // 5 seconds timeout for heredoc parsing
+const DEFAULT_MAX_TOKENS: usize = 10_000_000; // Default token budget per lexer
+const DEFAULT_MAX_STRING_LENGTH: usize = 1024 * 1024; // 1MB max for string literals
 
 /// Configuration for the lexer
 #[derive(Debug, Clone)]
@@ -186,11 +202,21 @@ pub struct LexerConfig {
     pub track_positions: bool,
     /// Maximum lookahead for disambiguation
     pub max_lookahead: usize,
+    /// Maximum number of tokens to emit before reporting a budget error
+    pub max_tokens: usize,
+    /// Maximum byte length of a single string or quote-like literal (default 1MB)
+    pub max_string_length: usize,
 }
 
 impl Default for LexerConfig {
     fn default() -> Self {
-        Self { parse_interpolation: true, track_positions: true, max_lookahead: 1024 }
+        Self {
+            parse_interpolation: true,
+            track_positions: true,
+            max_lookahead: 1024,
+            max_tokens: DEFAULT_MAX_TOKENS,
+            max_string_length: DEFAULT_MAX_STRING_LENGTH,
+        }
     }
 }
 
@@ -217,14 +243,18 @@ pub struct PerlLexer<'a> {
     pending_heredocs: Vec<HeredocSpec>,
     /// Track the byte offset of the current line's start
     line_start_offset: usize,
-    /// If true, emit `HeredocBody` tokens; otherwise just consume them.
-    emit_heredoc_body_tokens: bool,
+    /// If true, emit `HeredocBody` and `Pod` tokens; otherwise just consume them.
+    emit_body_tokens: bool,
     /// Current quote operator being parsed
     current_quote_op: Option<quote_handler::QuoteOperatorInfo>,
     /// Track if EOF has been emitted to prevent infinite loops
     eof_emitted: bool,
     /// Start time for timeout protection
     start_time: std::time::Instant,
+    /// Number of tokens emitted so far (checked against `config.max_tokens`)
+    tokens_emitted: usize,
+    /// Offset just past the `=` of a `format NAME =` header; the body starts here
+    format_body_start: Option<usize>,
 }
 
 impl<'a> PerlLexer<'a> {
@@ -248,17 +278,19 @@ impl<'a> PerlLexer<'a> {
             after_newline: true, // Start of file counts as after newline
             pending_heredocs: Vec::new(),
             line_start_offset: 0,
-            emit_heredoc_body_tokens: false,
+            emit_body_tokens: false,
             current_quote_op: None,
             eof_emitted: false,
             start_time: std::time::Instant::now(),
+            tokens_emitted: 0,
+            format_body_start: None,
         }
     }
 
-    /// Create a new lexer that emits `HeredocBody` tokens (for LSP folding)
+    /// Create a new lexer that emits `HeredocBody` and `Pod` tokens (for LSP folding)
     pub fn with_body_tokens(input: &'a str) -> Self {
         let mut lexer = Self::new(input);
-        lexer.emit_heredoc_body_tokens = true;
+        lexer.emit_body_tokens = true;
         lexer
     }
 
@@ -308,6 +340,40 @@ impl<'a> PerlLexer<'a> {
         self.line_start_offset = self.position;
     }
 
+    /// End offset of a POD block starting at the current position, if one starts here
+    ///
+    /// A POD block starts with `=` and a letter at the beginning of a line
+    /// (`=pod`, `=head1`, `=begin`, ...) and runs through the end of the next
+    /// `=cut` line, or to the end of the input when there is none. Where an
+    /// operator is expected, a line-leading `=word` continues the expression.
+    fn pod_block_end(&self) -> Option<usize> {
+        if self.position != self.line_start_offset
+            || self.mode == LexerMode::ExpectOperator
+            || self.peek_byte(0) != Some(b'=')
+            || !self.peek_byte(1).is_some_and(|b| b.is_ascii_alphabetic())
+        {
+            return None;
+        }
+
+        let mut line_start = self.position;
+        loop {
+            let (line_end, _) = Self::find_line_end(self.input_bytes, line_start);
+            let line = &self.input_bytes[line_start..line_end];
+            let next_line = match self.input_bytes.get(line_end..line_end + 2) {
+                Some(b"\r\n") => line_end + 2,
+                _ if line_end < self.input_bytes.len() => line_end + 1,
+                _ => line_end,
+            };
+
+            let is_cut = line.starts_with(b"=cut")
+                && !line.get(4).is_some_and(|b| b.is_ascii_alphanumeric() || *b == b'_');
+            if is_cut || next_line >= self.input_bytes.len() {
+                return Some(next_line);
+            }
+            line_start = next_line;
+        }
+    }
+
     /// Find the end of the current line, returning both raw end and visible end (without trailing CR)
     #[inline]
     fn find_line_end(bytes: &[u8], start: usize) -> (usize, usize) {
@@ -325,12 +391,58 @@ impl<'a> PerlLexer<'a> {
     }
 
     /// Get the next token from the input
+    ///
+    /// Once `config.max_tokens` tokens have been emitted, the rest of the input
+    /// is returned as a single budget-exceeded `Error` token followed by `EOF`.
     pub fn next_token(&mut self) -> Option<Token> {
+        if self.tokens_emitted >= self.config.max_tokens && self.position < self.input.len() {
+            return Some(self.budget_exceeded_token(self.position, "Token budget exceeded"));
+        }
+
+        let token = self.lex_token();
+        if token.is_some() {
+            self.tokens_emitted += 1;
+        }
+        token
+    }
+
+    /// Consume the rest of the input as a budget-exceeded error token
+    fn budget_exceeded_token(&mut self, start: usize, message: &str) -> Token {
+        self.position = self.input.len();
+        self.pending_heredocs.clear();
+        self.current_quote_op = None;
+        self.mode = LexerMode::ExpectOperator;
+        Token {
+            token_type: TokenType::Error(Arc::from(message)),
+            text: Arc::from(&self.input[start..]),
+            start,
+            end: self.position,
+        }
+    }
+
+    /// Whether a literal starting at `start` has outgrown `config.max_string_length`
+    #[inline]
+    fn string_budget_exceeded(&self, start: usize) -> bool {
+        self.position - start > self.config.max_string_length
+    }
+
+    /// Lex the next token without budget accounting
+    fn lex_token(&mut self) -> Option<Token> {
         // Normalize file start (BOM) once
         if self.position == 0 {
             self.normalize_file_start();
         }
 
+        // A `format NAME =` header was just lexed: its body follows the `=`
+        if let Some(body_start) = self.format_body_start
+            && self.position >= body_start
+        {
+            self.format_body_start = None;
+            if self.position == body_start {
+                self.mode = LexerMode::InFormatBody;
+            }
+        }
+
         // Loop to avoid recursion when processing heredocs
         loop {
             // Handle format body parsing if we're in that mode
@@ -449,7 +561,7 @@ impl<'a> PerlLexer<'a> {
                             }
 
                             // Only emit HeredocBody if requested (for folding)
-                            if self.emit_heredoc_body_tokens {
+                            if self.emit_body_tokens {
                                 return Some(Token {
                                     token_type: TokenType::HeredocBody(empty_arc()),
                                     text: empty_arc(),
@@ -518,6 +630,22 @@ impl<'a> PerlLexer<'a> {
 
             let start = self.position;
 
+            // POD runs from a `=word` line to the next `=cut` line and is skipped like a comment
+            if let Some(end) = self.pod_block_end() {
+                self.position = end;
+                self.after_newline = true;
+                self.line_start_offset = end;
+                if self.emit_body_tokens {
+                    return Some(Token {
+                        token_type: TokenType::Pod,
+                        text: Arc::from(&self.input[start..end]),
+                        start,
+                        end,
+                    });
+                }
+                continue;
+            }
+
             // Check for special tokens first
             if let Some(token) = self.try_heredoc() {
                 return Some(token);
@@ -637,6 +765,7 @@ impl<'a> PerlLexer<'a> {
         let saved_prototype = self.in_prototype;
         let saved_depth = self.prototype_depth;
         let saved_after_newline = self.after_newline;
+        let saved_tokens_emitted = self.tokens_emitted;
 
         let token = self.next_token();
 
@@ -645,6 +774,7 @@ impl<'a> PerlLexer<'a> {
         self.in_prototype = saved_prototype;
         self.prototype_depth = saved_depth;
         self.after_newline = saved_after_newline;
+        self.tokens_emitted = saved_tokens_emitted;
 
         token
     }
@@ -672,6 +802,7 @@ impl<'a> PerlLexer<'a> {
         self.after_newline = true;
         self.pending_heredocs.clear();
         self.line_start_offset = 0;
+        self.format_body_start = None;
     }
 
     /// Switch lexer to format body parsing mode
@@ -1173,6 +1304,9 @@ impl<'a> PerlLexer<'a> {
         match sigil {
             '$' | '@' | '%' | '*' => {
                 // In ExpectOperator mode, treat % and * as operators rather than sigils
+                // (`$n % 2`, `$x * $y`); `try_operator` then lexes them as modulo and
+                // multiplication. `&` never reaches this branch, so `$x & $y` is always
+                // bitwise-and, while `%$ref` and `%hash` in term position stay sigils.
                 if self.mode == LexerMode::ExpectOperator && matches!(sigil, '*' | '%') {
                     return None;
                 }
@@ -1445,6 +1579,35 @@ impl<'a> PerlLexer<'a> {
         None
     }
 
+    /// Offset just past the `=` when the input after a `format` keyword is
+    /// an optional name followed by `=` at the end of the line.
+    ///
+    /// `format(...)`, `format => ...` and `format = EXPR` do not match.
+    fn format_header_end(&self) -> Option<usize> {
+        let bytes = self.input_bytes;
+        let skip_blanks = |mut i: usize| {
+            while matches!(bytes.get(i), Some(b' ' | b'\t')) {
+                i += 1;
+            }
+            i
+        };
+
+        let mut i = skip_blanks(self.position);
+        while bytes.get(i).is_some_and(|&b| b.is_ascii_alphanumeric() || b == b'_' || b == b':') {
+            i += 1;
+        }
+        i = skip_blanks(i);
+        if bytes.get(i) != Some(&b'=') {
+            return None;
+        }
+
+        let body_start = i + 1;
+        match bytes.get(skip_blanks(body_start)) {
+            None | Some(b'\n' | b'\r') => Some(body_start),
+            _ => None,
+        }
+    }
+
     /// Is `c` a valid quote-like delimiter? (non-alnum, including paired)
     fn is_quote_delim(c: char) -> bool {
         // Quote delimiters are punctuation, but not whitespace or control characters
@@ -1688,14 +1851,20 @@ impl<'a> PerlLexer<'a> {
                     }
                     // Format declarations need special handling
                     "format" => {
-                        // We'll need to check for the = after the format name
-                        // For now, just mark that we saw format
+                        self.format_body_start = self.format_header_end();
                     }
                     _ => {}
                 }
                 TokenType::Keyword(Arc::from(text))
             } else {
-                self.mode = LexerMode::ExpectOperator;
+                // Hash builtins take a term, so `keys %$ref` is a deref rather than modulo
+                self.mode = if matches!(text, "keys" | "values" | "each" | "delete" | "exists")
+                    && self.peek_nonspace() == Some('%')
+                {
+                    LexerMode::ExpectTerm
+                } else {
+                    LexerMode::ExpectOperator
+                };
                 TokenType::Identifier(Arc::from(text))
             };
 
@@ -1982,12 +2151,15 @@ impl<'a> PerlLexer<'a> {
             self.mode = LexerMode::ExpectTerm;
         }
 
-        Some(Token {
-            token_type: TokenType::Operator(Arc::from(text)),
-            text: Arc::from(text),
-            start,
-            end: self.position,
-        })
+        // A pattern always follows a binding operator, and ExpectTerm (set above)
+        // makes a following `/` start a regex rather than a division
+        let token_type = match text {
+            "=~" => TokenType::Bind,
+            "!~" => TokenType::NotBind,
+            _ => TokenType::Operator(Arc::from(text)),
+        };
+
+        Some(Token { token_type, text: Arc::from(text), start, end: self.position })
     }
 
     fn try_delimiter(&mut self) -> Option<Token> {
@@ -2135,6 +2307,10 @@ impl<'a> PerlLexer<'a> {
         let mut last_pos = self.position;
 
         while let Some(ch) = self.current_char() {
+            if self.string_budget_exceeded(start) {
+                return Some(self.budget_exceeded_token(start, "String length budget exceeded"));
+            }
+
             match ch {
                 '"' => {
                     self.advance();
@@ -2201,8 +2377,18 @@ impl<'a> PerlLexer<'a> {
                     }
 
                     if self.position > var_start {
-                        let var_name = &self.input[var_start - 1..self.position];
-                        parts.push(StringPart::Variable(Arc::from(var_name)));
+                        // `$obj->{key}` and `$arr->[0]` chains interpolate; `->method` does not
+                        let chain_len = quote_handler::subscript_chain_len(
+                            &self.input[self.position..],
+                            Some('"'),
+                        );
+                        self.position += chain_len;
+                        let var_text = &self.input[var_start - 1..self.position];
+                        parts.push(if chain_len > 0 {
+                            StringPart::Expression(Arc::from(var_text))
+                        } else {
+                            StringPart::Variable(Arc::from(var_text))
+                        });
                     }
                 }
                 _ => {
@@ -2240,6 +2426,10 @@ impl<'a> PerlLexer<'a> {
         let mut last_pos = self.position;
 
         while let Some(ch) = self.current_char() {
+            if self.string_budget_exceeded(start) {
+                return Some(self.budget_exceeded_token(start, "String length budget exceeded"));
+            }
+
             match ch {
                 '\'' => {
                     self.advance();
@@ -2287,6 +2477,10 @@ impl<'a> PerlLexer<'a> {
         let mut last_pos = self.position;
 
         while let Some(ch) = self.current_char() {
+            if self.string_budget_exceeded(start) {
+                return Some(self.budget_exceeded_token(start, "String length budget exceeded"));
+            }
+
             match ch {
                 '`' => {
                     self.advance();
@@ -2450,6 +2644,7 @@ impl<'a> PerlLexer<'a> {
         }
 
         // Parse modifiers - include all alphanumeric for proper validation in parser (MUT_005 fix)
+        let modifiers_start = self.position;
         while let Some(ch) = self.current_char() {
             if ch.is_ascii_alphanumeric() {
                 self.advance();
@@ -2457,12 +2652,13 @@ impl<'a> PerlLexer<'a> {
                 break;
             }
         }
+        let extended = self.input[modifiers_start..self.position].contains('x');
 
         let text = &self.input[start..self.position];
         self.mode = LexerMode::ExpectOperator;
 
         Some(Token {
-            token_type: TokenType::Substitution,
+            token_type: TokenType::Substitution { extended },
             text: Arc::from(text),
             start,
             end: self.position,
@@ -2557,6 +2753,7 @@ impl<'a> PerlLexer<'a> {
         }
 
         // Parse modifiers - include all alphanumeric for proper validation in parser (MUT_005 fix)
+        let modifiers_start = self.position;
         while let Some(ch) = self.current_char() {
             if ch.is_ascii_alphanumeric() {
                 self.advance();
@@ -2564,12 +2761,14 @@ impl<'a> PerlLexer<'a> {
                 break;
             }
         }
+        let modifiers =
+            TransliterationModifiers::parse(&self.input[modifiers_start..self.position]);
 
         let text = &self.input[start..self.position];
         self.mode = LexerMode::ExpectOperator;
 
         Some(Token {
-            token_type: TokenType::Transliteration,
+            token_type: TokenType::Transliteration { modifiers },
             text: Arc::from(text),
             start,
             end: self.position,
@@ -2584,6 +2783,11 @@ impl<'a> PerlLexer<'a> {
         let mut depth = i32::from(paired.is_some());
 
         while let Some(ch) = self.current_char() {
+            // Stop accumulating once the literal is over budget; the caller reports it
+            if body.len() > self.config.max_string_length {
+                break;
+            }
+
             if ch == '\\' {
                 body.push(ch);
                 self.advance();
@@ -2629,6 +2833,8 @@ impl<'a> PerlLexer<'a> {
         let info = self.current_quote_op.as_ref()?;
         let start = info.start_pos;
         let operator = info.operator.clone();
+        // Operators whose token records its modifiers set this instead of the generic type
+        let mut token_type = None;
 
         // Parse based on operator type
         match operator.as_str() {
@@ -2654,7 +2860,8 @@ impl<'a> PerlLexer<'a> {
                 let _replacement = self.read_delimited_body(delimiter);
 
                 // Parse modifiers
-                self.parse_regex_modifiers(&quote_handler::S_SPEC);
+                let modifiers = self.parse_regex_modifiers(&quote_handler::S_SPEC);
+                token_type = Some(TokenType::Substitution { extended: modifiers.contains('x') });
             }
             "tr" | "y" => {
                 // Transliteration: two bodies
@@ -2678,15 +2885,45 @@ impl<'a> PerlLexer<'a> {
                 let _to = self.read_delimited_body(delimiter);
 
                 // Parse modifiers
-                self.parse_regex_modifiers(&quote_handler::TR_SPEC);
+                let modifiers = self.parse_regex_modifiers(&quote_handler::TR_SPEC);
+                token_type = Some(TokenType::Transliteration {
+                    modifiers: TransliterationModifiers::parse(modifiers),
+                });
             }
             "qr" => {
-                let _pattern = self.read_delimited_body(delimiter);
-                self.parse_regex_modifiers(&quote_handler::QR_SPEC);
+                let pattern = self.read_delimited_body(delimiter);
+                if self.string_budget_exceeded(start) {
+                    return Some(
+                        self.budget_exceeded_token(start, "String length budget exceeded"),
+                    );
+                }
+                let modifiers = self.parse_regex_modifiers(&quote_handler::QR_SPEC);
+
+                // Single-quote delimiters suppress interpolation, but code blocks still run
+                let parts = if delimiter == '\'' {
+                    Vec::new()
+                } else {
+                    quote_handler::split_regex_interpolation(&pattern)
+                };
+                let has_embedded_code = RegexValidator::new()
+                    .with_extended(modifiers.contains('x'))
+                    .detects_code_execution(&pattern);
+
+                let text = &self.input[start..self.position];
+                self.mode = LexerMode::ExpectOperator;
+                self.current_quote_op = None;
+
+                return Some(Token {
+                    token_type: TokenType::QuoteRegex { parts, has_embedded_code },
+                    text: Arc::from(text),
+                    start,
+                    end: self.position,
+                });
             }
             "m" => {
                 let _pattern = self.read_delimited_body(delimiter);
-                self.parse_regex_modifiers(&quote_handler::M_SPEC);
+                let modifiers = self.parse_regex_modifiers(&quote_handler::M_SPEC);
+                token_type = Some(TokenType::RegexMatch { extended: modifiers.contains('x') });
             }
             _ => {
                 // q, qq, qw, qx - no modifiers
@@ -2694,8 +2931,13 @@ impl<'a> PerlLexer<'a> {
             }
         }
 
+        if self.string_budget_exceeded(start) {
+            return Some(self.budget_exceeded_token(start, "String length budget exceeded"));
+        }
+
         let text = &self.input[start..self.position];
-        let token_type = quote_handler::get_quote_token_type(&operator);
+        let token_type =
+            token_type.unwrap_or_else(|| quote_handler::get_quote_token_type(&operator));
 
         self.mode = LexerMode::ExpectOperator;
         self.current_quote_op = None;
@@ -2708,8 +2950,9 @@ impl<'a> PerlLexer<'a> {
     /// This function includes ALL characters that could be intended as modifiers,
     /// including invalid ones. This allows the parser to properly reject invalid
     /// modifiers with a clear error message, rather than leaving them as separate
-    /// tokens that could be confusingly parsed.
-    fn parse_regex_modifiers(&mut self, _spec: &quote_handler::ModSpec) {
+    /// tokens that could be confusingly parsed. Returns the consumed modifier run.
+    fn parse_regex_modifiers(&mut self, _spec: &quote_handler::ModSpec) -> &'a str {
+        let modifiers_start = self.position;
         // Consume all alphanumeric characters that could be intended as modifiers
         // The parser will validate and reject invalid ones
         while let Some(ch) = self.current_char() {
@@ -2721,6 +2964,7 @@ impl<'a> PerlLexer<'a> {
         }
         // Note: We no longer validate here - the parser will validate and provide
         // clear error messages for invalid modifiers (MUT_005 fix)
+        &self.input[modifiers_start..self.position]
     }
 
     /// Parse a regex literal starting with `/`
@@ -2748,6 +2992,7 @@ impl<'a> PerlLexer<'a> {
                 '/' => {
                     self.advance();
                     // Parse flags - include all alphanumeric for proper validation in parser (MUT_005 fix)
+                    let flags_start = self.position;
                     while let Some(ch) = self.current_char() {
                         if ch.is_ascii_alphanumeric() {
                             self.advance();
@@ -2755,12 +3000,13 @@ impl<'a> PerlLexer<'a> {
                             break;
                         }
                     }
+                    let extended = self.input[flags_start..self.position].contains('x');
 
                     let text = &self.input[start..self.position];
                     self.mode = LexerMode::ExpectOperator;
 
                     return Some(Token {
-                        token_type: TokenType::RegexMatch,
+                        token_type: TokenType::RegexMatch { extended },
                         text: Arc::from(text),
                         start,
                         end: self.position,
@@ -2783,9 +3029,6 @@ impl<'a> PerlLexer<'a> {
     }
 }
 
-// Pre-computed keyword hash for fast lookup
-static KEYWORDS: OnceLock<HashSet<&'static str>> = OnceLock::new();
-
 // Pre-allocated empty Arc to avoid repeated allocations
 static EMPTY_ARC: OnceLock<Arc<str>> = OnceLock::new();
 
@@ -2794,89 +3037,6 @@ fn empty_arc() -> Arc<str> {
     EMPTY_ARC.get_or_init(|| Arc::from("")).clone()
 }
 
-#[inline(always)]
-fn is_keyword(word: &str) -> bool {
-    let keywords = KEYWORDS.get_or_init(|| {
-        [
-            // Single char keywords
-            "q",
-            "m",
-            "s",
-            "y",
-            // Two char keywords
-            "if",
-            "do",
-            "my",
-            "or",
-            "qq",
-            "qw",
-            "qr",
-            "qx",
-            "tr",
-            // Three char keywords
-            "sub",
-            "our",
-            "use",
-            "and",
-            "not",
-            "xor",
-            "die",
-            "say",
-            "for",
-            "try",
-            "END",
-            "cmp",
-            // Four char keywords
-            "else",
-            "when",
-            "next",
-            "last",
-            "redo",
-            "goto",
-            "eval",
-            "warn",
-            "INIT",
-            // Five char keywords
-            "elsif",
-            "while",
-            "until",
-            "local",
-            "state",
-            "given",
-            "break",
-            "print",
-            "catch",
-            "BEGIN",
-            "CHECK",
-            "class",
-            "undef",
-            // Six char keywords
-            "unless",
-            "return",
-            "method",
-            "format",
-            // Seven char keywords
-            "require",
-            "package",
-            "default",
-            "foreach",
-            "finally",
-            // Eight char keywords
-            "continue",
-            // Nine char keywords
-            "UNITCHECK",
-        ]
-        .into_iter()
-        .collect()
-    });
-
-    // Fast length-based rejection for most cases
-    match word.len() {
-        1..=9 => keywords.contains(word),
-        _ => false,
-    }
-}
-
 /// Fast lookup table for compound operator second characters
 const COMPOUND_SECOND_CHARS: &[u8] = b"=<>&|+->.~*";
 
@@ -3041,7 +3201,7 @@ mod tests {
         lexer.next_token(); // if
         lexer.next_token(); // (
         let token = lexer.next_token().ok_or("Expected regex token")?;
-        assert_eq!(token.token_type, TokenType::RegexMatch);
+        assert_eq!(token.token_type, TokenType::RegexMatch { extended: false });
         Ok(())
     }
 
@@ -3075,7 +3235,7 @@ mod tests {
         lexer.next_token(); // $x
         lexer.next_token(); // =~
         let token = lexer.next_token().ok_or("Expected regex token")?;
-        assert_eq!(token.token_type, TokenType::RegexMatch);
+        assert_eq!(token.token_type, TokenType::RegexMatch { extended: false });
 
         // Exponent operator
         let mut lexer = PerlLexer::new("2 ** 3");
@@ -1,4 +1,5 @@
-use crate::TokenType;
+use crate::keywords::QUOTE_OPERATORS;
+use crate::{StringPart, TokenType};
 /// Quote operator handling with uniform delimiter processing and modifier attachment
 ///
 /// This module provides consistent handling for all Perl quote-like operators:
@@ -121,7 +122,7 @@ pub struct QuoteResult {
 
 /// Check if we're currently parsing a quote operator
 pub fn is_quote_operator(word: &str) -> bool {
-    matches!(word, "q" | "qq" | "qw" | "qr" | "qx" | "m" | "s" | "tr" | "y")
+    QUOTE_OPERATORS.contains(word)
 }
 
 /// Get the token type for a completed quote operator
@@ -130,11 +131,11 @@ pub fn get_quote_token_type(operator: &str) -> TokenType {
         "q" => TokenType::QuoteSingle,
         "qq" => TokenType::QuoteDouble,
         "qw" => TokenType::QuoteWords,
-        "qr" => TokenType::QuoteRegex,
+        "qr" => TokenType::QuoteRegex { parts: Vec::new(), has_embedded_code: false },
         "qx" => TokenType::QuoteCommand,
-        "m" => TokenType::RegexMatch,
-        "s" => TokenType::Substitution,
-        "tr" | "y" => TokenType::Transliteration,
+        "m" => TokenType::RegexMatch { extended: false },
+        "s" => TokenType::Substitution { extended: false },
+        "tr" | "y" => TokenType::Transliteration { modifiers: Default::default() },
         _ => TokenType::Error(Arc::from(format!("Unknown quote operator: {}", operator))),
     }
 }
@@ -150,3 +151,136 @@ pub fn get_mod_spec(operator: &str) -> Option<&'static ModSpec> {
         _ => None, // q, qq, qw, qx don't take modifiers
     }
 }
+
+/// Split an interpolating regex body into literal and variable parts
+///
+/// Only `$name`, `@name` and `${...}`/`@{...}` forms interpolate; a `$` that
+/// is not followed by an identifier (e.g. `foo$`, `(a$|b)`) is an end-of-line
+/// anchor and stays literal. Returns an empty list when nothing interpolates.
+pub fn split_regex_interpolation(pattern: &str) -> Vec<StringPart> {
+    let mut parts = Vec::new();
+    let mut literal = String::new();
+    let mut chars = pattern.char_indices().peekable();
+
+    while let Some((idx, ch)) = chars.next() {
+        match ch {
+            '\\' => {
+                literal.push(ch);
+                if let Some((_, escaped)) = chars.next() {
+                    literal.push(escaped);
+                }
+            }
+            '$' | '@' => {
+                let rest = &pattern[idx + 1..];
+                let name_len = identifier_len(rest);
+                if name_len > 0 {
+                    flush_literal(&mut literal, &mut parts);
+                    let end = idx + 1 + name_len;
+                    parts.push(StringPart::Variable(Arc::from(&pattern[idx..end])));
+                    while chars.peek().is_some_and(|&(i, _)| i < end) {
+                        chars.next();
+                    }
+                } else if rest.starts_with('{') {
+                    let Some(close) = matching_brace(rest) else {
+                        literal.push(ch);
+                        continue;
+                    };
+                    flush_literal(&mut literal, &mut parts);
+                    let end = idx + 1 + close + 1;
+                    parts.push(StringPart::Expression(Arc::from(&pattern[idx..end])));
+                    while chars.peek().is_some_and(|&(i, _)| i < end) {
+                        chars.next();
+                    }
+                } else {
+                    literal.push(ch);
+                }
+            }
+            _ => literal.push(ch),
+        }
+    }
+
+    if parts.is_empty() {
+        return parts;
+    }
+    flush_literal(&mut literal, &mut parts);
+    parts
+}
+
+/// Byte length of the `->{...}`/`->[...]` subscript chain at the start of `text`
+///
+/// The chain must start with an arrow; later subscripts may omit it
+/// (`->{a}{b}`, `->[0][1]`). An arrow followed by anything else, such as a
+/// method name, ends the chain. Bracket matching never crosses `stop`, the
+/// closing delimiter of the enclosing string.
+pub(crate) fn subscript_chain_len(text: &str, stop: Option<char>) -> usize {
+    let mut len = 0;
+    loop {
+        let rest = &text[len..];
+        let open = if rest.starts_with("->{") || rest.starts_with("->[") {
+            len + 2
+        } else if len > 0 && rest.starts_with(['{', '[']) {
+            len
+        } else {
+            return len;
+        };
+        let close = if text[open..].starts_with('{') { '}' } else { ']' };
+        match matching_close(&text[open..], close, stop) {
+            Some(offset) => len = open + offset + 1,
+            None => return len,
+        }
+    }
+}
+
+fn flush_literal(literal: &mut String, parts: &mut Vec<StringPart>) {
+    if !literal.is_empty() {
+        parts.push(StringPart::Literal(Arc::from(std::mem::take(literal))));
+    }
+}
+
+/// Byte length of a (possibly `::`-qualified) identifier at the start of `text`
+fn identifier_len(text: &str) -> usize {
+    let is_start = |c: char| c == '_' || c.is_alphabetic();
+    let mut len = 0;
+    while text[len..].starts_with(is_start) {
+        len += text[len..]
+            .char_indices()
+            .take_while(|&(_, c)| c == '_' || c.is_alphanumeric())
+            .map(|(i, c)| i + c.len_utf8())
+            .last()
+            .unwrap_or(0);
+        if text[len..].starts_with("::") && text[len + 2..].starts_with(is_start) {
+            len += 2;
+        } else {
+            break;
+        }
+    }
+    len
+}
+
+/// Byte offset of the `}` matching the `{` at the start of `text`
+fn matching_brace(text: &str) -> Option<usize> {
+    matching_close(text, '}', None)
+}
+
+/// Byte offset of the `close` bracket matching the opener at the start of `text`
+fn matching_close(text: &str, close: char, stop: Option<char>) -> Option<usize> {
+    let open = text.chars().next()?;
+    let mut depth = 0usize;
+    let mut escaped = false;
+    for (idx, ch) in text.char_indices() {
+        match ch {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            _ if Some(ch) == stop => return None,
+            c if c == open => depth += 1,
+            c if c == close => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return Some(idx);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
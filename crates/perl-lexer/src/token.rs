@@ -9,7 +9,7 @@ pub enum StringPart {
     Literal(Arc<str>),
     /// Variable interpolation: $var, @array, %hash
     Variable(Arc<str>),
-    /// Expression interpolation: ${expr}, @{expr}
+    /// Expression interpolation: ${expr}, @{expr}, or a subscript chain like `$h->{a}[0]`
     Expression(Arc<str>),
     /// Method call: `->method()`
     MethodCall(Arc<str>),
@@ -17,6 +17,42 @@ pub enum StringPart {
     ArraySlice(Arc<str>),
 }
 
+/// Modifiers of a `tr///` or `y///` operator
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransliterationModifiers {
+    /// `c`: complement the search list
+    pub complement: bool,
+    /// `d`: delete found characters that have no replacement
+    pub delete: bool,
+    /// `s`: squeeze runs of the same translated character
+    pub squeeze: bool,
+    /// `r`: return a modified copy and leave the target untouched
+    pub return_copy: bool,
+}
+
+impl TransliterationModifiers {
+    /// Parse a modifier run such as `cds`; characters other than `c`, `d`, `s`, `r` are ignored
+    pub fn parse(flags: &str) -> Self {
+        let mut modifiers = Self::default();
+        for flag in flags.chars() {
+            match flag {
+                'c' => modifiers.complement = true,
+                'd' => modifiers.delete = true,
+                's' => modifiers.squeeze = true,
+                'r' => modifiers.return_copy = true,
+                _ => {}
+            }
+        }
+        modifiers
+    }
+
+    /// Whether the operator writes to its target (`$x =~ tr/a/b/`) rather than
+    /// returning a copy (`$x =~ tr/a/b/r`)
+    pub fn mutates_target(&self) -> bool {
+        !self.return_copy
+    }
+}
+
 /// Token types for Perl
 #[derive(Debug, Clone, PartialEq)]
 pub enum TokenType {
@@ -24,13 +60,33 @@ pub enum TokenType {
     /// Division operator: /
     Division,
     /// Regex match: m// or //
-    RegexMatch,
+    RegexMatch {
+        /// Whether the `/x` modifier is set, so whitespace and `#` comments in the pattern are ignored
+        extended: bool,
+    },
     /// Substitution: s///
-    Substitution,
+    Substitution {
+        /// Whether the `/x` modifier is set, so whitespace and `#` comments in the pattern are ignored
+        extended: bool,
+    },
     /// Transliteration: tr/// or y///
-    Transliteration,
+    Transliteration {
+        /// Modifiers following the replacement list
+        modifiers: TransliterationModifiers,
+    },
     /// Quote regex: qr//
-    QuoteRegex,
+    QuoteRegex {
+        /// Interpolated parts of the pattern (empty when nothing interpolates)
+        parts: Vec<StringPart>,
+        /// Whether the pattern contains `(?{...})` or `(??{...})` code blocks
+        has_embedded_code: bool,
+    },
+
+    // Binding operators
+    /// Regex binding: =~
+    Bind,
+    /// Negated regex binding: !~
+    NotBind,
 
     // String and quote tokens
     /// String literal: "string" or 'string'
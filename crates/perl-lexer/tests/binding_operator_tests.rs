@@ -0,0 +1,79 @@
+//! Tests for the `=~` / `!~` binding operator tokens
+//!
+//! Binding operators get dedicated token types, and the pattern that follows
+//! them is always lexed as a regex rather than a division.
+
+use perl_lexer::{PerlLexer, TokenType};
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+fn lex(input: &str) -> Vec<(TokenType, String)> {
+    let mut lexer = PerlLexer::new(input);
+    let mut tokens = Vec::new();
+    while let Some(token) = lexer.next_token() {
+        if matches!(token.token_type, TokenType::EOF) {
+            break;
+        }
+        tokens.push((token.token_type, token.text.to_string()));
+    }
+    tokens
+}
+
+#[test]
+fn bind_followed_by_slash_regex() -> TestResult {
+    let tokens = lex("$x =~ /foo/");
+    assert_eq!(tokens.len(), 3, "Unexpected tokens: {tokens:?}");
+    assert!(matches!(tokens[1].0, TokenType::Bind), "Expected Bind, got {:?}", tokens[1]);
+    assert_eq!(tokens[1].1, "=~");
+    assert!(
+        matches!(tokens[2].0, TokenType::RegexMatch { .. }),
+        "Expected regex, got {:?}",
+        tokens[2]
+    );
+    assert_eq!(tokens[2].1, "/foo/");
+    Ok(())
+}
+
+#[test]
+fn bind_without_spaces() -> TestResult {
+    let tokens = lex("$x=~/a\\/b/i");
+    assert!(matches!(tokens[1].0, TokenType::Bind), "Expected Bind, got {:?}", tokens[1]);
+    assert!(
+        matches!(tokens[2].0, TokenType::RegexMatch { .. }),
+        "Expected regex, got {:?}",
+        tokens[2]
+    );
+    Ok(())
+}
+
+#[test]
+fn not_bind_with_alternate_delimiters() -> TestResult {
+    let tokens = lex("$x !~ m{bar}");
+    assert_eq!(tokens.len(), 3, "Unexpected tokens: {tokens:?}");
+    assert!(matches!(tokens[1].0, TokenType::NotBind), "Expected NotBind, got {:?}", tokens[1]);
+    assert_eq!(tokens[1].1, "!~");
+    assert!(
+        matches!(tokens[2].0, TokenType::RegexMatch { .. }),
+        "Expected regex, got {:?}",
+        tokens[2]
+    );
+    Ok(())
+}
+
+#[test]
+fn bind_followed_by_substitution() -> TestResult {
+    let tokens = lex("$s =~ s/old/new/g");
+    assert!(matches!(tokens[1].0, TokenType::Bind));
+    assert!(matches!(tokens[2].0, TokenType::Substitution { .. }), "Got {:?}", tokens[2]);
+    Ok(())
+}
+
+#[test]
+fn division_after_match_result_is_not_a_regex() -> TestResult {
+    let tokens = lex("($x =~ /a/) / 2");
+    assert!(
+        tokens.iter().any(|(kind, _)| matches!(kind, TokenType::Division)),
+        "Expected division after the closing paren: {tokens:?}"
+    );
+    Ok(())
+}
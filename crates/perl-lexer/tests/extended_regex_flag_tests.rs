@@ -0,0 +1,41 @@
+/// Tests for `/x` detection on match and substitution tokens
+use perl_lexer::{PerlLexer, TokenType};
+
+fn first_regex_token(code: &str) -> Option<(TokenType, String)> {
+    let mut lexer = PerlLexer::new(code);
+    lexer.collect_tokens().into_iter().find_map(|t| match t.token_type {
+        TokenType::RegexMatch { .. } | TokenType::Substitution { .. } => {
+            Some((t.token_type, t.text.to_string()))
+        }
+        _ => None,
+    })
+}
+
+#[test]
+fn test_multiline_x_regex_with_comments_is_one_extended_token() {
+    let pattern = "m{\n    ^ (\\d+)   # year\n    - (\\d+)   # month\n}x";
+    let code = format!("$date =~ {pattern};");
+    let found = first_regex_token(&code);
+
+    assert_eq!(found, Some((TokenType::RegexMatch { extended: true }, pattern.to_string())));
+}
+
+#[test]
+fn test_slash_regex_records_x_flag() {
+    let found = first_regex_token("$s =~ / a b # c\n /xi;").map(|(kind, _)| kind);
+    assert_eq!(found, Some(TokenType::RegexMatch { extended: true }));
+
+    let found = first_regex_token("$s =~ /a b/i;").map(|(kind, _)| kind);
+    assert_eq!(found, Some(TokenType::RegexMatch { extended: false }));
+}
+
+#[test]
+fn test_substitution_records_x_flag() {
+    for code in ["$s =~ s/ a # letter\n /b/gx;", "$s =~ s{ a # letter\n }{b}x;"] {
+        let found = first_regex_token(code).map(|(kind, _)| kind);
+        assert_eq!(found, Some(TokenType::Substitution { extended: true }), "{code}");
+    }
+
+    let found = first_regex_token("$s =~ s/a/b/g;").map(|(kind, _)| kind);
+    assert_eq!(found, Some(TokenType::Substitution { extended: false }));
+}
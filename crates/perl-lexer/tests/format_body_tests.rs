@@ -0,0 +1,55 @@
+use perl_lexer::{PerlLexer, TokenType};
+
+fn token_types(source: &str) -> Vec<TokenType> {
+    let mut lexer = PerlLexer::new(source);
+    let mut types = Vec::new();
+    while let Some(token) = lexer.next_token() {
+        if matches!(token.token_type, TokenType::EOF) {
+            break;
+        }
+        types.push(token.token_type);
+    }
+    types
+}
+
+fn format_bodies(source: &str) -> Vec<String> {
+    token_types(source)
+        .into_iter()
+        .filter_map(|t| match t {
+            TokenType::FormatBody(body) => Some(body.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn named_format_header_enters_format_body_mode() {
+    let source = "format STDOUT =\n@<<<\n$x\n.\nprint 1;\n";
+
+    assert_eq!(format_bodies(source), vec!["\n@<<<\n$x\n".to_string()]);
+    // Lexing resumes normally after the terminating `.`
+    assert!(token_types(source).contains(&TokenType::Semicolon));
+}
+
+#[test]
+fn anonymous_and_qualified_format_headers_enter_format_body_mode() {
+    assert_eq!(format_bodies("format =\ntext\n.\n"), vec!["\ntext\n".to_string()]);
+    assert_eq!(format_bodies("format My::Report = \r\nx\n.\n"), vec![" \r\nx\n".to_string()]);
+}
+
+#[test]
+fn format_without_header_does_not_enter_format_body_mode() {
+    for source in [
+        "my $format = 1;\n",
+        "format($x);\n",
+        "my %h = (format => 1);\n",
+        "$h{format} = 1;\n",
+        "format = 1;\n",
+    ] {
+        assert!(format_bodies(source).is_empty(), "{source:?} entered format mode");
+        assert!(
+            !token_types(source).iter().any(|t| matches!(t, TokenType::Error(_))),
+            "{source:?} produced an error token"
+        );
+    }
+}
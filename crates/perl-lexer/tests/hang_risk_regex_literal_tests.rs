@@ -33,7 +33,7 @@ fn lexer_regex_literal_simple_pattern() -> TestResult {
 
     let tok = lexer.next_token().ok_or("Expected regex token")?;
     assert!(
-        matches!(tok.token_type, TokenType::RegexMatch),
+        matches!(tok.token_type, TokenType::RegexMatch { .. }),
         "Expected regex token, got {:?}",
         tok.token_type
     );
@@ -61,7 +61,7 @@ fn lexer_regex_literal_character_classes() -> TestResult {
             lexer.next_token().ok_or_else(|| format!("Expected regex token for '{}'", code))?;
 
         assert!(
-            matches!(tok.token_type, TokenType::RegexMatch),
+            matches!(tok.token_type, TokenType::RegexMatch { .. }),
             "Expected regex for '{}', got {:?}",
             code,
             tok.token_type
@@ -93,7 +93,7 @@ fn lexer_regex_literal_quantifiers() -> TestResult {
             lexer.next_token().ok_or_else(|| format!("Expected regex token for '{}'", code))?;
 
         assert!(
-            matches!(tok.token_type, TokenType::RegexMatch),
+            matches!(tok.token_type, TokenType::RegexMatch { .. }),
             "Expected regex for '{}', got {:?}",
             code,
             tok.token_type
@@ -122,7 +122,7 @@ fn lexer_regex_literal_capture_groups() -> TestResult {
             lexer.next_token().ok_or_else(|| format!("Expected regex token for '{}'", code))?;
 
         assert!(
-            matches!(tok.token_type, TokenType::RegexMatch),
+            matches!(tok.token_type, TokenType::RegexMatch { .. }),
             "Expected regex for '{}', got {:?}",
             code,
             tok.token_type
@@ -156,7 +156,7 @@ fn lexer_regex_literal_deeply_nested_captures() {
     match result {
         Some(tok) => {
             assert!(
-                matches!(tok.token_type, TokenType::RegexMatch | TokenType::UnknownRest),
+                matches!(tok.token_type, TokenType::RegexMatch { .. } | TokenType::UnknownRest),
                 "Expected regex or UnknownRest for deeply nested captures, got {:?}",
                 tok.token_type
             );
@@ -185,7 +185,7 @@ fn lexer_regex_literal_lookahead_assertions() -> TestResult {
             lexer.next_token().ok_or_else(|| format!("Expected regex token for '{}'", code))?;
 
         assert!(
-            matches!(tok.token_type, TokenType::RegexMatch),
+            matches!(tok.token_type, TokenType::RegexMatch { .. }),
             "Expected regex for '{}', got {:?}",
             code,
             tok.token_type
@@ -211,7 +211,7 @@ fn lexer_regex_literal_lookbehind_assertions() -> TestResult {
             lexer.next_token().ok_or_else(|| format!("Expected regex token for '{}'", code))?;
 
         assert!(
-            matches!(tok.token_type, TokenType::RegexMatch),
+            matches!(tok.token_type, TokenType::RegexMatch { .. }),
             "Expected regex for '{}', got {:?}",
             code,
             tok.token_type
@@ -241,7 +241,7 @@ fn lexer_regex_literal_modifiers() -> TestResult {
             lexer.next_token().ok_or_else(|| format!("Expected regex token for '{}'", code))?;
 
         assert!(
-            matches!(tok.token_type, TokenType::RegexMatch),
+            matches!(tok.token_type, TokenType::RegexMatch { .. }),
             "Expected regex for '{}', got {:?}",
             code,
             tok.token_type
@@ -273,7 +273,7 @@ fn lexer_regex_literal_various_delimiters() -> TestResult {
             lexer.next_token().ok_or_else(|| format!("Expected regex token for '{}'", code))?;
 
         assert!(
-            matches!(tok.token_type, TokenType::RegexMatch),
+            matches!(tok.token_type, TokenType::RegexMatch { .. }),
             "Expected regex for '{}', got {:?}",
             code,
             tok.token_type
@@ -301,7 +301,7 @@ fn lexer_regex_literal_nested_delimiters() -> TestResult {
             lexer.next_token().ok_or_else(|| format!("Expected regex token for '{}'", code))?;
 
         assert!(
-            matches!(tok.token_type, TokenType::RegexMatch),
+            matches!(tok.token_type, TokenType::RegexMatch { .. }),
             "Expected regex for '{}', got {:?}",
             code,
             tok.token_type
@@ -325,7 +325,7 @@ fn lexer_regex_literal_very_long_pattern() -> TestResult {
     let tok = lexer.next_token().ok_or("Expected token for long pattern")?;
 
     assert!(
-        matches!(tok.token_type, TokenType::RegexMatch | TokenType::UnknownRest),
+        matches!(tok.token_type, TokenType::RegexMatch { .. } | TokenType::UnknownRest),
         "Expected regex or UnknownRest for long pattern, got {:?}",
         tok.token_type
     );
@@ -374,7 +374,7 @@ fn lexer_regex_literal_unicode_patterns() -> TestResult {
             lexer.next_token().ok_or_else(|| format!("Expected regex token for '{}'", code))?;
 
         assert!(
-            matches!(tok.token_type, TokenType::RegexMatch),
+            matches!(tok.token_type, TokenType::RegexMatch { .. }),
             "Expected regex for '{}', got {:?}",
             code,
             tok.token_type
@@ -394,7 +394,7 @@ fn lexer_regex_literal_pathological_backtracking_pattern() -> TestResult {
 
     let tok = lexer.next_token().ok_or("Expected regex token")?;
     assert!(
-        matches!(tok.token_type, TokenType::RegexMatch),
+        matches!(tok.token_type, TokenType::RegexMatch { .. }),
         "Expected regex for pathological pattern, got {:?}",
         tok.token_type
     );
@@ -416,7 +416,7 @@ fn lexer_regex_literal_embedded_comments() -> TestResult {
     let tok = lexer.next_token().ok_or("Expected regex token")?;
 
     assert!(
-        matches!(tok.token_type, TokenType::RegexMatch),
+        matches!(tok.token_type, TokenType::RegexMatch { .. }),
         "Expected regex with embedded comments, got {:?}",
         tok.token_type
     );
@@ -437,7 +437,7 @@ fn lexer_regex_literal_malformed_unclosed() {
     match result {
         Some(tok) => {
             assert!(
-                matches!(tok.token_type, TokenType::UnknownRest | TokenType::RegexMatch),
+                matches!(tok.token_type, TokenType::UnknownRest | TokenType::RegexMatch { .. }),
                 "Expected error token for malformed regex, got {:?}",
                 tok.token_type
             );
@@ -467,7 +467,7 @@ fn lexer_regex_literal_malformed_unbalanced_groups() {
         match result {
             Some(tok) => {
                 assert!(
-                    matches!(tok.token_type, TokenType::UnknownRest | TokenType::RegexMatch),
+                    matches!(tok.token_type, TokenType::UnknownRest | TokenType::RegexMatch { .. }),
                     "Expected error handling for malformed regex '{}', got {:?}",
                     code,
                     tok.token_type
@@ -498,7 +498,7 @@ fn lexer_regex_literal_escaped_delimiter() -> TestResult {
             lexer.next_token().ok_or_else(|| format!("Expected regex token for '{}'", code))?;
 
         assert!(
-            matches!(tok.token_type, TokenType::RegexMatch),
+            matches!(tok.token_type, TokenType::RegexMatch { .. }),
             "Expected regex for '{}', got {:?}",
             code,
             tok.token_type
@@ -520,7 +520,7 @@ fn lexer_regex_literal_alternation() -> TestResult {
             lexer.next_token().ok_or_else(|| format!("Expected regex token for '{}'", code))?;
 
         assert!(
-            matches!(tok.token_type, TokenType::RegexMatch),
+            matches!(tok.token_type, TokenType::RegexMatch { .. }),
             "Expected regex for '{}', got {:?}",
             code,
             tok.token_type
@@ -550,7 +550,7 @@ fn lexer_regex_literal_anchors() -> TestResult {
             lexer.next_token().ok_or_else(|| format!("Expected regex token for '{}'", code))?;
 
         assert!(
-            matches!(tok.token_type, TokenType::RegexMatch),
+            matches!(tok.token_type, TokenType::RegexMatch { .. }),
             "Expected regex for '{}', got {:?}",
             code,
             tok.token_type
@@ -650,7 +650,7 @@ fn lexer_regex_literal_delimiter_nesting_limit() {
         Some(tok) => {
             // Should emit UnknownRest after hitting nesting limit
             assert!(
-                matches!(tok.token_type, TokenType::UnknownRest | TokenType::RegexMatch),
+                matches!(tok.token_type, TokenType::UnknownRest | TokenType::RegexMatch { .. }),
                 "Expected UnknownRest or graceful handling for deep nesting, got {:?}",
                 tok.token_type
             );
@@ -674,7 +674,7 @@ fn lexer_regex_literal_qr_quoted_regex() -> TestResult {
         let tok = lexer.next_token().ok_or_else(|| format!("Expected qr token for '{}'", code))?;
 
         assert!(
-            matches!(tok.token_type, TokenType::QuoteRegex),
+            matches!(tok.token_type, TokenType::QuoteRegex { .. }),
             "Expected qr token for '{}', got {:?}",
             code,
             tok.token_type
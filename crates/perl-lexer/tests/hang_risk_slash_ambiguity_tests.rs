@@ -125,7 +125,7 @@ fn lexer_slash_ambiguity_regex_at_statement_start() -> TestResult {
 
     let tok = lexer.next_token().ok_or("Expected regex token")?;
     assert!(
-        matches!(tok.token_type, TokenType::RegexMatch),
+        matches!(tok.token_type, TokenType::RegexMatch { .. }),
         "Expected regex at statement start, got {:?}",
         tok.token_type
     );
@@ -150,7 +150,7 @@ fn lexer_slash_ambiguity_regex_after_binding() -> TestResult {
 
     let tok = lexer.next_token().ok_or("Expected regex token")?;
     assert!(
-        matches!(tok.token_type, TokenType::RegexMatch),
+        matches!(tok.token_type, TokenType::RegexMatch { .. }),
         "Expected regex after =~, got {:?}",
         tok.token_type
     );
@@ -175,7 +175,7 @@ fn lexer_slash_ambiguity_regex_after_negated_binding() -> TestResult {
 
     let tok = lexer.next_token().ok_or("Expected regex token")?;
     assert!(
-        matches!(tok.token_type, TokenType::RegexMatch),
+        matches!(tok.token_type, TokenType::RegexMatch { .. }),
         "Expected regex after !~, got {:?}",
         tok.token_type
     );
@@ -290,7 +290,7 @@ fn lexer_slash_ambiguity_regex_whitespace_variations() -> TestResult {
 
         let tok = lexer.next_token().ok_or("Expected regex token")?;
         assert!(
-            matches!(tok.token_type, TokenType::RegexMatch),
+            matches!(tok.token_type, TokenType::RegexMatch { .. }),
             "Expected regex in '{}', got {:?}",
             code,
             tok.token_type
@@ -361,7 +361,7 @@ fn lexer_slash_ambiguity_regex_in_list_context() -> TestResult {
 
     let tok = lexer.next_token().ok_or("Expected regex token")?;
     assert!(
-        matches!(tok.token_type, TokenType::RegexMatch),
+        matches!(tok.token_type, TokenType::RegexMatch { .. }),
         "Expected regex in if condition, got {:?}",
         tok.token_type
     );
@@ -413,7 +413,7 @@ fn lexer_slash_ambiguity_regex_with_modifiers() -> TestResult {
 
         let tok = lexer.next_token().ok_or("Expected regex token")?;
         assert!(
-            matches!(tok.token_type, TokenType::RegexMatch),
+            matches!(tok.token_type, TokenType::RegexMatch { .. }),
             "Expected regex with modifiers in '{}', got {:?}",
             code,
             tok.token_type
@@ -498,7 +498,7 @@ fn lexer_slash_ambiguity_regex_after_brace() -> TestResult {
 
     let tok = lexer.next_token().ok_or("Expected regex token")?;
     assert!(
-        matches!(tok.token_type, TokenType::RegexMatch),
+        matches!(tok.token_type, TokenType::RegexMatch { .. }),
         "Expected regex after brace, got {:?}",
         tok.token_type
     );
@@ -544,7 +544,7 @@ fn lexer_slash_ambiguity_regex_in_ternary() -> TestResult {
 
     let tok = lexer.next_token().ok_or("Expected regex token")?;
     assert!(
-        matches!(tok.token_type, TokenType::RegexMatch),
+        matches!(tok.token_type, TokenType::RegexMatch { .. }),
         "Expected regex in ternary, got {:?}",
         tok.token_type
     );
@@ -683,7 +683,7 @@ fn lexer_slash_ambiguity_real_world_regex_in_map_grep() -> TestResult {
         let mut found_regex = false;
         loop {
             let tok = lexer.next_token().ok_or("Expected token")?;
-            if matches!(tok.token_type, TokenType::RegexMatch) {
+            if matches!(tok.token_type, TokenType::RegexMatch { .. }) {
                 found_regex = true;
                 break;
             }
@@ -56,9 +56,9 @@ fn test_nested_quantifiers_safe_handling() {
         let has_regex_or_error = tokens.iter().any(|t| {
             matches!(
                 t.token_type,
-                TokenType::RegexMatch
-                    | TokenType::Substitution
-                    | TokenType::QuoteRegex
+                TokenType::RegexMatch { .. }
+                    | TokenType::Substitution { .. }
+                    | TokenType::QuoteRegex { .. }
                     | TokenType::Error(_)
                     | TokenType::UnknownRest
             )
@@ -266,11 +266,11 @@ fn test_mixed_operators_sequence() {
 
     // Count different operator types
     let regex_count =
-        tokens.iter().filter(|t| matches!(t.token_type, TokenType::RegexMatch)).count();
+        tokens.iter().filter(|t| matches!(t.token_type, TokenType::RegexMatch { .. })).count();
     let subst_count =
-        tokens.iter().filter(|t| matches!(t.token_type, TokenType::Substitution)).count();
+        tokens.iter().filter(|t| matches!(t.token_type, TokenType::Substitution { .. })).count();
     let trans_count =
-        tokens.iter().filter(|t| matches!(t.token_type, TokenType::Transliteration)).count();
+        tokens.iter().filter(|t| matches!(t.token_type, TokenType::Transliteration { .. })).count();
 
     // Verify we found multiple operators (demonstrates no hang on any single operator)
     assert!(
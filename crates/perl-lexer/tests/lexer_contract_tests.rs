@@ -82,9 +82,9 @@ fn quote_ops_with_delimiters_tokenize_correctly() -> TestResult {
             TokenType::QuoteDouble => "QuoteDouble",
             TokenType::QuoteWords => "QuoteWords",
             TokenType::QuoteCommand => "QuoteCommand",
-            TokenType::RegexMatch => "RegexMatch",
-            TokenType::Substitution => "Substitution",
-            TokenType::Transliteration => "Transliteration",
+            TokenType::RegexMatch { .. } => "RegexMatch",
+            TokenType::Substitution { .. } => "Substitution",
+            TokenType::Transliteration { .. } => "Transliteration",
             _ => "Other",
         };
 
@@ -106,7 +106,7 @@ fn substitution_single_quote_delimiters() -> TestResult {
         let mut lx = PerlLexer::new(input);
         let t = lx.next_token().ok_or_else(|| format!("Expected token for '{}'", input))?;
         assert!(
-            matches!(t.token_type, TokenType::Substitution),
+            matches!(t.token_type, TokenType::Substitution { .. }),
             "{input} should tokenize as Substitution but got {:?}",
             t.token_type
         );
@@ -132,9 +132,17 @@ fn test_ac2_lexer_substitution_operator_error_handling() {
     // AC:2 - Test that valid substitution operators work correctly
     // This establishes that the lexer correctly parses s//, tr//, and y// operators
     let test_cases = vec![
-        ("s/old/new/", TokenType::Substitution, "Valid substitution operator"),
-        ("tr/abc/xyz/", TokenType::Transliteration, "Valid transliteration operator"),
-        ("y/abc/xyz/", TokenType::Transliteration, "Valid transliteration operator (y syntax)"),
+        ("s/old/new/", TokenType::Substitution { extended: false }, "Valid substitution operator"),
+        (
+            "tr/abc/xyz/",
+            TokenType::Transliteration { modifiers: Default::default() },
+            "Valid transliteration operator",
+        ),
+        (
+            "y/abc/xyz/",
+            TokenType::Transliteration { modifiers: Default::default() },
+            "Valid transliteration operator (y syntax)",
+        ),
     ];
 
     for (input, expected_token_type, description) in test_cases {
@@ -643,9 +651,9 @@ fn test_multiple_error_tokens_diagnostic_collection() {
 
     // Count different token types
     let substitution_count =
-        tokens.iter().filter(|t| matches!(t.token_type, TokenType::Substitution)).count();
+        tokens.iter().filter(|t| matches!(t.token_type, TokenType::Substitution { .. })).count();
     let transliteration_count =
-        tokens.iter().filter(|t| matches!(t.token_type, TokenType::Transliteration)).count();
+        tokens.iter().filter(|t| matches!(t.token_type, TokenType::Transliteration { .. })).count();
 
     // Verify we found multiple operators (demonstrates error recovery continuation)
     assert!(
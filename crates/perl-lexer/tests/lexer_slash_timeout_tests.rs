@@ -51,7 +51,7 @@ fn test_slash_after_operator_is_regex() -> TestResult {
     let mut lexer = PerlLexer::new("=~ /pattern/");
     lexer.next_token(); // =~
     let token = lexer.next_token().ok_or("Expected regex token")?;
-    assert_eq!(token.token_type, TokenType::RegexMatch);
+    assert_eq!(token.token_type, TokenType::RegexMatch { extended: false });
     Ok(())
 }
 
@@ -61,7 +61,7 @@ fn test_slash_after_keyword_is_regex() -> TestResult {
     let mut lexer = PerlLexer::new("if /pattern/");
     lexer.next_token(); // if
     let token = lexer.next_token().ok_or("Expected regex token")?;
-    assert_eq!(token.token_type, TokenType::RegexMatch);
+    assert_eq!(token.token_type, TokenType::RegexMatch { extended: false });
     Ok(())
 }
 
@@ -72,7 +72,7 @@ fn test_slash_after_opening_paren_is_regex() -> TestResult {
     lexer.next_token(); // if
     lexer.next_token(); // (
     let token = lexer.next_token().ok_or("Expected regex token")?;
-    assert_eq!(token.token_type, TokenType::RegexMatch);
+    assert_eq!(token.token_type, TokenType::RegexMatch { extended: false });
     Ok(())
 }
 
@@ -110,7 +110,7 @@ fn test_deeply_nested_slashes_with_escapes() -> TestResult {
     let token = lexer.next_token().ok_or("Expected regex token")?;
 
     // Should complete quickly with regex token
-    assert_eq!(token.token_type, TokenType::RegexMatch);
+    assert_eq!(token.token_type, TokenType::RegexMatch { extended: false });
     Ok(())
 }
 
@@ -125,7 +125,7 @@ fn test_alternating_context_slashes() -> TestResult {
     lexer.next_token(); // 2
     lexer.next_token(); // if
     let token2 = lexer.next_token().ok_or("Expected regex token")?;
-    assert_eq!(token2.token_type, TokenType::RegexMatch);
+    assert_eq!(token2.token_type, TokenType::RegexMatch { extended: false });
     Ok(())
 }
 
@@ -148,7 +148,7 @@ fn test_regex_after_match_operator() -> TestResult {
     lexer.next_token(); // $x
     lexer.next_token(); // =~
     let token = lexer.next_token().ok_or("Expected regex token")?;
-    assert_eq!(token.token_type, TokenType::RegexMatch);
+    assert_eq!(token.token_type, TokenType::RegexMatch { extended: false });
     Ok(())
 }
 
@@ -209,7 +209,7 @@ fn test_regex_with_complex_escapes() -> TestResult {
     let token = lexer.next_token().ok_or("Expected regex token")?;
 
     // Main goal: verify regex is parsed correctly without timeout
-    assert_eq!(token.token_type, TokenType::RegexMatch);
+    assert_eq!(token.token_type, TokenType::RegexMatch { extended: false });
     assert!(token.text.contains("\\d")); // Contains escape sequences
     assert!(token.text.starts_with('/')); // Starts with slash
     Ok(())
@@ -238,7 +238,12 @@ fn test_regex_in_conditional() -> TestResult {
         lexer.next_token(); // (
         let token =
             lexer.next_token().ok_or_else(|| format!("Expected regex token for: {}", code))?;
-        assert_eq!(token.token_type, TokenType::RegexMatch, "Failed for: {}", code);
+        assert_eq!(
+            token.token_type,
+            TokenType::RegexMatch { extended: false },
+            "Failed for: {}",
+            code
+        );
     }
     Ok(())
 }
@@ -0,0 +1,103 @@
+/// Tests for the configurable lexer token and string-length budgets
+/// Verifies that adversarial inputs terminate with a budget error token
+use perl_lexer::{LexerConfig, PerlLexer, TokenType};
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+#[test]
+fn test_token_cap_stops_huge_input() -> TestResult {
+    let code = "$x; ".repeat(10_000);
+    let config = LexerConfig { max_tokens: 100, ..LexerConfig::default() };
+    let mut lexer = PerlLexer::with_config(&code, config);
+    let tokens = lexer.collect_tokens();
+
+    // 100 regular tokens, one budget error covering the rest, then EOF
+    assert_eq!(tokens.len(), 102, "Expected lexing to stop at the token cap");
+    let error = &tokens[100];
+    assert!(
+        matches!(&error.token_type, TokenType::Error(msg) if msg.contains("budget")),
+        "Expected budget error token, got: {:?}",
+        error.token_type
+    );
+    assert_eq!(error.end, code.len());
+    assert_eq!(tokens[101].token_type, TokenType::EOF);
+    assert!(lexer.next_token().is_none());
+    Ok(())
+}
+
+#[test]
+fn test_peek_does_not_consume_token_budget() -> TestResult {
+    let config = LexerConfig { max_tokens: 2, ..LexerConfig::default() };
+    let mut lexer = PerlLexer::with_config("$a + $b", config);
+
+    for _ in 0..5 {
+        lexer.peek_token();
+    }
+    let first = lexer.next_token().ok_or("Expected first token")?;
+    assert!(matches!(first.token_type, TokenType::Identifier(_)));
+    Ok(())
+}
+
+#[test]
+fn test_oversized_string_literal() -> TestResult {
+    let code = format!("my $s = \"{}\"; print $s;", "a".repeat(5_000));
+    let config = LexerConfig { max_string_length: 1_000, ..LexerConfig::default() };
+    let mut lexer = PerlLexer::with_config(&code, config);
+    let tokens = lexer.collect_tokens();
+
+    let error = tokens
+        .iter()
+        .find(|t| matches!(&t.token_type, TokenType::Error(msg) if msg.contains("String length")))
+        .ok_or("Expected string length budget error")?;
+    assert_eq!(error.start, code.find('"').ok_or("quote")?);
+    assert_eq!(error.end, code.len());
+    assert_eq!(tokens.last().map(|t| &t.token_type), Some(&TokenType::EOF));
+    Ok(())
+}
+
+#[test]
+fn test_oversized_quote_operator() -> TestResult {
+    let code = format!("q{{{}}}", "b".repeat(5_000));
+    let config = LexerConfig { max_string_length: 1_000, ..LexerConfig::default() };
+    let mut lexer = PerlLexer::with_config(&code, config);
+    let token = lexer.next_token().ok_or("Expected token")?;
+
+    assert!(
+        matches!(&token.token_type, TokenType::Error(msg) if msg.contains("String length")),
+        "Expected string length budget error, got: {:?}",
+        token.token_type
+    );
+    Ok(())
+}
+
+#[test]
+fn test_string_within_budget_is_unaffected() -> TestResult {
+    let code = format!("'{}'", "c".repeat(500));
+    let config = LexerConfig { max_string_length: 1_000, ..LexerConfig::default() };
+    let mut lexer = PerlLexer::with_config(&code, config);
+    let token = lexer.next_token().ok_or("Expected token")?;
+
+    assert_eq!(token.token_type, TokenType::StringLiteral);
+    assert_eq!(token.end, code.len());
+    Ok(())
+}
+
+#[test]
+fn test_default_string_budget_is_one_megabyte() -> TestResult {
+    let limit = LexerConfig::default().max_string_length;
+    assert_eq!(limit, 1024 * 1024);
+
+    // The budget covers the whole literal, quotes included
+    let within = format!("'{}'", "d".repeat(limit - 2));
+    let token = PerlLexer::new(&within).next_token().ok_or("Expected token")?;
+    assert_eq!(token.token_type, TokenType::StringLiteral);
+
+    let over = format!("'{}'", "d".repeat(limit + 1));
+    let token = PerlLexer::new(&over).next_token().ok_or("Expected token")?;
+    assert!(
+        matches!(&token.token_type, TokenType::Error(msg) if msg.contains("String length")),
+        "Expected string length budget error, got: {:?}",
+        token.token_type
+    );
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use perl_lexer::{PerlLexer, TokenType};
+
+fn token_types(mut lexer: PerlLexer<'_>) -> Vec<TokenType> {
+    let mut types = Vec::new();
+    while let Some(token) = lexer.next_token() {
+        if matches!(token.token_type, TokenType::EOF) {
+            break;
+        }
+        types.push(token.token_type);
+    }
+    types
+}
+
+#[test]
+fn pod_block_is_skipped_like_a_comment() {
+    let types = token_types(PerlLexer::new("my $x;\n\n=head1 NAME\n\nmy $y = 1;\n\n=cut\n\n1;\n"));
+
+    assert!(!types.contains(&TokenType::Pod));
+    assert_eq!(types.iter().filter(|t| matches!(t, TokenType::Semicolon)).count(), 2);
+}
+
+#[test]
+fn body_token_lexer_emits_pod_through_cut_line() {
+    let source = "1;\n=pod\n\nDocs.\n\n=cut\nmy $x;\n";
+    let tokens = PerlLexer::with_body_tokens(source).collect_tokens();
+
+    let pods: Vec<&str> = tokens
+        .iter()
+        .filter(|token| token.token_type == TokenType::Pod)
+        .map(|token| &source[token.start..token.end])
+        .collect();
+    assert_eq!(pods, vec!["=pod\n\nDocs.\n\n=cut\n"]);
+}
+
+#[test]
+fn unterminated_pod_runs_to_end_of_input() {
+    let source = "1;\n=head1 NAME\n\nsub not_code {}\n";
+    let types = token_types(PerlLexer::with_body_tokens(source));
+
+    assert_eq!(types.last(), Some(&TokenType::Pod));
+    assert!(!types.iter().any(|t| matches!(t, TokenType::Keyword(k) if &**k == "sub")));
+}
+
+#[test]
+fn equals_at_line_start_is_still_an_operator() {
+    let types = token_types(PerlLexer::new("my %h = (a\n=> 1);\nmy $z\n= 2;\n"));
+
+    assert!(!types.contains(&TokenType::Pod));
+    assert!(types.iter().any(|t| matches!(t, TokenType::Operator(op) if &**op == "=")));
+}
+
+#[test]
+fn equals_word_continuing_an_expression_is_not_pod() {
+    let types = token_types(PerlLexer::with_body_tokens("my $x\n=foo;\n"));
+
+    assert!(!types.contains(&TokenType::Pod));
+    assert!(types.iter().any(|t| matches!(t, TokenType::Identifier(name) if &**name == "foo")));
+}
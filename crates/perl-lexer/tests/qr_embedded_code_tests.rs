@@ -0,0 +1,79 @@
+/// Tests for `qr//` interpolation parts and embedded code detection
+/// Verifies that `(?{...})`/`(??{...})` are flagged at tokenization time
+use perl_lexer::{PerlLexer, StringPart, TokenType};
+use std::sync::Arc;
+
+#[test]
+fn test_qr_with_code_block_is_single_flagged_token() {
+    let code = r#"qr/foo(?{die})/"#;
+    let mut lexer = PerlLexer::new(code);
+    let tokens = lexer.collect_tokens();
+
+    assert_eq!(tokens.len(), 2, "Expected qr token and EOF, got: {:?}", tokens);
+    assert_eq!(tokens[0].text.as_ref(), code);
+    assert!(
+        matches!(tokens[0].token_type, TokenType::QuoteRegex { has_embedded_code: true, .. }),
+        "Expected QuoteRegex flagged as containing code, got: {:?}",
+        tokens[0].token_type
+    );
+}
+
+#[test]
+fn test_qr_with_postponed_code_block_is_flagged() {
+    let code = r#"qr{a(??{ $re })b}x"#;
+    let mut lexer = PerlLexer::new(code);
+    let tokens = lexer.collect_tokens();
+
+    assert_eq!(tokens.len(), 2, "Expected qr token and EOF, got: {:?}", tokens);
+    assert_eq!(tokens[0].text.as_ref(), code);
+    assert!(matches!(tokens[0].token_type, TokenType::QuoteRegex { has_embedded_code: true, .. }));
+}
+
+#[test]
+fn test_qr_with_interpolated_regex() {
+    let code = r#"qr/$interp/"#;
+    let mut lexer = PerlLexer::new(code);
+    let tokens = lexer.collect_tokens();
+
+    assert_eq!(tokens.len(), 2, "Expected qr token and EOF, got: {:?}", tokens);
+    assert_eq!(tokens[0].text.as_ref(), code);
+    assert_eq!(
+        tokens[0].token_type,
+        TokenType::QuoteRegex {
+            parts: vec![StringPart::Variable(Arc::from("$interp"))],
+            has_embedded_code: false,
+        }
+    );
+}
+
+#[test]
+fn test_qr_interpolation_keeps_anchor_literal() {
+    let code = r#"qr/^$Foo::prefix\d+$/i"#;
+    let mut lexer = PerlLexer::new(code);
+    let tokens = lexer.collect_tokens();
+
+    assert_eq!(
+        tokens[0].token_type,
+        TokenType::QuoteRegex {
+            parts: vec![
+                StringPart::Literal(Arc::from("^")),
+                StringPart::Variable(Arc::from("$Foo::prefix")),
+                StringPart::Literal(Arc::from("\\d+$")),
+            ],
+            has_embedded_code: false,
+        }
+    );
+}
+
+#[test]
+fn test_plain_qr_is_clean() {
+    let code = r#"qr/\d+/"#;
+    let mut lexer = PerlLexer::new(code);
+    let tokens = lexer.collect_tokens();
+
+    assert_eq!(tokens.len(), 2, "Expected qr token and EOF, got: {:?}", tokens);
+    assert_eq!(
+        tokens[0].token_type,
+        TokenType::QuoteRegex { parts: Vec::new(), has_embedded_code: false }
+    );
+}
@@ -11,7 +11,7 @@ fn test_m_operator_exclamation_delimiter() {
     // Should have RegexMatch token and EOF
     assert_eq!(tokens.len(), 2, "Expected 2 tokens");
     assert!(
-        matches!(tokens[0].token_type, TokenType::RegexMatch),
+        matches!(tokens[0].token_type, TokenType::RegexMatch { .. }),
         "Expected RegexMatch token, got: {:?}",
         tokens[0].token_type
     );
@@ -26,7 +26,7 @@ fn test_m_operator_brace_delimiter() {
 
     assert_eq!(tokens.len(), 2, "Expected 2 tokens");
     assert!(
-        matches!(tokens[0].token_type, TokenType::RegexMatch),
+        matches!(tokens[0].token_type, TokenType::RegexMatch { .. }),
         "Expected RegexMatch token, got: {:?}",
         tokens[0].token_type
     );
@@ -41,7 +41,7 @@ fn test_m_operator_pipe_delimiter() {
 
     assert_eq!(tokens.len(), 2, "Expected 2 tokens");
     assert!(
-        matches!(tokens[0].token_type, TokenType::RegexMatch),
+        matches!(tokens[0].token_type, TokenType::RegexMatch { .. }),
         "Expected RegexMatch token, got: {:?}",
         tokens[0].token_type
     );
@@ -56,7 +56,7 @@ fn test_m_operator_hash_delimiter() {
 
     assert_eq!(tokens.len(), 2, "Expected 2 tokens");
     assert!(
-        matches!(tokens[0].token_type, TokenType::RegexMatch),
+        matches!(tokens[0].token_type, TokenType::RegexMatch { .. }),
         "Expected RegexMatch token, got: {:?}",
         tokens[0].token_type
     );
@@ -71,7 +71,7 @@ fn test_s_operator_exclamation_delimiter() {
 
     assert_eq!(tokens.len(), 2, "Expected 2 tokens");
     assert!(
-        matches!(tokens[0].token_type, TokenType::Substitution),
+        matches!(tokens[0].token_type, TokenType::Substitution { .. }),
         "Expected Substitution token, got: {:?}",
         tokens[0].token_type
     );
@@ -86,7 +86,7 @@ fn test_s_operator_brace_delimiter() {
 
     assert_eq!(tokens.len(), 2, "Expected 2 tokens");
     assert!(
-        matches!(tokens[0].token_type, TokenType::Substitution),
+        matches!(tokens[0].token_type, TokenType::Substitution { .. }),
         "Expected Substitution token, got: {:?}",
         tokens[0].token_type
     );
@@ -101,7 +101,7 @@ fn test_s_operator_pipe_delimiter() {
 
     assert_eq!(tokens.len(), 2, "Expected 2 tokens");
     assert!(
-        matches!(tokens[0].token_type, TokenType::Substitution),
+        matches!(tokens[0].token_type, TokenType::Substitution { .. }),
         "Expected Substitution token, got: {:?}",
         tokens[0].token_type
     );
@@ -116,7 +116,7 @@ fn test_qr_operator_exclamation_delimiter() {
 
     assert_eq!(tokens.len(), 2, "Expected 2 tokens");
     assert!(
-        matches!(tokens[0].token_type, TokenType::QuoteRegex),
+        matches!(tokens[0].token_type, TokenType::QuoteRegex { .. }),
         "Expected QuoteRegex token, got: {:?}",
         tokens[0].token_type
     );
@@ -131,7 +131,7 @@ fn test_tr_operator_exclamation_delimiter() {
 
     assert_eq!(tokens.len(), 2, "Expected 2 tokens");
     assert!(
-        matches!(tokens[0].token_type, TokenType::Transliteration),
+        matches!(tokens[0].token_type, TokenType::Transliteration { .. }),
         "Expected Transliteration token, got: {:?}",
         tokens[0].token_type
     );
@@ -146,7 +146,7 @@ fn test_y_operator_pipe_delimiter() {
 
     assert_eq!(tokens.len(), 2, "Expected 2 tokens");
     assert!(
-        matches!(tokens[0].token_type, TokenType::Transliteration),
+        matches!(tokens[0].token_type, TokenType::Transliteration { .. }),
         "Expected Transliteration token for y alias, got: {:?}",
         tokens[0].token_type
     );
@@ -161,7 +161,7 @@ fn test_nested_braces_in_m_operator() {
 
     assert_eq!(tokens.len(), 2, "Expected 2 tokens");
     assert!(
-        matches!(tokens[0].token_type, TokenType::RegexMatch),
+        matches!(tokens[0].token_type, TokenType::RegexMatch { .. }),
         "Expected RegexMatch token with nested braces, got: {:?}",
         tokens[0].token_type
     );
@@ -176,7 +176,7 @@ fn test_modifiers_attached_to_m_operator() {
 
     assert_eq!(tokens.len(), 2, "Expected 2 tokens (regex + EOF)");
     assert!(
-        matches!(tokens[0].token_type, TokenType::RegexMatch),
+        matches!(tokens[0].token_type, TokenType::RegexMatch { .. }),
         "Expected RegexMatch token, got: {:?}",
         tokens[0].token_type
     );
@@ -192,7 +192,7 @@ fn test_modifiers_attached_to_s_operator() {
 
     assert_eq!(tokens.len(), 2, "Expected 2 tokens (subst + EOF)");
     assert!(
-        matches!(tokens[0].token_type, TokenType::Substitution),
+        matches!(tokens[0].token_type, TokenType::Substitution { .. }),
         "Expected Substitution token, got: {:?}",
         tokens[0].token_type
     );
@@ -203,19 +203,19 @@ fn test_modifiers_attached_to_s_operator() {
 #[test]
 fn test_various_non_standard_delimiters() {
     let test_cases = vec![
-        ("m~pattern~", TokenType::RegexMatch, "tilde"),
-        ("m@pattern@", TokenType::RegexMatch, "at"),
-        ("m%pattern%", TokenType::RegexMatch, "percent"),
-        ("m^pattern^", TokenType::RegexMatch, "caret"),
-        ("m&pattern&", TokenType::RegexMatch, "ampersand"),
-        ("m*pattern*", TokenType::RegexMatch, "asterisk"),
-        ("m-pattern-", TokenType::RegexMatch, "dash"),
-        ("m+pattern+", TokenType::RegexMatch, "plus"),
-        ("m=pattern=", TokenType::RegexMatch, "equals"),
-        ("m:pattern:", TokenType::RegexMatch, "colon"),
-        ("m;pattern;", TokenType::RegexMatch, "semicolon"),
-        ("m,pattern,", TokenType::RegexMatch, "comma"),
-        ("m.pattern.", TokenType::RegexMatch, "dot"),
+        ("m~pattern~", TokenType::RegexMatch { extended: false }, "tilde"),
+        ("m@pattern@", TokenType::RegexMatch { extended: false }, "at"),
+        ("m%pattern%", TokenType::RegexMatch { extended: false }, "percent"),
+        ("m^pattern^", TokenType::RegexMatch { extended: false }, "caret"),
+        ("m&pattern&", TokenType::RegexMatch { extended: false }, "ampersand"),
+        ("m*pattern*", TokenType::RegexMatch { extended: false }, "asterisk"),
+        ("m-pattern-", TokenType::RegexMatch { extended: false }, "dash"),
+        ("m+pattern+", TokenType::RegexMatch { extended: false }, "plus"),
+        ("m=pattern=", TokenType::RegexMatch { extended: false }, "equals"),
+        ("m:pattern:", TokenType::RegexMatch { extended: false }, "colon"),
+        ("m;pattern;", TokenType::RegexMatch { extended: false }, "semicolon"),
+        ("m,pattern,", TokenType::RegexMatch { extended: false }, "comma"),
+        ("m.pattern.", TokenType::RegexMatch { extended: false }, "dot"),
     ];
 
     for (code, expected_type, delim_name) in test_cases {
@@ -240,7 +240,7 @@ fn test_m_vs_bareword_disambiguation() {
     let mut lexer1 = PerlLexer::new(code1);
     let tokens1 = lexer1.collect_tokens();
     assert!(
-        matches!(tokens1[0].token_type, TokenType::RegexMatch),
+        matches!(tokens1[0].token_type, TokenType::RegexMatch { .. }),
         "m followed by ! should be regex match"
     );
 
@@ -0,0 +1,104 @@
+//! Tests for `%`, `*` and `&` disambiguation between sigils and operators
+//!
+//! In `ExpectOperator` mode these characters are binary operators (modulo,
+//! multiplication, bitwise-and); in `ExpectTerm` mode `%` and `*` start a
+//! hash or glob variable.
+
+use perl_lexer::{PerlLexer, TokenType};
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+fn lex(input: &str) -> Vec<TokenType> {
+    let mut lexer = PerlLexer::new(input);
+    let mut tokens = Vec::new();
+    while let Some(token) = lexer.next_token() {
+        if matches!(token.token_type, TokenType::EOF) {
+            break;
+        }
+        tokens.push(token.token_type);
+    }
+    tokens
+}
+
+fn is_operator(token: &TokenType, op: &str) -> bool {
+    matches!(token, TokenType::Operator(text) if text.as_ref() == op)
+}
+
+fn is_identifier(token: &TokenType, name: &str) -> bool {
+    matches!(token, TokenType::Identifier(text) if text.as_ref() == name)
+}
+
+#[test]
+fn modulo_between_scalars() -> TestResult {
+    let tokens = lex("$a % $b");
+    assert_eq!(tokens.len(), 3, "Unexpected tokens: {tokens:?}");
+    assert!(is_identifier(&tokens[0], "$a"));
+    assert!(is_operator(&tokens[1], "%"), "Expected modulo, got {:?}", tokens[1]);
+    assert!(is_identifier(&tokens[2], "$b"));
+    Ok(())
+}
+
+#[test]
+fn modulo_before_number_is_not_a_sigil() -> TestResult {
+    let tokens = lex("$count % 2");
+    assert_eq!(tokens.len(), 3, "Unexpected tokens: {tokens:?}");
+    assert!(is_operator(&tokens[1], "%"), "Expected modulo, got {:?}", tokens[1]);
+    assert!(matches!(tokens[2], TokenType::Number(_)));
+    Ok(())
+}
+
+#[test]
+fn modulo_without_spaces_before_scalar() -> TestResult {
+    let tokens = lex("$x %$y");
+    assert_eq!(tokens.len(), 3, "Unexpected tokens: {tokens:?}");
+    assert!(is_operator(&tokens[1], "%"), "Expected modulo, got {:?}", tokens[1]);
+    assert!(is_identifier(&tokens[2], "$y"));
+    Ok(())
+}
+
+#[test]
+fn hash_in_term_position_is_a_sigil() -> TestResult {
+    let tokens = lex("my %hash = %other;");
+    assert!(is_identifier(&tokens[1], "%hash"), "Expected %hash, got {:?}", tokens[1]);
+    assert!(is_identifier(&tokens[3], "%other"), "Expected %other, got {:?}", tokens[3]);
+    Ok(())
+}
+
+#[test]
+fn hash_deref_in_term_position_is_a_sigil() -> TestResult {
+    let tokens = lex("my %h = %$ref;");
+    assert!(is_operator(&tokens[2], "="));
+    assert!(is_identifier(&tokens[3], "%"), "Expected deref sigil, got {:?}", tokens[3]);
+    assert!(is_identifier(&tokens[4], "$ref"));
+    Ok(())
+}
+
+#[test]
+fn bitwise_and_between_scalars() -> TestResult {
+    let tokens = lex("$x & $y");
+    assert_eq!(tokens.len(), 3, "Unexpected tokens: {tokens:?}");
+    assert!(is_operator(&tokens[1], "&"), "Expected bitwise-and, got {:?}", tokens[1]);
+    assert!(is_identifier(&tokens[2], "$y"));
+    Ok(())
+}
+
+#[test]
+fn multiplication_is_not_a_glob() -> TestResult {
+    let tokens = lex("$x * $y");
+    assert_eq!(tokens.len(), 3, "Unexpected tokens: {tokens:?}");
+    assert!(is_operator(&tokens[1], "*"), "Expected multiplication, got {:?}", tokens[1]);
+    Ok(())
+}
+
+#[test]
+fn hash_deref_after_hash_builtin_is_a_sigil() -> TestResult {
+    let tokens = lex("keys %$ref");
+    assert_eq!(tokens.len(), 3, "Unexpected tokens: {tokens:?}");
+    assert!(is_identifier(&tokens[0], "keys"));
+    assert!(is_identifier(&tokens[1], "%"), "Expected deref sigil, got {:?}", tokens[1]);
+    assert!(is_identifier(&tokens[2], "$ref"));
+
+    let tokens = lex("values %hash");
+    assert!(is_identifier(&tokens[1], "%hash"), "Expected %hash, got {:?}", tokens[1]);
+    Ok(())
+}
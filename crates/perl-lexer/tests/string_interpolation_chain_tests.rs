@@ -0,0 +1,63 @@
+/// Tests for subscript chain interpolation in double-quoted strings
+/// `->{...}`/`->[...]` chains interpolate as one expression; `->method` stays literal
+use perl_lexer::{PerlLexer, StringPart, TokenType};
+use std::sync::Arc;
+
+fn string_parts(code: &str) -> Vec<StringPart> {
+    let mut lexer = PerlLexer::new(code);
+    match lexer.collect_tokens().into_iter().next().map(|t| t.token_type) {
+        Some(TokenType::InterpolatedString(parts)) => parts,
+        _ => Vec::new(),
+    }
+}
+
+fn expression(text: &str) -> StringPart {
+    StringPart::Expression(Arc::from(text))
+}
+
+fn literal(text: &str) -> StringPart {
+    StringPart::Literal(Arc::from(text))
+}
+
+#[test]
+fn test_hash_subscript_chain_is_interpolated() {
+    assert_eq!(string_parts(r#""$h->{a}{b}""#), vec![expression("$h->{a}{b}")]);
+}
+
+#[test]
+fn test_array_index_chain_is_interpolated() {
+    assert_eq!(
+        string_parts(r#""at $a->[0][1]!""#),
+        vec![literal("at "), expression("$a->[0][1]"), literal("!")]
+    );
+}
+
+#[test]
+fn test_chain_stops_before_method_call() {
+    assert_eq!(
+        string_parts(r#""$obj->{k}->method""#),
+        vec![expression("$obj->{k}"), literal("->method")]
+    );
+}
+
+#[test]
+fn test_method_call_alone_is_not_interpolated() {
+    assert_eq!(
+        string_parts(r#""$obj->name""#),
+        vec![StringPart::Variable(Arc::from("$obj")), literal("->name")]
+    );
+}
+
+#[test]
+fn test_unclosed_subscript_does_not_cross_closing_quote() {
+    let code = r#""$h->{a" . "}""#;
+    let mut lexer = PerlLexer::new(code);
+    let tokens = lexer.collect_tokens();
+
+    assert_eq!(tokens[0].text.as_ref(), r#""$h->{a""#);
+    assert!(matches!(
+        &tokens[0].token_type,
+        TokenType::InterpolatedString(parts)
+            if parts.first() == Some(&StringPart::Variable(Arc::from("$h")))
+    ));
+}
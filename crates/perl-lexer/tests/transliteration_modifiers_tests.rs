@@ -0,0 +1,47 @@
+/// Tests for the modifier set carried by `tr///` and `y///` tokens
+use perl_lexer::{PerlLexer, TokenType, TransliterationModifiers};
+
+fn first_modifiers(code: &str) -> Option<TransliterationModifiers> {
+    let mut lexer = PerlLexer::new(code);
+    lexer.collect_tokens().into_iter().find_map(|t| match t.token_type {
+        TokenType::Transliteration { modifiers } => Some(modifiers),
+        _ => None,
+    })
+}
+
+#[test]
+fn test_tr_without_modifiers_mutates_target() {
+    let modifiers = first_modifiers("$x =~ tr/a-z/A-Z/;");
+    assert_eq!(modifiers, Some(TransliterationModifiers::default()));
+    assert!(modifiers.is_some_and(|m| m.mutates_target()));
+}
+
+#[test]
+fn test_tr_cds_modifiers_are_captured() {
+    let modifiers = first_modifiers("$x =~ tr/a-zA-Z//cds;");
+    assert_eq!(
+        modifiers,
+        Some(TransliterationModifiers {
+            complement: true,
+            delete: true,
+            squeeze: true,
+            return_copy: false
+        })
+    );
+}
+
+#[test]
+fn test_tr_r_modifier_returns_copy() {
+    for code in ["my $y = $x =~ tr/a/b/r;", "my $y = $x =~ y{a}{b}r;"] {
+        let modifiers = first_modifiers(code);
+        assert!(modifiers.is_some_and(|m| m.return_copy), "{code}: {modifiers:?}");
+        assert!(modifiers.is_some_and(|m| !m.mutates_target()), "{code}: {modifiers:?}");
+    }
+}
+
+#[test]
+fn test_parse_ignores_unknown_modifiers() {
+    let modifiers = TransliterationModifiers::parse("sxr");
+    assert!(modifiers.squeeze && modifiers.return_copy);
+    assert!(!modifiers.complement && !modifiers.delete);
+}
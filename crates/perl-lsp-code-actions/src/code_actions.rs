@@ -112,7 +112,7 @@ impl CodeActionsProvider {
                     "undefined-variable" | "undeclared-variable" => {
                         actions.extend(quick_fixes::fix_undefined_variable(&self.source, &qf_diag));
                     }
-                    "unused-variable" => {
+                    "unused-variable" | "PL102" => {
                         actions.extend(quick_fixes::fix_unused_variable(&self.source, &qf_diag));
                     }
                     "assignment-in-condition" => {
@@ -121,10 +121,10 @@ impl CodeActionsProvider {
                             &qf_diag,
                         ));
                     }
-                    "missing-strict" => {
+                    "missing-strict" | "PL100" => {
                         actions.extend(quick_fixes::add_use_strict());
                     }
-                    "missing-warnings" => {
+                    "missing-warnings" | "PL101" => {
                         actions.extend(quick_fixes::add_use_warnings());
                     }
                     "deprecated-defined" => {
@@ -133,6 +133,20 @@ impl CodeActionsProvider {
                     "numeric-undef" => {
                         actions.extend(quick_fixes::fix_numeric_undef(&self.source, &qf_diag));
                     }
+                    "comparison-operator-mismatch" => {
+                        actions
+                            .extend(quick_fixes::fix_comparison_operator(&self.source, &qf_diag));
+                    }
+                    "PL400" => {
+                        actions.extend(quick_fixes::fix_bareword_filehandle(
+                            ast,
+                            &self.source,
+                            &qf_diag,
+                        ));
+                    }
+                    "PL401" => {
+                        actions.extend(quick_fixes::fix_two_arg_open(&self.source, &qf_diag));
+                    }
                     "unquoted-bareword" => {
                         actions.extend(quick_fixes::fix_bareword(&self.source, &qf_diag));
                     }
@@ -225,4 +239,34 @@ mod tests {
             actions
         );
     }
+
+    #[test]
+    fn test_comparison_operator_mismatch_fix() {
+        let source = "if ($x == \"abc\") { }\nif ($n eq 5) { }\nif ($s<'a') { }";
+        let mut parser = Parser::new(source);
+        let ast = must(parser.parse());
+
+        let diagnostics = vec![
+            make_diagnostic(7, 9, "comparison-operator-mismatch", "String compared with '=='"),
+            make_diagnostic(28, 30, "comparison-operator-mismatch", "Number compared with 'eq'"),
+            make_diagnostic(44, 45, "comparison-operator-mismatch", "String compared with '<'"),
+        ];
+
+        let provider = CodeActionsProvider::new(source.to_string());
+        let actions = provider.get_code_actions(&ast, (0, 0), &diagnostics);
+        let fixes: Vec<_> = actions
+            .iter()
+            .filter(|a| a.diagnostics == ["comparison-operator-mismatch"])
+            .map(|a| (a.title.as_str(), a.edit.changes[0].new_text.as_str()))
+            .collect();
+
+        assert_eq!(
+            fixes,
+            vec![
+                ("Use 'eq' instead of '=='", "eq"),
+                ("Use '==' instead of 'eq'", "=="),
+                ("Use 'lt' instead of '<'", " lt "),
+            ]
+        );
+    }
 }
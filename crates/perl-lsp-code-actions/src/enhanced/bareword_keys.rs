@@ -0,0 +1,65 @@
+//! Bareword hash key modernization code actions
+
+use crate::types::{CodeAction, CodeActionEdit, CodeActionKind};
+use perl_lsp_rename::TextEdit;
+use perl_parser_core::ast::{Node, NodeKind};
+use perl_parser_core::builtin_signatures_phf::is_builtin;
+
+/// Words that read as an operator or keyword, not a key, when left bare
+const RESERVED_KEYS: &[&str] = &[
+    "q", "qq", "qw", "qr", "qx", "m", "s", "tr", "y", "and", "or", "not", "xor", "cmp", "eq", "ne",
+    "lt", "gt", "le", "ge", "x", "if", "unless", "while", "until", "for", "foreach", "sub", "my",
+    "our", "local", "state", "do", "eval", "return",
+];
+
+/// Rewrite quoted hash subscripts such as `$h->{'name'}` to `$h->{name}` in range
+///
+/// Only keys Perl auto-quotes unambiguously qualify: an identifier that does
+/// not start with a digit, in single quotes or interpolation-free double
+/// quotes, and that is not a builtin or keyword.
+pub fn convert_to_bareword_keys(
+    ast: &Node,
+    source: &str,
+    range: (usize, usize),
+) -> Vec<CodeAction> {
+    let mut actions = Vec::new();
+    collect(ast, source, range, &mut actions);
+    actions
+}
+
+fn collect(node: &Node, source: &str, range: (usize, usize), actions: &mut Vec<CodeAction>) {
+    if let NodeKind::Binary { op, right, .. } = &node.kind
+        && op == "{}"
+        && right.location.start <= range.1
+        && right.location.end >= range.0
+        && let Some(key) = bareword_key(right, source)
+    {
+        actions.push(CodeAction {
+            title: format!("Use bareword hash key '{}'", key),
+            kind: CodeActionKind::RefactorRewrite,
+            diagnostics: Vec::new(),
+            edit: CodeActionEdit {
+                changes: vec![TextEdit { location: right.location, new_text: key.to_string() }],
+            },
+            is_preferred: false,
+        });
+    }
+
+    node.for_each_child(|child| collect(child, source, range, actions));
+}
+
+/// The unquoted key if `node` is a string literal safe to write bare
+fn bareword_key<'a>(node: &Node, source: &'a str) -> Option<&'a str> {
+    if !matches!(node.kind, NodeKind::String { .. }) {
+        return None;
+    }
+    let text = source.get(node.location.start..node.location.end)?;
+    let key = text
+        .strip_prefix('\'')
+        .and_then(|rest| rest.strip_suffix('\''))
+        .or_else(|| text.strip_prefix('"').and_then(|rest| rest.strip_suffix('"')))?;
+
+    let is_identifier = key.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    (is_identifier && !is_builtin(key) && !RESERVED_KEYS.contains(&key)).then_some(key)
+}
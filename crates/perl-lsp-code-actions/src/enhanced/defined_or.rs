@@ -0,0 +1,83 @@
+//! Defined-or modernization code actions
+
+use crate::types::{CodeAction, CodeActionEdit, CodeActionKind};
+use perl_lsp_rename::TextEdit;
+use perl_parser_core::ast::{Node, NodeKind};
+use perl_parser_core::pragma_tracker::{PragmaState, PragmaTracker};
+use std::ops::Range;
+
+/// Rewrite `defined($x) ? $x : $default` to `$x // $default` in range
+///
+/// Only fires when the true branch is the same expression as the `defined`
+/// operand and `//` is available where the ternary appears.
+pub fn convert_to_defined_or(ast: &Node, source: &str, range: (usize, usize)) -> Vec<CodeAction> {
+    let pragma_map = PragmaTracker::build(ast);
+    let mut actions = Vec::new();
+    collect(ast, source, range, &pragma_map, &mut actions);
+    actions
+}
+
+fn collect(
+    node: &Node,
+    source: &str,
+    range: (usize, usize),
+    pragma_map: &[(Range<usize>, PragmaState)],
+    actions: &mut Vec<CodeAction>,
+) {
+    if node.location.start <= range.1
+        && node.location.end >= range.0
+        && let Some(action) = defined_or_action(node, source, pragma_map)
+    {
+        actions.push(action);
+    }
+
+    node.for_each_child(|child| collect(child, source, range, pragma_map, actions));
+}
+
+fn defined_or_action(
+    node: &Node,
+    source: &str,
+    pragma_map: &[(Range<usize>, PragmaState)],
+) -> Option<CodeAction> {
+    let NodeKind::Ternary { condition, then_expr, else_expr } = &node.kind else {
+        return None;
+    };
+    let NodeKind::FunctionCall { name, args } = &condition.kind else {
+        return None;
+    };
+    if name != "defined" || args.len() != 1 || args[0].to_sexp() != then_expr.to_sexp() {
+        return None;
+    }
+    if !PragmaTracker::state_for_offset(pragma_map, node.location.start).defined_or_available() {
+        return None;
+    }
+
+    let value = operand_text(then_expr, source)?;
+    let default = operand_text(else_expr, source)?;
+
+    Some(CodeAction {
+        title: "Use defined-or operator (//)".to_string(),
+        kind: CodeActionKind::RefactorRewrite,
+        diagnostics: Vec::new(),
+        edit: CodeActionEdit {
+            changes: vec![TextEdit {
+                location: node.location,
+                new_text: format!("{} // {}", value, default),
+            }],
+        },
+        is_preferred: false,
+    })
+}
+
+/// Source of an operand of `//`, parenthesized if it binds no tighter than `//`
+fn operand_text(node: &Node, source: &str) -> Option<String> {
+    let text = source.get(node.location.start..node.location.end)?;
+    let loose = match &node.kind {
+        NodeKind::Binary { op, .. } => {
+            matches!(op.as_str(), "||" | "//" | ".." | "..." | "and" | "or" | "xor")
+        }
+        NodeKind::Ternary { .. } | NodeKind::Assignment { .. } => true,
+        _ => false,
+    };
+    Some(if loose { format!("({})", text) } else { text.to_string() })
+}
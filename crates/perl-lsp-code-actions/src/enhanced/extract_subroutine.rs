@@ -1,95 +1,546 @@
 //! Extract subroutine code action
+//!
+//! Backs both the `refactor.extract` code action and the LSP server's
+//! `perl.extractSubroutine` command. The selection must cover whole
+//! statements in one block. Variables the statements use without declaring
+//! become parameters; variables they assign or declare that are still used
+//! afterwards are returned and assigned back at the call site. Arrays and
+//! hashes are passed by reference and copied on entry, so extracting code that
+//! modifies one the caller still needs is rejected.
 
 use crate::types::{CodeAction, CodeActionEdit, CodeActionKind};
 use perl_lsp_rename::TextEdit;
 use perl_parser_core::ast::{Node, NodeKind, SourceLocation};
-use std::collections::HashSet;
+use std::ops::Range;
 
-use super::helpers::Helpers;
+/// Base name for the generated subroutine; suffixed when already taken
+const EXTRACTED_NAME: &str = "extracted";
 
-/// Create extract subroutine action
+/// Result of a successful extraction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtractedSubroutine {
+    /// Name given to the new subroutine
+    pub name: String,
+    /// Replace the selection with a call, then insert the definition
+    pub edits: Vec<TextEdit>,
+}
+
+/// Create the extract subroutine action for the selected `range`
+///
+/// Returns `None` when the selection cannot be extracted.
 pub fn create_extract_subroutine_action(
-    node: &Node,
+    ast: &Node,
     source: &str,
-    helpers: &Helpers<'_>,
-) -> CodeAction {
-    let body_text = &source[node.location.start..node.location.end];
-    let sub_name = suggest_subroutine_name(node);
-    let params = detect_parameters(node);
-    let returns = detect_return_values(node);
-
-    // Generate function signature
-    let signature = if params.is_empty() {
-        format!("sub {} {{\n", sub_name)
-    } else {
-        format!("sub {} {{\n    my ({}) = @_;\n", sub_name, params.join(", "))
+    range: (usize, usize),
+) -> Option<CodeAction> {
+    let extracted = extract_subroutine(source, ast, range.0..range.1).ok()?;
+    Some(CodeAction {
+        title: format!("Extract to subroutine '{}'", extracted.name),
+        kind: CodeActionKind::RefactorExtract,
+        diagnostics: Vec::new(),
+        edit: CodeActionEdit { changes: extracted.edits },
+        is_preferred: false,
+    })
+}
+
+/// Extract the statements selected by `selection` in `source` into a new sub
+///
+/// Returns a user-facing message when the selection does not line up with
+/// whole statements or cannot be moved into a subroutine safely.
+pub fn extract_subroutine(
+    source: &str,
+    ast: &Node,
+    selection: Range<usize>,
+) -> Result<ExtractedSubroutine, String> {
+    let selection = trim_selection(source, selection)
+        .ok_or_else(|| "Select one or more statements to extract".to_string())?;
+    let statements = find_statements(ast, source, &selection)?;
+    let (Some(first), Some(last)) = (statements.first(), statements.last()) else {
+        return Err("Select one or more statements to extract".to_string());
     };
+    let extracted = statement_span(first, source).start..statement_span(last, source).end;
+
+    if statements.iter().any(|stmt| contains_return(stmt)) {
+        return Err("Cannot extract statements that contain `return`".to_string());
+    }
+
+    let usage = analyze_variables(&statements, source);
+    let container = enclosing_container(ast, &extracted).unwrap_or(ast);
+    let outputs = live_outputs(container, source, &usage, extracted.end);
+    if let Some(aggregate) = outputs.iter().find(|var| !var.starts_with('$')) {
+        return Err(format!(
+            "Cannot extract: {aggregate} is modified and used after the selection"
+        ));
+    }
 
-    // Find insertion position (before current sub or at end)
-    let insert_pos = helpers.find_subroutine_insert_position(node.location.start);
+    let name = unused_name(source);
+    let enclosing_sub = enclosing_named_sub(ast, &extracted);
 
-    // Generate function call
-    let call = if returns.is_empty() {
-        format!("{}({});", sub_name, params.join(", "))
+    let call = render_call(&name, &usage, &outputs);
+    let (insert_at, indent) = match enclosing_sub {
+        Some(sub) => (span(sub).end, line_indent(source, span(sub).start)),
+        None => (end_of_code(source), ""),
+    };
+    let definition = render_definition(
+        &name,
+        &usage.params,
+        &outputs,
+        &source[extracted.clone()],
+        line_indent(source, extracted.start),
+        indent,
+    );
+    let definition = if enclosing_sub.is_some() || !source[..insert_at].ends_with('\n') {
+        format!("\n\n{definition}")
     } else {
-        format!("my {} = {}({});", returns.join(", "), sub_name, params.join(", "))
+        format!("\n{definition}\n")
     };
 
-    CodeAction {
-        title: "Extract to subroutine".to_string(),
-        kind: CodeActionKind::RefactorExtract,
-        diagnostics: Vec::new(),
-        edit: CodeActionEdit {
-            changes: vec![
-                // Insert function definition
-                TextEdit {
-                    location: SourceLocation { start: insert_pos, end: insert_pos },
-                    new_text: format!("{}{}\n}}\n\n", signature, body_text),
-                },
-                // Replace block with function call
-                TextEdit { location: node.location, new_text: call },
-            ],
-        },
-        is_preferred: false,
+    Ok(ExtractedSubroutine {
+        name,
+        edits: vec![
+            TextEdit {
+                location: SourceLocation { start: extracted.start, end: extracted.end },
+                new_text: call,
+            },
+            TextEdit {
+                location: SourceLocation { start: insert_at, end: insert_at },
+                new_text: definition,
+            },
+        ],
+    })
+}
+
+/// Shrink `selection` to exclude surrounding whitespace
+fn trim_selection(source: &str, selection: Range<usize>) -> Option<Range<usize>> {
+    let end = selection.end.min(source.len());
+    let start = selection.start.min(end);
+    let text = source.get(start..end)?;
+    let leading = text.len() - text.trim_start().len();
+    let trailing = text.len() - text.trim_end().len();
+    (leading < text.len()).then(|| start + leading..end - trailing)
+}
+
+/// The statements of the innermost block that the selection covers exactly
+fn find_statements<'a>(
+    node: &'a Node,
+    source: &str,
+    selection: &Range<usize>,
+) -> Result<Vec<&'a Node>, String> {
+    let partial = || "Selection must cover whole statements".to_string();
+
+    match &node.kind {
+        NodeKind::Program { statements } | NodeKind::Block { statements } => {
+            let overlapping: Vec<&Node> = statements
+                .iter()
+                .filter(|stmt| {
+                    let stmt = statement_span(stmt, source);
+                    stmt.start < selection.end && selection.start < stmt.end
+                })
+                .collect();
+
+            let covered = overlapping.iter().all(|stmt| {
+                let stmt = statement_span(stmt, source);
+                selection.start <= stmt.start && stmt.end <= selection.end
+            });
+            match overlapping.as_slice() {
+                [] => Err(partial()),
+                _ if covered => Ok(overlapping),
+                // Descends into a bare block as well as into compound statements
+                [only] => find_statements(only, source, selection),
+                _ => Err(partial()),
+            }
+        }
+        _ => find_in_children(node, source, selection).unwrap_or_else(|| Err(partial())),
     }
 }
 
-/// Suggest a subroutine name
-pub fn suggest_subroutine_name(_node: &Node) -> String {
-    // Could analyze the code to suggest better names
-    "process_data".to_string()
+fn find_in_children<'a>(
+    node: &'a Node,
+    source: &str,
+    selection: &Range<usize>,
+) -> Option<Result<Vec<&'a Node>, String>> {
+    node.children().into_iter().find_map(|child| {
+        let child_span = span(child);
+        (child_span.start <= selection.start && selection.end <= child_span.end)
+            .then(|| find_statements(child, source, selection))
+    })
+}
+
+/// Byte span of `node` and everything beneath it
+///
+/// Some statement nodes record only their leading part, so the span is widened
+/// to cover every descendant.
+fn span(node: &Node) -> Range<usize> {
+    let mut range = node.location.start..node.location.end;
+    node.for_each_child(|child| {
+        let child = span(child);
+        range.start = range.start.min(child.start);
+        range.end = range.end.max(child.end);
+    });
+    range
 }
 
-/// Detect parameters used in a block
-pub fn detect_parameters(node: &Node) -> Vec<String> {
-    let mut params = HashSet::new();
-    collect_variables(node, &mut params);
-    params.into_iter().collect()
+/// [`span`] extended over the statement's terminating semicolon
+fn statement_span(node: &Node, source: &str) -> Range<usize> {
+    let range = span(node);
+    let rest = source.get(range.end..).unwrap_or("");
+    let after_ws = rest.len() - rest.trim_start().len();
+    if rest[after_ws..].starts_with(';') { range.start..range.end + after_ws + 1 } else { range }
 }
 
-/// Detect return values in a block
-pub fn detect_return_values(_node: &Node) -> Vec<String> {
-    // For now, return empty - could analyze return statements
-    Vec::new()
+fn contains_return(node: &Node) -> bool {
+    if matches!(node.kind, NodeKind::Return { .. }) {
+        return true;
+    }
+    let mut found = false;
+    node.for_each_child(|child| found = found || contains_return(child));
+    found
+}
+
+/// How the selected statements use variables, in order of first appearance
+#[derive(Debug, Default)]
+struct VariableUsage {
+    /// Used before any declaration in the selection
+    params: Vec<String>,
+    /// Declared with `my`/`state`/`our` in the selection
+    declared: Vec<String>,
+    /// Assigned or incremented in the selection
+    written: Vec<String>,
+}
+
+fn analyze_variables(statements: &[&Node], source: &str) -> VariableUsage {
+    let mut usage = VariableUsage::default();
+    for stmt in statements {
+        collect_usage(stmt, source, &mut usage);
+    }
+    usage
 }
 
-/// Collect variables used in a node
-#[allow(clippy::only_used_in_recursion)]
-pub fn collect_variables(node: &Node, vars: &mut HashSet<String>) {
+fn collect_usage(node: &Node, source: &str, usage: &mut VariableUsage) {
     match &node.kind {
-        NodeKind::Variable { name, .. } => {
-            let var_name = name.as_str();
-            vars.insert(var_name.to_string());
+        NodeKind::VariableDeclaration { variable, initializer, .. } => {
+            if let Some(init) = initializer {
+                collect_usage(init, source, usage);
+            }
+            push_unique(&mut usage.declared, variable_name(variable, source));
+        }
+        NodeKind::VariableListDeclaration { variables, initializer, .. } => {
+            if let Some(init) = initializer {
+                collect_usage(init, source, usage);
+            }
+            for variable in variables {
+                push_unique(&mut usage.declared, variable_name(variable, source));
+            }
+        }
+        NodeKind::Assignment { lhs, rhs, .. } => {
+            collect_usage(rhs, source, usage);
+            collect_written(lhs, source, usage);
+            collect_usage(lhs, source, usage);
+        }
+        NodeKind::Unary { op, operand } if is_increment(op) => {
+            collect_written(operand, source, usage);
+            collect_usage(operand, source, usage);
         }
-        NodeKind::Block { statements } => {
-            for stmt in statements {
-                collect_variables(stmt, vars);
+        _ => match variable_name(node, source) {
+            Some(name) => {
+                if !usage.declared.contains(&name) && !is_special(&name) {
+                    push_unique(&mut usage.params, Some(name));
+                }
+                // Element access: the subscript may use other variables
+                if let NodeKind::Binary { right, .. } = &node.kind {
+                    collect_usage(right, source, usage);
+                }
             }
+            None => node.for_each_child(|child| collect_usage(child, source, usage)),
+        },
+    }
+}
+
+fn collect_written(node: &Node, source: &str, usage: &mut VariableUsage) {
+    if let Some(name) = variable_name(node, source) {
+        push_unique(&mut usage.written, Some(name));
+        return;
+    }
+    node.for_each_child(|child| collect_written(child, source, usage));
+}
+
+fn is_increment(op: &str) -> bool {
+    op.contains("++") || op.contains("--")
+}
+
+fn push_unique(names: &mut Vec<String>, name: Option<String>) {
+    if let Some(name) = name
+        && !names.contains(&name)
+    {
+        names.push(name);
+    }
+}
+
+/// The variable `node` names, with element access mapped to its aggregate
+///
+/// `$items[0]` names `@items` and `$opts{key}` names `%opts`, while
+/// `$ref->[0]` names `$ref`.
+fn variable_name(node: &Node, source: &str) -> Option<String> {
+    match &node.kind {
+        NodeKind::Variable { sigil, name } => Some(format!("{sigil}{name}")),
+        NodeKind::Binary { op, left, .. } if op == "[]" || op == "{}" => match &left.kind {
+            NodeKind::Variable { sigil, name } if sigil == "$" => {
+                let after = source.get(left.location.end..).unwrap_or("");
+                if after.trim_start().starts_with("->") {
+                    return Some(format!("${name}"));
+                }
+                Some(format!("{}{name}", if op == "[]" { '@' } else { '%' }))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Punctuation, numbered, and package variables are never parameters
+fn is_special(name: &str) -> bool {
+    let bare = &name[1..];
+    !bare.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        || bare == "_"
+        || bare.contains("::")
+        || matches!(bare, "ENV" | "ARGV" | "INC" | "STDIN" | "STDOUT" | "STDERR" | "ARGVOUT")
+}
+
+/// Innermost sub body or the program that holds `range`
+fn enclosing_container<'a>(node: &'a Node, range: &Range<usize>) -> Option<&'a Node> {
+    let node_span = span(node);
+    if node_span.start > range.start || range.end > node_span.end {
+        return None;
+    }
+    let inner = node.children().into_iter().find_map(|child| enclosing_container(child, range));
+    match node.kind {
+        NodeKind::Subroutine { .. } | NodeKind::Method { .. } | NodeKind::Program { .. } => {
+            inner.or(Some(node))
+        }
+        _ => inner,
+    }
+}
+
+fn enclosing_named_sub<'a>(node: &'a Node, range: &Range<usize>) -> Option<&'a Node> {
+    let node_span = span(node);
+    if node_span.start > range.start || range.end > node_span.end {
+        return None;
+    }
+    let inner = node.children().into_iter().find_map(|child| enclosing_named_sub(child, range));
+    match &node.kind {
+        NodeKind::Subroutine { name: Some(_), .. } | NodeKind::Method { .. } => {
+            inner.or(Some(node))
+        }
+        _ => inner,
+    }
+}
+
+/// Declared or written variables that `container` reads after `after`
+fn live_outputs(
+    container: &Node,
+    source: &str,
+    usage: &VariableUsage,
+    after: usize,
+) -> Vec<String> {
+    let mut used_after = Vec::new();
+    collect_used_after(container, source, after, &mut used_after);
+
+    usage
+        .declared
+        .iter()
+        .chain(usage.written.iter().filter(|name| !usage.declared.contains(name)))
+        .filter(|name| used_after.contains(name))
+        .cloned()
+        .collect()
+}
+
+fn collect_used_after(node: &Node, source: &str, after: usize, names: &mut Vec<String>) {
+    if span(node).end <= after {
+        return;
+    }
+    if node.location.start >= after {
+        push_unique(names, variable_name(node, source));
+    }
+    node.for_each_child(|child| collect_used_after(child, source, after, names));
+}
+
+/// First of `extracted`, `extracted_2`, ... not already defined in `source`
+fn unused_name(source: &str) -> String {
+    let taken = |name: &str| {
+        let needle = format!("sub {name}");
+        source.match_indices(&needle).any(|(at, _)| {
+            !source[at + needle.len()..]
+                .starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_')
+        })
+    };
+
+    let mut name = EXTRACTED_NAME.to_string();
+    let mut suffix = 2;
+    while taken(&name) {
+        name = format!("{EXTRACTED_NAME}_{suffix}");
+        suffix += 1;
+    }
+    name
+}
+
+/// Leading whitespace of the line containing `offset`
+fn line_indent(source: &str, offset: usize) -> &str {
+    let line_start = source[..offset].rfind('\n').map_or(0, |at| at + 1);
+    let line = &source[line_start..];
+    &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+}
+
+/// Where a top-level sub goes: the end of the file, ahead of any data section
+fn end_of_code(source: &str) -> usize {
+    ["\n__END__", "\n__DATA__"]
+        .iter()
+        .filter_map(|marker| source.find(marker))
+        .min()
+        .map_or(source.len(), |at| at + 1)
+}
+
+fn render_call(name: &str, usage: &VariableUsage, outputs: &[String]) -> String {
+    let args: Vec<String> = usage
+        .params
+        .iter()
+        .map(|param| if param.starts_with('$') { param.clone() } else { format!("\\{param}") })
+        .collect();
+    let call = format!("{name}({});", args.join(", "));
+
+    if outputs.is_empty() {
+        return call;
+    }
+    let all_declared = outputs.iter().all(|var| usage.declared.contains(var));
+    if all_declared {
+        return format!("my ({}) = {call}", outputs.join(", "));
+    }
+    let targets: Vec<String> = outputs
+        .iter()
+        .map(|var| if usage.declared.contains(var) { format!("my {var}") } else { var.clone() })
+        .collect();
+    format!("({}) = {call}", targets.join(", "))
+}
+
+fn render_definition(
+    name: &str,
+    params: &[String],
+    outputs: &[String],
+    body: &str,
+    body_indent: &str,
+    indent: &str,
+) -> String {
+    let inner = format!("{indent}    ");
+    let mut lines = vec![format!("{indent}sub {name} {{")];
+
+    if !params.is_empty() {
+        let names: Vec<String> = params.iter().map(|param| param_name(param)).collect();
+        lines.push(format!("{inner}my ({}) = @_;", names.join(", ")));
+        for param in params.iter().filter(|param| !param.starts_with('$')) {
+            let sigil = &param[..1];
+            lines.push(format!("{inner}my {param} = {sigil}{{{}}};", param_name(param)));
         }
-        NodeKind::Binary { left, right, .. } => {
-            collect_variables(left, vars);
-            collect_variables(right, vars);
+    }
+
+    for (index, line) in body.lines().enumerate() {
+        let line = if index == 0 { line } else { line.strip_prefix(body_indent).unwrap_or(line) };
+        lines.push(if line.trim().is_empty() { String::new() } else { format!("{inner}{line}") });
+    }
+
+    if !outputs.is_empty() {
+        lines.push(format!("{inner}return ({});", outputs.join(", ")));
+    }
+    lines.push(format!("{indent}}}"));
+    lines.join("\n")
+}
+
+/// Scalar name a parameter arrives in: aggregates come as `$name_ref`
+fn param_name(param: &str) -> String {
+    if param.starts_with('$') { param.to_string() } else { format!("${}_ref", &param[1..]) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use perl_parser_core::Parser;
+    use perl_tdd_support::must;
+
+    fn extract(source: &str, selected: &str) -> Result<ExtractedSubroutine, String> {
+        let ast = must(Parser::new(source).parse());
+        let start = must(source.find(selected).ok_or("selection not in source"));
+        extract_subroutine(source, &ast, start..start + selected.len())
+    }
+
+    fn apply(source: &str, extracted: &ExtractedSubroutine) -> String {
+        let mut out = source.to_string();
+        let mut edits = extracted.edits.clone();
+        edits.sort_by_key(|edit| std::cmp::Reverse(edit.location.start));
+        for edit in edits {
+            out.replace_range(edit.location.start..edit.location.end, &edit.new_text);
         }
-        _ => {}
+        out
+    }
+
+    #[test]
+    fn declared_value_used_later_is_returned() {
+        let source = "my $n = 2;\nmy $sq = $n * $n;\nprint $sq;\n";
+        let extracted = must(extract(source, "my $sq = $n * $n;"));
+
+        assert_eq!(
+            apply(source, &extracted),
+            "my $n = 2;\nmy ($sq) = extracted($n);\nprint $sq;\n\n\
+             sub extracted {\n    my ($n) = @_;\n    my $sq = $n * $n;\n    return ($sq);\n}\n"
+        );
+    }
+
+    #[test]
+    fn arrays_are_passed_by_reference() {
+        let source = "my @items = (1, 2);\nprint scalar(@items);\nmy $first = $items[0];\n";
+        let extracted = must(extract(source, "print scalar(@items);\nmy $first = $items[0];"));
+
+        assert_eq!(extracted.edits[0].new_text, "extracted(\\@items);");
+        assert!(
+            extracted.edits[1]
+                .new_text
+                .contains("    my ($items_ref) = @_;\n    my @items = @{$items_ref};\n"),
+            "{:?}",
+            extracted.edits[1].new_text
+        );
+    }
+
+    #[test]
+    fn statements_of_a_bare_block_are_extracted() {
+        let source = "my $x = 5;\n{\n    my $t = $x + 1;\n    print $t;\n}\n";
+        let extracted = must(extract(source, "my $t = $x + 1;\n    print $t;\n}"));
+
+        assert_eq!(
+            apply(source, &extracted),
+            "my $x = 5;\n{\n    extracted($x);\n}\n\n\
+             sub extracted {\n    my ($x) = @_;\n    my $t = $x + 1;\n    print $t;\n}\n"
+        );
+    }
+
+    #[test]
+    fn code_action_uses_the_shared_extraction() {
+        let source = "my $n = 2;\nmy $sq = $n * $n;\nprint $sq;\n";
+        let ast = must(Parser::new(source).parse());
+        let start = must(source.find("my $sq").ok_or("selection not in source"));
+        let range = (start, start + "my $sq = $n * $n;".len());
+
+        let action = must(
+            create_extract_subroutine_action(&ast, source, range).ok_or("no extract action"),
+        );
+        let extracted = must(extract_subroutine(source, &ast, range.0..range.1));
+
+        assert_eq!(action.title, "Extract to subroutine 'extracted'");
+        assert_eq!(action.edit.changes, extracted.edits);
+    }
+
+    #[test]
+    fn return_in_selection_is_rejected() {
+        let source = "sub f {\n    my $x = shift;\n    return $x;\n}\n";
+        assert_eq!(
+            extract(source, "return $x;"),
+            Err("Cannot extract statements that contain `return`".to_string())
+        );
     }
 }
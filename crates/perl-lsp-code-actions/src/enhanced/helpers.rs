@@ -24,22 +24,6 @@ impl<'a> Helpers<'a> {
         0
     }
 
-    /// Find subroutine insertion position
-    pub fn find_subroutine_insert_position(&self, current_pos: usize) -> usize {
-        // Find the current subroutine
-        let mut pos = current_pos;
-        while pos > 0 {
-            if self.source[pos.saturating_sub(4)..pos].starts_with("sub ") {
-                // Found a sub, insert before it
-                return pos.saturating_sub(4);
-            }
-            pos = pos.saturating_sub(1);
-        }
-
-        // No sub found, insert at end
-        self.source.len()
-    }
-
     /// Find pragma insertion position
     pub fn find_pragma_insert_position(&self) -> usize {
         // After shebang if present
@@ -51,22 +35,6 @@ impl<'a> Helpers<'a> {
         0
     }
 
-    /// Find import insertion position
-    pub fn find_import_insert_position(&self) -> usize {
-        // After existing pragmas
-        let mut pos = self.find_pragma_insert_position();
-
-        for line in self.lines.iter() {
-            if line.starts_with("use ") || line.starts_with("require ") {
-                pos = self.source.find(line).unwrap_or(0) + line.len() + 1;
-            } else if !line.is_empty() && !line.starts_with('#') {
-                break;
-            }
-        }
-
-        pos
-    }
-
     /// Get indentation at position
     pub fn get_indent_at(&self, pos: usize) -> String {
         let line_start = self.source[..pos].rfind('\n').map(|p| p + 1).unwrap_or(0);
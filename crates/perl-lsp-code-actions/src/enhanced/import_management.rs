@@ -3,41 +3,63 @@
 use crate::types::{CodeAction, CodeActionEdit, CodeActionKind};
 use perl_lsp_rename::TextEdit;
 use perl_parser_core::ast::{Node, SourceLocation};
+use perl_refactoring::import_optimizer::ImportOptimizer;
 
 use super::helpers::Helpers;
 
-/// Add missing imports for undefined functions
-pub fn add_missing_imports(ast: &Node, _source: &str, helpers: &Helpers<'_>) -> Option<CodeAction> {
-    let undefined = find_undefined_functions(ast);
-    if undefined.is_empty() {
+/// Add imports for calls to well-known module exports that nothing imports
+///
+/// Symbols are merged into an existing `use Module qw(...)` list when there
+/// is one; otherwise a new `use` line goes after the existing imports.
+pub fn add_missing_imports(source: &str) -> Option<CodeAction> {
+    let optimizer = ImportOptimizer::new();
+    let analysis = optimizer.analyze_content(source).ok()?;
+    let edits = optimizer.generate_missing_import_edits(source, &analysis);
+    if edits.is_empty() {
         return None;
     }
 
-    let mut imports = Vec::new();
-
-    // Map common functions to their modules
-    for func in &undefined {
-        if let Some(module) = guess_module_for_function(func) {
-            imports.push(format!("use {};", module));
-        }
-    }
+    Some(CodeAction {
+        title: "Add missing imports".to_string(),
+        kind: CodeActionKind::QuickFix,
+        diagnostics: Vec::new(),
+        edit: CodeActionEdit {
+            changes: edits
+                .into_iter()
+                .map(|edit| TextEdit {
+                    location: SourceLocation { start: edit.range.0, end: edit.range.1 },
+                    new_text: edit.new_text,
+                })
+                .collect(),
+        },
+        is_preferred: false,
+    })
+}
 
-    if imports.is_empty() {
+/// Remove unused symbols from `use Module qw(...)` imports
+///
+/// Trims each import list to the symbols the file uses, and removes a `use`
+/// statement outright when none of its symbols are used.
+pub fn remove_unused_imports(source: &str) -> Option<CodeAction> {
+    let optimizer = ImportOptimizer::new();
+    let analysis = optimizer.analyze_content(source).ok()?;
+    let edits = optimizer.generate_unused_import_edits(source, &analysis);
+    if edits.is_empty() {
         return None;
     }
 
-    // Find insert position (after shebang and existing pragmas)
-    let insert_pos = helpers.find_import_insert_position();
-
     Some(CodeAction {
-        title: "Add missing imports".to_string(),
+        title: "Remove unused imports".to_string(),
         kind: CodeActionKind::QuickFix,
         diagnostics: Vec::new(),
         edit: CodeActionEdit {
-            changes: vec![TextEdit {
-                location: SourceLocation { start: insert_pos, end: insert_pos },
-                new_text: format!("{}\n", imports.join("\n")),
-            }],
+            changes: edits
+                .into_iter()
+                .map(|edit| TextEdit {
+                    location: SourceLocation { start: edit.range.0, end: edit.range.1 },
+                    new_text: edit.new_text,
+                })
+                .collect(),
         },
         is_preferred: false,
     })
@@ -72,27 +94,6 @@ pub fn organize_imports(_ast: &Node, source: &str, helpers: &Helpers<'_>) -> Opt
     None
 }
 
-/// Find undefined functions in the AST
-pub fn find_undefined_functions(_ast: &Node) -> Vec<String> {
-    // This would require full semantic analysis
-    // For now, return empty
-    Vec::new()
-}
-
-/// Guess module for a function
-pub fn guess_module_for_function(func: &str) -> Option<String> {
-    match func {
-        "dumper" => Some("Data::Dumper"),
-        "encode" | "decode" => Some("Encode"),
-        "basename" | "dirname" => Some("File::Basename"),
-        "mkpath" | "rmtree" => Some("File::Path"),
-        "slurp" => Some("File::Slurp"),
-        "decode_json" | "encode_json" => Some("JSON"),
-        _ => None,
-    }
-    .map(|s| s.to_string())
-}
-
 /// Collect all import statements
 pub fn collect_imports(lines: &Vec<String>) -> Vec<String> {
     let mut imports = Vec::new();
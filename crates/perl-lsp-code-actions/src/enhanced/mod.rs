@@ -8,10 +8,12 @@
 //! Enhanced actions are organized into focused submodules:
 //!
 //! - **extract_variable**: Extract selected expression into a named variable
-//! - **extract_subroutine**: Extract code block into a new subroutine
+//! - **extract_subroutine**: Extract whole statements into a new subroutine
 //! - **loop_conversion**: Convert between loop styles (for/foreach/while)
 //! - **import_management**: Organize and add/remove use statements
 //! - **postfix**: Postfix completion-style actions (e.g., `.if`, `.unless`)
+//! - **defined_or**: Rewrite `defined($x) ? $x : $y` to `$x // $y`
+//! - **bareword_keys**: Rewrite `$h->{'key'}` to `$h->{key}`
 //! - **error_checking**: Add error handling around expressions
 //! - **helpers**: Shared utilities for text manipulation and position mapping
 //!
@@ -20,7 +22,7 @@
 //! Actions are categorized following LSP CodeActionKind:
 //!
 //! - **refactor.extract**: Extract variable, extract subroutine
-//! - **refactor.rewrite**: Loop conversion, error wrapping
+//! - **refactor.rewrite**: Loop conversion, error wrapping, defined-or, bareword keys
 //! - **source.organizeImports**: Import management
 //!
 //! # Performance Characteristics
@@ -32,10 +34,14 @@
 use crate::types::CodeAction;
 use perl_parser_core::ast::{Node, NodeKind};
 
+mod bareword_keys;
+mod defined_or;
 mod error_checking;
 mod extract_subroutine;
 mod extract_variable;
 mod helpers;
+
+pub use extract_subroutine::{ExtractedSubroutine, extract_subroutine};
 mod import_management;
 mod loop_conversion;
 mod postfix;
@@ -61,11 +67,19 @@ impl EnhancedCodeActionsProvider {
         ast: &Node,
         range: (usize, usize),
     ) -> Vec<CodeAction> {
-        let mut actions = Vec::new();
+        // Extract subroutine works on the statements the selection covers
+        let mut actions: Vec<CodeAction> =
+            extract_subroutine::create_extract_subroutine_action(ast, &self.source, range)
+                .into_iter()
+                .collect();
 
         // Find all nodes that overlap the range and collect actions
         self.collect_actions_for_range(ast, range, &mut actions);
 
+        // Defined-or needs the pragma state at each ternary
+        actions.extend(defined_or::convert_to_defined_or(ast, &self.source, range));
+        actions.extend(bareword_keys::convert_to_bareword_keys(ast, &self.source, range));
+
         // Global actions (not node-specific)
         actions.extend(self.get_global_refactorings(ast));
 
@@ -106,15 +120,6 @@ impl EnhancedCodeActionsProvider {
             if let Some(action) = postfix::convert_to_postfix(node, &self.source) {
                 actions.push(action);
             }
-
-            // Extract subroutine
-            if self.is_extractable_block(node) {
-                actions.push(extract_subroutine::create_extract_subroutine_action(
-                    node,
-                    &self.source,
-                    &helpers,
-                ));
-            }
         }
 
         // Recursively check children
@@ -187,7 +192,9 @@ impl EnhancedCodeActionsProvider {
                 self.collect_actions_for_range(body, range, actions);
             }
             NodeKind::Subroutine { body, prototype, signature, .. } => {
-                self.collect_actions_for_range(body, range, actions);
+                if let Some(body) = body {
+                    self.collect_actions_for_range(body, range, actions);
+                }
                 if let Some(proto) = prototype {
                     self.collect_actions_for_range(proto, range, actions);
                 }
@@ -212,17 +219,18 @@ impl EnhancedCodeActionsProvider {
     }
 
     /// Check if block is extractable
-    fn is_extractable_block(&self, node: &Node) -> bool {
-        matches!(&node.kind, NodeKind::Block { .. })
-    }
-
     /// Get global refactoring actions
     fn get_global_refactorings(&self, ast: &Node) -> Vec<CodeAction> {
         let mut actions = Vec::new();
         let helpers = Helpers::new(&self.source, &self.lines);
 
         // Add missing imports
-        if let Some(action) = import_management::add_missing_imports(ast, &self.source, &helpers) {
+        if let Some(action) = import_management::add_missing_imports(&self.source) {
+            actions.push(action);
+        }
+
+        // Remove unused imports
+        if let Some(action) = import_management::remove_unused_imports(&self.source) {
             actions.push(action);
         }
 
@@ -347,4 +355,80 @@ mod tests {
 
         assert!(actions.iter().any(|a| a.title.contains("postfix")));
     }
+
+    fn defined_or_edits(source: &str) -> Vec<String> {
+        let mut parser = Parser::new(source);
+        let ast = must(parser.parse());
+
+        let provider = EnhancedCodeActionsProvider::new(source.to_string());
+        provider
+            .get_enhanced_refactoring_actions(&ast, (0, source.len()))
+            .into_iter()
+            .filter(|a| a.title.contains("defined-or"))
+            .flat_map(|a| a.edit.changes)
+            .map(|edit| {
+                let mut rewritten = source.to_string();
+                rewritten.replace_range(edit.location.start..edit.location.end, &edit.new_text);
+                rewritten
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_defined_or_paren_form() {
+        let edits = defined_or_edits("my $v = defined($x) ? $x : 'none';");
+        assert_eq!(edits, vec!["my $v = $x // 'none';".to_string()]);
+    }
+
+    #[test]
+    fn test_defined_or_no_paren_form() {
+        let edits = defined_or_edits("my $v = defined $opts{name} ? $opts{name} : $a || $b;");
+        assert_eq!(edits, vec!["my $v = $opts{name} // ($a || $b);".to_string()]);
+    }
+
+    #[test]
+    fn test_defined_or_needs_matching_branch() {
+        assert!(defined_or_edits("my $v = defined $x ? $y : $z;").is_empty());
+    }
+
+    fn bareword_key_edits(source: &str) -> Vec<String> {
+        let mut parser = Parser::new(source);
+        let ast = must(parser.parse());
+
+        let provider = EnhancedCodeActionsProvider::new(source.to_string());
+        provider
+            .get_enhanced_refactoring_actions(&ast, (0, source.len()))
+            .into_iter()
+            .filter(|a| a.title.contains("bareword hash key"))
+            .flat_map(|a| a.edit.changes)
+            .map(|edit| {
+                let mut rewritten = source.to_string();
+                rewritten.replace_range(edit.location.start..edit.location.end, &edit.new_text);
+                rewritten
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_bareword_key_single_quoted() {
+        assert_eq!(bareword_key_edits("my $v = $h->{'foo'};"), vec!["my $v = $h->{foo};"]);
+        assert_eq!(bareword_key_edits("$h{\"foo_2\"} = 1;"), vec!["$h{foo_2} = 1;"]);
+    }
+
+    #[test]
+    fn test_bareword_key_leaves_unsafe_keys_quoted() {
+        assert!(bareword_key_edits("my $v = $h->{'foo-bar'};").is_empty());
+        assert!(bareword_key_edits("my $v = $h->{'0x'};").is_empty());
+        assert!(bareword_key_edits("my $v = $h->{\"$name\"};").is_empty());
+        assert!(bareword_key_edits("my $v = $h->{'time'} + $h->{'q'};").is_empty());
+    }
+
+    #[test]
+    fn test_defined_or_not_before_perl_5_10() {
+        assert!(defined_or_edits("use 5.008;\nmy $v = defined $x ? $x : 0;").is_empty());
+        assert_eq!(
+            defined_or_edits("use v5.10;\nmy $v = defined $x ? $x : 0;"),
+            vec!["use v5.10;\nmy $v = $x // 0;".to_string()]
+        );
+    }
 }
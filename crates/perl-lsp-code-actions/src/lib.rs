@@ -30,5 +30,5 @@ mod refactors;
 mod types;
 
 pub use code_actions::{CodeAction, CodeActionKind, CodeActionsProvider};
-pub use enhanced::EnhancedCodeActionsProvider;
+pub use enhanced::{EnhancedCodeActionsProvider, ExtractedSubroutine, extract_subroutine};
 pub use types::CodeActionEdit;
@@ -4,8 +4,12 @@
 
 use crate::ast_utils::{find_declaration_position, get_indent_at};
 use crate::types::{CodeAction, CodeActionEdit, CodeActionKind, QuickFixDiagnostic};
+use perl_lsp_diagnostics::best_practices::{
+    lexical_filehandle_edits, lexical_filehandle_name, three_arg_open_args,
+};
+use perl_lsp_diagnostics::common_mistakes::corrected_comparison_operator;
 use perl_lsp_rename::TextEdit;
-use perl_parser_core::SourceLocation;
+use perl_parser_core::{Node, SourceLocation};
 
 /// Fix undefined variable by declaring it
 pub fn fix_undefined_variable(source: &str, diagnostic: &QuickFixDiagnostic) -> Vec<CodeAction> {
@@ -207,6 +211,100 @@ pub fn fix_deprecated_defined(source: &str, diagnostic: &QuickFixDiagnostic) ->
     actions
 }
 
+/// Fix two-argument open by splitting the mode from the filename
+pub fn fix_two_arg_open(source: &str, diagnostic: &QuickFixDiagnostic) -> Vec<CodeAction> {
+    let mut actions = Vec::new();
+
+    // The diagnostic covers the mode-and-filename string argument
+    if let Some(literal) = source.get(diagnostic.range.0..diagnostic.range.1)
+        && let Some((mode, filename)) = three_arg_open_args(literal)
+    {
+        actions.push(CodeAction {
+            title: "Use three-argument open".to_string(),
+            kind: CodeActionKind::QuickFix,
+            diagnostics: vec!["PL401".to_string()],
+            edit: CodeActionEdit {
+                changes: vec![TextEdit {
+                    location: SourceLocation { start: diagnostic.range.0, end: diagnostic.range.1 },
+                    new_text: format!("'{mode}', {filename}"),
+                }],
+            },
+            is_preferred: true,
+        });
+    }
+
+    actions
+}
+
+/// Fix bareword filehandle by converting it to a lexical variable
+pub fn fix_bareword_filehandle(
+    ast: &Node,
+    source: &str,
+    diagnostic: &QuickFixDiagnostic,
+) -> Vec<CodeAction> {
+    let mut actions = Vec::new();
+
+    // The diagnostic covers the handle name; the rewrite spans every use in its scope
+    if let Some(name) = source.get(diagnostic.range.0..diagnostic.range.1)
+        && let Some(edits) = lexical_filehandle_edits(ast, source, diagnostic.range)
+    {
+        actions.push(CodeAction {
+            title: format!(
+                "Convert '{name}' to lexical filehandle '{}'",
+                lexical_filehandle_name(name)
+            ),
+            kind: CodeActionKind::QuickFix,
+            diagnostics: vec!["PL400".to_string()],
+            edit: CodeActionEdit {
+                changes: edits
+                    .into_iter()
+                    .map(|((start, end), new_text)| TextEdit {
+                        location: SourceLocation { start, end },
+                        new_text,
+                    })
+                    .collect(),
+            },
+            is_preferred: true,
+        });
+    }
+
+    actions
+}
+
+/// Fix a comparison that uses the numeric operator on strings or vice versa
+pub fn fix_comparison_operator(source: &str, diagnostic: &QuickFixDiagnostic) -> Vec<CodeAction> {
+    let mut actions = Vec::new();
+
+    // The diagnostic covers the operator itself
+    let (start, end) = diagnostic.range;
+    if let Some(op) = source.get(start..end)
+        && let Some(replacement) = corrected_comparison_operator(op)
+    {
+        // Word operators need whitespace where `$x<"a"` had none
+        let mut new_text = replacement.to_string();
+        if replacement.starts_with(char::is_alphabetic) {
+            if !source[..start].ends_with(char::is_whitespace) {
+                new_text.insert(0, ' ');
+            }
+            if !source[end..].starts_with(char::is_whitespace) {
+                new_text.push(' ');
+            }
+        }
+
+        actions.push(CodeAction {
+            title: format!("Use '{replacement}' instead of '{op}'"),
+            kind: CodeActionKind::QuickFix,
+            diagnostics: vec!["comparison-operator-mismatch".to_string()],
+            edit: CodeActionEdit {
+                changes: vec![TextEdit { location: SourceLocation { start, end }, new_text }],
+            },
+            is_preferred: true,
+        });
+    }
+
+    actions
+}
+
 /// Fix numeric comparison with undef
 pub fn fix_numeric_undef(source: &str, diagnostic: &QuickFixDiagnostic) -> Vec<CodeAction> {
     let mut actions = Vec::new();
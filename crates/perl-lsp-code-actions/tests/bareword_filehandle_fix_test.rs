@@ -0,0 +1,55 @@
+/// Test the lexical filehandle rewrite for PL400 diagnostics
+use std::sync::Arc;
+
+use perl_lsp_code_actions::CodeActionsProvider;
+use perl_lsp_diagnostics::DiagnosticsProvider;
+use perl_parser_core::Parser;
+use perl_tdd_support::must;
+
+/// Apply the PL400 fix offered for the `nth` diagnostic and return the rewritten source
+fn fix_bareword_filehandle(source: &str, nth: usize) -> Option<String> {
+    let mut parser = Parser::new(source);
+    let ast = Arc::new(must(parser.parse()));
+    let diagnostics: Vec<_> = DiagnosticsProvider::new(&ast, source.to_string())
+        .get_diagnostics(&ast, &[], source)
+        .into_iter()
+        .filter(|d| d.code.as_deref() == Some("PL400"))
+        .skip(nth)
+        .take(1)
+        .collect();
+
+    let provider = CodeActionsProvider::new(source.to_string());
+    let actions = provider.get_code_actions(&ast, (0, source.len()), &diagnostics);
+    let action = actions.iter().find(|a| a.diagnostics.iter().any(|d| d == "PL400"))?;
+
+    let mut fixed = source.to_string();
+    let mut changes: Vec<_> = action.edit.changes.iter().collect();
+    changes.sort_by_key(|edit| edit.location.start);
+    for edit in changes.iter().rev() {
+        fixed.replace_range(edit.location.start..edit.location.end, &edit.new_text);
+    }
+    Some(fixed)
+}
+
+#[test]
+fn bareword_open_and_print_are_converted_to_lexical() {
+    let source = "open(FH, '>', $file) or die;\nprint FH \"hello\\n\";\nclose(FH);\n";
+    let expected = "open(my $fh, '>', $file) or die;\nprint $fh \"hello\\n\";\nclose($fh);\n";
+
+    assert_eq!(fix_bareword_filehandle(source, 0).as_deref(), Some(expected));
+    // The same rewrite is offered from a use of the handle
+    assert_eq!(fix_bareword_filehandle(source, 1).as_deref(), Some(expected));
+}
+
+#[test]
+fn readline_uses_in_nested_blocks_are_converted() {
+    let source = "sub slurp {\n    open(IN, '<', $_[0]) or die;\n    while (my $line = <IN>) { print $line; }\n    close IN;\n}\n";
+    let expected = "sub slurp {\n    open(my $in, '<', $_[0]) or die;\n    while (my $line = <$in>) { print $line; }\n    close $in;\n}\n";
+
+    assert_eq!(fix_bareword_filehandle(source, 0).as_deref(), Some(expected));
+}
+
+#[test]
+fn handle_without_open_in_scope_has_no_fix() {
+    assert_eq!(fix_bareword_filehandle("print LOG \"x\\n\";\n", 0), None);
+}
@@ -171,3 +171,69 @@ fn lsp_no_organize_imports_when_no_imports() -> TestResult {
 
     Ok(())
 }
+
+#[test]
+fn lsp_remove_unused_imports_trims_and_removes() -> TestResult {
+    let source = r#"use strict;
+use List::Util qw(first max);
+use Scalar::Util qw(blessed);
+
+my $hit = first { $_ > 1 } @ARGV;
+"#;
+
+    let mut parser = Parser::new(source);
+    let ast = must(parser.parse());
+
+    let provider = CodeActionsProvider::new(source.to_string());
+    let actions = provider.get_code_actions(&ast, (0, source.len()), &[]);
+
+    let action = actions
+        .iter()
+        .find(|a| a.title == "Remove unused imports")
+        .ok_or("No remove unused imports action found")?;
+
+    let mut result = source.to_string();
+    for edit in action.edit.changes.iter().rev() {
+        result.replace_range(edit.location.start..edit.location.end, &edit.new_text);
+    }
+    assert_eq!(
+        result,
+        r#"use strict;
+use List::Util qw(first);
+
+my $hit = first { $_ > 1 } @ARGV;
+"#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn lsp_add_missing_imports_for_well_known_exports() -> TestResult {
+    let source = r#"use strict;
+use warnings;
+
+my $hit = first { $_ > 1 } @ARGV;
+print "object\n" if blessed($hit);
+"#;
+
+    let mut parser = Parser::new(source);
+    let ast = must(parser.parse());
+
+    let provider = CodeActionsProvider::new(source.to_string());
+    let actions = provider.get_code_actions(&ast, (0, source.len()), &[]);
+
+    let action = actions
+        .iter()
+        .find(|a| a.title == "Add missing imports")
+        .ok_or("No add missing imports action found")?;
+
+    assert_eq!(action.edit.changes.len(), 1);
+    assert_eq!(
+        action.edit.changes[0].new_text,
+        "use List::Util qw(first);\nuse Scalar::Util qw(blessed);\n"
+    );
+    assert_eq!(action.edit.changes[0].location.start, "use strict;\nuse warnings;\n".len());
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+/// Test the three-argument open rewrite for PL401 diagnostics
+use std::sync::Arc;
+
+use perl_lsp_code_actions::CodeActionsProvider;
+use perl_lsp_diagnostics::DiagnosticsProvider;
+use perl_parser_core::Parser;
+use perl_tdd_support::must;
+
+/// Apply the preferred PL401 fix and return the rewritten source
+fn fix_two_arg_open(source: &str) -> Option<String> {
+    let mut parser = Parser::new(source);
+    let ast = Arc::new(must(parser.parse()));
+    let diagnostics =
+        DiagnosticsProvider::new(&ast, source.to_string()).get_diagnostics(&ast, &[], source);
+
+    let provider = CodeActionsProvider::new(source.to_string());
+    let actions = provider.get_code_actions(&ast, (0, source.len()), &diagnostics);
+    let action = actions.iter().find(|a| a.diagnostics.iter().any(|d| d == "PL401"))?;
+
+    let mut fixed = source.to_string();
+    for edit in action.edit.changes.iter().rev() {
+        fixed.replace_range(edit.location.start..edit.location.end, &edit.new_text);
+    }
+    Some(fixed)
+}
+
+#[test]
+fn two_arg_open_read_is_rewritten_to_three_arg() {
+    let fixed = fix_two_arg_open(r#"open(my $fh, "<$file") or die;"#);
+    assert_eq!(fixed.as_deref(), Some("open(my $fh, '<', $file) or die;"));
+}
+
+#[test]
+fn two_arg_open_modes_are_split_from_filename() {
+    let cases = [
+        (r#"open(FH, ">$file");"#, "open(FH, '>', $file);"),
+        (r#"open(FH, ">>$file");"#, "open(FH, '>>', $file);"),
+        (r#"open(FH, "+<$file");"#, "open(FH, '+<', $file);"),
+        (r#"open(FH, "| sendmail -t");"#, r#"open(FH, '|-', "sendmail -t");"#),
+    ];
+
+    for (source, expected) in cases {
+        assert_eq!(fix_two_arg_open(source).as_deref(), Some(expected), "for {source}");
+    }
+}
+
+#[test]
+fn three_arg_open_has_no_fix() {
+    assert_eq!(fix_two_arg_open("open(my $fh, '<', $file) or die;"), None);
+}
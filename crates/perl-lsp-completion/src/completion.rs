@@ -10,7 +10,8 @@
 //! - **Functions**: Built-in functions (150+ with signatures) and user-defined subroutines
 //! - **Keywords**: Perl keywords with snippet expansion (`sub`, `if`, `while`, etc.)
 //! - **Packages**: Package member completion with workspace index integration
-//! - **Methods**: Context-aware method completion including DBI methods
+//! - **Methods**: Methods of the receiver's inferred class and its ancestors, including DBI methods
+//! - **Hash Keys**: Literal keys assigned to a hash inside `$h{...}` and `$ref->{...}`
 //! - **Test Functions**: Test::More completions in test contexts
 //!
 //! ### File Path Completion (v0.8.7+)
@@ -92,8 +93,10 @@ mod builtins;
 mod context;
 mod file_path;
 mod functions;
+mod hash_keys;
 mod items;
 mod keywords;
+mod labels;
 mod methods;
 mod packages;
 mod sort;
@@ -107,6 +110,7 @@ pub use self::items::{CompletionItem, CompletionItemKind};
 
 use perl_parser_core::ast::Node;
 use perl_semantic_analyzer::symbol::{SymbolExtractor, SymbolKind, SymbolTable};
+use perl_semantic_analyzer::type_inference::{PerlType, TypeInferenceEngine};
 use perl_workspace_index::workspace_index::WorkspaceIndex;
 use std::sync::Arc;
 
@@ -114,6 +118,10 @@ use std::sync::Arc;
 pub struct CompletionProvider {
     symbol_table: SymbolTable,
     workspace_index: Option<Arc<WorkspaceIndex>>,
+    /// Literal keys assigned to hashes and hash references in the document
+    hash_keys: Vec<hash_keys::HashKeyAssignment>,
+    /// Inferred declaration types, used to find the class of a method receiver
+    type_engine: TypeInferenceEngine,
 }
 
 impl CompletionProvider {
@@ -194,8 +202,12 @@ impl CompletionProvider {
         workspace_index: Option<Arc<WorkspaceIndex>>,
     ) -> Self {
         let symbol_table = SymbolExtractor::new_with_source(source).extract(ast);
+        let hash_keys = hash_keys::collect_hash_keys(ast, source);
+        let mut type_engine = TypeInferenceEngine::new();
+        // Type errors are irrelevant here; only the declaration types are used
+        let _ = type_engine.infer(ast);
 
-        CompletionProvider { symbol_table, workspace_index }
+        CompletionProvider { symbol_table, workspace_index, hash_keys, type_engine }
     }
 
     /// Create a new completion provider from parsed AST without workspace context
@@ -372,9 +384,30 @@ impl CompletionProvider {
         // Determine what kind of completions to provide based on context
         if self.is_has_options_key_context(source, position) {
             self.add_has_option_completions(&mut completions, &context);
-        } else if context.trigger_character == Some('>') && context.prefix.ends_with("->") {
+        } else if let Some(target) = hash_keys::subscript_target(source, &context) {
+            // Hash key completion inside `$h{...}` or `$r->{...}`
+            hash_keys::add_hash_key_completions(
+                &mut completions,
+                &context,
+                &target,
+                &self.hash_keys,
+                &self.symbol_table,
+            );
+        } else if let Some(call) = methods::method_call_at(source, &context) {
             // Method completion must run before sigil-prefixed variable completion.
-            methods::add_method_completions(&mut completions, &context, source, &self.symbol_table);
+            let receiver_class = self.receiver_class(&call.receiver, call.method_start);
+            methods::add_method_completions(
+                &mut completions,
+                &context,
+                &call,
+                source,
+                &self.symbol_table,
+                &self.workspace_index,
+                receiver_class.as_deref(),
+            );
+        } else if labels::is_loop_control_target(source, &context) {
+            // Labels of enclosing loops after `next`/`last`/`redo`
+            labels::add_label_completions(&mut completions, &context, &self.symbol_table);
         } else if context.prefix.starts_with('$') {
             // Scalar variable completion
             variables::add_variable_completions(
@@ -457,9 +490,14 @@ impl CompletionProvider {
                 }
             }
         } else {
-            // General completion: keywords, functions, variables
+            // General completion: keywords, functions, variables.
+            // After `->` only a method name can follow, so skip keywords and built-ins there.
+            let in_method_position = source[..context.prefix_start].ends_with("->");
+
             let keywords = keywords::create_keywords();
-            if context.prefix.is_empty() || self.could_be_keyword(&context.prefix, &keywords) {
+            if !in_method_position
+                && (context.prefix.is_empty() || self.could_be_keyword(&context.prefix, &keywords))
+            {
                 keywords::add_keyword_completions(&mut completions, &context, &keywords);
                 if is_cancelled() {
                     return vec![];
@@ -468,7 +506,9 @@ impl CompletionProvider {
 
             let builtins = builtins::create_builtins();
             if context.prefix.is_empty() || self.could_be_function(&context.prefix, &builtins) {
-                builtins::add_builtin_completions(&mut completions, &context, &builtins);
+                if !in_method_position {
+                    builtins::add_builtin_completions(&mut completions, &context, &builtins);
+                }
                 if is_cancelled() {
                     return vec![];
                 }
@@ -555,6 +595,23 @@ impl CompletionProvider {
         self.get_completions_with_path(source, position, None)
     }
 
+    /// Class of a method call receiver: a bareword class name, or a scalar
+    /// whose declaration was inferred to hold an object (`my $obj = Foo->new`)
+    fn receiver_class(&self, receiver: &str, position: usize) -> Option<String> {
+        let Some(name) = receiver.strip_prefix('$') else {
+            // `Foo->` and `Foo::Bar->` name a class; `shift->` does not
+            let is_class =
+                receiver.contains("::") || receiver.starts_with(|c: char| c.is_ascii_uppercase());
+            return is_class.then(|| receiver.to_string());
+        };
+        let declaration =
+            context::resolve_variable(&self.symbol_table, name, SymbolKind::scalar(), position)?;
+        match self.type_engine.get_declaration_type(declaration.location.start)? {
+            PerlType::Object(class) => Some(class.clone()),
+            _ => None,
+        }
+    }
+
     /// Analyze the context at the cursor position
     fn analyze_context(&self, source: &str, position: usize) -> CompletionContext {
         // Find the prefix (text before cursor on the same line)
@@ -779,6 +836,7 @@ impl CompletionProvider {
                     detail: Some("Moo/Moose option".to_string()),
                     documentation: Some(doc.to_string()),
                     insert_text: Some(format!("{label} => ")),
+                    insert_text_is_snippet: false,
                     sort_text: Some(format!("0_{label}")),
                     filter_text: Some(label.to_string()),
                     additional_edits: vec![],
@@ -934,6 +992,45 @@ proc
         assert!(completions.iter().any(|c| c.label == "printf"));
     }
 
+    #[test]
+    fn test_builtin_completion_from_signature_table() {
+        let code = "my $s = spr";
+
+        let ast = must(Parser::new("").parse());
+        let provider = CompletionProvider::new(&ast);
+        let completions = provider.get_completions(code, code.len());
+
+        let sprintf = must_some(completions.iter().find(|c| c.label == "sprintf"));
+        assert_eq!(sprintf.kind, CompletionItemKind::Function);
+        assert_eq!(sprintf.detail.as_deref(), Some("sprintf FORMAT, LIST"));
+        assert_eq!(sprintf.insert_text.as_deref(), Some("sprintf(${1:FORMAT}, ${2:LIST})"));
+    }
+
+    #[test]
+    fn test_no_builtin_completion_in_method_position() {
+        let code = "my $obj; $obj->spr";
+
+        let ast = must(Parser::new("my $obj;").parse());
+        let provider = CompletionProvider::new(&ast);
+        let completions = provider.get_completions(code, code.len());
+
+        assert!(!completions.iter().any(|c| c.label == "sprintf"));
+    }
+
+    #[test]
+    fn test_user_subs_rank_above_builtins() {
+        let code = "sub sprint_report { }\nspr";
+
+        let ast = must(Parser::new(code).parse());
+        let provider = CompletionProvider::new(&ast);
+        let completions = provider.get_completions(code, code.len());
+
+        let position = |label: &str| completions.iter().position(|c| c.label == label);
+        let user_sub = must_some(position("sprint_report"));
+        let builtin = must_some(position("sprintf"));
+        assert!(user_sub < builtin, "user sub should rank above built-in: {completions:?}");
+    }
+
     #[test]
     fn test_current_package_detection() {
         let code = r#"package Foo;
@@ -1058,4 +1155,117 @@ has 'name' => (re
             "expected `reader` option completion inside has(...) context"
         );
     }
+
+    fn hash_key_labels(code: &str, position: usize) -> Vec<String> {
+        let ast = must(Parser::new(code).parse());
+        let provider = CompletionProvider::new_with_index_and_source(&ast, code, None);
+        let mut labels: Vec<String> = provider
+            .get_completions(code, position)
+            .into_iter()
+            .filter(|c| c.kind == CompletionItemKind::Field)
+            .map(|c| c.label)
+            .collect();
+        labels.sort();
+        labels
+    }
+
+    #[test]
+    fn test_hash_key_completion_from_assignments() {
+        let code = "my %h = (name => 1, 'age' => 2);\n$h{email} = 'x';\n$h{name} = 3;\nprint $h{";
+        assert_eq!(hash_key_labels(code, code.len()), vec!["age", "email", "name"]);
+
+        let partial = format!("{code}na");
+        assert_eq!(hash_key_labels(&partial, partial.len()), vec!["name"]);
+    }
+
+    #[test]
+    fn test_hash_ref_key_completion() {
+        let code = "my $config = { host => 'h', port => 1 };\n$config->{user} = 'me';\n$config->{";
+        assert_eq!(hash_key_labels(code, code.len()), vec!["host", "port", "user"]);
+    }
+
+    #[test]
+    fn test_hash_key_completion_respects_scope() {
+        let code = "my %h = (outer => 1);\nsub f { my %h = (inner => 1); }\n$h{";
+        assert_eq!(hash_key_labels(code, code.len()), vec!["outer"]);
+    }
+
+    #[test]
+    fn test_no_hash_key_completion_in_array_subscript() {
+        let code = "my %h = (name => 1);\nmy @h = (1);\n$h[";
+        assert!(hash_key_labels(code, code.len()).is_empty());
+    }
+
+    #[test]
+    fn test_loop_label_completion_after_last() {
+        let code = "OUTER: for my $i (1..3) {\n    INNER: while (1) { last  }\n}\nDONE: {}\n";
+        let position = must_some(code.find("last ")) + "last ".len();
+        let ast = must(Parser::new(code).parse());
+        let provider = CompletionProvider::new_with_index_and_source(&ast, code, None);
+
+        let labels: Vec<String> = provider
+            .get_completions(code, position)
+            .into_iter()
+            .filter(|c| c.detail.as_deref() == Some("label"))
+            .map(|c| c.label)
+            .collect();
+        assert_eq!(labels, vec!["INNER", "OUTER"]);
+    }
+
+    fn method_labels(code: &str, index: Arc<WorkspaceIndex>) -> Vec<String> {
+        let ast = must(Parser::new(code).parse());
+        let provider = CompletionProvider::new_with_index_and_source(&ast, code, Some(index));
+        let mut labels: Vec<String> = provider
+            .get_completions(code, code.len())
+            .into_iter()
+            .filter(|c| c.kind == CompletionItemKind::Method)
+            .map(|c| c.label)
+            .collect();
+        labels.sort();
+        labels
+    }
+
+    #[test]
+    fn test_method_completion_for_inferred_class_and_ancestors() {
+        let code = r#"package Animal;
+sub speak { }
+sub eat { }
+package Dog;
+use parent -norequire, 'Animal';
+sub fetch { }
+package Car;
+sub drive { }
+package main;
+sub helper { }
+my $dog = Dog->new;
+$dog->"#;
+        let index = Arc::new(WorkspaceIndex::new());
+        must(index.index_file_str("file:///workspace/dog.pl", code));
+
+        assert_eq!(
+            method_labels(code, index),
+            vec!["DOES", "VERSION", "can", "eat", "fetch", "isa", "new", "speak"]
+        );
+    }
+
+    #[test]
+    fn test_method_completion_for_unknown_class_uses_workspace_methods() {
+        let index = Arc::new(WorkspaceIndex::new());
+        must(index.index_file_str(
+            "file:///workspace/Widget.pm",
+            "package Widget;\nsub render { }\nsub resize { }\n1;\n",
+        ));
+
+        let code = "sub helper { }\nmy $thing = helper();\n$thing->re";
+        let ast = must(Parser::new(code).parse());
+        let provider = CompletionProvider::new_with_index_and_source(&ast, code, Some(index));
+        let completions = provider.get_completions(code, code.len());
+
+        let render = must_some(completions.iter().find(|c| c.label == "render"));
+        assert_eq!(render.kind, CompletionItemKind::Method);
+        assert_eq!(render.detail.as_deref(), Some("Widget"));
+        assert_eq!(render.text_edit_range, Some((code.len() - 2, code.len())));
+        assert!(completions.iter().any(|c| c.label == "resize"));
+        assert!(!completions.iter().any(|c| c.label == "helper"), "bareword subs are not methods");
+    }
 }
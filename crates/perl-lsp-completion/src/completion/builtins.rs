@@ -2,11 +2,16 @@
 //!
 //! Provides completion for Perl built-in functions with signatures.
 
-use super::{context::CompletionContext, items::CompletionItem};
+use super::{context::CompletionContext, items::CompletionItem, keywords};
+use perl_parser_core::builtin_signatures_phf::{BUILTIN_FULL_SIGS, BUILTIN_SIGS, get_param_names};
 use std::collections::HashSet;
 
 /// Create the builtins HashSet
+///
+/// Includes every function from the built-in signature table, except names
+/// that complete better as keywords (`my`, `return`, `package`, ...).
 pub fn create_builtins() -> HashSet<&'static str> {
+    let keywords = keywords::create_keywords();
     [
         // I/O
         "print",
@@ -134,6 +139,7 @@ pub fn create_builtins() -> HashSet<&'static str> {
         "qr",
     ]
     .into_iter()
+    .chain(BUILTIN_SIGS.keys().copied().filter(|name| !keywords.contains(name)))
     .collect()
 }
 
@@ -146,21 +152,25 @@ pub fn add_builtin_completions(
     for builtin in builtins {
         if builtin.starts_with(&context.prefix) {
             let (insert_text, detail) = match *builtin {
-                "print" => ("print ", "print FILEHANDLE LIST"),
-                "open" => ("open(my $fh, '<', )", "open FILEHANDLE, MODE, FILENAME"),
-                "push" => ("push(@, )", "push ARRAY, LIST"),
-                "map" => ("map { } ", "map BLOCK LIST"),
-                "grep" => ("grep { } ", "grep BLOCK LIST"),
-                "sort" => ("sort { } ", "sort BLOCK LIST"),
-                _ => (*builtin, "built-in function"),
+                "print" => ("print ".to_string(), "print FILEHANDLE LIST".to_string()),
+                "open" => (
+                    "open(my $fh, '<', )".to_string(),
+                    "open FILEHANDLE, MODE, FILENAME".to_string(),
+                ),
+                "push" => ("push(@, )".to_string(), "push ARRAY, LIST".to_string()),
+                "map" => ("map { } ".to_string(), "map BLOCK LIST".to_string()),
+                "grep" => ("grep { } ".to_string(), "grep BLOCK LIST".to_string()),
+                "sort" => ("sort { } ".to_string(), "sort BLOCK LIST".to_string()),
+                _ => (builtin_snippet(builtin), builtin_detail(builtin)),
             };
 
             completions.push(CompletionItem {
                 label: builtin.to_string(),
                 kind: crate::completion::items::CompletionItemKind::Function,
-                detail: Some(detail.to_string()),
+                detail: Some(detail),
                 documentation: None,
-                insert_text: Some(insert_text.to_string()),
+                insert_text_is_snippet: insert_text.contains("${"),
+                insert_text: Some(insert_text),
                 sort_text: Some(format!("3_{}", builtin)),
                 filter_text: Some(builtin.to_string()),
                 additional_edits: vec![],
@@ -169,3 +179,26 @@ pub fn add_builtin_completions(
         }
     }
 }
+
+/// Signature shown in the detail field, e.g. `sprintf FORMAT, LIST`
+fn builtin_detail(name: &str) -> String {
+    if let Some(full) = BUILTIN_FULL_SIGS.get(name).and_then(|sigs| sigs.first()) {
+        return full.to_string();
+    }
+    match BUILTIN_SIGS.get(name) {
+        Some([]) => name.to_string(),
+        Some(params) => format!("{name} {}", params.join(", ")),
+        None => "built-in function".to_string(),
+    }
+}
+
+/// Call snippet with a placeholder per parameter, e.g. `sprintf(${1:FORMAT}, ${2:LIST})`
+fn builtin_snippet(name: &str) -> String {
+    let params = get_param_names(name);
+    if params.is_empty() {
+        return name.to_string();
+    }
+    let placeholders: Vec<String> =
+        params.iter().enumerate().map(|(i, param)| format!("${{{}:{param}}}", i + 1)).collect();
+    format!("{name}({})", placeholders.join(", "))
+}
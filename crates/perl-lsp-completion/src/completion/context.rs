@@ -1,6 +1,9 @@
 //! Completion context analysis
 
-use perl_semantic_analyzer::symbol::{ScopeKind, SymbolKind, SymbolTable};
+use perl_parser_core::SourceLocation;
+use perl_semantic_analyzer::symbol::{
+    ScopeId, ScopeKind, Symbol, SymbolKind, SymbolReference, SymbolTable,
+};
 
 /// Context for completion
 #[derive(Debug, Clone)]
@@ -94,3 +97,30 @@ impl CompletionContext {
         }
     }
 }
+
+/// Resolve the declaration of a variable as seen from `position`
+pub(crate) fn resolve_variable<'a>(
+    symbol_table: &'a SymbolTable,
+    name: &str,
+    kind: SymbolKind,
+    position: usize,
+) -> Option<&'a Symbol> {
+    let reference = SymbolReference {
+        name: name.to_string(),
+        kind,
+        location: SourceLocation { start: position, end: position },
+        scope_id: innermost_scope(symbol_table, position),
+        is_write: false,
+    };
+    symbol_table.resolve_reference(&reference)
+}
+
+/// The innermost scope whose span contains `position`
+fn innermost_scope(symbol_table: &SymbolTable, position: usize) -> ScopeId {
+    symbol_table
+        .scopes
+        .values()
+        .filter(|scope| scope.location.start <= position && position <= scope.location.end)
+        .max_by_key(|scope| (scope.location.start, std::cmp::Reverse(scope.location.end)))
+        .map_or(0, |scope| scope.id)
+}
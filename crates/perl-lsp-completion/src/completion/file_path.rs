@@ -289,6 +289,7 @@ pub(crate) fn add_file_completions_with_cancellation(
             detail: Some(detail),
             documentation,
             insert_text: Some(completion_path.clone()),
+            insert_text_is_snippet: false,
             sort_text: Some(format!("1_{}", completion_path)),
             filter_text: Some(completion_path.clone()),
             additional_edits: vec![],
@@ -22,6 +22,7 @@ pub fn add_function_completions(
                     detail: Some("sub".to_string()),
                     documentation: symbol.documentation.clone(),
                     insert_text: Some(format!("{}()", name)),
+                    insert_text_is_snippet: false,
                     sort_text: Some(format!("2_{}", name)),
                     filter_text: Some(name.clone()),
                     additional_edits: vec![],
@@ -0,0 +1,195 @@
+//! Hash key completion for Perl
+//!
+//! Offers the literal keys assigned to a hash (`%h = (name => ...)`,
+//! `$h{name} = ...`) or hash reference (`$r = { name => ... }`,
+//! `$r->{name} = ...`) inside `$h{...}` and `$r->{...}` subscripts.
+
+use super::{
+    context::{CompletionContext, resolve_variable},
+    items::{CompletionItem, CompletionItemKind},
+};
+use perl_parser_core::ast::{Node, NodeKind};
+use perl_semantic_analyzer::symbol::{Symbol, SymbolKind, SymbolTable};
+
+/// A literal key assigned to a hash or hash reference variable
+#[derive(Debug, Clone)]
+pub(crate) struct HashKeyAssignment {
+    /// Variable name without sigil
+    name: String,
+    /// `SymbolKind::hash()` for `%h`, `SymbolKind::scalar()` for a hash reference
+    kind: SymbolKind,
+    /// Offset of the variable at the assignment, used to resolve its declaration
+    position: usize,
+    /// The literal key
+    key: String,
+}
+
+/// The hash variable whose subscript the cursor is in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SubscriptTarget {
+    name: String,
+    kind: SymbolKind,
+}
+
+/// Collect the literal keys assigned to hashes and hash references in `ast`
+///
+/// `source` is needed to tell `$h{key}` from `$r->{key}`, which parse the same
+/// way; element assignments are skipped when it is empty.
+pub(crate) fn collect_hash_keys(ast: &Node, source: &str) -> Vec<HashKeyAssignment> {
+    let mut keys = Vec::new();
+    collect_from_node(ast, source, &mut keys);
+    keys
+}
+
+fn collect_from_node(node: &Node, source: &str, keys: &mut Vec<HashKeyAssignment>) {
+    match &node.kind {
+        NodeKind::VariableDeclaration { variable, initializer: Some(init), .. } => {
+            add_literal_keys(variable, init, keys);
+        }
+        NodeKind::Assignment { lhs, rhs, op } if op == "=" => {
+            add_literal_keys(lhs, rhs, keys);
+            if let NodeKind::Binary { op, left, right } = &lhs.kind
+                && op == "{}"
+                && let NodeKind::Variable { sigil, name } = &left.kind
+                && sigil == "$"
+                && let Some(between) = source.get(left.location.end..right.location.start)
+                && let Some(key) = literal_key(right)
+            {
+                let kind =
+                    if between.contains("->") { SymbolKind::scalar() } else { SymbolKind::hash() };
+                keys.push(HashKeyAssignment {
+                    name: name.clone(),
+                    kind,
+                    position: left.location.start,
+                    key,
+                });
+            }
+        }
+        _ => {}
+    }
+    node.for_each_child(|child| collect_from_node(child, source, keys));
+}
+
+/// Record the keys of a hash literal assigned to `%h` or `$r`
+fn add_literal_keys(target: &Node, value: &Node, keys: &mut Vec<HashKeyAssignment>) {
+    let (NodeKind::Variable { sigil, name }, NodeKind::HashLiteral { pairs }) =
+        (&target.kind, &value.kind)
+    else {
+        return;
+    };
+    let kind = match sigil.as_str() {
+        "%" => SymbolKind::hash(),
+        "$" => SymbolKind::scalar(),
+        _ => return,
+    };
+    for (key, _) in pairs {
+        if let Some(key) = literal_key(key) {
+            keys.push(HashKeyAssignment {
+                name: name.clone(),
+                kind,
+                position: target.location.start,
+                key,
+            });
+        }
+    }
+}
+
+/// The text of a bareword or non-interpolating string key
+fn literal_key(node: &Node) -> Option<String> {
+    match &node.kind {
+        NodeKind::Identifier { name } => Some(name.clone()),
+        // Barewords auto-quoted by `=>` keep their text without quotes
+        NodeKind::String { value, interpolated: false }
+            if !value.is_empty()
+                && value.chars().all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | ':')) =>
+        {
+            Some(value.clone())
+        }
+        NodeKind::String { value, .. } => {
+            let inner = value
+                .strip_prefix('\'')
+                .and_then(|v| v.strip_suffix('\''))
+                .or_else(|| value.strip_prefix('"').and_then(|v| v.strip_suffix('"')))?;
+            (!inner.contains(['$', '@', '\\'])).then(|| inner.to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Find the hash being subscripted when the cursor is inside `$h{` or `$r->{`
+///
+/// Array subscripts and keys that are not plain words (`$h{$k}`) return `None`.
+pub(crate) fn subscript_target(
+    source: &str,
+    context: &CompletionContext,
+) -> Option<SubscriptTarget> {
+    if !context.prefix.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return None;
+    }
+    let before = source.get(..context.prefix_start)?.trim_end().strip_suffix('{')?;
+    let (before, kind) = match before.strip_suffix("->") {
+        Some(receiver) => (receiver, SymbolKind::scalar()),
+        None => (before, SymbolKind::hash()),
+    };
+    let name_start =
+        before.rfind(|c: char| !c.is_ascii_alphanumeric() && c != '_').map_or(0, |idx| idx + 1);
+    let name = &before[name_start..];
+    if name.is_empty() || !before[..name_start].ends_with('$') {
+        return None;
+    }
+    Some(SubscriptTarget { name: name.to_string(), kind })
+}
+
+/// Add the keys known for `target` that are visible at the cursor
+pub(crate) fn add_hash_key_completions(
+    completions: &mut Vec<CompletionItem>,
+    context: &CompletionContext,
+    target: &SubscriptTarget,
+    hash_keys: &[HashKeyAssignment],
+    symbol_table: &SymbolTable,
+) {
+    let declaration = resolve_variable(symbol_table, &target.name, target.kind, context.position);
+    let detail = if target.kind == SymbolKind::hash() {
+        format!("key of %{}", target.name)
+    } else {
+        format!("key of ${}->{{...}}", target.name)
+    };
+
+    let mut seen = std::collections::HashSet::new();
+    for entry in hash_keys {
+        if entry.name != target.name
+            || entry.kind != target.kind
+            || !entry.key.starts_with(&context.prefix)
+            || !same_declaration(
+                declaration,
+                resolve_variable(symbol_table, &entry.name, entry.kind, entry.position),
+            )
+            || !seen.insert(entry.key.as_str())
+        {
+            continue;
+        }
+
+        completions.push(CompletionItem {
+            label: entry.key.clone(),
+            kind: CompletionItemKind::Field,
+            detail: Some(detail.clone()),
+            documentation: None,
+            insert_text: Some(entry.key.clone()),
+            insert_text_is_snippet: false,
+            sort_text: Some(format!("0_{}", entry.key)),
+            filter_text: Some(entry.key.clone()),
+            additional_edits: vec![],
+            text_edit_range: Some((context.prefix_start, context.position)),
+        });
+    }
+}
+
+/// Two uses refer to the same variable if they resolve to the same declaration,
+/// or are both undeclared (package variables)
+fn same_declaration(a: Option<&Symbol>, b: Option<&Symbol>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a.location == b.location,
+        (None, None) => true,
+        _ => false,
+    }
+}
@@ -7,8 +7,10 @@ use perl_parser_core::SourceLocation;
 pub enum CompletionItemKind {
     /// Variable (scalar, array, hash)
     Variable,
-    /// Function or method
+    /// Function
     Function,
+    /// Method called through `->`
+    Method,
     /// Perl keyword
     Keyword,
     /// Package or module
@@ -21,6 +23,8 @@ pub enum CompletionItemKind {
     Constant,
     /// Property or hash key
     Property,
+    /// Key of a known hash literal
+    Field,
 }
 
 /// A single completion suggestion
@@ -36,6 +40,9 @@ pub struct CompletionItem {
     pub documentation: Option<String>,
     /// Text to insert (if different from label)
     pub insert_text: Option<String>,
+    /// Whether `insert_text` uses snippet syntax such as `${1:FORMAT}`;
+    /// clients without snippet support are sent `label()` instead
+    pub insert_text_is_snippet: bool,
     /// Sort priority (lower is better)
     pub sort_text: Option<String>,
     /// Filter text for matching
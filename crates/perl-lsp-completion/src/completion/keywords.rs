@@ -98,6 +98,7 @@ pub fn add_keyword_completions(
                 detail: Some("keyword".to_string()),
                 documentation: None,
                 insert_text: Some(insert_text.to_string()),
+                insert_text_is_snippet: false,
                 sort_text: Some(format!("4_{}", keyword)),
                 filter_text: Some(keyword.to_string()),
                 additional_edits: vec![],
@@ -0,0 +1,61 @@
+//! Loop label completion for Perl
+//!
+//! Offers the labels of enclosing loops after `next`, `last` and `redo`.
+
+use super::{
+    context::CompletionContext,
+    items::{CompletionItem, CompletionItemKind},
+};
+use perl_semantic_analyzer::symbol::{SymbolKind, SymbolTable};
+
+/// Whether the cursor is on the label operand of `next`, `last` or `redo`
+pub fn is_loop_control_target(source: &str, context: &CompletionContext) -> bool {
+    if !context.prefix.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return false;
+    }
+    let before = &source[..context.prefix_start];
+    let trimmed = before.trim_end_matches([' ', '\t']);
+    if trimmed.len() == before.len() {
+        return false;
+    }
+    ["next", "last", "redo"].iter().any(|op| {
+        trimmed.strip_suffix(op).is_some_and(|rest| {
+            !rest.ends_with(|c: char| c.is_alphanumeric() || c == '_' || c == '$')
+        })
+    })
+}
+
+/// Add labels of the labeled statements enclosing the cursor, innermost first
+pub fn add_label_completions(
+    completions: &mut Vec<CompletionItem>,
+    context: &CompletionContext,
+    symbol_table: &SymbolTable,
+) {
+    let mut labels: Vec<_> = symbol_table
+        .symbols
+        .values()
+        .flatten()
+        .filter(|symbol| {
+            symbol.kind == SymbolKind::Label
+                && symbol.location.start <= context.position
+                && context.position <= symbol.location.end
+                && symbol.name.starts_with(&context.prefix)
+        })
+        .collect();
+    labels.sort_by_key(|symbol| std::cmp::Reverse(symbol.location.start));
+
+    for (rank, symbol) in labels.into_iter().enumerate() {
+        completions.push(CompletionItem {
+            label: symbol.name.clone(),
+            kind: CompletionItemKind::Keyword,
+            detail: Some("label".to_string()),
+            documentation: None,
+            insert_text: Some(symbol.name.clone()),
+            insert_text_is_snippet: false,
+            sort_text: Some(format!("0_{:03}", rank)),
+            filter_text: Some(symbol.name.clone()),
+            additional_edits: vec![],
+            text_edit_range: Some((context.prefix_start, context.position)),
+        });
+    }
+}
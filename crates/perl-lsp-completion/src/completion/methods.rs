@@ -1,10 +1,17 @@
 //! Method completion for Perl
 //!
-//! Provides context-aware method completion including DBI methods.
-
-use super::{context::CompletionContext, items::CompletionItem};
+//! Provides method completion after `->` for the receiver's inferred class and
+//! its ancestors, falling back to methods seen anywhere when the class is
+//! unknown. DBI handles get their own method tables.
+
+use super::{
+    context::CompletionContext,
+    items::{CompletionItem, CompletionItemKind},
+};
 use perl_semantic_analyzer::symbol::{SymbolKind, SymbolTable};
+use perl_workspace_index::workspace_index::WorkspaceIndex;
 use std::collections::HashSet;
+use std::sync::Arc;
 
 /// DBI database handle methods
 pub const DBI_DB_METHODS: &[(&str, &str)] = &[
@@ -41,25 +48,67 @@ pub const DBI_ST_METHODS: &[(&str, &str)] = &[
     ("rows", "Get the number of rows affected"),
 ];
 
-/// Infer receiver type from context (for DBI method completion)
-pub fn infer_receiver_type(context: &CompletionContext, source: &str) -> Option<String> {
-    // Look backwards from the position to find the receiver
-    let prefix = context.prefix.trim_end_matches("->");
+/// Methods every object inherits from `UNIVERSAL`, plus the conventional constructor
+const UNIVERSAL_METHODS: &[(&str, &str)] = &[
+    ("new", "Constructor"),
+    ("isa", "Check if object is of given class"),
+    ("can", "Check if object can call method"),
+    ("DOES", "Check if object does role"),
+    ("VERSION", "Get version"),
+];
+
+/// A method call being completed: `$obj->meth` or `Class->meth`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MethodCallSite {
+    /// Receiver text before `->`, such as `$obj` or `Foo::Bar`
+    pub receiver: String,
+    /// Method name typed so far
+    pub method_prefix: String,
+    /// Offset where the method name starts
+    pub method_start: usize,
+}
+
+/// Find the method call the cursor is in, right after `->` or within the method name
+pub fn method_call_at(source: &str, context: &CompletionContext) -> Option<MethodCallSite> {
+    let (receiver_end, method_prefix) = match context.prefix.strip_suffix("->") {
+        Some(_) => (context.position - 2, ""),
+        None if context.prefix.chars().all(|c| c.is_alphanumeric() || c == '_') => {
+            let before = source.get(..context.prefix_start)?;
+            (before.strip_suffix("->")?.len(), context.prefix.as_str())
+        }
+        None => return None,
+    };
+    let head = source.get(..receiver_end)?;
+    let receiver_start = head
+        .rfind(|c: char| !c.is_alphanumeric() && c != '_' && c != ':' && c != '$')
+        .map_or(0, |idx| idx + 1);
+    let receiver = &head[receiver_start..];
+    if receiver.is_empty() || receiver == "$" {
+        return None;
+    }
+    Some(MethodCallSite {
+        receiver: receiver.to_string(),
+        method_prefix: method_prefix.to_string(),
+        method_start: context.position - method_prefix.len(),
+    })
+}
 
+/// Infer receiver type from context (for DBI method completion)
+pub fn infer_receiver_type(receiver: &str, source: &str) -> Option<String> {
     // Simple heuristics for DBI types based on variable name
-    if prefix.ends_with("$dbh") {
+    if receiver.ends_with("$dbh") {
         return Some("DBI::db".to_string());
     }
-    if prefix.ends_with("$sth") {
+    if receiver.ends_with("$sth") {
         return Some("DBI::st".to_string());
     }
 
     // Look at the broader context - check if variable was assigned from DBI->connect
-    if let Some(var_pos) = source.rfind(prefix) {
+    if let Some(var_pos) = source.rfind(receiver) {
         // Look backwards for assignment
         let before_var = &source[..var_pos];
         if let Some(assign_pos) = before_var.rfind('=') {
-            let assignment = &source[assign_pos..var_pos + prefix.len()];
+            let assignment = &source[assign_pos..var_pos + receiver.len()];
 
             // Check if this looks like DBI->connect result
             if assignment.contains("DBI") && assignment.contains("connect") {
@@ -76,101 +125,130 @@ pub fn infer_receiver_type(context: &CompletionContext, source: &str) -> Option<
     None
 }
 
-/// Add method completions
+/// Add method completions for a call on `receiver_class`, or on an unknown class
+///
+/// A known class offers its own methods, then those inherited along the
+/// workspace inheritance chain. An unknown class offers every method defined
+/// in a package in this file or the workspace. Subroutines of `main` are
+/// never offered, since they cannot be called as methods of an object.
 pub fn add_method_completions(
     completions: &mut Vec<CompletionItem>,
     context: &CompletionContext,
+    call: &MethodCallSite,
     source: &str,
     symbol_table: &SymbolTable,
+    workspace_index: &Option<Arc<WorkspaceIndex>>,
+    receiver_class: Option<&str>,
 ) {
     let mut seen = HashSet::new();
-
-    // Prefer discovered in-file methods first (including synthesized framework accessors).
-    let method_prefix = context.prefix.rsplit("->").next().unwrap_or(&context.prefix);
-    for (name, symbols) in &symbol_table.symbols {
-        let is_callable = symbols
-            .iter()
-            .any(|symbol| matches!(symbol.kind, SymbolKind::Subroutine | SymbolKind::Method));
-        if !is_callable {
-            continue;
-        }
-
-        if !method_prefix.is_empty() && !name.starts_with(method_prefix) {
-            continue;
-        }
-
-        let documentation = symbols.iter().find_map(|symbol| symbol.documentation.clone());
-        if seen.insert(name.clone()) {
+    let mut push = |name: &str, package: &str, documentation: Option<String>, rank: u8| {
+        if name.starts_with(call.method_prefix.as_str()) && seen.insert(name.to_string()) {
             completions.push(CompletionItem {
-                label: name.clone(),
-                kind: crate::completion::items::CompletionItemKind::Function,
-                detail: Some("method".to_string()),
+                label: name.to_string(),
+                kind: CompletionItemKind::Method,
+                detail: Some(package.to_string()),
                 documentation,
                 insert_text: Some(format!("{}()", name)),
-                sort_text: Some(format!("1_{}", name)),
-                filter_text: Some(name.clone()),
+                insert_text_is_snippet: false,
+                sort_text: Some(format!("{}_{}", rank, name)),
+                filter_text: Some(name.to_string()),
                 additional_edits: vec![],
-                text_edit_range: Some((context.prefix_start, context.position)),
+                text_edit_range: Some((call.method_start, context.position)),
             });
         }
-    }
+    };
 
-    // Try to infer the receiver type from context
-    let receiver_type = infer_receiver_type(context, source);
-
-    // Choose methods based on inferred type
-    let methods: Vec<(&str, &str)> = match receiver_type.as_deref() {
-        Some("DBI::db") => DBI_DB_METHODS.to_vec(),
-        Some("DBI::st") => DBI_ST_METHODS.to_vec(),
-        _ => {
-            // Default common object methods
-            vec![
-                ("new", "Constructor"),
-                ("isa", "Check if object is of given class"),
-                ("can", "Check if object can call method"),
-                ("DOES", "Check if object does role"),
-                ("VERSION", "Get version"),
-            ]
+    if let Some(class) = receiver_class {
+        for (depth, package) in class_hierarchy(class, workspace_index).iter().enumerate() {
+            let rank = if depth == 0 { 1 } else { 2 };
+            for (name, documentation) in package_methods(package, symbol_table, workspace_index) {
+                push(&name, package, documentation, rank);
+            }
+        }
+    } else {
+        // DBI handles are recognised by name, as their class comes from a driver
+        let dbi_methods = match infer_receiver_type(&call.receiver, source).as_deref() {
+            Some("DBI::db") => DBI_DB_METHODS,
+            Some("DBI::st") => DBI_ST_METHODS,
+            _ => &[],
+        };
+        for (method, desc) in dbi_methods {
+            push(method, "DBI", Some(desc.to_string()), 1);
         }
-    };
 
-    for (method, desc) in methods {
-        let method_name = method.to_string();
-        if seen.insert(method_name.clone()) {
-            completions.push(CompletionItem {
-                label: method_name.clone(),
-                kind: crate::completion::items::CompletionItemKind::Function,
-                detail: Some("method".to_string()),
-                documentation: Some(desc.to_string()),
-                insert_text: Some(format!("{}()", method)),
-                sort_text: Some(format!("2_{}", method)),
-                filter_text: Some(method_name),
-                additional_edits: vec![],
-                text_edit_range: Some((context.prefix_start, context.position)),
-            });
+        for symbols in symbol_table.symbols.values() {
+            for symbol in symbols {
+                if let Some(package) = method_package(&symbol.name, &symbol.qualified_name)
+                    && matches!(symbol.kind, SymbolKind::Subroutine | SymbolKind::Method)
+                {
+                    push(&symbol.name, package, symbol.documentation.clone(), 1);
+                }
+            }
+        }
+
+        if let Some(index) = workspace_index {
+            for symbol in index.all_symbols() {
+                let package = symbol.container_name.as_deref().filter(|p| *p != "main");
+                if let Some(package) = package
+                    && matches!(symbol.kind, SymbolKind::Subroutine | SymbolKind::Method)
+                {
+                    push(&symbol.name, package, symbol.documentation.clone(), 2);
+                }
+            }
+        }
+    }
+
+    for (method, desc) in UNIVERSAL_METHODS {
+        push(method, "UNIVERSAL", Some(desc.to_string()), 9);
+    }
+}
+
+/// `class` followed by its ancestors in depth-first, left-to-right order
+fn class_hierarchy(class: &str, workspace_index: &Option<Arc<WorkspaceIndex>>) -> Vec<String> {
+    let mut order = Vec::new();
+    let mut stack = vec![class.to_string()];
+    while let Some(package) = stack.pop() {
+        if order.contains(&package) {
+            continue;
         }
+        if let Some(index) = workspace_index {
+            stack.extend(index.find_supertypes(&package).into_iter().rev());
+        }
+        order.push(package);
     }
+    order
+}
 
-    // If we have a DBI type, also add common methods at lower priority
-    if receiver_type.as_deref() == Some("DBI::db") || receiver_type.as_deref() == Some("DBI::st") {
-        for (method, desc) in [
-            ("isa", "Check if object is of given class"),
-            ("can", "Check if object can call method"),
-        ] {
-            let method_name = method.to_string();
-            if seen.insert(method_name.clone()) {
-                completions.push(CompletionItem {
-                    label: method_name.clone(),
-                    kind: crate::completion::items::CompletionItemKind::Function,
-                    detail: Some("method".to_string()),
-                    documentation: Some(desc.to_string()),
-                    insert_text: Some(format!("{}()", method)),
-                    sort_text: Some(format!("9_{}", method)), // Lower priority
-                    filter_text: Some(method_name),
-                    additional_edits: vec![],
-                    text_edit_range: Some((context.prefix_start, context.position)),
-                });
+/// Methods defined directly in `package`, from this file and the workspace
+fn package_methods(
+    package: &str,
+    symbol_table: &SymbolTable,
+    workspace_index: &Option<Arc<WorkspaceIndex>>,
+) -> Vec<(String, Option<String>)> {
+    let mut methods = Vec::new();
+    for symbols in symbol_table.symbols.values() {
+        for symbol in symbols {
+            if matches!(symbol.kind, SymbolKind::Subroutine | SymbolKind::Method)
+                && method_package(&symbol.name, &symbol.qualified_name) == Some(package)
+            {
+                methods.push((symbol.name.clone(), symbol.documentation.clone()));
             }
         }
     }
+    if let Some(index) = workspace_index {
+        for symbol in index.get_package_members(package) {
+            if matches!(symbol.kind, SymbolKind::Subroutine | SymbolKind::Method)
+                && symbol.container_name.as_deref() == Some(package)
+            {
+                methods.push((symbol.name, symbol.documentation));
+            }
+        }
+    }
+    methods
+}
+
+/// The package a subroutine is defined in, or `None` for subroutines of `main`
+fn method_package<'a>(name: &str, qualified_name: &'a str) -> Option<&'a str> {
+    let package = qualified_name.strip_suffix(name)?.strip_suffix("::")?;
+    (package != "main" && !package.is_empty()).then_some(package)
 }
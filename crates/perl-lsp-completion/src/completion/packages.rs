@@ -37,6 +37,7 @@ pub fn add_package_completions(
                         detail: Some(package_name.clone()),
                         documentation: symbol.documentation.clone(),
                         insert_text: Some(symbol.name.clone()),
+                        insert_text_is_snippet: false,
                         sort_text: Some(format!("1_{}", symbol.name)),
                         filter_text: Some(symbol.name.clone()),
                         additional_edits: vec![],
@@ -52,6 +53,7 @@ pub fn add_package_completions(
                         detail: Some(package_name.clone()),
                         documentation: symbol.documentation.clone(),
                         insert_text: Some(symbol.name.clone()),
+                        insert_text_is_snippet: false,
                         sort_text: Some(format!("1_{}", symbol.name)),
                         filter_text: Some(symbol.name.clone()),
                         additional_edits: vec![],
@@ -67,6 +69,7 @@ pub fn add_package_completions(
                         detail: Some(package_name.clone()),
                         documentation: symbol.documentation.clone(),
                         insert_text: Some(symbol.name.clone()),
+                        insert_text_is_snippet: false,
                         sort_text: Some(format!("1_{}", symbol.name)),
                         filter_text: Some(symbol.name.clone()),
                         additional_edits: vec![],
@@ -48,6 +48,7 @@ pub fn add_test_more_completions(
                 detail: Some("Test::More".to_string()),
                 documentation: Some(doc.to_string()),
                 insert_text: Some(snippet.to_string()),
+                insert_text_is_snippet: false,
                 sort_text: Some(format!("2_{}", name)),
                 filter_text: Some(name.to_string()),
                 additional_edits: vec![],
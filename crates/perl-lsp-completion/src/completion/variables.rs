@@ -35,6 +35,7 @@ pub fn add_variable_completions(
                     ),
                     documentation: symbol.documentation.clone(),
                     insert_text: Some(insert_text),
+                    insert_text_is_snippet: false,
                     sort_text: Some(format!("1_{}", name)), // Variables have high priority
                     filter_text: Some(name.clone()),
                     additional_edits: vec![],
@@ -93,6 +94,7 @@ pub fn add_special_variables(
                 detail: Some("special variable".to_string()),
                 documentation: Some(description.to_string()),
                 insert_text: Some(var.to_string()),
+                insert_text_is_snippet: false,
                 sort_text: Some(format!("0_{}", var)), // Special vars have highest priority
                 filter_text: Some(var.to_string()),
                 additional_edits: vec![],
@@ -123,6 +125,7 @@ pub fn add_all_variables(
                         )),
                         documentation: symbol.documentation.clone(),
                         insert_text: Some(format!("{}{}", sigil, name)),
+                        insert_text_is_snippet: false,
                         sort_text: Some(format!("5_{}", name)),
                         filter_text: Some(name.clone()),
                         additional_edits: vec![],
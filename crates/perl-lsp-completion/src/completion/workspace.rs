@@ -60,6 +60,7 @@ pub fn add_workspace_symbol_completions(
                     detail: symbol.container_name.clone().or_else(|| Some("workspace".to_string())),
                     documentation: symbol.documentation.clone(),
                     insert_text: Some(symbol.name.clone()),
+                    insert_text_is_snippet: false,
                     sort_text: Some(format!("3_{}", label)), // Sort after local symbols
                     filter_text: Some(label),
                     additional_edits: vec![],
@@ -91,6 +92,7 @@ pub fn add_workspace_symbol_completions(
                     detail: symbol.container_name.clone().or_else(|| Some("workspace".to_string())),
                     documentation: symbol.documentation.clone(),
                     insert_text: Some(label.clone()),
+                    insert_text_is_snippet: false,
                     sort_text: Some(format!("3_{}", label)), // Sort after local symbols
                     filter_text: Some(label),
                     additional_edits: vec![],
@@ -105,6 +107,7 @@ pub fn add_workspace_symbol_completions(
                     detail: Some("package".to_string()),
                     documentation: symbol.documentation.clone(),
                     insert_text: Some(symbol.name.clone()),
+                    insert_text_is_snippet: false,
                     sort_text: Some(format!("3_{}", symbol.name)),
                     filter_text: Some(symbol.name.clone()),
                     additional_edits: vec![],
@@ -119,6 +122,7 @@ pub fn add_workspace_symbol_completions(
                     detail: symbol.container_name.clone().or_else(|| Some("workspace".to_string())),
                     documentation: symbol.documentation.clone(),
                     insert_text: Some(symbol.name.clone()),
+                    insert_text_is_snippet: false,
                     sort_text: Some(format!("3_{}", symbol.name)),
                     filter_text: Some(symbol.name.clone()),
                     additional_edits: vec![],
@@ -133,6 +137,7 @@ pub fn add_workspace_symbol_completions(
                     detail: Some("exported".to_string()),
                     documentation: symbol.documentation.clone(),
                     insert_text: Some(symbol.name.clone()),
+                    insert_text_is_snippet: false,
                     sort_text: Some(format!("2_{}", symbol.name)), // Prioritize exports
                     filter_text: Some(symbol.name.clone()),
                     additional_edits: vec![],
@@ -27,7 +27,13 @@ pub fn detect_dead_code(
 ) -> Vec<Diagnostic> {
     use perl_workspace_index::workspace_index::SymbolKind;
 
-    let unused_symbols = workspace_index.find_unused_symbols();
+    // Subroutines come from the stricter pass that accounts for exports,
+    // method dispatch and by-name references
+    let unused_symbols = workspace_index
+        .find_unused_symbols()
+        .into_iter()
+        .filter(|symbol| symbol.kind != SymbolKind::Subroutine)
+        .chain(workspace_index.find_unused_subroutines());
     let mut diagnostics = Vec::new();
 
     for symbol in unused_symbols {
@@ -3,15 +3,40 @@
 //! This module provides the core diagnostic generation functionality.
 
 use perl_parser_core::Node;
+use perl_parser_core::ast::NodeKind;
 use perl_parser_core::error::ParseError;
 use perl_pragma::PragmaTracker;
-use perl_semantic_analyzer::scope_analyzer::ScopeAnalyzer;
+use perl_semantic_analyzer::scope_analyzer::{IssueKind, ScopeAnalyzer};
+use perl_semantic_analyzer::symbol::SymbolExtractor;
 
+use crate::lints::best_practices::{
+    ImplicitReturnOptions, check_bareword_filehandles, check_implicit_returns,
+    check_indirect_object_syntax, check_two_arg_open,
+};
+use crate::lints::common_mistakes::{
+    check_args_forwarding_tail_call, check_comparison_operator_mismatch, check_do_loop_control,
+    check_empty_repetition, check_format_argument_count, check_three_way_comparison_as_boolean,
+};
+use crate::lints::performance::check_hoistable_loop_declarations;
+use crate::lints::reachability::{check_constant_conditions, check_unreachable_statements};
+use crate::lints::strict_warnings::{check_missing_strict_warnings, check_pragma_placement};
+use crate::lints::unused::check_unused_lexicals;
 use crate::scope::scope_issues_to_diagnostics;
 
 // Re-export types from types module
 pub use crate::types::{Diagnostic, DiagnosticSeverity, DiagnosticTag, RelatedInformation};
 
+/// Opt-in hint checks that [`DiagnosticsProvider`] skips unless enabled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HintOptions {
+    /// Suggest `goto &sub` for subs that only forward `@_` to another sub
+    pub args_forwarding_tail_call: bool,
+    /// Suggest moving constant `my` declarations out of loop bodies
+    pub hoistable_loop_declarations: bool,
+    /// Flag subs that implicitly return a complex final expression; `None` skips the check
+    pub implicit_returns: Option<ImplicitReturnOptions>,
+}
+
 /// Diagnostics provider
 ///
 /// Analyzes Perl source code and generates diagnostic messages for
@@ -19,12 +44,30 @@ pub use crate::types::{Diagnostic, DiagnosticSeverity, DiagnosticTag, RelatedInf
 pub struct DiagnosticsProvider {
     _ast: std::sync::Arc<Node>,
     _source: String,
+    uri: Option<String>,
+    hints: HintOptions,
 }
 
 impl DiagnosticsProvider {
     /// Create a new diagnostics provider
     pub fn new(ast: &std::sync::Arc<Node>, source: String) -> Self {
-        Self { _ast: ast.clone(), _source: source }
+        Self { _ast: ast.clone(), _source: source, uri: None, hints: HintOptions::default() }
+    }
+
+    /// Set the URI of the document being analyzed
+    ///
+    /// File-level checks such as missing `use strict`/`use warnings` only run
+    /// for `.pl`, `.pm` and `.t` documents; without a URI the source is treated
+    /// as a fragment.
+    pub fn with_uri(mut self, uri: &str) -> Self {
+        self.uri = Some(uri.to_string());
+        self
+    }
+
+    /// Enable opt-in hint checks, which are off by default
+    pub fn with_hints(mut self, hints: HintOptions) -> Self {
+        self.hints = hints;
+        self
     }
 
     /// Generate diagnostics for the given AST
@@ -64,9 +107,95 @@ impl DiagnosticsProvider {
         // Run scope analysis to detect undeclared/unused/shadowing issues
         let pragma_map = PragmaTracker::build(ast);
         let scope_analyzer = ScopeAnalyzer::new();
-        let scope_issues = scope_analyzer.analyze(ast, source, &pragma_map);
+        let mut scope_issues = scope_analyzer.analyze(ast, source, &pragma_map);
+        // Unused lexicals come from the symbol table, which tracks reads and interpolation
+        scope_issues.retain(|issue| issue.kind != IssueKind::UnusedVariable);
         diagnostics.extend(scope_issues_to_diagnostics(scope_issues));
 
+        // Shared by the lints that resolve variable references
+        let symbol_table = SymbolExtractor::new_with_source(source).extract(ast);
+
+        // Flag my/state variables that are never read
+        check_unused_lexicals(&symbol_table, &mut diagnostics);
+
+        // Flag pragmas that come too late to cover earlier code
+        check_pragma_placement(ast, &pragma_map, &mut diagnostics);
+
+        // Flag missing strict/warnings in whole files, not fragments or snippets
+        if self.uri.as_deref().is_some_and(is_perl_file_uri) && !is_snippet(ast, source) {
+            check_missing_strict_warnings(ast, &pragma_map, &mut diagnostics);
+        }
+
+        // Flag repetitions that can only produce an empty result
+        check_empty_repetition(ast, &mut diagnostics);
+
+        // Flag next/last/redo that cannot target a do-while/do-until block
+        check_do_loop_control(ast, &mut diagnostics);
+
+        // Flag <=>/cmp used as a condition or as a discarded statement
+        check_three_way_comparison_as_boolean(ast, &mut diagnostics);
+
+        // Flag ==/!=/< on string literals and eq/ne on numeric literals
+        check_comparison_operator_mismatch(ast, source, &symbol_table, &mut diagnostics);
+
+        // Flag branches and loops decided by a constant condition
+        check_constant_conditions(ast, &mut diagnostics);
+
+        // Flag statements after an unconditional return/die/next/last in the same block
+        check_unreachable_statements(ast, &mut diagnostics);
+
+        // Flag bareword filehandles that should be lexical variables
+        check_bareword_filehandles(ast, source, &mut diagnostics);
+
+        // Flag two-argument open calls that fold the mode into the filename
+        check_two_arg_open(ast, source, &mut diagnostics);
+
+        // Flag indirect object constructor calls such as `new Foo(...)`
+        check_indirect_object_syntax(ast, &mut diagnostics);
+
+        // Flag sprintf/printf calls whose arguments do not match a literal format
+        check_format_argument_count(ast, &mut diagnostics);
+
+        // Suggest `goto &sub` for subs that only forward @_
+        if self.hints.args_forwarding_tail_call {
+            check_args_forwarding_tail_call(ast, &mut diagnostics);
+        }
+
+        // Suggest hoisting loop-invariant `my` declarations above the loop
+        if self.hints.hoistable_loop_declarations {
+            check_hoistable_loop_declarations(ast, source, &mut diagnostics);
+        }
+
+        // Flag complex final expressions that subs return without saying so
+        if let Some(options) = self.hints.implicit_returns {
+            check_implicit_returns(ast, options, &mut diagnostics);
+        }
+
         diagnostics
     }
 }
+
+/// Whether a document URI names a Perl script, module or test file
+fn is_perl_file_uri(uri: &str) -> bool {
+    let path = uri.split(['?', '#']).next().unwrap_or_default();
+    let file_name = path.rsplit('/').next().unwrap_or_default();
+    file_name
+        .rsplit_once('.')
+        .is_some_and(|(_, ext)| matches!(ext.to_ascii_lowercase().as_str(), "pl" | "pm" | "t"))
+}
+
+/// Whether the source is clearly a snippet rather than a complete file
+///
+/// A file has a shebang line, a `package` declaration or a `use`/`no`
+/// statement; bare code with none of those is treated as a snippet.
+fn is_snippet(ast: &Node, source: &str) -> bool {
+    if source.starts_with("#!") {
+        return false;
+    }
+    let NodeKind::Program { statements } = &ast.kind else {
+        return true;
+    };
+    !statements.iter().any(|stmt| {
+        matches!(stmt.kind, NodeKind::Package { .. } | NodeKind::Use { .. } | NodeKind::No { .. })
+    })
+}
@@ -44,13 +44,18 @@ mod types;
 mod walker;
 
 pub use diagnostics::{
-    Diagnostic, DiagnosticSeverity, DiagnosticTag, DiagnosticsProvider, RelatedInformation,
+    Diagnostic, DiagnosticSeverity, DiagnosticTag, DiagnosticsProvider, HintOptions,
+    RelatedInformation,
 };
 
 // Re-export lint checks from the lints module
+pub use lints::best_practices;
 pub use lints::common_mistakes;
 pub use lints::deprecated;
+pub use lints::performance;
+pub use lints::reachability;
 pub use lints::strict_warnings;
+pub use lints::unused;
 
 // Re-export dead code detection (when not targeting WASM)
 #[cfg(not(target_arch = "wasm32"))]
@@ -0,0 +1,407 @@
+//! Best practice lint checks
+//!
+//! This module provides the PL4xx checks that flag legal but risky idioms,
+//! such as bareword filehandles, two-argument `open` where the mode is
+//! folded into the filename, indirect object constructor calls, and subs
+//! whose result comes from a complex implicit return.
+
+use perl_diagnostics_codes::DiagnosticCode;
+use perl_parser_core::ast::{Node, NodeKind};
+
+use super::super::types::{Diagnostic, DiagnosticSeverity, RelatedInformation};
+
+/// Filehandles predeclared by Perl, which are always barewords
+pub const STANDARD_FILEHANDLES: &[&str] = &["STDIN", "STDOUT", "STDERR", "DATA", "ARGV"];
+
+/// Builtins that take a filehandle as their first argument
+const FILEHANDLE_FUNCTIONS: &[&str] = &[
+    "open", "close", "binmode", "eof", "fileno", "flock", "seek", "tell", "truncate", "readline",
+    "print", "printf", "say",
+];
+
+/// Other builtins whose leading bareword is a handle or program, not a class
+const HANDLE_BUILTINS: &[&str] =
+    &["pipe", "sysopen", "sysread", "syswrite", "fcntl", "ioctl", "select", "exec", "system"];
+
+/// A bareword filehandle named at a call site or in `<FH>`
+struct FilehandleUse<'a> {
+    name: &'a str,
+    range: (usize, usize),
+    is_open: bool,
+}
+
+/// Check for bareword filehandles passed to `open`, `print`, `close` and `<FH>`
+///
+/// Bareword handles are package globals, so two subroutines opening `FH`
+/// silently share (and clobber) one handle. Every non-standard use is
+/// reported on the handle name; [`lexical_filehandle_edits`] computes the
+/// rewrite to a `my` variable.
+pub fn check_bareword_filehandles(node: &Node, source: &str, diagnostics: &mut Vec<Diagnostic>) {
+    if let Some(handle) = filehandle_use(node, source) {
+        let lexical = lexical_filehandle_name(handle.name);
+        let suggestion = if handle.is_open {
+            format!("💡 Use a lexical filehandle: open(my {lexical}, ...)")
+        } else {
+            format!("💡 Use a lexical filehandle such as {lexical}")
+        };
+        diagnostics.push(Diagnostic {
+            range: handle.range,
+            severity: DiagnosticSeverity::Warning,
+            code: Some(DiagnosticCode::BarewordFilehandle.to_string()),
+            message: format!("Bareword filehandle '{}' is a package global", handle.name),
+            related_information: vec![RelatedInformation {
+                location: (node.location.start, node.location.end),
+                message: suggestion,
+            }],
+            tags: Vec::new(),
+        });
+    }
+
+    node.for_each_child(|child| check_bareword_filehandles(child, source, diagnostics));
+}
+
+/// Check for indirect object method calls such as `new Foo(...)`
+///
+/// Perl resolves `new Foo` as a method call only when no `new` subroutine is
+/// in scope, so the same line can silently become a function call. Builtins
+/// that take a filehandle (`print STDERR ...`) are not method calls and are
+/// left alone. The hint covers the method name and class.
+pub fn check_indirect_object_syntax(node: &Node, diagnostics: &mut Vec<Diagnostic>) {
+    if let NodeKind::IndirectCall { method, object, .. } = &node.kind
+        && let NodeKind::Identifier { name: class } = &object.kind
+        && !FILEHANDLE_FUNCTIONS.contains(&method.as_str())
+        && !HANDLE_BUILTINS.contains(&method.as_str())
+    {
+        diagnostics.push(Diagnostic {
+            range: (node.location.start, object.location.end),
+            severity: DiagnosticSeverity::Hint,
+            code: Some(DiagnosticCode::IndirectObjectSyntax.to_string()),
+            message: format!("Indirect object syntax '{method} {class}' is discouraged"),
+            related_information: vec![RelatedInformation {
+                location: (node.location.start, node.location.end),
+                message: format!("💡 Use the arrow form: {class}->{method}(...)"),
+            }],
+            tags: Vec::new(),
+        });
+    }
+
+    node.for_each_child(|child| check_indirect_object_syntax(child, diagnostics));
+}
+
+/// Tuning for [`check_implicit_returns`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImplicitReturnOptions {
+    /// Largest final expression, in AST nodes, still treated as an obvious
+    /// result; accessors such as `$self->{name}` and `$_[0]->{name}` fit
+    pub max_trivial_nodes: usize,
+}
+
+impl Default for ImplicitReturnOptions {
+    fn default() -> Self {
+        Self { max_trivial_nodes: 5 }
+    }
+}
+
+/// Builtins that never return, so a sub ending in them has no result
+const NON_RETURNING_FUNCTIONS: &[&str] = &["die", "croak", "confess", "exit"];
+
+/// Check for named subs and methods that return a complex final expression implicitly
+///
+/// A sub returns the value of its last statement, so `sub total { ...; $a + $b * tax($c) }`
+/// hands back a computed value without saying so. This is an opt-in hint
+/// that [`crate::DiagnosticsProvider`] runs only with
+/// [`crate::HintOptions::implicit_returns`] set. Only a final expression
+/// statement larger than [`ImplicitReturnOptions::max_trivial_nodes`] is
+/// reported; control structures, explicit `return` and calls to `die`/`exit`
+/// are left alone.
+pub fn check_implicit_returns(
+    node: &Node,
+    options: ImplicitReturnOptions,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let named_body = match &node.kind {
+        NodeKind::Subroutine { name: Some(name), body: Some(body), .. } => Some((name, body)),
+        NodeKind::Method { name, body, .. } => Some((name, body)),
+        _ => None,
+    };
+
+    if let Some((name, body)) = named_body
+        && let NodeKind::Block { statements } = &body.kind
+        && let Some(last) = statements.last()
+        && let NodeKind::ExpressionStatement { expression } = &last.kind
+        && !matches!(&expression.kind, NodeKind::FunctionCall { name, .. }
+            if NON_RETURNING_FUNCTIONS.contains(&name.as_str()))
+        && expression.count_nodes() > options.max_trivial_nodes
+    {
+        diagnostics.push(Diagnostic {
+            range: (expression.location.start, expression.location.end),
+            severity: DiagnosticSeverity::Hint,
+            code: Some(DiagnosticCode::ImplicitReturn.to_string()),
+            message: format!("'{name}' implicitly returns the value of its last expression"),
+            related_information: vec![RelatedInformation {
+                location: (node.location.start, node.location.end),
+                message: "💡 Add an explicit 'return' to make the result clear".to_string(),
+            }],
+            tags: Vec::new(),
+        });
+    }
+
+    node.for_each_child(|child| check_implicit_returns(child, options, diagnostics));
+}
+
+/// Name of the lexical variable that replaces a bareword filehandle (`FH` → `$fh`)
+pub fn lexical_filehandle_name(bareword: &str) -> String {
+    format!("${}", bareword.to_ascii_lowercase())
+}
+
+/// Edits converting the bareword filehandle at `range` into a lexical one
+///
+/// The conversion covers the innermost block around `range` that also opens
+/// the handle: its first `open` declares the variable (`open(my $fh, ...)`)
+/// and every other use in that block is renamed to `$fh`. Returns `None`
+/// when `range` is not a bareword filehandle or no enclosing block opens it.
+pub fn lexical_filehandle_edits(
+    ast: &Node,
+    source: &str,
+    range: (usize, usize),
+) -> Option<Vec<((usize, usize), String)>> {
+    let name = source.get(range.0..range.1)?;
+    let mut scope = None;
+    find_filehandle_scope(ast, source, name, range, &mut scope);
+    let scope = scope?;
+
+    let mut uses = Vec::new();
+    collect_filehandle_uses(scope, source, name, &mut uses);
+    uses.sort_by_key(|handle| handle.range);
+
+    let lexical = lexical_filehandle_name(name);
+    let mut declared = false;
+    let edits = uses
+        .into_iter()
+        .map(|handle| {
+            let text = if handle.is_open && !declared {
+                declared = true;
+                format!("my {lexical}")
+            } else {
+                lexical.clone()
+            };
+            (handle.range, text)
+        })
+        .collect();
+    Some(edits)
+}
+
+/// Find the innermost block containing `range` that opens the handle `name`
+fn find_filehandle_scope<'a>(
+    node: &'a Node,
+    source: &str,
+    name: &str,
+    range: (usize, usize),
+    scope: &mut Option<&'a Node>,
+) {
+    if node.location.start > range.0 || node.location.end < range.1 {
+        return;
+    }
+    if matches!(node.kind, NodeKind::Program { .. } | NodeKind::Block { .. }) {
+        let mut uses = Vec::new();
+        collect_filehandle_uses(node, source, name, &mut uses);
+        if uses.iter().any(|handle| handle.is_open) {
+            *scope = Some(node);
+        }
+    }
+    node.for_each_child(|child| find_filehandle_scope(child, source, name, range, scope));
+}
+
+fn collect_filehandle_uses<'a>(
+    node: &'a Node,
+    source: &str,
+    name: &str,
+    uses: &mut Vec<FilehandleUse<'a>>,
+) {
+    if let Some(handle) = filehandle_use(node, source)
+        && handle.name == name
+    {
+        uses.push(handle);
+    }
+    node.for_each_child(|child| collect_filehandle_uses(child, source, name, uses));
+}
+
+/// The non-standard bareword filehandle this node names, if any
+fn filehandle_use<'a>(node: &'a Node, source: &str) -> Option<FilehandleUse<'a>> {
+    let (name, range, is_open) = match &node.kind {
+        NodeKind::FunctionCall { name: function, args }
+            if FILEHANDLE_FUNCTIONS.contains(&function.as_str()) =>
+        {
+            let handle = call_args(args).first()?;
+            let NodeKind::Identifier { name } = &handle.kind else {
+                return None;
+            };
+            (name.as_str(), (handle.location.start, handle.location.end), function == "open")
+        }
+        NodeKind::IndirectCall { method, object, .. }
+            if FILEHANDLE_FUNCTIONS.contains(&method.as_str()) =>
+        {
+            let NodeKind::Identifier { name } = &object.kind else {
+                return None;
+            };
+            (name.as_str(), (object.location.start, object.location.end), method == "open")
+        }
+        NodeKind::Readline { filehandle: Some(name) } => {
+            // The handle name sits between the angle brackets of `<FH>`
+            let range = (node.location.start + 1, node.location.end.checked_sub(1)?);
+            if source.get(range.0..range.1) != Some(name.as_str()) {
+                return None;
+            }
+            (name.as_str(), range, false)
+        }
+        _ => return None,
+    };
+
+    let is_bareword = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    (is_bareword && !STANDARD_FILEHANDLES.contains(&name)).then_some(FilehandleUse {
+        name,
+        range,
+        is_open,
+    })
+}
+
+/// Check for two-argument `open` calls whose second argument is a string literal
+///
+/// `open(FH, "<$file")` parses the mode out of the filename, so a name with
+/// leading `>` or trailing `|` silently changes what the call does. The
+/// diagnostic covers the mode-and-filename argument, which is what the
+/// three-argument rewrite from [`three_arg_open_args`] replaces.
+pub fn check_two_arg_open(node: &Node, source: &str, diagnostics: &mut Vec<Diagnostic>) {
+    if let NodeKind::FunctionCall { name, args } = &node.kind
+        && name == "open"
+        && let [_, target] = call_args(args)
+        && matches!(target.kind, NodeKind::String { .. })
+        && let Some(text) = source.get(target.location.start..target.location.end)
+    {
+        let suggestion = match three_arg_open_args(text) {
+            Some((mode, filename)) => format!("💡 Use three-argument open: '{mode}', {filename}"),
+            None => "💡 Pass the mode as a separate argument to open".to_string(),
+        };
+        diagnostics.push(Diagnostic {
+            range: (target.location.start, target.location.end),
+            severity: DiagnosticSeverity::Warning,
+            code: Some(DiagnosticCode::TwoArgOpen.to_string()),
+            message: "Two-argument 'open' takes its mode from the filename string".to_string(),
+            related_information: vec![RelatedInformation {
+                location: (node.location.start, node.location.end),
+                message: suggestion,
+            }],
+            tags: Vec::new(),
+        });
+    }
+
+    // open can appear inside any expression (`open(...) or die`), so visit every child
+    node.for_each_child(|child| check_two_arg_open(child, source, diagnostics));
+}
+
+/// Arguments of a builtin call, looking through a parenthesised argument list
+///
+/// `open(FH, $spec)` at the start of a statement is parsed with its
+/// parenthesised arguments as a single list literal.
+fn call_args(args: &[Node]) -> &[Node] {
+    match args {
+        [Node { kind: NodeKind::ArrayLiteral { elements }, .. }] => elements,
+        _ => args,
+    }
+}
+
+/// Split the string argument of a two-argument `open` into a mode and a filename
+///
+/// Takes the source text of the literal (`"<$file"`, `'>> log.txt'`,
+/// `"ls |"`) and returns the mode for the three-argument form together with
+/// the filename as Perl source. A filename that is just an interpolated
+/// scalar is returned bare (`$file`); anything else keeps the original
+/// quoting. Returns `None` for text that is not a quoted literal, and for
+/// `-`, `>-` and an empty filename, which mean STDIN/STDOUT or a fork only
+/// in the two-argument form.
+pub fn three_arg_open_args(literal: &str) -> Option<(String, String)> {
+    let (open_quote, body, close_quote, interpolated) = split_quoted(literal)?;
+    let (mode, rest) = split_open_spec(body.trim());
+
+    let rest = rest.trim();
+    if rest.is_empty() || rest == "-" {
+        return None;
+    }
+
+    let filename = if interpolated && is_simple_scalar(rest) {
+        rest.to_string()
+    } else {
+        format!("{open_quote}{rest}{close_quote}")
+    };
+    Some((mode, filename))
+}
+
+/// Split a two-argument open spec such as `>> log.txt` into mode and remainder
+fn split_open_spec(spec: &str) -> (String, &str) {
+    if let Some(command) = spec.strip_prefix('|') {
+        return ("|-".to_string(), command);
+    }
+    if let Some(command) = spec.strip_suffix('|') {
+        return ("-|".to_string(), command);
+    }
+
+    // Without an explicit mode the two-argument form opens for reading
+    let Some(base) =
+        ["+>>", "+<", "+>", ">>", "<", ">"].into_iter().find(|mode| spec.starts_with(mode))
+    else {
+        return ("<".to_string(), spec);
+    };
+    let mut mode = base.to_string();
+    let mut rest = &spec[base.len()..];
+
+    // `>&STDOUT` and `<&=3` duplicate a handle rather than naming a file
+    if let Some(dup) = rest.strip_prefix('&') {
+        mode.push('&');
+        rest = dup;
+        if let Some(fd) = rest.strip_prefix('=') {
+            mode.push('=');
+            rest = fd;
+        }
+    }
+    (mode, rest)
+}
+
+/// Split a quoted literal into its opening delimiter, body, closing delimiter,
+/// and whether it interpolates
+pub(crate) fn split_quoted(literal: &str) -> Option<(&str, &str, &str, bool)> {
+    let (open_len, interpolated) = if literal.starts_with('"') {
+        (1, true)
+    } else if literal.starts_with('\'') {
+        (1, false)
+    } else if let Some(after) = literal.strip_prefix("qq") {
+        (2 + after.chars().next()?.len_utf8(), true)
+    } else if let Some(after) = literal.strip_prefix('q') {
+        (1 + after.chars().next()?.len_utf8(), false)
+    } else {
+        return None;
+    };
+
+    let close_len = literal.chars().next_back()?.len_utf8();
+    if literal.len() < open_len + close_len {
+        return None;
+    }
+    let close_start = literal.len() - close_len;
+    Some((
+        &literal[..open_len],
+        &literal[open_len..close_start],
+        &literal[close_start..],
+        interpolated,
+    ))
+}
+
+/// Whether text is a lone scalar such as `$file`, `${file}` or `$Pkg::file`
+fn is_simple_scalar(text: &str) -> bool {
+    let Some(name) = text.strip_prefix('$') else {
+        return false;
+    };
+    let name = name.strip_prefix('{').and_then(|n| n.strip_suffix('}')).unwrap_or(name);
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':')
+}
@@ -1,13 +1,23 @@
 //! Common mistakes lint checks
 //!
 //! This module provides functionality for detecting common mistakes in Perl code
-//! such as assignment in conditions and comparing with undef.
+//! such as assignment in conditions, comparing with undef, repetition
+//! counts that always produce an empty result, loop control inside
+//! `do { } while` blocks, `<=>`/`cmp` used as a boolean, `==` applied to
+//! strings and `eq` applied to numbers, subs that only forward `@_` where
+//! `goto &sub` would do, and `sprintf`/`printf` calls whose argument count
+//! does not match their format.
+
+use std::cell::OnceCell;
 
 use perl_parser_core::ast::{Node, NodeKind};
+use perl_parser_core::builtin_signatures_phf::is_builtin;
 use perl_semantic_analyzer::symbol::{SymbolKind, SymbolTable};
+use perl_semantic_analyzer::type_inference::{PerlType, ScalarType, TypeInferenceEngine};
 
 use super::super::types::{Diagnostic, DiagnosticSeverity, RelatedInformation};
 use super::super::walker::walk_node;
+use super::best_practices::split_quoted;
 
 /// Check for common mistakes
 ///
@@ -50,6 +60,614 @@ pub fn check_common_mistakes(
     });
 }
 
+/// Check for repetition with a literal zero or negative count
+///
+/// `"ab" x 0` and `(1) x -3` both produce an empty result, which is rarely
+/// what was intended. Variable counts are not flagged.
+pub fn check_empty_repetition(node: &Node, diagnostics: &mut Vec<Diagnostic>) {
+    if let NodeKind::Binary { op, right, .. } = &node.kind
+        && op == "x"
+        && let Some(count) = literal_count(right)
+        && count <= 0.0
+    {
+        diagnostics.push(Diagnostic {
+            range: (node.location.start, node.location.end),
+            severity: DiagnosticSeverity::Hint,
+            code: Some("empty-repetition".to_string()),
+            message: format!(
+                "Repetition count {} always produces an empty result",
+                count_text(right)
+            ),
+            related_information: vec![RelatedInformation {
+                location: (right.location.start, right.location.end),
+                message: "ℹ️ 'x' with a count of zero or less yields an empty string or list"
+                    .to_string(),
+            }],
+            tags: Vec::new(),
+        });
+    }
+
+    // Repetitions appear anywhere in expressions, so visit every child
+    node.for_each_child(|child| check_empty_repetition(child, diagnostics));
+}
+
+/// Check for `next`/`last`/`redo` directly inside a `do { } while`/`until` block
+///
+/// A `do` block is not a loop body, so loop control there dies at runtime
+/// unless an enclosing loop or bare block gives it a target. Wrapping the
+/// statements in a bare block (or the whole loop in a labeled one) is the
+/// usual fix.
+pub fn check_do_loop_control(node: &Node, diagnostics: &mut Vec<Diagnostic>) {
+    visit_do_loops(node, false, diagnostics);
+}
+
+/// Walk the tree, tracking whether an enclosing loop or bare block exists
+fn visit_do_loops(node: &Node, has_target: bool, diagnostics: &mut Vec<Diagnostic>) {
+    if let NodeKind::DoWhile { block, .. } | NodeKind::DoUntil { block, .. } = &node.kind
+        && !has_target
+    {
+        let keyword = if matches!(node.kind, NodeKind::DoWhile { .. }) { "while" } else { "until" };
+        let mut controls = Vec::new();
+        collect_unlabeled_loop_control(block, &mut controls);
+
+        for control in controls {
+            let NodeKind::LoopControl { op, .. } = &control.kind else {
+                continue;
+            };
+            diagnostics.push(Diagnostic {
+                range: (control.location.start, control.location.end),
+                severity: DiagnosticSeverity::Warning,
+                code: Some("do-block-loop-control".to_string()),
+                message: format!(
+                    "'{op}' does not apply to a 'do {{ }} {keyword}' block, which is not a loop body"
+                ),
+                related_information: vec![RelatedInformation {
+                    location: (node.location.start, node.location.end),
+                    message: "ℹ️ Wrap the block contents in a bare block or use a labeled loop"
+                        .to_string(),
+                }],
+                tags: Vec::new(),
+            });
+        }
+    }
+
+    let has_target = match &node.kind {
+        NodeKind::While { .. } | NodeKind::For { .. } | NodeKind::Foreach { .. } => true,
+        NodeKind::LabeledStatement { statement, .. }
+            if matches!(statement.kind, NodeKind::Block { .. }) =>
+        {
+            true
+        }
+        NodeKind::Subroutine { .. } | NodeKind::Method { .. } => false,
+        _ => has_target,
+    };
+
+    match &node.kind {
+        // A block used as a statement is a bare block, which loop control can target
+        NodeKind::Program { statements } | NodeKind::Block { statements } => {
+            for statement in statements {
+                let is_bare_block = matches!(statement.kind, NodeKind::Block { .. });
+                visit_do_loops(statement, has_target || is_bare_block, diagnostics);
+            }
+        }
+        _ => node.for_each_child(|child| visit_do_loops(child, has_target, diagnostics)),
+    }
+}
+
+/// Collect unlabeled loop control that would target the enclosing `do` block
+///
+/// Stops at nested loops, subroutines and bare blocks, which give `next`,
+/// `last` and `redo` a valid target of their own.
+fn collect_unlabeled_loop_control<'a>(node: &'a Node, controls: &mut Vec<&'a Node>) {
+    match &node.kind {
+        NodeKind::LoopControl { label: None, .. } => controls.push(node),
+        NodeKind::While { .. }
+        | NodeKind::For { .. }
+        | NodeKind::Foreach { .. }
+        | NodeKind::DoWhile { .. }
+        | NodeKind::DoUntil { .. }
+        | NodeKind::Subroutine { .. }
+        | NodeKind::Method { .. } => {}
+        NodeKind::Block { statements } => {
+            for statement in statements {
+                if !matches!(statement.kind, NodeKind::Block { .. }) {
+                    collect_unlabeled_loop_control(statement, controls);
+                }
+            }
+        }
+        _ => node.for_each_child(|child| collect_unlabeled_loop_control(child, controls)),
+    }
+}
+
+/// Check for `<=>`/`cmp` results used as a boolean or thrown away
+///
+/// A three-way comparison returns -1, 0 or 1, so as an `if`/`while`
+/// condition it is true whenever the operands differ, and as a statement its
+/// result is discarded. Sort comparator blocks are skipped, and so is the
+/// final statement of any block, which may be its return value.
+pub fn check_three_way_comparison_as_boolean(node: &Node, diagnostics: &mut Vec<Diagnostic>) {
+    match &node.kind {
+        NodeKind::FunctionCall { name, .. } if name == "sort" => return,
+        NodeKind::If { condition, elsif_branches, .. } => {
+            flag_three_way_condition(condition, diagnostics);
+            for (condition, _) in elsif_branches {
+                flag_three_way_condition(condition, diagnostics);
+            }
+        }
+        NodeKind::While { condition, .. }
+        | NodeKind::DoWhile { condition, .. }
+        | NodeKind::DoUntil { condition, .. }
+        | NodeKind::Ternary { condition, .. } => flag_three_way_condition(condition, diagnostics),
+        NodeKind::StatementModifier { modifier, condition, .. }
+            if matches!(modifier.as_str(), "if" | "unless" | "while" | "until") =>
+        {
+            flag_three_way_condition(condition, diagnostics);
+        }
+        NodeKind::Program { statements } | NodeKind::Block { statements } => {
+            let discarded = statements.len().saturating_sub(1);
+            for statement in &statements[..discarded] {
+                let expression = match &statement.kind {
+                    NodeKind::ExpressionStatement { expression } => expression,
+                    _ => statement,
+                };
+                if let Some(op) = three_way_op(expression) {
+                    push_three_way_hint(
+                        expression,
+                        format!("Result of '{op}' is discarded"),
+                        diagnostics,
+                    );
+                }
+            }
+        }
+        _ => {}
+    }
+
+    node.for_each_child(|child| check_three_way_comparison_as_boolean(child, diagnostics));
+}
+
+/// Flag a condition that is a (possibly negated) three-way comparison
+fn flag_three_way_condition(condition: &Node, diagnostics: &mut Vec<Diagnostic>) {
+    let mut condition = condition;
+    while let NodeKind::Unary { op, operand } = &condition.kind
+        && matches!(op.as_str(), "!" | "not")
+    {
+        condition = operand;
+    }
+    if let Some(op) = three_way_op(condition) {
+        push_three_way_hint(
+            condition,
+            format!("'{op}' used as a boolean is true whenever the operands differ"),
+            diagnostics,
+        );
+    }
+}
+
+/// The operator of a `<=>` or `cmp` comparison
+fn three_way_op(node: &Node) -> Option<&str> {
+    match &node.kind {
+        NodeKind::Binary { op, .. } if op == "<=>" || op == "cmp" => Some(op),
+        _ => None,
+    }
+}
+
+fn push_three_way_hint(node: &Node, message: String, diagnostics: &mut Vec<Diagnostic>) {
+    diagnostics.push(Diagnostic {
+        range: (node.location.start, node.location.end),
+        severity: DiagnosticSeverity::Hint,
+        code: Some("three-way-comparison-as-boolean".to_string()),
+        message,
+        related_information: vec![RelatedInformation {
+            location: (node.location.start, node.location.end),
+            message: "💡 Use '=='/'!=' or 'eq'/'ne' to test for (in)equality".to_string(),
+        }],
+        tags: Vec::new(),
+    });
+}
+
+/// Check for numeric comparisons of strings and string comparisons of numbers
+///
+/// `$str == "foo"` numifies the string to 0, and `$n eq 5` compares `5.0`
+/// unequal to `5`. Numeric operators are flagged when an operand is a string
+/// literal that does not look like a number; `eq`/`ne` are flagged when an
+/// operand is a numeric literal and the other is not known to hold a string,
+/// using inferred declaration types for variables. The diagnostic covers the
+/// operator so a quick fix can swap it for [`corrected_comparison_operator`].
+pub fn check_comparison_operator_mismatch(
+    node: &Node,
+    source: &str,
+    table: &SymbolTable,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    // Inference only runs once an `eq`/`ne` against a number needs a variable's type
+    let types = LazyTypes { root: node, engine: OnceCell::new() };
+    visit_comparisons(node, source, table, &types, diagnostics);
+}
+
+/// Declaration types for a tree, inferred on first use
+struct LazyTypes<'a> {
+    root: &'a Node,
+    engine: OnceCell<TypeInferenceEngine>,
+}
+
+impl LazyTypes<'_> {
+    fn engine(&self) -> &TypeInferenceEngine {
+        self.engine.get_or_init(|| {
+            let mut engine = TypeInferenceEngine::new();
+            // Constraint violations only make `infer` return Err; the types are still recorded
+            let _ = engine.infer(self.root);
+            engine
+        })
+    }
+}
+
+fn visit_comparisons(
+    node: &Node,
+    source: &str,
+    table: &SymbolTable,
+    types: &LazyTypes<'_>,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    if let Some(diagnostic) = comparison_mismatch(node, source, table, types) {
+        diagnostics.push(diagnostic);
+    }
+    node.for_each_child(|child| visit_comparisons(child, source, table, types, diagnostics));
+}
+
+/// The mismatch diagnostic for a comparison node, covering its operator
+fn comparison_mismatch(
+    node: &Node,
+    source: &str,
+    table: &SymbolTable,
+    types: &LazyTypes<'_>,
+) -> Option<Diagnostic> {
+    let NodeKind::Binary { op, left, right } = &node.kind else {
+        return None;
+    };
+    let replacement = corrected_comparison_operator(op)?;
+    let operands = [left.as_ref(), right.as_ref()];
+
+    let (message, severity) = if matches!(op.as_str(), "eq" | "ne") {
+        let has_number = operands.iter().any(|o| matches!(o.kind, NodeKind::Number { .. }));
+        let has_string = operands.iter().any(|o| operand_holds_string(o, table, types));
+        if !has_number || has_string {
+            return None;
+        }
+        (format!("Number compared with string operator '{op}'"), DiagnosticSeverity::Information)
+    } else {
+        if !operands.iter().any(|o| is_non_numeric_string_literal(o)) {
+            return None;
+        }
+        (
+            format!("String compared with numeric operator '{op}'; it is treated as 0"),
+            DiagnosticSeverity::Warning,
+        )
+    };
+
+    let offset = source.get(left.location.end..right.location.start)?.find(op.as_str())?;
+    let start = left.location.end + offset;
+    Some(Diagnostic {
+        range: (start, start + op.len()),
+        severity,
+        code: Some("comparison-operator-mismatch".to_string()),
+        message,
+        related_information: vec![RelatedInformation {
+            location: (node.location.start, node.location.end),
+            message: format!("💡 Use '{replacement}' instead of '{op}'"),
+        }],
+        tags: Vec::new(),
+    })
+}
+
+/// The operator of the other comparison family that a mismatched comparison meant
+///
+/// Numeric operators map to their string counterparts; only `eq` and `ne` map
+/// back, since `lt`/`gt` on numbers are sometimes intended.
+pub fn corrected_comparison_operator(op: &str) -> Option<&'static str> {
+    Some(match op {
+        "==" => "eq",
+        "!=" => "ne",
+        "<" => "lt",
+        ">" => "gt",
+        "<=" => "le",
+        ">=" => "ge",
+        "<=>" => "cmp",
+        "eq" => "==",
+        "ne" => "!=",
+        _ => return None,
+    })
+}
+
+/// Whether a node is a string literal that numifies to 0 with a warning
+fn is_non_numeric_string_literal(node: &Node) -> bool {
+    string_literal_text(node).is_some_and(|text| !looks_like_number(text))
+}
+
+/// Whether an operand is a string literal or a variable declared with a string value
+fn operand_holds_string(node: &Node, table: &SymbolTable, types: &LazyTypes<'_>) -> bool {
+    if let NodeKind::String { .. } = node.kind {
+        return true;
+    }
+    let NodeKind::Variable { sigil, name } = &node.kind else {
+        return false;
+    };
+    if sigil != "$" {
+        return false;
+    }
+    table
+        .references
+        .get(name)
+        .and_then(|refs| refs.iter().find(|r| r.location == node.location))
+        .and_then(|reference| table.resolve_reference(reference))
+        .and_then(|symbol| types.engine().get_declaration_type(symbol.location.start))
+        .is_some_and(|ty| *ty == PerlType::Scalar(ScalarType::String))
+}
+
+/// The contents of a quoted literal without interpolated variables
+fn string_literal_text(node: &Node) -> Option<&str> {
+    let NodeKind::String { value, interpolated } = &node.kind else {
+        return None;
+    };
+    let (_, body, _, _) = split_quoted(value)?;
+    (!*interpolated || !body.contains(['$', '@'])).then_some(body)
+}
+
+/// Whether Perl numifies text without a warning
+///
+/// Strings are always read as decimal, so `"0x10"` numifies to 0.
+fn looks_like_number(text: &str) -> bool {
+    let text = text.trim();
+    let digits = text.strip_prefix(['+', '-']).unwrap_or(text);
+    matches!(digits.to_ascii_lowercase().as_str(), "inf" | "infinity" | "nan")
+        || digits.parse::<f64>().is_ok()
+}
+
+/// Check for `sprintf`/`printf` calls whose argument count does not match the format
+///
+/// Only literal formats are checked, and only when every argument is a
+/// single value, since arrays and calls can supply any number of them.
+/// Perl warns with "Missing argument" or "Redundant argument" at runtime.
+pub fn check_format_argument_count(node: &Node, diagnostics: &mut Vec<Diagnostic>) {
+    let call = match &node.kind {
+        NodeKind::FunctionCall { name, args } if name == "sprintf" || name == "printf" => {
+            Some((name.as_str(), flatten_parenthesized(args)))
+        }
+        NodeKind::IndirectCall { method, args, .. } if method == "printf" => {
+            Some((method.as_str(), args.as_slice()))
+        }
+        _ => None,
+    };
+
+    if let Some((name, args)) = call
+        && let Some((format, values)) = args.split_first()
+        && let Some(expected) = literal_format_argument_count(format)
+        && values.iter().all(is_single_value)
+        && values.len() != expected
+    {
+        let given = values.len();
+        let kind = if given < expected { "Missing" } else { "Redundant" };
+        diagnostics.push(Diagnostic {
+            range: (node.location.start, node.location.end),
+            severity: DiagnosticSeverity::Warning,
+            code: Some("format-argument-count".to_string()),
+            message: format!(
+                "{kind} argument in {name}: format expects {expected} argument{} but {given} {} given",
+                if expected == 1 { "" } else { "s" },
+                if given == 1 { "is" } else { "are" },
+            ),
+            related_information: vec![RelatedInformation {
+                location: (format.location.start, format.location.end),
+                message: "ℹ️ Each conversion, and each '*' width or precision, consumes one argument"
+                    .to_string(),
+            }],
+            tags: Vec::new(),
+        });
+    }
+
+    node.for_each_child(|child| check_format_argument_count(child, diagnostics));
+}
+
+/// Arguments of a call, looking through the list node of `printf(...)`
+fn flatten_parenthesized(args: &[Node]) -> &[Node] {
+    match args {
+        [Node { kind: NodeKind::ArrayLiteral { elements }, .. }] => elements,
+        _ => args,
+    }
+}
+
+/// Number of arguments a literal format consumes, or `None` for dynamic formats
+fn literal_format_argument_count(format: &Node) -> Option<usize> {
+    let NodeKind::String { value, .. } = &format.kind else {
+        return None;
+    };
+    let (_, body, _, interpolated) = split_quoted(value)?;
+    if interpolated && body.contains(['$', '@']) {
+        return None;
+    }
+    Some(format_argument_count(body))
+}
+
+/// Count the arguments consumed by the conversions of a `sprintf` format
+///
+/// Follows `perldoc -f sprintf`: `%%` is literal, `*` widths, precisions and
+/// vector joiners take an argument each, and explicit `N$` indexes refer to
+/// argument `N` without advancing the implicit position.
+fn format_argument_count(format: &str) -> usize {
+    let bytes = format.as_bytes();
+    let mut i = 0;
+    let mut next = 0;
+    let mut required = 0;
+    let mut consume = |index: Option<usize>| {
+        let position = index.unwrap_or_else(|| {
+            next += 1;
+            next
+        });
+        required = required.max(position);
+    };
+
+    while i < bytes.len() {
+        if bytes[i] != b'%' {
+            i += 1;
+            continue;
+        }
+        i += 1;
+        if bytes.get(i) == Some(&b'%') {
+            i += 1;
+            continue;
+        }
+
+        let index = take_explicit_index(bytes, &mut i);
+        while bytes.get(i).is_some_and(|b| b" +-0#".contains(b)) {
+            i += 1;
+        }
+
+        // Vector flag, optionally with a custom joiner string argument
+        if bytes.get(i) == Some(&b'*') {
+            let mut j = i + 1;
+            let joiner = take_explicit_index(bytes, &mut j);
+            if bytes.get(j) == Some(&b'v') {
+                consume(joiner);
+                i = j + 1;
+            }
+        } else if bytes.get(i) == Some(&b'v') {
+            i += 1;
+        }
+
+        // Width, then precision
+        if bytes.get(i) == Some(&b'*') {
+            i += 1;
+            consume(take_explicit_index(bytes, &mut i));
+        } else {
+            skip_digits(bytes, &mut i);
+        }
+        if bytes.get(i) == Some(&b'.') {
+            i += 1;
+            if bytes.get(i) == Some(&b'*') {
+                i += 1;
+                consume(take_explicit_index(bytes, &mut i));
+            } else {
+                skip_digits(bytes, &mut i);
+            }
+        }
+
+        while bytes.get(i).is_some_and(|b| b"hljqLtzV".contains(b)) {
+            i += 1;
+        }
+        if bytes.get(i).is_some_and(|b| b"csduoxXeEfFgGaAbBpniDUO".contains(b)) {
+            consume(index);
+            i += 1;
+        }
+    }
+
+    required
+}
+
+/// Parse an explicit `N$` argument index at `i`, advancing past it
+fn take_explicit_index(bytes: &[u8], i: &mut usize) -> Option<usize> {
+    let mut end = *i;
+    skip_digits(bytes, &mut end);
+    if end == *i || bytes.get(end) != Some(&b'$') {
+        return None;
+    }
+    let index = std::str::from_utf8(&bytes[*i..end]).ok()?.parse().ok()?;
+    *i = end + 1;
+    Some(index)
+}
+
+fn skip_digits(bytes: &[u8], i: &mut usize) {
+    while bytes.get(*i).is_some_and(u8::is_ascii_digit) {
+        *i += 1;
+    }
+}
+
+/// Whether an argument expression always supplies exactly one value
+fn is_single_value(node: &Node) -> bool {
+    match &node.kind {
+        NodeKind::Variable { sigil, .. } => sigil == "$",
+        NodeKind::Number { .. } | NodeKind::String { .. } => true,
+        NodeKind::Binary { op, left, .. } if op == "[]" || op == "{}" => {
+            matches!(&left.kind, NodeKind::Variable { sigil, .. } if sigil == "$")
+        }
+        NodeKind::Binary { op, .. } => !matches!(op.as_str(), "x" | ".." | "..." | ","),
+        NodeKind::Unary { op, operand } => op != "\\" || is_single_value(operand),
+        _ => false,
+    }
+}
+
+/// Check for subs whose only action is forwarding `@_` to another sub
+///
+/// `return foo(@_)` copies the argument list, while `goto &foo` hands the
+/// current `@_` to `foo` as a true tail call. This is an opt-in
+/// informational hint that [`crate::DiagnosticsProvider`] runs only with
+/// [`crate::HintOptions::args_forwarding_tail_call`] set. It only fires when the sub body is a single call passing `@_` unmodified to
+/// a non-builtin sub, either returned or as the lone statement.
+pub fn check_args_forwarding_tail_call(node: &Node, diagnostics: &mut Vec<Diagnostic>) {
+    if let NodeKind::Subroutine { name: Some(_), body: Some(body), .. } = &node.kind
+        && let Some((statement, callee)) = sole_args_forwarding_call(body)
+    {
+        diagnostics.push(Diagnostic {
+            range: (statement.location.start, statement.location.end),
+            severity: DiagnosticSeverity::Information,
+            code: Some("args-forwarding-tail-call".to_string()),
+            message: format!(
+                "Sub only forwards @_ to '{callee}'; 'goto &{callee}' passes it on without copying"
+            ),
+            related_information: vec![RelatedInformation {
+                location: (statement.location.start, statement.location.end),
+                message: format!("💡 Replace with 'goto &{callee};' for a tail call"),
+            }],
+            tags: Vec::new(),
+        });
+    }
+
+    node.for_each_child(|child| check_args_forwarding_tail_call(child, diagnostics));
+}
+
+/// The lone statement of a block that calls a sub with exactly `@_`, and the callee
+fn sole_args_forwarding_call(body: &Node) -> Option<(&Node, &str)> {
+    let NodeKind::Block { statements } = &body.kind else {
+        return None;
+    };
+    let [statement] = statements.as_slice() else {
+        return None;
+    };
+    let call = match &statement.kind {
+        NodeKind::ExpressionStatement { expression } => expression,
+        NodeKind::Return { value: Some(value) } => value,
+        _ => statement,
+    };
+    match &call.kind {
+        NodeKind::FunctionCall { name, args }
+            if !is_builtin(name)
+                && matches!(
+                    args.as_slice(),
+                    [Node { kind: NodeKind::Variable { sigil, name }, .. }]
+                        if sigil == "@" && name == "_"
+                ) =>
+        {
+            Some((statement, name))
+        }
+        _ => None,
+    }
+}
+
+/// Numeric value of a literal repetition count, including a negated literal
+fn literal_count(node: &Node) -> Option<f64> {
+    match &node.kind {
+        NodeKind::Number { value } => value.replace('_', "").parse().ok(),
+        NodeKind::Unary { op, operand } if op == "-" => literal_count(operand).map(|v| -v),
+        _ => None,
+    }
+}
+
+/// Source-like rendering of a literal repetition count for messages
+fn count_text(node: &Node) -> String {
+    match &node.kind {
+        NodeKind::Number { value } => value.clone(),
+        NodeKind::Unary { op, operand } => format!("{op}{}", count_text(operand)),
+        _ => String::new(),
+    }
+}
+
 /// Check for assignment in condition (common mistake)
 fn check_assignment_in_condition(condition: &Node, diagnostics: &mut Vec<Diagnostic>) {
     match &condition.kind {
@@ -7,9 +7,14 @@
 //!
 //! Lints are organized into focused submodules:
 //!
+//! - **best_practices**: Legal but risky idioms (e.g., bareword filehandles, two-argument `open`)
 //! - **deprecated**: Deprecated syntax warnings (e.g., `defined(@array)`)
 //! - **strict_warnings**: Missing `use strict` and `use warnings` advisories
 //! - **common_mistakes**: Frequent programming errors (assignment in conditions, etc.)
+//! - **performance**: Opt-in hints for repeated work (e.g., loop-invariant `my` declarations)
+//! - **reachability**: Branches and loops decided by a constant condition, and
+//!   statements after an unconditional `return`/`die`/`next`/`last`
+//! - **unused**: Lexical variables that are declared but never read
 //!
 //! # Severity Levels
 //!
@@ -28,6 +33,10 @@
 //! - Related information with suggestions and explanations
 //! - Diagnostic tags (Deprecated, Unnecessary) for IDE rendering
 
+pub mod best_practices;
 pub mod common_mistakes;
 pub mod deprecated;
+pub mod performance;
+pub mod reachability;
 pub mod strict_warnings;
+pub mod unused;
@@ -0,0 +1,161 @@
+//! Performance hint checks
+//!
+//! This module provides opt-in informational hints for code that does
+//! repeated work it could do once, such as a `my` declaration with a
+//! constant initializer inside a loop body.
+
+use perl_parser_core::ast::{Node, NodeKind};
+
+use super::super::types::{Diagnostic, DiagnosticSeverity, RelatedInformation};
+
+/// Builtins that modify the array, hash or scalar passed as their first argument
+const MUTATING_FUNCTIONS: &[&str] =
+    &["push", "pop", "shift", "unshift", "splice", "delete", "chomp", "chop", "undef"];
+
+/// Check for `my` declarations in a loop body that could be hoisted above the loop
+///
+/// `my $re = qr/abc/;` inside a loop compiles and assigns the same value on
+/// every iteration. This is an opt-in informational hint that
+/// [`crate::DiagnosticsProvider`] runs only with
+/// [`crate::HintOptions::hoistable_loop_declarations`] set. It only fires for a
+/// statement directly in the loop body whose initializer is made of
+/// literals, and whose variable is never modified in the body, so an
+/// accumulator such as `my @seen = ();` that is reset per iteration is left
+/// alone.
+pub fn check_hoistable_loop_declarations(
+    node: &Node,
+    source: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let body = match &node.kind {
+        NodeKind::For { body, .. }
+        | NodeKind::Foreach { body, .. }
+        | NodeKind::While { body, .. } => Some(body),
+        NodeKind::DoWhile { block, .. } | NodeKind::DoUntil { block, .. } => Some(block),
+        _ => None,
+    };
+
+    if let Some(body) = body
+        && let NodeKind::Block { statements } = &body.kind
+    {
+        for statement in statements {
+            if let NodeKind::VariableDeclaration {
+                declarator,
+                variable,
+                initializer: Some(init),
+                ..
+            } = &statement.kind
+                && declarator == "my"
+                && let NodeKind::Variable { sigil, name } = &variable.kind
+                && is_constant_initializer(init, source)
+                && !is_modified(body, name)
+            {
+                // The declaration's own span can stop at the variable, so extend it over the initializer
+                let end = statement.location.end.max(init.location.end);
+                diagnostics.push(Diagnostic {
+                    range: (statement.location.start, end),
+                    severity: DiagnosticSeverity::Information,
+                    code: Some("hoistable-declaration".to_string()),
+                    message: format!(
+                        "'my {sigil}{name}' is rebuilt with the same value on every iteration"
+                    ),
+                    related_information: vec![RelatedInformation {
+                        location: (node.location.start, node.location.end),
+                        message: "💡 Declare it once above the loop".to_string(),
+                    }],
+                    tags: Vec::new(),
+                });
+            }
+        }
+    }
+
+    node.for_each_child(|child| check_hoistable_loop_declarations(child, source, diagnostics));
+}
+
+/// Whether an initializer is built only from literals
+///
+/// Anonymous `[...]`/`{...}` constructors are excluded because they yield a
+/// fresh reference on each evaluation, and a bare `/.../` is a match against
+/// `$_` rather than a compiled pattern.
+fn is_constant_initializer(node: &Node, source: &str) -> bool {
+    let text = source.get(node.location.start..node.location.end).unwrap_or_default();
+    match &node.kind {
+        NodeKind::Number { .. } => true,
+        NodeKind::String { value, interpolated } => !interpolated || !value.contains(['$', '@']),
+        NodeKind::Regex { pattern, replacement: None, has_embedded_code: false, .. } => {
+            text.starts_with("qr") && !interpolates_pattern(pattern)
+        }
+        NodeKind::ArrayLiteral { elements } => {
+            !text.starts_with('[') && elements.iter().all(|e| is_constant_initializer(e, source))
+        }
+        NodeKind::HashLiteral { pairs } => {
+            !text.starts_with('{')
+                && pairs.iter().all(|(key, value)| {
+                    (matches!(key.kind, NodeKind::Identifier { .. })
+                        || is_constant_initializer(key, source))
+                        && is_constant_initializer(value, source)
+                })
+        }
+        NodeKind::Binary { left, right, .. } => {
+            is_constant_initializer(left, source) && is_constant_initializer(right, source)
+        }
+        NodeKind::Unary { op, operand } if op != "\\" && op != "++" && op != "--" => {
+            is_constant_initializer(operand, source)
+        }
+        _ => false,
+    }
+}
+
+/// Whether a pattern interpolates a variable, ignoring `$` used as an anchor
+fn interpolates_pattern(pattern: &str) -> bool {
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '@' => return true,
+            '$' if !matches!(chars.peek(), None | Some(')' | '|' | '/')) => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Whether a variable named `name` (under any sigil) is modified within `node`
+///
+/// Covers assignment, `++`/`--`, taking a reference, binding a substitution
+/// or transliteration, and the mutating builtins.
+fn is_modified(node: &Node, name: &str) -> bool {
+    let modified = match &node.kind {
+        NodeKind::Assignment { lhs, .. } => mentions(lhs, name),
+        NodeKind::Unary { op, operand } if op == "++" || op == "--" || op == "\\" => {
+            mentions(operand, name)
+        }
+        NodeKind::Substitution { expr, .. } | NodeKind::Transliteration { expr, .. } => {
+            mentions(expr, name)
+        }
+        NodeKind::FunctionCall { name: function, args }
+            if MUTATING_FUNCTIONS.contains(&function.as_str()) =>
+        {
+            args.first().is_some_and(|arg| mentions(arg, name))
+        }
+        _ => false,
+    };
+
+    let mut found = modified;
+    node.for_each_child(|child| found = found || is_modified(child, name));
+    found
+}
+
+/// Whether any variable named `name` appears within `node`
+fn mentions(node: &Node, name: &str) -> bool {
+    if let NodeKind::Variable { name: var, .. } = &node.kind
+        && var == name
+    {
+        return true;
+    }
+    let mut found = false;
+    node.for_each_child(|child| found = found || mentions(child, name));
+    found
+}
@@ -0,0 +1,269 @@
+//! Reachability lint checks
+//!
+//! This module flags code whose reachability is decided by a constant
+//! condition: `if`/`elsif` branches that can never run and loops that can
+//! never run or never stop. The parser normalizes `unless` and `until` to
+//! `If`/`While` with a negated condition, so both forms are covered by the
+//! same analysis.
+//!
+//! It also flags statements that follow an unconditional `return`, `die`,
+//! `exit`, `next`, `last` or `redo` in the same block.
+
+use perl_parser_core::ast::{Node, NodeKind};
+
+use super::super::types::{Diagnostic, DiagnosticSeverity, DiagnosticTag, RelatedInformation};
+
+/// Check for branches and loops controlled by a constant condition
+///
+/// A branch whose condition is always false, or that follows a branch whose
+/// condition is always true, is dead code. A loop whose condition is always
+/// false never runs its body, and one whose condition is always true never
+/// terminates unless the body leaves it with `last`, `return`, `die`,
+/// `exit` or `goto`.
+pub fn check_constant_conditions(node: &Node, diagnostics: &mut Vec<Diagnostic>) {
+    match &node.kind {
+        NodeKind::If { condition, then_branch, elsif_branches, else_branch } => {
+            let branches = std::iter::once((condition.as_ref(), then_branch.as_ref()))
+                .chain(elsif_branches.iter().map(|(c, b)| (c.as_ref(), b.as_ref())));
+
+            let mut always_taken: Option<&Node> = None;
+            for (condition, branch) in branches {
+                if let Some(taken) = always_taken {
+                    push_dead_branch(
+                        branch,
+                        taken,
+                        "an earlier condition is always true",
+                        diagnostics,
+                    );
+                    continue;
+                }
+                match constant_truth(condition) {
+                    Some(true) => always_taken = Some(condition),
+                    Some(false) => push_dead_branch(
+                        branch,
+                        condition,
+                        "its condition is always false",
+                        diagnostics,
+                    ),
+                    None => {}
+                }
+            }
+            if let (Some(taken), Some(branch)) = (always_taken, else_branch) {
+                push_dead_branch(branch, taken, "an earlier condition is always true", diagnostics);
+            }
+        }
+        NodeKind::While { condition, body, .. } => match constant_truth(condition) {
+            Some(false) => {
+                push_dead_branch(body, condition, "the loop condition is always false", diagnostics)
+            }
+            Some(true) if !has_loop_exit(body, false) => {
+                diagnostics.push(Diagnostic {
+                    range: (node.location.start, node.location.end),
+                    severity: DiagnosticSeverity::Warning,
+                    code: Some("infinite-loop".to_string()),
+                    message: "Loop condition is always true and the body never leaves the loop"
+                        .to_string(),
+                    related_information: vec![RelatedInformation {
+                        location: (condition.location.start, condition.location.end),
+                        message: "💡 Add a 'last' (or 'return'/'die') to end the loop".to_string(),
+                    }],
+                    tags: Vec::new(),
+                });
+            }
+            _ => {}
+        },
+        _ => {}
+    }
+
+    node.for_each_child(|child| check_constant_conditions(child, diagnostics));
+}
+
+/// Check for statements that can never run because an earlier statement in
+/// the same block always leaves it
+///
+/// A terminator with a statement modifier (`return if $done`) is
+/// conditional and does not count. Subroutine, package and `use`
+/// declarations after the terminator are not flagged since they take effect
+/// at compile time, and a labeled statement ends the unreachable region
+/// because `goto` can jump to it.
+pub fn check_unreachable_statements(node: &Node, diagnostics: &mut Vec<Diagnostic>) {
+    if let NodeKind::Program { statements } | NodeKind::Block { statements } = &node.kind
+        && let Some(index) = statements.iter().position(is_unconditional_exit)
+    {
+        let terminator = &statements[index];
+        let mut region: Option<(usize, usize)> = None;
+        for statement in &statements[index + 1..] {
+            if matches!(statement.kind, NodeKind::LabeledStatement { .. }) {
+                break;
+            }
+            if is_compile_time_declaration(statement) {
+                push_unreachable(region.take(), terminator, diagnostics);
+                continue;
+            }
+            let end = extent_end(statement);
+            region = Some(match region {
+                Some((start, _)) => (start, end),
+                None => (statement.location.start, end),
+            });
+        }
+        push_unreachable(region, terminator, diagnostics);
+    }
+
+    node.for_each_child(|child| check_unreachable_statements(child, diagnostics));
+}
+
+fn push_unreachable(
+    region: Option<(usize, usize)>,
+    terminator: &Node,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let Some(range) = region else {
+        return;
+    };
+    let statement = match &terminator.kind {
+        NodeKind::ExpressionStatement { expression } => expression.as_ref(),
+        _ => terminator,
+    };
+    let keyword = match &statement.kind {
+        NodeKind::Return { .. } => "return",
+        NodeKind::LoopControl { op, .. } => op.as_str(),
+        NodeKind::FunctionCall { name, .. } => name.as_str(),
+        _ => "the previous statement",
+    };
+    diagnostics.push(Diagnostic {
+        range,
+        severity: DiagnosticSeverity::Hint,
+        code: Some("unreachable-code".to_string()),
+        message: format!("Unreachable code after '{keyword}'"),
+        related_information: vec![RelatedInformation {
+            location: (
+                terminator.location.start,
+                terminator.location.end.max(terminator.location.start),
+            ),
+            message: "ℹ️ Control always leaves the block here".to_string(),
+        }],
+        tags: vec![DiagnosticTag::Unnecessary],
+    });
+}
+
+/// Whether a statement always transfers control out of its block
+fn is_unconditional_exit(statement: &Node) -> bool {
+    let statement = match &statement.kind {
+        NodeKind::ExpressionStatement { expression } => expression.as_ref(),
+        _ => statement,
+    };
+    match &statement.kind {
+        NodeKind::Return { .. } | NodeKind::LoopControl { .. } => true,
+        NodeKind::FunctionCall { name, .. } => {
+            matches!(name.as_str(), "die" | "exit" | "croak" | "confess" | "goto")
+        }
+        _ => false,
+    }
+}
+
+/// Declarations that take effect at compile time wherever they appear
+fn is_compile_time_declaration(statement: &Node) -> bool {
+    matches!(
+        statement.kind,
+        NodeKind::Subroutine { .. }
+            | NodeKind::Method { .. }
+            | NodeKind::Package { .. }
+            | NodeKind::Class { .. }
+            | NodeKind::Use { .. }
+            | NodeKind::No { .. }
+            | NodeKind::PhaseBlock { .. }
+            | NodeKind::Format { .. }
+            | NodeKind::DataSection { .. }
+    )
+}
+
+/// End offset of a statement including its operands
+///
+/// Statement nodes do not always span their arguments (`print 1` ends after
+/// `print`), so the furthest end among the descendants is used.
+fn extent_end(node: &Node) -> usize {
+    let mut end = node.location.end;
+    node.for_each_child(|child| end = end.max(extent_end(child)));
+    end
+}
+
+fn push_dead_branch(branch: &Node, cause: &Node, reason: &str, diagnostics: &mut Vec<Diagnostic>) {
+    diagnostics.push(Diagnostic {
+        range: (branch.location.start, branch.location.end),
+        severity: DiagnosticSeverity::Hint,
+        code: Some("unreachable-branch".to_string()),
+        message: format!("This block never runs because {reason}"),
+        related_information: vec![RelatedInformation {
+            location: (cause.location.start, cause.location.end),
+            message: "ℹ️ Constant condition".to_string(),
+        }],
+        tags: vec![DiagnosticTag::Unnecessary],
+    });
+}
+
+/// Boolean value of a condition made of literals, looking through `!`/`not`
+///
+/// Only numbers and non-interpolating (or variable-free) quoted strings are
+/// treated as constants; `unless`/`until` conditions arrive wrapped in a
+/// synthetic `!` and are folded the same way.
+fn constant_truth(node: &Node) -> Option<bool> {
+    match &node.kind {
+        NodeKind::Number { value } => {
+            let value = value.replace('_', "");
+            if let Some(hex) = value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+                u64::from_str_radix(hex, 16).ok().map(|n| n != 0)
+            } else if let Some(bin) = value.strip_prefix("0b").or_else(|| value.strip_prefix("0B"))
+            {
+                u64::from_str_radix(bin, 2).ok().map(|n| n != 0)
+            } else {
+                value.parse::<f64>().ok().map(|n| n != 0.0)
+            }
+        }
+        NodeKind::String { value, interpolated } => {
+            let body = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))?;
+            if *interpolated && body.contains(['$', '@', '\\']) {
+                return None;
+            }
+            Some(!body.is_empty() && body != "0")
+        }
+        NodeKind::Unary { op, operand } if op == "!" || op == "not" => {
+            constant_truth(operand).map(|truth| !truth)
+        }
+        _ => None,
+    }
+}
+
+/// Whether a loop body contains a way out of the loop
+///
+/// Unlabeled `last` only counts outside nested loops, since there it leaves
+/// the inner loop; labeled `last` is assumed to target this loop. Nested
+/// subroutines are skipped because their control flow is separate.
+fn has_loop_exit(node: &Node, in_nested_loop: bool) -> bool {
+    match &node.kind {
+        NodeKind::LoopControl { op, label } if op == "last" => label.is_some() || !in_nested_loop,
+        NodeKind::Return { .. } => true,
+        NodeKind::FunctionCall { name, .. }
+            if matches!(name.as_str(), "die" | "exit" | "croak" | "confess" | "goto") =>
+        {
+            true
+        }
+        NodeKind::Subroutine { .. } | NodeKind::Method { .. } => false,
+        NodeKind::While { .. }
+        | NodeKind::For { .. }
+        | NodeKind::Foreach { .. }
+        | NodeKind::DoWhile { .. }
+        | NodeKind::DoUntil { .. } => {
+            let mut found = false;
+            node.for_each_child(|child| found = found || has_loop_exit(child, true));
+            found
+        }
+        _ => {
+            let mut found = false;
+            node.for_each_child(|child| found = found || has_loop_exit(child, in_nested_loop));
+            found
+        }
+    }
+}
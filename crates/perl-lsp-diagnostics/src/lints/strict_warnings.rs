@@ -1,9 +1,14 @@
 //! Strict and warnings pragma lint checks
 //!
 //! This module provides functionality for checking if 'use strict' and 'use warnings'
-//! pragmas are present in Perl code.
+//! pragmas are present in Perl code, and whether they are placed before the
+//! code they are meant to cover.
 
+use std::ops::Range;
+
+use perl_diagnostics_codes::DiagnosticCode;
 use perl_parser_core::ast::{Node, NodeKind};
+use perl_pragma::{PragmaState, PragmaTracker};
 
 use super::super::types::{Diagnostic, DiagnosticSeverity, RelatedInformation};
 use super::super::walker::walk_node;
@@ -68,3 +73,157 @@ pub fn check_strict_warnings(node: &Node, diagnostics: &mut Vec<Diagnostic>) {
         });
     }
 }
+
+/// Modules that enable both `strict` and `warnings` in the code that loads them
+const STRICT_WARNINGS_IMPORTERS: &[&str] = &[
+    "Moose",
+    "Moose::Role",
+    "Moo",
+    "Moo::Role",
+    "Mouse",
+    "Mojo::Base",
+    "Modern::Perl",
+    "common::sense",
+    "strictures",
+    "Test2::V0",
+];
+
+/// Check for `use strict`/`use warnings` not in effect where the file's code starts
+///
+/// Unlike [`check_strict_warnings`], this asks the pragma map for the state
+/// at the first executable statement, so `use v5.36` (which implies both)
+/// and `use v5.12` (strict) count, while pragmas that only appear after the
+/// code starts do not. Modules such as `Moose` that turn both pragmas on
+/// count when loaded before that statement. Files without executable code
+/// are skipped.
+pub fn check_missing_strict_warnings(
+    node: &Node,
+    pragma_map: &[(Range<usize>, PragmaState)],
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let NodeKind::Program { statements } = &node.kind else {
+        return;
+    };
+    let Some(first_code) = statements.iter().find(|stmt| is_executable_statement(stmt)) else {
+        return;
+    };
+
+    let imported = statements.iter().take_while(|stmt| !is_executable_statement(stmt)).any(
+        |stmt| matches!(&stmt.kind, NodeKind::Use { module, .. } if STRICT_WARNINGS_IMPORTERS.contains(&module.as_str())),
+    );
+    if imported {
+        return;
+    }
+
+    let state = PragmaTracker::state_for_offset(pragma_map, first_code.location.start);
+    let code_range = (first_code.location.start, first_code.location.end);
+    let missing = [
+        (
+            DiagnosticCode::MissingStrict,
+            "strict",
+            state.strict_vars && state.strict_subs && state.strict_refs,
+        ),
+        (DiagnosticCode::MissingWarnings, "warnings", state.warnings),
+    ];
+
+    for (code, pragma, enabled) in missing {
+        if enabled {
+            continue;
+        }
+        diagnostics.push(Diagnostic {
+            range: (0, 0),
+            severity: DiagnosticSeverity::Warning,
+            code: Some(code.to_string()),
+            message: format!("Missing 'use {pragma};' before the first statement"),
+            related_information: vec![
+                RelatedInformation {
+                    location: code_range,
+                    message: format!("ℹ️ Code from here on runs without '{pragma}' in effect"),
+                },
+                RelatedInformation {
+                    location: (0, 0),
+                    message: format!(
+                        "💡 Add 'use {pragma};' (or 'use v5.36;', which implies both) at the top of the file"
+                    ),
+                },
+            ],
+            tags: Vec::new(),
+        });
+    }
+}
+
+/// Check for `use strict`/`use warnings` placed after executable code
+///
+/// Pragmas are lexically scoped and only affect code that follows them, so a
+/// `use strict;` that appears after executable statements leaves the earlier
+/// code unchecked. Statements that are themselves compile-time declarations
+/// (`use`, `no`, and `package NAME;`) do not count as code, so pragmas may
+/// follow them freely. The pragma map is consulted so that a pragma which is
+/// already in effect for the earlier code is not reported.
+pub fn check_pragma_placement(
+    node: &Node,
+    pragma_map: &[(Range<usize>, PragmaState)],
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    walk_node(node, &mut |n| {
+        let statements = match &n.kind {
+            NodeKind::Program { statements } | NodeKind::Block { statements } => statements,
+            _ => return,
+        };
+
+        let Some(first_code) = statements.iter().find(|stmt| is_executable_statement(stmt)) else {
+            return;
+        };
+        let state_before = PragmaTracker::state_for_offset(pragma_map, first_code.location.start);
+
+        for stmt in statements {
+            if stmt.location.start <= first_code.location.start {
+                continue;
+            }
+            let NodeKind::Use { module, .. } = &stmt.kind else {
+                continue;
+            };
+
+            let already_active = match module.as_str() {
+                "strict" => {
+                    state_before.strict_vars && state_before.strict_subs && state_before.strict_refs
+                }
+                "warnings" => state_before.warnings,
+                _ => continue,
+            };
+            if already_active {
+                continue;
+            }
+
+            diagnostics.push(Diagnostic {
+                range: (stmt.location.start, stmt.location.end),
+                severity: DiagnosticSeverity::Warning,
+                code: Some("late-pragma".to_string()),
+                message: format!(
+                    "'use {module}' appears after executable code and does not apply to the code before it"
+                ),
+                related_information: vec![
+                    RelatedInformation {
+                        location: (first_code.location.start, first_code.location.end),
+                        message: format!("ℹ️ This code runs without '{module}' in effect"),
+                    },
+                    RelatedInformation {
+                        location: (stmt.location.start, stmt.location.end),
+                        message: format!(
+                            "💡 Move 'use {module};' to the top of the file, after any 'package' declaration"
+                        ),
+                    },
+                ],
+                tags: Vec::new(),
+            });
+        }
+    });
+}
+
+/// Whether a statement is executable code rather than a compile-time declaration
+fn is_executable_statement(node: &Node) -> bool {
+    !matches!(
+        node.kind,
+        NodeKind::Use { .. } | NodeKind::No { .. } | NodeKind::Package { block: None, .. }
+    )
+}
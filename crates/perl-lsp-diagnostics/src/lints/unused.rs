@@ -0,0 +1,91 @@
+//! Unused lexical variable checks
+//!
+//! This module reports `my` and `state` variables that are never read,
+//! using the references recorded in the semantic analyzer's symbol table.
+
+use perl_diagnostics_codes::DiagnosticCode;
+use perl_semantic_analyzer::symbol::{ScopeId, Symbol, SymbolKind, SymbolTable, VarKind};
+
+use super::super::types::{Diagnostic, DiagnosticSeverity, DiagnosticTag, RelatedInformation};
+
+/// Check for lexical variables that are declared but never read (PL102)
+///
+/// A `my`/`state` variable counts as used when a read reference to the same
+/// name resolves to it from its declaring scope or a nested one. References
+/// inside interpolated strings are reads. Plain assignments are writes, so
+/// a variable that is only ever assigned is reported as well. Any sigil
+/// counts, since `$h{key}` and `@a[1, 2]` read `%h` and `@a`. Names starting
+/// with `_` are treated as intentionally unused.
+pub fn check_unused_lexicals(table: &SymbolTable, diagnostics: &mut Vec<Diagnostic>) {
+    let mut unused: Vec<(&Symbol, VarKind, bool)> = Vec::new();
+    for symbols in table.symbols.values() {
+        for symbol in symbols {
+            let SymbolKind::Variable(var_kind) = symbol.kind else {
+                continue;
+            };
+            if !matches!(symbol.declaration.as_deref(), Some("my" | "state"))
+                || symbol.name.starts_with('_')
+            {
+                continue;
+            }
+
+            let references: Vec<_> = table
+                .references
+                .get(&symbol.name)
+                .into_iter()
+                .flatten()
+                .filter(|r| matches!(r.kind, SymbolKind::Variable(_)))
+                .filter(|r| resolves_to(table, symbol, var_kind, r.scope_id))
+                .collect();
+
+            if references.iter().all(|r| r.is_write) {
+                unused.push((symbol, var_kind, !references.is_empty()));
+            }
+        }
+    }
+
+    unused.sort_by_key(|(symbol, ..)| symbol.location.start);
+    for (symbol, var_kind, assigned) in unused {
+        let display = format!("{}{}", var_kind.sigil(), symbol.name);
+        let message = if assigned {
+            format!("Variable '{display}' is assigned but never read")
+        } else {
+            format!("Variable '{display}' is declared but never used")
+        };
+
+        diagnostics.push(Diagnostic {
+            range: (symbol.location.start, symbol.location.end),
+            severity: DiagnosticSeverity::Warning,
+            code: Some(DiagnosticCode::UnusedVariable.to_string()),
+            message,
+            related_information: vec![RelatedInformation {
+                location: (symbol.location.start, symbol.location.end),
+                message: "💡 Remove the unused variable or prefix with '_' to indicate it's intentionally unused".to_string(),
+            }],
+            tags: vec![DiagnosticTag::Unnecessary],
+        });
+    }
+}
+
+/// Whether a reference made from `scope` resolves to `symbol`
+///
+/// The reference scope must be the declaring scope or nested inside it, and
+/// no closer declaration of the same name may shadow the symbol.
+fn resolves_to(table: &SymbolTable, symbol: &Symbol, kind: VarKind, scope: ScopeId) -> bool {
+    let mut current = Some(scope);
+    while let Some(id) = current {
+        if id == symbol.scope_id {
+            return true;
+        }
+        let shadowed = table.symbols.get(&symbol.name).into_iter().flatten().any(|other| {
+            other.scope_id == id
+                && other.kind == SymbolKind::Variable(kind)
+                && other.declaration.as_deref() != Some("our")
+        });
+        if shadowed {
+            return false;
+        }
+        current = table.scopes.get(&id).and_then(|s| s.parent);
+    }
+    false
+}
@@ -25,7 +25,8 @@ pub fn scope_issues_to_diagnostics(issues: Vec<ScopeIssue>) -> Vec<Diagnostic> {
             | IssueKind::UnusedVariable
             | IssueKind::ParameterShadowsGlobal
             | IssueKind::UnusedParameter
-            | IssueKind::UninitializedVariable => DiagnosticSeverity::Warning,
+            | IssueKind::UninitializedVariable
+            | IssueKind::SelfReferentialDeclaration => DiagnosticSeverity::Warning,
         };
 
         let code = match issue.kind {
@@ -38,6 +39,7 @@ pub fn scope_issues_to_diagnostics(issues: Vec<ScopeIssue>) -> Vec<Diagnostic> {
             IssueKind::UnusedParameter => "unused-parameter",
             IssueKind::UnquotedBareword => "unquoted-bareword",
             IssueKind::UninitializedVariable => "uninitialized-variable",
+            IssueKind::SelfReferentialDeclaration => "self-referential-declaration",
         };
 
         // Build helpful related information based on issue type
@@ -102,6 +104,16 @@ pub fn scope_issues_to_diagnostics(issues: Vec<ScopeIssue>) -> Vec<Diagnostic> {
                     message: "ℹ️ Using uninitialized variables may cause warnings and unexpected behavior.".to_string(),
                 }
             ],
+            IssueKind::SelfReferentialDeclaration => vec![
+                RelatedInformation {
+                    location: issue.range,
+                    message: "💡 Use a different name for the new variable, or declare it in an earlier statement".to_string(),
+                },
+                RelatedInformation {
+                    location: issue.range,
+                    message: "ℹ️ A 'my' variable is only visible from the next statement, so its initializer cannot refer to it.".to_string(),
+                }
+            ],
             IssueKind::UnquotedBareword => vec![
                 RelatedInformation {
                     location: issue.range,
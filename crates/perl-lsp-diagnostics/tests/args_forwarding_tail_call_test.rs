@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use perl_lsp_diagnostics::common_mistakes::check_args_forwarding_tail_call;
+use perl_lsp_diagnostics::{Diagnostic, DiagnosticSeverity, DiagnosticsProvider, HintOptions};
+use perl_parser_core::Parser;
+
+fn tail_call_hints(source: &str) -> Result<Vec<Diagnostic>, Box<dyn std::error::Error>> {
+    let mut parser = Parser::new(source);
+    let ast = parser.parse()?;
+    let mut diagnostics = Vec::new();
+    check_args_forwarding_tail_call(&ast, &mut diagnostics);
+    Ok(diagnostics)
+}
+
+#[test]
+fn test_returned_forwarding_call_is_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "sub wrapper { return inner(@_); }\nsub inner { 1 }\n";
+    let hints = tail_call_hints(source)?;
+
+    assert_eq!(hints.len(), 1, "Expected one tail-call hint, got {hints:?}");
+    assert_eq!(hints[0].severity, DiagnosticSeverity::Information);
+    assert_eq!(hints[0].code.as_deref(), Some("args-forwarding-tail-call"));
+    assert!(hints[0].message.contains("goto &inner"));
+    assert_eq!(&source[hints[0].range.0..hints[0].range.1], "return inner(@_)");
+
+    Ok(())
+}
+
+#[test]
+fn test_forwarding_call_as_last_statement_is_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let hints = tail_call_hints("sub wrapper { inner(@_); }")?;
+    assert_eq!(hints.len(), 1, "Expected one tail-call hint, got {hints:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_modified_arguments_are_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let hints = tail_call_hints("sub wrapper { return inner(@_, 1); }")?;
+    assert!(hints.is_empty(), "Unexpected hint: {hints:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_sub_doing_other_work_is_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "sub wrapper { my $self = shift; log_call(); return inner(@_); }";
+    let hints = tail_call_hints(source)?;
+    assert!(hints.is_empty(), "Unexpected hint: {hints:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_builtin_forwarding_is_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let hints = tail_call_hints("sub say_all { print(@_); }")?;
+    assert!(hints.is_empty(), "Unexpected hint: {hints:?}");
+
+    Ok(())
+}
+
+/// Tail-call hints reported by the provider with `hints` enabled
+fn provider_tail_call_hints(
+    source: &str,
+    hints: HintOptions,
+) -> Result<Vec<Diagnostic>, Box<dyn std::error::Error>> {
+    let mut parser = Parser::new(source);
+    let ast = Arc::new(parser.parse()?);
+    let provider = DiagnosticsProvider::new(&ast, source.to_string()).with_hints(hints);
+    let diagnostics = provider.get_diagnostics(&ast, &[], source);
+    Ok(diagnostics
+        .into_iter()
+        .filter(|d| d.code.as_deref() == Some("args-forwarding-tail-call"))
+        .collect())
+}
+
+#[test]
+fn test_hint_is_opt_in() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "sub wrapper { return inner(@_); }\nsub inner { 1 }\n";
+    let diagnostics = provider_tail_call_hints(source, HintOptions::default())?;
+
+    assert!(
+        diagnostics.is_empty(),
+        "Default diagnostics should not include the tail-call hint: {diagnostics:?}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_provider_reports_hint_when_enabled() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "sub wrapper { return inner(@_); }\nsub inner { 1 }\n";
+    let hints = HintOptions { args_forwarding_tail_call: true, ..HintOptions::default() };
+    let diagnostics = provider_tail_call_hints(source, hints)?;
+
+    assert_eq!(diagnostics.len(), 1, "Expected one tail-call hint, got {diagnostics:?}");
+    assert_eq!(&source[diagnostics[0].range.0..diagnostics[0].range.1], "return inner(@_)");
+
+    Ok(())
+}
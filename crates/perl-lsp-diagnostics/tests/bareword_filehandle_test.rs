@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use perl_lsp_diagnostics::{Diagnostic, DiagnosticSeverity, DiagnosticsProvider};
+use perl_parser_core::Parser;
+
+fn diagnostics_for(source: &str) -> Result<Vec<Diagnostic>, Box<dyn std::error::Error>> {
+    let mut parser = Parser::new(source);
+    let ast = Arc::new(parser.parse()?);
+    let provider = DiagnosticsProvider::new(&ast, source.to_string());
+    Ok(provider.get_diagnostics(&ast, &[], source))
+}
+
+/// Source text of every PL400 diagnostic, in order
+fn bareword_handles<'a>(source: &'a str, diagnostics: &[Diagnostic]) -> Vec<&'a str> {
+    diagnostics
+        .iter()
+        .filter(|d| d.code.as_deref() == Some("PL400"))
+        .map(|d| &source[d.range.0..d.range.1])
+        .collect()
+}
+
+#[test]
+fn test_open_print_close_pair_is_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "open(OUT, '>', $file) or die;\nprint OUT \"hello\\n\";\nclose(OUT);\n";
+    let diagnostics = diagnostics_for(source)?;
+
+    assert_eq!(bareword_handles(source, &diagnostics), ["OUT", "OUT", "OUT"]);
+    let open = diagnostics.iter().find(|d| d.code.as_deref() == Some("PL400"));
+    assert_eq!(open.map(|d| d.severity), Some(DiagnosticSeverity::Warning));
+
+    Ok(())
+}
+
+#[test]
+fn test_readline_handle_is_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "open(FH, '<', $file) or die;\nwhile (<FH>) { chomp; }\n";
+    let diagnostics = diagnostics_for(source)?;
+
+    assert_eq!(bareword_handles(source, &diagnostics), ["FH", "FH"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_standard_handles_are_exempt() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "print STDERR \"warning\\n\";\nprint STDOUT \"ok\\n\";\nmy $line = <STDIN>;\nwhile (<DATA>) { }\nclose(ARGV);\n";
+    let diagnostics = diagnostics_for(source)?;
+
+    assert!(
+        bareword_handles(source, &diagnostics).is_empty(),
+        "Standard handles should not be flagged: {diagnostics:?}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_lexical_filehandle_is_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "open(my $fh, '<', $file) or die;\nwhile (<$fh>) { print $fh $_; }\nclose($fh);\n";
+    let diagnostics = diagnostics_for(source)?;
+
+    assert!(bareword_handles(source, &diagnostics).is_empty(), "Got {diagnostics:?}");
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use perl_lsp_diagnostics::common_mistakes::corrected_comparison_operator;
+use perl_lsp_diagnostics::{Diagnostic, DiagnosticSeverity, DiagnosticsProvider};
+use perl_parser_core::Parser;
+
+fn diagnostics_for(source: &str) -> Result<Vec<Diagnostic>, Box<dyn std::error::Error>> {
+    let mut parser = Parser::new(source);
+    let ast = Arc::new(parser.parse()?);
+    let provider = DiagnosticsProvider::new(&ast, source.to_string());
+    Ok(provider.get_diagnostics(&ast, &[], source))
+}
+
+fn mismatches(diagnostics: &[Diagnostic]) -> Vec<&Diagnostic> {
+    diagnostics
+        .iter()
+        .filter(|d| d.code.as_deref() == Some("comparison-operator-mismatch"))
+        .collect()
+}
+
+#[test]
+fn test_numeric_comparison_of_string_literal_suggests_eq() -> Result<(), Box<dyn std::error::Error>>
+{
+    let source = "my $x = shift;\nif ($x == \"abc\") { print 1 }\n";
+    let diagnostics = diagnostics_for(source)?;
+    let hits = mismatches(&diagnostics);
+
+    assert_eq!(hits.len(), 1, "Expected one mismatch, got {diagnostics:?}");
+    assert_eq!(hits[0].severity, DiagnosticSeverity::Warning);
+    assert_eq!(&source[hits[0].range.0..hits[0].range.1], "==");
+    assert!(hits[0].related_information[0].message.contains("'eq'"));
+    assert_eq!(corrected_comparison_operator("=="), Some("eq"));
+
+    Ok(())
+}
+
+#[test]
+fn test_string_comparison_of_number_suggests_numeric_operator()
+-> Result<(), Box<dyn std::error::Error>> {
+    let source = "my $n = 3;\nprint 'five' if $n eq 5;\n";
+    let diagnostics = diagnostics_for(source)?;
+    let hits = mismatches(&diagnostics);
+
+    assert_eq!(hits.len(), 1, "Expected one mismatch, got {diagnostics:?}");
+    assert_eq!(&source[hits[0].range.0..hits[0].range.1], "eq");
+    assert!(hits[0].related_information[0].message.contains("'=='"));
+
+    Ok(())
+}
+
+#[test]
+fn test_other_numeric_operators_are_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "my ($a, $b) = @ARGV;\nprint 1 if $a != 'x';\nprint 2 if 'y' < $b;\n";
+    let diagnostics = diagnostics_for(source)?;
+    let hits = mismatches(&diagnostics);
+
+    let operators: Vec<_> = hits.iter().map(|d| &source[d.range.0..d.range.1]).collect();
+    assert_eq!(operators, vec!["!=", "<"], "{diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_matching_operators_and_numeric_strings_are_not_flagged()
+-> Result<(), Box<dyn std::error::Error>> {
+    let source = r#"
+my ($x, $n) = @ARGV;
+my $name = "bob";
+print 1 if $x eq "abc";
+print 2 if $n == 5;
+print 3 if $x == "10";
+print 4 if $] >= "5.010";
+print 5 if $name eq 5;
+print 6 if "$x" eq "5";
+"#;
+    let diagnostics = diagnostics_for(source)?;
+    let hits = mismatches(&diagnostics);
+
+    assert!(hits.is_empty(), "Expected no mismatches, got {hits:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_hex_string_is_not_numeric() -> Result<(), Box<dyn std::error::Error>> {
+    // Strings numify as decimal, so "0x10" is 0
+    let source = "my $x = shift;\nprint 1 if $x == \"0x10\";\n";
+    let diagnostics = diagnostics_for(source)?;
+    let hits = mismatches(&diagnostics);
+
+    assert_eq!(hits.len(), 1, "Expected one mismatch, got {diagnostics:?}");
+    assert_eq!(&source[hits[0].range.0..hits[0].range.1], "==");
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use perl_lsp_diagnostics::{Diagnostic, DiagnosticSeverity, DiagnosticTag, DiagnosticsProvider};
+use perl_parser_core::Parser;
+
+fn diagnostics_for(source: &str) -> Result<Vec<Diagnostic>, Box<dyn std::error::Error>> {
+    let mut parser = Parser::new(source);
+    let ast = Arc::new(parser.parse()?);
+    let provider = DiagnosticsProvider::new(&ast, source.to_string());
+    Ok(provider.get_diagnostics(&ast, &[], source))
+}
+
+fn with_code<'a>(diagnostics: &'a [Diagnostic], code: &str) -> Vec<&'a Diagnostic> {
+    diagnostics.iter().filter(|d| d.code.as_deref() == Some(code)).collect()
+}
+
+#[test]
+fn test_unless_true_body_is_dead() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "unless (1) { dead() }";
+    let diagnostics = diagnostics_for(source)?;
+    let hits = with_code(&diagnostics, "unreachable-branch");
+
+    assert_eq!(hits.len(), 1, "Expected one unreachable-branch hint, got {diagnostics:?}");
+    assert_eq!(hits[0].severity, DiagnosticSeverity::Hint);
+    assert_eq!(hits[0].tags, vec![DiagnosticTag::Unnecessary]);
+    assert_eq!(&source[hits[0].range.0..hits[0].range.1], "{ dead() }");
+
+    Ok(())
+}
+
+#[test]
+fn test_if_false_body_is_dead() -> Result<(), Box<dyn std::error::Error>> {
+    let diagnostics = diagnostics_for("if (0) { dead() }")?;
+    assert_eq!(with_code(&diagnostics, "unreachable-branch").len(), 1, "got {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_unless_false_else_is_dead() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "unless (0) { live() } else { dead() }";
+    let diagnostics = diagnostics_for(source)?;
+    let hits = with_code(&diagnostics, "unreachable-branch");
+
+    assert_eq!(hits.len(), 1, "Expected one unreachable-branch hint, got {diagnostics:?}");
+    assert_eq!(&source[hits[0].range.0..hits[0].range.1], "{ dead() }");
+
+    Ok(())
+}
+
+#[test]
+fn test_until_false_is_infinite_loop() -> Result<(), Box<dyn std::error::Error>> {
+    let diagnostics = diagnostics_for("until (0) {}")?;
+    let hits = with_code(&diagnostics, "infinite-loop");
+
+    assert_eq!(hits.len(), 1, "Expected one infinite-loop warning, got {diagnostics:?}");
+    assert_eq!(hits[0].severity, DiagnosticSeverity::Warning);
+
+    Ok(())
+}
+
+#[test]
+fn test_until_true_body_is_dead() -> Result<(), Box<dyn std::error::Error>> {
+    let diagnostics = diagnostics_for("until (1) { dead() }")?;
+    assert_eq!(with_code(&diagnostics, "unreachable-branch").len(), 1, "got {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_infinite_loop_with_last_is_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let diagnostics = diagnostics_for("while (1) { last if done(); }")?;
+    assert!(with_code(&diagnostics, "infinite-loop").is_empty(), "Unexpected: {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_last_in_nested_loop_does_not_exit() -> Result<(), Box<dyn std::error::Error>> {
+    let diagnostics = diagnostics_for("while (1) { for my $x (@list) { last; } }")?;
+    assert_eq!(with_code(&diagnostics, "infinite-loop").len(), 1, "got {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_variable_condition_is_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let diagnostics = diagnostics_for("my $x = 1; unless ($x) { a() } until ($x) { b() }")?;
+    assert!(with_code(&diagnostics, "unreachable-branch").is_empty(), "got {diagnostics:?}");
+    assert!(with_code(&diagnostics, "infinite-loop").is_empty(), "got {diagnostics:?}");
+
+    Ok(())
+}
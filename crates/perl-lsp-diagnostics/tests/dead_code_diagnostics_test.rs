@@ -61,3 +61,21 @@ fn test_dead_code_only_in_current_document() -> Result<(), Box<dyn std::error::E
 
     Ok(())
 }
+
+#[test]
+fn test_dead_subroutine_is_hint_and_respects_exports() -> Result<(), Box<dyn std::error::Error>> {
+    let index = WorkspaceIndex::new();
+    let source = "package Util;\nour @EXPORT_OK = qw(trim);\nsub trim { 1 }\nsub _orphan { 1 }\n";
+    index.index_file_str("file:///Util.pm", source)?;
+    let line_index = LineStartsCache::new(source);
+
+    let diagnostics = detect_dead_code(&index, "file:///Util.pm", source, &line_index);
+    let subs: Vec<_> =
+        diagnostics.iter().filter(|d| d.code.as_deref() == Some("dead-code-subroutine")).collect();
+
+    assert_eq!(subs.len(), 1, "only the private sub should be reported: {:?}", subs);
+    assert!(subs[0].message.contains("_orphan"));
+    assert_eq!(subs[0].severity, DiagnosticSeverity::Hint);
+
+    Ok(())
+}
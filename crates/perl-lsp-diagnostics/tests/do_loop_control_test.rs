@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use perl_lsp_diagnostics::{Diagnostic, DiagnosticSeverity, DiagnosticsProvider};
+use perl_parser_core::Parser;
+
+fn diagnostics_for(source: &str) -> Result<Vec<Diagnostic>, Box<dyn std::error::Error>> {
+    let mut parser = Parser::new(source);
+    let ast = Arc::new(parser.parse()?);
+    let provider = DiagnosticsProvider::new(&ast, source.to_string());
+    Ok(provider.get_diagnostics(&ast, &[], source))
+}
+
+fn loop_control_hits(diagnostics: &[Diagnostic]) -> Vec<&Diagnostic> {
+    diagnostics.iter().filter(|d| d.code.as_deref() == Some("do-block-loop-control")).collect()
+}
+
+#[test]
+fn test_last_in_do_while_is_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "my ($x, $y) = (1, 1);\ndo { last if $x; } while ($y);\n";
+    let diagnostics = diagnostics_for(source)?;
+    let hits = loop_control_hits(&diagnostics);
+
+    assert_eq!(hits.len(), 1, "Expected one loop-control warning, got {diagnostics:?}");
+    assert_eq!(hits[0].severity, DiagnosticSeverity::Warning);
+    assert!(hits[0].message.contains("'last'"));
+    assert!(hits[0].message.contains("while"));
+    assert_eq!(&source[hits[0].range.0..hits[0].range.1], "last");
+
+    Ok(())
+}
+
+#[test]
+fn test_next_and_redo_in_do_until_are_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "my $done = 0;\ndo { next if $done; redo unless $done; } until ($done);\n";
+    let diagnostics = diagnostics_for(source)?;
+
+    assert_eq!(loop_control_hits(&diagnostics).len(), 2, "Got {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_do_until_without_loop_control_is_clean() -> Result<(), Box<dyn std::error::Error>> {
+    let diagnostics = diagnostics_for("my $done = 1;\ndo { print 1 } until ($done);\n")?;
+    assert!(loop_control_hits(&diagnostics).is_empty(), "Unexpected warning: {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_loop_control_with_enclosing_bare_block_is_not_flagged()
+-> Result<(), Box<dyn std::error::Error>> {
+    let source = "my ($x, $y) = (1, 1);\nLOOP: {\n    do { last if $x; } while ($y);\n}\n";
+    let diagnostics = diagnostics_for(source)?;
+    assert!(loop_control_hits(&diagnostics).is_empty(), "Unexpected warning: {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_enclosing_sub_does_not_provide_a_target() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "for my $i (1..3) {\n    my $f = sub { do { next; } while (0); };\n}\n";
+    let diagnostics = diagnostics_for(source)?;
+    assert_eq!(loop_control_hits(&diagnostics).len(), 1, "Got {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_valid_loop_control_targets_are_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = r#"my ($x, $y) = (1, 1);
+do {{ last if $x; }} while ($y);
+do { for my $i (1..3) { next if $i == 2; } } while ($y);
+OUTER: for my $j (1..3) { do { next OUTER if $x; } while ($y); }
+"#;
+    let diagnostics = diagnostics_for(source)?;
+    assert!(loop_control_hits(&diagnostics).is_empty(), "Unexpected warning: {diagnostics:?}");
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use perl_lsp_diagnostics::{Diagnostic, DiagnosticSeverity, DiagnosticsProvider};
+use perl_parser_core::Parser;
+
+fn diagnostics_for(source: &str) -> Result<Vec<Diagnostic>, Box<dyn std::error::Error>> {
+    let mut parser = Parser::new(source);
+    let ast = Arc::new(parser.parse()?);
+    let provider = DiagnosticsProvider::new(&ast, source.to_string());
+    Ok(provider.get_diagnostics(&ast, &[], source))
+}
+
+fn empty_repetitions(diagnostics: &[Diagnostic]) -> Vec<&Diagnostic> {
+    diagnostics.iter().filter(|d| d.code.as_deref() == Some("empty-repetition")).collect()
+}
+
+#[test]
+fn test_string_repeated_zero_times_is_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = r#"my $s = "ab" x 0;"#;
+    let diagnostics = diagnostics_for(source)?;
+    let hits = empty_repetitions(&diagnostics);
+
+    assert_eq!(hits.len(), 1, "Expected one empty-repetition hint, got {diagnostics:?}");
+    assert_eq!(hits[0].severity, DiagnosticSeverity::Hint);
+    assert!(hits[0].message.contains('0'));
+    assert_eq!(&source[hits[0].range.0..hits[0].range.1], r#""ab" x 0"#);
+
+    Ok(())
+}
+
+#[test]
+fn test_list_repeated_negative_times_is_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "my @list = (0) x -3;";
+    let diagnostics = diagnostics_for(source)?;
+    let hits = empty_repetitions(&diagnostics);
+
+    assert_eq!(hits.len(), 1, "Expected one empty-repetition hint, got {diagnostics:?}");
+    assert!(hits[0].message.contains("-3"));
+
+    Ok(())
+}
+
+#[test]
+fn test_positive_count_is_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let diagnostics = diagnostics_for(r#"my $s = "x" x 5;"#)?;
+    assert!(empty_repetitions(&diagnostics).is_empty(), "Unexpected hint: {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_variable_count_is_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let diagnostics = diagnostics_for(r#"my $n = 0; my $s = "x" x $n;"#)?;
+    assert!(empty_repetitions(&diagnostics).is_empty(), "Unexpected hint: {diagnostics:?}");
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use perl_lsp_diagnostics::{Diagnostic, DiagnosticSeverity, DiagnosticsProvider};
+use perl_parser_core::Parser;
+
+fn diagnostics_for(source: &str) -> Result<Vec<Diagnostic>, Box<dyn std::error::Error>> {
+    let mut parser = Parser::new(source);
+    let ast = Arc::new(parser.parse()?);
+    let provider = DiagnosticsProvider::new(&ast, source.to_string());
+    Ok(provider.get_diagnostics(&ast, &[], source))
+}
+
+fn format_mismatches(diagnostics: &[Diagnostic]) -> Vec<&Diagnostic> {
+    diagnostics.iter().filter(|d| d.code.as_deref() == Some("format-argument-count")).collect()
+}
+
+#[test]
+fn test_matching_arguments_are_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = r#"my ($name, $n, $w, @list) = @ARGV;
+my $a = sprintf("%s has %d items (100%%)", $name, $n);
+my $b = sprintf('%-*.*f|%+05d', $w, 2, $n, -$n);
+my $c = sprintf('%2$s %1$s', $name, $n);
+printf STDERR "%s\n", $list[0];
+printf("%vd\n", $name);
+"#;
+    let diagnostics = diagnostics_for(source)?;
+    let hits = format_mismatches(&diagnostics);
+
+    assert!(hits.is_empty(), "Expected no format diagnostics, got {hits:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_too_few_arguments_are_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "my $name = 'x';\nmy $s = sprintf(\"%s is %d years\", $name);\n";
+    let diagnostics = diagnostics_for(source)?;
+    let hits = format_mismatches(&diagnostics);
+
+    assert_eq!(hits.len(), 1, "Expected one format diagnostic, got {diagnostics:?}");
+    assert_eq!(hits[0].severity, DiagnosticSeverity::Warning);
+    assert_eq!(&source[hits[0].range.0..hits[0].range.1], "sprintf(\"%s is %d years\", $name)");
+    assert!(hits[0].message.contains("Missing argument in sprintf"));
+    assert!(hits[0].message.contains("expects 2 arguments but 1 is given"));
+
+    Ok(())
+}
+
+#[test]
+fn test_star_width_and_redundant_arguments_are_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source =
+        "my ($w, $x, $y) = (1, 2, 3);\nprintf STDOUT '%*d', $x;\nprintf(\"%d\\n\", $x, $y);\n";
+    let diagnostics = diagnostics_for(source)?;
+    let hits = format_mismatches(&diagnostics);
+
+    assert_eq!(hits.len(), 2, "Expected two format diagnostics, got {diagnostics:?}");
+    assert!(hits.iter().any(|d| d.message.contains("Missing argument in printf")));
+    assert!(hits.iter().any(|d| d.message.contains("Redundant argument in printf")));
+
+    Ok(())
+}
+
+#[test]
+fn test_dynamic_formats_and_list_arguments_are_skipped() -> Result<(), Box<dyn std::error::Error>> {
+    let source = r#"my ($fmt, $prefix, @values) = @ARGV;
+my $a = sprintf($fmt, 1);
+my $b = sprintf("$prefix: %s %s", 1);
+my $c = sprintf("%s %s", @values);
+my $d = sprintf("%s %s %s", get_values());
+"#;
+    let diagnostics = diagnostics_for(source)?;
+    let hits = format_mismatches(&diagnostics);
+
+    assert!(hits.is_empty(), "Expected dynamic cases to be skipped, got {hits:?}");
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use perl_lsp_diagnostics::performance::check_hoistable_loop_declarations;
+use perl_lsp_diagnostics::{Diagnostic, DiagnosticSeverity, DiagnosticsProvider, HintOptions};
+use perl_parser_core::Parser;
+
+fn hoist_hints(source: &str) -> Result<Vec<Diagnostic>, Box<dyn std::error::Error>> {
+    let mut parser = Parser::new(source);
+    let ast = parser.parse()?;
+    let mut diagnostics = Vec::new();
+    check_hoistable_loop_declarations(&ast, source, &mut diagnostics);
+    Ok(diagnostics)
+}
+
+#[test]
+fn test_constant_regex_in_loop_is_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "for (@x) { my $re = qr/abc/; print if $_ =~ $re; }";
+    let hints = hoist_hints(source)?;
+
+    assert_eq!(hints.len(), 1, "Expected one hoist hint, got {hints:?}");
+    assert_eq!(hints[0].severity, DiagnosticSeverity::Information);
+    assert_eq!(hints[0].code.as_deref(), Some("hoistable-declaration"));
+    assert_eq!(&source[hints[0].range.0..hints[0].range.1], "my $re = qr/abc/");
+
+    Ok(())
+}
+
+#[test]
+fn test_constant_hash_in_while_loop_is_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let hints = hoist_hints("while (my $k = shift @keys) { my %names = (a => 1, b => 2); }")?;
+    assert_eq!(hints.len(), 1, "Expected one hoist hint, got {hints:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_initializer_using_loop_variable_is_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let hints = hoist_hints("for (@x) { my $y = $_ * 2; }")?;
+    assert!(hints.is_empty(), "Unexpected hint: {hints:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_per_iteration_accumulator_is_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "for my $row (@rows) { my @cells = (); my $n = 0; push @cells, $row; $n++; }";
+    let hints = hoist_hints(source)?;
+    assert!(hints.is_empty(), "Unexpected hint: {hints:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_fresh_references_and_interpolation_are_not_flagged()
+-> Result<(), Box<dyn std::error::Error>> {
+    let source = "for (@x) { my $list = [1, 2]; my $re = qr/$_/; my $s = \"n=$_\"; }";
+    let hints = hoist_hints(source)?;
+    assert!(hints.is_empty(), "Unexpected hint: {hints:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_declaration_outside_loop_is_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let hints = hoist_hints("my $re = qr/abc/;\nsub f { my %c = (a => 1); }")?;
+    assert!(hints.is_empty(), "Unexpected hint: {hints:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_provider_reports_hint_only_when_enabled() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "for (@x) { my $re = qr/abc/; print if $_ =~ $re; }";
+    let mut parser = Parser::new(source);
+    let ast = Arc::new(parser.parse()?);
+    let hoist_hint_count = |hints: HintOptions| {
+        DiagnosticsProvider::new(&ast, source.to_string())
+            .with_hints(hints)
+            .get_diagnostics(&ast, &[], source)
+            .iter()
+            .filter(|d| d.code.as_deref() == Some("hoistable-declaration"))
+            .count()
+    };
+
+    assert_eq!(hoist_hint_count(HintOptions::default()), 0);
+    let enabled = HintOptions { hoistable_loop_declarations: true, ..HintOptions::default() };
+    assert_eq!(hoist_hint_count(enabled), 1);
+
+    Ok(())
+}
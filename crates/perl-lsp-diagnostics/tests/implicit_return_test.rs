@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use perl_lsp_diagnostics::best_practices::{ImplicitReturnOptions, check_implicit_returns};
+use perl_lsp_diagnostics::{Diagnostic, DiagnosticSeverity, DiagnosticsProvider, HintOptions};
+use perl_parser_core::Parser;
+
+fn implicit_return_hints(
+    source: &str,
+    options: ImplicitReturnOptions,
+) -> Result<Vec<Diagnostic>, Box<dyn std::error::Error>> {
+    let mut parser = Parser::new(source);
+    let ast = parser.parse()?;
+    let mut diagnostics = Vec::new();
+    check_implicit_returns(&ast, options, &mut diagnostics);
+    Ok(diagnostics)
+}
+
+#[test]
+fn test_complex_implicit_return_is_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "sub total { my ($s) = @_; $s->{a} + $s->{b} * tax($s) }";
+    let hints = implicit_return_hints(source, ImplicitReturnOptions::default())?;
+
+    assert_eq!(hints.len(), 1, "Expected one implicit-return hint, got {hints:?}");
+    assert_eq!(hints[0].severity, DiagnosticSeverity::Hint);
+    assert_eq!(hints[0].code.as_deref(), Some("PL402"));
+    assert_eq!(&source[hints[0].range.0..hints[0].range.1], "$s->{a} + $s->{b} * tax($s)");
+
+    Ok(())
+}
+
+#[test]
+fn test_trivial_accessors_are_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "sub name { $_[0]->{name} }\nsub id { my $self = shift; $self->{id} }\n";
+    let hints = implicit_return_hints(source, ImplicitReturnOptions::default())?;
+    assert!(hints.is_empty(), "Expected no hints, got {hints:?}");
+
+    // Tightening the threshold reports them too
+    let strict = ImplicitReturnOptions { max_trivial_nodes: 1 };
+    assert_eq!(implicit_return_hints(source, strict)?.len(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_explicit_returns_and_control_flow_are_not_flagged() -> Result<(), Box<dyn std::error::Error>>
+{
+    let source = r#"
+sub explicit { my ($s) = @_; return $s->{a} + $s->{b} * tax($s); }
+sub dies { my ($s) = @_; die "bad value: " . join(", ", @$s) . "\n" }
+sub branch { my ($s) = @_; if ($s) { $s->{a} + $s->{b} * 2 } }
+my $cb = sub { $_[0]->{a} + $_[0]->{b} * tax($_[0]) };
+"#;
+    let hints = implicit_return_hints(source, ImplicitReturnOptions::default())?;
+    assert!(hints.is_empty(), "Expected no hints, got {hints:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_provider_uses_configured_options() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "sub name { my $self = shift; $self->{name} }\n";
+    let mut parser = Parser::new(source);
+    let ast = Arc::new(parser.parse()?);
+    let implicit_return_count = |implicit_returns: Option<ImplicitReturnOptions>| {
+        let hints = HintOptions { implicit_returns, ..HintOptions::default() };
+        DiagnosticsProvider::new(&ast, source.to_string())
+            .with_hints(hints)
+            .get_diagnostics(&ast, &[], source)
+            .iter()
+            .filter(|d| d.code.as_deref() == Some("PL402"))
+            .count()
+    };
+
+    // Off unless enabled, and the accessor is trivial under the default threshold
+    assert_eq!(implicit_return_count(None), 0);
+    assert_eq!(implicit_return_count(Some(ImplicitReturnOptions::default())), 0);
+    assert_eq!(implicit_return_count(Some(ImplicitReturnOptions { max_trivial_nodes: 1 })), 1);
+
+    Ok(())
+}
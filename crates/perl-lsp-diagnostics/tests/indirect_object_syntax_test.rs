@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use perl_lsp_diagnostics::{Diagnostic, DiagnosticSeverity, DiagnosticsProvider};
+use perl_parser_core::Parser;
+
+fn diagnostics_for(source: &str) -> Result<Vec<Diagnostic>, Box<dyn std::error::Error>> {
+    let mut parser = Parser::new(source);
+    let ast = Arc::new(parser.parse()?);
+    let provider = DiagnosticsProvider::new(&ast, source.to_string());
+    Ok(provider.get_diagnostics(&ast, &[], source))
+}
+
+fn indirect_calls(diagnostics: &[Diagnostic]) -> Vec<&Diagnostic> {
+    diagnostics.iter().filter(|d| d.code.as_deref() == Some("PL403")).collect()
+}
+
+#[test]
+fn test_indirect_constructor_is_hinted() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "my $obj = 1;\nnew Player(\"Steven\");\n";
+    let diagnostics = diagnostics_for(source)?;
+    let hits = indirect_calls(&diagnostics);
+
+    assert_eq!(hits.len(), 1, "Expected one PL403 hint, got {diagnostics:?}");
+    assert_eq!(hits[0].severity, DiagnosticSeverity::Hint);
+    assert_eq!(&source[hits[0].range.0..hits[0].range.1], "new Player");
+    assert!(hits[0].related_information.iter().any(|info| info.message.contains("Player->new")));
+
+    Ok(())
+}
+
+#[test]
+fn test_filehandle_builtins_are_not_hinted() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "print STDERR \"x\";\nprint $fh @x;\nsay {$fh} $y;\nclose FH;\n";
+    let diagnostics = diagnostics_for(source)?;
+
+    assert!(indirect_calls(&diagnostics).is_empty(), "Unexpected PL403: {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_arrow_constructor_is_not_hinted() -> Result<(), Box<dyn std::error::Error>> {
+    let diagnostics = diagnostics_for("my $p = Player->new(\"Steven\");")?;
+    assert!(indirect_calls(&diagnostics).is_empty(), "Unexpected PL403: {diagnostics:?}");
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use perl_lsp_diagnostics::{Diagnostic, DiagnosticSeverity, DiagnosticsProvider};
+use perl_parser_core::Parser;
+
+fn diagnostics_for(uri: &str, source: &str) -> Result<Vec<Diagnostic>, Box<dyn std::error::Error>> {
+    let mut parser = Parser::new(source);
+    let ast = Arc::new(parser.parse()?);
+    let provider = DiagnosticsProvider::new(&ast, source.to_string()).with_uri(uri);
+    Ok(provider.get_diagnostics(&ast, &[], source))
+}
+
+fn missing_pragma_codes(diagnostics: &[Diagnostic]) -> Vec<&str> {
+    diagnostics
+        .iter()
+        .filter_map(|d| d.code.as_deref())
+        .filter(|code| matches!(*code, "PL100" | "PL101"))
+        .collect()
+}
+
+#[test]
+fn test_use_v5_36_implies_strict_and_warnings() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "use v5.36;\nmy $x = 1;\nprint $x;\n";
+    let diagnostics = diagnostics_for("file:///project/script.pl", source)?;
+
+    assert!(missing_pragma_codes(&diagnostics).is_empty(), "Unexpected: {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_strict_only_warns_about_warnings() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "package My::Module;\nuse strict;\nsub run { 1 }\n1;\n";
+    let diagnostics = diagnostics_for("file:///project/lib/My/Module.pm", source)?;
+
+    assert_eq!(missing_pragma_codes(&diagnostics), vec!["PL101"], "Got {diagnostics:?}");
+    let warning =
+        diagnostics.iter().find(|d| d.code.as_deref() == Some("PL101")).ok_or("PL101 not found")?;
+    assert_eq!(warning.severity, DiagnosticSeverity::Warning);
+    assert!(warning.message.contains("use warnings"));
+
+    Ok(())
+}
+
+#[test]
+fn test_neither_pragma_warns_about_both() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "#!/usr/bin/perl\nmy $x = 1;\nprint $x;\n";
+    let diagnostics = diagnostics_for("file:///project/script.pl", source)?;
+
+    assert_eq!(missing_pragma_codes(&diagnostics), vec!["PL100", "PL101"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_pragma_enabled_after_code_starts_still_warns() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "#!/usr/bin/perl\nuse warnings;\nprint 1;\nuse strict;\n";
+    let diagnostics = diagnostics_for("file:///project/script.pl", source)?;
+
+    assert_eq!(missing_pragma_codes(&diagnostics), vec!["PL100"], "Got {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_moose_counts_as_strict_and_warnings() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "package Point;\nuse Moose;\nhas x => (is => 'ro');\n1;\n";
+    let diagnostics = diagnostics_for("file:///project/lib/Point.pm", source)?;
+
+    assert!(missing_pragma_codes(&diagnostics).is_empty(), "Unexpected: {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_fragments_and_snippets_are_skipped() -> Result<(), Box<dyn std::error::Error>> {
+    let file_source = "#!/usr/bin/perl\nprint 1;\n";
+    let snippet = "my $x = 1;\nprint $x;\n";
+
+    let untitled = diagnostics_for("untitled:Untitled-1", file_source)?;
+    assert!(missing_pragma_codes(&untitled).is_empty(), "Unexpected: {untitled:?}");
+
+    let snippet_diagnostics = diagnostics_for("file:///project/scratch.pl", snippet)?;
+    assert!(
+        missing_pragma_codes(&snippet_diagnostics).is_empty(),
+        "Unexpected: {snippet_diagnostics:?}"
+    );
+
+    let mut parser = Parser::new(file_source);
+    let ast = Arc::new(parser.parse()?);
+    let without_uri = DiagnosticsProvider::new(&ast, file_source.to_string()).get_diagnostics(
+        &ast,
+        &[],
+        file_source,
+    );
+    assert!(missing_pragma_codes(&without_uri).is_empty(), "Unexpected: {without_uri:?}");
+
+    Ok(())
+}
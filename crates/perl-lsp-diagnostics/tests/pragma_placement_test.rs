@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use perl_lsp_diagnostics::{Diagnostic, DiagnosticSeverity, DiagnosticsProvider};
+use perl_parser_core::Parser;
+
+fn diagnostics_for(source: &str) -> Result<Vec<Diagnostic>, Box<dyn std::error::Error>> {
+    let mut parser = Parser::new(source);
+    let ast = Arc::new(parser.parse()?);
+    let provider = DiagnosticsProvider::new(&ast, source.to_string());
+    Ok(provider.get_diagnostics(&ast, &[], source))
+}
+
+fn late_pragmas(diagnostics: &[Diagnostic]) -> Vec<&Diagnostic> {
+    diagnostics.iter().filter(|d| d.code.as_deref() == Some("late-pragma")).collect()
+}
+
+#[test]
+fn test_use_strict_after_code_is_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "my $x = 1; use strict;";
+    let diagnostics = diagnostics_for(source)?;
+    let late = late_pragmas(&diagnostics);
+
+    assert_eq!(late.len(), 1, "Expected one late-pragma diagnostic, got {diagnostics:?}");
+    assert_eq!(late[0].severity, DiagnosticSeverity::Warning);
+    assert!(late[0].message.contains("use strict"));
+    assert!(source[late[0].range.0..late[0].range.1].starts_with("use strict"));
+    assert!(late[0].related_information.iter().any(|info| info.message.contains("Move")));
+
+    Ok(())
+}
+
+#[test]
+fn test_use_strict_before_code_is_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let diagnostics = diagnostics_for("use strict; my $x = 1;")?;
+    assert!(late_pragmas(&diagnostics).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_use_strict_after_package_is_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let diagnostics = diagnostics_for("package Foo; use strict;")?;
+    assert!(late_pragmas(&diagnostics).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_pragmas_after_other_use_and_shebang_are_not_flagged()
+-> Result<(), Box<dyn std::error::Error>> {
+    let source = "#!/usr/bin/perl\nuse lib 'lib';\nuse strict;\nuse warnings;\nmy $x = 1;\n";
+    let diagnostics = diagnostics_for(source)?;
+    assert!(late_pragmas(&diagnostics).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_use_warnings_after_code_is_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "use strict;\nprint 'hi';\nuse warnings;\n";
+    let diagnostics = diagnostics_for(source)?;
+    let late = late_pragmas(&diagnostics);
+
+    assert_eq!(late.len(), 1, "Only the late 'use warnings' should be flagged: {diagnostics:?}");
+    assert!(late[0].message.contains("use warnings"));
+
+    Ok(())
+}
+
+#[test]
+fn test_pragma_already_in_effect_is_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let diagnostics = diagnostics_for("use strict;\nmy $x = 1;\nuse strict;\n")?;
+    assert!(late_pragmas(&diagnostics).is_empty());
+    Ok(())
+}
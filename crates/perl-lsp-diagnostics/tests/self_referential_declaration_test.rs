@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use perl_lsp_diagnostics::{Diagnostic, DiagnosticSeverity, DiagnosticsProvider};
+use perl_parser_core::Parser;
+
+fn diagnostics_for(source: &str) -> Result<Vec<Diagnostic>, Box<dyn std::error::Error>> {
+    let mut parser = Parser::new(source);
+    let ast = Arc::new(parser.parse()?);
+    let provider = DiagnosticsProvider::new(&ast, source.to_string());
+    Ok(provider.get_diagnostics(&ast, &[], source))
+}
+
+fn with_code<'a>(diagnostics: &'a [Diagnostic], code: &str) -> Vec<&'a Diagnostic> {
+    diagnostics.iter().filter(|d| d.code.as_deref() == Some(code)).collect()
+}
+
+#[test]
+fn test_self_reference_without_outer_variable_is_flagged() -> Result<(), Box<dyn std::error::Error>>
+{
+    let source = "use strict;\nmy $x = $x + 1;\nprint $x;\n";
+    let diagnostics = diagnostics_for(source)?;
+    let hits = with_code(&diagnostics, "self-referential-declaration");
+
+    assert_eq!(hits.len(), 1, "Expected one self-reference warning, got {diagnostics:?}");
+    assert_eq!(hits[0].severity, DiagnosticSeverity::Warning);
+    let rhs = source.find("= $x").map(|at| at + 2).unwrap_or_default();
+    assert_eq!(hits[0].range, (rhs, rhs + 2));
+    assert!(
+        with_code(&diagnostics, "undeclared-variable").is_empty(),
+        "The self-reference should replace the undeclared-variable error, got {diagnostics:?}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_self_reference_to_outer_variable_is_not_flagged() -> Result<(), Box<dyn std::error::Error>>
+{
+    let source = "use strict;\nmy $x = 1;\n{\n    my $x = $x + 1;\n    print $x;\n}\n";
+    let diagnostics = diagnostics_for(source)?;
+
+    assert!(
+        with_code(&diagnostics, "self-referential-declaration").is_empty(),
+        "Outer $x is a legitimate reference, got {diagnostics:?}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_list_declaration_swap_without_outer_variables_is_flagged()
+-> Result<(), Box<dyn std::error::Error>> {
+    let source = "my ($x, $y) = ($y, $x);\nprint $x, $y;\n";
+    let diagnostics = diagnostics_for(source)?;
+    let hits = with_code(&diagnostics, "self-referential-declaration");
+
+    assert_eq!(hits.len(), 2, "Expected both swapped names flagged, got {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_local_and_our_are_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "our $level = $level + 1;\nlocal $depth = $depth + 1;\n";
+    let diagnostics = diagnostics_for(source)?;
+
+    assert!(
+        with_code(&diagnostics, "self-referential-declaration").is_empty(),
+        "Package variables already exist, got {diagnostics:?}"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use perl_lsp_diagnostics::{Diagnostic, DiagnosticSeverity, DiagnosticsProvider};
+use perl_parser_core::Parser;
+
+fn diagnostics_for(source: &str) -> Result<Vec<Diagnostic>, Box<dyn std::error::Error>> {
+    let mut parser = Parser::new(source);
+    let ast = Arc::new(parser.parse()?);
+    let provider = DiagnosticsProvider::new(&ast, source.to_string());
+    Ok(provider.get_diagnostics(&ast, &[], source))
+}
+
+fn three_way_hints(diagnostics: &[Diagnostic]) -> Vec<&Diagnostic> {
+    diagnostics
+        .iter()
+        .filter(|d| d.code.as_deref() == Some("three-way-comparison-as-boolean"))
+        .collect()
+}
+
+#[test]
+fn test_spaceship_as_if_condition_is_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "our ($a, $b); if ($a <=> $b) {}";
+    let diagnostics = diagnostics_for(source)?;
+    let hits = three_way_hints(&diagnostics);
+
+    assert_eq!(hits.len(), 1, "Expected one three-way hint, got {diagnostics:?}");
+    assert_eq!(hits[0].severity, DiagnosticSeverity::Hint);
+    assert!(hits[0].message.contains("<=>"));
+    assert_eq!(&source[hits[0].range.0..hits[0].range.1], "$a <=> $b");
+
+    Ok(())
+}
+
+#[test]
+fn test_cmp_in_while_and_modifier_is_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "my ($x, $y) = @ARGV;\nwhile ($x cmp $y) { last }\nprint 1 unless $x cmp $y;\n";
+    let diagnostics = diagnostics_for(source)?;
+
+    assert_eq!(three_way_hints(&diagnostics).len(), 2, "Expected two hints: {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_discarded_comparison_statement_is_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "my ($x, $y) = @ARGV;\n$x <=> $y;\nprint $x;\n";
+    let diagnostics = diagnostics_for(source)?;
+    let hits = three_way_hints(&diagnostics);
+
+    assert_eq!(hits.len(), 1, "Expected one three-way hint, got {diagnostics:?}");
+    assert!(hits[0].message.contains("discarded"));
+
+    Ok(())
+}
+
+#[test]
+fn test_sort_comparator_is_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "my @x = (3, 1, 2);\nmy @sorted = sort { $a <=> $b } @x;\n";
+    let diagnostics = diagnostics_for(source)?;
+    assert!(three_way_hints(&diagnostics).is_empty(), "Unexpected hint: {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_captured_result_is_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "our ($a, $b);\nmy $ord = $a cmp $b;\nprint $ord;\n";
+    let diagnostics = diagnostics_for(source)?;
+    assert!(three_way_hints(&diagnostics).is_empty(), "Unexpected hint: {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_comparator_sub_return_value_is_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "sub by_name { $a->{name} cmp $b->{name} }\n";
+    let diagnostics = diagnostics_for(source)?;
+    assert!(three_way_hints(&diagnostics).is_empty(), "Unexpected hint: {diagnostics:?}");
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use perl_lsp_diagnostics::best_practices::three_arg_open_args;
+use perl_lsp_diagnostics::{Diagnostic, DiagnosticSeverity, DiagnosticsProvider};
+use perl_parser_core::Parser;
+
+fn diagnostics_for(source: &str) -> Result<Vec<Diagnostic>, Box<dyn std::error::Error>> {
+    let mut parser = Parser::new(source);
+    let ast = Arc::new(parser.parse()?);
+    let provider = DiagnosticsProvider::new(&ast, source.to_string());
+    Ok(provider.get_diagnostics(&ast, &[], source))
+}
+
+fn two_arg_opens(diagnostics: &[Diagnostic]) -> Vec<&Diagnostic> {
+    diagnostics.iter().filter(|d| d.code.as_deref() == Some("PL401")).collect()
+}
+
+#[test]
+fn test_two_arg_open_is_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = r#"open(FH, "<$file") or die;"#;
+    let diagnostics = diagnostics_for(source)?;
+    let hits = two_arg_opens(&diagnostics);
+
+    assert_eq!(hits.len(), 1, "Expected one PL401 warning, got {diagnostics:?}");
+    assert_eq!(hits[0].severity, DiagnosticSeverity::Warning);
+    assert_eq!(&source[hits[0].range.0..hits[0].range.1], r#""<$file""#);
+
+    Ok(())
+}
+
+#[test]
+fn test_two_arg_open_without_parens_is_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "open my $fh, '>> /tmp/log';";
+    let diagnostics = diagnostics_for(source)?;
+
+    assert_eq!(two_arg_opens(&diagnostics).len(), 1, "Expected PL401, got {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_three_arg_open_is_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let diagnostics = diagnostics_for(r#"open(my $fh, '<', $file) or die;"#)?;
+    assert!(two_arg_opens(&diagnostics).is_empty(), "Unexpected PL401: {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_three_arg_open_args_splits_modes() {
+    let cases = [
+        (r#""<$file""#, "<", "$file"),
+        (r#"">$file""#, ">", "$file"),
+        (r#"">> $log""#, ">>", "$log"),
+        (r#""+<$file""#, "+<", "$file"),
+        (r#""| lpr""#, "|-", r#""lpr""#),
+        (r#""ls -l |""#, "-|", r#""ls -l""#),
+        (r#""$dir/$file""#, "<", r#""$dir/$file""#),
+        ("'>out.txt'", ">", "'out.txt'"),
+        (r#"">&STDERR""#, ">&", r#""STDERR""#),
+    ];
+
+    for (literal, mode, filename) in cases {
+        assert_eq!(
+            three_arg_open_args(literal),
+            Some((mode.to_string(), filename.to_string())),
+            "for {literal}"
+        );
+    }
+}
+
+#[test]
+fn test_three_arg_open_args_skips_standard_streams() {
+    assert_eq!(three_arg_open_args(r#""-""#), None);
+    assert_eq!(three_arg_open_args(r#"">-""#), None);
+    assert_eq!(three_arg_open_args("$file"), None);
+}
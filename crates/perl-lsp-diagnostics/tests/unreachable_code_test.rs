@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use perl_lsp_diagnostics::{Diagnostic, DiagnosticSeverity, DiagnosticTag, DiagnosticsProvider};
+use perl_parser_core::Parser;
+
+fn diagnostics_for(source: &str) -> Result<Vec<Diagnostic>, Box<dyn std::error::Error>> {
+    let mut parser = Parser::new(source);
+    let ast = Arc::new(parser.parse()?);
+    let provider = DiagnosticsProvider::new(&ast, source.to_string());
+    Ok(provider.get_diagnostics(&ast, &[], source))
+}
+
+fn with_code<'a>(diagnostics: &'a [Diagnostic], code: &str) -> Vec<&'a Diagnostic> {
+    diagnostics.iter().filter(|d| d.code.as_deref() == Some(code)).collect()
+}
+
+#[test]
+fn test_code_after_bare_return_is_unreachable() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "sub f { return; print 1; print 2; }";
+    let diagnostics = diagnostics_for(source)?;
+    let hits = with_code(&diagnostics, "unreachable-code");
+
+    assert_eq!(hits.len(), 1, "Expected one unreachable-code hint, got {diagnostics:?}");
+    assert_eq!(hits[0].severity, DiagnosticSeverity::Hint);
+    assert_eq!(hits[0].tags, vec![DiagnosticTag::Unnecessary]);
+    assert_eq!(&source[hits[0].range.0..hits[0].range.1], "print 1; print 2");
+
+    Ok(())
+}
+
+#[test]
+fn test_code_after_conditional_return_is_reachable() -> Result<(), Box<dyn std::error::Error>> {
+    let diagnostics = diagnostics_for("sub f { return 1 if $x; print 1; }")?;
+    assert!(with_code(&diagnostics, "unreachable-code").is_empty(), "got {diagnostics:?}");
+
+    let diagnostics = diagnostics_for("for (@x) { next unless $_; print $_; }")?;
+    assert!(with_code(&diagnostics, "unreachable-code").is_empty(), "got {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_terminator_at_block_end_is_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let diagnostics = diagnostics_for("sub f { print 1; die 'boom'; }\nfor (@x) { last; }")?;
+    assert!(with_code(&diagnostics, "unreachable-code").is_empty(), "got {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_declarations_after_exit_are_not_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "exit;\nsub helper { 1 }\nprint 1;";
+    let diagnostics = diagnostics_for(source)?;
+    let hits = with_code(&diagnostics, "unreachable-code");
+
+    assert_eq!(hits.len(), 1, "Expected one unreachable-code hint, got {diagnostics:?}");
+    assert_eq!(&source[hits[0].range.0..hits[0].range.1], "print 1");
+
+    Ok(())
+}
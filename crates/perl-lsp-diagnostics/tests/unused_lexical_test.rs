@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use perl_lsp_diagnostics::{Diagnostic, DiagnosticSeverity, DiagnosticTag, DiagnosticsProvider};
+use perl_parser_core::Parser;
+
+fn diagnostics_for(source: &str) -> Result<Vec<Diagnostic>, Box<dyn std::error::Error>> {
+    let mut parser = Parser::new(source);
+    let ast = Arc::new(parser.parse()?);
+    let provider = DiagnosticsProvider::new(&ast, source.to_string());
+    Ok(provider.get_diagnostics(&ast, &[], source))
+}
+
+/// Source text of every PL102 diagnostic, in order
+fn unused_names<'a>(source: &'a str, diagnostics: &[Diagnostic]) -> Vec<&'a str> {
+    diagnostics
+        .iter()
+        .filter(|d| d.code.as_deref() == Some("PL102"))
+        .map(|d| &source[d.range.0..d.range.1])
+        .collect()
+}
+
+#[test]
+fn test_unused_my_is_flagged_as_unnecessary() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "my $x = 1;\nprint \"done\\n\";\n";
+    let diagnostics = diagnostics_for(source)?;
+
+    assert_eq!(unused_names(source, &diagnostics), ["$x"]);
+    let unused = diagnostics.iter().find(|d| d.code.as_deref() == Some("PL102"));
+    assert_eq!(unused.map(|d| d.severity), Some(DiagnosticSeverity::Warning));
+    assert_eq!(unused.map(|d| d.tags.clone()), Some(vec![DiagnosticTag::Unnecessary]));
+    assert!(unused.is_some_and(|d| d.message.contains("declared but never used")));
+
+    Ok(())
+}
+
+#[test]
+fn test_interpolated_use_counts_as_read() -> Result<(), Box<dyn std::error::Error>> {
+    let source =
+        "my $y = 1;\nmy @list = (1, 2);\nmy %h = (a => 1);\nprint \"y=$y @list ${y} $h{a}\\n\";\n";
+    let diagnostics = diagnostics_for(source)?;
+
+    assert!(unused_names(source, &diagnostics).is_empty(), "Got {diagnostics:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_escaped_sigil_is_not_a_read() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "my $cost = 5;\nprint \"\\$cost\\n\";\n";
+    let diagnostics = diagnostics_for(source)?;
+
+    assert_eq!(unused_names(source, &diagnostics), ["$cost"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_write_only_variable_is_flagged() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "my $total;\n$total = 10;\n";
+    let diagnostics = diagnostics_for(source)?;
+
+    assert_eq!(unused_names(source, &diagnostics), ["$total"]);
+    let unused = diagnostics.iter().find(|d| d.code.as_deref() == Some("PL102"));
+    assert!(unused.is_some_and(|d| d.message.contains("assigned but never read")));
+
+    Ok(())
+}
+
+#[test]
+fn test_loop_variable_read_in_body_is_used() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "for my $item (1 .. 3) {\n    print $item;\n}\nfor my $skipped (1 .. 3) {\n    print \"tick\\n\";\n}\n";
+    let diagnostics = diagnostics_for(source)?;
+
+    assert_eq!(unused_names(source, &diagnostics), ["$skipped"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_shadowed_inner_declaration_does_not_use_outer() -> Result<(), Box<dyn std::error::Error>> {
+    let source = "my $v = 1;\nsub f {\n    my $v = 2;\n    return $v;\n}\n";
+    let diagnostics = diagnostics_for(source)?;
+
+    assert_eq!(unused_names(source, &diagnostics), ["$v"]);
+    let unused = diagnostics.iter().find(|d| d.code.as_deref() == Some("PL102"));
+    assert_eq!(unused.map(|d| d.range.0), Some(3));
+
+    Ok(())
+}
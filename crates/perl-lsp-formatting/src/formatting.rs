@@ -21,6 +21,7 @@
 //! - **Graceful Degradation**: Continues operation even when perltidy is unavailable
 //! - **Memory Efficient**: Streams large files to minimize memory usage during formatting
 
+use perl_lsp_tooling::perltidy::FormatSkipMarkers;
 use serde::{Deserialize, Serialize};
 
 /// Text edit for formatting
@@ -125,12 +126,14 @@ pub struct FormattingProvider<R> {
     runtime: R,
     /// Optional custom perltidy path
     perltidy_path: Option<String>,
+    /// Markers for regions that must be left byte-identical
+    skip_markers: FormatSkipMarkers,
 }
 
 impl<R> FormattingProvider<R> {
     /// Create a new formatting provider with the given runtime
     pub fn new(runtime: R) -> Self {
-        Self { runtime, perltidy_path: None }
+        Self { runtime, perltidy_path: None, skip_markers: FormatSkipMarkers::default() }
     }
 
     /// Set a custom perltidy path
@@ -138,6 +141,12 @@ impl<R> FormattingProvider<R> {
         self.perltidy_path = Some(path);
         self
     }
+
+    /// Set the format-disable region markers (defaults to `#<<<` / `#>>>`)
+    pub fn with_format_skip_markers(mut self, markers: FormatSkipMarkers) -> Self {
+        self.skip_markers = markers;
+        self
+    }
 }
 
 impl<R: perl_lsp_tooling::SubprocessRuntime> FormattingProvider<R> {
@@ -274,6 +283,9 @@ impl<R: perl_lsp_tooling::SubprocessRuntime> FormattingProvider<R> {
             args.push(format!("-i={}", options.tab_size)); // Tab size
         }
 
+        // Let perltidy skip format-disable regions natively
+        args.extend(self.skip_markers.perltidy_args());
+
         // Get perltidy command
         let default_cmd = "perltidy";
         let perltidy_cmd = self.perltidy_path.as_deref().unwrap_or(default_cmd);
@@ -294,7 +306,9 @@ impl<R: perl_lsp_tooling::SubprocessRuntime> FormattingProvider<R> {
             ));
         }
 
-        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+        // Guarantee protected regions come back byte-identical
+        let formatted = String::from_utf8_lossy(&output.stdout);
+        Ok(self.skip_markers.restore_regions(content, &formatted))
     }
 }
 
@@ -308,6 +322,88 @@ impl FormatPosition {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use perl_lsp_tooling::{SubprocessError, SubprocessOutput, SubprocessRuntime};
+    use perl_tdd_support::must;
+    use std::sync::Mutex;
+
+    /// Runtime that answers every command with fixed stdout and records the arguments
+    struct StubRuntime {
+        stdout: String,
+        args: Mutex<Vec<String>>,
+    }
+
+    impl StubRuntime {
+        fn new(stdout: &str) -> Self {
+            Self { stdout: stdout.to_string(), args: Mutex::new(Vec::new()) }
+        }
+    }
+
+    impl SubprocessRuntime for StubRuntime {
+        fn run_command(
+            &self,
+            _program: &str,
+            args: &[&str],
+            _stdin: Option<&[u8]>,
+        ) -> Result<SubprocessOutput, SubprocessError> {
+            *must(self.args.lock()) = args.iter().map(|a| a.to_string()).collect();
+            Ok(SubprocessOutput {
+                stdout: self.stdout.as_bytes().to_vec(),
+                stderr: Vec::new(),
+                status_code: 0,
+            })
+        }
+    }
+
+    fn options() -> FormattingOptions {
+        FormattingOptions {
+            tab_size: 4,
+            insert_spaces: true,
+            trim_trailing_whitespace: None,
+            insert_final_newline: None,
+            trim_final_newlines: None,
+        }
+    }
+
+    #[test]
+    fn test_skip_region_preserved_verbatim() {
+        let source = "my $x=1;\n#<<<\nmy @grid = ( 1,  0,\n             0,  1 );\n#>>>\nmy $y=2;\n";
+        // Formatter output that (wrongly) reformatted the protected region
+        let tidied = "my $x = 1;\n#<<<\nmy @grid = ( 1, 0, 0, 1 );\n#>>>\nmy $y = 2;\n";
+
+        let provider = FormattingProvider::new(StubRuntime::new(tidied));
+        let doc = must(provider.format_document(source, &options()));
+
+        assert_eq!(
+            doc.text,
+            "my $x = 1;\n#<<<\nmy @grid = ( 1,  0,\n             0,  1 );\n#>>>\nmy $y = 2;\n"
+        );
+    }
+
+    #[test]
+    fn test_unclosed_skip_marker_protects_to_eof() {
+        let source = "my $x=1;\n#<<<\nmy %h = (a   => 1,\n         bb  => 2);\n";
+        let tidied = "my $x = 1;\n#<<<\nmy %h = ( a => 1, bb => 2 );\n";
+
+        let provider = FormattingProvider::new(StubRuntime::new(tidied));
+        let doc = must(provider.format_document(source, &options()));
+
+        assert_eq!(doc.text, "my $x = 1;\n#<<<\nmy %h = (a   => 1,\n         bb  => 2);\n");
+    }
+
+    #[test]
+    fn test_custom_skip_markers_passed_to_perltidy() {
+        let source = "## tidy off\nmy $x  =  1;\n## tidy on\n";
+        let runtime = StubRuntime::new(source);
+
+        let provider = FormattingProvider::new(runtime)
+            .with_format_skip_markers(FormatSkipMarkers::new("## tidy off", "## tidy on"));
+        let doc = must(provider.format_document(source, &options()));
+        assert!(doc.edits.is_empty());
+
+        let args = must(provider.runtime.args.lock()).clone();
+        assert!(args.contains(&"--format-skipping-begin=## tidy off".to_string()));
+        assert!(args.contains(&"--format-skipping-end=## tidy on".to_string()));
+    }
 
     #[test]
     fn test_formatting_options() {
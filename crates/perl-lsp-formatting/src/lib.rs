@@ -30,3 +30,4 @@ pub use formatting::{
     FormatPosition, FormatRange, FormatTextEdit, FormattedDocument, FormattingError,
     FormattingOptions, FormattingProvider,
 };
+pub use perl_lsp_tooling::perltidy::FormatSkipMarkers;
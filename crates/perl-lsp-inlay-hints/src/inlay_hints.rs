@@ -21,6 +21,7 @@
 use perl_parser_core::ast::{Node, NodeKind};
 use perl_position_tracking::{WirePosition as Position, WireRange as Range};
 use perl_semantic_analyzer::declaration::get_node_children;
+use perl_semantic_analyzer::type_inference::{PerlType, ScalarType, TypeInferenceEngine};
 use serde_json::Value;
 use serde_json::json;
 
@@ -67,6 +68,7 @@ impl InlayHintsProvider {
         let mut hints = Vec::new();
         hints.extend(self.parameter_hints(ast, to_pos16, range));
         hints.extend(self.trivial_type_hints(ast, to_pos16, range));
+        hints.extend(self.inferred_type_hints(ast, to_pos16, range));
         hints
     }
 
@@ -129,6 +131,31 @@ impl InlayHintsProvider {
             })
             .collect()
     }
+
+    /// Generate inferred type hints for `my`/`our`/`state` declarations.
+    pub fn inferred_type_hints(
+        &self,
+        ast: &Node,
+        to_pos16: &impl Fn(usize) -> (u32, u32),
+        range: Option<Range>,
+    ) -> Vec<InlayHint> {
+        inferred_type_hints(ast, to_pos16, range)
+            .into_iter()
+            .filter_map(|v| {
+                let pos = v["position"].clone();
+                Some(InlayHint {
+                    position: Position::new(
+                        pos["line"].as_u64()? as u32,
+                        pos["character"].as_u64()? as u32,
+                    ),
+                    label: v["label"].as_str()?.to_string(),
+                    kind: InlayHintKind::Type,
+                    padding_left: v["paddingLeft"].as_bool().unwrap_or(false),
+                    padding_right: v["paddingRight"].as_bool().unwrap_or(false),
+                })
+            })
+            .collect()
+    }
 }
 
 impl Default for InlayHintsProvider {
@@ -281,6 +308,97 @@ pub fn trivial_type_hints(
     out
 }
 
+/// Generates inlay hints for the inferred types of declared variables.
+///
+/// Runs the [`TypeInferenceEngine`] over the AST and places a hint such as
+/// `: Int` or `: ArrayRef` right after the variable name of each `my`, `our`
+/// or `state` declaration. Declarations whose type is unknown or too
+/// vague to be useful (an untyped scalar, `undef`, a union) get no hint.
+///
+/// # Arguments
+///
+/// * `ast` - The root node of the AST to traverse.
+/// * `to_pos16` - A function that converts a byte offset to a (line, character) tuple.
+/// * `range` - An optional range to filter the inlay hints.
+///
+/// # Returns
+///
+/// A vector of `serde_json::Value` objects, each representing an inlay hint.
+pub fn inferred_type_hints(
+    ast: &Node,
+    to_pos16: &impl Fn(usize) -> (u32, u32),
+    range: Option<Range>,
+) -> Vec<Value> {
+    let mut engine = TypeInferenceEngine::new();
+    // Constraint violations only make `infer` return Err; the types are still recorded
+    let _ = engine.infer(ast);
+
+    let mut declared = Vec::new();
+    collect_declared_variables(ast, &mut declared);
+
+    let mut out = Vec::new();
+    for variable in declared {
+        let NodeKind::Variable { sigil, .. } = &variable.kind else {
+            continue;
+        };
+        let Some(label) = engine
+            .get_declaration_type(variable.location.start)
+            .and_then(|ty| type_label(ty, sigil))
+        else {
+            continue;
+        };
+
+        let (l, c) = to_pos16(variable.location.end);
+        if range.is_some_and(|filter_range| !pos_in_range(Position::new(l, c), filter_range)) {
+            continue;
+        }
+
+        out.push(json!({
+            "position": {"line": l, "character": c},
+            "label": format!(": {}", label),
+            "kind": 1, // type
+            "paddingLeft": false,
+            "paddingRight": false
+        }));
+    }
+    out
+}
+
+/// Variables introduced by single-variable declarations, in source order
+fn collect_declared_variables<'a>(node: &'a Node, out: &mut Vec<&'a Node>) {
+    if let NodeKind::VariableDeclaration { variable, .. } = &node.kind {
+        out.push(variable);
+    }
+    node.for_each_child(|child| collect_declared_variables(child, out));
+}
+
+/// Short label for an inferred type, or `None` when the type says nothing useful
+///
+/// A scalar variable holding an array or hash must hold a reference to one,
+/// so those are labelled `ArrayRef`/`HashRef`.
+fn type_label(ty: &PerlType, sigil: &str) -> Option<String> {
+    let label = match ty {
+        PerlType::Scalar(ScalarType::Integer) => "Int",
+        PerlType::Scalar(ScalarType::Float) => "Num",
+        PerlType::Scalar(ScalarType::String) => "Str",
+        PerlType::Scalar(ScalarType::Boolean) => "Bool",
+        PerlType::Array(_) if sigil == "$" => "ArrayRef",
+        PerlType::Array(_) => "Array",
+        PerlType::Hash { .. } if sigil == "$" => "HashRef",
+        PerlType::Hash { .. } => "Hash",
+        PerlType::Reference(inner) => match inner.as_ref() {
+            PerlType::Array(_) => "ArrayRef",
+            PerlType::Hash { .. } => "HashRef",
+            PerlType::Subroutine { .. } => "CodeRef",
+            _ => "Ref",
+        },
+        PerlType::Subroutine { .. } => "CodeRef",
+        PerlType::Object(class) => return Some(class.clone()),
+        _ => return None,
+    };
+    Some(label.to_string())
+}
+
 fn walk_ast<F>(node: &Node, visitor: &mut F) -> bool
 where
     F: FnMut(&Node) -> bool,
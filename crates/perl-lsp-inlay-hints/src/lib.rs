@@ -25,5 +25,6 @@
 mod inlay_hints;
 
 pub use inlay_hints::{
-    InlayHint, InlayHintKind, InlayHintsProvider, parameter_hints, trivial_type_hints,
+    InlayHint, InlayHintKind, InlayHintsProvider, inferred_type_hints, parameter_hints,
+    trivial_type_hints,
 };
@@ -3,6 +3,7 @@
 //! This module provides document link detection for Perl source files,
 //! identifying `use`, `require` module statements, and file includes.
 
+use perl_parser_core::pragma_tracker::is_pragma;
 use serde_json::{Value, json};
 use url::Url;
 
@@ -25,33 +26,11 @@ pub fn compute_links(uri: &str, text: &str, _roots: &[Url]) -> Vec<Value> {
     let mut out = Vec::new();
 
     for (i, line) in text.lines().enumerate() {
-        // "use Foo::Bar;" — defer resolution to documentLink/resolve
-        if let Some(rest) = line.trim().strip_prefix("use ")
-            && let Some(pkg) = rest.split_whitespace().next()
-        {
-            let pkg = pkg.trim_end_matches(';');
+        // "use Foo::Bar;" / "require Foo::Bar;" — defer resolution to documentLink/resolve
+        for (start, module) in module_names(line) {
             // Skip pragmas and core modules
-            if !is_pragma(pkg) {
-                // Defer expensive resolution - use data field
-                if let Some(link) = make_deferred_module_link(uri, i as u32, line, pkg) {
-                    out.push(link);
-                }
-            }
-        }
-
-        // "require Module::Name" (module form)
-        if let Some(rest) = line.trim().strip_prefix("require ")
-            && let Some(pkg) = rest.split_whitespace().next()
-        {
-            let pkg = pkg.trim_end_matches(';');
-            // Check if it's a module name (not a quoted file path)
-            if !pkg.starts_with('"')
-                && !pkg.starts_with('\'')
-                && pkg.contains("::")
-                && !is_pragma(pkg)
-                && let Some(link) = make_deferred_module_link(uri, i as u32, line, pkg)
-            {
-                out.push(link);
+            if !is_pragma(module) {
+                out.push(make_deferred_module_link(uri, i as u32, line, start, module));
             }
         }
 
@@ -89,71 +68,90 @@ pub fn compute_links(uri: &str, text: &str, _roots: &[Url]) -> Vec<Value> {
     out
 }
 
-/// Create a document link with deferred target resolution
+/// Module names loaded by `use`/`require` statements on a line
 ///
-/// Returns a link structure with a `data` field that will be used
-/// by `documentLink/resolve` to compute the actual target URI.
-fn make_deferred_module_link(uri: &str, line: u32, line_text: &str, module: &str) -> Option<Value> {
-    // Find the module name position in the line
-    if let Some(start) = line_text.find(module) {
-        let col_start = start as u32;
-        let col_end = (start + module.len()) as u32;
+/// Returns the byte offset of each name within the line together with the
+/// name itself, so the link covers `Foo::Bar` rather than the keyword. Version
+/// requirements (`use 5.010;`, `use v5.36;`) and quoted file paths are skipped.
+fn module_names(line: &str) -> Vec<(usize, &str)> {
+    let mut names = Vec::new();
+    let mut statement_start = true;
+    let mut pos = 0;
 
-        Some(json!({
-            "range": {
-                "start": {"line": line, "character": col_start},
-                "end": {"line": line, "character": col_end}
-            },
-            "tooltip": format!("Open {}", module),
-            "data": {
-                "type": "module",
-                "module": module,
-                "baseUri": uri
+    while pos < line.len() {
+        let rest = &line[pos..];
+        let Some(ch) = rest.chars().next() else { break };
+
+        // Only keywords that begin a statement load modules; stop at comments
+        if ch == '#' {
+            break;
+        }
+        if matches!(ch, ';' | '{' | '}') {
+            statement_start = true;
+            pos += 1;
+            continue;
+        }
+        if ch.is_whitespace() {
+            pos += ch.len_utf8();
+            continue;
+        }
+
+        let word_len = rest.find(|c: char| !is_module_char(c)).unwrap_or(rest.len());
+        let word = &rest[..word_len.max(ch.len_utf8())];
+        if statement_start && matches!(word, "use" | "no" | "require") {
+            let after = &rest[word.len()..];
+            let name_start = pos + word.len() + (after.len() - after.trim_start().len());
+            let name_rest = &line[name_start..];
+            let name_len = name_rest.find(|c: char| !is_module_char(c)).unwrap_or(name_rest.len());
+            let name = name_rest[..name_len].trim_end_matches("::");
+            let is_version = name.starts_with(|c: char| c.is_ascii_digit())
+                || (name.starts_with('v') && name[1..].starts_with(|c: char| c.is_ascii_digit()));
+            if name_start > pos + word.len()
+                && name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                && !is_version
+            {
+                names.push((name_start, name));
             }
-        }))
-    } else {
-        None
+            pos = name_start + name_len.max(1);
+        } else {
+            pos += word.len();
+        }
+        statement_start = false;
     }
+
+    names
 }
 
-fn is_pragma(pkg: &str) -> bool {
-    matches!(
-        pkg,
-        "strict"
-            | "warnings"
-            | "utf8"
-            | "bytes"
-            | "integer"
-            | "feature"
-            | "constant"
-            | "lib"
-            | "vars"
-            | "subs"
-            | "overload"
-            | "parent"
-            | "base"
-            | "fields"
-            | "if"
-            | "attributes"
-            | "autouse"
-            | "autodie"
-            | "bigint"
-            | "bignum"
-            | "bigrat"
-            | "blib"
-            | "charnames"
-            | "diagnostics"
-            | "encoding"
-            | "filetest"
-            | "locale"
-            | "open"
-            | "ops"
-            | "re"
-            | "sigtrap"
-            | "sort"
-            | "threads"
-            | "vmsish"
-    )
+fn is_module_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == ':'
+}
+
+/// Create a document link with deferred target resolution
+///
+/// Returns a link structure with a `data` field that will be used
+/// by `documentLink/resolve` to compute the actual target URI.
+fn make_deferred_module_link(
+    uri: &str,
+    line: u32,
+    line_text: &str,
+    start: usize,
+    module: &str,
+) -> Value {
+    let col_start = line_text[..start].encode_utf16().count() as u32;
+    let col_end = col_start + module.len() as u32;
+
+    json!({
+        "range": {
+            "start": {"line": line, "character": col_start},
+            "end": {"line": line, "character": col_end}
+        },
+        "tooltip": format!("Open {}", module),
+        "data": {
+            "type": "module",
+            "module": module,
+            "baseUri": uri
+        }
+    })
 }
 
 #[allow(dead_code)] // Reserved for future document link resolution
@@ -212,3 +210,45 @@ fn make_link(_src: &str, line: u32, line_text: &str, pkg: &str, target: String)
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link_spans(text: &str) -> Vec<(u64, u64, String)> {
+        compute_links("file:///t.pl", text, &[])
+            .iter()
+            .filter(|link| link["data"]["type"] == "module")
+            .map(|link| {
+                (
+                    link["range"]["start"]["character"].as_u64().unwrap_or(0),
+                    link["range"]["end"]["character"].as_u64().unwrap_or(0),
+                    link["data"]["module"].as_str().unwrap_or_default().to_string(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_module_link_covers_full_name() {
+        assert_eq!(link_spans("use Foo::Bar::Baz qw(x);\n"), [(4, 17, "Foo::Bar::Baz".into())]);
+        // A module name that also appears inside the keyword is still located correctly
+        assert_eq!(link_spans("  use us;\n"), [(6, 8, "us".into())]);
+        assert_eq!(link_spans("require Local;\n"), [(8, 13, "Local".into())]);
+    }
+
+    #[test]
+    fn test_versions_pragmas_and_non_statements_are_skipped() {
+        assert!(link_spans("use 5.010;\nuse v5.36;\nuse strict;\nuse warnings;\n").is_empty());
+        assert!(link_spans("my $use = 1; # use Foo::Bar\n").is_empty());
+        assert!(link_spans("require $file;\n").is_empty());
+    }
+
+    #[test]
+    fn test_several_statements_on_one_line() {
+        assert_eq!(
+            link_spans("use A::B; use C::D();\n"),
+            [(4, 8, "A::B".into()), (14, 18, "C::D".into())]
+        );
+    }
+}
@@ -35,9 +35,11 @@ mod workspace_symbols;
 
 // Re-export key types and functions
 pub use self::document_links::compute_links;
-pub use self::references::find_references_single_file;
+pub use self::references::{ReferencesProvider, find_references_single_file};
 pub use self::type_definition::TypeDefinitionProvider;
-pub use self::type_hierarchy::{TypeHierarchyItem, TypeHierarchyProvider, TypeHierarchySymbolKind};
+pub use self::type_hierarchy::{
+    HierarchyIndex, TypeHierarchyItem, TypeHierarchyProvider, TypeHierarchySymbolKind,
+};
 pub use self::workspace_symbols::{WorkspaceSymbol, WorkspaceSymbolsProvider};
 
 // Re-export Location type for convenience
@@ -34,7 +34,54 @@
 //! # }
 //! ```
 
+use lsp_types::{Location, Position, Range};
 use perl_parser_core::ast::{Node, NodeKind};
+use perl_workspace_index::workspace_index::{SymbolKey, WorkspaceIndex};
+
+/// Find-all-references across every file in the [`WorkspaceIndex`]
+///
+/// The index records `Pkg::name()` calls, `Pkg->name` class-method calls and
+/// bare calls from inside `Pkg` under the qualified name, so one lookup finds
+/// all three forms wherever they appear.
+pub struct ReferencesProvider<'a> {
+    index: &'a WorkspaceIndex,
+}
+
+impl<'a> ReferencesProvider<'a> {
+    /// Create a provider over an indexed workspace
+    pub fn new(index: &'a WorkspaceIndex) -> Self {
+        Self { index }
+    }
+
+    /// All references to `key` in indexed files, grouped by URI
+    ///
+    /// Locations are deduplicated and ordered by URI, then position, so each
+    /// file's references are contiguous. The symbol's definition is included
+    /// only when `include_declaration` is set.
+    pub fn find_references(&self, key: &SymbolKey, include_declaration: bool) -> Vec<Location> {
+        // find_refs already drops the definition and duplicate ranges
+        let mut refs = self.index.find_refs(key);
+        if include_declaration {
+            refs.extend(self.index.find_def(key));
+        }
+
+        refs.sort_by(|a, b| {
+            (a.uri.as_str(), a.range.start.line, a.range.start.column).cmp(&(
+                b.uri.as_str(),
+                b.range.start.line,
+                b.range.start.column,
+            ))
+        });
+        refs.into_iter()
+            .filter_map(|loc| {
+                let uri = loc.uri.parse().ok()?;
+                let start = Position::new(loc.range.start.line, loc.range.start.column);
+                let end = Position::new(loc.range.end.line, loc.range.end.column);
+                Some(Location::new(uri, Range::new(start, end)))
+            })
+            .collect()
+    }
+}
 
 /// Return (start_offset, end_offset) for same-file references
 pub fn find_references_single_file(ast: &Node, offset: usize) -> Option<Vec<(usize, usize)>> {
@@ -141,10 +188,48 @@ fn get_node_children(node: &Node) -> Vec<&Node> {
         NodeKind::Assignment { lhs, rhs, .. } => vec![lhs.as_ref(), rhs.as_ref()],
         NodeKind::Binary { left, right, .. } => vec![left.as_ref(), right.as_ref()],
         NodeKind::FunctionCall { args, .. } => args.iter().collect(),
-        NodeKind::Subroutine { body, .. } => {
-            vec![body.as_ref()]
-        }
+        NodeKind::Subroutine { body, .. } => body.iter().map(|b| b.as_ref()).collect(),
         NodeKind::ExpressionStatement { expression } => vec![expression.as_ref()],
         _ => vec![],
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use perl_tdd_support::must;
+    use perl_workspace_index::workspace_index::SymKind;
+
+    fn greet_key() -> SymbolKey {
+        SymbolKey { pkg: "A".into(), name: "greet".into(), sigil: None, kind: SymKind::Sub }
+    }
+
+    fn indexed_workspace() -> WorkspaceIndex {
+        let index = WorkspaceIndex::new();
+        must(index.index_file_str("file:///lib/A.pm", "package A;\nsub greet { 1 }\n1;\n"));
+        must(index.index_file_str("file:///bin/B.pl", "use A;\nA::greet();\nA->greet();\n"));
+        index
+    }
+
+    #[test]
+    fn test_references_span_files_grouped_by_uri() {
+        let index = indexed_workspace();
+        let refs = ReferencesProvider::new(&index).find_references(&greet_key(), true);
+
+        let summary: Vec<(&str, u32)> =
+            refs.iter().map(|loc| (loc.uri.as_str(), loc.range.start.line)).collect();
+        assert_eq!(
+            summary,
+            [("file:///bin/B.pl", 1), ("file:///bin/B.pl", 2), ("file:///lib/A.pm", 1)]
+        );
+    }
+
+    #[test]
+    fn test_references_exclude_declaration() {
+        let index = indexed_workspace();
+        let refs = ReferencesProvider::new(&index).find_references(&greet_key(), false);
+
+        assert_eq!(refs.len(), 2);
+        assert!(refs.iter().all(|loc| loc.uri.as_str() == "file:///bin/B.pl"));
+    }
+}
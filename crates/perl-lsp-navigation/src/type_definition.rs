@@ -281,7 +281,7 @@ impl TypeDefinitionProvider {
                     f(arg);
                 }
             }
-            NodeKind::Subroutine { body, .. } => {
+            NodeKind::Subroutine { body: Some(body), .. } => {
                 f(body);
             }
             NodeKind::ExpressionStatement { expression } => {
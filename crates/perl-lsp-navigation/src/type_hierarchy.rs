@@ -30,6 +30,7 @@
 use perl_parser_core::PositionMapper;
 use perl_parser_core::ast::{Node, NodeKind};
 use perl_position_tracking::{WirePosition, WireRange};
+use perl_workspace_index::inheritance::parent_declaration;
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, BTreeSet};
 
@@ -66,26 +67,32 @@ pub enum TypeHierarchySymbolKind {
     Function = 12,
 }
 
-/// Index for tracking package hierarchy relationships
-#[derive(Default, Debug)]
-struct HierarchyIndex {
-    /// Map from child package to its parent packages
-    parents: BTreeMap<String, BTreeSet<String>>,
+/// Package inheritance edges, as declared by `use parent`, `use base`, `@ISA`
+/// and `class ... :isa(...)`
+#[derive(Default, Debug, Clone)]
+pub struct HierarchyIndex {
+    /// Map from child package to its parent packages, in declaration order
+    parents: BTreeMap<String, Vec<String>>,
     /// Map from parent package to its child packages
     children: BTreeMap<String, BTreeSet<String>>,
 }
 
 impl HierarchyIndex {
     fn add_inheritance(&mut self, child: &str, parent: &str) {
-        self.parents.entry(child.to_string()).or_default().insert(parent.to_string());
+        let parents = self.parents.entry(child.to_string()).or_default();
+        if !parents.iter().any(|p| p == parent) {
+            parents.push(parent.to_string());
+        }
         self.children.entry(parent.to_string()).or_default().insert(child.to_string());
     }
 
-    fn get_parents(&self, package: &str) -> Vec<String> {
-        self.parents.get(package).map(|set| set.iter().cloned().collect()).unwrap_or_default()
+    /// Direct parents (supertypes) of a package, in declaration order
+    pub fn get_parents(&self, package: &str) -> Vec<String> {
+        self.parents.get(package).cloned().unwrap_or_default()
     }
 
-    fn get_children(&self, package: &str) -> Vec<String> {
+    /// Direct children (subtypes) of a package, sorted by name
+    pub fn get_children(&self, package: &str) -> Vec<String> {
         self.children.get(package).map(|set| set.iter().cloned().collect()).unwrap_or_default()
     }
 }
@@ -105,8 +112,11 @@ impl TypeHierarchyProvider {
         Self
     }
 
-    /// Build a hierarchy index from the AST
-    fn build_hierarchy_index(&self, ast: &Node) -> HierarchyIndex {
+    /// Build the inheritance hierarchy declared in a document
+    ///
+    /// Recognizes `use parent` (including `-norequire` and multiple parents),
+    /// `use base`, `our @ISA = (...)`, `push @ISA, ...` and `class Foo :isa(Bar)`.
+    pub fn build_hierarchy(&self, ast: &Node) -> HierarchyIndex {
         let mut index = HierarchyIndex::default();
         let mut current_package = "main".to_string();
 
@@ -122,8 +132,15 @@ impl TypeHierarchyProvider {
         index: &mut HierarchyIndex,
         current_package: &mut String,
     ) {
+        if let Some(decl) = parent_declaration(node) {
+            let child = decl.class.unwrap_or(current_package.as_str()).to_string();
+            for parent in &decl.parents {
+                index.add_inheritance(&child, parent);
+            }
+        }
+
         match &node.kind {
-            NodeKind::Package { name, block, name_span: _ } => {
+            NodeKind::Package { name, block, .. } => {
                 if block.is_some() {
                     // Block form: package Foo { ... }
                     // Save current package, process block, restore
@@ -139,42 +156,10 @@ impl TypeHierarchyProvider {
                     *current_package = name.clone();
                 }
             }
-            NodeKind::Use { module, args, .. } => {
-                if module == "parent" || module == "base" {
-                    for arg in args {
-                        for parent in self.normalize_parent_arg(arg) {
-                            index.add_inheritance(current_package, &parent);
-                        }
-                    }
-                }
-            }
-            NodeKind::VariableDeclaration { declarator, variable, initializer, .. } => {
-                if declarator == "our"
-                    && let NodeKind::Variable { sigil, name: var_name } = &variable.kind
-                    && sigil == "@"
-                    && var_name == "ISA"
-                    && let Some(init) = initializer
-                {
-                    for parent in self.extract_isa_parents(init) {
-                        index.add_inheritance(current_package, &parent);
-                    }
-                }
-            }
-            NodeKind::VariableListDeclaration { declarator, variables, initializer, .. } => {
-                if declarator == "our" {
-                    // Check if any variable is @ISA
-                    for var in variables {
-                        if let NodeKind::Variable { sigil, name: var_name } = &var.kind
-                            && sigil == "@"
-                            && var_name == "ISA"
-                            && let Some(init) = initializer
-                        {
-                            for parent in self.extract_isa_parents(init) {
-                                index.add_inheritance(current_package, &parent);
-                            }
-                        }
-                    }
-                }
+            NodeKind::Class { name, body, .. } => {
+                let saved_package = std::mem::replace(current_package, name.clone());
+                self.index_hierarchy_recursive(body, index, current_package);
+                *current_package = saved_package;
             }
             NodeKind::Program { statements } | NodeKind::Block { statements } => {
                 for stmt in statements {
@@ -192,75 +177,6 @@ impl TypeHierarchyProvider {
         }
     }
 
-    /// Normalize parent argument (handle quotes, qw(), etc.)
-    fn normalize_parent_arg(&self, arg: &str) -> Vec<String> {
-        let arg = arg.trim();
-
-        // Handle qw(Base Other)
-        if arg.starts_with("qw(") && arg.ends_with(')') {
-            let content = &arg[3..arg.len() - 1];
-            return content.split_whitespace().map(|s| s.to_string()).collect();
-        }
-
-        // Handle qw{Base Other}, qw[Base Other], etc.
-        if arg.starts_with("qw") && arg.len() > 2 {
-            let delim_start = arg.chars().nth(2).unwrap_or(' ');
-            let delim_end = match delim_start {
-                '(' => ')',
-                '{' => '}',
-                '[' => ']',
-                '<' => '>',
-                _ => delim_start,
-            };
-            if let Some(start) = arg.find(delim_start)
-                && let Some(end) = arg.rfind(delim_end)
-            {
-                let content = &arg[start + 1..end];
-                return content.split_whitespace().map(|s| s.to_string()).collect();
-            }
-        }
-
-        // Remove quotes
-        let clean = arg.trim_matches('"').trim_matches('\'').trim_matches('`');
-        vec![clean.to_string()]
-    }
-
-    /// Extract parent classes from @ISA initialization
-    fn extract_isa_parents(&self, node: &Node) -> Vec<String> {
-        let mut parents = Vec::new();
-
-        match &node.kind {
-            NodeKind::ArrayLiteral { elements } => {
-                for elem in elements {
-                    match &elem.kind {
-                        NodeKind::String { value, .. } => {
-                            for parent in self.normalize_parent_arg(value) {
-                                parents.push(parent);
-                            }
-                        }
-                        NodeKind::Identifier { name } => {
-                            // Bareword
-                            parents.push(name.clone());
-                        }
-                        _ => {}
-                    }
-                }
-            }
-            NodeKind::String { value, .. } => {
-                for parent in self.normalize_parent_arg(value) {
-                    parents.push(parent);
-                }
-            }
-            NodeKind::Identifier { name } => {
-                // Bareword
-                parents.push(name.clone());
-            }
-            _ => {}
-        }
-
-        parents
-    }
-
     /// Prepare type hierarchy at position
     pub fn prepare(&self, ast: &Node, code: &str, offset: usize) -> Option<Vec<TypeHierarchyItem>> {
         let position_mapper = PositionMapper::new(code);
@@ -310,7 +226,7 @@ impl TypeHierarchyProvider {
 
     /// Find supertypes (parent classes) via @ISA
     pub fn find_supertypes(&self, ast: &Node, item: &TypeHierarchyItem) -> Vec<TypeHierarchyItem> {
-        let index = self.build_hierarchy_index(ast);
+        let index = self.build_hierarchy(ast);
         let parents = index.get_parents(&item.name);
 
         parents
@@ -329,7 +245,7 @@ impl TypeHierarchyProvider {
 
     /// Find subtypes (child classes) that inherit from this class
     pub fn find_subtypes(&self, ast: &Node, item: &TypeHierarchyItem) -> Vec<TypeHierarchyItem> {
-        let index = self.build_hierarchy_index(ast);
+        let index = self.build_hierarchy(ast);
         let children = index.get_children(&item.name);
 
         children
@@ -382,7 +298,7 @@ impl TypeHierarchyProvider {
             }
             NodeKind::Package { block, .. } => block.as_ref().map(|b| vec![b.as_ref()]),
             NodeKind::Class { body, .. } => Some(vec![body.as_ref()]),
-            NodeKind::Subroutine { body, .. } => Some(vec![body.as_ref()]),
+            NodeKind::Subroutine { body, .. } => body.as_ref().map(|b| vec![b.as_ref()]),
             NodeKind::Assignment { lhs, rhs, .. } => Some(vec![lhs.as_ref(), rhs.as_ref()]),
             NodeKind::ExpressionStatement { expression } => Some(vec![expression.as_ref()]),
             _ => None,
@@ -485,10 +401,9 @@ our @ISA = qw(Parent1 Parent2);
         let items = must_some(items);
         assert_eq!(items[0].name, "Child");
 
-        // Find supertypes - qw() parsing needs AST improvements
         let supertypes = provider.find_supertypes(&ast, &items[0]);
-        // Just verify it doesn't panic for now
-        let _ = supertypes.len();
+        let names: Vec<&str> = supertypes.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["Parent1", "Parent2"]);
     }
 
     #[test]
@@ -545,8 +460,8 @@ our @ISA = qw(Parent1 Parent2 Parent3);
 
         // Find supertypes - should handle qw() properly
         let supertypes = provider.find_supertypes(&ast, &items[0]);
-        // For now just check it doesn't panic - full qw() support needs AST improvements
-        let _ = supertypes.len();
+        let names: Vec<&str> = supertypes.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["Parent1", "Parent2", "Parent3"]);
     }
 
     #[test]
@@ -577,4 +492,68 @@ use parent 'Outer';
         // Both Inner and Other inherit from Outer
         assert_eq!(subtypes.len(), 2, "Should find both Inner and Other as subtypes");
     }
+
+    #[test]
+    fn test_build_hierarchy_declaration_forms() {
+        let code = r#"package ViaParent;
+use parent -norequire, 'Base';
+
+package ViaBase;
+use base 'Base';
+
+package ViaOurIsa;
+our @ISA = ('Base');
+
+package ViaPush;
+push @ISA, 'Base';
+
+package main;
+use feature 'class';
+class ViaClass :isa(Base) {
+    method name { return "class"; }
+}
+"#;
+        let ast = must(Parser::new(code).parse());
+        let index = TypeHierarchyProvider::new().build_hierarchy(&ast);
+
+        for child in ["ViaParent", "ViaBase", "ViaOurIsa", "ViaPush", "ViaClass"] {
+            assert_eq!(index.get_parents(child), vec!["Base"], "{child} should inherit from Base");
+        }
+        assert_eq!(
+            index.get_children("Base"),
+            vec!["ViaBase", "ViaClass", "ViaOurIsa", "ViaParent", "ViaPush"]
+        );
+        assert!(index.get_parents("main").is_empty(), "-norequire is not a parent class");
+    }
+
+    #[test]
+    fn test_build_hierarchy_multiple_inheritance() {
+        let code = r#"package Duck;
+use parent -norequire, 'Animal', 'Swimmer';
+push @ISA, 'Flyer';
+
+package Platypus;
+use parent -norequire, qw(Mammal Swimmer);
+"#;
+        let ast = must(Parser::new(code).parse());
+        let provider = TypeHierarchyProvider::new();
+        let index = provider.build_hierarchy(&ast);
+
+        assert_eq!(index.get_parents("Duck"), vec!["Animal", "Swimmer", "Flyer"]);
+        assert_eq!(index.get_parents("Platypus"), vec!["Mammal", "Swimmer"]);
+        assert_eq!(index.get_children("Swimmer"), vec!["Duck", "Platypus"]);
+
+        let swimmer = TypeHierarchyItem {
+            name: "Swimmer".to_string(),
+            kind: TypeHierarchySymbolKind::Class,
+            uri: "file:///test".to_string(),
+            range: WireRange::default(),
+            selection_range: WireRange::default(),
+            detail: None,
+            data: None,
+        };
+        let subtypes: Vec<String> =
+            provider.find_subtypes(&ast, &swimmer).into_iter().map(|t| t.name).collect();
+        assert_eq!(subtypes, vec!["Duck", "Platypus"]);
+    }
 }
@@ -95,6 +95,85 @@ fn extract_container_name(qualified_name: &str) -> Option<String> {
     qualified_name.rfind("::").map(|idx| qualified_name[..idx].to_string())
 }
 
+/// Maximum number of results returned by a `workspace/symbol` search.
+pub const DEFAULT_RESULT_LIMIT: usize = 256;
+
+/// Base score for each matched query character.
+const MATCH_SCORE: i32 = 1;
+/// Bonus for matching the first character of a word or camel hump.
+const BOUNDARY_BONUS: i32 = 8;
+/// Bonus for matching directly after the previous query character.
+const CONSECUTIVE_BONUS: i32 = 4;
+/// Bonus for a whole-name, case-insensitive match.
+const EXACT_BONUS: i32 = 100;
+
+/// Strip leading Perl sigils so `$user_count` is matched as `user_count`.
+fn strip_sigil(s: &str) -> &str {
+    s.trim_start_matches(['$', '@', '%', '&', '*'])
+}
+
+/// Score `name` against `query` as a fuzzy subsequence match.
+///
+/// Returns `None` when the query characters do not all appear in order in the
+/// name. Each matched character scores [`MATCH_SCORE`], plus
+/// [`BOUNDARY_BONUS`] when it starts a word (start of name, after `_` or a
+/// package separator, or a lower-to-upper camel hump) or [`CONSECUTIVE_BONUS`]
+/// when it directly follows the previous match. The best-scoring alignment is
+/// chosen, so `gun` lands on the word starts of `get_user_name`.
+///
+/// An empty query matches everything with score 0.
+fn fuzzy_score(name: &str, query: &str) -> Option<i32> {
+    let name: Vec<char> = strip_sigil(name).chars().collect();
+    let query: Vec<char> = strip_sigil(query).chars().map(|c| c.to_ascii_lowercase()).collect();
+
+    let Some((&first, rest)) = query.split_first() else {
+        return Some(0);
+    };
+    if query.len() > name.len() {
+        return None;
+    }
+
+    let boundary: Vec<bool> = (0..name.len())
+        .map(|i| match i.checked_sub(1).map(|p| name[p]) {
+            None => true,
+            Some('_' | ':' | '\'') => !matches!(name[i], '_' | ':'),
+            Some(prev) => name[i].is_uppercase() && (prev.is_lowercase() || prev.is_ascii_digit()),
+        })
+        .collect();
+    let lower: Vec<char> = name.iter().map(|c| c.to_ascii_lowercase()).collect();
+    let boundary_bonus = |j: usize| if boundary[j] { BOUNDARY_BONUS } else { 0 };
+
+    // best[j]: best score for the query prefix so far with its last character at name[j].
+    let mut best: Vec<Option<i32>> = (0..lower.len())
+        .map(|j| (lower[j] == first).then(|| MATCH_SCORE + boundary_bonus(j)))
+        .collect();
+
+    for &qc in rest {
+        let mut next = vec![None; lower.len()];
+        // Best score over positions before j - 1, i.e. with a gap before j.
+        let mut gap_best: Option<i32> = None;
+
+        for j in 1..lower.len() {
+            if j >= 2 {
+                gap_best = gap_best.max(best[j - 2]);
+            }
+            if lower[j] != qc {
+                continue;
+            }
+
+            let from_gap = gap_best.map(|s| s + MATCH_SCORE + boundary_bonus(j));
+            let adjacent = if boundary[j] { BOUNDARY_BONUS } else { CONSECUTIVE_BONUS };
+            let from_prev = best[j - 1].map(|s| s + MATCH_SCORE + adjacent);
+            next[j] = from_gap.max(from_prev);
+        }
+
+        best = next;
+    }
+
+    let score = best.into_iter().flatten().max()?;
+    Some(if lower.len() == query.len() { score + EXACT_BONUS } else { score })
+}
+
 /// LSP WorkspaceSymbol representing a symbol found in the workspace.
 ///
 /// Corresponds to the LSP `WorkspaceSymbol` type used in `workspace/symbol` responses.
@@ -211,8 +290,8 @@ impl WorkspaceSymbolsProvider {
     /// More efficient than `search` when the caller has already narrowed down
     /// potential matches (e.g., from a global symbol index).
     ///
-    /// Results are sorted by relevance: exact matches first, then prefix matches,
-    /// then alphabetically.
+    /// Results are ranked with the same fuzzy scoring as [`search`](Self::search)
+    /// and capped to [`DEFAULT_RESULT_LIMIT`].
     #[must_use]
     pub fn search_with_candidates(
         &self,
@@ -220,72 +299,54 @@ impl WorkspaceSymbolsProvider {
         source_map: &HashMap<String, String>,
         candidates: &[String],
     ) -> Vec<WorkspaceSymbol> {
-        let query_lower = query.to_lowercase();
-        let mut results = Vec::new();
-
         // Create a set of candidate names for fast lookup
         let candidate_set: std::collections::HashSet<_> =
             candidates.iter().map(|s| s.to_lowercase()).collect();
 
-        for (uri, symbols) in &self.documents {
-            // Get source for this document to convert offsets
-            let source = match source_map.get(uri) {
-                Some(s) => s,
-                None => continue,
-            };
-
-            for symbol in symbols {
-                // Check if this symbol is in our candidate set
-                if candidate_set.contains(&symbol.name.to_lowercase())
-                    && self.matches_query(&symbol.name, &query_lower)
-                {
-                    results.push(self.symbol_to_workspace_symbol(uri, symbol, source));
-                }
-            }
-        }
-
-        // Sort by relevance
-        results.sort_by(|a, b| {
-            let a_exact = a.name.to_lowercase() == query_lower;
-            let b_exact = b.name.to_lowercase() == query_lower;
-
-            match (a_exact, b_exact) {
-                (true, false) => std::cmp::Ordering::Less,
-                (false, true) => std::cmp::Ordering::Greater,
-                _ => {
-                    let a_starts = a.name.to_lowercase().starts_with(&query_lower);
-                    let b_starts = b.name.to_lowercase().starts_with(&query_lower);
-
-                    match (a_starts, b_starts) {
-                        (true, false) => std::cmp::Ordering::Less,
-                        (false, true) => std::cmp::Ordering::Greater,
-                        _ => a.name.cmp(&b.name),
-                    }
-                }
-            }
-        });
-
-        results
+        self.ranked(query, source_map, DEFAULT_RESULT_LIMIT, |symbol| {
+            candidate_set.contains(&symbol.name.to_lowercase())
+        })
     }
 
     /// Searches for symbols matching a query string.
     ///
-    /// Supports multiple match strategies:
-    /// - Exact match (case-insensitive)
-    /// - Prefix match
-    /// - Contains match
-    /// - Fuzzy/subsequence match
+    /// Matching is fuzzy: the query must be a case-insensitive subsequence of
+    /// the symbol name, with sigils ignored on both sides. Matches on word
+    /// boundaries (`get_user_name`) and camel humps (`getUserName`) score
+    /// higher, so `gun` ranks `get_user_name` above `gunzip`.
     ///
-    /// Results are sorted by relevance: exact matches first, then prefix matches,
-    /// then alphabetically.
+    /// Results are sorted by score (alphabetically on ties) and capped to
+    /// [`DEFAULT_RESULT_LIMIT`]. An empty query returns the first symbols in
+    /// name order.
     #[must_use]
     pub fn search(
         &self,
         query: &str,
         source_map: &HashMap<String, String>,
     ) -> Vec<WorkspaceSymbol> {
-        let query_lower = query.to_lowercase();
-        let mut results = Vec::new();
+        self.search_with_limit(query, source_map, DEFAULT_RESULT_LIMIT)
+    }
+
+    /// Like [`search`](Self::search), but returns at most `limit` results.
+    #[must_use]
+    pub fn search_with_limit(
+        &self,
+        query: &str,
+        source_map: &HashMap<String, String>,
+        limit: usize,
+    ) -> Vec<WorkspaceSymbol> {
+        self.ranked(query, source_map, limit, |_| true)
+    }
+
+    /// Scores every indexed symbol accepted by `filter`, then sorts and truncates.
+    fn ranked(
+        &self,
+        query: &str,
+        source_map: &HashMap<String, String>,
+        limit: usize,
+        filter: impl Fn(&SymbolInfo) -> bool,
+    ) -> Vec<WorkspaceSymbol> {
+        let mut scored = Vec::new();
 
         for (uri, symbols) in &self.documents {
             // Get source for this document to convert offsets
@@ -295,74 +356,29 @@ impl WorkspaceSymbolsProvider {
             };
 
             for symbol in symbols {
-                if self.matches_query(&symbol.name, &query_lower) {
-                    results.push(self.symbol_to_workspace_symbol(uri, symbol, source));
+                if !filter(symbol) {
+                    continue;
+                }
+                if let Some(score) = fuzzy_score(&symbol.name, query) {
+                    scored.push((score, uri, symbol, source));
                 }
             }
         }
 
-        // Sort by relevance
-        results.sort_by(|a, b| {
-            let a_exact = a.name.to_lowercase() == query_lower;
-            let b_exact = b.name.to_lowercase() == query_lower;
-            let a_prefix = a.name.to_lowercase().starts_with(&query_lower);
-            let b_prefix = b.name.to_lowercase().starts_with(&query_lower);
-
-            match (a_exact, b_exact) {
-                (true, false) => std::cmp::Ordering::Less,
-                (false, true) => std::cmp::Ordering::Greater,
-                _ => match (a_prefix, b_prefix) {
-                    (true, false) => std::cmp::Ordering::Less,
-                    (false, true) => std::cmp::Ordering::Greater,
-                    _ => a.name.cmp(&b.name),
-                },
-            }
+        // Highest score first; ties are alphabetical, then in document order so
+        // results are stable across HashMap iteration.
+        scored.sort_by(|a, b| {
+            b.0.cmp(&a.0)
+                .then_with(|| a.2.name.cmp(&b.2.name))
+                .then_with(|| a.1.cmp(b.1))
+                .then_with(|| a.2.location.start.cmp(&b.2.location.start))
         });
+        scored.truncate(limit);
 
-        results
-    }
-
-    /// Checks if a symbol name matches the query using multiple strategies.
-    ///
-    /// Returns true if query is empty, or if name matches via exact, prefix,
-    /// contains, or fuzzy (subsequence) matching.
-    fn matches_query(&self, name: &str, query: &str) -> bool {
-        if query.is_empty() {
-            return true;
-        }
-
-        let name_lower = name.to_lowercase();
-
-        // Exact match
-        if name_lower == query {
-            return true;
-        }
-
-        // Prefix match
-        if name_lower.starts_with(query) {
-            return true;
-        }
-
-        // Contains match
-        if name_lower.contains(query) {
-            return true;
-        }
-
-        // Simple fuzzy match
-        let mut query_chars = query.chars();
-        let mut current_char = query_chars.next();
-
-        for ch in name_lower.chars() {
-            if let Some(qch) = current_char {
-                if ch == qch {
-                    current_char = query_chars.next();
-                }
-            } else {
-                return true;
-            }
-        }
-
-        current_char.is_none()
+        scored
+            .into_iter()
+            .map(|(_, uri, symbol, source)| self.symbol_to_workspace_symbol(uri, symbol, source))
+            .collect()
     }
 
     /// Converts an internal `SymbolInfo` to an LSP `WorkspaceSymbol`.
@@ -699,4 +715,58 @@ sub connect {
         let unique_containers: std::collections::HashSet<_> = containers.iter().collect();
         assert_eq!(unique_containers.len(), 3, "Each symbol should have a unique container");
     }
+
+    fn index_source(source: &str) -> (WorkspaceSymbolsProvider, HashMap<String, String>) {
+        let mut provider = WorkspaceSymbolsProvider::new();
+        let mut source_map = HashMap::new();
+        source_map.insert("file:///fuzzy.pl".to_string(), source.to_string());
+
+        let mut parser = Parser::new(source);
+        let ast = must(parser.parse());
+        provider.index_document("file:///fuzzy.pl", &ast, source);
+
+        (provider, source_map)
+    }
+
+    #[test]
+    fn test_fuzzy_word_boundaries_outrank_prefix() {
+        let (provider, source_map) = index_source(
+            "sub gunzip { }\nsub get_user_name { }\nsub getUserName { }\nsub unrelated { }\n",
+        );
+
+        let results = provider.search("gun", &source_map);
+        let names: Vec<&str> = results.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["getUserName", "get_user_name", "gunzip"]);
+
+        // Case in the query does not matter
+        let results = provider.search("gUN", &source_map);
+        assert_eq!(results.len(), 3);
+        assert_ne!(results[2].name, "get_user_name");
+    }
+
+    #[test]
+    fn test_fuzzy_score_ignores_sigils() {
+        assert!(fuzzy_score("$user_count", "user").is_some());
+        assert_eq!(fuzzy_score("$user_count", "user"), fuzzy_score("user_count", "user"));
+        assert_eq!(fuzzy_score("@items", "$items"), fuzzy_score("items", "items"));
+        assert!(fuzzy_score("user_count", "uz").is_none());
+
+        let (provider, source_map) = index_source("our $user_count = 0;\nsub reset { }\n");
+        let results = provider.search("user", &source_map);
+        assert!(results.iter().any(|r| r.name.contains("user_count")));
+        assert!(results.iter().all(|r| r.name != "reset"));
+    }
+
+    #[test]
+    fn test_empty_query_returns_first_n_symbols() {
+        let (provider, source_map) = index_source(
+            "sub delta { }\nsub alpha { }\nsub echo { }\nsub charlie { }\nsub bravo { }\n",
+        );
+
+        let results = provider.search_with_limit("", &source_map, 3);
+        let names: Vec<&str> = results.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["alpha", "bravo", "charlie"]);
+
+        assert_eq!(provider.search("", &source_map).len(), 5);
+    }
 }
@@ -431,7 +431,7 @@ pub fn capabilities_for(build: BuildFlags) -> ServerCapabilities {
                     ],
                 },
                 range: Some(true),
-                full: Some(SemanticTokensFullOptions::Bool(true)),
+                full: Some(SemanticTokensFullOptions::Delta { delta: Some(true) }),
             }));
     }
 
@@ -486,7 +486,7 @@ pub fn capabilities_for(build: BuildFlags) -> ServerCapabilities {
     if build.on_type_formatting {
         caps.document_on_type_formatting_provider = Some(DocumentOnTypeFormattingOptions {
             first_trigger_character: "}".to_string(),
-            more_trigger_character: Some(vec![";".to_string()]),
+            more_trigger_character: Some(vec![";".to_string(), "\n".to_string()]),
         });
     }
 
@@ -562,6 +562,8 @@ pub fn get_supported_commands() -> Vec<String> {
         "perl.runTest".to_string(),
         "perl.runTestFile".to_string(),
         "perl.debugFile".to_string(),
+        "perl.sortUseStatements".to_string(),
+        "perl.extractSubroutine".to_string(),
     ]
 }
 
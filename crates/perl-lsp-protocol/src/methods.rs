@@ -220,6 +220,9 @@ pub const TEXT_DOCUMENT_LINKED_EDITING_RANGE: &str = "textDocument/linkedEditing
 /// Semantic tokens full document request
 pub const TEXT_DOCUMENT_SEMANTIC_TOKENS_FULL: &str = "textDocument/semanticTokens/full";
 
+/// Semantic tokens full document delta request
+pub const TEXT_DOCUMENT_SEMANTIC_TOKENS_FULL_DELTA: &str = "textDocument/semanticTokens/full/delta";
+
 /// Semantic tokens range request
 pub const TEXT_DOCUMENT_SEMANTIC_TOKENS_RANGE: &str = "textDocument/semanticTokens/range";
 
@@ -75,7 +75,7 @@ impl FoldingRangeExtractor {
     /// Scans the source for heredoc bodies and returns their ranges.
     pub fn extract_heredoc_ranges(text: &str) -> Vec<FoldingRange> {
         let mut ranges = Vec::new();
-        let mut lexer = PerlLexer::new(text);
+        let mut lexer = PerlLexer::with_body_tokens(text);
 
         while let Some(token) = lexer.next_token() {
             if matches!(token.token_type, TokenType::HeredocBody(_)) {
@@ -95,6 +95,65 @@ impl FoldingRangeExtractor {
         ranges
     }
 
+    /// Extract folding ranges for regions the AST does not cover, using the lexer.
+    ///
+    /// Returns a `Comment` range for each POD block (`=pod`..`=cut`), a
+    /// `Region` range for each heredoc body, and a `Region` range from a
+    /// `__DATA__`/`__END__` marker to the end of the text. Ranges are byte
+    /// offsets; POD and heredoc ranges end just past their last line.
+    ///
+    /// Explicit regions are folded as `Region` too: `#region`/`#endregion`
+    /// comments in code, and `=begin fold`/`=end fold` inside POD. Markers
+    /// pair like brackets, and unmatched ones are ignored.
+    pub fn extract_lexical_ranges(text: &str) -> Vec<FoldingRange> {
+        let mut ranges = Vec::new();
+        let mut lexer = PerlLexer::with_body_tokens(text);
+
+        while let Some(token) = lexer.next_token() {
+            let kind = match token.token_type {
+                TokenType::Pod => FoldingRangeKind::Comment,
+                TokenType::HeredocBody(_) => FoldingRangeKind::Region,
+                TokenType::DataMarker(_) => {
+                    ranges.push(FoldingRange {
+                        start_offset: token.start,
+                        end_offset: text.len(),
+                        kind: Some(FoldingRangeKind::Region),
+                    });
+                    break;
+                }
+                TokenType::EOF => break,
+                _ => continue,
+            };
+            ranges.push(FoldingRange {
+                start_offset: token.start,
+                end_offset: token.end,
+                kind: Some(kind),
+            });
+        }
+
+        let mut regions = Vec::new();
+        let mut code_markers = Vec::new();
+        let mut code_start = 0;
+        for range in &ranges {
+            // Marker comments only count in code; POD gets its own `=begin fold` markers
+            add_marker_lines(text, code_start, range.start_offset, &mut code_markers, &mut regions);
+            if matches!(range.kind, Some(FoldingRangeKind::Comment)) {
+                add_marker_lines(
+                    text,
+                    range.start_offset,
+                    range.end_offset,
+                    &mut Vec::new(),
+                    &mut regions,
+                );
+            }
+            code_start = code_start.max(range.end_offset);
+        }
+        add_marker_lines(text, code_start, text.len(), &mut code_markers, &mut regions);
+
+        ranges.extend(regions);
+        ranges
+    }
+
     /// Visit a node and extract folding ranges
     fn visit_node(&mut self, node: &Node) {
         match &node.kind {
@@ -148,7 +207,7 @@ impl FoldingRangeExtractor {
                 }
             }
 
-            NodeKind::Package { name: _, block, name_span: _ } => {
+            NodeKind::Package { name: _, block, .. } => {
                 // Package with block is foldable
                 if let Some(block_node) = block {
                     self.add_range_from_node(node, None);
@@ -160,7 +219,9 @@ impl FoldingRangeExtractor {
                 }
             }
 
-            NodeKind::Subroutine { name: _, prototype: _, signature: _, body, .. }
+            NodeKind::Subroutine {
+                name: _, prototype: _, signature: _, body: Some(body), ..
+            }
             | NodeKind::Method { name: _, signature: _, body, .. } => {
                 // Subroutines and methods are foldable
                 self.add_range_from_node(node, None);
@@ -208,6 +269,12 @@ impl FoldingRangeExtractor {
                 self.visit_node(block);
             }
 
+            NodeKind::DoWhile { block, condition } | NodeKind::DoUntil { block, condition } => {
+                self.add_range_from_node(node, None);
+                self.visit_node(block);
+                self.visit_node(condition);
+            }
+
             NodeKind::Try { body, catch_blocks, finally_block } => {
                 self.add_range_from_node(node, None);
                 self.visit_node(body);
@@ -230,12 +297,12 @@ impl FoldingRangeExtractor {
                 self.visit_node(block);
             }
 
-            NodeKind::Class { name: _, body } => {
+            NodeKind::Class { body, .. } => {
                 self.add_range_from_node(node, None);
                 self.visit_node(body);
             }
 
-            // POD is typically inside strings or special constructs, not a separate NodeKind
+            // POD is not a NodeKind; extract_lexical_ranges folds it from the lexer
             NodeKind::Heredoc { .. } => {
                 // Heredocs are always foldable
                 self.add_range_from_node(node, None);
@@ -276,13 +343,7 @@ impl FoldingRangeExtractor {
                 }
             }
 
-            NodeKind::DataSection { marker: _, body } => {
-                // Fold the data section body as a comment
-                if body.is_some() {
-                    self.add_range_from_node(node, Some(FoldingRangeKind::Comment));
-                }
-            }
-
+            // Data sections, like POD and heredoc bodies, are folded by extract_lexical_ranges
             // Other node types - visit children if any
             _ => {}
         }
@@ -315,3 +376,67 @@ impl FoldingRangeExtractor {
         }
     }
 }
+
+/// A region marker line
+enum RegionMarker {
+    Start,
+    End,
+}
+
+/// Classify a line as a region start or end marker
+///
+/// Recognizes `#region`/`#endregion` comments (with optional space after `#`
+/// and an optional region name) and `=begin fold`/`=end fold` POD commands.
+fn region_marker(line: &str) -> Option<RegionMarker> {
+    if let Some(format) = line.strip_prefix("=begin") {
+        return (format.trim() == "fold").then_some(RegionMarker::Start);
+    }
+    if let Some(format) = line.strip_prefix("=end") {
+        return (format.trim() == "fold").then_some(RegionMarker::End);
+    }
+
+    let comment = line.trim_start().strip_prefix('#')?.trim_start();
+    let is_word = |rest: &str| rest.is_empty() || rest.starts_with(char::is_whitespace);
+    if let Some(rest) = comment.strip_prefix("endregion") {
+        is_word(rest).then_some(RegionMarker::End)
+    } else if let Some(rest) = comment.strip_prefix("region") {
+        is_word(rest).then_some(RegionMarker::Start)
+    } else {
+        None
+    }
+}
+
+/// Pair the region markers on the lines of `text[start..end]`
+///
+/// `open` holds the offsets of unclosed start markers and carries over
+/// between calls; each end marker closes the innermost one, so nested
+/// regions pair correctly and an end marker with nothing open is skipped.
+fn add_marker_lines(
+    text: &str,
+    start: usize,
+    end: usize,
+    open: &mut Vec<usize>,
+    regions: &mut Vec<FoldingRange>,
+) {
+    let Some(section) = text.get(start..end) else {
+        return;
+    };
+    let mut line_start = start;
+    for line in section.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        match region_marker(content) {
+            Some(RegionMarker::Start) => open.push(line_start),
+            Some(RegionMarker::End) => {
+                if let Some(region_start) = open.pop() {
+                    regions.push(FoldingRange {
+                        start_offset: region_start,
+                        end_offset: line_start + content.len(),
+                        kind: Some(FoldingRangeKind::Region),
+                    });
+                }
+            }
+            None => {}
+        }
+        line_start += line.len();
+    }
+}
@@ -2,6 +2,10 @@
 //!
 //! Provides support for simultaneous editing of matching brackets, quotes,
 //! and other paired delimiters.
+//!
+//! Heredocs are linked as well: the tag in `<<EOF`, `<<"EOF"`, `<<'EOF'` or
+//! `<<~EOF` and the terminator line that closes the body are edited together,
+//! so renaming one side keeps the heredoc well-formed.
 
 use lsp_types::{LinkedEditingRanges, Position, Range};
 use perl_parser_core::position::{offset_to_utf16_line_col, utf16_line_col_to_offset};
@@ -9,6 +13,87 @@ use perl_parser_core::position::{offset_to_utf16_line_col, utf16_line_col_to_off
 const OPEN: &[char] = &['(', '[', '{', '<', '\'', '"'];
 const CLOSE: &[char] = &[')', ']', '}', '>', '\'', '"'];
 
+/// Characters a heredoc tag may be edited into
+const HEREDOC_TAG_PATTERN: &str = "[A-Za-z_][A-Za-z0-9_]*";
+
+/// A heredoc start tag on its line, as byte offsets into the document
+struct HeredocOpener {
+    start: usize,
+    end: usize,
+    indented: bool,
+}
+
+/// Heredoc start tags on one line, in the order their bodies follow
+fn heredoc_openers(line: &str, line_start: usize) -> Vec<HeredocOpener> {
+    let bytes = line.as_bytes();
+    let mut openers = Vec::new();
+    let mut i = 0;
+
+    while let Some(found) = line.get(i..).and_then(|rest| rest.find("<<")) {
+        let mut pos = i + found + 2;
+        i = pos;
+        // `<<=` is an assignment and `<<<` is not a heredoc
+        if matches!(bytes.get(pos), Some(b'=') | Some(b'<')) {
+            continue;
+        }
+        let indented = bytes.get(pos) == Some(&b'~');
+        if indented {
+            pos += 1;
+        }
+
+        let quoted = line[pos..].trim_start();
+        let (start, quote) = match quoted.as_bytes().first() {
+            Some(&q @ (b'"' | b'\'')) => (line.len() - quoted.len() + 1, Some(q)),
+            _ => (pos, None),
+        };
+        let len =
+            line[start..].bytes().take_while(|b| b.is_ascii_alphanumeric() || *b == b'_').count();
+        // Bare tags must look like identifiers, which also rules out `1 << 2`
+        if len == 0 || line.as_bytes()[start].is_ascii_digit() {
+            continue;
+        }
+        if let Some(q) = quote {
+            if bytes.get(start + len) != Some(&q) {
+                continue;
+            }
+        }
+
+        i = start + len;
+        openers.push(HeredocOpener {
+            start: line_start + start,
+            end: line_start + start + len,
+            indented,
+        });
+    }
+
+    openers
+}
+
+/// Pairs of (start tag, terminator) byte ranges for every closed heredoc
+fn heredoc_links(text: &str) -> Vec<((usize, usize), (usize, usize))> {
+    let mut links = Vec::new();
+    let mut pending: std::collections::VecDeque<HeredocOpener> = Default::default();
+    let mut line_start = 0;
+
+    for raw in text.split_inclusive('\n') {
+        let line = raw.trim_end_matches(['\n', '\r']);
+        if let Some(opener) = pending.front() {
+            let tag = &text[opener.start..opener.end];
+            let indent = if opener.indented { line.len() - line.trim_start().len() } else { 0 };
+            if line[indent..] == *tag {
+                let close = line_start + indent;
+                links.push(((opener.start, opener.end), (close, close + tag.len())));
+                pending.pop_front();
+            }
+        } else {
+            pending.extend(heredoc_openers(line, line_start));
+        }
+        line_start += raw.len();
+    }
+
+    links
+}
+
 fn char_at(text: &str, byte: usize) -> Option<char> {
     text.get(byte..)?.chars().next()
 }
@@ -103,9 +188,11 @@ fn find_pair(text: &str, start_byte: usize) -> Option<(usize, usize)> {
 
 /// Handles the `textDocument/linkedEditingRange` request.
 ///
-/// This function finds a matching bracket or quote for the character at the given
-/// position and returns a `LinkedEditingRanges` object containing the ranges of
-/// the two matching characters.
+/// When the position is on a heredoc tag, this returns the tag after `<<` and
+/// the terminator line as linked ranges. Otherwise it finds a matching bracket
+/// or quote for the character at the given position and returns a
+/// `LinkedEditingRanges` object containing the ranges of the two matching
+/// characters.
 ///
 /// # Arguments
 ///
@@ -118,6 +205,22 @@ fn find_pair(text: &str, start_byte: usize) -> Option<(usize, usize)> {
 /// An `Option<LinkedEditingRanges>` object.
 pub fn handle_linked_editing(text: &str, line: u32, character: u32) -> Option<LinkedEditingRanges> {
     let byte = utf16_line_col_to_offset(text, line, character);
+
+    let to_range = |(start, end): (usize, usize)| {
+        let (start_line, start_char) = offset_to_utf16_line_col(text, start);
+        let (end_line, end_char) = offset_to_utf16_line_col(text, end);
+        Range::new(Position::new(start_line, start_char), Position::new(end_line, end_char))
+    };
+    let on_tag = |(start, end): (usize, usize)| start <= byte && byte <= end;
+    if let Some((open, close)) =
+        heredoc_links(text).into_iter().find(|&(open, close)| on_tag(open) || on_tag(close))
+    {
+        return Some(LinkedEditingRanges {
+            ranges: vec![to_range(open), to_range(close)],
+            word_pattern: Some(HEREDOC_TAG_PATTERN.to_string()),
+        });
+    }
+
     let (a, b) = find_pair(text, byte)?;
     let (a_line, a_char) = offset_to_utf16_line_col(text, a);
     let (b_line, b_char) = offset_to_utf16_line_col(text, b);
@@ -128,3 +231,66 @@ pub fn handle_linked_editing(text: &str, line: u32, character: u32) -> Option<Li
     ];
     Some(LinkedEditingRanges { ranges, word_pattern: None })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use perl_tdd_support::must_some;
+
+    /// Source text covered by each linked range (all tests use single-line ranges)
+    fn linked_texts(text: &str, line: u32, character: u32) -> Vec<String> {
+        let ranges = must_some(handle_linked_editing(text, line, character)).ranges;
+        ranges
+            .iter()
+            .map(|r| {
+                let start = utf16_line_col_to_offset(text, r.start.line, r.start.character);
+                let end = utf16_line_col_to_offset(text, r.end.line, r.end.character);
+                text.get(start..end).unwrap_or_default().to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_heredoc_tag_links_to_terminator() {
+        let text = "my $s = <<EOF;\nhello\nEOF\nprint $s;\n";
+
+        let result = must_some(handle_linked_editing(text, 0, 11));
+        assert_eq!(result.ranges[0], Range::new(Position::new(0, 10), Position::new(0, 13)));
+        assert_eq!(result.ranges[1], Range::new(Position::new(2, 0), Position::new(2, 3)));
+        assert_eq!(result.word_pattern.as_deref(), Some(HEREDOC_TAG_PATTERN));
+
+        // The same pair is offered from the terminator line
+        assert_eq!(linked_texts(text, 2, 1), ["EOF", "EOF"]);
+    }
+
+    #[test]
+    fn test_quoted_and_indented_heredoc_tags() {
+        let text = "sub f {\n    print << \"END\";\n    body\nEND\n    my $t = <<~'TXT';\n      x\n      TXT\n}\n";
+
+        let result = must_some(handle_linked_editing(text, 1, 15));
+        assert_eq!(result.ranges[0], Range::new(Position::new(1, 14), Position::new(1, 17)));
+        assert_eq!(result.ranges[1], Range::new(Position::new(3, 0), Position::new(3, 3)));
+
+        let result = must_some(handle_linked_editing(text, 6, 7));
+        assert_eq!(result.ranges[0], Range::new(Position::new(4, 16), Position::new(4, 19)));
+        assert_eq!(result.ranges[1], Range::new(Position::new(6, 6), Position::new(6, 9)));
+    }
+
+    #[test]
+    fn test_stacked_heredocs_link_in_order() {
+        let text = "print <<A, <<B;\nfirst\nA\nsecond\nB\n";
+
+        assert_eq!(linked_texts(text, 0, 8), ["A", "A"]);
+        let result = must_some(handle_linked_editing(text, 0, 13));
+        assert_eq!(result.ranges[1], Range::new(Position::new(4, 0), Position::new(4, 1)));
+    }
+
+    #[test]
+    fn test_shift_operator_falls_back_to_brackets() {
+        let text = "my $x = (1 << 2);\n";
+
+        let result = must_some(handle_linked_editing(text, 0, 8));
+        assert_eq!(result.ranges[1], Range::new(Position::new(0, 15), Position::new(0, 16)));
+        assert!(result.word_pattern.is_none());
+    }
+}
@@ -1,127 +1,150 @@
 //! On-type formatting provider for Perl LSP.
 //!
 //! Provides automatic indentation and formatting when typing trigger characters.
+//!
+//! The typed character is already part of `text` when a request arrives:
+//!
+//! - `\n` indents the new line, one level deeper after a line ending in `{`;
+//!   if that brace is not yet closed, a matching `}` is added on its own line
+//! - `}` dedents a closing brace to the column of the line that opened it
+//! - `;` re-indents the finished statement to its block's depth
+//!
+//! One indentation level follows the client's `FormattingOptions`: `tabSize`
+//! spaces, or a tab when `insertSpaces` is false.
 
+use perl_lsp_formatting::FormattingOptions;
 use serde_json::Value;
 use serde_json::json;
 
 /// Computes on-type formatting edits for a Perl document based on character input.
 ///
-/// Handles special characters (`{`, `}`, `;`, newlines) to provide automatic indentation
-/// and formatting adjustments. Returns a vector of text edits to apply, or `None` if no
-/// edits are needed for the given character.
+/// Handles the trigger characters (`}`, `;`, newlines) to provide automatic
+/// indentation and block closing. Returns a vector of text edits to apply, or
+/// `None` if no edits are needed for the given character.
 ///
 /// # Arguments
 ///
-/// * `text` - The full document text
+/// * `text` - The full document text, including the typed character
 /// * `line` - The line number where the character was typed
-/// * `col` - The column position where the character was typed
+/// * `col` - The column position just after the typed character
 /// * `ch` - The character that triggered the formatting request
-pub fn compute_on_type_edit(text: &str, line: u32, col: u32, ch: char) -> Option<Vec<Value>> {
+/// * `options` - Client formatting options supplying the indentation unit
+pub fn compute_on_type_edit(
+    text: &str,
+    line: u32,
+    col: u32,
+    ch: char,
+    options: &FormattingOptions,
+) -> Option<Vec<Value>> {
     let lines: Vec<&str> = text.lines().collect();
-
-    if line as usize >= lines.len() {
-        return None;
-    }
+    let line_idx = line as usize;
+    // A trailing newline leaves the cursor on a line `lines()` does not report
+    let current_line = match lines.get(line_idx) {
+        Some(current) => *current,
+        None if line_idx == lines.len() && text.ends_with('\n') => "",
+        None => return None,
+    };
+    let unit = indent_unit(options);
 
     match ch {
-        '{' => {
-            // After typing '{', add proper indentation for next line
-            // Determine current line indentation
-            let current_indent = get_indentation(lines[line as usize]);
-            let new_indent = current_indent + 2; // Add 2 spaces
-
-            // Return newline with increased indent
-            Some(vec![json!({
-                "range": {
-                    "start": {"line": line, "character": col},
-                    "end": {"line": line, "character": col}
-                },
-                "newText": format!("\n{}", " ".repeat(new_indent))
-            })])
-        }
         '}' => {
-            // After typing '}', adjust indentation of current line
-            if line > 0 {
-                let current_line = lines[line as usize];
-                let current_indent = get_indentation(current_line);
-
-                // Find matching opening brace to determine proper indent
-                let target_indent = find_matching_brace_indent(&lines, line as usize)
-                    .unwrap_or_else(|| current_indent.saturating_sub(2));
-
-                if current_indent != target_indent {
-                    // Replace leading whitespace
-                    let _trimmed = current_line.trim_start();
-                    Some(vec![json!({
-                        "range": {
-                            "start": {"line": line, "character": 0},
-                            "end": {"line": line, "character": current_indent as u32}
-                        },
-                        "newText": " ".repeat(target_indent)
-                    })])
-                } else {
-                    None
-                }
-            } else {
-                None
+            // Only a brace that starts its line is re-indented
+            let brace_col = current_line.find('}')?;
+            if !current_line[..brace_col].trim().is_empty() {
+                return None;
             }
+
+            let opening = find_open_brace(&lines, line_idx, brace_col)?;
+            let target = leading_whitespace(lines[opening]);
+            replace_indent(line, current_line, target)
         }
         ';' => {
-            // After semicolon at end of line, maintain indent on next line
-            let current_indent = get_indentation(lines[line as usize]);
-            Some(vec![json!({
-                "range": {
-                    "start": {"line": line, "character": col},
-                    "end": {"line": line, "character": col}
-                },
-                "newText": format!("\n{}", " ".repeat(current_indent))
-            })])
+            let target = match find_open_brace(&lines, line_idx, col as usize) {
+                Some(opening) => format!("{}{}", leading_whitespace(lines[opening]), unit),
+                None => String::new(),
+            };
+            replace_indent(line, current_line, &target)
         }
         '\n' | '\r' => {
-            // After newline, maintain current indentation
-            if line > 0 {
-                let prev_line = lines[(line - 1) as usize];
-                let prev_indent = get_indentation(prev_line);
+            let prev_line = lines.get(line_idx.checked_sub(1)?)?;
+            let prev_indent = leading_whitespace(prev_line);
+            if !prev_line.trim_end().ends_with('{') {
+                return replace_indent(line, current_line, prev_indent);
+            }
 
-                // If previous line ends with '{', increase indent
-                let trimmed = prev_line.trim_end();
-                let indent = if trimmed.ends_with('{') { prev_indent + 2 } else { prev_indent };
+            let inner = format!("{}{}", prev_indent, unit);
+            let mut edits = replace_indent(line, current_line, &inner).unwrap_or_default();
 
-                Some(vec![json!({
+            // Close the block unless a `}` already follows or matches it later
+            let rest = current_line.trim_start();
+            if !rest.starts_with('}') && !is_brace_closed(&lines, line_idx - 1) {
+                let end = utf16_len(current_line);
+                edits.push(json!({
+                    "range": {
+                        "start": {"line": line, "character": end},
+                        "end": {"line": line, "character": end}
+                    },
+                    "newText": format!("\n{}}}", prev_indent)
+                }));
+            } else if rest.starts_with('}') {
+                // Cursor between `{` and an auto-closed `}`: move the brace down
+                let indent_len = utf16_len(leading_whitespace(current_line));
+                edits = vec![json!({
                     "range": {
                         "start": {"line": line, "character": 0},
-                        "end": {"line": line, "character": 0}
+                        "end": {"line": line, "character": indent_len}
                     },
-                    "newText": " ".repeat(indent)
-                })])
-            } else {
-                None
+                    "newText": format!("{}\n{}", inner, prev_indent)
+                })];
             }
+
+            if edits.is_empty() { None } else { Some(edits) }
         }
         _ => None,
     }
 }
 
-fn get_indentation(line: &str) -> usize {
-    line.len() - line.trim_start().len()
+/// One level of indentation according to the client's options
+fn indent_unit(options: &FormattingOptions) -> String {
+    if options.insert_spaces { " ".repeat(options.tab_size as usize) } else { "\t".to_string() }
 }
 
-fn find_matching_brace_indent(lines: &[&str], closing_line: usize) -> Option<usize> {
-    let mut brace_count = 1;
+fn leading_whitespace(line: &str) -> &str {
+    &line[..line.len() - line.trim_start().len()]
+}
 
-    // Search backwards for matching opening brace
-    for i in (0..closing_line).rev() {
-        let line = lines[i];
-        for ch in line.chars().rev() {
+fn utf16_len(text: &str) -> u32 {
+    text.encode_utf16().count() as u32
+}
+
+/// Edit replacing a line's leading whitespace with `target`, unless it already matches
+fn replace_indent(line: u32, current_line: &str, target: &str) -> Option<Vec<Value>> {
+    let current = leading_whitespace(current_line);
+    if current == target {
+        return None;
+    }
+
+    Some(vec![json!({
+        "range": {
+            "start": {"line": line, "character": 0},
+            "end": {"line": line, "character": utf16_len(current)}
+        },
+        "newText": target
+    })])
+}
+
+/// Line of the innermost `{` still open before column `col` of line `line`
+fn find_open_brace(lines: &[&str], line: usize, col: usize) -> Option<usize> {
+    let mut depth = 0usize;
+
+    // Search backwards for the unmatched opening brace
+    for i in (0..=line).rev() {
+        let text = if i == line { lines[i].get(..col).unwrap_or(lines[i]) } else { lines[i] };
+        for ch in text.chars().rev() {
             match ch {
-                '}' => brace_count += 1,
-                '{' => {
-                    brace_count -= 1;
-                    if brace_count == 0 {
-                        return Some(get_indentation(line));
-                    }
-                }
+                '}' => depth += 1,
+                '{' if depth == 0 => return Some(i),
+                '{' => depth -= 1,
                 _ => {}
             }
         }
@@ -129,3 +152,106 @@ fn find_matching_brace_indent(lines: &[&str], closing_line: usize) -> Option<usi
 
     None
 }
+
+/// Whether the last `{` on line `line` is matched by a later `}`
+fn is_brace_closed(lines: &[&str], line: usize) -> bool {
+    let mut depth = 0usize;
+    let after_brace = lines[line].rfind('{').map_or("", |i| &lines[line][i + 1..]);
+
+    for text in std::iter::once(after_brace).chain(lines[line + 1..].iter().copied()) {
+        for ch in text.chars() {
+            match ch {
+                '{' => depth += 1,
+                '}' if depth == 0 => return true,
+                '}' => depth -= 1,
+                _ => {}
+            }
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use perl_tdd_support::must_some;
+
+    fn options(tab_size: u32, insert_spaces: bool) -> FormattingOptions {
+        FormattingOptions {
+            tab_size,
+            insert_spaces,
+            trim_trailing_whitespace: None,
+            insert_final_newline: None,
+            trim_final_newlines: None,
+        }
+    }
+
+    /// Apply single-line edits (in reverse order) to check the resulting text
+    fn apply(text: &str, edits: &[Value]) -> String {
+        let mut lines: Vec<String> = text.split('\n').map(str::to_string).collect();
+        for edit in edits.iter().rev() {
+            let line = edit["range"]["start"]["line"].as_u64().unwrap_or(0) as usize;
+            let start = edit["range"]["start"]["character"].as_u64().unwrap_or(0) as usize;
+            let end = edit["range"]["end"]["character"].as_u64().unwrap_or(0) as usize;
+            let new_text = edit["newText"].as_str().unwrap_or_default();
+            lines[line].replace_range(start..end, new_text);
+        }
+        lines.join("\n")
+    }
+
+    #[test]
+    fn test_enter_after_open_brace_adds_indented_body_and_closing_brace() {
+        let text = "sub foo {\n";
+        let edits = must_some(compute_on_type_edit(text, 1, 0, '\n', &options(4, true)));
+        assert_eq!(apply(text, &edits), "sub foo {\n    \n}");
+
+        // Tabs when the client does not insert spaces
+        let edits = must_some(compute_on_type_edit(text, 1, 0, '\n', &options(4, false)));
+        assert_eq!(apply(text, &edits), "sub foo {\n\t\n}");
+    }
+
+    #[test]
+    fn test_enter_between_auto_closed_braces_moves_closing_brace_down() {
+        let text = "sub foo {\n}\n";
+        let edits = must_some(compute_on_type_edit(text, 1, 0, '\n', &options(2, true)));
+        assert_eq!(apply(text, &edits), "sub foo {\n  \n}\n");
+    }
+
+    #[test]
+    fn test_enter_in_nested_block_keeps_existing_closing_brace() {
+        let text = "sub foo {\n    if ($x) {\n\n    }\n}\n";
+        let edits = must_some(compute_on_type_edit(text, 2, 0, '\n', &options(4, true)));
+        assert_eq!(apply(text, &edits), "sub foo {\n    if ($x) {\n        \n    }\n}\n");
+    }
+
+    #[test]
+    fn test_enter_after_statement_keeps_indent() {
+        let text = "sub foo {\n    my $x = 1;\n\n}\n";
+        let edits = must_some(compute_on_type_edit(text, 2, 0, '\n', &options(4, true)));
+        assert_eq!(apply(text, &edits), "sub foo {\n    my $x = 1;\n    \n}\n");
+    }
+
+    #[test]
+    fn test_closing_brace_dedents_to_opening_line() {
+        let text = "sub foo {\n    if ($x) {\n        bar();\n        }\n";
+        let edits = must_some(compute_on_type_edit(text, 3, 9, '}', &options(4, true)));
+        assert_eq!(apply(text, &edits), "sub foo {\n    if ($x) {\n        bar();\n    }\n");
+    }
+
+    #[test]
+    fn test_closing_brace_already_aligned_is_noop() {
+        let text = "sub foo {\n    bar();\n}\n";
+        assert!(compute_on_type_edit(text, 2, 1, '}', &options(4, true)).is_none());
+    }
+
+    #[test]
+    fn test_semicolon_reindents_statement_to_block_depth() {
+        let text = "sub foo {\n    if ($x) {\n  bar();\n    }\n}\n";
+        let edits = must_some(compute_on_type_edit(text, 2, 8, ';', &options(4, true)));
+        assert_eq!(apply(text, &edits), "sub foo {\n    if ($x) {\n        bar();\n    }\n}\n");
+
+        let aligned = "sub foo {\n    bar();\n}\n";
+        assert!(compute_on_type_edit(aligned, 1, 10, ';', &options(4, true)).is_none());
+    }
+}
@@ -2,50 +2,76 @@
 //!
 //! Provides expand/shrink selection functionality by building nested selection
 //! ranges through parent AST traversal.
+//!
+//! Besides ordinary expressions, statements and blocks, the chain understands a
+//! few Perl-specific shapes: a word inside `qw(...)` expands to the whole list,
+//! a line inside a heredoc body expands to the body and then to the heredoc
+//! including its `<<TAG` marker, and ternary branches expand to the ternary.
 
-use perl_parser_core::ast::Node;
+use perl_parser_core::ast::{Node, NodeKind};
 use rustc_hash::FxHashMap;
 use serde_json::{Value, json};
 
 /// Build nested selection range objects by climbing parent map.
+///
+/// `source` is the document text the AST was parsed from; it is used to find
+/// individual `qw()` words, which the parser does not locate separately.
 pub fn selection_chain(
     ast: &Node,
     parent_map: &FxHashMap<*const Node, *const Node>,
+    source: &str,
     offset: usize,
     to_pos16: &impl Fn(usize) -> (u32, u32),
 ) -> Value {
-    // Find leaf node at offset
-    let leaf = perl_semantic_analyzer::declaration::find_node_at_offset(ast, offset).unwrap_or(ast);
-    let mut node_lookup = FxHashMap::default();
-    build_node_lookup(ast, &mut node_lookup);
+    let mut extents = FxHashMap::default();
+    build_extents(ast, source, &mut extents);
+
+    let leaf = find_leaf(ast, &extents, offset);
+
+    // Innermost first; sub-node ranges the AST does not carry come before the leaf
+    let mut spans = Vec::new();
+    if let Some(word) = qw_word_at(leaf, source, offset) {
+        spans.push(word);
+    }
+    if let NodeKind::Heredoc { body_span: Some(body), .. } = &leaf.kind {
+        if body.start <= offset && offset <= body.end {
+            spans.push((body.start, body.end));
+        }
+    }
 
     let mut current_ptr = leaf as *const Node;
-    let mut acc = None;
+    while let Some(&extent) = extents.get(&current_ptr) {
+        spans.push(extent);
+        match parent_map.get(&current_ptr) {
+            Some(&parent_ptr) => current_ptr = parent_ptr,
+            None => break,
+        }
+    }
 
-    loop {
-        let Some(node) = node_lookup.get(&current_ptr).copied() else {
-            break;
+    // Each range must contain the previous one; drop ranges that do not grow
+    let mut nested: Vec<(usize, usize)> = Vec::new();
+    for (start, end) in spans {
+        let (start, end) = match nested.last() {
+            Some(&(inner_start, inner_end)) => (start.min(inner_start), end.max(inner_end)),
+            None => (start, end),
         };
+        if nested.last() != Some(&(start, end)) {
+            nested.push((start, end));
+        }
+    }
 
-        let (sl, sc) = to_pos16(node.location.start);
-        let (el, ec) = to_pos16(node.location.end);
+    let mut acc = None;
+    for (start, end) in nested.into_iter().rev() {
+        let (sl, sc) = to_pos16(start);
+        let (el, ec) = to_pos16(end);
 
-        let here = json!({
+        acc = Some(json!({
             "range": {
                 "start": {"line": sl, "character": sc},
                 "end": {"line": el, "character": ec}
             },
             "parent": acc
-        });
-
-        acc = Some(here);
-
-        // Move to parent
-        if let Some(&parent_ptr) = parent_map.get(&current_ptr) {
-            current_ptr = parent_ptr;
-        } else {
-            break;
-        }
+        }));
     }
 
     acc.unwrap_or_else(|| {
@@ -58,11 +84,89 @@ pub fn selection_chain(
     })
 }
 
-fn build_node_lookup<'a>(node: &'a Node, map: &mut FxHashMap<*const Node, &'a Node>) {
-    map.insert(node as *const Node, node);
-    for child in perl_semantic_analyzer::declaration::get_node_children(node) {
-        build_node_lookup(child, map);
+/// Children considered when expanding a selection
+fn selection_children(node: &Node) -> Vec<&Node> {
+    node.children()
+}
+
+/// Byte span a node covers on its own, including a heredoc's body and terminator
+fn own_span(node: &Node, source: &str) -> (usize, usize) {
+    let (start, end) = (node.location.start, node.location.end);
+    match &node.kind {
+        NodeKind::Heredoc { body_span: Some(body), delimiter, .. } => {
+            // The body span stops before the terminator line
+            let rest = source.get(body.end..).unwrap_or_default();
+            let newline =
+                if rest.starts_with("\r\n") { 2 } else { usize::from(rest.starts_with('\n')) };
+            let line_start = body.end + newline;
+            let line = source.get(line_start..).and_then(|s| s.lines().next()).unwrap_or_default();
+            let terminator =
+                (newline > 0 && line.trim() == delimiter).then_some(line_start + line.len());
+            (start, terminator.unwrap_or(body.end).max(end))
+        }
+        _ => (start, end),
+    }
+}
+
+/// Record each node's span widened to cover all of its descendants
+///
+/// Some nodes, such as `my $x = <<EOT` declarations, end before their children
+/// do, so the widened span is what keeps the chain properly nested.
+fn build_extents(
+    node: &Node,
+    source: &str,
+    extents: &mut FxHashMap<*const Node, (usize, usize)>,
+) -> (usize, usize) {
+    let (mut start, mut end) = own_span(node, source);
+    for child in selection_children(node) {
+        let (child_start, child_end) = build_extents(child, source, extents);
+        start = start.min(child_start);
+        end = end.max(child_end);
     }
+    extents.insert(node as *const Node, (start, end));
+    (start, end)
+}
+
+/// Deepest node whose extent contains `offset`
+fn find_leaf<'a>(
+    node: &'a Node,
+    extents: &FxHashMap<*const Node, (usize, usize)>,
+    offset: usize,
+) -> &'a Node {
+    let contains = |child: &&Node| {
+        extents
+            .get(&(*child as *const Node))
+            .is_some_and(|&(start, end)| start <= offset && offset <= end)
+    };
+    match selection_children(node).into_iter().find(contains) {
+        Some(child) => find_leaf(child, extents, offset),
+        None => node,
+    }
+}
+
+/// The `qw()` word under the cursor, when `leaf` is a word of a `qw` list
+///
+/// Every word of a `qw` list shares the location of the whole list, so the
+/// word itself is found in the source text.
+fn qw_word_at(leaf: &Node, source: &str, offset: usize) -> Option<(usize, usize)> {
+    if !matches!(leaf.kind, NodeKind::String { .. }) {
+        return None;
+    }
+    let text = source.get(leaf.location.start..leaf.location.end)?;
+    let body = text.strip_prefix("qw")?.trim_start();
+    let open = leaf.location.end - body.len() + 1;
+    let close = leaf.location.end.checked_sub(1)?;
+    if offset < open || offset > close {
+        return None;
+    }
+
+    let is_word = |c: char| !c.is_whitespace();
+    let before = source.get(open..offset)?;
+    let after = source.get(offset..close)?;
+    let start = offset
+        - before.chars().rev().take_while(|&c| is_word(c)).map(char::len_utf8).sum::<usize>();
+    let end = offset + after.chars().take_while(|&c| is_word(c)).map(char::len_utf8).sum::<usize>();
+    (start < end).then_some((start, end))
 }
 
 /// Helper to build parent map for an AST
@@ -83,7 +187,70 @@ fn build_parent_map_impl(
         map.insert(node_ptr, parent_ptr);
     }
 
-    for child in perl_semantic_analyzer::declaration::get_node_children(node) {
+    for child in selection_children(node) {
         build_parent_map_impl(child, Some(node_ptr), map);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use perl_parser_core::Parser;
+    use perl_tdd_support::must;
+
+    /// Selected source text for each level of the chain, innermost first
+    fn chain_texts(code: &str, offset: usize) -> Vec<String> {
+        let ast = must(Parser::new(code).parse());
+        let parent_map = build_parent_map(&ast);
+        // Encode byte offsets as positions so ranges map straight back to text
+        let chain = selection_chain(&ast, &parent_map, code, offset, &|o| (0, o as u32));
+
+        let mut texts = Vec::new();
+        let mut current = Some(&chain);
+        while let Some(range) = current {
+            let start = range["range"]["start"]["character"].as_u64().unwrap_or(0) as usize;
+            let end = range["range"]["end"]["character"].as_u64().unwrap_or(0) as usize;
+            texts.push(code.get(start..end).unwrap_or_default().to_string());
+            current = range.get("parent").filter(|p| !p.is_null());
+        }
+        texts
+    }
+
+    #[test]
+    fn test_qw_word_expands_to_list_then_statement() {
+        let code = "my @list = qw(alpha beta gamma);\nprint @list;\n";
+        let offset = code.find("beta").unwrap_or(0) + 1;
+
+        let texts = chain_texts(code, offset);
+        assert_eq!(texts[0], "beta");
+        assert_eq!(texts[1], "qw(alpha beta gamma)");
+        assert_eq!(texts[2], "my @list = qw(alpha beta gamma)");
+        assert_eq!(texts.last().map(String::as_str), Some(code.trim_end()));
+    }
+
+    #[test]
+    fn test_heredoc_line_expands_to_whole_heredoc() {
+        let code = "my $text = <<EOT;\nline one\nline two\nEOT\nprint $text;\n";
+        let offset = code.find("two").unwrap_or(0);
+
+        let texts = chain_texts(code, offset);
+        assert!(texts[0].starts_with("line one\nline two"), "{:?}", texts);
+        assert_eq!(texts[1], "<<EOT;\nline one\nline two\nEOT", "{:?}", texts);
+        assert!(texts[2].starts_with("my $text = <<EOT;\nline one"), "{:?}", texts);
+        // Every level contains the previous one
+        for pair in texts.windows(2) {
+            assert!(pair[1].contains(pair[0].as_str()), "{:?}", texts);
+        }
+    }
+
+    #[test]
+    fn test_ternary_branch_expands_to_whole_ternary() {
+        let code = "my $r = $c ? $a : $b;\n";
+        let offset = code.find("$a").unwrap_or(0) + 1;
+
+        let texts = chain_texts(code, offset);
+        assert_eq!(texts[0], "$a");
+        assert_eq!(texts[1], "$c ? $a : $b");
+        assert_eq!(texts[2], "my $r = $c ? $a : $b");
+    }
+}
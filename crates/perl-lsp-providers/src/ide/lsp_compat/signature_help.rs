@@ -46,6 +46,7 @@ pub struct SignatureHelp {
 /// Signature help provider
 pub struct SignatureHelpProvider {
     ast: Node,
+    source: String,
     symbol_table: SymbolTable,
     builtin_signatures: &'static HashMap<&'static str, ImportedBuiltinSignature>,
 }
@@ -61,7 +62,12 @@ impl SignatureHelpProvider {
         let symbol_table = SymbolExtractor::new_with_source(source).extract(ast);
         let builtin_signatures = create_builtin_signatures();
 
-        SignatureHelpProvider { ast: ast.clone(), symbol_table, builtin_signatures }
+        SignatureHelpProvider {
+            ast: ast.clone(),
+            source: source.to_string(),
+            symbol_table,
+            builtin_signatures,
+        }
     }
 
     /// Check if a built-in function exists
@@ -90,18 +96,16 @@ impl SignatureHelpProvider {
             return None;
         }
 
-        // Determine active parameter
-        let active_parameter = self.calculate_active_parameter(source, &context);
+        // Determine active parameter; a slurpy parameter absorbs everything after it
+        let argument_index = self.calculate_active_parameter(source, &context);
 
         for sig in &mut signatures {
-            sig.active_parameter = Some(active_parameter);
+            let slurpy = sig.parameters.iter().position(is_slurpy_parameter);
+            sig.active_parameter = Some(slurpy.map_or(argument_index, |i| argument_index.min(i)));
         }
+        let active_parameter = signatures[0].active_parameter;
 
-        Some(SignatureHelp {
-            signatures,
-            active_signature: Some(0),
-            active_parameter: Some(active_parameter),
-        })
+        Some(SignatureHelp { signatures, active_signature: Some(0), active_parameter })
     }
 
     /// Find the function call context at position
@@ -118,7 +122,13 @@ impl SignatureHelpProvider {
 
         // Find our position in the char array
         // Handle the case where position is beyond the end of the string (valid cursor position)
-        let pos_idx = chars.iter().position(|(idx, _)| *idx >= position).unwrap_or(chars.len() - 1);
+        let mut pos_idx =
+            chars.iter().position(|(idx, _)| *idx >= position).unwrap_or(chars.len() - 1);
+
+        // A `)` right after the cursor (as in `foo(1, |)`) does not close the call yet
+        if chars[pos_idx] == (position, ')') {
+            pos_idx = pos_idx.checked_sub(1)?;
+        }
 
         // Search backwards
         for i in (0..=pos_idx).rev() {
@@ -224,12 +234,33 @@ impl SignatureHelpProvider {
     }
 
     /// Convert a parameter node into ParameterInfo
+    ///
+    /// Optional parameters are labelled with their default (`$y = 5`) and
+    /// slurpy parameters are documented as absorbing the remaining arguments.
     fn param_info_from_node(&self, node: &Node) -> Option<ParameterInfo> {
         match &node.kind {
-            NodeKind::MandatoryParameter { variable }
-            | NodeKind::OptionalParameter { variable, .. }
-            | NodeKind::SlurpyParameter { variable }
-            | NodeKind::NamedParameter { variable } => {
+            NodeKind::OptionalParameter { variable, default_value } => {
+                let NodeKind::Variable { sigil, name } = &variable.kind else {
+                    return None;
+                };
+                let label = match self.default_text(default_value) {
+                    Some(default) => format!("{sigil}{name} = {default}"),
+                    None => format!("{sigil}{name}="),
+                };
+                Some(ParameterInfo { label, documentation: Some("Optional parameter".to_string()) })
+            }
+            NodeKind::SlurpyParameter { variable } => {
+                let NodeKind::Variable { sigil, name } = &variable.kind else {
+                    return None;
+                };
+                Some(ParameterInfo {
+                    label: format!("{sigil}{name}"),
+                    documentation: Some(
+                        "Slurpy parameter (absorbs remaining arguments)".to_string(),
+                    ),
+                })
+            }
+            NodeKind::MandatoryParameter { variable } | NodeKind::NamedParameter { variable } => {
                 if let NodeKind::Variable { sigil, name } = &variable.kind {
                     Some(ParameterInfo { label: format!("{}{}", sigil, name), documentation: None })
                 } else {
@@ -243,6 +274,23 @@ impl SignatureHelpProvider {
         }
     }
 
+    /// Source text of a parameter default, or `None` for a bare `$=`
+    fn default_text(&self, default: &Node) -> Option<String> {
+        let text = self.source.get(default.location.start..default.location.end).unwrap_or("");
+        match (text.trim(), &default.kind) {
+            // The parser gives a bare `$=` an `undef` default located at the `=`
+            ("=", NodeKind::Undef) => None,
+            ("", NodeKind::Undef) if default.location.end - default.location.start <= 1 => None,
+            ("", kind) => Some(match kind {
+                NodeKind::Number { value } | NodeKind::String { value, .. } => value.clone(),
+                NodeKind::Variable { sigil, name } => format!("{sigil}{name}"),
+                NodeKind::Undef => "undef".to_string(),
+                _ => "...".to_string(),
+            }),
+            (text, _) => Some(text.to_string()),
+        }
+    }
+
     /// Parse parameters from a built-in function signature
     fn parse_builtin_parameters(&self, signature: &str) -> Vec<ParameterInfo> {
         let mut params = Vec::new();
@@ -297,7 +345,7 @@ impl SignatureHelpProvider {
     /// - Supports semantic token generation
     /// - Enables precise hover information
     fn build_signature_from_symbol(&self, symbol: &Symbol) -> SignatureInfo {
-        let mut label = format!("sub {}", symbol.name);
+        let mut label = symbol.name.clone();
         let mut params = Vec::new();
 
         // Try to extract parameters from the AST signature node first (modern Perl syntax)
@@ -383,25 +431,49 @@ impl SignatureHelpProvider {
             return 0;
         }
 
-        let arg_text = &source[context.call_start + 1..context.position];
-
-        // Also need to handle nested parentheses
-        let mut paren_depth: usize = 0;
-        let mut actual_comma_count = 0;
-
-        for ch in arg_text.chars() {
+        let Some(arg_text) = source.get(context.call_start + 1..context.position) else {
+            return 0;
+        };
+
+        // Count top-level `,` and `=>` separators, skipping nested brackets and quoted strings
+        let mut depth: usize = 0;
+        let mut quote = None;
+        let mut separator_count = 0;
+        let mut chars = arg_text.chars().peekable();
+
+        while let Some(ch) = chars.next() {
+            if let Some(open) = quote {
+                match ch {
+                    '\\' => {
+                        chars.next();
+                    }
+                    c if c == open => quote = None,
+                    _ => {}
+                }
+                continue;
+            }
             match ch {
-                '(' => paren_depth += 1,
-                ')' => paren_depth = paren_depth.saturating_sub(1),
-                ',' if paren_depth == 0 => actual_comma_count += 1,
+                '\'' | '"' => quote = Some(ch),
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => separator_count += 1,
+                '=' if depth == 0 && chars.peek() == Some(&'>') => {
+                    chars.next();
+                    separator_count += 1;
+                }
                 _ => {}
             }
         }
 
-        actual_comma_count
+        separator_count
     }
 }
 
+/// Whether a parameter absorbs all remaining arguments (`@rest`, `%opts`)
+fn is_slurpy_parameter(param: &ParameterInfo) -> bool {
+    param.label.starts_with(['@', '%'])
+}
+
 /// Context of a function call
 #[derive(Debug)]
 struct CallContext {
@@ -476,4 +548,66 @@ mod tests {
         assert_eq!(sigs[0].parameters[0].label, "$x");
         assert_eq!(sigs[0].parameters[1].label, "$y");
     }
+
+    fn signature_help_at(code: &str, marker: &str) -> Option<SignatureHelp> {
+        let ast = must(Parser::new(code).parse());
+        let provider = SignatureHelpProvider::new_with_source(&ast, code);
+        let position = code.rfind(marker)? + marker.len();
+        provider.get_signature_help(code, position)
+    }
+
+    #[test]
+    fn test_signature_label_shows_defaults_and_slurpy() {
+        let code = "sub foo($x, $y = 5, @rest) { }\nfoo(1);";
+        let ast = must(Parser::new(code).parse());
+        let provider = SignatureHelpProvider::new_with_source(&ast, code);
+
+        let sigs = provider.get_signatures("foo");
+        assert_eq!(sigs[0].label, "foo($x, $y = 5, @rest)");
+        assert_eq!(sigs[0].parameters[1].label, "$y = 5");
+        assert!(must_some(sigs[0].parameters[2].documentation.as_ref()).contains("Slurpy"));
+    }
+
+    #[test]
+    fn test_signature_label_without_source_renders_literal_defaults() {
+        let code = "sub greet($name = 'world', $punct = \"!\", $=) { }";
+        let ast = must(Parser::new(code).parse());
+        let provider = SignatureHelpProvider::new(&ast);
+
+        let sigs = provider.get_signatures("greet");
+        assert_eq!(sigs[0].label, "greet($name = 'world', $punct = \"!\", $=)");
+    }
+
+    #[test]
+    fn test_active_parameter_advances_per_comma_and_stops_at_slurpy() {
+        let code = "sub foo($x, $y = 5, @rest) { }\nfoo(1, 2, 3, 4);";
+
+        for (marker, expected) in [("foo(", 0), ("foo(1, ", 1), ("2, ", 2), ("3, ", 2), ("4", 2)] {
+            let help = must_some(signature_help_at(code, marker));
+            assert_eq!(help.active_parameter, Some(expected), "cursor after {marker:?}");
+        }
+    }
+
+    #[test]
+    fn test_active_parameter_with_trailing_comma() {
+        let code = "sub foo($x, $y = 5, @rest) { }\nfoo(1, 2,)";
+        let help = must_some(signature_help_at(code, "2,"));
+        assert_eq!(help.active_parameter, Some(2));
+
+        let code = "sub add($x, $y) { }\nadd(1,)";
+        let help = must_some(signature_help_at(code, "add(1"));
+        assert_eq!(help.active_parameter, Some(0));
+        let help = must_some(signature_help_at(code, "add(1,"));
+        assert_eq!(help.active_parameter, Some(1));
+    }
+
+    #[test]
+    fn test_active_parameter_with_fat_comma_arguments() {
+        let code = "sub pair($key, $value, $extra) { }\npair(name => 'a,b', ";
+
+        let help = must_some(signature_help_at(code, "name => "));
+        assert_eq!(help.active_parameter, Some(1));
+        let help = must_some(signature_help_at(code, "'a,b', "));
+        assert_eq!(help.active_parameter, Some(2));
+    }
 }
@@ -100,12 +100,12 @@ mod validate;
 pub use apply::adjust_location_for_sigil;
 #[allow(unused_imports)]
 pub use apply::apply_rename_edits;
-pub use resolve::{find_symbol_at_position, get_symbol_range_at_position};
+pub use resolve::{find_declaring_scope, find_symbol_at_position, get_symbol_range_at_position};
 pub use types::{RenameOptions, RenameResult, TextEdit};
-pub use validate::{can_rename_symbol, validate_name};
+pub use validate::{can_rename_symbol, find_rename_collision, validate_name};
 
 use perl_parser_core::Node;
-use perl_semantic_analyzer::symbol::{SymbolExtractor, SymbolTable};
+use perl_semantic_analyzer::symbol::{SymbolExtractor, SymbolKind, SymbolTable};
 
 /// Rename provider
 pub struct RenameProvider {
@@ -160,6 +160,16 @@ impl RenameProvider {
             return RenameResult { edits: vec![], is_valid: false, error: Some(error) };
         }
 
+        // Reject renames that would alias or shadow a variable already in scope
+        if options.validate_new_name
+            && kind != SymbolKind::Subroutine
+            && let Some(scope) = find_declaring_scope(position, &old_name, kind, &self.symbol_table)
+            && let Some(error) =
+                find_rename_collision(&self.symbol_table, &old_name, new_name, kind, scope)
+        {
+            return RenameResult { edits: vec![], is_valid: false, error: Some(error) };
+        }
+
         // Check if we can rename this symbol
         if !can_rename_symbol(&old_name, kind) {
             return RenameResult {
@@ -217,7 +227,6 @@ impl RenameProvider {
 mod tests {
     use super::*;
     use perl_parser_core::Parser;
-    use perl_semantic_analyzer::symbol::SymbolKind;
     use perl_tdd_support::{must, must_some};
 
     #[test]
@@ -299,4 +308,45 @@ my $result = calculate();
         assert!(validate_name("_private", SymbolKind::scalar(), &provider.symbol_table).is_ok());
         assert!(validate_name("camelCase", SymbolKind::scalar(), &provider.symbol_table).is_ok());
     }
+
+    #[test]
+    fn test_rename_rejects_collision_in_same_scope() {
+        let code = "my $foo = 1;\nmy $bar = 2;\nprint $foo + $bar;\n";
+        let ast = must(Parser::new(code).parse());
+        let provider = RenameProvider::new(&ast, code.to_string());
+
+        let pos = must_some(code.find("$foo")) + 1;
+        let result = provider.rename(pos, "bar", &RenameOptions::default());
+
+        assert!(!result.is_valid);
+        assert!(result.edits.is_empty());
+        assert!(must_some(result.error).contains("collide with the existing '$bar'"));
+    }
+
+    #[test]
+    fn test_rename_rejects_capturing_outer_variable() {
+        // Renaming the inner $foo to $bar would hide the outer $bar it prints
+        let code = "my $bar = 1;\nsub f {\n    my $foo = 2;\n    print $foo, $bar;\n}\n";
+        let ast = must(Parser::new(code).parse());
+        let provider = RenameProvider::new(&ast, code.to_string());
+
+        let pos = must_some(code.find("$foo")) + 1;
+        let result = provider.rename(pos, "bar", &RenameOptions::default());
+
+        assert!(!result.is_valid);
+    }
+
+    #[test]
+    fn test_rename_allows_name_from_sibling_scope() {
+        let code = "sub f {\n    my $foo = 1;\n    print $foo;\n}\nsub g {\n    my $bar = 2;\n    print $bar;\n}\n";
+        let ast = must(Parser::new(code).parse());
+        let provider = RenameProvider::new(&ast, code.to_string());
+
+        let pos = must_some(code.find("$foo")) + 1;
+        let result = provider.rename(pos, "bar", &RenameOptions::default());
+
+        assert!(result.is_valid, "Unexpected error: {:?}", result.error);
+        let new_code = apply_rename_edits(code, &result.edits);
+        assert!(new_code.contains("my $bar = 1;\n    print $bar;"));
+    }
 }
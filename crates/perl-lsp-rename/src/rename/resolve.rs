@@ -3,7 +3,7 @@
 //! This module provides symbol resolution for rename operations.
 
 use perl_parser_core::SourceLocation;
-use perl_semantic_analyzer::symbol::{SymbolKind, SymbolTable};
+use perl_semantic_analyzer::symbol::{ScopeId, SymbolKind, SymbolTable};
 
 /// Find the symbol at a given position
 pub fn find_symbol_at_position(
@@ -11,13 +11,16 @@ pub fn find_symbol_at_position(
     symbol_table: &SymbolTable,
     source: &str,
 ) -> Option<(String, SymbolKind)> {
-    // First check if we're on a definition
-    for (name, symbols) in &symbol_table.symbols {
-        for symbol in symbols {
-            if symbol.location.start <= position && position <= symbol.location.end {
-                return Some((name.clone(), symbol.kind));
-            }
-        }
+    // First check if we're on a definition, preferring the narrowest one so a
+    // subroutine's span does not hide the variables declared inside it
+    let definition = symbol_table
+        .symbols
+        .iter()
+        .flat_map(|(name, symbols)| symbols.iter().map(move |symbol| (name, symbol)))
+        .filter(|(_, symbol)| symbol.location.start <= position && position <= symbol.location.end)
+        .min_by_key(|(_, symbol)| symbol.location.end - symbol.location.start);
+    if let Some((name, symbol)) = definition {
+        return Some((name.clone(), symbol.kind));
     }
 
     // Then check references
@@ -33,6 +36,39 @@ pub fn find_symbol_at_position(
     extract_symbol_from_source(position, source)
 }
 
+/// Find the scope declaring the symbol named at a given position
+///
+/// On a declaration this is the declaration's own scope; on a reference it
+/// is the scope of the nearest visible declaration. Returns `None` when the
+/// name does not resolve to a declaration in this file.
+pub fn find_declaring_scope(
+    position: usize,
+    name: &str,
+    kind: SymbolKind,
+    symbol_table: &SymbolTable,
+) -> Option<ScopeId> {
+    let contains =
+        |location: SourceLocation| location.start <= position && position <= location.end;
+
+    if let Some(symbol) = symbol_table
+        .symbols
+        .get(name)
+        .into_iter()
+        .flatten()
+        .find(|symbol| symbol.kind == kind && contains(symbol.location))
+    {
+        return Some(symbol.scope_id);
+    }
+
+    let reference = symbol_table
+        .references
+        .get(name)
+        .into_iter()
+        .flatten()
+        .find(|reference| reference.kind == kind && contains(reference.location))?;
+    symbol_table.find_symbol(name, reference.scope_id, kind).first().map(|symbol| symbol.scope_id)
+}
+
 /// Extract symbol from source text at position
 pub fn extract_symbol_from_source(position: usize, source: &str) -> Option<(String, SymbolKind)> {
     let chars: Vec<char> = source.chars().collect();
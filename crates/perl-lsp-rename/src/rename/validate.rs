@@ -3,7 +3,7 @@
 //! This module provides validation for rename operations.
 
 use perl_semantic_analyzer::symbol::SymbolKind;
-use perl_semantic_analyzer::symbol::SymbolTable;
+use perl_semantic_analyzer::symbol::{ScopeId, SymbolTable};
 
 /// Check if a symbol can be renamed
 pub fn can_rename_symbol(name: &str, _kind: SymbolKind) -> bool {
@@ -77,3 +77,41 @@ pub fn validate_name(
 
     Ok(())
 }
+
+/// Check whether renaming a variable would collide with one already in scope
+///
+/// `scope` is the scope declaring the variable being renamed. Renaming
+/// collides when a variable of the same kind named `new_name` is visible
+/// from that scope or from any scope where the old name is referenced,
+/// since the renamed uses would then alias or shadow it. A `new_name`
+/// declared only in an unrelated sibling scope is not visible and is fine.
+pub fn find_rename_collision(
+    symbol_table: &SymbolTable,
+    old_name: &str,
+    new_name: &str,
+    kind: SymbolKind,
+    scope: ScopeId,
+) -> Option<String> {
+    let reference_scopes = symbol_table
+        .references
+        .get(old_name)
+        .into_iter()
+        .flatten()
+        .filter(|reference| reference.kind == kind)
+        .filter(|reference| {
+            symbol_table
+                .find_symbol(old_name, reference.scope_id, kind)
+                .first()
+                .is_some_and(|symbol| symbol.scope_id == scope)
+        })
+        .map(|reference| reference.scope_id);
+
+    std::iter::once(scope).chain(reference_scopes).find_map(|scope_id| {
+        let existing = symbol_table.find_symbol(new_name, scope_id, kind).into_iter().next()?;
+        let sigil = kind.sigil().unwrap_or_default();
+        Some(format!(
+            "Renaming '{sigil}{old_name}' to '{sigil}{new_name}' would collide with the existing '{sigil}{new_name}' declared at offset {}",
+            existing.location.start
+        ))
+    })
+}
@@ -22,7 +22,9 @@
 
 mod semantic_tokens;
 
-pub use semantic_tokens::{EncodedToken, TokensLegend, collect_semantic_tokens, legend};
+pub use semantic_tokens::{
+    EncodedToken, TokensEdit, TokensLegend, collect_semantic_tokens, legend, semantic_tokens_delta,
+};
 
 /// Semantic tokens provider for LSP
 ///
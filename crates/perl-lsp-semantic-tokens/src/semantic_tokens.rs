@@ -106,7 +106,8 @@
 
 use perl_lexer::{PerlLexer, TokenType};
 use perl_parser_core::ast::{Node, NodeKind};
-use rustc_hash::FxHashMap;
+use perl_semantic_analyzer::symbol::{SymbolExtractor, SymbolKind};
+use rustc_hash::{FxHashMap, FxHashSet};
 
 /// LSP semantic token encoding format for client transmission
 ///
@@ -114,6 +115,55 @@ use rustc_hash::FxHashMap;
 /// following the LSP specification for efficient delta-encoded token streams.
 pub type EncodedToken = [u32; 5];
 
+/// One edit to a flattened token array, as sent in a `SemanticTokensDelta`
+///
+/// Replaces `delete_count` integers starting at `start` with `data`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokensEdit {
+    /// Index of the first integer to replace
+    pub start: u32,
+    /// Number of integers removed
+    pub delete_count: u32,
+    /// Integers inserted in their place
+    pub data: Vec<u32>,
+}
+
+/// Compute the edits that turn the `previous` flattened token array into `current`
+///
+/// Unchanged integers at both ends are kept, so the result is empty for
+/// identical arrays and otherwise a single edit covering only the span that
+/// differs. A token whose length changed therefore costs one integer.
+///
+/// # Examples
+///
+/// ```rust
+/// use perl_lsp_semantic_tokens::{TokensEdit, semantic_tokens_delta};
+///
+/// let previous = [0, 0, 2, 1, 0, 0, 3, 4, 4, 0];
+/// let current = [0, 0, 2, 1, 0, 0, 3, 5, 4, 0];
+/// assert_eq!(
+///     semantic_tokens_delta(&previous, &current),
+///     vec![TokensEdit { start: 7, delete_count: 1, data: vec![5] }]
+/// );
+/// ```
+pub fn semantic_tokens_delta(previous: &[u32], current: &[u32]) -> Vec<TokensEdit> {
+    let prefix = previous.iter().zip(current).take_while(|(a, b)| a == b).count();
+    let suffix = previous[prefix..]
+        .iter()
+        .rev()
+        .zip(current[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let deleted = previous.len() - prefix - suffix;
+    let inserted = &current[prefix..current.len() - suffix];
+    if deleted == 0 && inserted.is_empty() {
+        return Vec::new();
+    }
+    vec![TokensEdit { start: prefix as u32, delete_count: deleted as u32, data: inserted.to_vec() }]
+}
+
 /// Semantic token legend mapping token types and modifiers to indices
 ///
 /// Provides the mapping between semantic token names and their numeric indices
@@ -170,14 +220,18 @@ pub fn legend() -> TokensLegend {
     .map(|s| s.to_string())
     .collect::<Vec<_>>();
 
+    // Same order as the legend advertised in the server capabilities
     let modifiers = vec![
         "declaration",
         "definition",
         "readonly",
-        "defaultLibrary",
-        "deprecated",
         "static",
+        "deprecated",
+        "abstract",
         "async",
+        "modification",
+        "documentation",
+        "defaultLibrary",
     ]
     .into_iter()
     .map(|s| s.to_string())
@@ -196,6 +250,11 @@ fn kind_idx(leg: &TokensLegend, k: &str) -> u32 {
     *leg.map.get(k).unwrap_or(&0)
 }
 
+#[inline]
+fn modifier_bit(leg: &TokensLegend, m: &str) -> u32 {
+    leg.modifiers.iter().position(|x| x == m).map_or(0, |i| 1 << i)
+}
+
 /// Collect semantic tokens for LSP highlighting in the Complete stage.
 ///
 /// # Arguments
@@ -254,12 +313,14 @@ pub fn collect_semantic_tokens(
 
             TokenType::Number(_) => "number",
 
-            TokenType::RegexMatch
-            | TokenType::Substitution
-            | TokenType::Transliteration
-            | TokenType::QuoteRegex => "regexp",
+            TokenType::RegexMatch { .. }
+            | TokenType::Substitution { .. }
+            | TokenType::Transliteration { .. }
+            | TokenType::QuoteRegex { .. } => "regexp",
 
             TokenType::Division
+            | TokenType::Bind
+            | TokenType::NotBind
             | TokenType::Operator(_)
             | TokenType::Arrow
             | TokenType::FatComma => "operator",
@@ -274,18 +335,63 @@ pub fn collect_semantic_tokens(
     }
 
     // 2) AST overlays: package/sub/variable (prefer identifier spans if you track them)
+    let declaration = modifier_bit(&leg, "declaration");
+    let readonly = modifier_bit(&leg, "readonly");
+    let modification = modifier_bit(&leg, "modification");
+    let usage = VariableUsage::collect(ast, text);
+    let constants = collect_constants(ast, text);
+    let constant_names: FxHashSet<&str> = constants.iter().map(|(name, _)| name.as_str()).collect();
+
+    for (_, (s, e)) in &constants {
+        let (sl, sc) = to_pos16(*s);
+        let (_, ec) = to_pos16(*e);
+        raw_tokens.push((
+            sl,
+            sc,
+            ec.saturating_sub(sc),
+            kind_idx(&leg, "macro"),
+            declaration | readonly,
+        ));
+    }
+
     walk_ast(ast, &mut |node| {
         let (s, e) = (node.location.start, node.location.end);
+        // A call's span includes its arguments; highlight just the name
+        let name_end = match &node.kind {
+            NodeKind::FunctionCall { name, .. }
+                if text.get(s..).is_some_and(|rest| rest.starts_with(name.as_str())) =>
+            {
+                s + name.len()
+            }
+            _ => e,
+        };
         let (sl, sc) = to_pos16(s);
-        let (el, ec) = to_pos16(e);
+        let (el, ec) = to_pos16(name_end);
         let len = if sl == el { ec.saturating_sub(sc) } else { 0 };
 
         let (kind, mods): (&str, u32) = match &node.kind {
             NodeKind::Package { .. } => ("namespace", 0),
-            NodeKind::Subroutine { name: Some(_), .. } => ("function", 1 /*declaration*/),
+            NodeKind::Subroutine { name: Some(_), .. } => ("function", declaration),
+            NodeKind::FunctionCall { name, args }
+                if args.is_empty() && constant_names.contains(name.as_str()) =>
+            {
+                ("macro", readonly)
+            }
+            NodeKind::Identifier { name } if constant_names.contains(name.as_str()) => {
+                ("macro", readonly)
+            }
             NodeKind::FunctionCall { .. } => ("function", 0),
             NodeKind::MethodCall { .. } => ("method", 0),
-            NodeKind::Variable { .. } => ("variable", 0),
+            NodeKind::Variable { .. } => {
+                let mut mods = 0;
+                if usage.declarations.contains(&(s, e)) {
+                    mods |= declaration;
+                }
+                if usage.writes.contains(&(s, e)) {
+                    mods |= modification;
+                }
+                ("variable", mods)
+            }
             _ => return true,
         };
 
@@ -366,27 +472,186 @@ fn encode_raw_tokens_to_deltas(
     out
 }
 
-fn walk_ast<F>(node: &Node, visitor: &mut F) -> bool
-where
-    F: FnMut(&Node) -> bool,
-{
-    if !visitor(node) {
-        return false;
+/// Byte spans of variable occurrences that declare or modify a variable
+#[derive(Default)]
+struct VariableUsage {
+    /// Variables introduced by `my`/`our`/`local`/`state`
+    declarations: FxHashSet<(usize, usize)>,
+    /// Assignment targets and `++`/`--` operands
+    writes: FxHashSet<(usize, usize)>,
+}
+
+impl VariableUsage {
+    fn collect(ast: &Node, text: &str) -> Self {
+        let mut usage = Self::default();
+
+        // Plain assignments are recorded as write references by the symbol table
+        let table = SymbolExtractor::new_with_source(text).extract(ast);
+        for reference in table.references.values().flatten() {
+            if reference.is_write && matches!(reference.kind, SymbolKind::Variable(_)) {
+                usage.writes.insert((reference.location.start, reference.location.end));
+            }
+        }
+
+        // Compound assignments and increments also read their target, so the
+        // table records them as reads; pick them up from the AST with declarations
+        walk_ast(ast, &mut |node| {
+            match &node.kind {
+                NodeKind::VariableDeclaration { variable, .. } => {
+                    usage.add_declared(variable);
+                }
+                NodeKind::VariableListDeclaration { variables, .. } => {
+                    for variable in variables {
+                        usage.add_declared(variable);
+                    }
+                }
+                NodeKind::Assignment { lhs, op, .. } if op != "=" => {
+                    usage.add_written(lhs);
+                }
+                NodeKind::Unary { op, operand } if op == "++" || op == "--" => {
+                    usage.add_written(operand);
+                }
+                _ => {}
+            }
+            true
+        });
+
+        usage
     }
 
-    for child in perl_semantic_analyzer::analysis::declaration::get_node_children(node) {
-        if !walk_ast(child, visitor) {
-            return false;
+    fn add_declared(&mut self, node: &Node) {
+        match &node.kind {
+            NodeKind::Variable { .. } => {
+                self.declarations.insert((node.location.start, node.location.end));
+            }
+            // `local $x = ...` wraps the assignment in the declaration
+            NodeKind::Assignment { lhs, .. } => self.add_declared(lhs),
+            _ => {}
         }
     }
 
-    true
+    fn add_written(&mut self, node: &Node) {
+        match &node.kind {
+            NodeKind::Variable { .. } => {
+                self.writes.insert((node.location.start, node.location.end));
+            }
+            NodeKind::ArrayLiteral { elements } => {
+                for element in elements {
+                    self.add_written(element);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Names declared with `use constant`, with the byte span of each declaring occurrence
+///
+/// Handles `use constant NAME => ...` and `use constant { A => ..., B => ... }`.
+fn collect_constants(ast: &Node, text: &str) -> Vec<(String, (usize, usize))> {
+    let mut constants = Vec::new();
+    walk_ast(ast, &mut |node| {
+        let NodeKind::Use { module, args, .. } = &node.kind else {
+            return true;
+        };
+        if module != "constant" {
+            return true;
+        }
+
+        let args: Vec<&str> =
+            args.iter().map(String::as_str).skip_while(|arg| arg.starts_with('-')).collect();
+        let names: Vec<&str> = if args.first() == Some(&"{") {
+            args.windows(2).filter(|w| w[1] == "=>").map(|w| w[0]).collect()
+        } else {
+            args.first().copied().into_iter().collect()
+        };
+
+        let Some(source) = text.get(node.location.start..node.location.end) else {
+            return true;
+        };
+        // Search after the `constant` keyword so the module name is never matched
+        let mut from = source.find("constant").map_or(0, |i| i + "constant".len());
+        for name in names {
+            if !is_identifier(name) {
+                continue;
+            }
+            if let Some(offset) = find_word(&source[from..], name) {
+                let start = node.location.start + from + offset;
+                constants.push((name.to_string(), (start, start + name.len())));
+                from += offset + name.len();
+            }
+        }
+        true
+    });
+    constants
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Offset of the first occurrence of `word` in `haystack` not embedded in a longer identifier
+fn find_word(haystack: &str, word: &str) -> Option<usize> {
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    haystack.match_indices(word).map(|(i, _)| i).find(|&i| {
+        !haystack[..i].ends_with(is_ident) && !haystack[i + word.len()..].starts_with(is_ident)
+    })
+}
+
+/// Visit `node` and its descendants depth-first, skipping the children of
+/// nodes for which `visitor` returns `false`
+fn walk_ast<'a, F>(node: &'a Node, visitor: &mut F)
+where
+    F: FnMut(&'a Node) -> bool,
+{
+    if visitor(node) {
+        node.for_each_child(|child| walk_ast(child, visitor));
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_delta_for_changed_token_length_is_one_integer() {
+        // `my $count` -> `my $counter`: only the variable's length changes
+        let previous = [0, 0, 2, 0, 0, 0, 3, 6, 4, 1, 1, 0, 5, 2, 0];
+        let current = [0, 0, 2, 0, 0, 0, 3, 8, 4, 1, 1, 0, 5, 2, 0];
+        assert_eq!(
+            semantic_tokens_delta(&previous, &current),
+            vec![TokensEdit { start: 7, delete_count: 1, data: vec![8] }]
+        );
+    }
+
+    #[test]
+    fn test_delta_for_inserted_and_removed_tokens() {
+        let one = [0, 0, 2, 0, 0];
+        let two = [0, 0, 2, 0, 0, 1, 0, 3, 2, 0];
+        assert_eq!(
+            semantic_tokens_delta(&one, &two),
+            vec![TokensEdit { start: 5, delete_count: 0, data: vec![1, 0, 3, 2, 0] }]
+        );
+        assert_eq!(
+            semantic_tokens_delta(&two, &one),
+            vec![TokensEdit { start: 5, delete_count: 5, data: vec![] }]
+        );
+        assert!(semantic_tokens_delta(&two, &two).is_empty());
+    }
+
+    #[test]
+    fn test_delta_applied_to_previous_gives_current() {
+        let previous = [0, 0, 2, 0, 0, 0, 3, 4, 4, 0, 2, 0, 5, 2, 0];
+        let current = [0, 0, 2, 0, 0, 1, 0, 3, 2, 0, 1, 0, 5, 2, 0];
+        let mut applied = previous.to_vec();
+        for edit in semantic_tokens_delta(&previous, &current) {
+            let start = edit.start as usize;
+            applied.splice(start..start + edit.delete_count as usize, edit.data);
+        }
+        assert_eq!(applied, current);
+    }
+
     // Helper to create token tuple
     fn tok(line: u32, start: u32, len: u32, kind: u32, mods: u32) -> (u32, u32, u32, u32, u32) {
         (line, start, len, kind, mods)
@@ -728,4 +993,105 @@ mod tests {
             }
         }
     }
+
+    // ==================== Declaration / modification modifiers ====================
+
+    /// Decode single-line tokens into (column, length, type, modifiers)
+    fn single_line_tokens(code: &str) -> Vec<(u32, u32, u32, u32)> {
+        let ast = perl_parser_core::Parser::new(code).parse().unwrap_or_else(|e| {
+            unreachable!("test source must parse: {e:?}");
+        });
+        let to_pos16 = |pos: usize| (0u32, pos as u32);
+        let mut column = 0;
+        collect_semantic_tokens(&ast, code, &to_pos16)
+            .into_iter()
+            .map(|[_, delta, len, kind, mods]| {
+                column += delta;
+                (column, len, kind, mods)
+            })
+            .collect()
+    }
+
+    fn mods_at(tokens: &[(u32, u32, u32, u32)], column: u32) -> u32 {
+        tokens.iter().find(|t| t.0 == column).map_or(u32::MAX, |t| t.3)
+    }
+
+    #[test]
+    fn legend_modifiers_match_advertised_order() {
+        let leg = legend();
+        assert_eq!(
+            leg.modifiers,
+            [
+                "declaration",
+                "definition",
+                "readonly",
+                "static",
+                "deprecated",
+                "abstract",
+                "async",
+                "modification",
+                "documentation",
+                "defaultLibrary"
+            ]
+        );
+    }
+
+    #[test]
+    fn variable_modifiers_mark_declaration_write_and_read() {
+        let leg = legend();
+        let declaration = modifier_bit(&leg, "declaration");
+        let modification = modifier_bit(&leg, "modification");
+        assert_eq!((declaration, modification), (1, 1 << 7));
+
+        // my $x = 1; $x++; print $x;
+        // 0123456789012345678901234
+        let tokens = single_line_tokens("my $x = 1; $x++; print $x;");
+        let variable = kind_idx(&leg, "variable");
+        let variables: Vec<_> = tokens.iter().filter(|t| t.2 == variable).collect();
+        assert_eq!(variables.len(), 3);
+
+        assert_eq!(mods_at(&tokens, 3), declaration, "`my $x` declares");
+        assert_eq!(mods_at(&tokens, 11), modification, "`$x++` writes");
+        assert_eq!(mods_at(&tokens, 23), 0, "`print $x` only reads");
+    }
+
+    #[test]
+    fn variable_modifiers_cover_assignment_and_declarators() {
+        let leg = legend();
+        let declaration = modifier_bit(&leg, "declaration");
+        let modification = modifier_bit(&leg, "modification");
+
+        // our $y; $y = 2; $y += 1; --$y; state $s; local $z = 1;
+        // 0         1         2         3         4         5
+        // 012345678901234567890123456789012345678901234567890123
+        let tokens = single_line_tokens("our $y; $y = 2; $y += 1; --$y; state $s; local $z = 1;");
+
+        assert_eq!(mods_at(&tokens, 4), declaration);
+        assert_eq!(mods_at(&tokens, 8), modification, "plain assignment");
+        assert_eq!(mods_at(&tokens, 16), modification, "compound assignment");
+        assert_eq!(mods_at(&tokens, 27), modification, "prefix decrement");
+        assert_eq!(mods_at(&tokens, 37), declaration);
+        assert_eq!(mods_at(&tokens, 47), declaration);
+    }
+
+    #[test]
+    fn use_constant_names_are_readonly() {
+        let leg = legend();
+        let declaration = modifier_bit(&leg, "declaration");
+        let readonly = modifier_bit(&leg, "readonly");
+        let constant = kind_idx(&leg, "macro");
+
+        // use constant PI => 3; use constant { E => 2, N => 1 }; my $r = PI + E;
+        // 0         1         2         3         4         5         6
+        // 0123456789012345678901234567890123456789012345678901234567890123456789
+        let tokens = single_line_tokens(
+            "use constant PI => 3; use constant { E => 2, N => 1 }; my $r = PI + E;",
+        );
+
+        assert!(tokens.contains(&(13, 2, constant, declaration | readonly)));
+        assert!(tokens.contains(&(37, 1, constant, declaration | readonly)));
+        assert!(tokens.contains(&(45, 1, constant, declaration | readonly)));
+        assert!(tokens.contains(&(63, 2, constant, readonly)));
+        assert!(tokens.contains(&(68, 1, constant, readonly)));
+    }
 }
@@ -1,7 +1,7 @@
 //! Tooling integration for Perl LSP
 //!
 //! This crate provides abstractions for integrating with external Perl tooling
-//! such as perltidy (formatting) and perlcritic (linting).
+//! such as perltidy (formatting), perlcritic (linting) and prove (testing).
 //!
 //! ## Features
 //!
@@ -32,6 +32,8 @@ pub mod perl_critic;
 /// Perltidy integration for code formatting.
 pub mod perltidy;
 mod subprocess_runtime;
+/// TAP test execution through the subprocess runtime.
+pub mod tap_runner;
 
 pub use subprocess_runtime::{SubprocessError, SubprocessOutput, SubprocessRuntime};
 
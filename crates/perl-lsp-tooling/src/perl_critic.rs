@@ -4,18 +4,23 @@
 //! and policy enforcement in Perl code.
 
 use super::subprocess_runtime::SubprocessRuntime;
+use perl_diagnostics_codes::DiagnosticCode;
 use perl_parser_core::{
     Node,
     position::{Position, Range},
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 #[cfg(feature = "lsp-compat")]
 use lsp_types;
 
+/// `--verbose` format requested from perlcritic: file, line, column, severity,
+/// policy and message separated by tabs
+pub const MACHINE_FORMAT: &str = "%f\\t%l\\t%c\\t%s\\t%p\\t%m\\n";
+
 /// Severity levels for Perl::Critic violations
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Severity {
@@ -56,6 +61,17 @@ impl Severity {
         }
     }
 
+    /// Returns the stable `PC001`-`PC005` diagnostic code for this severity.
+    pub fn diagnostic_code(&self) -> DiagnosticCode {
+        match self {
+            Self::Brutal => DiagnosticCode::CriticSeverity1,
+            Self::Cruel => DiagnosticCode::CriticSeverity2,
+            Self::Harsh => DiagnosticCode::CriticSeverity3,
+            Self::Stern => DiagnosticCode::CriticSeverity4,
+            Self::Gentle => DiagnosticCode::CriticSeverity5,
+        }
+    }
+
     /// Converts this severity to a numeric severity level (for non-LSP contexts).
     #[cfg(not(feature = "lsp-compat"))]
     pub fn to_severity_level(&self) -> u8 {
@@ -85,8 +101,12 @@ pub struct Violation {
 pub struct CriticConfig {
     /// Minimum severity level to report (1-5)
     pub severity: u8,
-    /// Path to perlcriticrc file
+    /// Path to perlcriticrc file; when unset, a `.perlcriticrc` is looked up
+    /// from the analyzed file's directory upwards
     pub profile: Option<String>,
+    /// Passed as perlcritic's `--severity`, which reports violations of this
+    /// severity number and above; overrides `severity` when set
+    pub min_severity: Option<u8>,
     /// Policies to explicitly include in analysis
     pub include: Vec<String>,
     /// Policies to explicitly exclude from analysis
@@ -104,6 +124,7 @@ impl Default for CriticConfig {
         Self {
             severity: 3, // Harsh and above
             profile: None,
+            min_severity: None,
             include: Vec::new(),
             exclude: Vec::new(),
             theme: None,
@@ -149,12 +170,20 @@ impl CriticAnalyzer {
         let mut args: Vec<String> = Vec::new();
 
         // Add severity
-        args.push(format!("--severity={}", self.config.severity));
-
-        // Add profile if specified
-        if let Some(ref profile) = self.config.profile {
-            args.push(format!("--profile={}", profile));
+        let threshold = self.severity_threshold();
+        args.push(format!("--severity={}", threshold));
+
+        // Add profile if specified, otherwise the nearest project .perlcriticrc
+        let profile =
+            self.config.profile.as_ref().map(PathBuf::from).or_else(|| find_profile(file_path));
+        if let Some(ref profile) = profile {
+            args.push(format!("--profile={}", profile.display()));
         }
+        eprintln!(
+            "perlcritic: profile={} min_severity={}",
+            profile.as_ref().map_or_else(|| "<none>".to_string(), |p| p.display().to_string()),
+            threshold
+        );
 
         // Add theme if specified
         if let Some(ref theme) = self.config.theme {
@@ -171,8 +200,8 @@ impl CriticAnalyzer {
             args.push(format!("--exclude={}", policy));
         }
 
-        // Use verbose format for parsing
-        args.push("--verbose=%f:%l:%c:%s:%p:%m\\n".to_string());
+        // Tab-separated verbose format: policy names contain `::` and paths may contain `:`
+        args.push(format!("--verbose={}", MACHINE_FORMAT));
 
         // SECURITY: Add `--` to prevent argument injection via filenames starting with `-`
         // (e.g., a file named `-rf` would otherwise be interpreted as a flag)
@@ -188,7 +217,7 @@ impl CriticAnalyzer {
         let output =
             self.runtime.run_command("perlcritic", &args_refs, None).map_err(|e| e.message)?;
 
-        // Parse output
+        // Parse output; `--severity` already limited it to the threshold
         let violations = self.parse_output(&output.stdout, &path_str)?;
 
         // Cache results
@@ -197,36 +226,35 @@ impl CriticAnalyzer {
         Ok(violations)
     }
 
+    /// Effective minimum severity: `min_severity` if set, otherwise `severity`
+    pub fn severity_threshold(&self) -> u8 {
+        self.config.min_severity.unwrap_or(self.config.severity)
+    }
+
     /// Parse perlcritic output
+    ///
+    /// Accepts the tab-separated [`MACHINE_FORMAT`] as well as perlcritic's
+    /// built-in `--verbose 8` format. Lines in neither shape are skipped.
     fn parse_output(&self, output: &[u8], file_path: &str) -> Result<Vec<Violation>, String> {
         let output_str = String::from_utf8_lossy(output);
         let mut violations = Vec::new();
 
         for line in output_str.lines() {
-            if line.trim().is_empty() {
-                continue;
-            }
-
-            // Parse format: file:line:column:severity:policy:message
-            let parts: Vec<&str> = line.splitn(6, ':').collect();
-            if parts.len() != 6 {
+            let Some(raw) = parse_machine_line(line).or_else(|| parse_verbose8_line(line)) else {
                 continue;
-            }
-
-            let line_num: u32 = parts[1].parse().unwrap_or(1);
-            let column: u32 = parts[2].parse().unwrap_or(1);
-            let severity: u8 = parts[3].parse().unwrap_or(3);
-            let policy = parts[4].to_string();
-            let message = parts[5].to_string();
+            };
 
+            // perlcritic reports 1-based lines and 1-based character columns
+            let line_num = raw.line.saturating_sub(1);
+            let column = raw.column.saturating_sub(1);
             violations.push(Violation {
-                policy: policy.clone(),
-                description: message,
-                explanation: self.get_policy_explanation(&policy),
-                severity: Severity::from_number(severity),
+                policy: raw.policy.to_string(),
+                description: raw.message.to_string(),
+                explanation: self.get_policy_explanation(raw.policy),
+                severity: Severity::from_number(raw.severity),
                 range: Range {
-                    start: Position { byte: 0, line: line_num - 1, column: column - 1 },
-                    end: Position { byte: 0, line: line_num - 1, column },
+                    start: Position { byte: 0, line: line_num, column },
+                    end: Position { byte: 0, line: line_num, column: column + 1 },
                 },
                 file: file_path.to_string(),
             });
@@ -247,22 +275,56 @@ impl CriticAnalyzer {
         self.cache.remove(file_path);
     }
 
+    /// Run perlcritic on a file and convert the violations to diagnostics
+    ///
+    /// `source` is the current text of the file, used to convert perlcritic's
+    /// character columns to UTF-16 positions. When perlcritic is not installed
+    /// or fails to run, no diagnostics are returned.
+    #[cfg(feature = "lsp-compat")]
+    pub fn diagnostics_for_file(
+        &mut self,
+        file_path: &Path,
+        source: &str,
+    ) -> Vec<lsp_types::Diagnostic> {
+        match self.analyze_file(file_path) {
+            Ok(violations) => self.to_diagnostics(&violations, source),
+            Err(_) => Vec::new(),
+        }
+    }
+
     /// Convert violations to diagnostics
+    ///
+    /// Each diagnostic carries the `PC00N` code for its severity and names the
+    /// policy in its message; columns are converted to UTF-16 using `source`.
     #[cfg(feature = "lsp-compat")]
-    pub fn to_diagnostics(&self, violations: &[Violation]) -> Vec<lsp_types::Diagnostic> {
+    pub fn to_diagnostics(
+        &self,
+        violations: &[Violation],
+        source: &str,
+    ) -> Vec<lsp_types::Diagnostic> {
+        let lines: Vec<&str> = source.lines().collect();
         violations
             .iter()
             .map(|v| {
+                let line_text = lines.get(v.range.start.line as usize).copied().unwrap_or("");
                 let lsp_range = lsp_types::Range::new(
-                    lsp_types::Position::new(v.range.start.line, v.range.start.column),
-                    lsp_types::Position::new(v.range.end.line, v.range.end.column),
+                    lsp_types::Position::new(
+                        v.range.start.line,
+                        utf16_column(line_text, v.range.start.column),
+                    ),
+                    lsp_types::Position::new(
+                        v.range.end.line,
+                        utf16_column(line_text, v.range.end.column),
+                    ),
                 );
                 lsp_types::Diagnostic {
                     range: lsp_range,
                     severity: Some(v.severity.to_diagnostic_severity()),
-                    code: Some(lsp_types::NumberOrString::String(v.policy.clone())),
+                    code: Some(lsp_types::NumberOrString::String(
+                        v.severity.diagnostic_code().as_str().to_string(),
+                    )),
                     source: Some("perlcritic".to_string()),
-                    message: v.description.clone(),
+                    message: format!("{} [{}]", v.description, v.policy),
                     related_information: None,
                     tags: None,
                     code_description: None,
@@ -342,6 +404,63 @@ impl CriticAnalyzer {
     }
 }
 
+/// Nearest `.perlcriticrc` in the directory of `file_path` or any ancestor
+pub fn find_profile(file_path: &Path) -> Option<PathBuf> {
+    file_path
+        .parent()?
+        .ancestors()
+        .map(|dir| dir.join(".perlcriticrc"))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Fields of one perlcritic violation line, before conversion to a [`Violation`]
+struct RawViolation<'a> {
+    line: u32,
+    column: u32,
+    severity: u8,
+    policy: &'a str,
+    message: &'a str,
+}
+
+/// Parse a line in [`MACHINE_FORMAT`]
+fn parse_machine_line(line: &str) -> Option<RawViolation<'_>> {
+    let mut parts = line.splitn(6, '\t');
+    let _file = parts.next()?;
+    let line_num = parts.next()?.trim().parse().ok()?;
+    let column = parts.next()?.trim().parse().ok()?;
+    let severity = parts.next()?.trim().parse().ok()?;
+    let policy = parts.next()?.trim();
+    let message = parts.next()?.trim_end();
+    Some(RawViolation { line: line_num, column, severity, policy, message })
+}
+
+/// Parse a line in perlcritic's `--verbose 8` format:
+/// `[Policy] Message at line 5, column 1.  (Severity: 3)`
+fn parse_verbose8_line(line: &str) -> Option<RawViolation<'_>> {
+    let rest = line.trim().strip_prefix('[')?;
+    let (policy, rest) = rest.split_once("] ")?;
+    let (rest, severity) = rest.rsplit_once("(Severity: ")?;
+    let severity = severity.strip_suffix(')')?.trim().parse().ok()?;
+    let (message, location) = rest.trim_end().rsplit_once(" at line ")?;
+    let (line_num, column) = location.strip_suffix('.')?.split_once(", column ")?;
+    Some(RawViolation {
+        line: line_num.trim().parse().ok()?,
+        column: column.trim().parse().ok()?,
+        severity,
+        policy,
+        message,
+    })
+}
+
+/// UTF-16 offset of the character at `column` (0-based) in `line`
+#[cfg(feature = "lsp-compat")]
+fn utf16_column(line: &str, column: u32) -> u32 {
+    let prefix: usize = line.chars().take(column as usize).map(char::len_utf16).sum();
+    // Columns past the end of the line (stale source) keep their character count
+    let missing = (column as usize).saturating_sub(line.chars().count());
+    (prefix + missing) as u32
+}
+
 /// A quick fix for a violation
 #[derive(Debug, Clone)]
 pub struct QuickFix {
@@ -530,10 +649,9 @@ mod tests {
         use super::super::subprocess_runtime::mock::{MockResponse, MockSubprocessRuntime};
 
         let runtime = Arc::new(MockSubprocessRuntime::new());
-        // Mock perlcritic output format: file:line:column:severity:policy:message
-        // Note: The current parser uses splitn(6, ':') which doesn't handle policy names
-        // with '::' well - using a simple policy name for this test
-        let mock_output = b"test.pl:5:1:3:RequireStrict:Code does not use strict\n";
+        // Mock perlcritic output in MACHINE_FORMAT: file, line, column, severity, policy, message
+        let mock_output =
+            b"test.pl\t5\t1\t3\tTestingAndDebugging::RequireUseStrict\tCode before strictures are enabled\n";
         runtime.add_response(MockResponse::success(mock_output.to_vec()));
 
         let config = CriticConfig::default();
@@ -542,7 +660,7 @@ mod tests {
         let result = analyzer.analyze_file(Path::new("test.pl"));
         let violations = must(result);
         assert_eq!(violations.len(), 1);
-        assert_eq!(violations[0].policy, "RequireStrict");
+        assert_eq!(violations[0].policy, "TestingAndDebugging::RequireUseStrict");
         assert_eq!(violations[0].range.start.line, 4); // 0-indexed
 
         let invocations = runtime.invocations();
@@ -606,4 +724,120 @@ mod tests {
         assert!(invocations[0].args.contains(&"--include=RequireUseStrict".to_string()));
         assert!(invocations[0].args.contains(&"--exclude=ProhibitMagicNumbers".to_string()));
     }
+
+    #[test]
+    fn test_canned_output_to_diagnostics() {
+        use super::super::subprocess_runtime::mock::{MockResponse, MockSubprocessRuntime};
+
+        let source = "my $café = 1;\nprint \"ok\";\n";
+        let canned = "\
+lib/Foo.pm\t1\t10\t4\tValuesAndExpressions::ProhibitMagicNumbers\tUnnamed numeric literals make code less maintainable
+[InputOutput::RequireCheckedSyscalls] Return value of flagged function ignored - print at line 2, column 1.  (Severity: 1)
+not a violation line
+";
+        let runtime = Arc::new(MockSubprocessRuntime::new());
+        runtime.add_response(MockResponse::success(canned.as_bytes().to_vec()));
+        let mut analyzer = CriticAnalyzer::new(CriticConfig::default(), runtime);
+
+        let diagnostics = analyzer.diagnostics_for_file(Path::new("lib/Foo.pm"), source);
+        assert_eq!(diagnostics.len(), 2);
+
+        let magic = &diagnostics[0];
+        assert_eq!(magic.code, Some(lsp_types::NumberOrString::String("PC004".to_string())));
+        assert_eq!(magic.source.as_deref(), Some("perlcritic"));
+        assert_eq!(
+            magic.message,
+            "Unnamed numeric literals make code less maintainable \
+             [ValuesAndExpressions::ProhibitMagicNumbers]"
+        );
+        assert_eq!(magic.severity, Some(lsp_types::DiagnosticSeverity::INFORMATION));
+        // Character column 10 on `my $café = 1;` is the literal, after one two-byte char
+        assert_eq!(magic.range.start, lsp_types::Position::new(0, 9));
+        assert_eq!(magic.range.end, lsp_types::Position::new(0, 10));
+
+        let syscall = &diagnostics[1];
+        assert_eq!(syscall.code, Some(lsp_types::NumberOrString::String("PC001".to_string())));
+        assert_eq!(syscall.severity, Some(lsp_types::DiagnosticSeverity::ERROR));
+        assert!(syscall.message.ends_with("[InputOutput::RequireCheckedSyscalls]"));
+        assert_eq!(syscall.range.start, lsp_types::Position::new(1, 0));
+    }
+
+    #[test]
+    fn test_utf16_columns_count_surrogate_pairs() {
+        let violation = Violation {
+            policy: "CodeLayout::ProhibitTrailingWhitespace".to_string(),
+            description: "Found trailing whitespace".to_string(),
+            explanation: String::new(),
+            severity: Severity::Gentle,
+            range: Range {
+                start: Position { byte: 0, line: 0, column: 7 },
+                end: Position { byte: 0, line: 0, column: 8 },
+            },
+            file: "emoji.pl".to_string(),
+        };
+        let analyzer = CriticAnalyzer::new(
+            CriticConfig::default(),
+            Arc::new(super::super::subprocess_runtime::mock::MockSubprocessRuntime::new()),
+        );
+
+        let diagnostics = analyzer.to_diagnostics(&[violation], "# 😀😀 x \n");
+        assert_eq!(diagnostics[0].range.start, lsp_types::Position::new(0, 9));
+        assert_eq!(
+            diagnostics[0].code,
+            Some(lsp_types::NumberOrString::String("PC005".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_missing_perlcritic_yields_no_diagnostics() {
+        use super::super::subprocess_runtime::{SubprocessError, SubprocessOutput};
+
+        struct NotInstalled;
+        impl SubprocessRuntime for NotInstalled {
+            fn run_command(
+                &self,
+                program: &str,
+                _args: &[&str],
+                _stdin: Option<&[u8]>,
+            ) -> Result<SubprocessOutput, SubprocessError> {
+                Err(SubprocessError::new(format!("Failed to start {}: not found", program)))
+            }
+        }
+
+        let mut analyzer = CriticAnalyzer::new(CriticConfig::default(), Arc::new(NotInstalled));
+        assert!(analyzer.diagnostics_for_file(Path::new("test.pl"), "print 1;\n").is_empty());
+    }
+
+    #[test]
+    fn test_min_severity_is_passed_as_severity_argument() {
+        use super::super::subprocess_runtime::mock::MockSubprocessRuntime;
+
+        let runtime = Arc::new(MockSubprocessRuntime::new());
+        let config = CriticConfig { min_severity: Some(3), ..Default::default() };
+        let mut analyzer = CriticAnalyzer::new(config, runtime.clone());
+
+        must(analyzer.analyze_file(Path::new("test.pl")));
+        assert!(runtime.invocations()[0].args.contains(&"--severity=3".to_string()));
+    }
+
+    #[test]
+    fn test_discovered_profile_is_passed() {
+        use super::super::subprocess_runtime::mock::MockSubprocessRuntime;
+
+        let project = must(tempfile::tempdir());
+        let profile = project.path().join(".perlcriticrc");
+        must(std::fs::write(&profile, "severity = 3\n"));
+        let lib = project.path().join("lib").join("My");
+        must(std::fs::create_dir_all(&lib));
+        let file = lib.join("Module.pm");
+
+        assert_eq!(find_profile(&file), Some(profile.clone()));
+
+        let runtime = Arc::new(MockSubprocessRuntime::new());
+        let mut analyzer = CriticAnalyzer::new(CriticConfig::default(), runtime.clone());
+        let _ = analyzer.analyze_file(&file);
+
+        let expected = format!("--profile={}", profile.display());
+        assert!(runtime.invocations()[0].args.contains(&expected));
+    }
 }
@@ -6,6 +6,7 @@
 use super::subprocess_runtime::SubprocessRuntime;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::ops::Range;
 use std::path::Path;
 use std::sync::Arc;
 
@@ -166,6 +167,109 @@ impl PerlTidyConfig {
     }
 }
 
+/// Comment markers delimiting regions the formatter must leave untouched
+///
+/// Defaults to perltidy's own format-skipping markers, `#<<<` and `#>>>`.
+/// A begin marker without a matching end marker protects the rest of the file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FormatSkipMarkers {
+    /// Comment that opens a protected region
+    pub begin: String,
+    /// Comment that closes a protected region
+    pub end: String,
+}
+
+impl Default for FormatSkipMarkers {
+    fn default() -> Self {
+        Self { begin: "#<<<".to_string(), end: "#>>>".to_string() }
+    }
+}
+
+impl FormatSkipMarkers {
+    /// Create markers with custom begin and end comments
+    pub fn new(begin: impl Into<String>, end: impl Into<String>) -> Self {
+        Self { begin: begin.into(), end: end.into() }
+    }
+
+    /// Check whether a line opens a protected region
+    pub fn is_begin(&self, line: &str) -> bool {
+        line.trim_start().starts_with(&self.begin)
+    }
+
+    /// Check whether a line closes a protected region
+    pub fn is_end(&self, line: &str) -> bool {
+        line.trim_start().starts_with(&self.end)
+    }
+
+    /// Arguments that make perltidy honor these markers
+    ///
+    /// Perltidy skips `#<<<`/`#>>>` regions natively, so the default markers
+    /// need no extra arguments.
+    pub fn perltidy_args(&self) -> Vec<String> {
+        if *self == Self::default() {
+            return Vec::new();
+        }
+        vec![
+            "--format-skipping".to_string(),
+            format!("--format-skipping-begin={}", self.begin),
+            format!("--format-skipping-end={}", self.end),
+        ]
+    }
+
+    /// Protected regions of `code` as half-open line index ranges
+    ///
+    /// Each region includes its marker lines. An unclosed region extends to EOF.
+    pub fn regions(&self, code: &str) -> Vec<Range<usize>> {
+        let mut regions = Vec::new();
+        let mut open: Option<usize> = None;
+        let mut line_count = 0;
+
+        for (index, line) in code.lines().enumerate() {
+            line_count = index + 1;
+            match open {
+                None if self.is_begin(line) => open = Some(index),
+                Some(start) if self.is_end(line) => {
+                    regions.push(start..index + 1);
+                    open = None;
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(start) = open {
+            regions.push(start..line_count);
+        }
+
+        regions
+    }
+
+    /// Copy protected regions of `original` back into `formatted` verbatim
+    ///
+    /// Regions are matched by position. If the formatter added or removed
+    /// markers, `formatted` is returned unchanged.
+    pub fn restore_regions(&self, original: &str, formatted: &str) -> String {
+        let original_regions = self.regions(original);
+        let formatted_regions = self.regions(formatted);
+        if original_regions.is_empty() || original_regions.len() != formatted_regions.len() {
+            return formatted.to_string();
+        }
+
+        let original_lines: Vec<&str> = original.split_inclusive('\n').collect();
+        let formatted_lines: Vec<&str> = formatted.split_inclusive('\n').collect();
+
+        let mut result = String::with_capacity(formatted.len());
+        let mut next = 0;
+        for (original_region, formatted_region) in original_regions.iter().zip(&formatted_regions) {
+            formatted_lines[next..formatted_region.start].iter().for_each(|l| result.push_str(l));
+            original_lines[original_region.clone()].iter().for_each(|l| result.push_str(l));
+            next = formatted_region.end;
+        }
+        formatted_lines[next..].iter().for_each(|l| result.push_str(l));
+
+        result
+    }
+}
+
 /// Perltidy formatter
 pub struct PerlTidyFormatter {
     /// Configuration settings for perltidy invocation.
@@ -335,12 +439,20 @@ pub struct FormatSuggestion {
 pub struct BuiltInFormatter {
     /// Configuration settings controlling formatting behavior.
     config: PerlTidyConfig,
+    /// Markers for regions that are copied through unformatted.
+    skip_markers: FormatSkipMarkers,
 }
 
 impl BuiltInFormatter {
     /// Creates a new built-in formatter with the given configuration.
     pub fn new(config: PerlTidyConfig) -> Self {
-        Self { config }
+        Self { config, skip_markers: FormatSkipMarkers::default() }
+    }
+
+    /// Use custom format-skipping markers instead of `#<<<` / `#>>>`
+    pub fn with_skip_markers(mut self, markers: FormatSkipMarkers) -> Self {
+        self.skip_markers = markers;
+        self
     }
 
     /// Basic formatting without perltidy
@@ -353,9 +465,19 @@ impl BuiltInFormatter {
             " ".repeat(self.config.indent_columns.unwrap_or(4) as usize)
         };
 
+        let mut skipping = false;
+
         for line in code.lines() {
             let trimmed = line.trim();
 
+            // Copy protected regions through verbatim, markers included
+            if skipping || self.skip_markers.is_begin(line) {
+                skipping = !(skipping && self.skip_markers.is_end(line));
+                result.push_str(line);
+                result.push('\n');
+                continue;
+            }
+
             // Decrease indent for closing braces
             if trimmed.starts_with('}') || trimmed.starts_with(')') || trimmed.starts_with(']') {
                 indent_level = indent_level.saturating_sub(1);
@@ -415,6 +537,56 @@ mod tests {
         assert!(formatted.contains("    print")); // Should be indented
     }
 
+    #[test]
+    fn test_builtin_formatter_preserves_skip_region() {
+        let formatter = BuiltInFormatter::new(PerlTidyConfig::default());
+
+        let code = "if ($x) {\nprint $x;\n#<<<\nmy @t = (1,   2,\n          3,   4);\n#>>>\n}\n";
+        let formatted = formatter.format(code);
+
+        assert!(formatted.contains("    print $x;"));
+        assert!(formatted.contains("#<<<\nmy @t = (1,   2,\n          3,   4);\n#>>>\n"));
+    }
+
+    #[test]
+    fn test_builtin_formatter_unclosed_skip_region_protects_to_eof() {
+        let formatter = BuiltInFormatter::new(PerlTidyConfig::default());
+
+        let code = "if ($x) {\nprint $x;\n#<<<\n  print   $y;\n}\n";
+        let formatted = formatter.format(code);
+
+        assert_eq!(formatted, "if ($x) {\n    print $x;\n#<<<\n  print   $y;\n}\n");
+    }
+
+    #[test]
+    fn test_skip_marker_regions() {
+        let markers = FormatSkipMarkers::default();
+        let code = "a;\n#<<<\nb;\n#>>>\nc;\n  #<<< again\nd;\n";
+
+        assert_eq!(markers.regions(code), vec![1..4, 5..7]);
+    }
+
+    #[test]
+    fn test_skip_marker_restore_regions() {
+        let markers = FormatSkipMarkers::default();
+        let original = "my $a=1;\n#<<<\nmy %h = (a   => 1);\n#>>>\nmy $b=2;\n";
+        let formatted = "my $a = 1;\n#<<<\nmy %h = ( a => 1 );\n#>>>\nmy $b = 2;\n";
+
+        assert_eq!(
+            markers.restore_regions(original, formatted),
+            "my $a = 1;\n#<<<\nmy %h = (a   => 1);\n#>>>\nmy $b = 2;\n"
+        );
+    }
+
+    #[test]
+    fn test_custom_skip_markers_perltidy_args() {
+        assert!(FormatSkipMarkers::default().perltidy_args().is_empty());
+
+        let args = FormatSkipMarkers::new("## tidy off", "## tidy on").perltidy_args();
+        assert!(args.contains(&"--format-skipping-begin=## tidy off".to_string()));
+        assert!(args.contains(&"--format-skipping-end=## tidy on".to_string()));
+    }
+
     #[test]
     fn test_formatter_with_mock_runtime() {
         use super::super::subprocess_runtime::mock::{MockResponse, MockSubprocessRuntime};
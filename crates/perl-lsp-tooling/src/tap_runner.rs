@@ -0,0 +1,85 @@
+//! TAP test execution
+//!
+//! Runs a Perl test file with `prove` (or `perl` when `prove` is not
+//! installed) through the subprocess runtime and parses the TAP stream.
+
+use super::subprocess_runtime::SubprocessRuntime;
+use perl_tdd_support::test_runner::{TestRunResult, parse_tap};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Runs test files and summarizes their TAP output
+pub struct TapRunner {
+    /// Subprocess runtime for executing prove/perl
+    runtime: Arc<dyn SubprocessRuntime>,
+}
+
+impl TapRunner {
+    /// Creates a new runner with the given runtime.
+    pub fn new(runtime: Arc<dyn SubprocessRuntime>) -> Self {
+        Self { runtime }
+    }
+
+    /// Creates a new runner with the OS subprocess runtime (non-WASM only).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_os_runtime() -> Self {
+        use super::subprocess_runtime::OsSubprocessRuntime;
+        Self::new(Arc::new(OsSubprocessRuntime::new()))
+    }
+
+    /// Run a test file and parse its TAP output
+    ///
+    /// `prove --merge` keeps Test::More's stderr diagnostics next to the
+    /// failing test. The `perl` fallback captures the streams separately, so
+    /// stderr is parsed after stdout.
+    pub fn run_file(&self, file_path: &Path) -> Result<TestRunResult, String> {
+        let path_str = file_path.to_string_lossy();
+
+        // SECURITY: `--` keeps a file named like a flag from being read as one
+        let output = match self.runtime.run_command(
+            "prove",
+            &["-v", "--merge", "-Ilib", "--", &path_str],
+            None,
+        ) {
+            Ok(output) => output,
+            Err(_) => self
+                .runtime
+                .run_command("perl", &["-Ilib", "--", &path_str], None)
+                .map_err(|e| format!("Failed to run test: {}", e))?,
+        };
+
+        let mut tap = output.stdout_lossy();
+        tap.push('\n');
+        tap.push_str(&output.stderr_lossy());
+        Ok(parse_tap(&tap))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subprocess_runtime::mock::{MockResponse, MockSubprocessRuntime};
+    use perl_tdd_support::must;
+
+    #[test]
+    fn test_run_file_parses_prove_output() {
+        let runtime = Arc::new(MockSubprocessRuntime::new());
+        let stdout = "t/basic.t .. \n1..2\nok 1 - loads\nnot ok 2 - adds\n#   Failed test 'adds'\n#   at t/basic.t line 8.\nFailed 1/2 subtests\n";
+        runtime.add_response(MockResponse {
+            stdout: stdout.as_bytes().to_vec(),
+            stderr: Vec::new(),
+            status_code: 1,
+        });
+
+        let runner = TapRunner::new(runtime.clone());
+        let result = must(runner.run_file(Path::new("t/basic.t")));
+
+        assert_eq!(result.planned, Some(2));
+        assert_eq!((result.passed, result.failed), (1, 1));
+        assert_eq!(result.cases[1].failure_line, Some(8));
+
+        let invocations = runtime.invocations();
+        assert_eq!(invocations[0].program, "prove");
+        assert_eq!(invocations[0].args, ["-v", "--merge", "-Ilib", "--", "t/basic.t"]);
+    }
+}
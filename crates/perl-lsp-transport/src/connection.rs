@@ -0,0 +1,84 @@
+//! Byte-stream connections the LSP message loop runs over
+//!
+//! A [`Transport`] yields a buffered reading half and a writing half. The
+//! server frames messages on both identically, so stdio and TCP clients see
+//! the same Content-Length protocol.
+
+use std::io::{self, BufReader, Stdin, Stdout};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+
+/// A connection to a single LSP client
+pub trait Transport {
+    /// Buffered half that incoming messages are read from
+    type Reader: io::BufRead + Send + 'static;
+    /// Half that responses and notifications are written to
+    type Writer: io::Write + Send + 'static;
+
+    /// Split the connection into its reading and writing halves
+    fn split(self) -> io::Result<(Self::Reader, Self::Writer)>;
+}
+
+/// Standard input/output, the default editor transport
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StdioTransport;
+
+impl Transport for StdioTransport {
+    type Reader = BufReader<Stdin>;
+    type Writer = Stdout;
+
+    fn split(self) -> io::Result<(Self::Reader, Self::Writer)> {
+        Ok((BufReader::new(io::stdin()), io::stdout()))
+    }
+}
+
+/// A TCP connection accepted from a listening socket
+#[derive(Debug)]
+pub struct TcpTransport {
+    stream: TcpStream,
+    peer_addr: SocketAddr,
+}
+
+impl TcpTransport {
+    /// Bind a listener on `addr`
+    ///
+    /// Port `0` picks an ephemeral port; read it back with
+    /// [`TcpListener::local_addr`].
+    pub fn bind(addr: impl ToSocketAddrs) -> io::Result<TcpListener> {
+        TcpListener::bind(addr)
+    }
+
+    /// Block until one client connects to `listener`
+    pub fn accept(listener: &TcpListener) -> io::Result<Self> {
+        let (stream, peer_addr) = listener.accept()?;
+        stream.set_nodelay(true)?;
+        Ok(Self { stream, peer_addr })
+    }
+
+    /// Address of the connected client
+    pub fn peer_addr(&self) -> SocketAddr {
+        self.peer_addr
+    }
+}
+
+impl Transport for TcpTransport {
+    type Reader = BufReader<TcpStream>;
+    type Writer = TcpStream;
+
+    fn split(self) -> io::Result<(Self::Reader, Self::Writer)> {
+        let writer = self.stream.try_clone()?;
+        Ok((BufReader::new(self.stream), writer))
+    }
+}
+
+/// Whether `err` means the client went away rather than the server failing
+///
+/// Callers treat these as a clean end of the session, like EOF.
+pub fn is_disconnect(err: &io::Error) -> bool {
+    matches!(
+        err.kind(),
+        io::ErrorKind::BrokenPipe
+            | io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::UnexpectedEof
+    )
+}
@@ -12,6 +12,8 @@
 //! - [`write_message`] - Write an LSP response with proper framing
 //! - [`write_notification`] - Write an LSP notification with proper framing
 //! - [`log_response`] - Debug logging for outgoing responses
+//! - [`Transport`] - A client connection split into reading and writing halves,
+//!   implemented by [`StdioTransport`] and [`TcpTransport`]
 //!
 //! # Example
 //!
@@ -39,6 +41,8 @@
 #![deny(unsafe_code)]
 #![warn(missing_docs)]
 
+mod connection;
 mod framing;
 
+pub use connection::{StdioTransport, TcpTransport, Transport, is_disconnect};
 pub use framing::{log_response, read_message, write_message, write_notification};
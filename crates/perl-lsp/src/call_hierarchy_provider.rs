@@ -1,7 +1,10 @@
 use perl_parser::PositionMapper;
 use perl_parser::ast::{Node, NodeKind};
+use perl_parser::workspace::inheritance::parent_declaration;
 use perl_position_tracking::{WirePosition, WireRange};
 use serde_json::{Value, json};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
 
 /// LSP wire type alias for position (0-based line/character with UTF-16 counting)
 pub type Position = WirePosition;
@@ -27,6 +30,8 @@ pub struct CallHierarchyItem {
     pub selection_range: Range,
     /// Optional additional detail about the symbol
     pub detail: Option<String>,
+    /// Package the symbol belongs to, when known
+    pub package: Option<String>,
 }
 
 /// Call Hierarchy Provider
@@ -34,6 +39,7 @@ pub struct CallHierarchyProvider {
     source: String,
     uri: String,
     position_mapper: PositionMapper,
+    hierarchy: Option<Arc<ClassHierarchy>>,
 }
 
 impl CallHierarchyProvider {
@@ -61,38 +67,72 @@ impl CallHierarchyProvider {
         // Validate that URI is well-formed (basic security check)
         let uri = if uri.is_empty() { "file:///unknown".to_string() } else { uri };
         let position_mapper = PositionMapper::new(&source);
-        Self { source, uri, position_mapper }
+        Self { source, uri, position_mapper, hierarchy: None }
+    }
+
+    /// Resolve method calls through the given class hierarchy
+    ///
+    /// Without a hierarchy, calls are matched by name only.
+    pub fn with_class_hierarchy(mut self, hierarchy: Arc<ClassHierarchy>) -> Self {
+        self.hierarchy = Some(hierarchy);
+        self
     }
 
     /// Prepare call hierarchy - find items at a given position
     pub fn prepare(&self, ast: &Node, line: u32, character: u32) -> Option<Vec<CallHierarchyItem>> {
         let byte_offset = self.position_to_offset(line, character);
-        let item = self.find_callable_at_position(ast, byte_offset)?;
+        let scopes = PackageScopes::build(ast);
+        let item = self.find_callable_at_position(ast, byte_offset, &scopes)?;
         Some(vec![item])
     }
 
     /// Get incoming calls (callers of a function)
+    ///
+    /// When a class hierarchy is attached and the item's package is known, method
+    /// calls are only counted if their dispatch can reach the item's package.
     pub fn incoming_calls(
         &self,
         ast: &Node,
         item: &CallHierarchyItem,
     ) -> Vec<CallHierarchyIncomingCall> {
+        let scopes = PackageScopes::build(ast);
+        let receivers = match (&self.hierarchy, &item.package) {
+            (Some(hierarchy), Some(package)) => {
+                Some(hierarchy.dispatch_packages(package, &item.name))
+            }
+            _ => None,
+        };
+        let target = IncomingTarget {
+            name: &item.name,
+            package: item.package.as_deref(),
+            receivers: receivers.as_ref(),
+        };
+
         let mut calls = Vec::new();
-        self.find_incoming_calls(ast, &item.name, &mut calls, None);
+        self.find_incoming_calls(ast, &target, &scopes, &mut calls, None);
         calls
     }
 
     /// Get outgoing calls (functions called by this function)
+    ///
+    /// With a class hierarchy attached, `SUPER::` calls resolve to the parent
+    /// implementation and dynamically dispatched calls list every candidate.
     pub fn outgoing_calls(
         &self,
         ast: &Node,
         item: &CallHierarchyItem,
     ) -> Vec<CallHierarchyOutgoingCall> {
+        let scopes = PackageScopes::build(ast);
+        let func_node = self
+            .find_function_by_name(ast, &item.name, item.package.as_deref(), &scopes)
+            .or_else(|| self.find_function_by_name(ast, &item.name, None, &scopes));
+
         // Find the function node
-        if let Some(func_node) = self.find_function_by_name(ast, &item.name) {
+        if let Some(func_node) = func_node {
+            let package = scopes.package_at(func_node.location.start);
             let mut calls = Vec::new();
-            if let NodeKind::Subroutine { body, .. } = &func_node.kind {
-                self.find_outgoing_calls(body, &mut calls);
+            if let NodeKind::Subroutine { body: Some(body), .. } = &func_node.kind {
+                self.find_outgoing_calls(body, package, &mut calls);
             }
             calls
         } else {
@@ -101,33 +141,19 @@ impl CallHierarchyProvider {
     }
 
     /// Find a callable item at the given position
-    fn find_callable_at_position(&self, node: &Node, offset: usize) -> Option<CallHierarchyItem> {
+    fn find_callable_at_position(
+        &self,
+        node: &Node,
+        offset: usize,
+        scopes: &PackageScopes,
+    ) -> Option<CallHierarchyItem> {
         if offset >= node.location.start && offset <= node.location.end {
             match &node.kind {
                 NodeKind::Subroutine { name, prototype: _, signature, name_span, .. } => {
                     if let Some(name_str) = name {
-                        if let Some(span) = name_span {
-                            if offset >= span.start && offset <= span.end {
-                                let range = self.node_to_range(node);
-                                let selection_range =
-                                    self.selection_range_from_name_span(name_span, &range);
-
-                                let detail = if signature.is_some() {
-                                    Some("(signature)".to_string())
-                                } else {
-                                    None
-                                };
-
-                                return Some(CallHierarchyItem {
-                                    name: name_str.clone(),
-                                    kind: "function".to_string(),
-                                    uri: self.uri.clone(),
-                                    range,
-                                    selection_range,
-                                    detail,
-                                });
-                            }
-                        } else {
+                        let on_name =
+                            name_span.is_none_or(|span| offset >= span.start && offset <= span.end);
+                        if on_name {
                             let range = self.node_to_range(node);
                             let selection_range =
                                 self.selection_range_from_name_span(name_span, &range);
@@ -145,23 +171,35 @@ impl CallHierarchyProvider {
                                 range,
                                 selection_range,
                                 detail,
+                                package: Some(scopes.package_at(node.location.start).to_string()),
                             });
                         }
                     }
                 }
-                NodeKind::MethodCall { method, .. } => {
+                NodeKind::MethodCall { method, object, .. } => {
                     let range = self.node_to_range(node);
+                    let package = scopes.package_at(node.location.start);
+                    let (name, package) = match self.method_targets(method, object, package).first()
+                    {
+                        Some(target) => (target.name.clone(), target.package.clone()),
+                        None => (method.clone(), None),
+                    };
                     return Some(CallHierarchyItem {
-                        name: method.clone(),
+                        name,
                         kind: "method".to_string(),
                         uri: self.uri.clone(),
                         range,
                         selection_range: range,
                         detail: None,
+                        package,
                     });
                 }
                 NodeKind::FunctionCall { name, .. } => {
                     let range = self.node_to_range(node);
+                    let package = match name.rsplit_once("::") {
+                        Some((package, _)) => package,
+                        None => scopes.package_at(node.location.start),
+                    };
                     return Some(CallHierarchyItem {
                         name: name.clone(),
                         kind: "function".to_string(),
@@ -169,13 +207,14 @@ impl CallHierarchyProvider {
                         range,
                         selection_range: range,
                         detail: None,
+                        package: Some(package.to_string()),
                     });
                 }
                 _ => {}
             }
 
             // Check children
-            self.visit_children(node, |child| self.find_callable_at_position(child, offset))
+            self.visit_children(node, |child| self.find_callable_at_position(child, offset, scopes))
         } else {
             None
         }
@@ -185,7 +224,8 @@ impl CallHierarchyProvider {
     fn find_incoming_calls(
         &self,
         node: &Node,
-        target_name: &str,
+        target: &IncomingTarget<'_>,
+        scopes: &PackageScopes,
         calls: &mut Vec<CallHierarchyIncomingCall>,
         current_function: Option<&CallHierarchyItem>,
     ) {
@@ -201,48 +241,30 @@ impl CallHierarchyProvider {
                         range,
                         selection_range,
                         detail: None,
+                        package: Some(scopes.package_at(node.location.start).to_string()),
                     };
 
                     // Search within this function
                     self.visit_children(node, |child| {
-                        self.find_incoming_calls(child, target_name, calls, Some(&item));
+                        self.find_incoming_calls(child, target, scopes, calls, Some(&item));
                         None::<()>
                     });
+                    return;
                 }
             }
             NodeKind::FunctionCall { name, .. } => {
-                if name == target_name {
-                    if let Some(from) = current_function {
-                        let ranges = vec![self.node_to_range(node)];
-
-                        // Check if we already have a call from this function
-                        if let Some(existing) = calls.iter_mut().find(|c| c.from.name == from.name)
-                        {
-                            existing.from_ranges.extend(ranges);
-                        } else {
-                            calls.push(CallHierarchyIncomingCall {
-                                from: from.clone(),
-                                from_ranges: ranges,
-                            });
-                        }
-                    }
+                let matches = name == target.name
+                    || name.rsplit_once("::").is_some_and(|(package, function)| {
+                        function == target.name && target.package == Some(package)
+                    });
+                if matches {
+                    Self::record_incoming_call(calls, current_function, self.node_to_range(node));
                 }
             }
-            NodeKind::MethodCall { method, .. } => {
-                if method == target_name {
-                    if let Some(from) = current_function {
-                        let ranges = vec![self.node_to_range(node)];
-
-                        if let Some(existing) = calls.iter_mut().find(|c| c.from.name == from.name)
-                        {
-                            existing.from_ranges.extend(ranges);
-                        } else {
-                            calls.push(CallHierarchyIncomingCall {
-                                from: from.clone(),
-                                from_ranges: ranges,
-                            });
-                        }
-                    }
+            NodeKind::MethodCall { method, object, .. } => {
+                let package = scopes.package_at(node.location.start);
+                if self.method_call_reaches(method, object, package, target) {
+                    Self::record_incoming_call(calls, current_function, self.node_to_range(node));
                 }
             }
             _ => {}
@@ -250,76 +272,243 @@ impl CallHierarchyProvider {
 
         // Visit children
         self.visit_children(node, |child| {
-            self.find_incoming_calls(child, target_name, calls, current_function);
+            self.find_incoming_calls(child, target, scopes, calls, current_function);
             None::<()>
         });
     }
 
+    /// Add a call site to the incoming call for the enclosing function
+    fn record_incoming_call(
+        calls: &mut Vec<CallHierarchyIncomingCall>,
+        current_function: Option<&CallHierarchyItem>,
+        range: Range,
+    ) {
+        let Some(from) = current_function else {
+            return;
+        };
+
+        // Check if we already have a call from this function
+        if let Some(existing) =
+            calls.iter_mut().find(|c| c.from.name == from.name && c.from.package == from.package)
+        {
+            existing.from_ranges.push(range);
+        } else {
+            calls.push(CallHierarchyIncomingCall { from: from.clone(), from_ranges: vec![range] });
+        }
+    }
+
+    /// Decide whether a method call can dispatch to the target
+    fn method_call_reaches(
+        &self,
+        method: &str,
+        object: &Node,
+        package: &str,
+        target: &IncomingTarget<'_>,
+    ) -> bool {
+        let (qualifier, name) = match method.rsplit_once("::") {
+            Some((qualifier, name)) => (Some(qualifier), name),
+            None => (None, method),
+        };
+        if name != target.name {
+            return false;
+        }
+
+        let (Some(hierarchy), Some(target_package), Some(receivers)) =
+            (&self.hierarchy, target.package, target.receivers)
+        else {
+            return qualifier.is_none() || qualifier == Some("SUPER");
+        };
+
+        match qualifier {
+            Some("SUPER") => hierarchy
+                .resolve_super(package, name)
+                .is_some_and(|def| def.package.as_deref() == Some(target_package)),
+            Some(qualifier) => qualifier == target_package,
+            None => match Invocant::classify(object) {
+                Invocant::Class(class) => receivers.contains(class),
+                // `$self` may be an instance of the current package or any subclass
+                Invocant::Current => {
+                    receivers.contains(package)
+                        || hierarchy.subclasses(package).iter().any(|sub| receivers.contains(sub))
+                }
+                Invocant::Unknown => true,
+            },
+        }
+    }
+
     /// Find all function calls within a node
-    fn find_outgoing_calls(&self, node: &Node, calls: &mut Vec<CallHierarchyOutgoingCall>) {
+    fn find_outgoing_calls(
+        &self,
+        node: &Node,
+        package: &str,
+        calls: &mut Vec<CallHierarchyOutgoingCall>,
+    ) {
         match &node.kind {
             NodeKind::FunctionCall { name, .. } => {
-                let item = CallHierarchyItem {
-                    name: name.clone(),
-                    kind: "function".to_string(),
-                    uri: self.uri.clone(),
-                    range: self.node_to_range(node),
-                    selection_range: self.node_to_range(node),
-                    detail: None,
-                };
-
-                let ranges = vec![self.node_to_range(node)];
-
-                // Check if we already have a call to this function
-                if let Some(existing) = calls.iter_mut().find(|c| &c.to.name == name) {
-                    existing.from_ranges.extend(ranges);
-                } else {
-                    calls.push(CallHierarchyOutgoingCall { to: item, from_ranges: ranges });
+                let mut targets = self.function_targets(name, package);
+                if targets.is_empty() {
+                    targets.push(CallHierarchyItem {
+                        name: name.clone(),
+                        kind: "function".to_string(),
+                        uri: self.uri.clone(),
+                        range: self.node_to_range(node),
+                        selection_range: self.node_to_range(node),
+                        detail: None,
+                        package: None,
+                    });
                 }
+                Self::record_outgoing_calls(calls, targets, self.node_to_range(node));
             }
             NodeKind::MethodCall { method, object, .. } => {
-                let detail = if let NodeKind::Variable { name, .. } = &object.kind {
-                    Some(format!("on ${}", name))
-                } else {
-                    None
-                };
-
-                let item = CallHierarchyItem {
-                    name: method.clone(),
-                    kind: "method".to_string(),
-                    uri: self.uri.clone(),
-                    range: self.node_to_range(node),
-                    selection_range: self.node_to_range(node),
-                    detail,
-                };
-
-                let ranges = vec![self.node_to_range(node)];
+                let mut targets = self.method_targets(method, object, package);
+                if targets.is_empty() {
+                    let detail = if let NodeKind::Variable { name, .. } = &object.kind {
+                        Some(format!("on ${}", name))
+                    } else {
+                        None
+                    };
 
-                if let Some(existing) = calls.iter_mut().find(|c| &c.to.name == method) {
-                    existing.from_ranges.extend(ranges);
-                } else {
-                    calls.push(CallHierarchyOutgoingCall { to: item, from_ranges: ranges });
+                    targets.push(CallHierarchyItem {
+                        name: method.clone(),
+                        kind: "method".to_string(),
+                        uri: self.uri.clone(),
+                        range: self.node_to_range(node),
+                        selection_range: self.node_to_range(node),
+                        detail,
+                        package: None,
+                    });
                 }
+                Self::record_outgoing_calls(calls, targets, self.node_to_range(node));
             }
             _ => {}
         }
 
         // Visit children
         self.visit_children(node, |child| {
-            self.find_outgoing_calls(child, calls);
+            self.find_outgoing_calls(child, package, calls);
             None::<()>
         });
     }
 
-    /// Find a function by name
-    fn find_function_by_name<'a>(&self, node: &'a Node, target_name: &str) -> Option<&'a Node> {
+    /// Add a call site to the outgoing call of every candidate target
+    fn record_outgoing_calls(
+        calls: &mut Vec<CallHierarchyOutgoingCall>,
+        targets: Vec<CallHierarchyItem>,
+        range: Range,
+    ) {
+        for to in targets {
+            // Check if we already have a call to this function
+            if let Some(existing) = calls
+                .iter_mut()
+                .find(|c| c.to.name == to.name && c.to.package == to.package && c.to.uri == to.uri)
+            {
+                existing.from_ranges.push(range);
+            } else {
+                calls.push(CallHierarchyOutgoingCall { to, from_ranges: vec![range] });
+            }
+        }
+    }
+
+    /// Definitions a plain function call made from `package` can reach
+    fn function_targets(&self, name: &str, package: &str) -> Vec<CallHierarchyItem> {
+        let Some(hierarchy) = &self.hierarchy else {
+            return Vec::new();
+        };
+        let (package, name) = name.rsplit_once("::").unwrap_or((package, name));
+        hierarchy.definition(package, name).into_iter().cloned().collect()
+    }
+
+    /// Definitions a method call made from `package` can dispatch to
+    fn method_targets(&self, method: &str, object: &Node, package: &str) -> Vec<CallHierarchyItem> {
+        let Some(hierarchy) = &self.hierarchy else {
+            return Vec::new();
+        };
+
+        if let Some(name) = method.strip_prefix("SUPER::") {
+            return hierarchy.resolve_super(package, name).into_iter().cloned().collect();
+        }
+        if let Some((class, name)) = method.rsplit_once("::") {
+            return hierarchy.resolve_method(class, name).into_iter().cloned().collect();
+        }
+
+        match Invocant::classify(object) {
+            Invocant::Class(class) => {
+                hierarchy.resolve_method(class, method).into_iter().cloned().collect()
+            }
+            // The inherited implementation plus every override a subclass instance could reach
+            Invocant::Current => {
+                let mut targets: Vec<CallHierarchyItem> =
+                    hierarchy.resolve_method(package, method).into_iter().cloned().collect();
+                for subclass in hierarchy.subclasses(package) {
+                    if let Some(def) = hierarchy.definition(&subclass, method) {
+                        if !targets.iter().any(|t| t.package == def.package) {
+                            targets.push(def.clone());
+                        }
+                    }
+                }
+                targets
+            }
+            Invocant::Unknown => hierarchy.definitions(method).to_vec(),
+        }
+    }
+
+    /// Find a function by name, optionally restricted to a package
+    fn find_function_by_name<'a>(
+        &self,
+        node: &'a Node,
+        target_name: &str,
+        package: Option<&str>,
+        scopes: &PackageScopes,
+    ) -> Option<&'a Node> {
         if let NodeKind::Subroutine { name, .. } = &node.kind {
-            if name.as_ref() == Some(&target_name.to_string()) {
+            if name.as_deref() == Some(target_name)
+                && package.is_none_or(|p| scopes.package_at(node.location.start) == p)
+            {
                 return Some(node);
             }
         }
 
-        self.visit_children(node, |child| self.find_function_by_name(child, target_name))
+        self.visit_children(node, |child| {
+            self.find_function_by_name(child, target_name, package, scopes)
+        })
+    }
+
+    /// Record sub definitions and inheritance declarations into a class hierarchy
+    fn collect_class_info(
+        &self,
+        node: &Node,
+        scopes: &PackageScopes,
+        hierarchy: &mut ClassHierarchy,
+    ) {
+        let package = || scopes.package_at(node.location.start);
+        match &node.kind {
+            NodeKind::Subroutine { name: Some(name), name_span, .. } => {
+                let range = self.node_to_range(node);
+                let selection_range = self.selection_range_from_name_span(name_span, &range);
+                hierarchy.add_definition(CallHierarchyItem {
+                    name: name.clone(),
+                    kind: "function".to_string(),
+                    uri: self.uri.clone(),
+                    range,
+                    selection_range,
+                    detail: Some(package().to_string()),
+                    package: Some(package().to_string()),
+                });
+            }
+            _ => {
+                if let Some(decl) = parent_declaration(node) {
+                    let child = decl.class.unwrap_or_else(package);
+                    for parent in &decl.parents {
+                        hierarchy.add_parent(child, parent);
+                    }
+                }
+            }
+        }
+
+        self.visit_children(node, |child| {
+            self.collect_class_info(child, scopes, hierarchy);
+            None::<()>
+        });
     }
 
     /// Visit children of a node
@@ -342,6 +531,11 @@ impl CallHierarchyProvider {
                     }
                 }
             }
+            NodeKind::Package { block: Some(block), .. } | NodeKind::Class { body: block, .. } => {
+                if let Some(result) = f(block) {
+                    return Some(result);
+                }
+            }
             NodeKind::ExpressionStatement { expression } => {
                 if let Some(result) = f(expression) {
                     return Some(result);
@@ -417,7 +611,7 @@ impl CallHierarchyProvider {
                         }
                     }
                 }
-                if let Some(result) = f(body) {
+                if let Some(result) = body.as_deref().and_then(&mut f) {
                     return Some(result);
                 }
             }
@@ -536,6 +730,191 @@ impl CallHierarchyProvider {
     }
 }
 
+/// Method being looked up by an incoming call query
+struct IncomingTarget<'a> {
+    name: &'a str,
+    package: Option<&'a str>,
+    /// Packages whose method lookup for `name` ends in `package`
+    receivers: Option<&'a BTreeSet<String>>,
+}
+
+/// What a method call's invocant tells us about the receiving class
+enum Invocant<'a> {
+    /// `Class->method`
+    Class(&'a str),
+    /// `$self->method`, `$class->method` or `__PACKAGE__->method`
+    Current,
+    /// Any other expression; the class is only known at runtime
+    Unknown,
+}
+
+impl<'a> Invocant<'a> {
+    fn classify(object: &'a Node) -> Self {
+        match &object.kind {
+            NodeKind::Identifier { name } if name == "__PACKAGE__" => Invocant::Current,
+            NodeKind::Identifier { name } => Invocant::Class(name),
+            NodeKind::Variable { sigil, name }
+                if sigil == "$" && (name == "self" || name == "class") =>
+            {
+                Invocant::Current
+            }
+            _ => Invocant::Unknown,
+        }
+    }
+}
+
+/// Byte ranges covered by each `package` declaration in a file
+struct PackageScopes {
+    scopes: Vec<(usize, usize, String)>,
+}
+
+impl PackageScopes {
+    fn build(ast: &Node) -> Self {
+        let mut scopes = Vec::new();
+        Self::collect(ast, &mut scopes);
+        Self { scopes }
+    }
+
+    fn collect(node: &Node, scopes: &mut Vec<(usize, usize, String)>) {
+        let statements = match &node.kind {
+            NodeKind::Program { statements } | NodeKind::Block { statements } => statements,
+            _ => return,
+        };
+
+        // `package Foo;` runs until the next statement-form package in the same list
+        let mut open: Option<(usize, String)> = None;
+        for stmt in statements {
+            match &stmt.kind {
+                NodeKind::Package { name, block: Some(block), .. }
+                | NodeKind::Class { name, body: block, .. } => {
+                    scopes.push((stmt.location.start, stmt.location.end, name.clone()));
+                    Self::collect(block, scopes);
+                }
+                NodeKind::Package { name, block: None, .. } => {
+                    if let Some((start, name)) = open.take() {
+                        scopes.push((start, stmt.location.start, name));
+                    }
+                    open = Some((stmt.location.start, name.clone()));
+                }
+                NodeKind::Block { .. } => Self::collect(stmt, scopes),
+                _ => {}
+            }
+        }
+        if let Some((start, name)) = open {
+            scopes.push((start, node.location.end, name));
+        }
+    }
+
+    /// Innermost package enclosing `offset`, defaulting to `main`
+    fn package_at(&self, offset: usize) -> &str {
+        self.scopes
+            .iter()
+            .filter(|(start, end, _)| offset >= *start && offset <= *end)
+            .max_by_key(|(start, _, _)| *start)
+            .map_or("main", |(_, _, name)| name.as_str())
+    }
+}
+
+/// Package inheritance and sub definitions gathered across documents
+///
+/// Built from `use parent`, `use base`, `@ISA` and `class :isa` declarations, and used to
+/// resolve method dispatch the way Perl's default depth-first MRO does.
+#[derive(Debug, Default, Clone)]
+pub struct ClassHierarchy {
+    /// Direct parents of each package, in declaration order
+    parents: BTreeMap<String, Vec<String>>,
+    /// Sub definitions keyed by sub name
+    definitions: BTreeMap<String, Vec<CallHierarchyItem>>,
+}
+
+impl ClassHierarchy {
+    /// Create an empty class hierarchy
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the packages, parents and subs declared in a document
+    pub fn add_document(&mut self, uri: &str, source: &str, ast: &Node) {
+        let provider = CallHierarchyProvider::new(source.to_string(), uri.to_string());
+        let scopes = PackageScopes::build(ast);
+        provider.collect_class_info(ast, &scopes, self);
+    }
+
+    fn add_parent(&mut self, package: &str, parent: &str) {
+        let parents = self.parents.entry(package.to_string()).or_default();
+        if !parents.iter().any(|p| p == parent) {
+            parents.push(parent.to_string());
+        }
+    }
+
+    fn add_definition(&mut self, item: CallHierarchyItem) {
+        self.definitions.entry(item.name.clone()).or_default().push(item);
+    }
+
+    /// Direct parents of a package
+    pub fn parents(&self, package: &str) -> &[String] {
+        self.parents.get(package).map_or(&[], Vec::as_slice)
+    }
+
+    /// Method resolution order of a package, starting with the package itself
+    pub fn linearize(&self, package: &str) -> Vec<String> {
+        let mut order = Vec::new();
+        self.linearize_into(package, &mut order);
+        order
+    }
+
+    fn linearize_into(&self, package: &str, order: &mut Vec<String>) {
+        if order.iter().any(|p| p == package) {
+            return;
+        }
+        order.push(package.to_string());
+        for parent in self.parents(package) {
+            self.linearize_into(parent, order);
+        }
+    }
+
+    /// All packages that inherit from `package`, directly or indirectly
+    pub fn subclasses(&self, package: &str) -> Vec<String> {
+        self.parents
+            .keys()
+            .filter(|child| *child != package && self.linearize(child).iter().any(|p| p == package))
+            .cloned()
+            .collect()
+    }
+
+    /// Every definition of a sub with the given name
+    pub fn definitions(&self, name: &str) -> &[CallHierarchyItem] {
+        self.definitions.get(name).map_or(&[], Vec::as_slice)
+    }
+
+    /// The sub `name` defined directly in `package`
+    pub fn definition(&self, package: &str, name: &str) -> Option<&CallHierarchyItem> {
+        self.definitions(name).iter().find(|def| def.package.as_deref() == Some(package))
+    }
+
+    /// The implementation `package->name` dispatches to
+    pub fn resolve_method(&self, package: &str, name: &str) -> Option<&CallHierarchyItem> {
+        self.linearize(package).iter().find_map(|p| self.definition(p, name))
+    }
+
+    /// The implementation `SUPER::name` dispatches to from code in `package`
+    pub fn resolve_super(&self, package: &str, name: &str) -> Option<&CallHierarchyItem> {
+        self.linearize(package).iter().skip(1).find_map(|p| self.definition(p, name))
+    }
+
+    /// Packages whose lookup of `name` lands on the implementation in `package`
+    fn dispatch_packages(&self, package: &str, name: &str) -> BTreeSet<String> {
+        let mut packages = BTreeSet::from([package.to_string()]);
+        for subclass in self.subclasses(package) {
+            let resolved = self.resolve_method(&subclass, name);
+            if resolved.is_none_or(|def| def.package.as_deref() == Some(package)) {
+                packages.insert(subclass);
+            }
+        }
+        packages
+    }
+}
+
 /// Incoming call information representing a caller of a function
 ///
 /// This structure represents a function that calls the target function,
@@ -602,6 +981,10 @@ impl CallHierarchyItem {
             item["detail"] = json!(detail);
         }
 
+        if let Some(package) = &self.package {
+            item["data"] = json!({ "package": package });
+        }
+
         item
     }
 }
@@ -723,6 +1106,7 @@ sub target_func {
                     end: Position { line: 10, character: 15 },
                 },
                 detail: None,
+                package: None,
             };
 
             let incoming = provider.incoming_calls(&ast, &target_item);
@@ -774,6 +1158,7 @@ sub helper {
                     end: Position { line: 1, character: 8 },
                 },
                 detail: None,
+                package: None,
             };
 
             let outgoing = provider.outgoing_calls(&ast, &main_item);
@@ -786,4 +1171,185 @@ sub helper {
             assert!(called_names.contains(&&"method_call".to_string()));
         }
     }
+
+    /// Parse each `(uri, source)` pair and build a hierarchy spanning all of them
+    fn workspace(files: &[(&str, &str)]) -> (Vec<Node>, Arc<ClassHierarchy>) {
+        let mut hierarchy = ClassHierarchy::new();
+        let mut asts = Vec::new();
+        for (uri, source) in files {
+            if let Ok(ast) = Parser::new(source).parse() {
+                hierarchy.add_document(uri, source, &ast);
+                asts.push(ast);
+            }
+        }
+        assert_eq!(asts.len(), files.len(), "all fixtures should parse");
+        (asts, Arc::new(hierarchy))
+    }
+
+    const BASE: &str = r#"package Animal;
+sub new { my $class = shift; return bless {}, $class; }
+sub speak { my $self = shift; return $self->sound(); }
+sub sound { return "..."; }
+1;
+"#;
+
+    const DOG: &str = r#"package Dog;
+use parent -norequire, 'Animal';
+sub fetch { my $self = shift; $self->speak(); }
+sub sound { my $self = shift; return "Woof " . $self->SUPER::sound(); }
+1;
+"#;
+
+    const SCRIPT: &str = r#"package Robot;
+sub speak { return "beep"; }
+package main;
+sub run {
+    my $dog = Dog->new();
+    Dog->speak();
+    Robot->speak();
+}
+sub chat { my $thing = shift; $thing->speak(); }
+"#;
+
+    #[test]
+    fn test_incoming_calls_through_inheritance() {
+        let files = [
+            ("file:///lib/Animal.pm", BASE),
+            ("file:///lib/Dog.pm", DOG),
+            ("file:///script.pl", SCRIPT),
+        ];
+        let (asts, hierarchy) = workspace(&files);
+
+        let base = CallHierarchyProvider::new(BASE.to_string(), files[0].0.to_string())
+            .with_class_hierarchy(hierarchy.clone());
+        let items = base.prepare(&asts[0], 2, 5).unwrap_or_default();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].name, "speak");
+        assert_eq!(items[0].package.as_deref(), Some("Animal"));
+
+        let mut callers = Vec::new();
+        for ((uri, source), ast) in files.iter().zip(&asts) {
+            let provider = CallHierarchyProvider::new(source.to_string(), uri.to_string())
+                .with_class_hierarchy(hierarchy.clone());
+            for call in provider.incoming_calls(ast, &items[0]) {
+                callers.push((
+                    call.from.uri.clone(),
+                    call.from.name.clone(),
+                    call.from_ranges.len(),
+                ));
+            }
+        }
+
+        // `$self->speak` in a subclass, `Dog->speak` and an untyped invocant all reach
+        // Animal::speak; `Robot->speak` does not.
+        assert_eq!(
+            callers,
+            vec![
+                ("file:///lib/Dog.pm".to_string(), "fetch".to_string(), 1),
+                ("file:///script.pl".to_string(), "run".to_string(), 1),
+                ("file:///script.pl".to_string(), "chat".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_incoming_calls_skip_overridden_receivers() {
+        let files = [("file:///lib/Animal.pm", BASE), ("file:///lib/Dog.pm", DOG)];
+        let (asts, hierarchy) = workspace(&files);
+
+        let base = CallHierarchyProvider::new(BASE.to_string(), files[0].0.to_string())
+            .with_class_hierarchy(hierarchy.clone());
+        let items = base.prepare(&asts[0], 3, 5).unwrap_or_default();
+        assert_eq!(items[0].name, "sound");
+
+        // Animal::speak calls `$self->sound`, which may reach Animal::sound; Dog::sound
+        // reaches it only through SUPER::.
+        let dog = CallHierarchyProvider::new(DOG.to_string(), files[1].0.to_string())
+            .with_class_hierarchy(hierarchy.clone());
+        let from_dog: Vec<_> =
+            dog.incoming_calls(&asts[1], &items[0]).into_iter().map(|c| c.from.name).collect();
+        assert_eq!(from_dog, vec!["sound".to_string()]);
+
+        let from_base: Vec<_> =
+            base.incoming_calls(&asts[0], &items[0]).into_iter().map(|c| c.from.name).collect();
+        assert_eq!(from_base, vec!["speak".to_string()]);
+    }
+
+    #[test]
+    fn test_outgoing_calls_follow_super_and_overrides() {
+        let files = [("file:///lib/Animal.pm", BASE), ("file:///lib/Dog.pm", DOG)];
+        let (asts, hierarchy) = workspace(&files);
+
+        let dog = CallHierarchyProvider::new(DOG.to_string(), files[1].0.to_string())
+            .with_class_hierarchy(hierarchy.clone());
+        let items = dog.prepare(&asts[1], 3, 5).unwrap_or_default();
+        let outgoing: Vec<_> = dog
+            .outgoing_calls(&asts[1], &items[0])
+            .into_iter()
+            .filter(|c| c.to.name != "shift")
+            .collect();
+        assert_eq!(outgoing.len(), 1);
+        assert_eq!(outgoing[0].to.name, "sound");
+        assert_eq!(outgoing[0].to.package.as_deref(), Some("Animal"));
+        assert_eq!(outgoing[0].to.uri, "file:///lib/Animal.pm");
+        assert_eq!(outgoing[0].to.selection_range.start, Position { line: 3, character: 4 });
+
+        // `$self->sound` inside Animal may dispatch to either implementation
+        let base = CallHierarchyProvider::new(BASE.to_string(), files[0].0.to_string())
+            .with_class_hierarchy(hierarchy.clone());
+        let items = base.prepare(&asts[0], 2, 5).unwrap_or_default();
+        let targets: Vec<_> = base
+            .outgoing_calls(&asts[0], &items[0])
+            .into_iter()
+            .filter(|c| c.to.name == "sound")
+            .map(|c| (c.to.package.unwrap_or_default(), c.to.uri))
+            .collect();
+        assert_eq!(
+            targets,
+            vec![
+                ("Animal".to_string(), "file:///lib/Animal.pm".to_string()),
+                ("Dog".to_string(), "file:///lib/Dog.pm".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_outgoing_calls_list_all_dynamic_candidates() {
+        let files = [
+            ("file:///lib/Animal.pm", BASE),
+            ("file:///lib/Dog.pm", DOG),
+            ("file:///script.pl", SCRIPT),
+        ];
+        let (asts, hierarchy) = workspace(&files);
+
+        let script = CallHierarchyProvider::new(SCRIPT.to_string(), files[2].0.to_string())
+            .with_class_hierarchy(hierarchy.clone());
+        let items = script.prepare(&asts[2], 8, 5).unwrap_or_default();
+        assert_eq!(items[0].name, "chat");
+
+        let mut targets: Vec<_> = script
+            .outgoing_calls(&asts[2], &items[0])
+            .into_iter()
+            .filter(|c| c.to.name == "speak")
+            .map(|c| c.to.package.unwrap_or_default())
+            .collect();
+        targets.sort();
+        assert_eq!(targets, vec!["Animal".to_string(), "Robot".to_string()]);
+    }
+
+    #[test]
+    fn test_class_hierarchy_linearization() {
+        let code = r#"package A; sub hi {}
+package B; our @ISA = ('A');
+package C; use base qw(A);
+package D; @ISA = qw(B C);
+"#;
+        let (_, hierarchy) = workspace(&[("file:///mro.pm", code)]);
+        assert_eq!(hierarchy.linearize("D"), vec!["D", "B", "A", "C"]);
+        assert_eq!(hierarchy.subclasses("A"), vec!["B", "C", "D"]);
+        assert_eq!(
+            hierarchy.resolve_method("D", "hi").and_then(|d| d.package.clone()),
+            Some("A".to_string())
+        );
+    }
 }
@@ -0,0 +1,151 @@
+//! Parser error to LSP diagnostic conversion
+
+use lsp_types::{
+    CodeDescription, Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, DiagnosticTag,
+    Location, NumberOrString, Position, Range, Uri,
+};
+use perl_diagnostics_codes::{DiagnosticSeverity as CodeSeverity, DiagnosticTag as CodeTag};
+use perl_parser::error::ParseError;
+use perl_position_tracking::LineIndex;
+
+/// Convert a parse error into an LSP diagnostic for the document at `uri`
+///
+/// Severity, `code`, `codeDescription`, and tags all come from
+/// [`ParseError::diagnostic_code`]. Ranges are UTF-16. An unclosed delimiter is
+/// reported at end of input, with related information pointing at the opening
+/// delimiter in `uri`.
+pub fn to_lsp_diagnostic(err: &ParseError, line_index: &LineIndex, uri: &Uri) -> Diagnostic {
+    let code = err.diagnostic_code();
+    let text = line_index.text();
+
+    let (range, message, related_information) = match err {
+        ParseError::UnexpectedToken { expected, found, location } => {
+            (char_range(line_index, *location), format!("Expected {expected}, found {found}"), None)
+        }
+        ParseError::SyntaxError { message, location } => {
+            (char_range(line_index, *location), message.clone(), None)
+        }
+        ParseError::UnclosedDelimiter { delimiter, location } => {
+            let opened_here = DiagnosticRelatedInformation {
+                location: Location { uri: uri.clone(), range: char_range(line_index, *location) },
+                message: format!("'{delimiter}' opened here"),
+            };
+            (empty_range(line_index, text.len()), err.to_string(), Some(vec![opened_here]))
+        }
+        ParseError::UnmatchedClose { location, .. } => {
+            (char_range(line_index, *location), err.to_string(), None)
+        }
+        ParseError::UnexpectedEof => (empty_range(line_index, text.len()), err.to_string(), None),
+        _ => (char_range(line_index, 0), err.to_string(), None),
+    };
+
+    let tags: Vec<DiagnosticTag> = code
+        .tags()
+        .iter()
+        .map(|tag| match tag {
+            CodeTag::Unnecessary => DiagnosticTag::UNNECESSARY,
+            CodeTag::Deprecated => DiagnosticTag::DEPRECATED,
+        })
+        .collect();
+
+    Diagnostic {
+        range,
+        severity: Some(match code.severity() {
+            CodeSeverity::Error => DiagnosticSeverity::ERROR,
+            CodeSeverity::Warning => DiagnosticSeverity::WARNING,
+            CodeSeverity::Information => DiagnosticSeverity::INFORMATION,
+            CodeSeverity::Hint => DiagnosticSeverity::HINT,
+        }),
+        code: Some(NumberOrString::String(code.as_str().to_string())),
+        code_description: code
+            .documentation_url()
+            .and_then(|url| url.parse::<Uri>().ok())
+            .map(|href| CodeDescription { href }),
+        source: Some("perl-lsp".to_string()),
+        message,
+        related_information,
+        tags: if tags.is_empty() { None } else { Some(tags) },
+        data: None,
+    }
+}
+
+/// Range covering the character at `offset`, or an empty range at end of input
+fn char_range(line_index: &LineIndex, offset: usize) -> Range {
+    let text = line_index.text();
+    let start = floor_char_boundary(text, offset);
+    let width = text[start..].chars().next().map_or(0, char::len_utf8);
+    Range::new(position(line_index, start), position(line_index, start + width))
+}
+
+fn empty_range(line_index: &LineIndex, offset: usize) -> Range {
+    let at = position(line_index, floor_char_boundary(line_index.text(), offset));
+    Range::new(at, at)
+}
+
+fn position(line_index: &LineIndex, offset: usize) -> Position {
+    let (line, character) = line_index.offset_to_position(offset);
+    Position::new(line, character)
+}
+
+fn floor_char_boundary(text: &str, offset: usize) -> usize {
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use perl_tdd_support::must;
+
+    fn uri() -> Uri {
+        must("file:///test.pl".parse::<Uri>())
+    }
+
+    #[test]
+    fn unexpected_token_fields() {
+        let index = LineIndex::new("my $x = 1;\nmy é = ;\n".to_string());
+        let err = ParseError::unexpected("expression", "semicolon `;`", 19);
+
+        let diag = to_lsp_diagnostic(&err, &index, &uri());
+
+        // Byte 19 is the ';' on line 1; 'é' is two bytes but one UTF-16 unit
+        assert_eq!(diag.range, Range::new(Position::new(1, 7), Position::new(1, 8)));
+        assert_eq!(diag.severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(diag.code, Some(NumberOrString::String("PL002".to_string())));
+        assert_eq!(
+            diag.code_description.map(|d| d.href.as_str().to_string()),
+            Some("https://docs.perl-lsp.org/errors/PL002".to_string())
+        );
+        assert_eq!(diag.tags, None);
+        assert_eq!(diag.source.as_deref(), Some("perl-lsp"));
+        assert_eq!(diag.message, "Expected expression, found semicolon `;`");
+        assert_eq!(diag.related_information, None);
+    }
+
+    #[test]
+    fn unclosed_delimiter_fields() {
+        let index = LineIndex::new("foo(1,\n  2".to_string());
+        let err = ParseError::UnclosedDelimiter { delimiter: '(', location: 3 };
+
+        let diag = to_lsp_diagnostic(&err, &index, &uri());
+
+        assert_eq!(diag.range, Range::new(Position::new(1, 3), Position::new(1, 3)));
+        assert_eq!(diag.severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(diag.code, Some(NumberOrString::String("PL003".to_string())));
+        assert_eq!(
+            diag.code_description.map(|d| d.href.as_str().to_string()),
+            Some("https://docs.perl-lsp.org/errors/PL003".to_string())
+        );
+        assert_eq!(diag.tags, None);
+        assert_eq!(diag.message, "Unclosed delimiter: (");
+
+        let related = must(diag.related_information.ok_or("missing related information"));
+        assert_eq!(related.len(), 1);
+        assert_eq!(related[0].location.uri, uri());
+        assert_eq!(related[0].location.range, Range::new(Position::new(0, 3), Position::new(0, 4)));
+        assert_eq!(related[0].message, "'(' opened here");
+    }
+}
@@ -29,3 +29,7 @@
 
 // Re-export wire types from perl-position-tracking (canonical implementation)
 pub use perl_position_tracking::{WireLocation, WirePosition, WireRange};
+
+mod diagnostic;
+
+pub use diagnostic::to_lsp_diagnostic;
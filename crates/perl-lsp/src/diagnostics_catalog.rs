@@ -178,6 +178,18 @@ pub fn implicit_return() -> DiagnosticMeta {
     DiagnosticMeta::new("PL402", Some("https://docs.perl-lsp.org/errors/PL402"))
 }
 
+/// Generate diagnostic for indirect object method calls in Perl scripts
+///
+/// Flags constructor calls written as `new Class(...)`, which Perl may
+/// resolve to a function call instead of a method call.
+///
+/// # Returns
+///
+/// Diagnostic metadata with error code PL403 for indirect object syntax
+pub fn indirect_object_syntax() -> DiagnosticMeta {
+    DiagnosticMeta::new("PL403", Some("https://docs.perl-lsp.org/errors/PL403"))
+}
+
 // Perl::Critic violations (PC000-PC999)
 
 /// Generate diagnostic for Perl::Critic severity level 5 violations
@@ -928,6 +928,8 @@ pub fn command_exists(command: &str) -> bool {
 /// - `perl.runTest`: Run a single test
 /// - `perl.runTestFile`: Run a test file
 /// - `perl.debugFile`: Debug a Perl file
+/// - `perl.sortUseStatements`: Sort and group a file's `use` statements
+/// - `perl.extractSubroutine`: Extract selected statements into a new subroutine
 ///
 /// # Examples
 ///
@@ -936,7 +938,7 @@ pub fn command_exists(command: &str) -> bool {
 ///
 /// let commands = get_supported_commands();
 /// assert!(commands.contains(&"perl.runCritic".to_string()));
-/// assert_eq!(commands.len(), 8);
+/// assert_eq!(commands.len(), 10);
 /// ```
 ///
 /// # Performance
@@ -235,6 +235,65 @@ pub fn folding_ranges_from_text(src: &str, limit: usize) -> Vec<serde_json::Valu
     out
 }
 
+/// Upper bound on tokens scanned by [`harvest_words`], so binary or huge
+/// buffers cannot stall a request
+const HARVEST_TOKEN_LIMIT: usize = 100_000;
+
+/// Words found by a raw lexer pass over a buffer that failed to parse
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct HarvestedWords {
+    /// Variables including their sigil (`$name`, `@items`, `%opts`), first-seen order
+    pub variables: Vec<String>,
+    /// Barewords such as sub, package, and method names, first-seen order
+    pub barewords: Vec<String>,
+    /// Perl keywords that occur in the buffer, first-seen order
+    pub keywords: Vec<String>,
+}
+
+/// Harvest identifiers and keywords from `text` without building an AST
+///
+/// The token containing `cursor` is skipped so the partial word being typed is
+/// not offered back as its own completion.
+pub fn harvest_words(text: &str, cursor: usize) -> HarvestedWords {
+    let mut words = HarvestedWords::default();
+    let mut seen = std::collections::HashSet::new();
+    let mut lexer = perl_lexer::PerlLexer::new(text);
+
+    for _ in 0..HARVEST_TOKEN_LIMIT {
+        let Some(token) = lexer.next_token() else { break };
+        if token.start <= cursor && cursor <= token.end {
+            continue;
+        }
+
+        let (bucket, word) = match &token.token_type {
+            perl_lexer::TokenType::EOF => break,
+            perl_lexer::TokenType::Keyword(word) => (&mut words.keywords, word),
+            perl_lexer::TokenType::Identifier(word) => {
+                let name = word.trim_start_matches(['$', '@', '%', '&']);
+                let is_word = name.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_')
+                    && name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == ':');
+                if !is_word {
+                    continue;
+                }
+                // `&name` refers to a sub, so it is offered as a bareword
+                if name.len() == word.len() || word.starts_with('&') {
+                    (&mut words.barewords, word)
+                } else {
+                    (&mut words.variables, word)
+                }
+            }
+            _ => continue,
+        };
+
+        let word = word.trim_start_matches('&');
+        if seen.insert(word.to_string()) {
+            bucket.push(word.to_string());
+        }
+    }
+
+    words
+}
+
 /// Count opening and closing braces in a line, attempting to skip strings
 fn count_braces_in_line(line: &str) -> (usize, usize) {
     let mut opens = 0;
@@ -355,6 +414,23 @@ sub foo {
         assert_eq!(ranges[0]["endLine"], 3); // Line 3 is the closing brace
     }
 
+    #[test]
+    fn test_harvest_words_from_unparseable_text() {
+        let src = "my $alpha = 1;\nsub beta_gamma { return $alpha + @items + (((((";
+        let words = harvest_words(src, src.len());
+        assert_eq!(words.variables, vec!["$alpha", "@items"]);
+        assert_eq!(words.barewords, vec!["beta_gamma"]);
+        assert!(words.keywords.contains(&"my".to_string()), "{:?}", words.keywords);
+        assert!(words.keywords.contains(&"return".to_string()), "{:?}", words.keywords);
+    }
+
+    #[test]
+    fn test_harvest_words_skips_word_at_cursor() {
+        let src = "sub beta_gamma {}\nbet";
+        let words = harvest_words(src, src.len());
+        assert_eq!(words.barewords, vec!["beta_gamma"]);
+    }
+
     #[test]
     fn test_count_braces_basic() {
         assert_eq!(count_braces_in_line("sub foo {"), (1, 0));
@@ -93,7 +93,7 @@ impl CodeActionsProvider {
                 "undefined-variable" | "undeclared-variable" => {
                     actions.extend(self.fix_undefined_variable(diagnostic));
                 }
-                "unused-variable" => {
+                "unused-variable" | "PL102" => {
                     actions.extend(self.fix_unused_variable(diagnostic));
                 }
                 "variable-shadowing" => {
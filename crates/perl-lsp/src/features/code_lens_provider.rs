@@ -36,17 +36,29 @@ pub struct Command {
 /// Code lens provider
 pub struct CodeLensProvider {
     source: String,
+    uri: Option<String>,
 }
 
 impl CodeLensProvider {
     /// Create a new code lens provider
     pub fn new(source: String) -> Self {
-        Self { source }
+        Self { source, uri: None }
+    }
+
+    /// Set the document URI used to build test IDs for "Run Test" lenses
+    pub fn with_uri(mut self, uri: &str) -> Self {
+        self.uri = Some(uri.to_string());
+        self
     }
 
     /// Extract code lenses from an AST
     pub fn extract(&self, ast: &Node) -> Vec<CodeLens> {
         let mut lenses = Vec::new();
+        if let NodeKind::Program { statements } = &ast.kind
+            && statements.iter().any(is_test_module_import)
+        {
+            self.add_test_lens(WireRange::empty(WirePosition::new(0, 0)), None, &mut lenses);
+        }
         self.visit_node(ast, &mut lenses);
         lenses
     }
@@ -54,10 +66,8 @@ impl CodeLensProvider {
     /// Visit a node and extract code lenses
     fn visit_node(&self, node: &Node, lenses: &mut Vec<CodeLens>) {
         match &node.kind {
-            NodeKind::Program { statements } => {
-                for stmt in statements {
-                    self.visit_node(stmt, lenses);
-                }
+            NodeKind::Program { statements } | NodeKind::Block { statements } => {
+                self.visit_statements(statements, lenses);
             }
 
             NodeKind::Subroutine {
@@ -79,10 +89,12 @@ impl CodeLensProvider {
                 }
 
                 // Visit body
-                self.visit_node(body, lenses);
+                if let Some(body) = body {
+                    self.visit_node(body, lenses);
+                }
             }
 
-            NodeKind::Package { name, block, name_span: _ } => {
+            NodeKind::Package { name, block, .. } => {
                 // Add "X references" lens for packages
                 self.add_references_lens(node, name, lenses);
 
@@ -91,12 +103,6 @@ impl CodeLensProvider {
                 }
             }
 
-            NodeKind::Block { statements } => {
-                for stmt in statements {
-                    self.visit_node(stmt, lenses);
-                }
-            }
-
             // Look for shebang line to add "Run Script" lens
             _ => {
                 // Visit children for other node types
@@ -105,6 +111,74 @@ impl CodeLensProvider {
         }
     }
 
+    /// Visit a statement list, adding "Run Test" lenses for `subtest` calls
+    ///
+    /// Without parentheses the parser splits `subtest 'name' => sub { ... };`
+    /// into a bare `subtest` statement followed by a `'name' => sub { ... }`
+    /// list, so both that shape and `subtest('name' => sub { ... })` are
+    /// recognized. Subtest bodies are visited too, so nested subtests get
+    /// their own lenses.
+    fn visit_statements(&self, statements: &[Node], lenses: &mut Vec<CodeLens>) {
+        let mut i = 0;
+        while i < statements.len() {
+            let stmt = &statements[i];
+            match &unwrap_statement(stmt).kind {
+                NodeKind::FunctionCall { name, args } if name == "subtest" => {
+                    if let Some((name, body)) = subtest_parts(args.first(), args.get(1)) {
+                        self.add_subtest_lens(stmt.location.start, stmt.location.end, name, lenses);
+                        self.visit_node(body, lenses);
+                        i += 1;
+                        continue;
+                    }
+                }
+                NodeKind::Identifier { name } if name == "subtest" => {
+                    if let Some(next) = statements.get(i + 1)
+                        && let NodeKind::HashLiteral { pairs } = &unwrap_statement(next).kind
+                        && let [(key, value)] = pairs.as_slice()
+                        && let Some((name, body)) = subtest_parts(Some(key), Some(value))
+                    {
+                        self.add_subtest_lens(stmt.location.start, next.location.end, name, lenses);
+                        self.visit_node(body, lenses);
+                        i += 2;
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+            self.visit_node(stmt, lenses);
+            i += 1;
+        }
+    }
+
+    /// Add a "Run Test" lens for a subtest, resolved later by [`resolve_code_lens`]
+    ///
+    /// Subtests whose name is not a literal, such as `subtest "case $n" => ...`,
+    /// cannot be selected by name and get a lens that runs the whole file.
+    fn add_subtest_lens(
+        &self,
+        start: usize,
+        end: usize,
+        name: Option<String>,
+        lenses: &mut Vec<CodeLens>,
+    ) {
+        let range = WireRange::from_byte_offsets(&self.source, start, end);
+        self.add_test_lens(range, Some(name), lenses);
+    }
+
+    /// Add an unresolved test lens; `subtest` is `None` for the whole-file lens
+    fn add_test_lens(
+        &self,
+        range: WireRange,
+        subtest: Option<Option<String>>,
+        lenses: &mut Vec<CodeLens>,
+    ) {
+        let data = match subtest {
+            Some(name) => json!({ "kind": "subtest", "name": name, "uri": self.uri }),
+            None => json!({ "kind": "test_file", "uri": self.uri }),
+        };
+        lenses.push(CodeLens { range, command: None, data: Some(data) });
+    }
+
     /// Check if a subroutine is a test
     fn is_test_subroutine(&self, name: &str) -> bool {
         // Common test naming patterns
@@ -129,7 +203,10 @@ impl CodeLensProvider {
             command: Some(Command {
                 title: "▶ Run Test".to_string(),
                 command: "perl.runTest".to_string(),
-                arguments: Some(vec![json!(name)]),
+                arguments: Some(vec![json!(match &self.uri {
+                    Some(uri) => format!("{}::{}", uri, name),
+                    None => name.to_string(),
+                })]),
             }),
             data: None,
         });
@@ -161,8 +238,76 @@ impl CodeLensProvider {
     }
 }
 
+/// Whether a statement loads a Test::More-style testing module
+fn is_test_module_import(node: &Node) -> bool {
+    matches!(
+        &node.kind,
+        NodeKind::Use { module, .. }
+            if matches!(module.as_str(), "Test::More" | "Test::Most" | "Test2::V0" | "Test::Simple")
+    )
+}
+
+/// Look through expression statements and statement modifiers to the expression
+fn unwrap_statement(node: &Node) -> &Node {
+    match &node.kind {
+        NodeKind::ExpressionStatement { expression } => unwrap_statement(expression),
+        NodeKind::StatementModifier { statement, .. } => unwrap_statement(statement),
+        _ => node,
+    }
+}
+
+/// The name and body of a `subtest NAME => sub { ... }` call
+///
+/// The name is `None` when it is not a literal string, for example when it
+/// interpolates a variable.
+fn subtest_parts<'a>(
+    name: Option<&Node>,
+    body: Option<&'a Node>,
+) -> Option<(Option<String>, &'a Node)> {
+    let body = match &body?.kind {
+        NodeKind::Subroutine { name: None, body: Some(body), .. } => body.as_ref(),
+        _ => return None,
+    };
+    let name = match &name?.kind {
+        NodeKind::String { value, interpolated } => {
+            let unquoted = ['\'', '"']
+                .iter()
+                .find_map(|q| value.strip_prefix(*q).and_then(|v| v.strip_suffix(*q)))
+                .unwrap_or(value);
+            (!interpolated || !unquoted.contains(['$', '@'])).then(|| unquoted.to_string())
+        }
+        NodeKind::Identifier { name } => Some(name.clone()),
+        _ => None,
+    };
+    Some((name, body))
+}
+
 /// Resolve a code lens (add command with reference count)
+///
+/// Test lenses ignore `reference_count`: they resolve to a `perl.runTest`
+/// command carrying the `uri::subtest` test ID, or to `perl.runTestFile`
+/// for the whole-file lens and for subtests without a literal name.
 pub fn resolve_code_lens(lens: CodeLens, reference_count: usize) -> CodeLens {
+    let data = lens.data.as_ref();
+    let kind = data.and_then(|d| d.get("kind")).and_then(|k| k.as_str());
+    if lens.command.is_none() && matches!(kind, Some("subtest" | "test_file")) {
+        let uri = data.and_then(|d| d.get("uri")).and_then(|u| u.as_str()).unwrap_or("");
+        let subtest = data.and_then(|d| d.get("name")).and_then(|n| n.as_str());
+        let command = match subtest {
+            Some(name) => Command {
+                title: "▶ Run Test".to_string(),
+                command: "perl.runTest".to_string(),
+                arguments: Some(vec![json!(format!("{}::{}", uri, name))]),
+            },
+            None => Command {
+                title: "▶ Run Tests".to_string(),
+                command: "perl.runTestFile".to_string(),
+                arguments: Some(vec![json!(uri)]),
+            },
+        };
+        return CodeLens { range: lens.range, command: Some(command), data: lens.data };
+    }
+
     if lens.command.is_none() && lens.data.is_some() {
         // This is a references lens that needs resolving
         let _name = lens
@@ -292,4 +437,55 @@ sub test_another {
             assert_eq!(cmd.title, "3 references");
         }
     }
+
+    #[test]
+    fn test_subtest_lenses() -> Result<(), Box<dyn std::error::Error>> {
+        let source = r#"use Test::More;
+
+subtest 'outer' => sub {
+    ok(1);
+    subtest "inner" => sub { ok(1) };
+};
+subtest(parens => sub { ok(1) });
+subtest "case $_" => sub { ok(1) } for 1 .. 2;
+
+done_testing;
+"#;
+
+        let ast = Parser::new(source).parse()?;
+        let lenses =
+            CodeLensProvider::new(source.to_string()).with_uri("file:///t/a.t").extract(&ast);
+
+        let resolved: Vec<_> = lenses
+            .into_iter()
+            .filter(|l| l.data.as_ref().is_some_and(|d| d["kind"] != "subroutine"))
+            .map(|l| resolve_code_lens(l, 0))
+            .collect();
+        let summary: Vec<_> = resolved
+            .iter()
+            .map(|l| {
+                let command = l.command.as_ref().map(|c| c.command.as_str()).unwrap_or("");
+                let argument = l
+                    .command
+                    .as_ref()
+                    .and_then(|c| c.arguments.as_ref())
+                    .and_then(|args| args.first())
+                    .and_then(|a| a.as_str())
+                    .unwrap_or("");
+                (l.range.start.line, l.range.start.character, l.range.end.line, command, argument)
+            })
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                (0, 0, 0, "perl.runTestFile", "file:///t/a.t"),
+                (2, 0, 5, "perl.runTest", "file:///t/a.t::outer"),
+                (4, 4, 4, "perl.runTest", "file:///t/a.t::inner"),
+                (6, 0, 6, "perl.runTest", "file:///t/a.t::parens"),
+                (7, 0, 7, "perl.runTestFile", "file:///t/a.t"),
+            ]
+        );
+        Ok(())
+    }
 }
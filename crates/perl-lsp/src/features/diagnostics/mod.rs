@@ -5,5 +5,6 @@ pub use pull::PullDiagnosticsProvider;
 
 // Re-export core diagnostics types from perl-lsp-diagnostics
 pub use perl_lsp_diagnostics::{
-    Diagnostic, DiagnosticSeverity, DiagnosticTag, DiagnosticsProvider, RelatedInformation,
+    Diagnostic, DiagnosticSeverity, DiagnosticTag, DiagnosticsProvider, HintOptions,
+    RelatedInformation,
 };
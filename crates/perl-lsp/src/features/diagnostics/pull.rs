@@ -12,12 +12,14 @@ use lsp_types::{
     WorkspaceFullDocumentDiagnosticReport, WorkspaceUnchangedDocumentDiagnosticReport,
 };
 
+use crate::convert::to_lsp_diagnostic;
 use crate::state::DocumentState;
 use crate::util::uri::parse_uri;
 use perl_parser::Parser;
 use perl_parser::error::ParseError;
 use perl_parser::position::offset_to_utf16_line_col;
 use perl_parser::util::code_slice;
+use perl_position_tracking::LineIndex;
 
 // Import core diagnostics types from perl-lsp-providers (via parent module re-export)
 use super::{
@@ -119,7 +121,9 @@ impl PullDiagnosticsProvider {
                     .map(|d| self.to_lsp_diagnostic(uri, content, d))
                     .collect()
             }
-            Err(error) => vec![self.parse_error_to_diagnostic(uri, content, &error)],
+            Err(error) => {
+                vec![to_lsp_diagnostic(&error, &LineIndex::new(content.to_string()), uri)]
+            }
         }
     }
 
@@ -138,10 +142,11 @@ impl PullDiagnosticsProvider {
         } else if doc_state.parse_errors.is_empty() {
             Vec::new()
         } else {
+            let line_index = LineIndex::new(doc_state.text.clone());
             doc_state
                 .parse_errors
                 .iter()
-                .map(|error| self.parse_error_to_diagnostic(uri, &doc_state.text, error))
+                .map(|error| to_lsp_diagnostic(error, &line_index, uri))
                 .collect()
         }
     }
@@ -226,38 +231,6 @@ impl PullDiagnosticsProvider {
             data: None,
         }
     }
-
-    fn parse_error_to_diagnostic(
-        &self,
-        uri: &Uri,
-        text: &str,
-        error: &ParseError,
-    ) -> LspDiagnostic {
-        let (offset, message) = match error {
-            ParseError::UnexpectedToken { location, expected, found } => {
-                (*location, format!("Expected {expected}, found {found}"))
-            }
-            ParseError::SyntaxError { location, message } => (*location, message.clone()),
-            ParseError::UnexpectedEof => (text.len(), "Unexpected end of input".to_string()),
-            ParseError::LexerError { message } => (0, message.clone()),
-            _ => (0, error.to_string()),
-        };
-
-        let end_offset = offset.saturating_add(1).min(text.len());
-        let range = lsp_range_from_offsets(text, offset, end_offset);
-
-        LspDiagnostic {
-            range,
-            severity: Some(LspDiagnosticSeverity::ERROR),
-            code: Some(NumberOrString::String("parse-error".to_string())),
-            code_description: None,
-            source: Some("perl-lsp".to_string()),
-            message,
-            related_information: to_lsp_related_information(uri, text, &[]),
-            tags: None,
-            data: None,
-        }
-    }
 }
 
 fn lsp_range_from_offsets(text: &str, start: usize, end: usize) -> Range {
@@ -2,8 +2,13 @@
 //!
 //! Highlights all occurrences of a symbol when cursor is positioned on it.
 //! Distinguishes between read and write access.
+//!
+//! Variables are resolved through the symbol table's lexical scopes, so only
+//! occurrences bound to the same declaration are highlighted, including uses
+//! inside interpolated strings.
 
 use perl_parser::ast::{Node, NodeKind, SourceLocation};
+use perl_parser::symbol::{Symbol, SymbolExtractor, SymbolTable};
 
 /// Types of symbol highlights
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -47,6 +52,11 @@ impl DocumentHighlightProvider {
         source: &str,
         byte_offset: usize,
     ) -> Vec<DocumentHighlight> {
+        let table = SymbolExtractor::new_with_source(source).extract(ast);
+        if let Some(highlights) = self.find_variable_highlights(ast, source, &table, byte_offset) {
+            return highlights;
+        }
+
         // Find the node at the cursor position
         let target_node = match self.find_node_at_offset(ast, byte_offset) {
             Some(node) => node,
@@ -67,6 +77,83 @@ impl DocumentHighlightProvider {
         self.deduplicate_highlights(highlights)
     }
 
+    /// Highlight the variable at the cursor, limited to its own declaration
+    ///
+    /// Returns `None` when the cursor is not on a variable known to the symbol table.
+    fn find_variable_highlights(
+        &self,
+        ast: &Node,
+        source: &str,
+        table: &SymbolTable,
+        byte_offset: usize,
+    ) -> Option<Vec<DocumentHighlight>> {
+        let at_cursor = |location: &SourceLocation| {
+            location.start <= byte_offset && byte_offset <= location.end
+        };
+
+        // The cursor sits either on a declaration or on a reference
+        let (name, kind, declaration) = if let Some(symbol) = table
+            .symbols
+            .values()
+            .flatten()
+            .find(|s| s.kind.is_variable() && at_cursor(&s.location))
+        {
+            (symbol.name.as_str(), symbol.kind, Some(symbol))
+        } else {
+            let reference = table
+                .references
+                .values()
+                .flatten()
+                .find(|r| r.kind.is_variable() && at_cursor(&r.location))?;
+            (reference.name.as_str(), reference.kind, table.resolve_reference(reference))
+        };
+
+        // Syntactic read/write classification catches writes such as `$x++`
+        let target = SymbolInfo {
+            name: name.to_string(),
+            sigil: kind.sigil().map(str::to_string),
+            is_method: false,
+            is_function: false,
+        };
+        let mut syntactic = Vec::new();
+        self.collect_highlights(ast, source, &target, &mut syntactic);
+        let is_syntactic_write = |location: &SourceLocation| {
+            syntactic
+                .iter()
+                .any(|h| h.location == *location && h.kind == DocumentHighlightKind::Write)
+        };
+
+        let mut highlights = Vec::new();
+        if let Some(symbol) = declaration {
+            highlights.push(DocumentHighlight {
+                location: symbol.location,
+                kind: DocumentHighlightKind::Write,
+            });
+        }
+
+        for reference in table.references.get(name).into_iter().flatten() {
+            if reference.kind != kind {
+                continue;
+            }
+            // Undeclared (package) variables only match other undeclared uses
+            let same_binding = match (declaration, table.resolve_reference(reference)) {
+                (Some(expected), Some(found)) => std::ptr::eq::<Symbol>(expected, found),
+                (None, None) => true,
+                _ => false,
+            };
+            if same_binding {
+                let kind = if reference.is_write || is_syntactic_write(&reference.location) {
+                    DocumentHighlightKind::Write
+                } else {
+                    DocumentHighlightKind::Read
+                };
+                highlights.push(DocumentHighlight { location: reference.location, kind });
+            }
+        }
+
+        Some(self.deduplicate_highlights(highlights))
+    }
+
     /// Deduplicate highlights by location, preferring Write kind over Read
     fn deduplicate_highlights(&self, highlights: Vec<DocumentHighlight>) -> Vec<DocumentHighlight> {
         use std::collections::HashMap;
@@ -180,7 +267,7 @@ impl DocumentHighlightProvider {
             NodeKind::While { condition, body, .. } => {
                 Some(vec![condition.as_ref(), body.as_ref()])
             }
-            NodeKind::Subroutine { body, .. } => Some(vec![body.as_ref()]),
+            NodeKind::Subroutine { body, .. } => body.as_ref().map(|b| vec![b.as_ref()]),
             NodeKind::Return { value } => value.as_ref().map(|v| vec![v.as_ref()]),
             NodeKind::ArrayLiteral { elements } => Some(elements.iter().collect()),
             NodeKind::HashLiteral { pairs } => {
@@ -211,6 +298,9 @@ impl DocumentHighlightProvider {
             NodeKind::LabeledStatement { statement, .. } => Some(vec![statement.as_ref()]),
             // Code evaluation (Issue #191)
             NodeKind::Eval { block } | NodeKind::Do { block } => Some(vec![block.as_ref()]),
+            NodeKind::DoWhile { block, condition } | NodeKind::DoUntil { block, condition } => {
+                Some(vec![block.as_ref(), condition.as_ref()])
+            }
             // Error handling (Issue #191)
             NodeKind::Try { body, catch_blocks, finally_block } => {
                 let mut children = vec![body.as_ref()];
@@ -274,7 +364,7 @@ impl DocumentHighlightProvider {
             }),
             _ => {
                 // Try to extract from source text
-                let text = &source[node.location.start..node.location.end];
+                let text = source.get(node.location.start..node.location.end)?;
                 // Check for sigil prefix and extract safely
                 let first = text.chars().next();
                 match first {
@@ -344,8 +434,7 @@ impl DocumentHighlightProvider {
                 // Check source text as fallback
                 if let Some(target_sigil) = &target.sigil {
                     let expected = format!("{}{}", target_sigil, target.name);
-                    let text = &source[node.location.start..node.location.end];
-                    text == expected
+                    source.get(node.location.start..node.location.end) == Some(expected.as_str())
                 } else {
                     false
                 }
@@ -495,4 +584,57 @@ print $x if $x > 0;"#;
         );
         Ok(())
     }
+    fn highlighted(code: &str, highlights: &[DocumentHighlight]) -> Vec<(String, usize)> {
+        highlights
+            .iter()
+            .map(|h| (code[h.location.start..h.location.end].to_string(), h.location.start))
+            .collect()
+    }
+
+    #[test]
+    fn test_highlight_respects_sibling_scopes() -> Result<(), Box<dyn std::error::Error>> {
+        let code = "{\n    my $x = 1;\n    print $x;\n}\n{\n    my $x = 2;\n    $x++;\n}\n";
+        let ast = Parser::new(code).parse()?;
+        let provider = DocumentHighlightProvider::new();
+
+        let first = code.find("print $x").map_or(0, |i| i + 7);
+        let highlights = provider.find_highlights(&ast, code, first);
+        let second_block = code.find("my $x = 2").unwrap_or(code.len());
+        assert_eq!(highlights.len(), 2, "{:?}", highlighted(code, &highlights));
+        assert!(highlights.iter().all(|h| h.location.end < second_block));
+        assert_eq!(highlights[0].kind, DocumentHighlightKind::Write);
+        assert_eq!(highlights[1].kind, DocumentHighlightKind::Read);
+
+        let highlights = provider.find_highlights(&ast, code, second_block + 4);
+        assert_eq!(highlights.len(), 2, "{:?}", highlighted(code, &highlights));
+        assert!(highlights.iter().all(|h| h.location.start >= second_block));
+        assert!(highlights.iter().all(|h| h.kind == DocumentHighlightKind::Write));
+        Ok(())
+    }
+
+    #[test]
+    fn test_highlight_includes_interpolated_use() -> Result<(), Box<dyn std::error::Error>> {
+        let code = "my $name = 'outer';\nsub greet {\n    my $name = shift;\n    print \"Hello, $name!\";\n    $name = 'x';\n}\nprint $name;\n";
+        let ast = Parser::new(code).parse()?;
+        let provider = DocumentHighlightProvider::new();
+
+        let inner = code.find("my $name = shift").map_or(0, |i| i + 3);
+        let interpolated = code.find("$name!").unwrap_or(0);
+        let assigned = code.find("$name = 'x'").unwrap_or(0);
+        let expected = vec![
+            ("$name".to_string(), inner),
+            ("$name".to_string(), interpolated),
+            ("$name".to_string(), assigned),
+        ];
+
+        let highlights = provider.find_highlights(&ast, code, inner + 1);
+        assert_eq!(highlighted(code, &highlights), expected);
+        assert_eq!(highlights[1].kind, DocumentHighlightKind::Read);
+        assert_eq!(highlights[2].kind, DocumentHighlightKind::Write);
+
+        // The cursor inside the string resolves to the same declaration
+        let highlights = provider.find_highlights(&ast, code, interpolated + 2);
+        assert_eq!(highlighted(code, &highlights), expected);
+        Ok(())
+    }
 }
@@ -5,8 +5,8 @@
 
 use crate::convert::WireRange;
 pub use perl_lsp_formatting::{
-    FormatPosition, FormatRange, FormatTextEdit, FormattedDocument, FormattingError,
-    FormattingOptions, FormattingProvider,
+    FormatPosition, FormatRange, FormatSkipMarkers, FormatTextEdit, FormattedDocument,
+    FormattingError, FormattingOptions, FormattingProvider,
 };
 use perl_lsp_tooling::OsSubprocessRuntime;
 
@@ -24,6 +24,12 @@ impl CodeFormatter {
         Self { inner: FormattingProvider::new(OsSubprocessRuntime::new()) }
     }
 
+    /// Set the comment markers for regions left untouched (defaults to `#<<<` / `#>>>`)
+    pub fn with_format_skip_markers(mut self, markers: FormatSkipMarkers) -> Self {
+        self.inner = self.inner.with_format_skip_markers(markers);
+        self
+    }
+
     /// Format an entire document, returning just the edits for backwards compatibility
     pub fn format_document(
         &self,
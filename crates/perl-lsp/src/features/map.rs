@@ -216,7 +216,7 @@ pub fn caps_from_feature_ids(features: &[&str]) -> ServerCapabilities {
                                     SemanticTokenModifier::DEFAULT_LIBRARY,
                                 ],
                             },
-                            full: Some(SemanticTokensFullOptions::Bool(true)),
+                            full: Some(SemanticTokensFullOptions::Delta { delta: Some(true) }),
                             range: Some(true),
                             ..Default::default()
                         },
@@ -240,7 +240,7 @@ pub fn caps_from_feature_ids(features: &[&str]) -> ServerCapabilities {
             "lsp.on_type_formatting" => {
                 caps.document_on_type_formatting_provider = Some(DocumentOnTypeFormattingOptions {
                     first_trigger_character: ";".to_string(),
-                    more_trigger_character: Some(vec!["}".to_string()]),
+                    more_trigger_character: Some(vec!["}".to_string(), "\n".to_string()]),
                 });
             }
             "lsp.selection_range" => {
@@ -88,20 +88,24 @@ pub enum SemanticTokenModifier {
     Declaration,
     /// Token is at a definition site (same as declaration)
     Definition,
-    /// Token is a reference to a previously declared item
-    Reference,
-    /// Token is being modified (e.g., assignment target)
-    Modification,
-    /// Token is package-level (not lexically scoped)
-    Static,
-    /// Token refers to a built-in function or variable
-    DefaultLibrary,
-    /// Token is part of an async operation
-    Async,
     /// Token is a constant or read-only value
     Readonly,
+    /// Token is package-level (not lexically scoped)
+    Static,
     /// Token refers to a deprecated item
     Deprecated,
+    /// Token refers to an abstract item
+    Abstract,
+    /// Token is part of an async operation
+    Async,
+    /// Token is being modified (e.g., assignment target)
+    Modification,
+    /// Token is part of documentation
+    Documentation,
+    /// Token refers to a built-in function or variable
+    DefaultLibrary,
+    /// Token is a reference to a previously declared item (not in the legend, so never encoded)
+    Reference,
 }
 
 impl SemanticTokenModifier {
@@ -110,28 +114,31 @@ impl SemanticTokenModifier {
         match self {
             Self::Declaration => "declaration",
             Self::Definition => "definition",
-            Self::Reference => "reference",
-            Self::Modification => "modification",
-            Self::Static => "static",
-            Self::DefaultLibrary => "defaultLibrary",
-            Self::Async => "async",
             Self::Readonly => "readonly",
+            Self::Static => "static",
             Self::Deprecated => "deprecated",
+            Self::Abstract => "abstract",
+            Self::Async => "async",
+            Self::Modification => "modification",
+            Self::Documentation => "documentation",
+            Self::DefaultLibrary => "defaultLibrary",
+            Self::Reference => "reference",
         }
     }
 
-    /// Get all modifiers in order
+    /// Get all legend modifiers in the order advertised by the server capabilities
     pub fn all() -> Vec<Self> {
         vec![
             Self::Declaration,
             Self::Definition,
-            Self::Reference,
-            Self::Modification,
-            Self::Static,
-            Self::DefaultLibrary,
-            Self::Async,
             Self::Readonly,
+            Self::Static,
             Self::Deprecated,
+            Self::Abstract,
+            Self::Async,
+            Self::Modification,
+            Self::Documentation,
+            Self::DefaultLibrary,
         ]
     }
 }
@@ -211,7 +218,7 @@ impl<'a> TokenCollector<'a> {
         is_declaration_context: bool,
     ) {
         match &node.kind {
-            NodeKind::Package { name, block, name_span: _ } => {
+            NodeKind::Package { name, block, .. } => {
                 // Package name is a namespace
                 self.add_token_from_string(
                     name,
@@ -251,7 +258,9 @@ impl<'a> TokenCollector<'a> {
                 }
 
                 // Body
-                self.visit_node(body, tokens, false);
+                if let Some(body) = body {
+                    self.visit_node(body, tokens, false);
+                }
             }
 
             NodeKind::Variable { sigil: _, name: _ } => {
@@ -357,6 +366,11 @@ impl<'a> TokenCollector<'a> {
                 self.visit_node(rhs, tokens, false);
             }
 
+            NodeKind::Unary { op, operand } if op == "++" || op == "--" => {
+                // Increment/decrement target is in modification context
+                self.visit_node(operand, tokens, true);
+            }
+
             NodeKind::ArrayLiteral { elements } => {
                 for elem in elements {
                     self.visit_node(elem, tokens, is_declaration_context);
@@ -617,6 +631,21 @@ sub test_function {
         assert_eq!(encoded[9], 1); // modifier bits
     }
 
+    #[test]
+    fn test_semantic_token_modifier_bits_follow_legend() {
+        let tokens = vec![SemanticToken {
+            line: 0,
+            start_char: 0,
+            length: 2,
+            token_type: SemanticTokenType::Variable,
+            modifiers: vec![SemanticTokenModifier::Modification, SemanticTokenModifier::Reference],
+        }];
+
+        // Modification is bit 7 of the advertised legend; Reference has no bit
+        let encoded = encode_semantic_tokens(&tokens);
+        assert_eq!(encoded[4], 1 << 7);
+    }
+
     #[test]
     fn test_semantic_tokens_thread_safety() -> Result<(), Box<dyn std::error::Error>> {
         let code = r#"
@@ -0,0 +1,302 @@
+//! Sort and group `use` statements
+//!
+//! Backs the `perl.sortUseStatements` command. Each contiguous block of `use`
+//! lines at statement level is rewritten as up to three groups separated by a
+//! blank line: pragmas (with any `use VERSION` first), core modules, then
+//! third-party modules, each alphabetized. Pragmas are the ones [`is_pragma`]
+//! knows, so grouping agrees with the import optimizer and document links.
+//! Comment lines directly above a `use` travel with it. Any other statement,
+//! including `no`, ends the block, so imports are never moved across code.
+
+use lsp_types::{Position, Range, TextEdit};
+use perl_parser::pragma_tracker::is_pragma;
+
+/// Modules shipped with perl that are commonly imported
+const CORE_MODULES: &[&str] = &[
+    "B",
+    "Benchmark",
+    "Carp",
+    "Config",
+    "Cwd",
+    "Data::Dumper",
+    "Digest::MD5",
+    "Digest::SHA",
+    "Encode",
+    "English",
+    "Env",
+    "Errno",
+    "Exporter",
+    "Fcntl",
+    "File::Basename",
+    "File::Compare",
+    "File::Copy",
+    "File::Find",
+    "File::Glob",
+    "File::Path",
+    "File::Spec",
+    "File::Spec::Functions",
+    "File::Temp",
+    "File::stat",
+    "FindBin",
+    "Getopt::Long",
+    "Getopt::Std",
+    "Hash::Util",
+    "I18N::Langinfo",
+    "IO::File",
+    "IO::Handle",
+    "IO::Select",
+    "IO::Socket",
+    "IO::Socket::INET",
+    "IPC::Cmd",
+    "IPC::Open2",
+    "IPC::Open3",
+    "JSON::PP",
+    "List::Util",
+    "MIME::Base64",
+    "Math::BigFloat",
+    "Math::BigInt",
+    "Module::Load",
+    "POSIX",
+    "Pod::Usage",
+    "Safe",
+    "Scalar::Util",
+    "Socket",
+    "Storable",
+    "Symbol",
+    "Sys::Hostname",
+    "Term::ANSIColor",
+    "Test::Builder",
+    "Test::More",
+    "Test::Simple",
+    "Text::Abbrev",
+    "Text::ParseWords",
+    "Text::Wrap",
+    "Tie::Hash",
+    "Time::HiRes",
+    "Time::Local",
+    "Time::Piece",
+    "Unicode::Normalize",
+];
+
+/// Which group a `use` statement sorts into, in output order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum UseGroup {
+    Pragma,
+    Core,
+    ThirdParty,
+}
+
+/// One `use` statement with the comment lines attached above it
+#[derive(Debug)]
+struct UseEntry<'a> {
+    group: UseGroup,
+    /// `use VERSION` sorts ahead of everything else in its group
+    is_version: bool,
+    module: &'a str,
+    lines: Vec<&'a str>,
+}
+
+/// A run of `use` statements spanning `first_line..=last_line`
+#[derive(Debug)]
+struct UseBlock<'a> {
+    first_line: usize,
+    last_line: usize,
+    entries: Vec<UseEntry<'a>>,
+}
+
+/// Compute edits that sort and group every contiguous `use` block in `text`
+///
+/// Blocks already in order produce no edit, so an empty result means the
+/// document is sorted.
+pub fn sort_use_statements(text: &str) -> Vec<TextEdit> {
+    let lines: Vec<&str> = text.lines().collect();
+    let newline = if text.contains("\r\n") { "\r\n" } else { "\n" };
+
+    find_blocks(&lines)
+        .into_iter()
+        .filter_map(|block| {
+            let original = lines[block.first_line..=block.last_line].join(newline);
+            let sorted = render_block(block.entries, newline);
+            (sorted != original).then(|| TextEdit {
+                range: Range::new(
+                    Position::new(block.first_line as u32, 0),
+                    Position::new(
+                        block.last_line as u32,
+                        lines[block.last_line].encode_utf16().count() as u32,
+                    ),
+                ),
+                new_text: sorted,
+            })
+        })
+        .collect()
+}
+
+fn find_blocks<'a>(lines: &[&'a str]) -> Vec<UseBlock<'a>> {
+    let mut blocks = Vec::new();
+    let mut current: Option<UseBlock<'a>> = None;
+    // Comment lines waiting to see whether a `use` follows them directly
+    let mut comments: Vec<(usize, &'a str)> = Vec::new();
+    let mut index = 0;
+
+    while index < lines.len() {
+        let line = lines[index];
+        let trimmed = line.trim_start();
+
+        if trimmed.starts_with('#') && !(index == 0 && trimmed.starts_with("#!")) {
+            comments.push((index, line));
+            index += 1;
+            continue;
+        }
+
+        if trimmed.is_empty() {
+            // A comment separated from the next line by a blank belongs to no
+            // statement, so it fences off the block like code would
+            if !comments.is_empty() {
+                comments.clear();
+                blocks.extend(current.take());
+            }
+            index += 1;
+            continue;
+        }
+
+        let Some((module, is_version)) = parse_use(trimmed) else {
+            comments.clear();
+            blocks.extend(current.take());
+            index += 1;
+            continue;
+        };
+
+        // Multi-line imports run to the terminating semicolon
+        let start = index;
+        while index < lines.len() && !lines[index].contains(';') {
+            index += 1;
+        }
+        let end = index.min(lines.len() - 1);
+
+        let first_line = comments.first().map_or(start, |&(line, _)| line);
+        let mut entry_lines: Vec<&str> = comments.drain(..).map(|(_, line)| line).collect();
+        entry_lines.extend_from_slice(&lines[start..=end]);
+
+        let block = current.get_or_insert_with(|| UseBlock {
+            first_line,
+            last_line: end,
+            entries: Vec::new(),
+        });
+        block.last_line = end;
+        block.entries.push(UseEntry {
+            group: classify(module, is_version),
+            is_version,
+            module,
+            lines: entry_lines,
+        });
+
+        index = end + 1;
+    }
+
+    blocks.extend(current);
+    blocks
+}
+
+/// Module name and whether it is a `use VERSION`, for a line starting a `use`
+fn parse_use(trimmed: &str) -> Option<(&str, bool)> {
+    let rest = trimmed.strip_prefix("use")?;
+    if !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let rest = rest.trim_start();
+    let end = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == ':' || c == '.'))
+        .unwrap_or(rest.len());
+    let module = &rest[..end];
+    let first = module.chars().next()?;
+
+    let is_version = first.is_ascii_digit()
+        || (first == 'v' && module[1..].starts_with(|c: char| c.is_ascii_digit()));
+    Some((module, is_version))
+}
+
+fn classify(module: &str, is_version: bool) -> UseGroup {
+    if is_version {
+        UseGroup::Pragma
+    } else if is_pragma(module) {
+        UseGroup::Pragma
+    } else if CORE_MODULES.contains(&module) {
+        UseGroup::Core
+    } else {
+        UseGroup::ThirdParty
+    }
+}
+
+fn render_block(mut entries: Vec<UseEntry<'_>>, newline: &str) -> String {
+    // Stable, so duplicate imports keep their relative order
+    entries.sort_by(|a, b| {
+        (a.group, !a.is_version, a.module.to_ascii_lowercase()).cmp(&(
+            b.group,
+            !b.is_version,
+            b.module.to_ascii_lowercase(),
+        ))
+    });
+
+    let mut out = String::new();
+    let mut previous_group = None;
+    for entry in &entries {
+        if let Some(group) = previous_group {
+            out.push_str(newline);
+            if group != entry.group {
+                out.push_str(newline);
+            }
+        }
+        out.push_str(&entry.lines.join(newline));
+        previous_group = Some(entry.group);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(text: &str, edits: &[TextEdit]) -> String {
+        let lines: Vec<&str> = text.lines().collect();
+        let mut out: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+        for edit in edits.iter().rev() {
+            let start = edit.range.start.line as usize;
+            let end = edit.range.end.line as usize;
+            out.splice(start..=end, edit.new_text.lines().map(str::to_string));
+        }
+        out.join("\n")
+    }
+
+    #[test]
+    fn comments_travel_with_their_use_line() {
+        let text = "#!/usr/bin/perl\n# JSON for the API\nuse JSON::XS;\nuse strict; # always\n";
+        let edits = sort_use_statements(text);
+
+        assert_eq!(
+            apply(text, &edits),
+            "#!/usr/bin/perl\nuse strict; # always\n\n# JSON for the API\nuse JSON::XS;"
+        );
+    }
+
+    #[test]
+    fn sorted_block_needs_no_edit() {
+        let text = "use v5.36;\nuse strict;\nuse warnings;\n\nuse Carp;\n\nuse Moo;\n";
+        assert!(sort_use_statements(text).is_empty());
+    }
+
+    #[test]
+    fn pragmas_group_ahead_of_core_modules() {
+        let text = "use POSIX;\nuse lib 'lib';\nuse constant PI => 3;\n";
+        let edits = sort_use_statements(text);
+
+        assert_eq!(apply(text, &edits), "use constant PI => 3;\nuse lib 'lib';\n\nuse POSIX;");
+    }
+
+    #[test]
+    fn multi_line_import_stays_whole() {
+        let text = "use Moo;\nuse List::Util qw(\n    first\n    sum\n);\n";
+        let edits = sort_use_statements(text);
+
+        assert_eq!(apply(text, &edits), "use List::Util qw(\n    first\n    sum\n);\n\nuse Moo;");
+    }
+}
@@ -222,9 +222,10 @@
 //! let ast = parser.parse()?;
 //!
 //! // Convert parser errors to LSP diagnostics
+//! let line_index = LineIndex::new(code.to_string());
 //! let diagnostics: Vec<_> = parser.errors()
 //!     .iter()
-//!     .map(|e| to_lsp_diagnostic(e, &ast))
+//!     .map(|e| to_lsp_diagnostic(e, &line_index, &uri))
 //!     .collect();
 //! ```
 //!
@@ -429,10 +430,6 @@ pub(crate) mod semantic {
     pub use perl_parser::semantic::*;
 }
 
-pub(crate) mod error {
-    pub use perl_parser::error::*;
-}
-
 pub(crate) mod completion {
     pub use crate::features::completion::*;
 }
@@ -524,3 +521,37 @@ pub fn run_stdio() -> Result<(), Box<dyn std::error::Error>> {
     let mut server = LspServer::new();
     server.run().map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
 }
+
+/// Run the LSP server over a TCP socket.
+///
+/// Binds `127.0.0.1:port`, accepts a single client, and serves it with the same
+/// message loop and Content-Length framing as stdio mode. Port `0` binds an
+/// ephemeral port; the bound address is logged to stderr as
+/// `Perl LSP listening on <addr>`. Returns once the client disconnects.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The port cannot be bound or the client cannot be accepted
+/// - The connection fails other than by the client disconnecting
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// perl_lsp::run_socket(9257)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn run_socket(port: u16) -> Result<(), Box<dyn std::error::Error>> {
+    use transport::TcpTransport;
+
+    let listener = TcpTransport::bind(("127.0.0.1", port))?;
+    eprintln!("Perl LSP listening on {}", listener.local_addr()?);
+
+    let transport = TcpTransport::accept(&listener)?;
+    eprintln!("Accepted connection from {}", transport.peer_addr());
+
+    let mut server = LspServer::new();
+    server.serve_transport(transport).map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
@@ -5,6 +5,8 @@
 //! - Pull diagnostics: Client-initiated via `textDocument/diagnostic` and `workspace/diagnostic`
 
 use super::*;
+use crate::convert::to_lsp_diagnostic;
+use perl_position_tracking::LineIndex;
 
 impl LspServer {
     /// Generate markdown-formatted diagnostic message (LSP 3.18)
@@ -25,6 +27,17 @@ impl LspServer {
         if let Some(c) = code { format!("**{}**: {}", c, message) } else { message.to_string() }
     }
 
+    /// Diagnostics provider for a document, with the configured opt-in hints enabled
+    pub(crate) fn diagnostics_provider(
+        &self,
+        ast: &Arc<Node>,
+        text: &str,
+        uri: &str,
+    ) -> DiagnosticsProvider {
+        let hints = self.config.lock().diagnostic_hints;
+        DiagnosticsProvider::new(ast, text.to_string()).with_uri(uri).with_hints(hints)
+    }
+
     /// Publish diagnostics for a document (push diagnostics)
     ///
     /// Computes and publishes diagnostics for a Perl document including syntax
@@ -56,7 +69,7 @@ impl LspServer {
         if let Some(doc) = documents.get(uri) {
             let lsp_diagnostics: Vec<Value> = if let Some(ast) = &doc.ast {
                 // Get diagnostics (already includes unused variable detection)
-                let provider = DiagnosticsProvider::new(ast, doc.text.clone());
+                let provider = self.diagnostics_provider(ast, &doc.text, uri);
                 let mut diagnostics = provider.get_diagnostics(ast, &doc.parse_errors, &doc.text);
 
                 // Add Perl::Critic built-in analysis
@@ -113,41 +126,12 @@ impl LspServer {
                     .collect()
             } else {
                 // No AST available (parse failed completely), just report parse errors
+                let line_index = LineIndex::new(doc.text.clone());
+                let lsp_uri = parse_uri(uri);
                 doc.parse_errors
                     .iter()
-                    .map(|e| {
-                        // Extract location and message from error enum
-                        let (location, message) = match e {
-                            crate::error::ParseError::UnexpectedToken {
-                                location,
-                                expected,
-                                found,
-                            } => (*location, format!("Expected {}, found {}", expected, found)),
-                            crate::error::ParseError::SyntaxError { location, message } => {
-                                (*location, message.clone())
-                            }
-                            crate::error::ParseError::UnexpectedEof => {
-                                (doc.text.len(), "Unexpected end of input".to_string())
-                            }
-                            crate::error::ParseError::LexerError { message } => {
-                                (0, message.clone())
-                            }
-                            _ => (0, e.to_string()),
-                        };
-
-                        // Convert byte offset to line/column
-                        let (line, character) = self.offset_to_pos16(doc, location);
-
-                        json!({
-                            "range": {
-                                "start": {"line": line, "character": character},
-                                "end": {"line": line, "character": character + 1},
-                            },
-                            "severity": 1, // Error
-                            "code": "parse-error",
-                            "source": "perl-parser",
-                            "message": message,
-                        })
+                    .filter_map(|e| {
+                        serde_json::to_value(to_lsp_diagnostic(e, &line_index, &lsp_uri)).ok()
                     })
                     .collect()
             };
@@ -212,13 +196,11 @@ impl LspServer {
             if let Some(doc) = self.get_document(&documents, uri) {
                 // Get diagnostics from the existing provider
                 if let Some(ast) = &doc.ast {
-                    let provider = DiagnosticsProvider::new(ast, doc.text.clone());
-                    let diagnostics = provider.get_diagnostics(ast, &doc.parse_errors, &doc.text);
-
                     // Generate a result ID based on content
                     let result_id = format!("{:x}", md5::compute(&doc.text));
 
                     // If the result ID matches the previous one, return unchanged
+                    // without re-running the provider
                     if let Some(prev_id) = previous_result_id {
                         if prev_id == result_id {
                             return Ok(Some(json!({
@@ -228,6 +210,9 @@ impl LspServer {
                         }
                     }
 
+                    let provider = self.diagnostics_provider(ast, &doc.text, uri);
+                    let diagnostics = provider.get_diagnostics(ast, &doc.parse_errors, &doc.text);
+
                     // Convert to LSP diagnostics
                     let lsp_diagnostics: Vec<Value> = diagnostics
                         .into_iter()
@@ -359,7 +344,7 @@ impl LspServer {
                 previous_result_ids.iter().find(|(u, _)| u == uri_str).map(|(_, id)| id.clone());
 
             if let Some(ast) = &doc.ast {
-                let provider = DiagnosticsProvider::new(ast, doc.text.clone());
+                let provider = self.diagnostics_provider(ast, &doc.text, uri_str);
                 let diagnostics = provider.get_diagnostics(ast, &doc.parse_errors, &doc.text);
 
                 // Generate result ID
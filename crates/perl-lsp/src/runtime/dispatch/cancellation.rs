@@ -3,9 +3,28 @@
 //! Provides enhanced cancellation responses and early cancellation checking macros.
 
 use super::super::*;
-use crate::cancellation::{PerlLspCancellationToken, ProviderCleanupContext};
+use crate::cancellation::{
+    GLOBAL_CANCELLATION_REGISTRY, PerlLspCancellationToken, ProviderCleanupContext,
+};
 use serde_json::json;
 
+/// Apply a `$/cancelRequest` notification
+///
+/// Records the id so the request is answered with `RequestCancelled` when it is
+/// dispatched, and cancels its token if the request is already running.
+/// Returns the cancelled id, or `None` when the notification has no `id`.
+pub(crate) fn apply_cancel_request(
+    cancelled: &Mutex<HashSet<Value>>,
+    request: &JsonRpcRequest,
+) -> Option<Value> {
+    let id = request.params.as_ref()?.get("id")?.clone();
+
+    let _ = GLOBAL_CANCELLATION_REGISTRY.cancel_request(&id);
+    cancelled.lock().insert(id.clone());
+
+    Some(id)
+}
+
 /// Enhanced cancelled response with provider context and performance tracking
 pub fn enhanced_cancelled_response(
     token: &PerlLspCancellationToken,
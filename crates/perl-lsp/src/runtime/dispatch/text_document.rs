@@ -262,6 +262,13 @@ impl LspServer {
         self.handle_semantic_tokens(params)
     }
 
+    pub(super) fn handle_semantic_tokens_delta_dispatch(
+        &mut self,
+        params: Option<Value>,
+    ) -> Result<Option<Value>, JsonRpcError> {
+        self.handle_semantic_tokens_delta(params)
+    }
+
     pub(super) fn handle_semantic_tokens_range_dispatch(
         &mut self,
         params: Option<Value>,
@@ -31,7 +31,7 @@ impl LspServer {
             let end_offset = self.pos16_to_offset(doc, end_line, end_char);
 
             // Get diagnostics from the document
-            let diag_provider = DiagnosticsProvider::new(ast, doc.text.clone());
+            let diag_provider = self.diagnostics_provider(ast, &doc.text, uri);
             let diagnostics = diag_provider.get_diagnostics(ast, &doc.parse_errors, &doc.text);
 
             // Get code actions from both providers
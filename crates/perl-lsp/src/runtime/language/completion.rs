@@ -11,6 +11,7 @@ use crate::cancellation::{
     GLOBAL_CANCELLATION_REGISTRY, PerlLspCancellationToken, RequestCleanupGuard,
 };
 use crate::completion::{CompletionItemKind, CompletionProvider};
+use crate::fallback::text::harvest_words;
 use crate::{
     protocol::{JsonRpcError, REQUEST_CANCELLED, req_position, req_uri},
     runtime::routing::{IndexAccessMode, route_index_access},
@@ -240,6 +241,7 @@ impl LspServer {
                                     kind,
                                     detail: symbol.qualified_name,
                                     insert_text: Some(symbol.name),
+                                    insert_text_is_snippet: false,
                                     sort_text: None,
                                     filter_text: None,
                                     documentation: None,
@@ -266,7 +268,8 @@ impl LspServer {
                     .into_iter()
                     .map(|c| {
                         // Determine insertTextFormat based on client capability and completion kind
-                        let is_snippet = c.kind == CompletionItemKind::Snippet;
+                        let is_snippet =
+                            c.kind == CompletionItemKind::Snippet || c.insert_text_is_snippet;
                         let insert_text_format =
                             if is_snippet && self.client_capabilities.snippet_support {
                                 2 // Snippet format
@@ -279,12 +282,14 @@ impl LspServer {
                             "kind": match c.kind {
                                 CompletionItemKind::Variable => 6,
                                 CompletionItemKind::Function => 3,
+                                CompletionItemKind::Method => 2,
                                 CompletionItemKind::Keyword => 14,
                                 CompletionItemKind::Module => 9,
                                 CompletionItemKind::File => 17,
                                 CompletionItemKind::Snippet => 15,
                                 CompletionItemKind::Constant => 14,
                                 CompletionItemKind::Property => 7,
+                                CompletionItemKind::Field => 5,
                             },
                             "insertTextFormat": insert_text_format,
                         });
@@ -297,7 +302,11 @@ impl LspServer {
                         // Only include insertText if it has a value
                         if let Some(mut insert_text) = c.insert_text {
                             // Degrade snippets to plaintext if client doesn't support snippets
-                            if is_snippet && !self.client_capabilities.snippet_support {
+                            if c.insert_text_is_snippet && !self.client_capabilities.snippet_support
+                            {
+                                // Placeholder names are not valid code, so insert a bare call
+                                insert_text = format!("{}()", c.label);
+                            } else if is_snippet && !self.client_capabilities.snippet_support {
                                 // Remove snippet syntax: $1, $0, ${1:placeholder}, etc.
                                 insert_text = Self::degrade_snippet_to_plaintext(&insert_text);
                             }
@@ -458,12 +467,14 @@ impl LspServer {
                             "kind": match c.kind {
                                 CompletionItemKind::Variable => 6,
                                 CompletionItemKind::Function => 3,
+                                CompletionItemKind::Method => 2,
                                 CompletionItemKind::Keyword => 14,
                                 CompletionItemKind::Module => 9,
                                 CompletionItemKind::File => 17,
                                 CompletionItemKind::Snippet => 15,
                                 CompletionItemKind::Constant => 14,
                                 CompletionItemKind::Property => 7,
+                                CompletionItemKind::Field => 5,
                             },
                         });
 
@@ -548,6 +559,7 @@ impl LspServer {
                         documentation: None,
                         insert_text: Some(method.to_string()),
                         additional_edits: vec![],
+                        insert_text_is_snippet: false,
                         sort_text: None,
                         filter_text: None,
                         text_edit_range: None,
@@ -577,6 +589,7 @@ impl LspServer {
                         documentation: None,
                         insert_text: Some("_".to_string()),
                         additional_edits: vec![],
+                        insert_text_is_snippet: false,
                         sort_text: None,
                         filter_text: None,
                         text_edit_range: None,
@@ -593,6 +606,7 @@ impl LspServer {
                         documentation: None,
                         insert_text: Some("ARGV".to_string()),
                         additional_edits: vec![],
+                        insert_text_is_snippet: false,
                         sort_text: None,
                         filter_text: None,
                         text_edit_range: None,
@@ -606,6 +620,7 @@ impl LspServer {
                         documentation: None,
                         insert_text: Some("_".to_string()),
                         additional_edits: vec![],
+                        insert_text_is_snippet: false,
                         sort_text: None,
                         filter_text: None,
                         text_edit_range: None,
@@ -622,6 +637,7 @@ impl LspServer {
                         documentation: None,
                         insert_text: Some("ENV".to_string()),
                         additional_edits: vec![],
+                        insert_text_is_snippet: false,
                         sort_text: None,
                         filter_text: None,
                         text_edit_range: None,
@@ -639,6 +655,7 @@ impl LspServer {
                             documentation: None,
                             insert_text: Some(kw.to_string()),
                             additional_edits: vec![],
+                            insert_text_is_snippet: false,
                             sort_text: None,
                             filter_text: None,
                             text_edit_range: None,
@@ -648,6 +665,51 @@ impl LspServer {
             }
         }
 
+        // Identifiers and keywords that actually occur in this buffer
+        let harvested = harvest_words(content, offset);
+        let (words, kind, detail): (Vec<&String>, _, _) = match sigil {
+            Some(sigil @ ('$' | '@' | '%')) => (
+                harvested.variables.iter().filter(|var| var.starts_with(sigil)).collect(),
+                CompletionItemKind::Variable,
+                "variable (from buffer)",
+            ),
+            _ => (
+                harvested.barewords.iter().collect(),
+                CompletionItemKind::Function,
+                "identifier (from buffer)",
+            ),
+        };
+        let mut seen: std::collections::HashSet<String> =
+            completions.iter().map(|c| c.label.clone()).collect();
+        let harvested_keywords = match sigil {
+            Some('$' | '@' | '%') => &[][..],
+            _ => &harvested.keywords[..],
+        };
+        let candidates = words
+            .into_iter()
+            .map(|word| (word.trim_start_matches(['$', '@', '%']), kind, detail))
+            .chain(
+                harvested_keywords
+                    .iter()
+                    .map(|kw| (kw.as_str(), CompletionItemKind::Keyword, "keyword")),
+            );
+        for (label, kind, detail) in candidates {
+            if label.starts_with(&prefix) && seen.insert(label.to_string()) {
+                completions.push(crate::completion::CompletionItem {
+                    label: label.to_string(),
+                    kind,
+                    detail: Some(detail.to_string()),
+                    documentation: None,
+                    insert_text: Some(label.to_string()),
+                    additional_edits: vec![],
+                    insert_text_is_snippet: false,
+                    sort_text: None,
+                    filter_text: None,
+                    text_edit_range: None,
+                });
+            }
+        }
+
         completions
     }
 
@@ -931,4 +993,48 @@ mod tests {
         let result = LspServer::degrade_snippet_to_plaintext("");
         assert_eq!(result, "");
     }
+
+    /// Complete `sprint` in a fresh document and return the `sprintf` item
+    fn sprintf_completion(snippet_support: bool) -> Result<Value, Box<dyn std::error::Error>> {
+        let mut server = LspServer::default();
+        server.client_capabilities.snippet_support = snippet_support;
+        let uri = "file:///builtin_snippet.pl";
+        server
+            .handle_did_open(Some(json!({
+                "textDocument": {"uri": uri, "languageId": "perl", "version": 1, "text": "sprint"}
+            })))
+            .map_err(|e| e.message.to_string())?;
+
+        let result = server
+            .handle_completion(Some(json!({
+                "textDocument": {"uri": uri},
+                "position": {"line": 0, "character": 6}
+            })))
+            .map_err(|e| e.message.to_string())?
+            .ok_or("expected completion result")?;
+        let items = result["items"].as_array().ok_or("expected items")?;
+        let item = items
+            .iter()
+            .find(|item| item["label"] == "sprintf")
+            .ok_or("expected sprintf completion")?;
+        Ok(item.clone())
+    }
+
+    #[test]
+    fn test_builtin_completion_is_snippet_when_client_supports_snippets()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let item = sprintf_completion(true)?;
+        assert_eq!(item["insertTextFormat"], json!(2));
+        assert_eq!(item["insertText"], json!("sprintf(${1:FORMAT}, ${2:LIST})"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_builtin_completion_falls_back_to_plain_call() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let item = sprintf_completion(false)?;
+        assert_eq!(item["insertTextFormat"], json!(1));
+        assert_eq!(item["insertText"], json!("sprintf()"));
+        Ok(())
+    }
 }
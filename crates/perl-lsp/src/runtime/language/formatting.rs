@@ -9,6 +9,12 @@ use crate::features::formatting::{CodeFormatter, FormattingOptions};
 use crate::protocol::{invalid_params, req_position, req_range, req_uri};
 
 impl LspServer {
+    /// Formatter honouring the configured skip-region markers
+    fn code_formatter(&self) -> CodeFormatter {
+        let markers = self.config.lock().format_skip_markers.clone();
+        CodeFormatter::new().with_format_skip_markers(markers)
+    }
+
     /// Handle textDocument/onTypeFormatting request
     pub(crate) fn handle_on_type_formatting(
         &self,
@@ -18,6 +24,14 @@ impl LspServer {
             let uri = req_uri(&p)?;
             let ch = p["ch"].as_str().and_then(|s| s.chars().next()).unwrap_or('\n');
             let (line, col) = req_position(&p)?;
+            let options: FormattingOptions = serde_json::from_value(p["options"].clone())
+                .unwrap_or(FormattingOptions {
+                    tab_size: 4,
+                    insert_spaces: true,
+                    trim_trailing_whitespace: None,
+                    insert_final_newline: None,
+                    trim_final_newlines: None,
+                });
 
             let documents = self.documents_guard();
             let doc = self.get_document(&documents, uri).ok_or_else(|| JsonRpcError {
@@ -27,7 +41,7 @@ impl LspServer {
             })?;
 
             if let Some(edits) =
-                crate::on_type_formatting::compute_on_type_edit(&doc.text, line, col, ch)
+                crate::on_type_formatting::compute_on_type_edit(&doc.text, line, col, ch, &options)
             {
                 return Ok(Some(json!(edits)));
             }
@@ -61,7 +75,7 @@ impl LspServer {
 
             let documents = self.documents_guard();
             if let Some(doc) = self.get_document(&documents, uri) {
-                let formatter = CodeFormatter::new();
+                let formatter = self.code_formatter();
                 match formatter.format_document(&doc.text, &options) {
                     Ok(edits) => {
                         let lsp_edits: Vec<Value> = edits
@@ -126,7 +140,7 @@ impl LspServer {
 
             let documents = self.documents_guard();
             if let Some(doc) = self.get_document(&documents, uri) {
-                let formatter = CodeFormatter::new();
+                let formatter = self.code_formatter();
                 match formatter.format_range(&doc.text, &range, &options) {
                     Ok(edits) => {
                         let lsp_edits: Vec<Value> = edits
@@ -195,7 +209,7 @@ impl LspServer {
 
             let documents = self.documents_guard();
             if let Some(doc) = self.get_document(&documents, uri) {
-                let formatter = CodeFormatter::new();
+                let formatter = self.code_formatter();
                 let mut all_edits = Vec::new();
 
                 // Process each range
@@ -377,7 +377,8 @@ impl LspServer {
             let documents = self.documents_guard();
             if let Some(doc) = self.get_document(&documents, uri) {
                 if let Some(ref ast) = doc.ast {
-                    let provider = CallHierarchyProvider::new(doc.text.clone(), uri.to_string());
+                    let provider = CallHierarchyProvider::new(doc.text.clone(), uri.to_string())
+                        .with_class_hierarchy(Self::build_class_hierarchy(&documents));
                     if let Some(items) = provider.prepare(ast, line, character) {
                         let json_items: Vec<_> = items.iter().map(|item| item.to_json()).collect();
                         return Ok(Some(json!(json_items)));
@@ -401,17 +402,28 @@ impl LspServer {
             eprintln!("Getting incoming calls for: {}", item["name"].as_str().unwrap_or(""));
 
             let documents = self.documents_guard();
-            if let Some(doc) = self.get_document(&documents, uri) {
-                if let Some(ref ast) = doc.ast {
-                    // Reconstruct the CallHierarchyItem from JSON
-                    let ch_item = self.json_to_call_hierarchy_item(item)?;
-
-                    let provider = CallHierarchyProvider::new(doc.text.clone(), uri.to_string());
-                    let calls = provider.incoming_calls(ast, &ch_item);
-
-                    let json_calls: Vec<_> = calls.iter().map(|call| call.to_json()).collect();
-                    return Ok(Some(json!(json_calls)));
+            if self.get_document(&documents, uri).is_some() {
+                // Reconstruct the CallHierarchyItem from JSON
+                let ch_item = self.json_to_call_hierarchy_item(item)?;
+                let hierarchy = Self::build_class_hierarchy(&documents);
+
+                // Callers may live in any open document, e.g. subclasses of the item's package
+                let mut doc_uris: Vec<&String> = documents.keys().collect();
+                doc_uris.sort();
+
+                let mut calls = Vec::new();
+                for doc_uri in doc_uris {
+                    let doc = &documents[doc_uri];
+                    if let Some(ref ast) = doc.ast {
+                        let provider =
+                            CallHierarchyProvider::new(doc.text.clone(), doc_uri.clone())
+                                .with_class_hierarchy(hierarchy.clone());
+                        calls.extend(provider.incoming_calls(ast, &ch_item));
+                    }
                 }
+
+                let json_calls: Vec<_> = calls.iter().map(|call| call.to_json()).collect();
+                return Ok(Some(json!(json_calls)));
             }
         }
 
@@ -435,7 +447,8 @@ impl LspServer {
                     // Reconstruct the CallHierarchyItem from JSON
                     let ch_item = self.json_to_call_hierarchy_item(item)?;
 
-                    let provider = CallHierarchyProvider::new(doc.text.clone(), uri.to_string());
+                    let provider = CallHierarchyProvider::new(doc.text.clone(), uri.to_string())
+                        .with_class_hierarchy(Self::build_class_hierarchy(&documents));
                     let calls = provider.outgoing_calls(ast, &ch_item);
 
                     let json_calls: Vec<_> = calls.iter().map(|call| call.to_json()).collect();
@@ -447,6 +460,19 @@ impl LspServer {
         Ok(Some(json!([])))
     }
 
+    /// Build the package inheritance view used to resolve method dispatch
+    fn build_class_hierarchy(
+        documents: &HashMap<String, DocumentState>,
+    ) -> std::sync::Arc<crate::call_hierarchy_provider::ClassHierarchy> {
+        let mut hierarchy = crate::call_hierarchy_provider::ClassHierarchy::new();
+        for (doc_uri, doc) in documents {
+            if let Some(ref ast) = doc.ast {
+                hierarchy.add_document(doc_uri, &doc.text, ast);
+            }
+        }
+        std::sync::Arc::new(hierarchy)
+    }
+
     /// Convert JSON to CallHierarchyItem
     pub(crate) fn json_to_call_hierarchy_item(
         &self,
@@ -486,7 +512,8 @@ impl LspServer {
         };
 
         let detail = json["detail"].as_str().map(|s| s.to_string());
+        let package = json["data"]["package"].as_str().map(|s| s.to_string());
 
-        Ok(CallHierarchyItem { name, kind, uri, range, selection_range, detail })
+        Ok(CallHierarchyItem { name, kind, uri, range, selection_range, detail, package })
     }
 }
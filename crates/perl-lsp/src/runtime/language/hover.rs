@@ -5,6 +5,7 @@
 use super::super::*;
 use crate::cancellation::RequestCleanupGuard;
 use crate::protocol::{req_position, req_uri};
+use perl_parser::type_inference::{ExpressionContext, TypeInferenceEngine};
 
 impl LspServer {
     /// Handle textDocument/hover request for symbol information display
@@ -40,9 +41,9 @@ impl LspServer {
 
             let documents = self.documents_guard();
             if let Some(doc) = self.get_document(&documents, uri) {
-                if let Some(ast) = &doc.ast {
-                    let offset = self.pos16_to_offset(doc, line, character);
+                let offset = self.pos16_to_offset(doc, line, character);
 
+                if let Some(ast) = &doc.ast {
                     // Use SemanticAnalyzer for type information
                     // Pass the source text to enable proper symbol resolution
                     let analyzer =
@@ -93,13 +94,35 @@ impl LspServer {
                             .map(|d| format!("\n\n{}", d))
                             .unwrap_or_default();
 
+                        // Arrays and hashes yield a count in scalar context,
+                        // which is easy to miss at the use site
+                        let context_info = match symbol_info.kind {
+                            crate::symbol::SymbolKind::Variable(
+                                kind @ (VarKind::Array | VarKind::Hash),
+                            ) => {
+                                let mut type_engine = TypeInferenceEngine::new();
+                                let _ = type_engine.infer(ast);
+                                match type_engine.context_at(offset) {
+                                    Some(ExpressionContext::Scalar) if kind == VarKind::Array => {
+                                        "\n**Context**: evaluated in scalar context (count)"
+                                    }
+                                    Some(ExpressionContext::Scalar) => {
+                                        "\n**Context**: evaluated in scalar context (key count)"
+                                    }
+                                    _ => "",
+                                }
+                            }
+                            _ => "",
+                        };
+
                         return Ok(Some(json!({
                             "contents": {
                                 "kind": "markdown",
-                                "value": format!("**{}**\n\n`{}`{}{}{}",
+                                "value": format!("**{}**\n\n`{}`{}{}{}{}",
                                     kind_str,
                                     full_name,
                                     decl_info,
+                                    context_info,
                                     attrs_info,
                                     doc_info
                                 ),
@@ -118,6 +141,22 @@ impl LspServer {
                             },
                         })));
                     }
+                } else {
+                    // The document did not parse; show the raw word so hover stays useful
+                    let hover_text = self.get_token_at_position(&doc.text, offset);
+
+                    if !hover_text.is_empty() {
+                        return Ok(Some(json!({
+                            "contents": {
+                                "kind": "markdown",
+                                "value": format!(
+                                    "**Perl**: `{}`\n\n_Parsing unavailable for this file; \
+                                     showing text only._",
+                                    hover_text
+                                ),
+                            },
+                        })));
+                    }
                 }
             }
         }
@@ -443,7 +482,7 @@ impl LspServer {
                         self.extract_signature_params(param, &mut params);
                     }
                 }
-            } else {
+            } else if let Some(body) = body {
                 // Look for my (...) = @_; pattern in the body
                 self.extract_params_from_body(body, &mut params);
             }
@@ -115,6 +115,17 @@ impl LspServer {
                     .with_parent_map(&doc.parent_map)
                     .with_doc_version(doc.version);
 
+                    // Follow `use Module qw(name)` to the sub in the module's file
+                    #[cfg(feature = "workspace")]
+                    if let Some((module, name)) = provider.find_import(offset) {
+                        if let Some(result) =
+                            lookup_workspace_definition(self.coordinator(), &module, &name)
+                        {
+                            return Ok(Some(result));
+                        }
+                        // Module outside the workspace: link to the `use` statement below
+                    }
+
                     // Find declaration at the position
                     if let Some(location_links) = provider.find_declaration(offset, doc.version) {
                         // Check client capability and return appropriate format
@@ -366,6 +377,17 @@ impl LspServer {
                     .with_parent_map(&doc.parent_map)
                     .with_doc_version(doc.version);
 
+                    // Follow `use Module qw(name)` to the sub in the module's file
+                    #[cfg(feature = "workspace")]
+                    if let Some((module, name)) = provider.find_import(offset) {
+                        if let Some(result) =
+                            lookup_workspace_definition(self.coordinator(), &module, &name)
+                        {
+                            return Ok(Some(result));
+                        }
+                        // Module outside the workspace: link to the `use` statement below
+                    }
+
                     if let Some(location_links) = provider.find_declaration(offset, doc.version) {
                         // Convert to Location format for definition
                         let result: Vec<Value> = location_links
@@ -75,6 +75,19 @@ impl LspServer {
                                 ) {
                                     eprintln!("Looking for references of {:?}", symbol_key);
 
+                                    // Package subs: every indexed file, declaration per the request
+                                    if symbol_key.kind == crate::workspace_index::SymKind::Sub {
+                                        let mut locations =
+                                            crate::features::references::ReferencesProvider::new(
+                                                index,
+                                            )
+                                            .find_references(&symbol_key, include_declaration);
+                                        if !locations.is_empty() {
+                                            locations.truncate(cap);
+                                            return Ok(Some(json!(locations)));
+                                        }
+                                    }
+
                                     // Try to find references using the symbol key
                                     let mut all_refs = index.find_refs(&symbol_key);
 
@@ -1,6 +1,7 @@
 //! Semantic tokens handlers
 //!
-//! Handles textDocument/semanticTokens/full and textDocument/semanticTokens/range requests.
+//! Handles textDocument/semanticTokens/full, textDocument/semanticTokens/full/delta and
+//! textDocument/semanticTokens/range requests.
 //!
 //! Includes deadline enforcement to prevent blocking on large files.
 
@@ -14,42 +15,93 @@ impl LspServer {
     ///
     /// Uses deadline enforcement to prevent blocking on very large files.
     /// If deadline is exceeded, returns partial tokens collected so far.
+    /// The result carries a `resultId` so later delta requests can diff against it.
     pub(crate) fn handle_semantic_tokens(
         &self,
         params: Option<Value>,
     ) -> Result<Option<Value>, JsonRpcError> {
-        let start = Instant::now();
-        let deadline = semantic_tokens_deadline();
-
         if let Some(p) = params {
             let uri = req_uri(&p)?;
-            let documents = self.documents_guard();
-            let doc = self.get_document(&documents, uri).ok_or_else(|| JsonRpcError {
-                code: INVALID_REQUEST,
-                message: format!("Document not open: {}", uri),
-                data: None,
-            })?;
-            if let Some(ref ast) = doc.ast {
-                let data =
-                    crate::semantic_tokens::collect_semantic_tokens(ast, &doc.text, &|off| {
-                        self.offset_to_pos16(doc, off)
-                    });
-                let flat_data: Vec<_> = data.into_iter().flatten().collect();
-
-                if start.elapsed() >= deadline {
-                    eprintln!(
-                        "SemanticTokens: deadline exceeded ({:?}), returning {} tokens",
-                        start.elapsed(),
-                        flat_data.len() / 5 // Each token is 5 u32s
-                    );
-                }
+            if let Some((result_id, data)) = self.compute_semantic_tokens(uri)? {
+                self.semantic_tokens_cache
+                    .lock()
+                    .insert(uri.to_string(), (result_id.clone(), data.clone()));
+                return Ok(Some(json!({ "resultId": result_id, "data": data })));
+            }
+        }
+        Ok(Some(json!({ "data": [] })))
+    }
 
-                return Ok(Some(json!({ "data": flat_data })));
+    /// Handle textDocument/semanticTokens/full/delta request
+    ///
+    /// When `previousResultId` matches the last result sent for the document,
+    /// returns the edits that turn that result into the current one. Otherwise
+    /// falls back to a full result, which the protocol permits.
+    pub(crate) fn handle_semantic_tokens_delta(
+        &self,
+        params: Option<Value>,
+    ) -> Result<Option<Value>, JsonRpcError> {
+        if let Some(p) = params {
+            let uri = req_uri(&p)?;
+            let previous_result_id = p.get("previousResultId").and_then(|v| v.as_str());
+            if let Some((result_id, data)) = self.compute_semantic_tokens(uri)? {
+                let previous = self
+                    .semantic_tokens_cache
+                    .lock()
+                    .insert(uri.to_string(), (result_id.clone(), data.clone()));
+
+                return Ok(Some(match previous {
+                    Some((previous_id, previous_data))
+                        if Some(previous_id.as_str()) == previous_result_id =>
+                    {
+                        let edits =
+                            crate::semantic_tokens::semantic_tokens_delta(&previous_data, &data);
+                        json!({ "resultId": result_id, "edits": edits })
+                    }
+                    _ => json!({ "resultId": result_id, "data": data }),
+                }));
             }
         }
         Ok(Some(json!({ "data": [] })))
     }
 
+    /// Compute the encoded semantic tokens for an open document.
+    ///
+    /// Returns the result id (derived from the document version) together with
+    /// the flat token data, or `None` if the document has no AST.
+    fn compute_semantic_tokens(
+        &self,
+        uri: &str,
+    ) -> Result<Option<(String, Vec<u32>)>, JsonRpcError> {
+        let start = Instant::now();
+        let deadline = semantic_tokens_deadline();
+
+        let documents = self.documents_guard();
+        let doc = self.get_document(&documents, uri).ok_or_else(|| JsonRpcError {
+            code: INVALID_REQUEST,
+            message: format!("Document not open: {}", uri),
+            data: None,
+        })?;
+        let Some(ref ast) = doc.ast else {
+            return Ok(None);
+        };
+
+        let data = crate::semantic_tokens::collect_semantic_tokens(ast, &doc.text, &|off| {
+            self.offset_to_pos16(doc, off)
+        });
+        let flat_data: Vec<u32> = data.into_iter().flatten().collect();
+
+        if start.elapsed() >= deadline {
+            eprintln!(
+                "SemanticTokens: deadline exceeded ({:?}), returning {} tokens",
+                start.elapsed(),
+                flat_data.len() / 5 // Each token is 5 u32s
+            );
+        }
+
+        Ok(Some((doc.version.to_string(), flat_data)))
+    }
+
     /// Handle semantic tokens full request (alternative method name)
     #[allow(dead_code)] // Alternative implementation using SemanticTokensProvider
     pub(crate) fn handle_semantic_tokens_full(
@@ -185,35 +185,23 @@ impl LspServer {
             if let Some(doc) = self.get_document(&documents, uri) {
                 let mut lsp_ranges = Vec::new();
 
-                // Add text-based data section folding
-                if let Some(marker_offset) = crate::util::find_data_marker_byte_lexed(&doc.text) {
-                    let marker_line = offset_to_line(&doc.text, marker_offset);
-                    let total_lines = doc.text.lines().count();
-
-                    // Add fold for data section body if it exists
-                    if marker_line + 1 < total_lines {
-                        lsp_ranges.push(json!({
-                            "startLine": marker_line + 1,
-                            "endLine": total_lines - 1,
-                            "kind": "comment"
-                        }));
-                    }
-                }
-
-                // Add heredoc folding ranges from lexer
-                let heredoc_ranges =
-                    crate::folding::FoldingRangeExtractor::extract_heredoc_ranges(&doc.text);
-                for range in heredoc_ranges {
-                    // Use saturating_sub to ensure we're inside the body
-                    let (start_line, _) = self.offset_to_pos16(doc, range.start_offset);
-                    let (end_line, _) =
-                        self.offset_to_pos16(doc, range.end_offset.saturating_sub(1));
-
-                    if start_line <= end_line {
+                // Add POD, heredoc body and data section folding from the lexer
+                let lexical_ranges =
+                    crate::folding::FoldingRangeExtractor::extract_lexical_ranges(&doc.text);
+                for range in &lexical_ranges {
+                    // Ranges end just past their last line, so step back into it
+                    let start_line = offset_to_line(&doc.text, range.start_offset);
+                    let end_line = offset_to_line(&doc.text, range.end_offset.saturating_sub(1));
+
+                    if end_line > start_line {
+                        let kind = match range.kind {
+                            Some(crate::folding::FoldingRangeKind::Comment) => "comment",
+                            _ => "region",
+                        };
                         lsp_ranges.push(json!({
                             "startLine": start_line,
                             "endLine": end_line,
-                            "kind": "region"
+                            "kind": kind
                         }));
                     }
                 }
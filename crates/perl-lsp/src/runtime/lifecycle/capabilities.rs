@@ -191,7 +191,7 @@ impl LspServer {
         eprintln!("Tool availability: perltidy={}, perlcritic={}", has_perltidy, has_perlcritic);
 
         // Incremental text sync: ropey handles range-based edits correctly
-        let sync_kind = 2;
+        let sync_kind = lsp_types::TextDocumentSyncKind::INCREMENTAL;
 
         // Build capabilities using catalog-driven approach
         let mut build_flags = if cfg!(feature = "lsp-ga-lock") {
@@ -69,7 +69,7 @@ use crate::{
         ClientCapabilities, DocumentState, ServerConfig, WorkspaceConfig,
         normalize_package_separator,
     },
-    transport::{log_response, read_message, write_message},
+    transport::{Transport, is_disconnect, log_response, read_message, write_message},
     // Import text processing helpers
     util::{
         byte_to_line_col, byte_to_utf16_col, extract_module_reference, get_text_around_offset,
@@ -91,6 +91,7 @@ use std::sync::{
 use url::Url;
 
 use crate::util::uri::parse_uri;
+use dispatch::apply_cancel_request;
 #[cfg(feature = "workspace")]
 use perl_parser::workspace_index::{
     IndexCoordinator, LspWorkspaceSymbol, WorkspaceIndex, uri_to_fs_path,
@@ -178,6 +179,9 @@ pub struct LspServer {
     pub(crate) notebook_store: notebook::NotebookStore,
     /// Trace level set by client via $/setTrace (off, messages, verbose)
     trace_level: Arc<Mutex<String>>,
+    /// Last semantic tokens sent per document URI as (result id, encoded data),
+    /// used to answer semanticTokens/full/delta requests
+    semantic_tokens_cache: Arc<Mutex<HashMap<String, (String, Vec<u32>)>>>,
 }
 
 // Note: DocumentState, ServerConfig, and normalize_package_separator are
@@ -225,6 +229,7 @@ impl LspServer {
             refresh_controller: refresh::RefreshController::new(),
             notebook_store: notebook::NotebookStore::new(),
             trace_level: Arc::new(Mutex::new("off".to_string())),
+            semantic_tokens_cache: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -301,6 +306,7 @@ impl LspServer {
             refresh_controller: refresh::RefreshController::new(),
             notebook_store: notebook::NotebookStore::new(),
             trace_level: Arc::new(Mutex::new("off".to_string())),
+            semantic_tokens_cache: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -346,6 +352,7 @@ impl LspServer {
             refresh_controller: refresh::RefreshController::new(),
             notebook_store: notebook::NotebookStore::new(),
             trace_level: Arc::new(Mutex::new("off".to_string())),
+            semantic_tokens_cache: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -493,16 +500,72 @@ impl LspServer {
     /// Run the LSP server using stdio
     pub fn run(&mut self) -> io::Result<()> {
         eprintln!("LSP server started (stdio)");
-        let reader_arc = Arc::clone(&self.reader);
-        let mut reader = reader_arc.lock();
-        self.serve(&mut **reader)
+        let reader = Arc::clone(&self.reader);
+        self.serve_messages(move || read_message(&mut **reader.lock()))
+    }
+
+    /// Serve one client connected through `transport`
+    ///
+    /// Responses and notifications go to the transport's writer. The session
+    /// ends cleanly when the client closes or drops the connection.
+    pub fn serve_transport<T: Transport>(&mut self, transport: T) -> io::Result<()> {
+        let (reader, writer) = transport.split()?;
+        *self.output.lock() = Box::new(writer);
+
+        match self.serve(reader) {
+            Err(e) if is_disconnect(&e) => {
+                eprintln!("LSP server: client disconnected, shutting down");
+                Ok(())
+            }
+            result => result,
+        }
     }
 
     /// Serve LSP requests from the given reader
-    pub fn serve(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
-        loop {
-            // Read LSP message using transport module
-            match read_message(reader)? {
+    ///
+    /// Messages are read ahead on a separate thread, which applies each
+    /// `$/cancelRequest` as soon as it arrives. A request that is still queued
+    /// behind the one being handled, or that is running and reaches a
+    /// cancellation check, then answers with `RequestCancelled` (-32800) instead
+    /// of a stale result.
+    pub fn serve<R: BufRead + Send + 'static>(&mut self, mut reader: R) -> io::Result<()> {
+        self.serve_messages(move || read_message(&mut reader))
+    }
+
+    /// Handle messages produced by `next_message` until EOF or a write error
+    ///
+    /// The reader thread is detached rather than joined: it may be blocked
+    /// reading from a client that is still connected, so an error writing a
+    /// response returns immediately and the thread exits once its next send
+    /// finds the receiver gone.
+    fn serve_messages<F>(&mut self, mut next_message: F) -> io::Result<()>
+    where
+        F: FnMut() -> io::Result<Option<JsonRpcRequest>> + Send + 'static,
+    {
+        let cancelled = Arc::clone(&self.cancelled);
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        std::thread::spawn(move || {
+            loop {
+                // Read LSP message using transport module
+                let message = next_message();
+                if let Ok(Some(request)) = &message {
+                    if request.method == "$/cancelRequest" {
+                        apply_cancel_request(&cancelled, request);
+                    }
+                }
+
+                let done = !matches!(message, Ok(Some(_)));
+                if sender.send(message).is_err() || done {
+                    break;
+                }
+            }
+        });
+
+        for message in receiver {
+            match message? {
+                // Already applied by the reader thread
+                Some(request) if request.method == "$/cancelRequest" => {}
                 Some(request) => {
                     eprintln!("Received request: {}", request.method);
 
@@ -602,19 +665,15 @@ impl LspServer {
     pub(crate) fn run_test(&self, test_id: &str) -> Result<Option<Value>, JsonRpcError> {
         eprintln!("Running test: {}", test_id);
 
-        // Parse test ID to get URI and test name
-        let parts: Vec<&str> = test_id.split("::").collect();
-        if parts.len() < 2 {
+        // Test IDs are `uri::test name`; the runner selects the test from the full ID
+        let Some((uri, _)) = test_id.split_once("::") else {
             return Ok(Some(json!({"status": "error", "message": "Invalid test ID"})));
-        }
-
-        let uri = parts[0];
-        let test_name = parts[1..].join("::");
+        };
 
         let documents = self.documents.lock();
         if let Some(doc) = documents.get(uri) {
             let runner = TestRunner::new(doc.text.clone(), uri.to_string());
-            let results = runner.run_test(&test_name);
+            let results = runner.run_test(test_id);
 
             // Convert results to JSON
             let json_results: Vec<Value> = results
@@ -866,13 +925,15 @@ impl LspServer {
                     });
 
                     // Recurse into body with this subroutine as container
-                    self.extract_symbols_recursive(
-                        body,
-                        source,
-                        uri,
-                        Some(sub_name.as_str()),
-                        symbols,
-                    );
+                    if let Some(body) = body {
+                        self.extract_symbols_recursive(
+                            body,
+                            source,
+                            uri,
+                            Some(sub_name.as_str()),
+                            symbols,
+                        );
+                    }
                 }
             }
 
@@ -1132,7 +1193,7 @@ impl LspServer {
                 }
             }
 
-            NodeKind::Subroutine { body, .. } => {
+            NodeKind::Subroutine { body: Some(body), .. } => {
                 count += self.count_references(body, symbol_name, symbol_kind);
             }
 
@@ -4,6 +4,7 @@
 
 use super::*;
 use crate::protocol::invalid_params;
+use perl_parser::document_store::DocumentStore;
 #[cfg(feature = "workspace")]
 use perl_parser::workspace_index::{IndexPhase, IndexState};
 
@@ -86,7 +87,7 @@ impl LspServer {
             // Index symbols for workspace search
             // Note: Indexing is a MUTATION operation - use coordinator.index() directly
             // This must happen BEFORE notify_parse_complete to keep work inside the tracking window
-            if let Some(ref _ast) = ast_arc {
+            if let Some(ref ast) = ast_arc {
                 // Update the fast symbol index with symbols from workspace index
                 #[cfg(feature = "workspace")]
                 if let Some(coordinator) = self.coordinator() {
@@ -114,7 +115,7 @@ impl LspServer {
                 if let Some(coordinator) = self.coordinator() {
                     let workspace_index = coordinator.index();
                     if let Ok(url) = url::Url::parse(uri) {
-                        match workspace_index.index_file(url, text.to_string()) {
+                        match workspace_index.update_file(url, text.to_string(), ast) {
                             Ok(()) => {
                                 // Transition to Ready on first successful index if still Building
                                 if matches!(
@@ -172,20 +173,27 @@ impl LspServer {
                 // Get current document state or create new one
                 let mut documents = self.documents.lock();
                 let normalized_uri = self.normalize_uri_key(uri);
-                let mut doc_state = documents
-                    .get(&normalized_uri)
-                    .or_else(|| documents.get(uri))
-                    .cloned()
-                    .unwrap_or_else(|| DocumentState {
-                        rope: ropey::Rope::new(),
-                        text: String::new(),
-                        version,
-                        ast: None,
-                        parse_errors: vec![],
-                        parent_map: ParentMap::default(),
-                        line_starts: LineStartsCache::new(""),
-                        generation: Arc::new(AtomicU32::new(0)),
-                    });
+                let existing =
+                    documents.get(&normalized_uri).or_else(|| documents.get(uri)).cloned();
+
+                // Drop out-of-order changes instead of applying them to newer text
+                if let Some(existing) = &existing {
+                    if let Err(e) = DocumentStore::check_version(uri, existing.version, version) {
+                        eprintln!("Ignoring didChange: {}", e);
+                        return Ok(());
+                    }
+                }
+
+                let mut doc_state = existing.unwrap_or_else(|| DocumentState {
+                    rope: ropey::Rope::new(),
+                    text: String::new(),
+                    version,
+                    ast: None,
+                    parse_errors: vec![],
+                    parent_map: ParentMap::default(),
+                    line_starts: LineStartsCache::new(""),
+                    generation: Arc::new(AtomicU32::new(0)),
+                });
 
                 // Increment generation counter for this change
                 let next_gen = doc_state.generation.fetch_add(1, Ordering::SeqCst).wrapping_add(1);
@@ -304,7 +312,7 @@ impl LspServer {
                 // Index symbols for workspace search
                 // Note: Indexing is a MUTATION operation - use coordinator.index() directly
                 // This must happen BEFORE notify_parse_complete to keep work inside the tracking window
-                if let Some(ref _ast) = ast_arc {
+                if let Some(ref ast) = ast_arc {
                     // Update the workspace-wide index for cross-file features
                     // Note: version is maintained by the document state
                     #[cfg(feature = "workspace")]
@@ -317,7 +325,8 @@ impl LspServer {
                                 .get(uri)
                                 .map(|d| d.text.clone())
                                 .unwrap_or_default();
-                            if let Err(e) = workspace_index.index_file(url, doc_content) {
+                            // Swap in this file's entries from the AST just parsed
+                            if let Err(e) = workspace_index.update_file(url, doc_content, ast) {
                                 eprintln!("Failed to index file {}: {}", uri, e);
                             }
                         }
@@ -360,6 +369,7 @@ impl LspServer {
 
             // Remove from documents
             self.documents.lock().remove(uri);
+            self.semantic_tokens_cache.lock().remove(uri);
 
             // Clear from workspace index
             // Note: Mutation operation - use coordinator.index() directly
@@ -406,7 +416,7 @@ impl LspServer {
             if let Some(doc) = self.get_document(&documents, uri) {
                 if let Some(ref ast) = doc.ast {
                     // Run diagnostics
-                    let provider = DiagnosticsProvider::new(ast, doc.text.clone());
+                    let provider = self.diagnostics_provider(ast, &doc.text, uri);
                     let diagnostics = provider.get_diagnostics(ast, &doc.parse_errors, &doc.text);
 
                     // Convert diagnostics
@@ -487,7 +497,8 @@ impl LspServer {
                 if config.test_runner_enabled {
                     // Using existing config field as example
                     // Could add format_on_save config option
-                    let formatter = CodeFormatter::new();
+                    let formatter = CodeFormatter::new()
+                        .with_format_skip_markers(config.format_skip_markers.clone());
                     let format_options = FormattingOptions {
                         tab_size: 4,
                         insert_spaces: true,
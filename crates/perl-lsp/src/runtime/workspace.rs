@@ -396,6 +396,24 @@ impl LspServer {
                                 "perl.testRunner.testCommand" => json!(config.test_runner_command),
                                 "perl.testRunner.testArgs" => json!(config.test_runner_args),
                                 "perl.testRunner.testTimeout" => json!(config.test_runner_timeout),
+                                "perl.diagnostics.hints.argsForwardingTailCall" => {
+                                    json!(config.diagnostic_hints.args_forwarding_tail_call)
+                                }
+                                "perl.diagnostics.hints.hoistableLoopDeclarations" => {
+                                    json!(config.diagnostic_hints.hoistable_loop_declarations)
+                                }
+                                "perl.diagnostics.hints.implicitReturns.enabled" => {
+                                    json!(config.diagnostic_hints.implicit_returns.is_some())
+                                }
+                                "perl.diagnostics.hints.implicitReturns.maxTrivialNodes" => {
+                                    json!(
+                                        config
+                                            .diagnostic_hints
+                                            .implicit_returns
+                                            .unwrap_or_default()
+                                            .max_trivial_nodes
+                                    )
+                                }
                                 _ => json!(null),
                             }
                         };
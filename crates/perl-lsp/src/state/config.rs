@@ -1,8 +1,11 @@
 //! Server configuration management
 //!
 //! Runtime configuration for the LSP server, including inlay hints,
-//! test runner settings, and workspace module resolution configuration.
+//! test runner settings, opt-in diagnostic hints, formatter skip markers,
+//! and workspace module resolution configuration.
 
+use crate::features::diagnostics::HintOptions;
+use crate::features::formatting::FormatSkipMarkers;
 use std::path::PathBuf;
 #[cfg(not(target_arch = "wasm32"))]
 use std::process::Command;
@@ -35,6 +38,12 @@ pub struct ServerConfig {
 
     /// Whether telemetry events are enabled.
     pub telemetry_enabled: bool,
+
+    /// Opt-in diagnostic hints, all off by default.
+    pub diagnostic_hints: HintOptions,
+
+    /// Comment markers for regions the formatter leaves untouched.
+    pub format_skip_markers: FormatSkipMarkers,
 }
 
 impl Default for ServerConfig {
@@ -50,6 +59,8 @@ impl Default for ServerConfig {
             test_runner_args: vec![],
             test_runner_timeout: 60000,
             telemetry_enabled: false,
+            diagnostic_hints: HintOptions::default(),
+            format_skip_markers: FormatSkipMarkers::default(),
         }
     }
 }
@@ -96,6 +107,36 @@ impl ServerConfig {
                 self.telemetry_enabled = enabled;
             }
         }
+
+        if let Some(hints) = settings.get("diagnostics").and_then(|d| d.get("hints")) {
+            if let Some(enabled) = hints.get("argsForwardingTailCall").and_then(|v| v.as_bool()) {
+                self.diagnostic_hints.args_forwarding_tail_call = enabled;
+            }
+            if let Some(enabled) = hints.get("hoistableLoopDeclarations").and_then(|v| v.as_bool())
+            {
+                self.diagnostic_hints.hoistable_loop_declarations = enabled;
+            }
+            if let Some(implicit) = hints.get("implicitReturns") {
+                let mut options = self.diagnostic_hints.implicit_returns.unwrap_or_default();
+                if let Some(max) = implicit.get("maxTrivialNodes").and_then(|v| v.as_u64()) {
+                    options.max_trivial_nodes = max as usize;
+                }
+                let enabled = implicit
+                    .get("enabled")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(self.diagnostic_hints.implicit_returns.is_some());
+                self.diagnostic_hints.implicit_returns = enabled.then_some(options);
+            }
+        }
+
+        if let Some(markers) = settings.get("formatting").and_then(|f| f.get("skipMarkers")) {
+            if let Some(begin) = markers.get("begin").and_then(|v| v.as_str()) {
+                self.format_skip_markers.begin = begin.to_string();
+            }
+            if let Some(end) = markers.get("end").and_then(|v| v.as_str()) {
+                self.format_skip_markers.end = end.to_string();
+            }
+        }
     }
 }
 
@@ -218,3 +259,50 @@ impl WorkspaceConfig {
         Vec::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use perl_lsp_diagnostics::best_practices::ImplicitReturnOptions;
+
+    #[test]
+    fn test_diagnostic_hints_are_opt_in() {
+        let mut config = ServerConfig::default();
+        assert_eq!(config.diagnostic_hints, HintOptions::default());
+
+        let settings = serde_json::json!({
+            "diagnostics": { "hints": { "argsForwardingTailCall": true } }
+        });
+        config.update_from_value(&settings);
+        assert!(config.diagnostic_hints.args_forwarding_tail_call);
+        assert!(!config.diagnostic_hints.hoistable_loop_declarations);
+
+        let settings = serde_json::json!({
+            "diagnostics": { "hints": { "hoistableLoopDeclarations": true } }
+        });
+        config.update_from_value(&settings);
+        assert!(config.diagnostic_hints.hoistable_loop_declarations);
+        assert_eq!(config.diagnostic_hints.implicit_returns, None);
+
+        let settings = serde_json::json!({
+            "diagnostics": { "hints": { "implicitReturns": { "enabled": true, "maxTrivialNodes": 3 } } }
+        });
+        config.update_from_value(&settings);
+        assert_eq!(
+            config.diagnostic_hints.implicit_returns,
+            Some(ImplicitReturnOptions { max_trivial_nodes: 3 })
+        );
+    }
+
+    #[test]
+    fn test_format_skip_markers_from_settings() {
+        let mut config = ServerConfig::default();
+        assert_eq!(config.format_skip_markers, FormatSkipMarkers::default());
+
+        let settings = serde_json::json!({
+            "formatting": { "skipMarkers": { "begin": "## tidy off", "end": "## tidy on" } }
+        });
+        config.update_from_value(&settings);
+        assert_eq!(config.format_skip_markers, FormatSkipMarkers::new("## tidy off", "## tidy on"));
+    }
+}
@@ -295,6 +295,102 @@ mod tests {
         assert_eq!(pos.character, 5, "UTF-16 character should account for emoji surrogate pair");
     }
 
+    fn range_change(
+        start: (u32, u32),
+        end: (u32, u32),
+        text: &str,
+    ) -> TextDocumentContentChangeEvent {
+        TextDocumentContentChangeEvent {
+            range: Some(Range {
+                start: Position { line: start.0, character: start.1 },
+                end: Position { line: end.0, character: end.1 },
+            }),
+            range_length: None,
+            text: text.to_string(),
+        }
+    }
+
+    /// Apply the same edit to a plain `String` using UTF-16 positions.
+    fn apply_to_reference(reference: &mut String, start: (u32, u32), end: (u32, u32), text: &str) {
+        let byte_at = |s: &str, (line, character): (u32, u32)| {
+            let line_start: usize = s.split_inclusive('\n').take(line as usize).map(str::len).sum();
+            let mut units = 0u32;
+            let mut offset = line_start;
+            for ch in s[line_start..].chars().take_while(|&c| c != '\n') {
+                if units + ch.len_utf16() as u32 > character {
+                    break;
+                }
+                units += ch.len_utf16() as u32;
+                offset += ch.len_utf8();
+            }
+            offset
+        };
+        let (s, e) = (byte_at(reference, start), byte_at(reference, end));
+        reference.replace_range(s..e, text);
+    }
+
+    /// A sequence of ranged edits, each relative to the previous result,
+    /// must produce the same buffer as splicing a reference string.
+    #[test]
+    fn test_sequential_range_edits_match_reference() {
+        let initial = "my $x = 1;\nprint \"caf\u{e9} \u{1F600}\";\nsub f { }\n";
+        let edits: &[((u32, u32), (u32, u32), &str)] = &[
+            // Rename $x -> $count
+            ((0, 4), (0, 5), "count"),
+            // Insert after the emoji (surrogate pair) on line 1
+            ((1, 14), (1, 14), "!"),
+            // Replace the accented word
+            ((1, 7), (1, 11), "tea"),
+            // Insert a new line inside the sub body
+            ((2, 7), (2, 7), "\n    return 42;\n"),
+            // Join lines 0 and 1 by deleting the newline
+            ((0, 14), (1, 0), " "),
+            // Delete across lines: from the end of line 0 into the sub body
+            ((0, 31), (2, 4), ""),
+        ];
+
+        let mut doc = Doc { rope: Rope::from_str(initial), version: 1 };
+        let mut reference = initial.to_string();
+        for (version, &(start, end, text)) in (2..).zip(edits) {
+            apply_changes(&mut doc, &[range_change(start, end, text)], PosEnc::Utf16);
+            doc.version = version;
+            apply_to_reference(&mut reference, start, end, text);
+            assert_eq!(doc.rope.to_string(), reference, "mismatch after edit {:?}", (start, end));
+        }
+
+        assert_eq!(doc.version, 7);
+        assert_eq!(
+            doc.rope.to_string(),
+            "my $count = 1; print \"tea \u{1F600}!\";return 42;\n }\n"
+        );
+    }
+
+    /// Several ranged edits in one notification apply in order, and a
+    /// full-document change in the same batch resets the buffer.
+    #[test]
+    fn test_batched_range_and_full_changes() {
+        let mut doc = Doc { rope: Rope::from_str("abc\ndef\n"), version: 1 };
+
+        let changes = [
+            range_change((0, 3), (0, 3), "X"),
+            range_change((1, 0), (1, 1), ""),
+            range_change((0, 0), (0, 0), "# "),
+        ];
+        apply_changes(&mut doc, &changes, PosEnc::Utf16);
+        assert_eq!(doc.rope.to_string(), "# abcX\nef\n");
+
+        let changes = [
+            TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: "fresh\n".to_string(),
+            },
+            range_change((0, 5), (0, 5), ";"),
+        ];
+        apply_changes(&mut doc, &changes, PosEnc::Utf16);
+        assert_eq!(doc.rope.to_string(), "fresh;\n");
+    }
+
     /// Test roundtrip: byte -> lsp position -> byte
     #[test]
     fn test_roundtrip_with_emoji() {
@@ -616,7 +616,7 @@ fn test_supported_commands_structure() -> TestResult {
 
     // MUTATION KILLER: Verify not empty/default list
     assert!(!commands.is_empty(), "Supported commands should not be empty");
-    assert_eq!(commands.len(), 8, "Should have exactly 8 supported commands");
+    assert_eq!(commands.len(), 10, "Should have exactly 10 supported commands");
 
     // Verify specific commands are present
     let expected_commands = vec![
@@ -628,6 +628,8 @@ fn test_supported_commands_structure() -> TestResult {
         "perl.runTest",
         "perl.runTestFile",
         "perl.debugFile",
+        "perl.sortUseStatements",
+        "perl.extractSubroutine",
     ];
 
     for expected in &expected_commands {
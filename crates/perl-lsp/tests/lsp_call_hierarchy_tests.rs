@@ -972,6 +972,100 @@ sub run_query {
     Ok(())
 }
 
+/// Tests feature spec: call_hierarchy_provider.rs#inheritance
+/// Test that an inherited base class method links to callers and SUPER:: calls in other files
+#[test]
+fn test_call_hierarchy_across_inheritance() -> TestResult {
+    let mut harness = LspHarness::new();
+    let _init = harness.initialize(None)?;
+
+    let base_uri = "file:///lib/Shape.pm";
+    let sub_uri = "file:///lib/Circle.pm";
+    let script_uri = "file:///draw.pl";
+    harness.open(
+        base_uri,
+        r#"package Shape;
+sub new { my $class = shift; return bless {}, $class; }
+sub describe { my $self = shift; return "shape"; }
+1;
+"#,
+    )?;
+    harness.open(
+        sub_uri,
+        r#"package Circle;
+use parent -norequire, 'Shape';
+sub describe { my $self = shift; return "round " . $self->SUPER::describe(); }
+1;
+"#,
+    )?;
+    harness.open(
+        script_uri,
+        r#"sub main {
+    my $circle = Circle->new();
+    return $circle->describe();
+}
+"#,
+    )?;
+
+    // Prepare on Shape::describe
+    let prepare_response = harness.request(
+        "textDocument/prepareCallHierarchy",
+        json!({
+            "textDocument": { "uri": base_uri },
+            "position": { "line": 2, "character": 5 }
+        }),
+    )?;
+    let items = prepare_response.as_array().ok_or("not an array")?;
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["name"], "describe");
+
+    let incoming_response =
+        harness.request("callHierarchy/incomingCalls", json!({ "item": items[0] }))?;
+    let callers: Vec<(String, String)> = incoming_response
+        .as_array()
+        .ok_or("not an array")?
+        .iter()
+        .map(|call| {
+            (
+                call["from"]["uri"].as_str().unwrap_or_default().to_string(),
+                call["from"]["name"].as_str().unwrap_or_default().to_string(),
+            )
+        })
+        .collect();
+    assert!(
+        callers.contains(&(sub_uri.to_string(), "describe".to_string())),
+        "SUPER::describe in the subclass should call Shape::describe, got: {:?}",
+        callers
+    );
+    assert!(
+        callers.contains(&(script_uri.to_string(), "main".to_string())),
+        "a dynamically dispatched describe() is a candidate caller, got: {:?}",
+        callers
+    );
+
+    // Outgoing calls from Circle::describe follow SUPER:: into the base class file
+    let prepare_response = harness.request(
+        "textDocument/prepareCallHierarchy",
+        json!({
+            "textDocument": { "uri": sub_uri },
+            "position": { "line": 2, "character": 5 }
+        }),
+    )?;
+    let items = prepare_response.as_array().ok_or("not an array")?;
+    assert_eq!(items.len(), 1);
+
+    let outgoing_response =
+        harness.request("callHierarchy/outgoingCalls", json!({ "item": items[0] }))?;
+    let calls = outgoing_response.as_array().ok_or("not an array")?;
+    let super_call = calls
+        .iter()
+        .find(|call| call["to"]["name"] == "describe")
+        .ok_or("SUPER::describe should be an outgoing call")?;
+    assert_eq!(super_call["to"]["uri"], base_uri);
+    assert_eq!(super_call["to"]["selectionRange"]["start"]["line"], 2);
+    Ok(())
+}
+
 /// Tests feature spec: call_hierarchy_provider.rs#capability-advertisement
 /// Test that call hierarchy capability is advertised in server capabilities
 #[test]
@@ -48,7 +48,7 @@ fn full_capabilities_match_contract() -> Result<(), Box<dyn std::error::Error>>
 
     let st = &caps["semanticTokensProvider"];
     assert!(st.is_object());
-    assert_eq!(st["full"], json!(true));
+    assert_eq!(st["full"], json!({ "delta": true }));
 
     let ih = &caps["inlayHintProvider"];
     assert!(ih.is_object());
@@ -16,6 +16,11 @@ fn test_capability_shapes_lsp_318_contract() -> Result<(), Box<dyn std::error::E
     );
     assert!(caps_json["textDocumentSync"]["openClose"].is_boolean());
     assert!(caps_json["textDocumentSync"]["change"].is_number());
+    assert_eq!(
+        caps_json["textDocumentSync"]["change"],
+        json!(2),
+        "textDocumentSync.change must advertise Incremental sync"
+    );
 
     // Test completion shape (must be object with trigger characters)
     if build.completion {
@@ -182,7 +187,7 @@ fn test_capability_shapes_lsp_318_contract() -> Result<(), Box<dyn std::error::E
         assert!(caps_json["semanticTokensProvider"]["legend"].is_object());
         assert!(caps_json["semanticTokensProvider"]["legend"]["tokenTypes"].is_array());
         assert!(caps_json["semanticTokensProvider"]["legend"]["tokenModifiers"].is_array());
-        assert!(caps_json["semanticTokensProvider"]["full"].is_boolean());
+        assert_eq!(caps_json["semanticTokensProvider"]["full"], json!({ "delta": true }));
         assert!(caps_json["semanticTokensProvider"]["range"].is_boolean());
     }
 
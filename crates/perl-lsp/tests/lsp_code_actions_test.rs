@@ -55,7 +55,7 @@ fn test_unused_variable_quick_fix() -> Result<(), Box<dyn std::error::Error>> {
     // Find unused variable diagnostic
     let unused_diag = diagnostics
         .iter()
-        .find(|d| d.code.as_ref().is_some_and(|c| c == "unused-variable"))
+        .find(|d| d.code.as_ref().is_some_and(|c| c == "PL102"))
         .ok_or("Should have unused variable diagnostic")?;
 
     // Get code actions
@@ -874,3 +874,48 @@ sub regular_function { return 9; }
 
     Ok(())
 }
+
+/// Tests feature spec: code_lens_provider.rs#subtest-lenses
+///
+/// Validates that each `subtest` gets a lens that resolves to a `perl.runTest`
+/// command carrying the subtest's test ID.
+#[test]
+fn test_code_lens_subtests_resolve_to_run_test() -> TestResult {
+    let doc = r#"use Test::More;
+
+subtest 'parses input' => sub {
+    ok(1);
+    subtest 'handles unicode' => sub { ok(1) };
+};
+
+done_testing;
+"#;
+
+    let mut harness = LspHarness::new();
+    harness.initialize(None)?;
+    harness.open_document("file:///t/parse.t", doc)?;
+
+    let lenses_result = harness
+        .request("textDocument/codeLens", json!({"textDocument": {"uri": "file:///t/parse.t"}}))
+        .unwrap_or(json!(null));
+    let lenses = lenses_result.as_array().ok_or("Expected array of lenses")?;
+
+    let subtest_lenses: Vec<_> =
+        lenses.iter().filter(|lens| lens["data"]["kind"] == "subtest").collect();
+    assert_eq!(subtest_lenses.len(), 2, "Expected one lens per subtest, got: {:?}", lenses);
+    assert_eq!(subtest_lenses[0]["range"]["start"], json!({"line": 2, "character": 0}));
+    assert_eq!(subtest_lenses[1]["range"]["start"], json!({"line": 4, "character": 4}));
+
+    let mut arguments = Vec::new();
+    for lens in subtest_lenses {
+        let resolved = harness.request("codeLens/resolve", lens.clone()).unwrap_or(json!(null));
+        assert_eq!(resolved["command"]["command"], "perl.runTest");
+        arguments.push(resolved["command"]["arguments"][0].clone());
+    }
+    assert_eq!(
+        arguments,
+        vec![json!("file:///t/parse.t::parses input"), json!("file:///t/parse.t::handles unicode")]
+    );
+
+    Ok(())
+}
@@ -18,6 +18,14 @@ fn init_server() -> LspServer {
 
     // Verify initialization succeeded
     assert!(init_response.is_some());
+
+    let initialized = JsonRpcRequest {
+        _jsonrpc: "2.0".to_string(),
+        id: None,
+        method: "initialized".to_string(),
+        params: Some(json!({})),
+    };
+    srv.handle_request(initialized);
     srv
 }
 
@@ -279,3 +287,229 @@ sub reverse_str { reverse $_[0] }
     }
     Ok(())
 }
+
+/// Send a navigation request and return the locations it resolved to
+fn navigate(
+    server: &mut LspServer,
+    method: &str,
+    uri: &str,
+    line: u32,
+    character: u32,
+) -> Result<Vec<serde_json::Value>, Box<dyn std::error::Error>> {
+    let request = JsonRpcRequest {
+        _jsonrpc: "2.0".to_string(),
+        id: Some(json!(7)),
+        method: method.to_string(),
+        params: Some(json!({
+            "textDocument": {"uri": uri},
+            "position": {"line": line, "character": character}
+        })),
+    };
+
+    let response = server.handle_request(request).ok_or("Expected a response")?;
+    let result = response.result.ok_or("Expected a result")?;
+    Ok(result.as_array().ok_or("Expected array of locations")?.clone())
+}
+
+#[test]
+fn test_imported_sub_definition_in_other_file() -> Result<(), Box<dyn std::error::Error>> {
+    let mut srv = init_server();
+
+    open(
+        &mut srv,
+        "file:///lib/My/Util.pm",
+        r#"package My::Util;
+use Exporter 'import';
+our @EXPORT_OK = qw(first_match);
+
+sub first_match {
+    my ($pred, @items) = @_;
+    return;
+}
+
+1;
+"#,
+    );
+
+    open(
+        &mut srv,
+        "file:///app.pl",
+        r#"use strict;
+use My::Util qw(first_match);
+
+my $hit = first_match(sub { 1 }, 1, 2);
+"#,
+    );
+
+    for method in ["textDocument/definition", "textDocument/declaration"] {
+        // On "first_match" in the call
+        let items = navigate(&mut srv, method, "file:///app.pl", 3, 12)?;
+        assert!(!items.is_empty(), "{method} should resolve the imported sub");
+
+        let uri = items[0]["uri"].as_str().ok_or("Expected URI to be a string")?;
+        assert!(uri.ends_with("/lib/My/Util.pm"), "{method} landed in {uri}");
+        assert_eq!(items[0]["range"]["start"]["line"], json!(4), "{method} should hit the sub");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_import_outside_workspace_falls_back_to_use() -> Result<(), Box<dyn std::error::Error>> {
+    let mut srv = init_server();
+
+    open(
+        &mut srv,
+        "file:///app.pl",
+        r#"use strict;
+use List::Util qw(first max);
+
+my $biggest = max(1, 2, 3);
+"#,
+    );
+
+    // On "max" in the call
+    let items = navigate(&mut srv, "textDocument/definition", "file:///app.pl", 3, 15)?;
+    assert_eq!(items.len(), 1, "Expected the use statement, got {items:?}");
+
+    let location = &items[0];
+    let uri = location["uri"].as_str().ok_or("Expected URI to be a string")?;
+    assert!(uri.ends_with("/app.pl"));
+    // "max" inside `use List::Util qw(first max);`
+    assert_eq!(location["range"]["start"], json!({"line": 1, "character": 24}));
+    assert_eq!(location["range"]["end"], json!({"line": 1, "character": 27}));
+    Ok(())
+}
+
+#[test]
+fn test_local_sub_wins_over_import() -> Result<(), Box<dyn std::error::Error>> {
+    let mut srv = init_server();
+
+    open(
+        &mut srv,
+        "file:///lib/My/Util.pm",
+        r#"package My::Util;
+
+sub helper { 1 }
+
+1;
+"#,
+    );
+
+    open(
+        &mut srv,
+        "file:///app.pl",
+        r#"use My::Util qw(helper);
+
+sub helper { 2 }
+
+helper();
+"#,
+    );
+
+    // On "helper" in the call
+    let items = navigate(&mut srv, "textDocument/definition", "file:///app.pl", 4, 2)?;
+    assert!(!items.is_empty(), "Should find the local definition");
+
+    let uri = items[0]["uri"].as_str().ok_or("Expected URI to be a string")?;
+    assert!(uri.ends_with("/app.pl"), "Local sub should win, got {uri}");
+    assert_eq!(items[0]["range"]["start"]["line"], json!(2));
+    Ok(())
+}
+
+/// Open `A.pm` defining `greet` and `B.pl` calling it qualified and as a class method
+fn open_greet_workspace(server: &mut LspServer) {
+    open(
+        server,
+        "file:///lib/A.pm",
+        r#"package A;
+
+sub greet {
+    return "hi";
+}
+
+1;
+"#,
+    );
+
+    open(
+        server,
+        "file:///bin/B.pl",
+        r#"use A;
+
+print A::greet();
+print A->greet();
+"#,
+    );
+}
+
+fn references(
+    server: &mut LspServer,
+    uri: &str,
+    line: u32,
+    character: u32,
+    include_declaration: bool,
+) -> Result<Vec<serde_json::Value>, Box<dyn std::error::Error>> {
+    let request = JsonRpcRequest {
+        _jsonrpc: "2.0".to_string(),
+        id: Some(json!(8)),
+        method: "textDocument/references".to_string(),
+        params: Some(json!({
+            "textDocument": {"uri": uri},
+            "position": {"line": line, "character": character},
+            "context": {"includeDeclaration": include_declaration}
+        })),
+    };
+
+    let response = server.handle_request(request).ok_or("Expected a response")?;
+    let result = response.result.ok_or("Expected a result")?;
+    Ok(result.as_array().ok_or("Expected array of locations")?.clone())
+}
+
+/// (uri, line) of each location, in response order
+fn uri_lines(locations: &[serde_json::Value]) -> Vec<(String, u64)> {
+    locations
+        .iter()
+        .filter_map(|loc| {
+            Some((loc["uri"].as_str()?.to_string(), loc["range"]["start"]["line"].as_u64()?))
+        })
+        .collect()
+}
+
+#[test]
+fn test_references_to_package_sub_across_files() -> Result<(), Box<dyn std::error::Error>> {
+    let mut srv = init_server();
+    open_greet_workspace(&mut srv);
+
+    // On "greet" in `sub greet`
+    let locations = references(&mut srv, "file:///lib/A.pm", 2, 5, true)?;
+    assert_eq!(
+        uri_lines(&locations),
+        [
+            ("file:///bin/B.pl".to_string(), 2),
+            ("file:///bin/B.pl".to_string(), 3),
+            ("file:///lib/A.pm".to_string(), 2),
+        ],
+        "Expected both calls in B.pl and the declaration, got {locations:?}"
+    );
+
+    let without_declaration = references(&mut srv, "file:///lib/A.pm", 2, 5, false)?;
+    assert_eq!(
+        uri_lines(&without_declaration),
+        [("file:///bin/B.pl".to_string(), 2), ("file:///bin/B.pl".to_string(), 3)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_references_from_method_call_site() -> Result<(), Box<dyn std::error::Error>> {
+    let mut srv = init_server();
+    open_greet_workspace(&mut srv);
+
+    // On "greet" in `A->greet()`
+    let locations = references(&mut srv, "file:///bin/B.pl", 3, 10, false)?;
+    assert_eq!(
+        uri_lines(&locations),
+        [("file:///bin/B.pl".to_string(), 2), ("file:///bin/B.pl".to_string(), 3)]
+    );
+    Ok(())
+}
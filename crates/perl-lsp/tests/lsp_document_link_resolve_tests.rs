@@ -497,3 +497,79 @@ require Foo::Bar;
 
     Ok(())
 }
+
+/// `use` links resolve to a workspace `lib/` file, falling back to MetaCPAN
+#[test]
+fn test_document_link_module_targets_local_file_or_metacpan() -> TestResult {
+    let workspace = tempfile::tempdir()?;
+    std::fs::create_dir_all(workspace.path().join("lib/My"))?;
+    std::fs::write(workspace.path().join("lib/My/Local.pm"), "package My::Local;\n1;\n")?;
+    let root_uri = format!("file://{}", workspace.path().display());
+
+    let mut server = LspServer::new();
+    let _ = server.handle_request(JsonRpcRequest {
+        _jsonrpc: "2.0".to_string(),
+        id: Some(json!(1)),
+        method: "initialize".to_string(),
+        params: Some(json!({"capabilities": {}, "rootUri": root_uri})),
+    });
+    let _ = server.handle_request(JsonRpcRequest {
+        _jsonrpc: "2.0".to_string(),
+        id: None,
+        method: "initialized".to_string(),
+        params: Some(json!({})),
+    });
+
+    let doc_uri = format!("{}/script.pl", root_uri);
+    let _ = server.handle_request(JsonRpcRequest {
+        _jsonrpc: "2.0".to_string(),
+        id: None,
+        method: "textDocument/didOpen".to_string(),
+        params: Some(json!({
+            "textDocument": {
+                "uri": doc_uri,
+                "languageId": "perl",
+                "version": 1,
+                "text": "use strict;\nuse My::Local;\nuse Definitely::Not::Installed qw(x);\n"
+            }
+        })),
+    });
+
+    let link_resp = server
+        .handle_request(JsonRpcRequest {
+            _jsonrpc: "2.0".to_string(),
+            id: Some(json!(2)),
+            method: "textDocument/documentLink".to_string(),
+            params: Some(json!({"textDocument": {"uri": doc_uri}})),
+        })
+        .ok_or("Expected response from documentLink")?;
+    let links = link_resp.result.ok_or("Expected result field in link response")?;
+    let links = links.as_array().ok_or("Expected links to be an array")?;
+    assert_eq!(links.len(), 2, "pragmas are not linked: {:?}", links);
+
+    let mut targets = Vec::new();
+    for link in links {
+        let resolved = server
+            .handle_request(JsonRpcRequest {
+                _jsonrpc: "2.0".to_string(),
+                id: Some(json!(3)),
+                method: "documentLink/resolve".to_string(),
+                params: Some(link.clone()),
+            })
+            .and_then(|resp| resp.result)
+            .ok_or("Expected result from documentLink/resolve")?;
+        targets.push(resolved["target"].as_str().unwrap_or_default().to_string());
+    }
+
+    // Links cover exactly the module name, not the `use` keyword
+    assert_eq!(links[0]["range"]["start"], json!({"line": 1, "character": 4}));
+    assert_eq!(links[0]["range"]["end"], json!({"line": 1, "character": 13}));
+    assert!(targets[0].starts_with("file://"), "local target: {}", targets[0]);
+    assert!(targets[0].ends_with("lib/My/Local.pm"), "local target: {}", targets[0]);
+
+    assert_eq!(links[1]["range"]["start"], json!({"line": 2, "character": 4}));
+    assert_eq!(links[1]["range"]["end"], json!({"line": 2, "character": 30}));
+    assert_eq!(targets[1], "https://metacpan.org/pod/Definitely::Not::Installed");
+
+    Ok(())
+}
@@ -175,6 +175,48 @@ fn test_rapid_edits() {
 
 #[test]
 
+fn test_stale_did_change_is_ignored() -> Result<(), Box<dyn std::error::Error>> {
+    let mut server = setup_server();
+    server.handle_request(JsonRpcRequest {
+        _jsonrpc: "2.0".to_string(),
+        id: None,
+        method: "initialized".to_string(),
+        params: Some(json!({})),
+    });
+    let uri = "file:///stale.pl";
+    open_doc(&mut server, uri, "sub alpha { 1 }\n");
+
+    let change = |version: i32, text: &str| JsonRpcRequest {
+        _jsonrpc: "2.0".to_string(),
+        id: None,
+        method: "textDocument/didChange".to_string(),
+        params: Some(json!({
+            "textDocument": { "uri": uri, "version": version },
+            "contentChanges": [{ "text": text }]
+        })),
+    };
+    server.handle_request(change(3, "sub beta { 1 }\n"));
+    // Arrives after version 3 was applied; must not overwrite it
+    server.handle_request(change(2, "sub gamma { 1 }\n"));
+    server.handle_request(change(3, "sub delta { 1 }\n"));
+
+    let response = server
+        .handle_request(JsonRpcRequest {
+            _jsonrpc: "2.0".to_string(),
+            id: Some(json!(2)),
+            method: "textDocument/documentSymbol".to_string(),
+            params: Some(json!({ "textDocument": { "uri": uri } })),
+        })
+        .ok_or("Expected documentSymbol response")?;
+    let symbols = response.result.ok_or("Expected documentSymbol result")?.to_string();
+    assert!(symbols.contains("beta"), "Expected version 3 text, got {symbols}");
+    assert!(!symbols.contains("gamma") && !symbols.contains("delta"), "{symbols}");
+
+    Ok(())
+}
+
+#[test]
+
 fn test_circular_references() {
     let mut server = setup_server();
 
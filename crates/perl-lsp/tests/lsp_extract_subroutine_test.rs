@@ -0,0 +1,91 @@
+//! `workspace/executeCommand` with `perl.extractSubroutine`
+
+use perl_lsp::{JsonRpcRequest, JsonRpcResponse, LspServer};
+use serde_json::{Value, json};
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+const URI: &str = "file:///extract.pl";
+
+fn request(id: i64, method: &str, params: Value) -> Result<JsonRpcRequest, serde_json::Error> {
+    serde_json::from_value(json!({"jsonrpc": "2.0", "id": id, "method": method, "params": params}))
+}
+
+/// Open `text` and extract the lines `first..=last`
+fn extract_lines(
+    text: &str,
+    first: u32,
+    last: u32,
+) -> Result<JsonRpcResponse, Box<dyn std::error::Error>> {
+    let mut server = LspServer::new();
+    let _ = server.handle_request(request(1, "initialize", json!({"capabilities": {}}))?);
+    let _ = server.handle_request(serde_json::from_value(
+        json!({"jsonrpc": "2.0", "method": "initialized", "params": {}}),
+    )?);
+    let _ = server.handle_request(serde_json::from_value(json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/didOpen",
+        "params": {"textDocument": {
+            "uri": URI, "languageId": "perl", "version": 1, "text": text
+        }}
+    }))?);
+
+    let selection = json!({
+        "start": {"line": first, "character": 0},
+        "end": {"line": last + 1, "character": 0}
+    });
+    Ok(server
+        .handle_request(request(
+            2,
+            "workspace/executeCommand",
+            json!({"command": "perl.extractSubroutine", "arguments": [URI, selection]}),
+        )?)
+        .ok_or("no executeCommand response")?)
+}
+
+#[test]
+fn two_statements_reading_outer_variables_become_a_sub() -> TestResult {
+    let text = "\
+my $a = 3;
+my $b = 4;
+
+sub report {
+    my $sum = $a + $b;
+    print \"sum: $sum\\n\";
+}
+
+report();
+";
+    let response = extract_lines(text, 4, 5)?;
+    let result = response.result.ok_or("perl.extractSubroutine returned an error")?;
+    let edits = result["changes"][URI].as_array().ok_or("no edits for the document")?;
+
+    assert_eq!(edits.len(), 2, "got {edits:?}");
+    assert_eq!(
+        edits[0],
+        json!({
+            "range": {"start": {"line": 4, "character": 4}, "end": {"line": 5, "character": 24}},
+            "newText": "extracted($a, $b);"
+        })
+    );
+    assert_eq!(
+        edits[1],
+        json!({
+            "range": {"start": {"line": 6, "character": 1}, "end": {"line": 6, "character": 1}},
+            "newText": "\n\nsub extracted {\n    my ($a, $b) = @_;\n    my $sum = $a + $b;\n    print \"sum: $sum\\n\";\n}"
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn selection_ending_inside_a_statement_is_rejected() -> TestResult {
+    let text = "my $a = 3;\nmy $b = $a\n    + 4;\nprint $b;\n";
+    let response = extract_lines(text, 0, 1)?;
+
+    let error = response.error.ok_or("partial statement selection was accepted")?;
+    assert_eq!(error.message, "Cannot extract subroutine: Selection must cover whole statements");
+
+    Ok(())
+}
@@ -372,3 +372,146 @@ fn test_folding_ranges_empty_document() -> TestResult {
 
     Ok(())
 }
+
+#[test]
+fn test_folding_ranges_pod_heredoc_and_data_section() -> TestResult {
+    let mut server = setup_server();
+
+    let content = r#"package Foo;
+
+=pod
+
+Docs here.
+
+=cut
+
+sub greet {
+    my $msg = <<"EOT";
+Hello
+World
+EOT
+    return $msg;
+}
+
+1;
+__DATA__
+line one
+line two
+"#;
+
+    open_document(&mut server, "file:///pod.pl", content);
+
+    let request = JsonRpcRequest {
+        _jsonrpc: "2.0".to_string(),
+        method: "textDocument/foldingRange".to_string(),
+        params: Some(json!({
+            "textDocument": {
+                "uri": "file:///pod.pl"
+            }
+        })),
+        id: Some(json!(2)),
+    };
+
+    let response = server.handle_request(request).ok_or("Expected response from server")?;
+    let result = response.result.ok_or("Expected result in response")?;
+    let ranges = result.as_array().ok_or("Expected array of folding ranges")?;
+
+    let folds: Vec<(u64, u64, Option<&str>)> = ranges
+        .iter()
+        .filter_map(|r| {
+            Some((r["startLine"].as_u64()?, r["endLine"].as_u64()?, r["kind"].as_str()))
+        })
+        .collect();
+
+    // POD block from =pod to =cut
+    assert!(folds.contains(&(2, 6, Some("comment"))), "Missing POD fold in {:?}", folds);
+    // Heredoc body lines
+    assert!(folds.contains(&(10, 11, Some("region"))), "Missing heredoc fold in {:?}", folds);
+    // __DATA__ marker to end of file
+    assert!(folds.contains(&(17, 19, Some("region"))), "Missing data fold in {:?}", folds);
+
+    // The three lexical folds come on top of the subroutine and its block
+    assert_eq!(folds.len(), 5, "Unexpected folds: {:?}", folds);
+
+    Ok(())
+}
+
+fn folds_for(content: &str) -> Result<Vec<(u64, u64, Option<String>)>, Box<dyn std::error::Error>> {
+    let mut server = setup_server();
+    open_document(&mut server, "file:///regions.pl", content);
+
+    let request = JsonRpcRequest {
+        _jsonrpc: "2.0".to_string(),
+        method: "textDocument/foldingRange".to_string(),
+        params: Some(json!({
+            "textDocument": {
+                "uri": "file:///regions.pl"
+            }
+        })),
+        id: Some(json!(2)),
+    };
+
+    let response = server.handle_request(request).ok_or("Expected response from server")?;
+    let result = response.result.ok_or("Expected result in response")?;
+    let ranges = result.as_array().ok_or("Expected array of folding ranges")?;
+
+    Ok(ranges
+        .iter()
+        .filter_map(|r| {
+            Some((
+                r["startLine"].as_u64()?,
+                r["endLine"].as_u64()?,
+                r["kind"].as_str().map(str::to_string),
+            ))
+        })
+        .collect())
+}
+
+#[test]
+fn test_folding_ranges_nested_region_markers() -> TestResult {
+    let content = r#"# region Setup
+my $x = 1;
+#region inner
+my $y = 2;
+#endregion
+my $z = 3;
+# endregion
+
+=begin fold
+
+Folded docs.
+
+=end fold
+
+=cut
+"#;
+
+    let folds = folds_for(content)?;
+    let region = Some("region".to_string());
+
+    assert!(folds.contains(&(0, 6, region.clone())), "Missing outer region in {:?}", folds);
+    assert!(folds.contains(&(2, 4, region.clone())), "Missing inner region in {:?}", folds);
+    assert!(folds.contains(&(8, 12, region)), "Missing POD fold region in {:?}", folds);
+
+    Ok(())
+}
+
+#[test]
+fn test_folding_ranges_mismatched_region_markers_ignored() -> TestResult {
+    let content = r#"#endregion
+#region paired
+my $x = 1;
+#endregion
+#region never closed
+my $y = 2;
+my $z = 3;
+"#;
+
+    let folds = folds_for(content)?;
+    let regions: Vec<_> =
+        folds.iter().filter(|(_, _, kind)| kind.as_deref() == Some("region")).collect();
+
+    assert_eq!(regions, vec![&(1, 3, Some("region".to_string()))], "Unexpected folds: {:?}", folds);
+
+    Ok(())
+}
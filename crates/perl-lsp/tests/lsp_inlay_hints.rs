@@ -75,3 +75,85 @@ fn inlay_hints_for_substr_and_types() -> Result<(), Box<dyn std::error::Error>>
 
     Ok(())
 }
+
+/// Open `text` in a fresh server and return the (line, character, label) of every type hint
+fn type_hints_for(text: &str, settings: Option<serde_json::Value>) -> Vec<(u64, u64, String)> {
+    let mut srv = LspServer::new();
+    let request = |method: &str, id: Option<i64>, params: serde_json::Value| JsonRpcRequest {
+        _jsonrpc: "2.0".into(),
+        id: id.map(|id| json!(id)),
+        method: method.into(),
+        params: Some(params),
+    };
+    srv.handle_request(request("initialize", Some(1), json!({"capabilities": {}})));
+    srv.handle_request(request("initialized", None, json!({})));
+    if let Some(settings) = settings {
+        srv.handle_request(request(
+            "workspace/didChangeConfiguration",
+            None,
+            json!({"settings": {"perl": settings}}),
+        ));
+    }
+
+    let uri = "file:///inferred.pl";
+    srv.handle_request(request(
+        "textDocument/didOpen",
+        None,
+        json!({"textDocument": {"uri": uri, "languageId": "perl", "version": 1, "text": text}}),
+    ));
+    let res = srv.handle_request(request(
+        "textDocument/inlayHint",
+        Some(2),
+        json!({
+            "textDocument": {"uri": uri},
+            "range": {"start": {"line": 0, "character": 0}, "end": {"line": 10, "character": 0}}
+        }),
+    ));
+
+    res.and_then(|r| r.result)
+        .and_then(|result| result.as_array().cloned())
+        .unwrap_or_default()
+        .iter()
+        .filter(|h| h["kind"] == 1)
+        .map(|h| {
+            (
+                h["position"]["line"].as_u64().unwrap_or(u64::MAX),
+                h["position"]["character"].as_u64().unwrap_or(u64::MAX),
+                h["label"].as_str().unwrap_or_default().to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn inferred_type_hint_for_integer_declaration() {
+    let hints = type_hints_for("my @items = (1, 2);\nmy $count = scalar @items;\n", None);
+
+    // Right after `$count`, not after the initializer
+    assert!(hints.contains(&(1, 9, ": Int".to_string())), "hints: {:?}", hints);
+}
+
+#[test]
+fn inferred_type_hint_for_string_declaration_uses_utf16_columns() {
+    // `é` is two bytes in UTF-8 but one UTF-16 code unit, so `$name` ends at column 21
+    let hints = type_hints_for("my $s = \"é\"; my $name = 'perl';\n", None);
+
+    assert!(hints.contains(&(0, 5, ": Str".to_string())), "hints: {:?}", hints);
+    assert!(hints.contains(&(0, 21, ": Str".to_string())), "hints: {:?}", hints);
+}
+
+#[test]
+fn inferred_type_hint_suppressed_for_unknown_type() {
+    let hints = type_hints_for("my $result = compute();\n", None);
+
+    assert!(hints.iter().all(|(_, character, _)| *character != 10), "hints: {:?}", hints);
+}
+
+#[test]
+fn inferred_type_hints_follow_type_hints_setting() {
+    let text = "my $n = 42;\n";
+    assert!(type_hints_for(text, None).contains(&(0, 5, ": Int".to_string())));
+
+    let hints = type_hints_for(text, Some(json!({"inlayHints": {"typeHints": false}})));
+    assert!(hints.is_empty(), "hints: {:?}", hints);
+}
@@ -166,6 +166,85 @@ print "Hello, World!\n";
     Ok(())
 }
 
+#[test]
+fn test_document_diagnostic_full_after_edit() -> Result<(), Box<dyn std::error::Error>> {
+    let mut server = LspServer::new();
+
+    let _ = server.handle_request(JsonRpcRequest {
+        _jsonrpc: "2.0".into(),
+        id: Some(json!(1)),
+        method: "initialize".into(),
+        params: Some(json!({
+            "processId": 1,
+            "capabilities": {}
+        })),
+    });
+    let _ = server.handle_request(JsonRpcRequest {
+        _jsonrpc: "2.0".into(),
+        id: None,
+        method: "initialized".into(),
+        params: Some(json!({})),
+    });
+
+    let uri = "file:///edited.pl";
+    let _ = server.handle_request(JsonRpcRequest {
+        _jsonrpc: "2.0".into(),
+        id: None,
+        method: "textDocument/didOpen".into(),
+        params: Some(json!({
+            "textDocument": {
+                "uri": uri,
+                "languageId": "perl",
+                "version": 1,
+                "text": "my $x = 1;\n"
+            }
+        })),
+    });
+
+    let response1 = server
+        .handle_request(JsonRpcRequest {
+            _jsonrpc: "2.0".into(),
+            id: Some(json!(2)),
+            method: "textDocument/diagnostic".into(),
+            params: Some(json!({ "textDocument": { "uri": uri } })),
+        })
+        .ok_or("Failed to get response from first diagnostic request")?;
+    let result1 = response1.result.ok_or("First response missing result field")?;
+    let result_id = result1["resultId"].as_str().ok_or("Expected resultId to be a string")?;
+
+    // Introduce a syntax error
+    let _ = server.handle_request(JsonRpcRequest {
+        _jsonrpc: "2.0".into(),
+        id: None,
+        method: "textDocument/didChange".into(),
+        params: Some(json!({
+            "textDocument": { "uri": uri, "version": 2 },
+            "contentChanges": [{ "text": "my $x = ;\n" }]
+        })),
+    });
+
+    // The stale result ID must not produce an unchanged report
+    let response2 = server
+        .handle_request(JsonRpcRequest {
+            _jsonrpc: "2.0".into(),
+            id: Some(json!(3)),
+            method: "textDocument/diagnostic".into(),
+            params: Some(json!({
+                "textDocument": { "uri": uri },
+                "previousResultId": result_id
+            })),
+        })
+        .ok_or("Failed to get response from second diagnostic request")?;
+    let result2 = response2.result.ok_or("Second response missing result field")?;
+
+    assert_eq!(result2["kind"], "full");
+    assert_ne!(result2["resultId"], result_id);
+    let items = result2["items"].as_array().ok_or("Expected items array")?;
+    assert!(!items.is_empty(), "Edited document should report the syntax error");
+
+    Ok(())
+}
+
 #[test]
 fn test_workspace_diagnostic() -> Result<(), Box<dyn std::error::Error>> {
     let mut server = LspServer::new();
@@ -55,3 +55,79 @@ fn semantic_tokens_emit_data() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn semantic_tokens_delta_returns_edits_against_previous_result()
+-> Result<(), Box<dyn std::error::Error>> {
+    let mut srv = LspServer::new();
+    let request = |id: Option<i64>, method: &str, params: serde_json::Value| JsonRpcRequest {
+        _jsonrpc: "2.0".into(),
+        id: id.map(|id| json!(id)),
+        method: method.into(),
+        params: Some(params),
+    };
+    srv.handle_request(request(Some(1), "initialize", json!({"capabilities":{}})));
+    srv.handle_request(request(None, "initialized", json!({})));
+
+    let uri = "file:///tokens_delta.pl";
+    srv.handle_request(request(
+        None,
+        "textDocument/didOpen",
+        json!({
+            "textDocument": {
+                "uri": uri,
+                "languageId": "perl",
+                "version": 1,
+                "text": "my $x = 1;\nprint $x;\n"
+            }
+        }),
+    ));
+
+    let res = srv
+        .handle_request(request(
+            Some(2),
+            "textDocument/semanticTokens/full",
+            json!({"textDocument": {"uri": uri}}),
+        ))
+        .ok_or("handle_request returned None")?;
+    let full = res.result.ok_or("response result is None")?;
+    let first_id = full["resultId"].as_str().ok_or("full result has no resultId")?.to_string();
+    let full_data = full["data"].as_array().ok_or("data field is not an array")?;
+
+    srv.handle_request(request(
+        None,
+        "textDocument/didChange",
+        json!({
+            "textDocument": {"uri": uri, "version": 2},
+            "contentChanges": [{"text": "my $xyz = 1;\nprint $xyz;\n"}]
+        }),
+    ));
+
+    let res = srv
+        .handle_request(request(
+            Some(3),
+            "textDocument/semanticTokens/full/delta",
+            json!({"textDocument": {"uri": uri}, "previousResultId": first_id}),
+        ))
+        .ok_or("handle_request returned None")?;
+    let delta = res.result.ok_or("response result is None")?;
+    assert_ne!(delta["resultId"].as_str(), Some(first_id.as_str()));
+    let edits = delta["edits"].as_array().ok_or("delta result has no edits")?;
+    assert_eq!(edits.len(), 1, "renaming a variable should produce a single edit");
+    let edit_len = edits[0]["data"].as_array().map_or(0, Vec::len);
+    assert!(edit_len < full_data.len(), "delta should be smaller than the full token array");
+
+    // An unknown previous result id falls back to a full result.
+    let res = srv
+        .handle_request(request(
+            Some(4),
+            "textDocument/semanticTokens/full/delta",
+            json!({"textDocument": {"uri": uri}, "previousResultId": "stale"}),
+        ))
+        .ok_or("handle_request returned None")?;
+    let fallback = res.result.ok_or("response result is None")?;
+    assert!(fallback["data"].is_array(), "stale result id should return full data");
+    assert!(fallback.get("edits").is_none());
+
+    Ok(())
+}
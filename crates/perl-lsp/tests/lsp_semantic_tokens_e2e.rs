@@ -65,7 +65,7 @@ fn semantic_tokens_expected_ranges() -> Result<(), Box<dyn std::error::Error>> {
         (0, 6, 1, 12), // = - operator (index 12)
         (0, 8, 1, 10), // 1 - number (index 10)
         (1, 0, 14, 2), // sub foo { $x } - function (index 2) - longer token preferred
-        (2, 0, 5, 2),  // foo(); - function (index 2)
+        (2, 0, 3, 2),  // foo - function name (index 2), arguments excluded
     ];
 
     assert_eq!(tokens.len(), expected_non_overlapping.len(), "semantic token count mismatch");
@@ -0,0 +1,129 @@
+//! TCP socket transport: `perl-lsp --socket --port <n>`
+
+use serde_json::{Value, json};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, TcpStream};
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+/// Kills the server if the test bails out early
+struct ServerProcess(Child);
+
+impl Drop for ServerProcess {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+/// Start the server on an ephemeral port and return the address it bound
+fn spawn_socket_server() -> Result<(ServerProcess, String), Box<dyn std::error::Error>> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_perl-lsp"))
+        .args(["--socket", "--port", "0"])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let stderr = child.stderr.take().ok_or("stderr not captured")?;
+    let server = ServerProcess(child);
+
+    let mut lines = BufReader::new(stderr).lines();
+    let addr = loop {
+        let line = lines.next().ok_or("server exited before listening")??;
+        if let Some(addr) = line.strip_prefix("Perl LSP listening on ") {
+            break addr.to_string();
+        }
+    };
+
+    // Keep draining stderr so logging never blocks the server
+    std::thread::spawn(move || lines.for_each(drop));
+
+    Ok((server, addr))
+}
+
+fn send(stream: &mut TcpStream, message: &Value) -> TestResult {
+    let body = message.to_string();
+    write!(stream, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+    stream.flush()?;
+    Ok(())
+}
+
+fn receive(reader: &mut BufReader<TcpStream>) -> Result<Value, Box<dyn std::error::Error>> {
+    let mut length = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Err("connection closed while reading headers".into());
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some(value) = line.strip_prefix("Content-Length: ") {
+            length = Some(value.parse::<usize>()?);
+        }
+    }
+
+    let mut body = vec![0; length.ok_or("missing Content-Length header")?];
+    reader.read_exact(&mut body)?;
+    Ok(serde_json::from_slice(&body)?)
+}
+
+/// Read messages until the response to `id`, skipping server notifications
+fn receive_response(
+    reader: &mut BufReader<TcpStream>,
+    id: i64,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    loop {
+        let message = receive(reader)?;
+        if message["id"] == json!(id) {
+            return Ok(message);
+        }
+    }
+}
+
+#[test]
+fn socket_transport_initialize_and_shutdown() -> TestResult {
+    let (mut server, addr) = spawn_socket_server()?;
+
+    let mut stream = TcpStream::connect(&addr)?;
+    stream.set_read_timeout(Some(Duration::from_secs(30)))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    send(
+        &mut stream,
+        &json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": {"processId": null, "rootUri": null, "capabilities": {}}
+        }),
+    )?;
+    let initialize = receive_response(&mut reader, 1)?;
+    assert!(initialize.get("error").is_none(), "initialize failed: {initialize}");
+    assert!(initialize["result"]["capabilities"].is_object(), "missing server capabilities");
+
+    send(&mut stream, &json!({"jsonrpc": "2.0", "method": "initialized", "params": {}}))?;
+    send(&mut stream, &json!({"jsonrpc": "2.0", "id": 2, "method": "shutdown"}))?;
+    let shutdown = receive_response(&mut reader, 2)?;
+    assert!(shutdown.get("error").is_none(), "shutdown failed: {shutdown}");
+    assert!(shutdown["result"].is_null(), "shutdown should return null");
+
+    // Disconnecting ends the session cleanly
+    stream.shutdown(Shutdown::Both)?;
+    let deadline = Instant::now() + Duration::from_secs(30);
+    let status = loop {
+        if let Some(status) = server.0.try_wait()? {
+            break status;
+        }
+        if Instant::now() > deadline {
+            return Err("server did not exit after the client disconnected".into());
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    };
+    assert!(status.success(), "server exited with {status}");
+
+    Ok(())
+}
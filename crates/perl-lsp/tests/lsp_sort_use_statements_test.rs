@@ -0,0 +1,98 @@
+//! `workspace/executeCommand` with `perl.sortUseStatements`
+
+use perl_lsp::{JsonRpcRequest, LspServer};
+use serde_json::{Value, json};
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+const URI: &str = "file:///sort_use.pl";
+
+fn request(id: i64, method: &str, params: Value) -> Result<JsonRpcRequest, serde_json::Error> {
+    serde_json::from_value(json!({"jsonrpc": "2.0", "id": id, "method": method, "params": params}))
+}
+
+/// Open `text` and run the command, returning the edits for the document
+fn sort_use_edits(text: &str) -> Result<Vec<Value>, Box<dyn std::error::Error>> {
+    let mut server = LspServer::new();
+    let _ = server.handle_request(request(1, "initialize", json!({"capabilities": {}}))?);
+    let _ = server.handle_request(serde_json::from_value(
+        json!({"jsonrpc": "2.0", "method": "initialized", "params": {}}),
+    )?);
+    let _ = server.handle_request(serde_json::from_value(json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/didOpen",
+        "params": {"textDocument": {
+            "uri": URI, "languageId": "perl", "version": 1, "text": text
+        }}
+    }))?);
+
+    let response = server
+        .handle_request(request(
+            2,
+            "workspace/executeCommand",
+            json!({"command": "perl.sortUseStatements", "arguments": [URI]}),
+        )?)
+        .ok_or("no executeCommand response")?;
+    let result = response.result.ok_or("perl.sortUseStatements returned an error")?;
+
+    Ok(result["changes"][URI].as_array().ok_or("no edits for the document")?.clone())
+}
+
+#[test]
+fn unsorted_block_is_grouped_and_sorted() -> TestResult {
+    let text = "\
+#!/usr/bin/perl
+use Moo;
+use warnings;
+# for error reporting
+use Carp qw(croak);
+use Data::Dumper;
+use strict;
+use Acme::Widget;
+
+print Dumper({});
+";
+    let edits = sort_use_edits(text)?;
+
+    assert_eq!(edits.len(), 1, "got {edits:?}");
+    assert_eq!(
+        edits[0]["range"],
+        json!({"start": {"line": 1, "character": 0}, "end": {"line": 7, "character": 17}})
+    );
+    assert_eq!(
+        edits[0]["newText"],
+        "\
+use strict;
+use warnings;
+
+# for error reporting
+use Carp qw(croak);
+use Data::Dumper;
+
+use Acme::Widget;
+use Moo;"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn import_block_interrupted_by_code_sorts_only_the_top_block() -> TestResult {
+    let text = "\
+use warnings;
+use strict;
+my $ready = 1;
+use Alpha;
+";
+    let edits = sort_use_edits(text)?;
+
+    // `use Alpha` sits after code, so it is not pulled up into the top block
+    assert_eq!(edits.len(), 1, "got {edits:?}");
+    assert_eq!(
+        edits[0]["range"],
+        json!({"start": {"line": 0, "character": 0}, "end": {"line": 1, "character": 11}})
+    );
+    assert_eq!(edits[0]["newText"], "use strict;\nuse warnings;");
+
+    Ok(())
+}
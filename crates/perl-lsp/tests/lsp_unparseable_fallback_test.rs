@@ -0,0 +1,101 @@
+//! Completion and hover on a buffer the parser rejects outright
+
+use perl_lsp::{JsonRpcRequest, LspServer};
+use serde_json::{Value, json};
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+const URI: &str = "file:///unparseable.pl";
+
+/// Nesting past the parser's depth limit makes `parse` fail with no AST
+fn unparseable_source() -> String {
+    format!(
+        "my $alpha_count = 1;\nmy @beta_items = ();\nsub gamma_handler {{ {}\nalp\n$alp\ngam",
+        "(".repeat(400)
+    )
+}
+
+fn request(id: i64, method: &str, params: Value) -> Result<JsonRpcRequest, serde_json::Error> {
+    serde_json::from_value(json!({"jsonrpc": "2.0", "id": id, "method": method, "params": params}))
+}
+
+fn open_unparseable() -> Result<(LspServer, String), Box<dyn std::error::Error>> {
+    let mut server = LspServer::new();
+    let _ = server.handle_request(request(1, "initialize", json!({"capabilities": {}}))?);
+    let _ = server.handle_request(serde_json::from_value(
+        json!({"jsonrpc": "2.0", "method": "initialized", "params": {}}),
+    )?);
+
+    let source = unparseable_source();
+    let _ = server.handle_request(serde_json::from_value(json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/didOpen",
+        "params": {"textDocument": {
+            "uri": URI, "languageId": "perl", "version": 1, "text": source
+        }}
+    }))?);
+
+    Ok((server, source))
+}
+
+fn completion_labels(
+    server: &mut LspServer,
+    line: usize,
+    character: usize,
+) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let response = server
+        .handle_request(request(
+            2,
+            "textDocument/completion",
+            json!({
+                "textDocument": {"uri": URI},
+                "position": {"line": line, "character": character}
+            }),
+        )?)
+        .ok_or("no completion response")?;
+    let result = response.result.ok_or("completion returned an error")?;
+    let items = result["items"].as_array().or(result.as_array()).ok_or("no completion items")?;
+
+    Ok(items.iter().filter_map(|item| item["label"].as_str().map(str::to_string)).collect())
+}
+
+#[test]
+fn completion_offers_lexer_harvested_identifiers() -> TestResult {
+    let (mut server, source) = open_unparseable()?;
+    let lines: Vec<&str> = source.lines().collect();
+
+    // `alp` on line 3: barewords and keywords, not variables
+    let labels = completion_labels(&mut server, 3, lines[3].len())?;
+    assert!(!labels.contains(&"alp".to_string()), "word being typed offered back: {labels:?}");
+
+    // `gam` on line 5: the sub name harvested from the broken buffer
+    let labels = completion_labels(&mut server, 5, lines[5].len())?;
+    assert!(labels.contains(&"gamma_handler".to_string()), "got {labels:?}");
+
+    // `$alp` on line 4: the scalar harvested from the broken buffer
+    let labels = completion_labels(&mut server, 4, lines[4].len())?;
+    assert!(labels.contains(&"alpha_count".to_string()), "got {labels:?}");
+    assert!(!labels.contains(&"beta_items".to_string()), "array offered after '$': {labels:?}");
+
+    Ok(())
+}
+
+#[test]
+fn hover_shows_word_with_parsing_unavailable_note() -> TestResult {
+    let (mut server, _) = open_unparseable()?;
+
+    let response = server
+        .handle_request(request(
+            3,
+            "textDocument/hover",
+            json!({"textDocument": {"uri": URI}, "position": {"line": 2, "character": 6}}),
+        )?)
+        .ok_or("no hover response")?;
+    let result = response.result.ok_or("hover returned an error")?;
+    let value = result["contents"]["value"].as_str().ok_or("hover has no markdown")?;
+
+    assert!(value.contains("`gamma_handler`"), "got {value}");
+    assert!(value.contains("Parsing unavailable"), "got {value}");
+
+    Ok(())
+}
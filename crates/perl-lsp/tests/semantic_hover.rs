@@ -460,4 +460,35 @@ my $circumference = 2 * PI * $radius;
         }
         Ok(())
     }
+
+    #[test]
+    fn hover_on_array_in_scalar_context_notes_count() -> Result<(), Box<dyn std::error::Error>> {
+        let code = r#"my @items = (1, 2, 3);
+my $n = @items;
+my @copy = @items;
+"#;
+        let uri = "file:///test.pl";
+
+        let mut server = TestServerBuilder::new().build();
+        server.open_document(uri, code);
+
+        // `my $n = @items` evaluates the array for its element count
+        let (line, character) = find_pos(code, "@items", 1)?;
+        let response = server.get_hover(uri, line, character);
+        let content = hover_content(&response).ok_or("expected hover content for @items")?;
+        assert!(
+            content.contains("evaluated in scalar context (count)"),
+            "hover should note scalar context, got: {content}"
+        );
+
+        // `my @copy = @items` copies the elements, so no note
+        let (line, character) = find_pos(code, "@items", 2)?;
+        let response = server.get_hover(uri, line, character);
+        let content = hover_content(&response).ok_or("expected hover content for @items")?;
+        assert!(
+            !content.contains("scalar context"),
+            "list use should not mention scalar context, got: {content}"
+        );
+        Ok(())
+    }
 }
@@ -1,5 +1,7 @@
 use perl_lsp::{JsonRpcRequest, LspServer};
-use serde_json::json;
+use serde_json::{Value, json};
+use std::io::{Cursor, Read, Write};
+use std::sync::{Arc, Mutex};
 
 #[test]
 fn server_side_cancellation_emits_err_server_cancelled() -> Result<(), Box<dyn std::error::Error>> {
@@ -36,3 +38,132 @@ fn server_side_cancellation_emits_err_server_cancelled() -> Result<(), Box<dyn s
 
     Ok(())
 }
+
+/// Writer whose buffer stays readable after the server takes ownership of it
+#[derive(Clone, Default)]
+struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuf {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().map_err(|_| std::io::Error::other("poisoned"))?.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn frame(message: &Value) -> Vec<u8> {
+    let body = message.to_string();
+    format!("Content-Length: {}\r\n\r\n{}", body.len(), body).into_bytes()
+}
+
+fn read_frames(mut bytes: &[u8]) -> Result<Vec<Value>, Box<dyn std::error::Error>> {
+    let mut frames = Vec::new();
+    while let Some(header_end) = bytes.windows(4).position(|w| w == b"\r\n\r\n") {
+        let header = std::str::from_utf8(&bytes[..header_end])?;
+        let length: usize = header
+            .lines()
+            .find_map(|line| line.strip_prefix("Content-Length: "))
+            .ok_or("missing Content-Length")?
+            .trim()
+            .parse()?;
+        let body_start = header_end + 4;
+        frames.push(serde_json::from_slice(&bytes[body_start..body_start + length])?);
+        bytes = &bytes[body_start + length..];
+    }
+    Ok(frames)
+}
+
+#[test]
+fn cancel_request_for_queued_request_returns_request_cancelled()
+-> Result<(), Box<dyn std::error::Error>> {
+    let uri = "file:///tmp/queued_cancel.pl";
+    let messages = [
+        json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {"capabilities": {}}}),
+        json!({"jsonrpc": "2.0", "method": "initialized", "params": {}}),
+        json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didOpen",
+            "params": {"textDocument": {
+                "uri": uri, "languageId": "perl", "version": 1,
+                "text": "my $value = 1;\nsub helper { return $value }\n$va"
+            }}
+        }),
+        // Keeps the main loop busy while the cancellation is read ahead
+        json!({"jsonrpc": "2.0", "id": 3, "method": "$/test/slowOperation", "params": {"serverTimeoutMs": 200}}),
+        json!({
+            "jsonrpc": "2.0",
+            "id": 7,
+            "method": "textDocument/completion",
+            "params": {"textDocument": {"uri": uri}, "position": {"line": 2, "character": 3}}
+        }),
+        json!({"jsonrpc": "2.0", "method": "$/cancelRequest", "params": {"id": 7}}),
+    ];
+    let input: Vec<u8> = messages.iter().flat_map(frame).collect();
+
+    let output = SharedBuf::default();
+    let mut server = LspServer::with_io(Box::new(Cursor::new(input)), Box::new(output.clone()));
+    server.run()?;
+
+    let written = output.0.lock().map_err(|_| "poisoned")?.clone();
+    let frames = read_frames(&written)?;
+    let response =
+        frames.iter().find(|frame| frame["id"] == json!(7)).ok_or("no response for id 7")?;
+
+    assert_eq!(response["error"]["code"], json!(-32800), "expected RequestCancelled");
+    assert!(response.get("result").is_none_or(Value::is_null));
+
+    Ok(())
+}
+
+/// Replays `pending`, then blocks like a client that stays connected but silent
+struct SilentAfter {
+    pending: Cursor<Vec<u8>>,
+    idle: std::sync::mpsc::Receiver<()>,
+}
+
+impl Read for SilentAfter {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.pending.read(buf)?;
+        if read == 0 {
+            // Returns EOF only once the test drops the sender
+            let _ = self.idle.recv();
+        }
+        Ok(read)
+    }
+}
+
+struct BrokenPipe;
+
+impl Write for BrokenPipe {
+    fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::ErrorKind::BrokenPipe.into())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Err(std::io::ErrorKind::BrokenPipe.into())
+    }
+}
+
+#[test]
+fn write_error_ends_session_while_reader_is_blocked() -> Result<(), Box<dyn std::error::Error>> {
+    let initialize =
+        json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {"capabilities": {}}});
+    let (hold_open, idle) = std::sync::mpsc::channel();
+    let reader = SilentAfter { pending: Cursor::new(frame(&initialize)), idle };
+
+    let (done, finished) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let mut server = LspServer::with_io(Box::new(reader), Box::new(BrokenPipe));
+        let _ = done.send(server.run());
+    });
+
+    let result = finished.recv_timeout(std::time::Duration::from_secs(10));
+    drop(hold_open);
+    let result = result.map_err(|_| "server hung after the write failed")?;
+    assert!(result.is_err(), "expected the write error to end the session");
+
+    Ok(())
+}
@@ -38,11 +38,15 @@ impl ErrorRecovery for ParserContext {
         self.add_error(error.clone());
 
         // Create error node
-        let error_node = self.create_error_node(error.message, error.expected, None);
+        let mut error_node = self.create_error_node(error.message, error.expected, None);
 
-        // Try to synchronize
+        // Try to synchronize; the error node stands in for every token skipped
         let sync_points = vec![SyncPoint::Semicolon, SyncPoint::CloseBrace, SyncPoint::Keyword];
-        self.synchronize(&sync_points);
+        if self.synchronize(&sync_points) {
+            if let Some(last_skipped) = self.previous_token() {
+                error_node.range.end = last_skipped.range().end;
+            }
+        }
 
         error_node
     }
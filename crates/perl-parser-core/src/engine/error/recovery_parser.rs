@@ -13,6 +13,10 @@ use crate::{
 };
 use perl_lexer::TokenType;
 
+/// Tokens a failed statement skips to: its `;`, the enclosing `}`, or the next statement keyword
+const STATEMENT_SYNC_POINTS: [SyncPoint; 3] =
+    [SyncPoint::Semicolon, SyncPoint::CloseBrace, SyncPoint::Keyword];
+
 /// A parser with error recovery capabilities
 pub struct RecoveryParser {
     context: ParserContext,
@@ -143,12 +147,29 @@ impl ParserErrorRecovery for RecoveryParser {
 
 impl StatementRecovery for RecoveryParser {
     fn parse_statement_with_recovery(&mut self) -> Node {
+        let start_index = self.context.current_index();
+
         // Try to parse a statement
         match self.try_parse_statement() {
             Ok(node) => node,
             Err(error) => {
                 // Create error node and recover
-                self.context.recover_with_node(error)
+                let mut error_node = self.context.recover_with_node(error);
+
+                // Recovery stops at a sync point without consuming it. When the
+                // statement itself starts there (a stray `}`, or a keyword this
+                // parser cannot handle), step past it and skip the rest of the
+                // statement so one error node covers it and the caller's loop advances.
+                if self.context.current_index() == start_index && !self.context.is_eof() {
+                    self.context.advance();
+                    self.context.budget_tracker_mut().record_skip(1);
+                    self.context.synchronize(&STATEMENT_SYNC_POINTS);
+                    if let Some(skipped) = self.context.previous_token() {
+                        error_node.range.end = skipped.range().end;
+                    }
+                }
+
+                error_node
             }
         }
     }
@@ -400,6 +421,79 @@ impl RecoveryParser {
 mod tests {
     use super::*;
 
+    fn program_statements(ast: &Node) -> &[Node] {
+        match &ast.kind {
+            NodeKind::Program { statements } => statements,
+            _ => unreachable!("Expected program node"),
+        }
+    }
+
+    /// Count error nodes in the node kinds this parser builds
+    fn count_error_nodes(node: &Node) -> usize {
+        match &node.kind {
+            NodeKind::Error { .. } => 1,
+            NodeKind::Program { statements } | NodeKind::Block { statements } => {
+                statements.iter().map(count_error_nodes).sum()
+            }
+            NodeKind::VariableDeclaration { initializer, .. } => {
+                initializer.as_deref().map_or(0, count_error_nodes)
+            }
+            NodeKind::If { condition, then_branch, .. } => {
+                count_error_nodes(condition) + count_error_nodes(then_branch)
+            }
+            _ => 0,
+        }
+    }
+
+    #[test]
+    fn test_missing_initializer_yields_one_error_node() {
+        let source = "my $x = ; my $y = 2;";
+        let (ast, errors) = RecoveryParser::new(source.to_string()).parse();
+
+        let statements = program_statements(&ast);
+        assert_eq!(statements.len(), 2);
+        assert_eq!(count_error_nodes(&ast), 1);
+        assert!(matches!(
+            &statements[1].kind,
+            NodeKind::VariableDeclaration { initializer: Some(init), .. }
+                if matches!(&init.kind, NodeKind::Number { value } if value == "2")
+        ));
+
+        // The diagnostic points at the `;` where the expression is missing
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].range.start.byte, source.find(';').unwrap_or_default());
+    }
+
+    #[test]
+    fn test_error_node_spans_skipped_tokens_and_records_budget() {
+        let source = "my $x = 1 ) ) ); my $y = 2;";
+        let mut parser = RecoveryParser::new(source.to_string());
+        let ast = parser.parse_program();
+        let errors = parser.context.take_errors();
+
+        let statements = program_statements(&ast);
+        assert_eq!(statements.len(), 3);
+        assert_eq!(errors.len(), 1);
+        let skipped = &statements[1].range;
+        assert_eq!(&source[skipped.start.byte..skipped.end.byte], ") ) )");
+        assert_eq!(parser.context.budget_tracker().tokens_skipped, 3);
+        assert!(matches!(statements[2].kind, NodeKind::VariableDeclaration { .. }));
+    }
+
+    #[test]
+    fn test_statement_stuck_on_sync_point_still_advances() {
+        // A stray `}` and an unsupported keyword both start on sync points
+        let (ast, errors) = RecoveryParser::new("} die \"x\"; my $y = 2;".to_string()).parse();
+
+        let statements = program_statements(&ast);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(count_error_nodes(&ast), 2);
+        assert!(matches!(
+            statements.last().map(|s| &s.kind),
+            Some(NodeKind::VariableDeclaration { .. })
+        ));
+    }
+
     #[test]
     fn test_parse_with_errors() {
         let source = "my $x = ; my $y = 42".to_string();
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use crate::engine::parser::Parser;
+    use perl_ast::ast::{Node, NodeKind};
+    use perl_tdd_support::must;
+
+    /// Parse `source` without recovered errors and return the top-level statements
+    fn parse_statements(source: &str) -> Vec<Node> {
+        let mut parser = Parser::new(source);
+        let ast = must(parser.parse());
+        assert!(parser.errors().is_empty(), "unexpected errors: {:?}", parser.errors());
+        match ast.kind {
+            NodeKind::Program { statements } => statements,
+            other => unreachable!("Expected Program, got {:?}", other),
+        }
+    }
+
+    fn class_body(class: &Node) -> &[Node] {
+        match &class.kind {
+            NodeKind::Class { body, .. } => match &body.kind {
+                NodeKind::Block { statements } => statements,
+                other => unreachable!("Expected class block, got {:?}", other),
+            },
+            other => unreachable!("Expected Class, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_class_with_fields_method_and_adjust() {
+        let source = r#"use v5.38;
+class Point 1.0 :isa(Shape) {
+    field $x :param = 0;
+    field $y :param(why) :reader //= 1;
+    field @items;
+    field %opts;
+
+    method move ($dx) { $x += $dx; }
+
+    ADJUST { push @items, $x; }
+}
+"#;
+        let statements = parse_statements(source);
+        let class = &statements[1];
+        if let NodeKind::Class { name, attributes, .. } = &class.kind {
+            assert_eq!(name, "Point");
+            assert_eq!(attributes, &["isa(Shape)"]);
+        }
+
+        let members = class_body(class);
+        let fields: Vec<_> = members
+            .iter()
+            .filter_map(|m| match &m.kind {
+                NodeKind::Field { variable, attributes, default } => {
+                    Some((variable.to_sexp(), attributes.clone(), default.is_some()))
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                ("(variable $ x)".to_string(), vec!["param".to_string()], true),
+                (
+                    "(variable $ y)".to_string(),
+                    vec!["param(why)".to_string(), "reader".to_string()],
+                    true
+                ),
+                ("(variable @ items)".to_string(), vec![], false),
+                ("(variable % opts)".to_string(), vec![], false),
+            ]
+        );
+
+        match &members[4].kind {
+            NodeKind::Method { name, signature, .. } => {
+                assert_eq!(name, "move");
+                assert!(signature.is_some(), "method signature should be parsed");
+            }
+            other => unreachable!("Expected Method, got {:?}", other),
+        }
+        match &members[5].kind {
+            NodeKind::PhaseBlock { phase, .. } => assert_eq!(phase, "ADJUST"),
+            other => unreachable!("Expected ADJUST phase block, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_class_with_multiple_attributes_and_method_attributes() {
+        let statements = parse_statements(
+            "class Rect :isa(Shape) :does(Drawable) { method area :lvalue ($scale) { 1 } }",
+        );
+        if let NodeKind::Class { attributes, .. } = &statements[0].kind {
+            assert_eq!(attributes, &["isa(Shape)", "does(Drawable)"]);
+        }
+        match &class_body(&statements[0])[0].kind {
+            NodeKind::Method { attributes, signature, .. } => {
+                assert_eq!(attributes, &["lvalue"]);
+                assert!(signature.is_some(), "detached parens are a signature, not an argument");
+            }
+            other => unreachable!("Expected Method, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_statement_form_class_owns_following_fields() {
+        let statements =
+            parse_statements("class Counter;\nfield $count = 0;\nADJUST { $count++ }\n");
+        assert!(matches!(statements[0].kind, NodeKind::Class { .. }));
+        assert!(matches!(statements[1].kind, NodeKind::Field { .. }));
+        assert!(
+            matches!(&statements[2].kind, NodeKind::PhaseBlock { phase, .. } if phase == "ADJUST")
+        );
+    }
+
+    #[test]
+    fn test_field_and_adjust_are_plain_words_outside_classes() {
+        let statements = parse_statements("field $x;\nmy %h = (ADJUST => 1);\n");
+        assert!(!matches!(statements[0].kind, NodeKind::Field { .. }));
+        assert!(matches!(statements[1].kind, NodeKind::VariableDeclaration { .. }));
+    }
+}
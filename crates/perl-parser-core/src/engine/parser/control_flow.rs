@@ -1,8 +1,22 @@
 impl<'a> Parser<'a> {
     /// Parse if statement
     fn parse_if_statement(&mut self) -> ParseResult<Node> {
+        self.parse_conditional_statement(false)
+    }
+
+    /// Parse unless statement (syntactic sugar for if not)
+    ///
+    /// `unless` is normalized to an `If` whose condition is wrapped in a
+    /// synthetic `!`, so analyses of `if` apply to it unchanged. Like `if`, it
+    /// accepts `elsif` and `else` branches, whose conditions are not negated.
+    fn parse_unless_statement(&mut self) -> ParseResult<Node> {
+        self.parse_conditional_statement(true)
+    }
+
+    /// Parse an if/unless statement with its elsif/else chain
+    fn parse_conditional_statement(&mut self, negated: bool) -> ParseResult<Node> {
         let start = self.current_position();
-        self.tokens.next()?; // consume 'if'
+        self.tokens.next()?; // consume 'if' or 'unless'
 
         self.expect(TokenKind::LeftParen)?;
 
@@ -21,6 +35,7 @@ impl<'a> Parser<'a> {
 
         self.expect(TokenKind::RightParen)?;
 
+        let condition = if negated { Self::negate_condition(condition) } else { condition };
         let then_branch = self.parse_block()?;
 
         let mut elsif_branches = Vec::new();
@@ -67,35 +82,6 @@ impl<'a> Parser<'a> {
         ))
     }
 
-    /// Parse unless statement (syntactic sugar for if not)
-    fn parse_unless_statement(&mut self) -> ParseResult<Node> {
-        let start = self.current_position();
-        self.tokens.next()?; // consume 'unless'
-
-        self.expect(TokenKind::LeftParen)?;
-        let condition = self.parse_expression()?;
-        self.expect(TokenKind::RightParen)?;
-
-        // Negate the condition
-        let negated_condition = Node::new(
-            NodeKind::Unary { op: "!".to_string(), operand: Box::new(condition) },
-            SourceLocation { start, end: self.previous_position() },
-        );
-
-        let then_branch = self.parse_block()?;
-        let end = self.previous_position();
-
-        Ok(Node::new(
-            NodeKind::If {
-                condition: Box::new(negated_condition),
-                then_branch: Box::new(then_branch),
-                elsif_branches: vec![],
-                else_branch: None,
-            },
-            SourceLocation { start, end },
-        ))
-    }
-
     /// Parse while loop
     fn parse_while_statement(&mut self) -> ParseResult<Node> {
         let start = self.current_position();
@@ -140,6 +126,9 @@ impl<'a> Parser<'a> {
     }
 
     /// Parse until loop (while not)
+    ///
+    /// `until` is normalized to a `While` whose condition is wrapped in a
+    /// synthetic `!`, so analyses of `while` apply to it unchanged.
     fn parse_until_statement(&mut self) -> ParseResult<Node> {
         let start = self.current_position();
         self.tokens.next()?; // consume 'until'
@@ -148,11 +137,7 @@ impl<'a> Parser<'a> {
         let condition = self.parse_expression()?;
         self.expect(TokenKind::RightParen)?;
 
-        // Negate the condition
-        let negated_condition = Node::new(
-            NodeKind::Unary { op: "!".to_string(), operand: Box::new(condition) },
-            SourceLocation { start, end: self.previous_position() },
-        );
+        let negated_condition = Self::negate_condition(condition);
 
         let body = self.parse_block()?;
 
@@ -176,6 +161,15 @@ impl<'a> Parser<'a> {
         ))
     }
 
+    /// Wrap an `unless`/`until` condition in the `!` it implies
+    ///
+    /// The synthetic node shares the condition's span, so diagnostics about
+    /// the negated condition point at the source the user wrote.
+    fn negate_condition(condition: Node) -> Node {
+        let location = condition.location;
+        Node::new(NodeKind::Unary { op: "!".to_string(), operand: Box::new(condition) }, location)
+    }
+
     /// Parse for loop
     fn parse_for_statement(&mut self) -> ParseResult<Node> {
         let start = self.current_position();
@@ -381,6 +375,17 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Whether the current `try` token starts a try/catch statement
+    ///
+    /// With a try pragma in scope (`use feature 'try'`, `use Try::Tiny`, ...)
+    /// `try` is always the keyword. Without one, only `try {` is, since the
+    /// keyword may be imported indirectly; `try(...)`, `try => 1` and similar
+    /// stay ordinary barewords.
+    fn is_try_start(&mut self) -> bool {
+        self.pragma_state.try_catch
+            || self.tokens.peek_second().is_ok_and(|next| next.kind == TokenKind::LeftBrace)
+    }
+
     /// Parse try/catch/finally block
     fn parse_try(&mut self) -> ParseResult<Node> {
         let start = self.consume_token()?.start; // consume 'try'
@@ -398,19 +403,16 @@ impl<'a> Parser<'a> {
             // Check for optional variable
             let var = if self.peek_kind() == Some(TokenKind::LeftParen) {
                 self.consume_token()?; // consume '('
-                let var_name = if self.peek_kind() == Some(TokenKind::ScalarSigil)
+                let var = if self.peek_kind() == Some(TokenKind::ScalarSigil)
                     || self.tokens.peek()?.text.starts_with('$')
                 {
                     let var = self.parse_variable()?;
-                    match &var.kind {
-                        NodeKind::Variable { sigil, name } => Some(format!("{}{}", sigil, name)),
-                        _ => None,
-                    }
+                    matches!(var.kind, NodeKind::Variable { .. }).then(|| Box::new(var))
                 } else {
                     None
                 };
                 self.expect(TokenKind::RightParen)?;
-                var_name
+                var
             } else {
                 None
             };
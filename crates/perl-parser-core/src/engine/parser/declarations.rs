@@ -28,6 +28,57 @@ impl<'a> Parser<'a> {
         };
 
         // Parse optional attributes first (they come before signature in modern Perl)
+        let attributes = self.parse_attributes()?;
+
+        // Parse optional prototype or signature after attributes
+        let (prototype, signature) = if self.peek_kind() == Some(TokenKind::LeftParen) {
+            let paren_start = self.current_position();
+            // Look ahead to determine if this is a prototype or signature
+            if self.is_likely_prototype()? {
+                // Parse as prototype
+                let proto_content = self.parse_prototype()?;
+                let proto_node = Node::new(
+                    NodeKind::Prototype { content: proto_content },
+                    SourceLocation { start: paren_start, end: self.previous_position() },
+                );
+                (Some(Box::new(proto_node)), None)
+            } else {
+                // Parse as signature
+                let params = self.parse_signature()?;
+                let sig_node = Node::new(
+                    NodeKind::Signature { parameters: params },
+                    SourceLocation { start: paren_start, end: self.previous_position() },
+                );
+                (None, Some(Box::new(sig_node)))
+            }
+        } else {
+            (None, None)
+        };
+
+        // Named subs may be forward-declared without a body: `sub foo;` / `sub foo ($$);`
+        let body = if name.is_some() && self.peek_kind() == Some(TokenKind::Semicolon) {
+            None
+        } else {
+            Some(Box::new(self.parse_block()?))
+        };
+
+        let end = self.previous_position();
+        Ok(Node::new(
+            NodeKind::Subroutine { name, name_span, prototype, signature, attributes, body },
+            SourceLocation { start, end },
+        ))
+    }
+
+    /// Parse `:attr` lists such as `:lvalue :prototype($)` or `:isa(Base)`
+    ///
+    /// Each attribute is returned with its parenthesized value, if any, e.g. `prototype($)`.
+    fn parse_attribute_list(&mut self) -> ParseResult<Vec<String>> {
+        Ok(self.parse_attributes()?.iter().map(ToString::to_string).collect())
+    }
+
+    /// Parse `:attr` lists into [`Attribute`]s, keeping each name apart from the
+    /// raw text of its parenthesized argument.
+    fn parse_attributes(&mut self) -> ParseResult<Vec<Attribute>> {
         let mut attributes = Vec::new();
         while self.peek_kind() == Some(TokenKind::Colon) {
             self.tokens.next()?; // consume colon
@@ -43,33 +94,45 @@ impl<'a> Parser<'a> {
                     }
                 };
 
-                let mut attr_name = attr_token.text.to_string();
+                let mut args = None;
+                let mut end = attr_token.end;
 
-                // Check if attribute has a value in parentheses (like :prototype($))
-                if self.peek_kind() == Some(TokenKind::LeftParen) {
-                    self.consume_token()?; // consume (
-                    attr_name.push('(');
+                // Check if attribute has a value in parentheses (like :prototype($)); the
+                // parenthesis must be attached, as `:lvalue ($x)` is followed by a signature
+                if self.peek_kind() == Some(TokenKind::LeftParen)
+                    && self.tokens.peek().is_ok_and(|paren| paren.start == attr_token.end)
+                {
+                    let open = self.consume_token()?; // consume (
+                    let mut text = String::new();
 
-                    // Collect tokens until matching )
+                    // Collect tokens until matching ), keeping the spacing between them
                     let mut paren_depth = 1;
+                    let mut last_end = open.end;
                     while paren_depth > 0 && !self.tokens.is_eof() {
                         let token = self.tokens.next()?;
-                        attr_name.push_str(&token.text);
-
                         match token.kind {
                             TokenKind::LeftParen => paren_depth += 1,
-                            TokenKind::RightParen => {
-                                paren_depth -= 1;
-                                if paren_depth == 0 {
-                                    attr_name.push(')');
-                                }
-                            }
+                            TokenKind::RightParen => paren_depth -= 1,
                             _ => {}
                         }
+                        if paren_depth == 0 {
+                            end = token.end;
+                            break;
+                        }
+                        if token.start > last_end && !text.is_empty() {
+                            text.push(' ');
+                        }
+                        text.push_str(&token.text);
+                        last_end = token.end;
                     }
+                    args = Some(text);
                 }
 
-                attributes.push(attr_name);
+                attributes.push(Attribute {
+                    name: attr_token.text.to_string(),
+                    args,
+                    location: SourceLocation { start: attr_token.start, end },
+                });
 
                 // Check if there's another attribute (not preceded by colon)
                 match self.peek_kind() {
@@ -81,59 +144,96 @@ impl<'a> Parser<'a> {
                 }
             }
         }
+        Ok(attributes)
+    }
 
-        // Parse optional prototype or signature after attributes
-        let (prototype, signature) = if self.peek_kind() == Some(TokenKind::LeftParen) {
-            // Look ahead to determine if this is a prototype or signature
-            if self.is_likely_prototype()? {
-                // Parse as prototype
-                let proto_content = self.parse_prototype()?;
-                let proto_node = Node::new(
-                    NodeKind::Prototype { content: proto_content },
-                    SourceLocation { start: self.current_position(), end: self.current_position() },
-                );
-                (Some(Box::new(proto_node)), None)
-            } else {
-                // Parse as signature
-                let params = self.parse_signature()?;
-                let sig_node = Node::new(
-                    NodeKind::Signature { parameters: params },
-                    SourceLocation { start: self.current_position(), end: self.current_position() },
-                );
-                (None, Some(Box::new(sig_node)))
-            }
-        } else {
-            (None, None)
-        };
+    /// Parse class declaration (Perl 5.38+)
+    ///
+    /// Handles both the block form `class Name :isa(Base) { ... }` and the
+    /// statement form `class Name;`, whose class extends to the end of the file.
+    fn parse_class(&mut self) -> ParseResult<Node> {
+        let start = self.current_position();
+        self.tokens.next()?; // consume 'class'
 
-        let body = self.parse_block()?;
+        let name_token = self.expect(TokenKind::Identifier)?;
+        let name = name_token.text.to_string();
+
+        // `class Name VERSION :isa(Base) { ... }`
+        if self.peek_kind() == Some(TokenKind::Number) {
+            self.tokens.next()?;
+        }
+        let attributes = self.parse_attribute_list()?;
+
+        if self.peek_kind() == Some(TokenKind::Semicolon) {
+            // Statement form: the following statements belong to the class
+            self.class_depth += 1;
+            let end = self.previous_position();
+            return Ok(Node::new(
+                NodeKind::Class {
+                    name,
+                    attributes,
+                    body: Box::new(Node::new(
+                        NodeKind::Block { statements: vec![] },
+                        SourceLocation { start: end, end },
+                    )),
+                },
+                SourceLocation { start, end },
+            ));
+        }
+
+        self.class_depth += 1;
+        let body = self.parse_block();
+        self.class_depth -= 1;
+        let body = body?;
 
         let end = self.previous_position();
         Ok(Node::new(
-            NodeKind::Subroutine {
-                name,
-                name_span,
-                prototype,
-                signature,
-                attributes,
-                body: Box::new(body),
-            },
+            NodeKind::Class { name, attributes, body: Box::new(body) },
             SourceLocation { start, end },
         ))
     }
 
-    /// Parse class declaration (Perl 5.38+)
-    fn parse_class(&mut self) -> ParseResult<Node> {
+    /// Whether the current identifier starts a `field` or `ADJUST` declaration
+    fn is_class_member_start(&mut self) -> bool {
+        let Ok(token) = self.tokens.peek() else { return false };
+        match token.text.as_ref() {
+            "field" => self.tokens.peek_second().is_ok_and(|next| {
+                matches!(
+                    next.kind,
+                    TokenKind::ScalarSigil | TokenKind::ArraySigil | TokenKind::HashSigil
+                ) || next.text.starts_with(['$', '@', '%'])
+            }),
+            "ADJUST" => {
+                self.tokens.peek_second().is_ok_and(|next| next.kind == TokenKind::LeftBrace)
+            }
+            _ => false,
+        }
+    }
+
+    /// Parse field declaration inside a class (Perl 5.38+)
+    ///
+    /// `field $x :param(x) :reader = 0;` as well as array and hash fields, with
+    /// defaults introduced by `=`, `//=` or `||=`.
+    fn parse_field(&mut self) -> ParseResult<Node> {
         let start = self.current_position();
-        self.tokens.next()?; // consume 'class'
+        self.tokens.next()?; // consume 'field'
 
-        let name_token = self.expect(TokenKind::Identifier)?;
-        let name = name_token.text.to_string();
+        let variable = self.parse_variable()?;
+        let attributes = self.parse_attribute_list()?;
 
-        let body = self.parse_block()?;
+        let default = match self.peek_kind() {
+            Some(TokenKind::Assign | TokenKind::DefinedOrAssign | TokenKind::LogicalOrAssign) => {
+                self.tokens.next()?;
+                Some(Box::new(self.parse_expression()?))
+            }
+            _ => None,
+        };
 
         let end = self.previous_position();
-        Ok(Node::new(NodeKind::Class { name, body: Box::new(body) }, SourceLocation { start, end }))
+        Ok(Node::new(
+            NodeKind::Field { variable: Box::new(variable), attributes, default },
+            SourceLocation { start, end },
+        ))
     }
 
     /// Parse method declaration (Perl 5.38+)
@@ -144,12 +244,16 @@ impl<'a> Parser<'a> {
         let name_token = self.expect(TokenKind::Identifier)?;
         let name = name_token.text.to_string();
 
+        // Attributes come before the signature, as for subs
+        let attributes = self.parse_attribute_list()?;
+
         // Parse optional signature
         let signature = if self.peek_kind() == Some(TokenKind::LeftParen) {
+            let sig_start = self.current_position();
             let params = self.parse_signature()?;
             Some(Box::new(Node::new(
                 NodeKind::Signature { parameters: params },
-                SourceLocation { start: self.current_position(), end: self.current_position() },
+                SourceLocation { start: sig_start, end: self.previous_position() },
             )))
         } else {
             None
@@ -159,7 +263,7 @@ impl<'a> Parser<'a> {
 
         let end = self.previous_position();
         Ok(Node::new(
-            NodeKind::Method { name, signature, attributes: Vec::new(), body: Box::new(body) },
+            NodeKind::Method { name, signature, attributes, body: Box::new(body) },
             SourceLocation { start, end },
         ))
     }
@@ -186,7 +290,7 @@ impl<'a> Parser<'a> {
         self.tokens.enter_format_mode();
 
         // Get the format body
-        let body_token = self.tokens.next()?;
+        let body_token = self.consume_token()?;
         let body = if body_token.kind == TokenKind::FormatBody {
             body_token.text.to_string()
         } else {
@@ -278,13 +382,6 @@ impl<'a> Parser<'a> {
             None
         };
 
-        // If we have a version, append it to the name for now
-        // (In a real AST, you'd probably want these as separate fields)
-        if let Some(ver) = version {
-            name.push(' ');
-            name.push_str(&ver);
-        }
-
         let block = if self.peek_kind() == Some(TokenKind::LeftBrace) {
             Some(Box::new(self.parse_block()?))
         } else {
@@ -293,7 +390,7 @@ impl<'a> Parser<'a> {
         };
 
         let end = self.previous_position();
-        Ok(Node::new(NodeKind::Package { name, name_span, block }, SourceLocation { start, end }))
+        Ok(Node::new(NodeKind::Package { name, name_span, version, block }, SourceLocation { start, end }))
     }
 
     /// Parse use statement
@@ -690,7 +787,10 @@ impl<'a> Parser<'a> {
 
         let end = self.previous_position();
         let has_filter_risk = Self::is_filter_module(&module);
-        Ok(Node::new(NodeKind::Use { module, args, has_filter_risk }, SourceLocation { start, end }))
+        let node =
+            Node::new(NodeKind::Use { module, args, has_filter_risk }, SourceLocation { start, end });
+        PragmaTracker::apply(&node, &mut self.pragma_state);
+        Ok(node)
     }
 
     /// Parse special block (AUTOLOAD, DESTROY, etc.)
@@ -713,13 +813,13 @@ impl<'a> Parser<'a> {
                 prototype: None,
                 signature: None,
                 attributes: vec![],
-                body: Box::new(block),
+                body: Some(Box::new(block)),
             },
             SourceLocation { start, end },
         ))
     }
 
-    /// Parse phase block (BEGIN, END, CHECK, INIT, UNITCHECK)
+    /// Parse phase block (BEGIN, END, CHECK, INIT, UNITCHECK, or a class's ADJUST)
     fn parse_phase_block(&mut self) -> ParseResult<Node> {
         let start = self.current_position();
         let phase_token = self.consume_token()?;
@@ -755,17 +855,21 @@ impl<'a> Parser<'a> {
         let marker = marker_token.text.to_string();
 
         // Check if there's a data body token
-        let body = if self.peek_kind() == Some(TokenKind::DataBody) {
+        let (body, body_span) = if self.peek_kind() == Some(TokenKind::DataBody) {
             let body_token = self.consume_token()?;
-            Some(body_token.text.to_string())
+            let body_span = SourceLocation { start: body_token.start, end: body_token.end };
+            (Some(body_token.text.to_string()), body_span)
         } else {
-            None
+            (None, SourceLocation { start: marker_token.end, end: marker_token.end })
         };
 
         let end = self.previous_position();
 
         // Create a data section node
-        Ok(Node::new(NodeKind::DataSection { marker, body }, SourceLocation { start, end }))
+        Ok(Node::new(
+            NodeKind::DataSection { marker, body, body_span },
+            SourceLocation { start, end },
+        ))
     }
 
     /// Parse no statement (similar to use but disables pragmas/modules)
@@ -893,7 +997,10 @@ impl<'a> Parser<'a> {
 
         let end = self.previous_position();
         let has_filter_risk = Self::is_filter_module(&module);
-        Ok(Node::new(NodeKind::No { module, args, has_filter_risk }, SourceLocation { start, end }))
+        let node =
+            Node::new(NodeKind::No { module, args, has_filter_risk }, SourceLocation { start, end });
+        PragmaTracker::apply(&node, &mut self.pragma_state);
+        Ok(node)
     }
 
 }
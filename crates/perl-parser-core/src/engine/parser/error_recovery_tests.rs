@@ -96,7 +96,7 @@ fn test_recovery_inside_block() {
                 // Should be 1 statement (the subroutine declaration)
                 assert_eq!(statements.len(), 1);
 
-                if let NodeKind::Subroutine { body, .. } = &statements[0].kind {
+                if let NodeKind::Subroutine { body: Some(body), .. } = &statements[0].kind {
                     if let NodeKind::Block { statements } = &body.kind {
                         assert_eq!(
                             statements.len(),
@@ -280,7 +280,7 @@ fn test_451_ac9_block_level_recovery() {
 
     if let NodeKind::Program { statements } = &ast.kind {
         if let Some(sub_node) = statements.first() {
-            if let NodeKind::Subroutine { body, .. } = &sub_node.kind {
+            if let NodeKind::Subroutine { body: Some(body), .. } = &sub_node.kind {
                 if let NodeKind::Block { statements: block_stmts } = &body.kind {
                     assert_eq!(block_stmts.len(), 4, "AC9: Block should have all statements");
 
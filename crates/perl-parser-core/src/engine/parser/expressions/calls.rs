@@ -52,6 +52,11 @@ impl<'a> Parser<'a> {
                 _ => {}
             }
 
+            // print {$fh} LIST: a block always names the filehandle
+            if next_kind == TokenKind::LeftBrace && matches!(name, "print" | "printf" | "say") {
+                return true;
+            }
+
             // Check for print $fh $x pattern first (variable followed by another arg)
             // This must be checked before the STDOUT pattern because $fh is also an Identifier
             if next_text.starts_with('$') {
@@ -83,10 +88,16 @@ impl<'a> Parser<'a> {
 
             // print STDOUT ... (uppercase bareword filehandle)
             // But NOT if followed by comma — that's a regular call: open FILE, "..."
+            // Nor by `->` or an adjoining `(`, which make the bareword a call:
+            // print Foo->new, print Foo::bar(1) (but print FH ($x) prints to FH)
             if next_kind == TokenKind::Identifier {
                 if next_text.chars().next().is_some_and(|c| c.is_uppercase()) {
+                    let next_end = next_token.end;
                     if let Ok(third) = self.tokens.peek_third() {
-                        if third.kind == TokenKind::Comma {
+                        let call_parens =
+                            third.kind == TokenKind::LeftParen && third.start == next_end;
+                        if matches!(third.kind, TokenKind::Comma | TokenKind::Arrow) || call_parens
+                        {
                             return false;
                         }
                     }
@@ -133,6 +144,9 @@ impl<'a> Parser<'a> {
     }
 
     /// Parse indirect object/method call
+    ///
+    /// Word operators after the arguments apply to the whole call, so
+    /// `open FH or die` parses as `(open FH) or die`.
     fn parse_indirect_call(&mut self) -> ParseResult<Node> {
         // Use recursion guard to prevent stack overflow on deep nesting
         // Indirect calls can be nested: new Class(new Class(new Class()))
@@ -145,20 +159,33 @@ impl<'a> Parser<'a> {
         // We're consuming the function name, no longer at statement start
         self.mark_not_stmt_start();
 
-        // Parse the object/filehandle
-        let object = self.parse_primary()?;
+        // Parse the object/filehandle; `print {EXPR} LIST` takes it from a block
+        let object = if matches!(method.as_str(), "print" | "printf" | "say")
+            && self.peek_kind() == Some(TokenKind::LeftBrace)
+        {
+            self.parse_block()?
+        } else {
+            self.parse_primary()?
+        };
 
         // Parse remaining arguments
         let mut args = vec![];
 
-        // Continue parsing arguments until we hit a statement terminator
+        // Continue parsing arguments until we hit a statement terminator or the
+        // closing brace of a block whose last statement has no semicolon.
         // Word operators (or, and, not, xor) bind less tightly than list operators,
         // so they terminate argument collection for indirect calls.
         while !Self::is_statement_terminator(self.peek_kind())
             && !self.is_statement_modifier_keyword()
             && !matches!(
                 self.peek_kind(),
-                Some(TokenKind::WordOr | TokenKind::WordAnd | TokenKind::WordXor | TokenKind::WordNot)
+                Some(
+                    TokenKind::RightBrace
+                        | TokenKind::WordOr
+                        | TokenKind::WordAnd
+                        | TokenKind::WordXor
+                        | TokenKind::WordNot
+                )
             )
         {
             // Use parse_assignment instead of parse_expression to avoid grouping by comma operator
@@ -179,10 +206,11 @@ impl<'a> Parser<'a> {
         self.exit_recursion();
 
         // Return as an indirect call node (using MethodCall with a flag or separate node)
-        Ok(Node::new(
+        let call = Node::new(
             NodeKind::IndirectCall { method, object: Box::new(object), args },
             SourceLocation { start, end },
-        ))
+        );
+        self.parse_word_or_expr(call)
     }
 
     /// Parse function arguments
@@ -195,20 +223,12 @@ impl<'a> Parser<'a> {
 
             while s.peek_kind() != Some(TokenKind::RightParen) && !s.tokens.is_eof() {
                 // Use parse_assignment instead of parse_expression to avoid comma operator handling
-                let mut arg = s.parse_assignment()?;
+                let arg = s.parse_assignment()?;
 
                 // Check for fat arrow after the argument
                 // If we see =>, the argument should be auto-quoted if it's a bare identifier
                 if s.peek_kind() == Some(TokenKind::FatArrow) {
-                    // Auto-quote bare identifiers before =>
-                    if let NodeKind::Identifier { ref name } = arg.kind {
-                        // Convert identifier to string (auto-quoting)
-                        arg = Node::new(
-                            NodeKind::String { value: name.clone(), interpolated: false },
-                            arg.location,
-                        );
-                    }
-                    args.push(arg);
+                    args.push(Self::auto_quote_fat_comma_key(arg));
                     s.tokens.next()?; // consume =>
                     // Continue to parse more arguments (the value after =>)
                     continue;
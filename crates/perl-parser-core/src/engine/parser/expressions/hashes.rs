@@ -6,12 +6,25 @@ impl<'a> Parser<'a> {
             let start_token = s.tokens.next()?; // consume {
             let start = start_token.start;
 
+            // `{;` is the conventional way to force a block in `map {; ... }`
+            while s.peek_kind() == Some(TokenKind::Semicolon) {
+                s.tokens.next()?;
+            }
+
             // Parse the expression inside the block (if any)
             let mut statements = Vec::new();
             if s.peek_kind() != Some(TokenKind::RightBrace) {
                 statements.push(s.parse_expression()?);
             }
 
+            // Multi-statement block: `map { my $x = $_; $x * 2 } @list`
+            if s.peek_kind() == Some(TokenKind::Semicolon) {
+                s.tokens.next()?;
+                while s.peek_kind() != Some(TokenKind::RightBrace) && !s.tokens.is_eof() {
+                    statements.push(s.parse_statement()?);
+                }
+            }
+
             s.expect(TokenKind::RightBrace)?;
             let end = s.previous_position();
 
@@ -20,8 +33,116 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse the `{` that starts the first argument of `map`, `grep` or `sort`.
+    ///
+    /// `sort` always takes a block. For `map` and `grep` Perl guesses the same
+    /// way as [`Self::brace_starts_anon_hash`]: braces that look like an
+    /// anonymous hash are an expression argument, which then needs a comma
+    /// before the list. Returns the node and whether it was parsed as a block.
+    fn parse_builtin_brace_arg(&mut self, func_name: &str) -> ParseResult<(Node, bool)> {
+        if func_name == "sort" || !self.brace_starts_anon_hash() {
+            return Ok((self.parse_builtin_block()?, true));
+        }
+
+        let hash = self.parse_anon_hash()?;
+        if self.peek_kind() != Some(TokenKind::Comma) && !self.is_at_statement_end() {
+            // perl reports this as a syntax error; keep the argument and carry on
+            self.record_error(ParseError::syntax(
+                format!(
+                    "'{{' after {func_name} was parsed as an anonymous hash; \
+                     add a comma before the list, or write '{{;' to force a block"
+                ),
+                hash.location.end,
+            ));
+        }
+        Ok((hash, false))
+    }
+
+    /// Perl's heuristic for braces that could open either a block or an
+    /// anonymous hash: with the current token on `{`, it is a hash when the
+    /// first token inside is a word (bareword or digits) or a string followed
+    /// by `,` or `=>`. Empty braces are left to the caller, since `map {} @x`
+    /// is an empty block while `my $h = {}` is an empty hash.
+    ///
+    /// `map { "$_" => 1 } @list` is therefore a hash (and a syntax error in
+    /// perl), while `map { $_ => 1 } @list` and `map {; "$_" => 1 } @list`
+    /// are blocks.
+    fn brace_starts_anon_hash(&mut self) -> bool {
+        if self.peek_kind() != Some(TokenKind::LeftBrace) {
+            return false;
+        }
+
+        let first_is_key = self.tokens.peek_second().is_ok_and(|token| {
+            matches!(token.kind, TokenKind::String | TokenKind::QuoteSingle | TokenKind::QuoteDouble)
+                || Self::is_word_text(&token.text)
+        });
+
+        first_is_key
+            && self
+                .tokens
+                .peek_third()
+                .is_ok_and(|t| matches!(t.kind, TokenKind::Comma | TokenKind::FatArrow))
+    }
+
+    /// Whether `text` is a single word (`key`, `Foo::Bar`, `42`), including keywords.
+    fn is_word_text(text: &str) -> bool {
+        !text.is_empty() && text.chars().all(|c| c.is_alphanumeric() || c == '_' || c == ':')
+    }
+
+    /// Parse `{ LIST }` as an anonymous hash constructor.
+    ///
+    /// Used once the braces are known to be a hash: after a unary `+`, or when
+    /// [`Self::brace_starts_anon_hash`] matched. List elements are paired in
+    /// order, so `+{ %$defaults }` keeps its single element as a key with an
+    /// `undef` value, like an odd-sized list below.
+    fn parse_anon_hash(&mut self) -> ParseResult<Node> {
+        self.with_recursion_guard(|s| {
+            let start_token = s.tokens.next()?; // consume {
+            let start = start_token.start;
+
+            let mut pairs = Vec::new();
+            if s.peek_kind() != Some(TokenKind::RightBrace) {
+                let list = s.parse_expression()?;
+                pairs = Self::hash_pairs_from_list(list);
+            }
+
+            s.expect(TokenKind::RightBrace)?;
+            let end = s.previous_position();
+
+            Ok(Node::new(NodeKind::HashLiteral { pairs }, SourceLocation { start, end }))
+        })
+    }
+
+    /// Pair up the elements of a parsed list as hash keys and values.
+    fn hash_pairs_from_list(list: Node) -> Vec<(Node, Node)> {
+        let elements = match list.kind {
+            NodeKind::HashLiteral { pairs } => return pairs,
+            NodeKind::ArrayLiteral { elements } => elements,
+            kind => vec![Node::new(kind, list.location)],
+        };
+
+        let mut pairs = Vec::with_capacity(elements.len().div_ceil(2));
+        let mut elements = elements.into_iter();
+        while let Some(key) = elements.next() {
+            let value = elements.next().unwrap_or_else(|| Self::missing_hash_value(key.location.end));
+            pairs.push((key, value));
+        }
+        pairs
+    }
+
+    /// Placeholder value for a hash key that has none (odd-sized list).
+    fn missing_hash_value(position: usize) -> Node {
+        Node::new(
+            NodeKind::Identifier { name: "undef".to_string() },
+            SourceLocation { start: position, end: position },
+        )
+    }
+
     /// Parse hash literal or block
     fn parse_hash_or_block(&mut self) -> ParseResult<Node> {
+        if self.brace_starts_anon_hash() {
+            return self.parse_anon_hash();
+        }
         self.parse_hash_or_block_with_context(false)
     }
 
@@ -62,6 +183,10 @@ impl<'a> Parser<'a> {
                 if matches!(e, ParseError::RecursionLimit | ParseError::NestingTooDeep { .. }) {
                     return Err(e);
                 }
+                // Tokens consumed by the failed attempt are not re-parsed, so keep its error
+                if self.current_position() != _saved_pos {
+                    self.record_error(e);
+                }
                 // If we can't parse an expression, parse as block statements
                 let mut statements = Vec::new();
                 while self.peek_kind() != Some(TokenKind::RightBrace) && !self.tokens.is_eof() {
@@ -130,11 +255,11 @@ impl<'a> Parser<'a> {
             // Parse as hash
             _is_hash = true;
 
-            if self.peek_kind() != /* ~ changed by cargo-mutants ~ */ Some(TokenKind::FatArrow) {
+            if self.peek_kind() == Some(TokenKind::FatArrow) {
                 // key => value pattern
                 self.tokens.next()?; // consume =>
                 let value = self.parse_expression()?;
-                pairs.push((first_expr, value));
+                pairs.push((Self::auto_quote_fat_comma_key(first_expr), value));
             } else if self.peek_kind() == Some(TokenKind::Comma) {
                 // comma-separated pattern: key, value, key2, value2
                 self.tokens.next()?; // consume comma
@@ -144,13 +269,7 @@ impl<'a> Parser<'a> {
                     pairs.push((first_expr, second));
                 } else {
                     // Trailing comma - treat as single element hash with undef value
-                    let undef = Node::new(
-                        NodeKind::Identifier { name: "undef".to_string() },
-                        SourceLocation {
-                            start: self.current_position(),
-                            end: self.current_position(),
-                        },
-                    );
+                    let undef = Self::missing_hash_value(self.current_position());
                     pairs.push((first_expr, undef));
                 }
             }
@@ -173,19 +292,13 @@ impl<'a> Parser<'a> {
                 if self.peek_kind() == Some(TokenKind::FatArrow) {
                     self.tokens.next()?; // consume =>
                     let value = self.parse_expression()?;
-                    pairs.push((key, value));
+                    pairs.push((Self::auto_quote_fat_comma_key(key), value));
                 } else if self.peek_kind() == Some(TokenKind::Comma) {
                     self.consume_token()?; // consume comma
 
                     if self.peek_kind() == Some(TokenKind::RightBrace) {
                         // Odd number of elements - last one becomes undef value
-                        let undef = Node::new(
-                            NodeKind::Identifier { name: "undef".to_string() },
-                            SourceLocation {
-                                start: self.current_position(),
-                                end: self.current_position(),
-                            },
-                        );
+                        let undef = Self::missing_hash_value(self.current_position());
                         pairs.push((key, undef));
                         break;
                     }
@@ -194,13 +307,7 @@ impl<'a> Parser<'a> {
                     pairs.push((key, value));
                 } else if self.peek_kind() == Some(TokenKind::RightBrace) {
                     // Key without value at end - add undef
-                    let undef = Node::new(
-                        NodeKind::Identifier { name: "undef".to_string() },
-                        SourceLocation {
-                            start: self.current_position(),
-                            end: self.current_position(),
-                        },
-                    );
+                    let undef = Self::missing_hash_value(self.current_position());
                     pairs.push((key, undef));
                     break;
                 } else {
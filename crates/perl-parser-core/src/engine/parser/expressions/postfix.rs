@@ -56,6 +56,23 @@ impl<'a> Parser<'a> {
                                     },
                                     SourceLocation { start, end },
                                 );
+                            } else if self.peek_kind() == Some(TokenKind::LeftBrace) {
+                                // ->@{...} hash slice (values)
+                                self.tokens.next()?; // consume {
+                                let key = self.parse_expression()?;
+                                self.expect(TokenKind::RightBrace)?;
+
+                                let start = expr.location.start;
+                                let end = self.previous_position();
+
+                                expr = Node::new(
+                                    NodeKind::Binary {
+                                        op: "->@{}".to_string(),
+                                        left: Box::new(expr),
+                                        right: Box::new(key),
+                                    },
+                                    SourceLocation { start, end },
+                                );
                             }
                         }
 
@@ -94,6 +111,23 @@ impl<'a> Parser<'a> {
                                     },
                                     SourceLocation { start, end },
                                 );
+                            } else if self.peek_kind() == Some(TokenKind::LeftBracket) {
+                                // ->%[...] index/value array slice
+                                self.tokens.next()?; // consume [
+                                let index = self.parse_expression()?;
+                                self.expect(TokenKind::RightBracket)?;
+
+                                let start = expr.location.start;
+                                let end = self.previous_position();
+
+                                expr = Node::new(
+                                    NodeKind::Binary {
+                                        op: "->%[]".to_string(),
+                                        left: Box::new(expr),
+                                        right: Box::new(index),
+                                    },
+                                    SourceLocation { start, end },
+                                );
                             }
                         }
 
@@ -154,6 +188,26 @@ impl<'a> Parser<'a> {
                             }
                         }
 
+                        Some(TokenKind::Identifier)
+                            if self.tokens.peek()?.text.as_ref() == "$#"
+                                && self.tokens.peek_second().map(|t| t.kind).ok()
+                                    == Some(TokenKind::Star) =>
+                        {
+                            // ->$#* (last index of array reference)
+                            self.tokens.next()?; // consume $#
+                            self.tokens.next()?; // consume *
+                            let start = expr.location.start;
+                            let end = self.previous_position();
+
+                            expr = Node::new(
+                                NodeKind::Unary {
+                                    op: "->$#*".to_string(),
+                                    operand: Box::new(expr),
+                                },
+                                SourceLocation { start, end },
+                            );
+                        }
+
                         Some(TokenKind::Identifier | TokenKind::Method) => {
                             // Method call
                             let method = self.tokens.next()?.text.to_string();
@@ -257,26 +311,16 @@ impl<'a> Parser<'a> {
                                     args.push(self.parse_comma()?);
                                 }
                             } else if matches!(name.as_str(), "sort" | "map" | "grep") {
-                                // Parse block expression as first argument
-                                let block_start = self.current_position();
-                                self.expect(TokenKind::LeftBrace)?;
-
-                                // Parse the expression inside the block (if any)
-                                let mut statements = Vec::new();
-                                if self.peek_kind() != Some(TokenKind::RightBrace) {
-                                    statements.push(self.parse_expression()?);
-                                }
-
-                                self.expect(TokenKind::RightBrace)?;
-                                let block_end = self.previous_position();
+                                let (arg, is_block) = self.parse_builtin_brace_arg(name)?;
+                                args.push(arg);
 
-                                // Wrap the expression in a block node
-                                let block = Node::new(
-                                    NodeKind::Block { statements },
-                                    SourceLocation { start: block_start, end: block_end },
-                                );
-
-                                args.push(block);
+                                // `map BLOCK LIST` takes its list without a separating comma
+                                if is_block
+                                    && self.peek_kind() != Some(TokenKind::Comma)
+                                    && !self.is_at_statement_end()
+                                {
+                                    args.push(self.parse_assignment()?);
+                                }
 
                                 // Parse remaining arguments
                                 while self.peek_kind() == Some(TokenKind::Comma) {
@@ -286,9 +330,6 @@ impl<'a> Parser<'a> {
                                     }
                                     args.push(self.parse_comma()?);
                                 }
-                            } else if matches!(name.as_str(), "sort" | "map" | "grep") {
-                                // These builtins should parse {} as blocks, not hashes
-                                args.push(self.parse_builtin_block()?);
                             } else {
                                 // Other builtins - parse {} as first argument
                                 args.push(self.parse_hash_or_block()?);
@@ -445,6 +486,10 @@ impl<'a> Parser<'a> {
                                         }
                                         args.push(self.parse_assignment()?);
                                     }
+                                } else if name == "defined" {
+                                    // Named unary operator: binds tighter than comparison
+                                    // and `?:`, so `defined $x ? $x : 0` tests only `$x`
+                                    args.push(self.parse_shift()?);
                                 } else {
                                     // Parse the first argument
                                     args.push(self.parse_ternary()?);
@@ -1,6 +1,17 @@
 impl<'a> Parser<'a> {
     /// Parse comma operator (lowest precedence except for word operators)
     fn parse_comma(&mut self) -> ParseResult<Node> {
+        let expr = self.parse_comma_list()?;
+
+        // Now handle word operators (or, xor, and, not) which have the lowest precedence
+        self.parse_word_or_expr(expr)
+    }
+
+    /// Parse a comma list without the word operators that may follow it
+    ///
+    /// Used where an enclosing construct binds tighter than `and`/`or`, such as
+    /// the initializer of `my $x = ... or die`.
+    fn parse_comma_list(&mut self) -> ParseResult<Node> {
         let mut expr = self.parse_assignment()?;
 
         // In scalar context, comma creates a list
@@ -9,15 +20,16 @@ impl<'a> Parser<'a> {
         if self.peek_kind() == Some(TokenKind::Comma)
             || self.peek_kind() == Some(TokenKind::FatArrow)
         {
-            let mut expressions = vec![expr];
             let mut saw_fat_comma = false;
 
             // Handle initial fat arrow
-            if self.peek_kind() == Some(TokenKind::FatArrow) {
+            let mut expressions = if self.peek_kind() == Some(TokenKind::FatArrow) {
                 saw_fat_comma = true;
                 self.tokens.next()?; // consume =>
-                expressions.push(self.parse_assignment()?);
-            }
+                vec![Self::auto_quote_fat_comma_key(expr), self.parse_assignment()?]
+            } else {
+                vec![expr]
+            };
 
             while self.peek_kind() == Some(TokenKind::Comma)
                 || self.peek_kind() == Some(TokenKind::FatArrow)
@@ -41,7 +53,7 @@ impl<'a> Parser<'a> {
                 if self.peek_kind() == Some(TokenKind::FatArrow) {
                     saw_fat_comma = true;
                     self.tokens.next()?; // consume =>
-                    expressions.push(elem);
+                    expressions.push(Self::auto_quote_fat_comma_key(elem));
 
                     // Check again for end of expression
                     match self.peek_kind() {
@@ -66,9 +78,6 @@ impl<'a> Parser<'a> {
             expr = Self::build_list_or_hash(expressions, saw_fat_comma, start, end);
         }
 
-        // Now handle word operators (or, xor, and, not) which have the lowest precedence
-        expr = self.parse_word_or_expr(expr)?;
-
         Ok(expr)
     }
 
@@ -215,8 +224,9 @@ impl<'a> Parser<'a> {
 
         if self.peek_kind() == Some(TokenKind::Question) {
             self.tokens.next()?; // consume ?
-            // Allow nested ternary in then-branch for right associativity
-            let then_expr = self.parse_ternary()?;
+            // The then-branch is delimited by `:`, so it may hold an assignment
+            // (`$c ? $x = 1 : $y`) as well as a nested ternary
+            let then_expr = self.parse_assignment()?;
             self.expect(TokenKind::Colon)?;
             let else_expr = self.parse_ternary()?;
 
@@ -328,11 +338,11 @@ impl<'a> Parser<'a> {
         Ok(expr)
     }
 
-    /// Parse range expression
+    /// Parse range expression (`..` and the flip-flop form `...`)
     fn parse_range(&mut self) -> ParseResult<Node> {
         let mut expr = self.parse_equality()?;
 
-        while self.peek_kind() == Some(TokenKind::Range) {
+        while matches!(self.peek_kind(), Some(TokenKind::Range | TokenKind::Ellipsis)) {
             let op_token = self.tokens.next()?;
             let right = self.parse_equality()?;
             let start = expr.location.start;
@@ -633,6 +643,29 @@ impl<'a> Parser<'a> {
                         SourceLocation { start, end },
                     );
                 }
+                TokenKind::Identifier => {
+                    // Check if it's the repetition operator ("ab" x 3, (0) x $n)
+                    if self.tokens.peek()?.text.as_ref() == "x"
+                        && self.tokens.peek_second().map(|t| t.kind).ok()
+                            != Some(TokenKind::FatArrow)
+                    {
+                        let op_token = self.tokens.next()?;
+                        let right = self.parse_unary()?;
+                        let start = expr.location.start;
+                        let end = right.location.end;
+
+                        expr = Node::new(
+                            NodeKind::Binary {
+                                op: op_token.text.to_string(),
+                                left: Box::new(expr),
+                                right: Box::new(right),
+                            },
+                            SourceLocation { start, end },
+                        );
+                    } else {
+                        break;
+                    }
+                }
                 _ => break,
             }
         }
@@ -47,6 +47,11 @@ impl<'a> Parser<'a> {
 
     /// Inner implementation of parse_primary (called under recursion guard)
     fn parse_primary_inner(&mut self) -> ParseResult<Node> {
+        // `word => ...` auto-quotes the word, whatever it would otherwise parse as
+        if let Some(key) = self.try_parse_fat_comma_bareword()? {
+            return Ok(key);
+        }
+
         let token = self.tokens.peek()?;
         let token_kind = token.kind;
 
@@ -74,7 +79,8 @@ impl<'a> Parser<'a> {
                 let (pattern, body, modifiers) = quote_parser::extract_regex_parts(&token.text);
 
                 // Validate regex complexity and check for embedded code
-                let validator = crate::engine::regex_validator::RegexValidator::new();
+                let validator = crate::engine::regex_validator::RegexValidator::new()
+                    .with_extended(modifiers.contains('x'));
                 validator.validate(&body, token.start)?;
                 let has_embedded_code = validator.detects_code_execution(&body);
 
@@ -185,7 +191,8 @@ impl<'a> Parser<'a> {
                     )?;
 
                 // Validate regex complexity and check for embedded code
-                let validator = crate::engine::regex_validator::RegexValidator::new();
+                let validator = crate::engine::regex_validator::RegexValidator::new()
+                    .with_extended(modifiers.contains('x'));
                 validator.validate(&pattern, token.start)?;
                 let has_embedded_code = validator.detects_code_execution(&pattern);
 
@@ -269,7 +276,7 @@ impl<'a> Parser<'a> {
 
             // Note: TokenKind::Sub is handled in the keyword-as-identifier case below
             // This allows 'sub' to be used as a hash key or identifier in expressions
-            TokenKind::Try => self.parse_try(),
+            TokenKind::Try if self.is_try_start() => self.parse_try(),
 
             TokenKind::Less => {
                 // Could be diamond operator <> or <FILEHANDLE>
@@ -445,15 +452,16 @@ impl<'a> Parser<'a> {
                     || self.peek_kind() == Some(TokenKind::FatArrow)
                 {
                     // It's a list
-                    let mut elements = vec![first];
                     let mut saw_fat_comma = false;
 
                     // Handle fat arrow after first element
-                    if self.peek_kind() == Some(TokenKind::FatArrow) {
+                    let mut elements = if self.peek_kind() == Some(TokenKind::FatArrow) {
                         saw_fat_comma = true;
                         self.tokens.next()?; // consume =>
-                        elements.push(self.parse_assignment()?);
-                    }
+                        vec![Self::auto_quote_fat_comma_key(first), self.parse_assignment()?]
+                    } else {
+                        vec![first]
+                    };
 
                     while self.peek_kind() == Some(TokenKind::Comma)
                         || self.peek_kind() == Some(TokenKind::FatArrow)
@@ -472,7 +480,7 @@ impl<'a> Parser<'a> {
                         if self.peek_kind() == Some(TokenKind::FatArrow) {
                             saw_fat_comma = true;
                             self.consume_token()?; // consume =>
-                            elements.push(elem);
+                            elements.push(Self::auto_quote_fat_comma_key(elem));
                             if self.peek_kind() != Some(TokenKind::RightParen) {
                                 elements.push(self.parse_assignment()?);
                             }
@@ -532,7 +540,7 @@ impl<'a> Parser<'a> {
             TokenKind::Ellipsis => {
                 let token = self.tokens.next()?;
                 Ok(Node::new(
-                    NodeKind::Ellipsis,
+                    NodeKind::YadaYada,
                     SourceLocation { start: token.start, end: token.end },
                 ))
             }
@@ -564,6 +572,18 @@ impl<'a> Parser<'a> {
                 }
             }
 
+            // A declaration used as a term, as in `open(my $fh, ...)` or `foo(my @list)`
+            TokenKind::My | TokenKind::Our | TokenKind::State
+                if self.tokens.peek_second().is_ok_and(|next| {
+                    next.kind == TokenKind::LeftParen
+                        || Self::is_variable_sigil(Some(next.kind))
+                        || (next.kind == TokenKind::Identifier
+                            && next.text.starts_with(['$', '@', '%']))
+                }) =>
+            {
+                self.parse_variable_declaration()
+            }
+
             // Handle keywords that can be used as identifiers in certain contexts
             // Note: Statement-level keywords (if, unless, while, return, etc.) should NOT be here
             TokenKind::My
@@ -581,6 +601,7 @@ impl<'a> Parser<'a> {
             | TokenKind::Given
             | TokenKind::When
             | TokenKind::Default
+            | TokenKind::Try
             | TokenKind::Catch
             | TokenKind::Finally
             | TokenKind::Continue
@@ -193,7 +193,8 @@ impl<'a> Parser<'a> {
             "qr" => {
                 // Regular expression
                 // Validate regex complexity and check for embedded code
-                let validator = crate::engine::regex_validator::RegexValidator::new();
+                let validator = crate::engine::regex_validator::RegexValidator::new()
+                    .with_extended(modifiers.contains('x'));
                 validator.validate(&content, start).map_err(|e| match e {
                     crate::engine::regex_validator::RegexError::Syntax { message, offset } => {
                         ParseError::syntax(message, offset)
@@ -221,7 +222,8 @@ impl<'a> Parser<'a> {
             "m" => {
                 // Match operator with pattern
                 // Validate regex complexity and check for embedded code
-                let validator = crate::engine::regex_validator::RegexValidator::new();
+                let validator = crate::engine::regex_validator::RegexValidator::new()
+                    .with_extended(modifiers.contains('x'));
                 validator.validate(&content, start).map_err(|e| match e {
                     crate::engine::regex_validator::RegexError::Syntax { message, offset } => {
                         ParseError::syntax(message, offset)
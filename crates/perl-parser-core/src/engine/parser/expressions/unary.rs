@@ -7,6 +7,11 @@ impl<'a> Parser<'a> {
                     let op_token = self.tokens.next()?;
                     let start = op_token.start;
 
+                    // `-word => ...` is the string "-word", even for single letters
+                    if let Some(word) = self.try_parse_fat_comma_bareword()? {
+                        return Ok(Self::negated_bareword(&word, start));
+                    }
+
                     // Check for file test operators (-e, -f, -d, etc.)
                     if let Some(TokenKind::Identifier) = self.peek_kind() {
                         let next_token = self.tokens.peek()?;
@@ -41,6 +46,11 @@ impl<'a> Parser<'a> {
                     let operand = self.parse_unary()?;
                     let end = operand.location.end;
 
+                    // Minus applied to a bareword yields the string "-word"
+                    if matches!(operand.kind, NodeKind::Identifier { .. }) {
+                        return Ok(Self::negated_bareword(&operand, start));
+                    }
+
                     return Ok(Node::new(
                         NodeKind::Unary { op: op_token.text.to_string(), operand: Box::new(operand) },
                         SourceLocation { start, end },
@@ -52,8 +62,7 @@ impl<'a> Parser<'a> {
 
                     // Special case: +{ ... } forces a hash constructor (not a block)
                     if self.peek_kind() == Some(TokenKind::LeftBrace) {
-                        // Parse as hash literal
-                        let hash = self.parse_hash_or_block()?;
+                        let hash = self.parse_anon_hash()?;
                         let end = hash.location.end;
 
                         // Wrap the hash in a unary plus to preserve the explicit disambiguation
@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use crate::engine::parser::Parser;
+    use perl_ast::ast::{Node, NodeKind};
+    use perl_tdd_support::must;
+
+    /// Parse `source` without recovered errors and return the top-level statements
+    fn parse_statements(source: &str) -> Vec<Node> {
+        let mut parser = Parser::new(source);
+        let ast = must(parser.parse());
+        assert!(parser.errors().is_empty(), "unexpected errors: {:?}", parser.errors());
+        match ast.kind {
+            NodeKind::Program { statements } => statements,
+            other => unreachable!("Expected Program, got {:?}", other),
+        }
+    }
+
+    /// Keys of the hash literal initializing the first `my` declaration
+    fn hash_keys(source: &str) -> Vec<NodeKind> {
+        let statements = parse_statements(source);
+        let declaration = statements
+            .iter()
+            .find(|s| matches!(s.kind, NodeKind::VariableDeclaration { .. }))
+            .map(|s| &s.kind);
+        match declaration {
+            Some(NodeKind::VariableDeclaration { initializer: Some(init), .. }) => {
+                match &init.kind {
+                    NodeKind::HashLiteral { pairs } => {
+                        pairs.iter().map(|(key, _)| key.kind.clone()).collect()
+                    }
+                    other => unreachable!("Expected HashLiteral, got {:?}", other),
+                }
+            }
+            other => unreachable!("Expected initialized declaration, got {:?}", other),
+        }
+    }
+
+    fn string(value: &str) -> NodeKind {
+        NodeKind::String { value: value.to_string(), interpolated: false }
+    }
+
+    #[test]
+    fn test_bareword_before_fat_comma_is_a_string() {
+        assert_eq!(hash_keys("my %h = (foo => 1);"), vec![string("foo")]);
+        assert_eq!(
+            hash_keys("my $r = { foo => 1, Foo::Bar => 2 };"),
+            vec![string("foo"), string("Foo::Bar")]
+        );
+    }
+
+    #[test]
+    fn test_negated_bareword_before_fat_comma_is_a_string() {
+        assert_eq!(hash_keys("my %h = (-bar => 2);"), vec![string("-bar")]);
+        // Single letters would otherwise be file tests
+        assert_eq!(hash_keys("my %h = (-e => 1, -x => 2);"), vec![string("-e"), string("-x")]);
+    }
+
+    #[test]
+    fn test_declared_subs_and_keywords_before_fat_comma_are_not_calls() {
+        let keys =
+            hash_keys("sub func { 1 }\nmy %h = (func => 1, print => 2, if => 3, shift => 4);");
+        assert_eq!(keys, vec![string("func"), string("print"), string("if"), string("shift")]);
+
+        let statements = parse_statements("configure(func => 1, -bar => 2);");
+        match &statements[0].kind {
+            NodeKind::ExpressionStatement { expression } => match &expression.kind {
+                NodeKind::FunctionCall { name, args } => {
+                    assert_eq!(name, "configure");
+                    assert_eq!(args[0].kind, string("func"));
+                    assert_eq!(args[2].kind, string("-bar"));
+                }
+                other => unreachable!("Expected FunctionCall, got {:?}", other),
+            },
+            other => unreachable!("Expected ExpressionStatement, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_negated_bareword_in_term_position() {
+        let statements = parse_statements("my $opt = -verbose;\nmy $n = -$x;\nmy $t = -e $file;");
+        assert!(statements[0].to_sexp().contains(r#"(string "-verbose")"#));
+        assert!(statements[1].to_sexp().contains("(unary_- (variable $ x))"));
+        assert!(statements[2].to_sexp().contains("(unary_-e (variable $ file))"));
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::engine::parser::Parser;
-    use perl_ast::ast::{Node, NodeKind, SourceLocation};
+    use perl_ast::ast::{FormatLine, Node, NodeKind, SourceLocation};
 
     fn parse_code(input: &str) -> Option<perl_ast::ast::Node> {
         let mut parser = Parser::new(input);
@@ -78,4 +78,54 @@ $val
         }
         Ok(())
     }
+
+    #[test]
+    fn test_format_picture_and_argument_lines() -> Result<(), String> {
+        let source = "format STDOUT =\n@<<<<<<< @>>>>\n$name, $total\n.\nprint 1;\n";
+        let ast = parse_code(source).ok_or("parse failed")?;
+        let NodeKind::Program { statements } = &ast.kind else {
+            return Err(format!("Expected Program, got {:?}", ast.kind));
+        };
+        let stmt = &statements[0];
+        let NodeKind::Format { name, body } = &stmt.kind else {
+            return Err(format!("Expected Format node, got {:?}", stmt.kind));
+        };
+        assert_eq!(name, "STDOUT");
+        assert_eq!(body, "\n@<<<<<<< @>>>>\n$name, $total\n");
+        // The span runs through the terminating `.` line
+        assert_eq!(stmt.location.end, source.find("print").ok_or("no print")?);
+        assert_eq!(
+            stmt.format_lines(),
+            Some(vec![FormatLine::Picture {
+                picture: "@<<<<<<< @>>>>".to_string(),
+                arguments: "$name, $total".to_string(),
+            }])
+        );
+        assert!(matches!(statements[1].kind, NodeKind::ExpressionStatement { .. }));
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_lines_classify_comments_literals_and_braced_arguments() {
+        let source = "format =\n# header\nName\n^<<<<\n{\n  $text\n}\n.\n";
+        let ast = parse_code(source);
+        let lines = ast
+            .as_ref()
+            .and_then(|ast| match &ast.kind {
+                NodeKind::Program { statements } => statements.first(),
+                _ => None,
+            })
+            .and_then(Node::format_lines);
+        assert_eq!(
+            lines,
+            Some(vec![
+                FormatLine::Comment("# header".to_string()),
+                FormatLine::Literal("Name".to_string()),
+                FormatLine::Picture {
+                    picture: "^<<<<".to_string(),
+                    arguments: "{\n  $text\n}".to_string(),
+                },
+            ])
+        );
+    }
 }
@@ -60,7 +60,8 @@ sub my_sub {
         let sexp = ast.to_sexp();
         assert!(sexp.contains("(block"), "map should take a block: {}", sexp);
 
-        // map { key => value } @list - block returning list
+        // map { key => value } @list - perl guesses an anonymous hash here,
+        // which then needs a comma before the list
         let code2 = "map { key => 'value' } @list;";
         let mut parser2 = Parser::new(code2);
         let result2 = parser2.parse();
@@ -68,9 +69,98 @@ sub my_sub {
         let ast2 = must(result2);
         let sexp2 = ast2.to_sexp();
         assert!(
-            sexp2.contains("(block"),
-            "map should take a block even with hash-like content: {}",
+            sexp2.contains("(call map ((hash"),
+            "map takes a hash when a bareword and => open the braces: {}",
             sexp2
         );
+        assert_eq!(parser2.errors().len(), 1, "missing comma after the hash is reported");
+    }
+
+    fn parse_sexp(code: &str) -> (String, usize) {
+        let mut parser = Parser::new(code);
+        let ast = must(parser.parse());
+        (ast.to_sexp(), parser.errors().len())
+    }
+
+    #[test]
+    fn test_map_brace_heuristic() {
+        // First token is a variable: block returning a list
+        let (sexp, errors) = parse_sexp("my %h = map { $_ => 1 } @list;");
+        assert!(sexp.contains("(call map ((block (hash"), "map takes a block: {}", sexp);
+        assert!(sexp.contains("(variable @ list)))"), "list stays in the call: {}", sexp);
+        assert_eq!(errors, 0);
+
+        // `{;` forces a block even though a string and `=>` follow
+        let (sexp, errors) = parse_sexp(r#"my %h = map {; "$_" => 1 } @list;"#);
+        assert!(sexp.contains("(call map ((block"), "{{; forces a block: {}", sexp);
+        assert_eq!(errors, 0);
+
+        // Multi-statement block
+        let (sexp, errors) = parse_sexp("my @r = map { my $y = $_; $y * 2 } @list;");
+        assert!(sexp.contains("(block (my_declaration"), "multi-statement block: {}", sexp);
+        assert_eq!(errors, 0);
+
+        // sort never takes a hash
+        let (sexp, _) = parse_sexp("my @s = sort { 'a', 1 } @list;");
+        assert!(sexp.contains("(call sort ((block"), "sort takes a block: {}", sexp);
+    }
+
+    #[test]
+    fn test_map_anon_hash_argument() {
+        // A string followed by `=>` makes perl guess an anonymous hash: `map EXPR, LIST`
+        let (sexp, errors) = parse_sexp(r#"my @r = map { "x" => $_ }, @list;"#);
+        assert!(sexp.contains("(call map ((hash"), "map takes a hash expression: {}", sexp);
+        assert_eq!(errors, 0);
+
+        // ...which then needs a comma before the list
+        let code = r#"my %h = map { "$_" => 1 } @list;"#;
+        let mut parser = Parser::new(code);
+        let sexp = must(parser.parse()).to_sexp();
+        assert!(sexp.contains("(call map ((hash"), "still parsed as a hash: {}", sexp);
+        assert_eq!(parser.errors().len(), 1);
+        assert!(parser.errors()[0].to_string().contains("anonymous hash"));
+    }
+
+    #[test]
+    fn test_anon_hash_in_term_position() {
+        let (sexp, _) = parse_sexp("my $ref = { a => 1 };");
+        assert!(sexp.contains("(hash ((string \"a\") (number 1)))"), "{}", sexp);
+
+        let (sexp, _) = parse_sexp("my $ref = { 'a', 1, 'b', 2 };");
+        assert!(sexp.contains("(hash ((string"), "{}", sexp);
+
+        let (sexp, _) = parse_sexp("sub f { return { a => 1 } }");
+        assert!(sexp.contains("(return (hash"), "{}", sexp);
+    }
+
+    #[test]
+    fn test_hash_subscript() {
+        let (sexp, _) = parse_sexp("my $v = $h{key};");
+        assert!(sexp.contains("(binary_{} (variable $ h) (identifier key))"), "{}", sexp);
+
+        let (sexp, _) = parse_sexp("my $v = $h->{a}{b};");
+        assert!(
+            sexp.contains("(binary_{} (binary_{} (variable $ h) (identifier a)) (identifier b))"),
+            "{}",
+            sexp
+        );
+
+        // A subscript inside a grep block is still a subscript
+        let (sexp, _) = parse_sexp("my @r = grep { $seen{$_} } @list;");
+        assert!(sexp.contains("(block (binary_{} (variable $ seen)"), "{}", sexp);
+    }
+
+    #[test]
+    fn test_unary_plus_forces_anon_hash() {
+        let (sexp, _) = parse_sexp("my $ref = +{ a => 1 };");
+        assert!(sexp.contains("(unary_+ (hash ((string \"a\") (number 1))))"), "{}", sexp);
+
+        // Would be guessed as a block without the `+`
+        let (sexp, _) = parse_sexp("my $copy = +{ %$orig };");
+        assert!(sexp.contains("(unary_+ (hash ((variable % $orig)"), "{}", sexp);
+        assert!(!sexp.contains("(block"), "{}", sexp);
+
+        let (sexp, _) = parse_sexp("my @r = map { +{ name => $_ } } @list;");
+        assert!(sexp.contains("(call map ((block (unary_+ (hash"), "{}", sexp);
     }
 }
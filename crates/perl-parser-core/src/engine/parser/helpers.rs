@@ -327,6 +327,53 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Whether token text is a plain bareword (no sigil, quote or package separator)
+    fn is_bareword_text(text: &str) -> bool {
+        let mut chars = text.chars();
+        chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+            && chars.all(|c| c.is_alphanumeric() || c == '_')
+    }
+
+    /// Auto-quote a bareword on the left of `=>` into a string literal
+    fn auto_quote_fat_comma_key(node: Node) -> Node {
+        if let NodeKind::Identifier { name } = &node.kind {
+            return Node::new(
+                NodeKind::String { value: name.clone(), interpolated: false },
+                node.location,
+            );
+        }
+        node
+    }
+
+    /// Parse a bareword immediately followed by `=>` as an auto-quoted string
+    ///
+    /// Perl quotes the word even when it is a keyword or the name of a declared
+    /// sub, so `print => 1` and `func => 1` never become calls.
+    fn try_parse_fat_comma_bareword(&mut self) -> ParseResult<Option<Node>> {
+        let is_bareword = self.tokens.peek().is_ok_and(|t| Self::is_bareword_text(&t.text));
+        if !is_bareword || self.tokens.peek_second().map(|t| t.kind) != Ok(TokenKind::FatArrow) {
+            return Ok(None);
+        }
+        let token = self.consume_token()?;
+        Ok(Some(Node::new(
+            NodeKind::String { value: token.text.to_string(), interpolated: false },
+            SourceLocation { start: token.start, end: token.end },
+        )))
+    }
+
+    /// Build the string literal `"-word"` for unary minus applied to a bareword
+    fn negated_bareword(word: &Node, start: usize) -> Node {
+        let name = match &word.kind {
+            NodeKind::Identifier { name } => name.as_str(),
+            NodeKind::String { value, .. } => value.as_str(),
+            _ => "",
+        };
+        Node::new(
+            NodeKind::String { value: format!("-{name}"), interpolated: false },
+            SourceLocation { start, end: word.location.end },
+        )
+    }
+
     /// Record a parse error for later retrieval
     fn record_error(&mut self, error: ParseError) {
         self.errors.push(error);
@@ -403,6 +450,29 @@ impl<'a> Parser<'a> {
         )
     }
 
+    /// Skip a closing delimiter found where a statement should start
+    ///
+    /// An extra `}` or `)` is the usual state of code mid-edit. Reporting it
+    /// once and moving past it keeps the following statements intact.
+    fn skip_unmatched_close(&mut self) -> Option<Node> {
+        let delimiter = match self.peek_kind()? {
+            TokenKind::RightBrace => '}',
+            TokenKind::RightParen => ')',
+            TokenKind::RightBracket => ']',
+            _ => return None,
+        };
+        let token = self.consume_token().ok()?;
+        let error = ParseError::UnmatchedClose { delimiter, location: token.start };
+        let message = error.to_string();
+        self.record_error(error);
+
+        let location = SourceLocation { start: token.start, end: token.end };
+        Some(Node::new(
+            NodeKind::Error { message, expected: vec![], found: Some(token), partial: None },
+            location,
+        ))
+    }
+
     // /// Enhanced error recovery with context-aware suggestions
     // fn recover_from_error_enhanced(&mut self, error: ParseError) -> Node {
     //     // Check resource limits
@@ -163,8 +163,11 @@ impl<'a> Parser<'a> {
             }
         }
 
-        // Advance to first content line (handle newline after statement terminator)
-        self.byte_cursor = after_line_break(self.src_bytes, self.byte_cursor);
+        // Advance to first content line (handle newline after statement terminator).
+        // An earlier statement on the same line may already own the following
+        // bodies (`print <<A; print <<B;`), so resume after them.
+        self.byte_cursor =
+            after_line_break(self.src_bytes, self.byte_cursor).max(self.heredoc_body_end);
 
         // Keep a copy of the declarations so we can match outputs back to inputs
         let pending: Vec<_> = self.pending_heredocs.iter().cloned().collect();
@@ -197,6 +200,7 @@ impl<'a> Parser<'a> {
             }
         }
         self.byte_cursor = out.next_offset;
+        self.heredoc_body_end = out.next_offset;
     }
 
     /// Attach collected heredoc content to its declaration node by matching declaration span
@@ -76,4 +76,83 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_print_scalar_filehandle_with_list() {
+        // print $fh @x: the list is not a second expression after print($fh)
+        let sexp = parse_code("print $fh @x;").map(|ast| ast.to_sexp());
+        assert_eq!(
+            sexp.as_deref(),
+            Some("(source_file (indirect_call print (variable $ fh) ((variable @ x))))")
+        );
+    }
+
+    #[test]
+    fn test_say_block_filehandle() {
+        let sexp = parse_code("say {$fh} $y;").map(|ast| ast.to_sexp());
+        assert_eq!(
+            sexp.as_deref(),
+            Some(
+                "(source_file (indirect_call say (block (expression_statement (variable $ fh))) ((variable $ y))))"
+            )
+        );
+    }
+
+    #[test]
+    fn test_print_bareword_filehandle() {
+        let sexp = parse_code("print STDERR \"x\";").map(|ast| ast.to_sexp());
+        assert_eq!(
+            sexp.as_deref(),
+            Some(
+                "(source_file (indirect_call print (identifier STDERR) ((string_interpolated \"\\\"x\\\"\"))))"
+            )
+        );
+    }
+
+    #[test]
+    fn test_filehandle_print_as_last_statement_in_block() {
+        // The closing brace ends the argument list when the semicolon is omitted
+        let sexp = parse_code("sub f { print {$out} $y }").map(|ast| ast.to_sexp());
+        assert_eq!(
+            sexp.as_deref(),
+            Some(
+                "(source_file (sub f ()(block (indirect_call print (block (expression_statement (variable $ out))) ((variable $ y))))))"
+            )
+        );
+    }
+
+    #[test]
+    fn test_print_qualified_call_and_class_method_are_not_filehandles() {
+        let sexp = parse_code("print A->greet();").map(|ast| ast.to_sexp());
+        assert_eq!(
+            sexp.as_deref(),
+            Some("(source_file (call print ((method_call (identifier A) greet ()))))")
+        );
+
+        let ast = parse_code("print Utils::process_data($_);");
+        let call = ast.as_ref().and_then(|ast| match &ast.kind {
+            NodeKind::Program { statements } => match statements.first().map(|s| &s.kind) {
+                Some(NodeKind::ExpressionStatement { expression }) => Some(&expression.kind),
+                _ => None,
+            },
+            _ => None,
+        });
+        if let Some(NodeKind::FunctionCall { name, args }) = call {
+            assert_eq!(name, "print");
+            assert!(
+                matches!(&args[0].kind, NodeKind::FunctionCall { name, .. } if name == "Utils::process_data"),
+                "Expected a call to Utils::process_data, got {:?}",
+                args[0].kind
+            );
+        } else {
+            unreachable!("Expected print(...) call, got {:?}", call);
+        }
+
+        // A space before the parenthesised list keeps the filehandle form
+        let sexp = parse_code("print FH ($x);").map(|ast| ast.to_sexp());
+        assert_eq!(
+            sexp.as_deref(),
+            Some("(source_file (indirect_call print (identifier FH) ((variable $ x))))")
+        );
+    }
 }
@@ -55,12 +55,13 @@
 //! ```
 
 use crate::{
-    ast::{Node, NodeKind, SourceLocation},
+    ast::{Attribute, Node, NodeKind, SourceLocation},
     error::{ParseError, ParseOutput, ParseResult},
     heredoc_collector::{self, HeredocContent, PendingHeredoc, collect_all},
     quote_parser,
     token_stream::{Token, TokenKind, TokenStream},
 };
+use perl_pragma::{PragmaState, PragmaTracker};
 use std::collections::VecDeque;
 use std::sync::Arc;
 use std::time::Instant;
@@ -90,10 +91,16 @@ pub struct Parser<'a> {
     src_bytes: &'a [u8],
     /// Byte cursor tracking position for heredoc content collection
     byte_cursor: usize,
+    /// End of the last collected heredoc body; later statements on the same line resume here
+    heredoc_body_end: usize,
     /// Start time of parsing for timeout enforcement (specifically heredocs)
     heredoc_start_time: Option<Instant>,
     /// Collection of parse errors encountered during parsing (for error recovery)
     errors: Vec<ParseError>,
+    /// Lexically scoped pragma state, used to tell signatures from prototypes
+    pragma_state: PragmaState,
+    /// Nesting depth of `class` declarations, where `field` and `ADJUST` are keywords
+    class_depth: usize,
     // Enhanced error recovery state
     // pub enhanced_recovery: EnhancedRecovery,
 }
@@ -135,12 +142,44 @@ impl<'a> Parser<'a> {
             pending_heredocs: VecDeque::new(),
             src_bytes: input.as_bytes(),
             byte_cursor: 0,
+            heredoc_body_end: 0,
             heredoc_start_time: None,
             errors: Vec::new(),
+            pragma_state: PragmaState::default(),
+            class_depth: 0,
             // enhanced_recovery: EnhancedRecovery::new(RecoveryConfig::default()),
         }
     }
 
+    /// Start parsing with `state` as the pragmas already in effect.
+    ///
+    /// Use this when parsing a fragment that sits inside a larger file, so
+    /// pragma-dependent syntax such as signatures versus prototypes is read
+    /// the same way as in the full document.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - Pragma state in effect at the start of the input
+    ///
+    /// # Returns
+    ///
+    /// The parser, seeded with `state`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use perl_parser_core::Parser;
+    /// use perl_parser_core::pragma_tracker::PragmaState;
+    ///
+    /// let pragmas = PragmaState { signatures: true, ..PragmaState::default() };
+    /// let mut parser = Parser::new("sub add ($x, $y) { $x + $y }").with_pragma_state(pragmas);
+    /// assert!(parser.parse().is_ok());
+    /// ```
+    pub fn with_pragma_state(mut self, state: PragmaState) -> Self {
+        self.pragma_state = state;
+        self
+    }
+
     /// Create a new parser with custom enhanced recovery configuration.
     ///
     /// This constructor exists for API compatibility while enhanced recovery
@@ -173,8 +212,11 @@ impl<'a> Parser<'a> {
             pending_heredocs: VecDeque::new(),
             src_bytes: input.as_bytes(),
             byte_cursor: 0,
+            heredoc_body_end: 0,
             heredoc_start_time: None,
             errors: Vec::new(),
+            pragma_state: PragmaState::default(),
+            class_depth: 0,
             // enhanced_recovery: EnhancedRecovery::new(config),
         }
     }
@@ -407,11 +449,15 @@ include!("expressions/calls.rs");
 include!("expressions/hashes.rs");
 include!("expressions/quotes.rs");
 
+#[cfg(test)]
+mod class_tests;
 #[cfg(test)]
 mod error_recovery_tests;
 // #[cfg(test)]
 // mod enhanced_recovery_tests;
 #[cfg(test)]
+mod fat_comma_tests;
+#[cfg(test)]
 mod format_comprehensive_tests;
 #[cfg(test)]
 mod format_tests;
@@ -434,6 +480,12 @@ mod regex_delimiter_tests;
 #[cfg(test)]
 mod slash_ambiguity_tests;
 #[cfg(test)]
+mod statement_modifier_tests;
+#[cfg(test)]
+mod sub_attribute_tests;
+#[cfg(test)]
 mod tests;
 #[cfg(test)]
 mod tie_tests;
+#[cfg(test)]
+mod try_catch_tests;
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use crate::engine::parser::Parser;
+    use perl_ast::ast::{Node, NodeKind};
+    use perl_tdd_support::must;
+
+    /// Parse `source` without recovered errors and return the top-level statements
+    fn parse_statements(source: &str) -> Vec<Node> {
+        let mut parser = Parser::new(source);
+        let ast = must(parser.parse());
+        assert!(parser.errors().is_empty(), "unexpected errors: {:?}", parser.errors());
+        match ast.kind {
+            NodeKind::Program { statements } => statements,
+            other => unreachable!("Expected Program, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_each_modifier_keyword_wraps_its_statement() {
+        for keyword in ["if", "unless", "while", "until", "for", "foreach"] {
+            let source = format!("print $_ {keyword} @list;");
+            let statements = parse_statements(&source);
+            assert_eq!(statements.len(), 1, "{source}");
+
+            match &statements[0].kind {
+                NodeKind::StatementModifier { statement, modifier, condition } => {
+                    assert_eq!(modifier, keyword);
+                    assert!(matches!(statement.kind, NodeKind::ExpressionStatement { .. }));
+                    assert!(matches!(condition.kind, NodeKind::Variable { .. }));
+                }
+                other => unreachable!("Expected StatementModifier for {keyword}, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_modifier_on_return_is_not_a_block_statement() {
+        let statements = parse_statements("return unless $ok;\nunless ($ok) { return; }");
+
+        match &statements[0].kind {
+            NodeKind::StatementModifier { statement, modifier, .. } => {
+                assert_eq!(modifier, "unless");
+                assert!(matches!(statement.kind, NodeKind::Return { .. }));
+            }
+            other => unreachable!("Expected StatementModifier, got {:?}", other),
+        }
+        assert!(matches!(statements[1].kind, NodeKind::If { .. }));
+    }
+
+    #[test]
+    fn test_chained_modifiers_report_an_error() {
+        let mut parser = Parser::new("print 1 if $a unless $b;");
+        let ast = must(parser.parse());
+
+        assert_eq!(parser.errors().len(), 1);
+        assert!(parser.errors()[0].to_string().contains("cannot be chained"));
+        // The outer modifier still wraps the inner one for recovery
+        assert!(ast.to_sexp().contains("(statement_modifier_unless (statement_modifier_if"));
+    }
+
+    #[test]
+    fn test_given_when_default_structure() {
+        let statements =
+            parse_statements("given ($x) {\n    when (1) { one(); }\n    default { other(); }\n}");
+
+        match &statements[0].kind {
+            NodeKind::Given { expr, body } => {
+                assert!(matches!(expr.kind, NodeKind::Variable { .. }));
+                let NodeKind::Block { statements: arms } = &body.kind else {
+                    unreachable!("Expected given block, got {:?}", body.kind)
+                };
+                assert!(matches!(arms[0].kind, NodeKind::When { .. }));
+                assert!(matches!(arms[1].kind, NodeKind::Default { .. }));
+            }
+            other => unreachable!("Expected Given, got {:?}", other),
+        }
+    }
+}
@@ -15,6 +15,12 @@ impl<'a> Parser<'a> {
                 break; // Stop parsing but preserve earlier nodes
             }
 
+            // A closing delimiter cannot start a statement; skip it and carry on
+            if let Some(error_node) = self.skip_unmatched_close() {
+                statements.push(error_node);
+                continue;
+            }
+
             // Parse statement with error recovery
             let stmt_result = self.parse_statement();
             match stmt_result {
@@ -81,8 +87,14 @@ impl<'a> Parser<'a> {
             }
 
             // Variable declarations
-            TokenKind::My | TokenKind::Our | TokenKind::State => self.parse_variable_declaration(),
-            TokenKind::Local => self.parse_local_statement(),
+            TokenKind::My | TokenKind::Our | TokenKind::State => {
+                let declaration = self.parse_variable_declaration()?;
+                self.parse_word_or_expr(declaration)
+            }
+            TokenKind::Local => {
+                let declaration = self.parse_local_statement()?;
+                self.parse_word_or_expr(declaration)
+            }
 
             // Control flow
             TokenKind::If => self.parse_if_statement(),
@@ -93,7 +105,7 @@ impl<'a> Parser<'a> {
             TokenKind::Foreach => self.parse_foreach_statement(),
             TokenKind::Given => self.parse_given_statement(),
             TokenKind::Default => self.parse_default_statement(),
-            TokenKind::Try => self.parse_try(),
+            TokenKind::Try if self.is_try_start() => self.parse_try(),
 
             // Loop control
             TokenKind::Next | TokenKind::Last | TokenKind::Redo => self.parse_loop_control(),
@@ -146,6 +158,15 @@ impl<'a> Parser<'a> {
             // Block
             TokenKind::LeftBrace => self.parse_block(),
 
+            // Native class members: `field $x ...;` and `ADJUST { ... }`
+            TokenKind::Identifier if self.class_depth > 0 && self.is_class_member_start() => {
+                if self.tokens.peek()?.text.as_ref() == "field" {
+                    self.parse_field()
+                } else {
+                    self.parse_phase_block()
+                }
+            }
+
             // Expression-ish statement
             _ => {
                 // Check if this might be a labeled statement
@@ -174,6 +195,20 @@ impl<'a> Parser<'a> {
         // Check for statement modifiers on ANY statement
         if matches!(self.peek_kind(), Some(k) if Self::is_stmt_modifier_kind(k)) {
             stmt = self.parse_statement_modifier(stmt)?;
+
+            // Perl allows one modifier per statement; report a chain but keep parsing it
+            while self.is_statement_modifier_keyword() {
+                let token = self.tokens.peek()?;
+                let error = ParseError::syntax(
+                    format!(
+                        "Statement modifiers cannot be chained: '{}' follows another modifier",
+                        token.text
+                    ),
+                    token.start,
+                );
+                self.record_error(error);
+                stmt = self.parse_statement_modifier(stmt)?;
+            }
         }
 
         // Check for optional semicolon
@@ -300,8 +335,9 @@ impl<'a> Parser<'a> {
                                 && self.peek_kind() == Some(TokenKind::LeftBrace)
                             {
                                 // Special handling for map/grep/sort with block first argument
-                                args.push(self.parse_builtin_block()?);
-                                parsed_block_arg = true;
+                                let (arg, is_block) = self.parse_builtin_brace_arg(&func_name)?;
+                                args.push(arg);
+                                parsed_block_arg = is_block;
                             } else {
                                 // For builtins, use parse_assignment to avoid consuming comma operators
                                 args.push(self.parse_assignment()?);
@@ -434,6 +470,24 @@ impl<'a> Parser<'a> {
         let start = statement.location.start;
         let end = condition.location.end;
 
+        // `do BLOCK while COND` / `do BLOCK until COND` is a post-condition loop,
+        // not an ordinary statement modifier
+        let statement = match modifier_token.kind {
+            TokenKind::While | TokenKind::Until => match Self::into_do_block(statement) {
+                Ok(block) => {
+                    let condition = Box::new(condition);
+                    let kind = if modifier_token.kind == TokenKind::While {
+                        NodeKind::DoWhile { block, condition }
+                    } else {
+                        NodeKind::DoUntil { block, condition }
+                    };
+                    return Ok(Node::new(kind, SourceLocation { start, end }));
+                }
+                Err(statement) => statement,
+            },
+            _ => statement,
+        };
+
         Ok(Node::new(
             NodeKind::StatementModifier {
                 statement: Box::new(statement),
@@ -444,9 +498,28 @@ impl<'a> Parser<'a> {
         ))
     }
 
+    /// Extract the block of a `do { ... }` statement, or give the statement back
+    fn into_do_block(statement: Node) -> Result<Box<Node>, Node> {
+        let Node { kind, location } = statement;
+        match kind {
+            NodeKind::Do { block } if matches!(block.kind, NodeKind::Block { .. }) => Ok(block),
+            NodeKind::ExpressionStatement { expression } => {
+                Self::into_do_block(*expression).map_err(|expression| {
+                    Node::new(
+                        NodeKind::ExpressionStatement { expression: Box::new(expression) },
+                        location,
+                    )
+                })
+            }
+            kind => Err(Node::new(kind, location)),
+        }
+    }
+
     /// Parse a block statement
     fn parse_block(&mut self) -> ParseResult<Node> {
-        self.with_recursion_guard(|s| {
+        // Pragmas are lexically scoped: restore the outer state after the block
+        let outer_pragmas = self.pragma_state.clone();
+        let block = self.with_recursion_guard(|s| {
             let start = s.current_position();
 
             s.expect(TokenKind::LeftBrace)?;
@@ -454,6 +527,12 @@ impl<'a> Parser<'a> {
             let mut statements = Vec::new();
 
             while s.peek_kind() != Some(TokenKind::RightBrace) && !s.tokens.is_eof() {
+                // A stray `)` or `]` cannot start a statement; skip it and carry on
+                if let Some(error_node) = s.skip_unmatched_close() {
+                    statements.push(error_node);
+                    continue;
+                }
+
                 // Parse statement with error recovery (AC3: Panic Mode Recovery inside blocks)
                 let stmt_result = s.parse_statement();
                 match stmt_result {
@@ -510,7 +589,9 @@ impl<'a> Parser<'a> {
             let end = s.previous_position();
 
             Ok(Node::new(NodeKind::Block { statements }, SourceLocation { start, end }))
-        })
+        });
+        self.pragma_state = outer_pragmas;
+        block
     }
 
     /// Check if we're at the start of a labeled statement (LABEL: ...)
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use crate::engine::parser::Parser;
+    use perl_ast::ast::{Attribute, Node, NodeKind};
+    use perl_tdd_support::must;
+
+    /// Parse `source` without recovered errors and return the top-level statements
+    fn parse_statements(source: &str) -> Vec<Node> {
+        let mut parser = Parser::new(source);
+        let ast = must(parser.parse());
+        assert!(parser.errors().is_empty(), "unexpected errors: {:?}", parser.errors());
+        match ast.kind {
+            NodeKind::Program { statements } => statements,
+            other => unreachable!("Expected Program, got {:?}", other),
+        }
+    }
+
+    /// Return (prototype content, has signature, attributes) of a sub declaration
+    fn sub_parts(node: &Node) -> (Option<&str>, bool, &[Attribute]) {
+        match &node.kind {
+            NodeKind::Subroutine { prototype, signature, attributes, .. } => {
+                let prototype = prototype.as_deref().map(|p| match &p.kind {
+                    NodeKind::Prototype { content } => content.as_str(),
+                    other => unreachable!("Expected Prototype, got {:?}", other),
+                });
+                (prototype, signature.is_some(), attributes)
+            }
+            other => unreachable!("Expected Subroutine, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_prototype_only() {
+        let statements = parse_statements("sub max_of ($$;@) { }");
+        let (prototype, has_signature, attributes) = sub_parts(&statements[0]);
+
+        assert_eq!(prototype, Some("$$;@"));
+        assert!(!has_signature);
+        assert!(attributes.is_empty());
+    }
+
+    #[test]
+    fn test_attributes_only() {
+        let source = "sub qux :method :lvalue :Foo(bar, 'baz') { }";
+        let statements = parse_statements(source);
+        let (prototype, has_signature, attributes) = sub_parts(&statements[0]);
+
+        assert_eq!(prototype, None);
+        assert!(!has_signature);
+
+        let parts: Vec<(&str, Option<&str>)> =
+            attributes.iter().map(|a| (a.name.as_str(), a.args.as_deref())).collect();
+        assert_eq!(parts, vec![("method", None), ("lvalue", None), ("Foo", Some("bar, 'baz'"))]);
+
+        let spans: Vec<&str> =
+            attributes.iter().map(|a| &source[a.location.start..a.location.end]).collect();
+        assert_eq!(spans, vec!["method", "lvalue", "Foo(bar, 'baz')"]);
+        assert_eq!(attributes[2].to_string(), "Foo(bar, 'baz')");
+    }
+
+    #[test]
+    fn test_attribute_with_signature() {
+        let statements =
+            parse_statements("use feature 'signatures';\nsub f :lvalue ($x, @rest) { $x }");
+        let (prototype, has_signature, attributes) = sub_parts(&statements[1]);
+
+        assert_eq!(prototype, None);
+        assert!(has_signature);
+        assert_eq!(attributes.len(), 1);
+        assert_eq!(attributes[0].name, "lvalue");
+        assert_eq!(attributes[0].args, None);
+    }
+
+    #[test]
+    fn test_prototype_attribute_with_signature() {
+        let statements =
+            parse_statements("use feature 'signatures';\nsub g :prototype($) ($x) { $x }");
+        let (prototype, has_signature, attributes) = sub_parts(&statements[1]);
+
+        // The `:prototype(...)` form stays an attribute; the parens after it are the signature
+        assert_eq!(prototype, None);
+        assert!(has_signature);
+        assert_eq!(attributes.len(), 1);
+        assert_eq!(attributes[0].name, "prototype");
+        assert_eq!(attributes[0].args.as_deref(), Some("$"));
+    }
+
+    #[test]
+    fn test_attribute_with_prototype() {
+        let statements = parse_statements("sub h :lvalue ($$) { }");
+        let (prototype, has_signature, attributes) = sub_parts(&statements[0]);
+
+        assert_eq!(prototype, Some("$$"));
+        assert!(!has_signature);
+        assert_eq!(attributes.len(), 1);
+        assert_eq!(attributes[0].name, "lvalue");
+    }
+}
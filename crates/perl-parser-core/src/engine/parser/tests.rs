@@ -150,6 +150,17 @@ fn test_qualified_function_call() {
     }
 }
 
+#[test]
+fn test_repetition_operator() {
+    let ast = must(Parser::new(r#"my $s = "ab" x 3; my @l = (0) x -2; my %h = (x => 1);"#).parse());
+    let sexp = ast.to_sexp();
+
+    assert!(sexp.contains("(binary_x (string_interpolated"), "string repetition: {sexp}");
+    assert!(sexp.contains("(binary_x (number 0) (unary_- (number 2)))"), "list repetition: {sexp}");
+    // `x` before a fat comma is a bareword hash key, not the operator
+    assert!(sexp.contains("(hash ((string \"x\") (number 1)))"), "hash key: {sexp}");
+}
+
 #[test]
 fn test_issue_461_variable_length_lookbehind() {
     // Variable-length lookbehind
@@ -417,3 +428,49 @@ fn test_catastrophic_backtracking_detection() {
         assert!(found, "Should have found specific error in: {:?}", errors);
     }
 }
+
+#[test]
+fn test_extended_regex_validation_ignores_comments() {
+    // Under /x the comment is not pattern text, so `(a+)+` in it is harmless
+    let code = "my $ok = $s =~ m{\n    ^ \\w+   # not (a+)+ here\n    \\z\n}x;";
+    let mut parser = Parser::new(code);
+    must(parser.parse());
+    assert!(parser.errors().is_empty(), "Unexpected errors: {:?}", parser.errors());
+
+    let code = "$s =~ s/ a+ # avoid (b+)+\n /x/x;";
+    let mut parser = Parser::new(code);
+    must(parser.parse());
+    assert!(parser.errors().is_empty(), "Unexpected errors: {:?}", parser.errors());
+
+    // Whitespace between a group and its quantifier is insignificant under /x
+    let mut parser = Parser::new("qr/(a+) +/x;");
+    let result = parser.parse();
+    let found = match result {
+        Err(e) => e.to_string().contains("catastrophic backtracking"),
+        Ok(_) => {
+            parser.errors().iter().any(|e| e.to_string().contains("catastrophic backtracking"))
+        }
+    };
+    assert!(found, "Expected nested quantifier error, got: {:?}", parser.errors());
+}
+
+#[test]
+fn test_defined_binds_tighter_than_ternary() {
+    // `defined` is a named unary operator: `defined $x ? $x : 0` tests only `$x`
+    let mut parser = Parser::new("my $v = defined $x ? $x : 0;");
+    let ast = must(parser.parse());
+    assert_eq!(
+        ast.to_sexp(),
+        "(source_file (my_declaration (variable $ v)\
+         (ternary (call defined ((variable $ x))) (variable $ x) (number 0))))"
+    );
+
+    // ...but looser than arithmetic
+    let mut parser = Parser::new("my $ok = defined $x + 1 == 2;");
+    let ast = must(parser.parse());
+    assert!(
+        ast.to_sexp().contains("(binary_== (call defined ((binary_+ (variable $ x) (number 1))))"),
+        "got {}",
+        ast.to_sexp()
+    );
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use crate::engine::parser::Parser;
+    use perl_ast::ast::{Node, NodeKind};
+    use perl_tdd_support::must;
+
+    /// Parse `source` without recovered errors and return the top-level statements
+    fn parse_statements(source: &str) -> Vec<Node> {
+        let mut parser = Parser::new(source);
+        let ast = must(parser.parse());
+        assert!(parser.errors().is_empty(), "unexpected errors: {:?}", parser.errors());
+        match ast.kind {
+            NodeKind::Program { statements } => statements,
+            other => unreachable!("Expected Program, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_native_try_binds_catch_variable_and_finally() {
+        let source = "use feature 'try';\ntry { die 1 } catch ($e) { warn $e; } finally { 1; }";
+        let statements = parse_statements(source);
+
+        match &statements[1].kind {
+            NodeKind::Try { catch_blocks, finally_block, .. } => {
+                assert_eq!(catch_blocks.len(), 1);
+                let var = catch_blocks[0].0.as_deref();
+                match var.map(|v| (&v.kind, v.location)) {
+                    Some((NodeKind::Variable { sigil, name }, location)) => {
+                        assert_eq!((sigil.as_str(), name.as_str()), ("$", "e"));
+                        assert_eq!(&source[location.start..location.end], "$e");
+                    }
+                    other => unreachable!("Expected catch variable, got {:?}", other),
+                }
+                assert!(finally_block.is_some());
+            }
+            other => unreachable!("Expected Try, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_try_tiny_catch_without_variable() {
+        let statements = parse_statements("use Try::Tiny;\ntry { die 1 } catch { warn $_; };");
+
+        match &statements[1].kind {
+            NodeKind::Try { catch_blocks, finally_block, .. } => {
+                assert_eq!(catch_blocks.len(), 1);
+                assert!(catch_blocks[0].0.is_none());
+                assert!(finally_block.is_none());
+            }
+            other => unreachable!("Expected Try, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_try_without_pragma_or_block_is_a_bareword() {
+        let statements = parse_statements("try(1);\nmy %h = (try => 1);");
+
+        match &statements[0].kind {
+            NodeKind::ExpressionStatement { expression } => assert!(
+                matches!(&expression.kind, NodeKind::FunctionCall { name, .. } if name == "try")
+            ),
+            other => unreachable!("Expected ExpressionStatement, got {:?}", other),
+        }
+        assert!(statements[1].to_sexp().contains("(string \"try\")"));
+    }
+
+    #[test]
+    fn test_try_pragma_makes_try_a_keyword() {
+        let mut parser = Parser::new("use feature 'try';\ntry(1);");
+        let _ = parser.parse();
+        assert!(!parser.errors().is_empty());
+    }
+}
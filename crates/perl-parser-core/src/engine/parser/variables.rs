@@ -57,7 +57,8 @@ impl<'a> Parser<'a> {
 
             let initializer = if self.peek_kind() == Some(TokenKind::Assign) {
                 self.tokens.next()?; // consume =
-                Some(Box::new(self.parse_expression()?))
+                // `my $x = f() or die` is `(my $x = f()) or die`
+                Some(Box::new(self.parse_comma_list()?))
             } else {
                 None
             };
@@ -97,7 +98,8 @@ impl<'a> Parser<'a> {
 
             let initializer = if self.peek_kind() == Some(TokenKind::Assign) {
                 self.tokens.next()?; // consume =
-                Some(Box::new(self.parse_expression()?))
+                // `my $x = f() or die` is `(my $x = f()) or die`
+                Some(Box::new(self.parse_comma_list()?))
             } else {
                 None
             };
@@ -125,11 +127,11 @@ impl<'a> Parser<'a> {
         let declarator = declarator_token.text.to_string();
 
         // Parse the lvalue expression that's being localized
-        let variable = Box::new(self.parse_expression()?);
+        let variable = Box::new(self.parse_comma_list()?);
 
         let initializer = if self.peek_kind() == Some(TokenKind::Assign) {
             self.tokens.next()?; // consume =
-            Some(Box::new(self.parse_expression()?))
+            Some(Box::new(self.parse_comma_list()?))
         } else {
             None
         };
@@ -486,14 +488,29 @@ impl<'a> Parser<'a> {
             None
         };
 
-        // Parse the variable
-        let variable = self.parse_variable()?;
+        // Parse the variable, or an unnamed placeholder (`$`, `@`, `%`)
+        let variable = if self.at_signature_placeholder()? {
+            let token = self.tokens.next()?;
+            Node::new(
+                NodeKind::Variable { sigil: token.text.to_string(), name: String::new() },
+                SourceLocation { start: token.start, end: token.end },
+            )
+        } else {
+            self.parse_variable()?
+        };
 
-        // Check for default value (= expression)
+        // Check for default value (= expression); a bare `$=` is optional with no default
         let default_value = if self.peek_kind() == Some(TokenKind::Assign) {
-            self.tokens.next()?; // consume =
-            // Parse a primary expression for default value to avoid parsing too far
-            Some(Box::new(self.parse_primary()?))
+            let assign = self.tokens.next()?; // consume =
+            if matches!(self.peek_kind(), Some(TokenKind::Comma | TokenKind::RightParen)) {
+                Some(Box::new(Node::new(
+                    NodeKind::Undef,
+                    SourceLocation { start: assign.start, end: assign.end },
+                )))
+            } else {
+                // Stop before the comma that separates parameters
+                Some(Box::new(self.parse_assignment()?))
+            }
         } else {
             None
         };
@@ -521,6 +538,21 @@ impl<'a> Parser<'a> {
         Ok(Node::new(param_kind, SourceLocation { start, end }))
     }
 
+    /// Check if the next token is an unnamed signature placeholder such as `$` in `($, $y)`
+    fn at_signature_placeholder(&mut self) -> ParseResult<bool> {
+        let token = self.tokens.peek()?;
+        if !matches!(
+            token.kind,
+            TokenKind::ScalarSigil | TokenKind::ArraySigil | TokenKind::HashSigil
+        ) {
+            return Ok(false);
+        }
+        Ok(matches!(
+            self.tokens.peek_second().map(|t| t.kind),
+            Ok(TokenKind::Comma | TokenKind::RightParen | TokenKind::Assign)
+        ))
+    }
+
     /// Check if the parenthesized content after sub name is a prototype (not a signature)
     #[allow(dead_code)]
     fn is_prototype(&mut self) -> bool {
@@ -560,22 +592,30 @@ impl<'a> Parser<'a> {
                     | TokenKind::BitwiseAnd
                     | TokenKind::SubSigil
                     | TokenKind::GlobSigil => true,
-                    // Sigils: peek past to distinguish prototype ($;@%) from signature ($x, @rest)
+                    // Sigils: peek past to distinguish prototype ($;@%) from signature ($x, @rest).
+                    // A lone sigil followed by `,` or `=` is an unnamed placeholder (prototypes
+                    // never contain either), as is `($)` once signatures are enabled.
                     TokenKind::ScalarSigil
                     | TokenKind::ArraySigil
                     | TokenKind::HashSigil => {
                         match self.tokens.peek_third() {
-                            Ok(third) => !matches!(third.kind, TokenKind::Identifier),
+                            Ok(third) => match third.kind {
+                                TokenKind::Identifier | TokenKind::Comma | TokenKind::Assign => {
+                                    false
+                                }
+                                TokenKind::RightParen => !self.pragma_state.signatures,
+                                _ => true,
+                            },
                             Err(_) => true, // default to prototype on error
                         }
                     }
-                    // Empty prototype
-                    TokenKind::RightParen => true,
+                    // Empty prototype, or an empty signature once signatures are enabled
+                    TokenKind::RightParen => !self.pragma_state.signatures,
                     // Colon indicates named parameter (:$foo), so it's a signature
                     TokenKind::Colon => false,
                     // Identifiers usually mean signature, but could be a special case
                     TokenKind::Identifier => {
-                        // Check if it's a sigil-only identifier like "$" or "@"
+                        // Check if it's a sigil-only identifier like "$$" or "@"
                         // or the special underscore prototype
                         &*token.text == "_"
                             || token.text.chars().all(|c| matches!(c, '$' | '@' | '%' | '*' | '&'))
@@ -745,4 +785,128 @@ mod prototype_heuristic_tests {
             assert!(prototype.is_some(), "sub foo(&) should have a prototype");
         }
     }
+
+    /// Helper: signature parameters of the first subroutine in `code`.
+    fn signature_params(code: &str) -> Option<Vec<Node>> {
+        let mut parser = Parser::new(code);
+        let ast = parser.parse().ok()?;
+        let mut params = None;
+        let mut stack = vec![&ast];
+        while let Some(node) = stack.pop() {
+            if let NodeKind::Subroutine { signature: Some(sig), .. } = &node.kind
+                && let NodeKind::Signature { parameters } = &sig.kind
+            {
+                params = Some(parameters.clone());
+                break;
+            }
+            node.for_each_child(|child| stack.push(child));
+        }
+        params
+    }
+
+    /// Helper: `(sigil, name)` of a parameter's variable.
+    fn param_variable(param: &Node) -> Option<(&str, &str)> {
+        let variable = match &param.kind {
+            NodeKind::MandatoryParameter { variable }
+            | NodeKind::OptionalParameter { variable, .. }
+            | NodeKind::SlurpyParameter { variable }
+            | NodeKind::NamedParameter { variable } => variable,
+            _ => return None,
+        };
+        match &variable.kind {
+            NodeKind::Variable { sigil, name } => Some((sigil, name)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn signature_with_default_and_slurpy() {
+        let params = signature_params("sub foo ($x, $y = 5, @rest) {}");
+        assert!(params.is_some(), "sub foo ($x, $y = 5, @rest) should have a signature");
+        let Some(params) = params else {
+            return;
+        };
+        assert_eq!(params.len(), 3);
+
+        assert!(matches!(params[0].kind, NodeKind::MandatoryParameter { .. }));
+        assert_eq!(param_variable(&params[0]), Some(("$", "x")));
+
+        assert!(matches!(
+            &params[1].kind,
+            NodeKind::OptionalParameter { default_value, .. }
+                if matches!(&default_value.kind, NodeKind::Number { value } if value == "5")
+        ));
+        assert_eq!(param_variable(&params[1]), Some(("$", "y")));
+
+        assert!(matches!(params[2].kind, NodeKind::SlurpyParameter { .. }));
+        assert_eq!(param_variable(&params[2]), Some(("@", "rest")));
+    }
+
+    #[test]
+    fn signature_default_is_a_full_expression() {
+        let params = signature_params("sub foo ($x, $y = $x + 1, %opts) {}");
+        assert!(params.is_some(), "default expressions should not end the signature");
+        let Some(params) = params else {
+            return;
+        };
+        assert_eq!(params.len(), 3);
+        assert!(matches!(
+            &params[1].kind,
+            NodeKind::OptionalParameter { default_value, .. }
+                if matches!(&default_value.kind, NodeKind::Binary { op, .. } if op == "+")
+        ));
+        assert_eq!(param_variable(&params[2]), Some(("%", "opts")));
+    }
+
+    #[test]
+    fn signature_with_unnamed_placeholders() {
+        let params = signature_params("sub foo ($, $y, $=, @) {}");
+        assert!(params.is_some(), "sub foo ($, $y, $=, @) should have a signature");
+        let Some(params) = params else {
+            return;
+        };
+        assert_eq!(params.len(), 4);
+        assert_eq!(param_variable(&params[0]), Some(("$", "")));
+        assert!(matches!(params[0].kind, NodeKind::MandatoryParameter { .. }));
+        assert!(matches!(params[2].kind, NodeKind::OptionalParameter { .. }));
+        assert_eq!(param_variable(&params[3]), Some(("@", "")));
+        assert!(matches!(params[3].kind, NodeKind::SlurpyParameter { .. }));
+    }
+
+    #[test]
+    fn prototype_with_multiple_sigils() {
+        let node = parse_sub("sub foo ($$;@) {}");
+        assert!(node.is_some(), "expected parsed subroutine for `sub foo ($$;@) {{}}`");
+        let Some(node) = node else {
+            return;
+        };
+
+        if let NodeKind::Subroutine { prototype, signature, .. } = &node.kind {
+            assert!(signature.is_none(), "sub foo ($$;@) should not have a signature");
+            assert!(matches!(
+                prototype.as_deref().map(|p| &p.kind),
+                Some(NodeKind::Prototype { content }) if content == "$$;@"
+            ));
+        }
+    }
+
+    #[test]
+    fn signatures_feature_turns_lone_sigil_into_placeholder() {
+        // Without the feature, `($)` is a prototype (see prototype_single_sigil)
+        let params = signature_params("use v5.36;\nsub foo ($) {}");
+        assert!(params.is_some(), "`($)` under `use v5.36` should be a signature");
+        assert_eq!(params.map(|p| p.len()), Some(1));
+
+        let params = signature_params("use feature 'signatures';\nsub foo () {}");
+        assert_eq!(params.map(|p| p.len()), Some(0), "`()` should be an empty signature");
+
+        // Real prototypes stay prototypes even with signatures enabled
+        assert!(signature_params("use v5.36;\nsub foo ($$;@) {}").is_none());
+    }
+
+    #[test]
+    fn signatures_feature_is_lexically_scoped() {
+        let params = signature_params("{ use feature 'signatures'; }\nsub foo ($) {}");
+        assert!(params.is_none(), "the feature should not leak out of its block");
+    }
 }
@@ -157,6 +157,11 @@ impl ParserContext {
         self.tokens.get(self.current + offset)
     }
 
+    /// Get the most recently consumed token
+    pub fn previous_token(&self) -> Option<&TokenWithPosition> {
+        self.current.checked_sub(1).and_then(|index| self.tokens.get(index))
+    }
+
     /// Advance to next token
     pub fn advance(&mut self) -> Option<&TokenWithPosition> {
         if self.current < self.tokens.len() {
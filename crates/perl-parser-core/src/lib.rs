@@ -86,7 +86,9 @@ pub mod line_index {
 pub use position::{LineEnding, PositionMapper};
 
 pub use ast::{Node, NodeKind, SourceLocation};
-pub use error::{BudgetTracker, ParseBudget, ParseError, ParseOutput, ParseResult};
+pub use error::{
+    BudgetTracker, ParseBudget, ParseDiagnostic, ParseError, ParseOutput, ParseResult,
+};
 
 pub use builtins::builtin_signatures;
 pub use builtins::builtin_signatures_phf;
@@ -3,7 +3,7 @@
 //! Tests for panic mode error recovery that allows the parser to continue
 //! parsing after encountering syntax errors by synchronizing to known points.
 
-use perl_parser_core::{NodeKind, ParseResult, Parser};
+use perl_parser_core::{NodeKind, ParseError, ParseResult, Parser};
 
 // AC1: Parser implements synchronization point detection for Perl syntax
 #[test]
@@ -395,3 +395,46 @@ fn parser_recovery_preserves_good_code() -> ParseResult<()> {
     }
     Ok(())
 }
+
+// Stray closing delimiters are skipped with a single error
+#[test]
+fn parser_unmatched_close_brace_keeps_preceding_sub() -> ParseResult<()> {
+    let code = "sub f { } }\nmy $y = 1;";
+    let mut parser = Parser::new(code);
+    let ast = parser.parse()?;
+
+    let errors = parser.errors();
+    assert_eq!(errors.len(), 1, "Expected one error, got {errors:?}");
+    assert!(matches!(errors[0], ParseError::UnmatchedClose { delimiter: '}', location: 10 }));
+
+    let NodeKind::Program { statements } = &ast.kind else {
+        return Err(ParseError::syntax("expected program", 0));
+    };
+    assert_eq!(statements.len(), 3, "sub, stray brace, declaration: {}", ast.to_sexp());
+    assert!(matches!(
+        &statements[0].kind,
+        NodeKind::Subroutine { name: Some(name), body: Some(_), .. } if name == "f"
+    ));
+    assert_eq!((statements[0].location.start, statements[0].location.end), (0, 9));
+    assert!(matches!(statements[1].kind, NodeKind::Error { .. }));
+    assert_eq!((statements[1].location.start, statements[1].location.end), (10, 11));
+    assert!(matches!(statements[2].kind, NodeKind::VariableDeclaration { .. }));
+    Ok(())
+}
+
+#[test]
+fn parser_unmatched_close_paren_inside_block_keeps_later_statements() -> ParseResult<()> {
+    let code = "sub g { 1; ) 2; }";
+    let mut parser = Parser::new(code);
+    let ast = parser.parse()?;
+
+    let errors = parser.errors();
+    assert_eq!(errors.len(), 1, "Expected one error, got {errors:?}");
+    assert!(matches!(errors[0], ParseError::UnmatchedClose { delimiter: ')', .. }));
+    assert_eq!(
+        ast.to_sexp(),
+        "(source_file (sub g ()(block (expression_statement (number 1)) \
+         (ERROR \"Unmatched closing delimiter: )\") (expression_statement (number 2)))))"
+    );
+    Ok(())
+}
@@ -46,3 +46,51 @@ fn test_no_strict_disables() {
     assert!(!state.strict_subs);
     assert!(state.strict_refs);
 }
+
+#[test]
+fn test_signatures_feature_tracking() {
+    let cases = [
+        ("use feature 'signatures';", true),
+        ("use feature qw(say signatures);", true),
+        ("use experimental 'signatures';", true),
+        ("use feature ':5.36';", true),
+        ("use feature ':5.10';", false),
+        ("use v5.36;", true),
+        ("use 5.036;", true),
+        ("use v5.10;", false),
+        ("use feature 'signatures';\nno feature 'signatures';", false),
+        ("use strict;", false),
+    ];
+
+    for (source, expected) in cases {
+        let mut parser = Parser::new(source);
+        let ast = must(parser.parse());
+
+        let pragma_map = PragmaTracker::build(&ast);
+        let state = PragmaTracker::state_for_offset(&pragma_map, source.len());
+        assert_eq!(state.signatures, expected, "signatures state after `{source}`");
+    }
+}
+
+#[test]
+fn test_use_version_implies_strict_and_warnings() {
+    let cases = [
+        ("use v5.36;", true, true),
+        ("use 5.036;", true, true),
+        ("use v5.12;", true, false),
+        ("use 5.012;", true, false),
+        ("use v5.10;", false, false),
+        ("use v5.36;\nno warnings;", true, false),
+    ];
+
+    for (source, strict, warnings) in cases {
+        let mut parser = Parser::new(source);
+        let ast = must(parser.parse());
+
+        let pragma_map = PragmaTracker::build(&ast);
+        let state = PragmaTracker::state_for_offset(&pragma_map, source.len());
+        assert_eq!(state.strict_vars, strict, "strict vars after `{source}`");
+        assert_eq!(state.strict_refs, strict, "strict refs after `{source}`");
+        assert_eq!(state.warnings, warnings, "warnings after `{source}`");
+    }
+}
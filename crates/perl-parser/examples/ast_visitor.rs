@@ -157,7 +157,7 @@ impl AstVisitor for VariableUsageCollector {
                     self.visit_node(init);
                 }
             }
-            NodeKind::Subroutine { body, .. } => {
+            NodeKind::Subroutine { body: Some(body), .. } => {
                 self.visit_node(body);
             }
             _ => {}
@@ -97,7 +97,7 @@ fn count_nodes(node: &perl_parser::Node, stats: &mut AstStats) {
                 count_nodes(arg, stats);
             }
         }
-        NodeKind::Subroutine { body, .. } => {
+        NodeKind::Subroutine { body: Some(body), .. } => {
             stats.subroutines += 1;
             count_nodes(body, stats);
         }
@@ -181,7 +181,7 @@ fn walk_variables(node: &perl_parser::Node, depth: usize) {
                 walk_variables(stmt, depth);
             }
         }
-        NodeKind::Subroutine { body, .. } => {
+        NodeKind::Subroutine { body: Some(body), .. } => {
             walk_variables(body, depth + 1);
         }
         NodeKind::If { then_branch, elsif_branches, else_branch, .. } => {
@@ -120,7 +120,7 @@ fn count_nodes(node: &perl_parser::Node, stats: &mut AstStats) {
                 count_nodes(arg, stats);
             }
         }
-        NodeKind::Subroutine { body, .. } => {
+        NodeKind::Subroutine { body: Some(body), .. } => {
             stats.subroutines += 1;
             count_nodes(body, stats);
         }
@@ -340,9 +340,19 @@ fn print_error(error: &ParseError, source: &str) {
         ParseError::InvalidString => {
             writeln!(stderr, "Parse error: Invalid string literal").ok();
         }
-        ParseError::UnclosedDelimiter { delimiter } => {
+        ParseError::UnclosedDelimiter { delimiter, .. } => {
             writeln!(stderr, "Parse error: Unclosed delimiter: {}", delimiter).ok();
         }
+        ParseError::UnmatchedClose { delimiter, location } => {
+            let (line, col) = position_to_line_col(source, *location);
+            writeln!(
+                stderr,
+                "Parse error: Unmatched closing delimiter: {} at line {}, column {}",
+                delimiter, line, col
+            )
+            .ok();
+            print_error_context(source, *location, &mut stderr);
+        }
         ParseError::InvalidRegex { message } => {
             writeln!(stderr, "Parse error: Invalid regex: {}", message).ok();
         }
@@ -580,7 +580,7 @@ pub use test_generator::{
     TestCase, TestFramework, TestGenerator, TestGeneratorOptions, TestResults, TestRunner,
 };
 pub use type_inference::{
-    PerlType, ScalarType, TypeBasedCompletion, TypeConstraint, TypeEnvironment,
+    ExpressionContext, PerlType, ScalarType, TypeBasedCompletion, TypeConstraint, TypeEnvironment,
     TypeInferenceEngine, TypeLocation,
 };
 
@@ -37,3 +37,68 @@ fn detects_dead_code() -> TestResult {
     ));
     Ok(())
 }
+
+fn unused_subroutines(index: WorkspaceIndex) -> Vec<String> {
+    let analysis = DeadCodeDetector::new(index).analyze_workspace();
+    let mut names: Vec<String> = analysis
+        .dead_code
+        .into_iter()
+        .filter(|d| d.code_type == DeadCodeType::UnusedSubroutine)
+        .filter_map(|d| d.name)
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn flags_private_sub_that_is_never_called() -> TestResult {
+    let index = WorkspaceIndex::new();
+    index.index_file_str(
+        "file:///Util.pm",
+        "package Util;\nsub used { _helper() }\nsub _helper { 1 }\nsub _orphan { 2 }\n",
+    )?;
+    index.index_file_str("file:///main.pl", "use Util;\nUtil::used();\n")?;
+
+    assert_eq!(unused_subroutines(index), vec!["_orphan".to_string()]);
+    Ok(())
+}
+
+#[test]
+fn does_not_flag_exported_subs() -> TestResult {
+    let index = WorkspaceIndex::new();
+    index.index_file_str(
+        "file:///Util.pm",
+        "package Util;\nuse Exporter 'import';\nour @EXPORT_OK = qw(trim $VERSION);\npush @EXPORT_OK, '&pad';\nsub trim { 1 }\nsub pad { 1 }\nsub _orphan { 2 }\n",
+    )?;
+
+    assert_eq!(unused_subroutines(index), vec!["_orphan".to_string()]);
+    Ok(())
+}
+
+#[test]
+fn does_not_flag_subs_referenced_by_name() -> TestResult {
+    let index = WorkspaceIndex::new();
+    index.index_file_str(
+        "file:///Handlers.pm",
+        "package Handlers;\nmy %dispatch = (start => \\&on_start);\nsub on_start { 1 }\nsub on_stop { 1 }\nsub maybe { __PACKAGE__->can('on_stop') }\n",
+    )?;
+
+    assert_eq!(unused_subroutines(index), vec!["maybe".to_string()]);
+    Ok(())
+}
+
+#[test]
+fn does_not_flag_lifecycle_hooks_or_inherited_methods() -> TestResult {
+    let index = WorkspaceIndex::new();
+    index.index_file_str(
+        "file:///Animal.pm",
+        "package Animal;\nsub new { bless {}, shift }\nsub DESTROY { }\nsub speak { 1 }\nsub name { 1 }\n",
+    )?;
+    index.index_file_str(
+        "file:///main.pl",
+        "package Dog;\nuse parent -norequire, 'Animal';\npackage main;\nmy $dog = Dog->new;\nDog->speak;\n$dog->name;\n",
+    )?;
+
+    assert!(unused_subroutines(index).is_empty());
+    Ok(())
+}
@@ -0,0 +1,69 @@
+//! Tests for `do { } while` / `do { } until` post-condition loops
+//!
+//! These parse to dedicated `DoWhile`/`DoUntil` nodes rather than a generic
+//! statement modifier, because the block always runs once and is not a loop
+//! body for `next`/`last`/`redo`.
+
+use perl_parser::Parser;
+use perl_parser::ast::{Node, NodeKind};
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+/// Parse `code` and return its first top-level statement
+fn first_statement(code: &str) -> Result<Node, Box<dyn std::error::Error>> {
+    let ast = Parser::new(code).parse()?;
+    let NodeKind::Program { statements } = ast.kind else {
+        return Err("Expected Program".into());
+    };
+    Ok(statements.into_iter().next().ok_or("Expected a statement")?)
+}
+
+#[test]
+fn test_do_until_loop() -> TestResult {
+    let stmt = first_statement("do { f() } until ($done);")?;
+
+    let NodeKind::DoUntil { block, condition } = &stmt.kind else {
+        return Err(format!("Expected DoUntil, got {}", stmt.to_sexp()).into());
+    };
+    assert!(matches!(block.kind, NodeKind::Block { .. }));
+    assert!(matches!(&condition.kind, NodeKind::Variable { name, .. } if name == "done"));
+    assert_eq!(
+        stmt.to_sexp(),
+        "(do_until (block (expression_statement (function_call_expression (function)))) (variable $ done))"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_do_while_loop() -> TestResult {
+    let stmt = first_statement("do { last if $x; } while ($y);")?;
+
+    let NodeKind::DoWhile { block, .. } = &stmt.kind else {
+        return Err(format!("Expected DoWhile, got {}", stmt.to_sexp()).into());
+    };
+    let NodeKind::Block { statements } = &block.kind else {
+        return Err("Expected Block".into());
+    };
+    assert_eq!(statements.len(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_other_modifiers_stay_statement_modifiers() -> TestResult {
+    for code in ["$i++ while ($i < 10);", "f() until $done;", "do { f() } if $x;"] {
+        let stmt = first_statement(code)?;
+        assert!(
+            matches!(stmt.kind, NodeKind::StatementModifier { .. }),
+            "{code} should remain a statement modifier: {}",
+            stmt.to_sexp()
+        );
+    }
+
+    // `do FILE while ...` is not a block loop
+    let stmt = first_statement("do $file while $retry;")?;
+    assert!(matches!(stmt.kind, NodeKind::StatementModifier { .. }), "{}", stmt.to_sexp());
+
+    Ok(())
+}
@@ -410,3 +410,74 @@ fn test_end_with_trailing_junk_is_ignored() -> TestResult {
     );
     Ok(())
 }
+
+/// Collect `(delimiter, content, interpolated)` for every heredoc in source order
+fn heredoc_bodies(node: &perl_parser::ast::Node) -> Vec<(String, String, bool)> {
+    use perl_parser::ast::NodeKind;
+
+    let mut out = Vec::new();
+    if let NodeKind::Heredoc { delimiter, content, interpolated, .. } = &node.kind {
+        out.push((delimiter.clone(), content.clone(), *interpolated));
+    }
+    node.for_each_child(|child| out.extend(heredoc_bodies(child)));
+    out
+}
+
+#[test]
+fn test_heredoc_assignment_attaches_body() -> TestResult {
+    let input = "my $sql = <<\"SQL\";\nSELECT *\n  FROM t\nSQL\nsay $sql;\n";
+
+    let mut parser = Parser::new(input);
+    let tree = parser.parse()?;
+
+    assert_eq!(
+        heredoc_bodies(&tree),
+        [("SQL".to_string(), "SELECT *\n  FROM t".to_string(), true)]
+    );
+    assert!(tree.to_sexp().contains("(call say"));
+    Ok(())
+}
+
+#[test]
+fn test_two_heredocs_in_print_resolve_in_order() -> TestResult {
+    let input = "print <<A, <<B;\nfirst\nA\nsecond\nB\n";
+
+    let mut parser = Parser::new(input);
+    let tree = parser.parse()?;
+
+    let bodies: Vec<_> = heredoc_bodies(&tree).into_iter().map(|(d, c, _)| (d, c)).collect();
+    assert_eq!(
+        bodies,
+        [("A".to_string(), "first".to_string()), ("B".to_string(), "second".to_string())]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_interpolating_and_literal_heredoc_kinds() -> TestResult {
+    let input = "print <<\"I\", <<'L';\nhello $name\nI\nhello $name\nL\n";
+
+    let mut parser = Parser::new(input);
+    let tree = parser.parse()?;
+
+    let kinds: Vec<_> = heredoc_bodies(&tree).into_iter().map(|(d, _, i)| (d, i)).collect();
+    assert_eq!(kinds, [("I".to_string(), true), ("L".to_string(), false)]);
+    let sexp = tree.to_sexp();
+    assert!(sexp.contains("(heredoc_interpolated \"I\""), "{sexp}");
+    assert!(sexp.contains("(heredoc \"L\""), "{sexp}");
+    Ok(())
+}
+
+#[test]
+fn test_heredocs_from_statements_sharing_a_line() -> TestResult {
+    // The second statement's body follows the first statement's body
+    let input = "print <<A; if ($x) { print <<B; } print <<C;\none\nA\ntwo\nB\nthree\nC\nsay 1;\n";
+
+    let mut parser = Parser::new(input);
+    let tree = parser.parse()?;
+
+    let bodies: Vec<_> = heredoc_bodies(&tree).into_iter().map(|(_, c, _)| c).collect();
+    assert_eq!(bodies, ["one", "two", "three"]);
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+    Ok(())
+}
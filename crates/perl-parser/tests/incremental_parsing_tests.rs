@@ -178,3 +178,69 @@ fn test_edit_in_subroutine() -> TestResult {
     assert!(result.reparsed_bytes > 0);
     Ok(())
 }
+
+#[test]
+fn test_edit_inside_one_sub_reuses_other_top_level_nodes() -> TestResult {
+    let source =
+        "sub first {\n    return 1;\n}\n\nsub second {\n    return 2;\n}\n\nsub third {\n    return 3;\n}\n"
+            .to_string();
+    let mut state = IncrementalState::new(source);
+    assert_eq!(state.top_level.len(), 3);
+    let before: Vec<*const _> = state.top_level.iter().map(std::sync::Arc::as_ptr).collect();
+
+    // Grow the body of the second sub so the third one shifts
+    let start = state.source.find("return 2").ok_or("expected 'return 2' in source")? + 7;
+    let edit = Edit {
+        start_byte: start,
+        old_end_byte: start + 1,
+        new_end_byte: start + 3,
+        new_text: "200".to_string(),
+    };
+    apply_edits(&mut state, &[edit])?;
+
+    assert_eq!(state.reused_node_count(), 2);
+    let after: Vec<*const _> = state.top_level.iter().map(std::sync::Arc::as_ptr).collect();
+    assert_eq!(after[0], before[0]);
+    assert_ne!(after[1], before[1]);
+    assert_eq!(after[2], before[2]);
+
+    // The reused tree matches a fresh parse of the edited text, shifted positions included
+    let fresh = IncrementalState::new(state.source.clone());
+    assert_eq!(state.ast, fresh.ast);
+    Ok(())
+}
+
+#[test]
+fn test_sub_reparse_sees_pragmas_in_effect() -> TestResult {
+    let source =
+        "use feature 'signatures';\nsub add ($x, $y) {\n    return $x + $y;\n}\n".to_string();
+    let mut state = IncrementalState::new(source);
+
+    // Without the pragma, `($x, $y)` would read as a prototype and the header would differ
+    let start = state.source.find("$x + $y").ok_or("expected '$x + $y' in source")?;
+    let edit = Edit {
+        start_byte: start,
+        old_end_byte: start + 7,
+        new_end_byte: start + 7,
+        new_text: "$x * $y".to_string(),
+    };
+    apply_edits(&mut state, &[edit])?;
+
+    assert_eq!(state.reused_node_count(), 1);
+    let fresh = IncrementalState::new(state.source.clone());
+    assert_eq!(state.ast, fresh.ast);
+    Ok(())
+}
+
+#[test]
+fn test_edit_outside_sub_bodies_reparses_everything() -> TestResult {
+    let source = "my $x = 1;\nsub foo {\n    return $x;\n}\n".to_string();
+    let mut state = IncrementalState::new(source);
+
+    let edit = Edit { start_byte: 8, old_end_byte: 9, new_end_byte: 9, new_text: "2".to_string() };
+    apply_edits(&mut state, &[edit])?;
+
+    assert_eq!(state.reused_node_count(), 0);
+    assert_eq!(state.ast, IncrementalState::new(state.source.clone()).ast);
+    Ok(())
+}
@@ -853,14 +853,22 @@ where
             find_nodes_recursive(body, predicate, results);
         }
         NodeKind::Subroutine { body, .. } => {
-            find_nodes_recursive(body, predicate, results);
+            if let Some(body) = body {
+                find_nodes_recursive(body, predicate, results);
+            }
         }
         NodeKind::Method { body, .. } => {
             find_nodes_recursive(body, predicate, results);
         }
-        NodeKind::Class { body, name: _ } => {
+        NodeKind::Class { body, .. } => {
             find_nodes_recursive(body, predicate, results);
         }
+        NodeKind::Field { variable, default, .. } => {
+            find_nodes_recursive(variable, predicate, results);
+            if let Some(default) = default {
+                find_nodes_recursive(default, predicate, results);
+            }
+        }
         NodeKind::FunctionCall { args, name: _ } => {
             for arg in args {
                 find_nodes_recursive(arg, predicate, results);
@@ -896,6 +904,10 @@ where
         NodeKind::Do { block } => {
             find_nodes_recursive(block, predicate, results);
         }
+        NodeKind::DoWhile { block, condition } | NodeKind::DoUntil { block, condition } => {
+            find_nodes_recursive(block, predicate, results);
+            find_nodes_recursive(condition, predicate, results);
+        }
         NodeKind::Return { value } => {
             if let Some(val) = value {
                 find_nodes_recursive(val, predicate, results);
@@ -920,7 +932,7 @@ where
         NodeKind::String { .. } => {}   // No children
         NodeKind::Heredoc { .. } => {}  // No children
         NodeKind::Undef => {}           // No children
-        NodeKind::Ellipsis => {}        // No children
+        NodeKind::YadaYada => {}        // No children
         NodeKind::Regex { .. } => {}    // No children
         NodeKind::Match { expr, .. } => {
             find_nodes_recursive(expr, predicate, results);
@@ -528,7 +528,9 @@ where
             find_nodes_recursive(body, predicate, results);
         }
         NodeKind::Subroutine { body, .. } => {
-            find_nodes_recursive(body, predicate, results);
+            if let Some(body) = body {
+                find_nodes_recursive(body, predicate, results);
+            }
         }
         NodeKind::Method { body, .. } => {
             find_nodes_recursive(body, predicate, results);
@@ -536,6 +538,12 @@ where
         NodeKind::Class { body, .. } => {
             find_nodes_recursive(body, predicate, results);
         }
+        NodeKind::Field { variable, default, .. } => {
+            find_nodes_recursive(variable, predicate, results);
+            if let Some(default) = default {
+                find_nodes_recursive(default, predicate, results);
+            }
+        }
         NodeKind::FunctionCall { args, .. } => {
             for arg in args {
                 find_nodes_recursive(arg, predicate, results);
@@ -571,6 +579,10 @@ where
         NodeKind::Do { block } => {
             find_nodes_recursive(block, predicate, results);
         }
+        NodeKind::DoWhile { block, condition } | NodeKind::DoUntil { block, condition } => {
+            find_nodes_recursive(block, predicate, results);
+            find_nodes_recursive(condition, predicate, results);
+        }
         NodeKind::Return { value } => {
             if let Some(val) = value {
                 find_nodes_recursive(val, predicate, results);
@@ -595,7 +607,7 @@ where
         NodeKind::String { .. } => {}   // No children
         NodeKind::Heredoc { .. } => {}  // No children
         NodeKind::Undef => {}           // No children
-        NodeKind::Ellipsis => {}        // No children
+        NodeKind::YadaYada => {}        // No children
         NodeKind::Regex { .. } => {}    // No children
         NodeKind::Match { expr, .. } => {
             find_nodes_recursive(expr, predicate, results);
@@ -0,0 +1,97 @@
+//! Tests for the `package NAME VERSION` and `package NAME BLOCK` forms
+//!
+//! The version is kept apart from the name, the block form scopes its
+//! contents to the package, and the statement form switches the current
+//! package for the rest of the file.
+
+use perl_parser::ast::{Node, NodeKind};
+use perl_parser::{Parser, SymbolExtractor};
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+/// Collect every Package node at the top level of the program
+fn packages(ast: &Node) -> Vec<&Node> {
+    let NodeKind::Program { statements } = &ast.kind else {
+        return Vec::new();
+    };
+    statements
+        .iter()
+        .map(|stmt| match &stmt.kind {
+            NodeKind::ExpressionStatement { expression } => expression.as_ref(),
+            _ => stmt,
+        })
+        .filter(|node| matches!(node.kind, NodeKind::Package { .. }))
+        .collect()
+}
+
+/// Qualified names of every subroutine symbol named `name`
+fn qualified_subs(source: &str, ast: &Node, name: &str) -> Vec<String> {
+    let table = SymbolExtractor::new_with_source(source).extract(ast);
+    let mut names: Vec<String> = table
+        .symbols
+        .get(name)
+        .into_iter()
+        .flatten()
+        .map(|symbol| symbol.qualified_name.clone())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn test_block_form_scopes_sub_to_package() -> TestResult {
+    let source = "package Foo { sub bar { 1 } }\nsub baz { 2 }\n";
+    let ast = Parser::new(source).parse()?;
+
+    let pkgs = packages(&ast);
+    assert_eq!(pkgs.len(), 1, "Expected one Package node: {}", ast.to_sexp());
+    assert!(matches!(
+        &pkgs[0].kind,
+        NodeKind::Package { name, version: None, block: Some(_), .. } if name == "Foo"
+    ));
+
+    assert_eq!(qualified_subs(source, &ast, "bar"), vec!["Foo::bar"]);
+    assert_eq!(qualified_subs(source, &ast, "baz"), vec!["main::baz"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_statement_form_with_version() -> TestResult {
+    let source = "package Foo::Bar 1.23;\nsub baz { 1 }\npackage Other;\nsub qux { 2 }\n";
+    let ast = Parser::new(source).parse()?;
+
+    let pkgs = packages(&ast);
+    assert_eq!(pkgs.len(), 2, "Expected two Package nodes: {}", ast.to_sexp());
+    let NodeKind::Package { name, version, block, name_span } = &pkgs[0].kind else {
+        return Err("Expected a Package node".into());
+    };
+    assert_eq!(name, "Foo::Bar");
+    assert_eq!(version.as_deref(), Some("1.23"));
+    assert!(block.is_none());
+    assert_eq!(&source[name_span.start..name_span.end], "Foo::Bar");
+
+    assert_eq!(qualified_subs(source, &ast, "baz"), vec!["Foo::Bar::baz"]);
+    assert_eq!(qualified_subs(source, &ast, "qux"), vec!["Other::qux"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_version_and_block_form() -> TestResult {
+    let source = "package Foo v1.2.3 { sub bar { 1 } }\n";
+    let ast = Parser::new(source).parse()?;
+
+    let pkgs = packages(&ast);
+    assert_eq!(pkgs.len(), 1, "Expected one Package node: {}", ast.to_sexp());
+    assert!(matches!(
+        &pkgs[0].kind,
+        NodeKind::Package { name, version: Some(version), block: Some(_), .. }
+            if name == "Foo" && version == "v1.2.3"
+    ));
+    assert!(ast.to_sexp().starts_with("(source_file (package Foo v1.2.3 (block"));
+
+    assert_eq!(qualified_subs(source, &ast, "bar"), vec!["Foo::bar"]);
+
+    Ok(())
+}
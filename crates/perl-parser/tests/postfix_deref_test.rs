@@ -82,3 +82,89 @@ fn test_postfix_deref_in_expression() -> Result<(), Box<dyn std::error::Error>>
     assert!(sexp.contains("unary_->@*"));
     Ok(())
 }
+
+/// Unwrap `Program -> ExpressionStatement` down to the single expression
+fn parse_expr(code: &str) -> Result<perl_parser::ast::Node, Box<dyn std::error::Error>> {
+    use perl_parser::ast::NodeKind;
+
+    let ast = Parser::new(code).parse()?;
+    let NodeKind::Program { statements } = ast.kind else {
+        return Err(format!("Expected Program for {code}").into());
+    };
+    let stmt = statements.into_iter().next().ok_or("Expected a statement")?;
+    match stmt.kind {
+        NodeKind::ExpressionStatement { expression } => Ok(*expression),
+        _ => Ok(stmt),
+    }
+}
+
+#[test]
+fn test_postfix_deref_node_kinds() -> Result<(), Box<dyn std::error::Error>> {
+    use perl_parser::ast::NodeKind;
+
+    let unary_cases = [
+        ("$x->@*;", "->@*"),
+        ("$x->%*;", "->%*"),
+        ("$x->$*;", "->$*"),
+        ("$x->$#*;", "->$#*"),
+        ("$x->&*;", "->&*"),
+        ("$x->**;", "->**"),
+    ];
+    for (code, expected) in unary_cases {
+        let expr = parse_expr(code)?;
+        assert!(
+            matches!(&expr.kind, NodeKind::Unary { op, operand }
+                if op == expected && matches!(operand.kind, NodeKind::Variable { .. })),
+            "{code} should be Unary {expected}: {}",
+            expr.to_sexp()
+        );
+    }
+
+    let slice_cases = [
+        ("$x->@[0,1];", "->@[]"),
+        ("$x->@{'a','b'};", "->@{}"),
+        ("$x->%{'a','b'};", "->%{}"),
+        ("$x->%[0,1];", "->%[]"),
+    ];
+    for (code, expected) in slice_cases {
+        let expr = parse_expr(code)?;
+        assert!(
+            matches!(&expr.kind, NodeKind::Binary { op, right, .. }
+                if op == expected && matches!(right.kind, NodeKind::ArrayLiteral { .. })),
+            "{code} should be Binary {expected} over a list: {}",
+            expr.to_sexp()
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_postfix_last_index_deref() -> Result<(), Box<dyn std::error::Error>> {
+    let mut parser = Parser::new("my $last = $ref->$#*;");
+    let ast = parser.parse()?;
+    assert_eq!(
+        ast.to_sexp(),
+        "(source_file (my_declaration (variable $ last)(unary_->$#* (variable $ ref))))"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_chained_postfix_deref_at_statement_end() -> Result<(), Box<dyn std::error::Error>> {
+    use perl_parser::ast::NodeKind;
+
+    let expr = parse_expr("$x->{a}->@*;")?;
+    let NodeKind::Unary { op, operand } = &expr.kind else {
+        return Err(format!("Expected Unary, got {}", expr.to_sexp()).into());
+    };
+    assert_eq!(op, "->@*");
+    assert!(matches!(&operand.kind, NodeKind::Binary { op, .. } if op == "{}"));
+
+    let mut parser = Parser::new("my @all = $x->{a}->@*; my $n = $x->$#*;");
+    let ast = parser.parse()?;
+    let sexp = ast.to_sexp();
+    assert!(sexp.contains("unary_->@*"), "{sexp}");
+    assert!(sexp.contains("unary_->$#*"), "{sexp}");
+    Ok(())
+}
@@ -130,7 +130,7 @@ where
                 f(cont)?;
             }
         }
-        Subroutine { body, .. } => {
+        Subroutine { body: Some(body), .. } => {
             f(body)?;
         }
         FunctionCall { args, .. } => {
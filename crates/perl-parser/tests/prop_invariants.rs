@@ -112,7 +112,7 @@ where
             f(list)?;
             f(body)?;
         }
-        Subroutine { body, .. } => {
+        Subroutine { body: Some(body), .. } => {
             f(body)?;
         }
         FunctionCall { args, .. } => {
@@ -113,7 +113,7 @@ fn extract_shape_rec(node: &Node, out: &mut Vec<String>) {
                 extract_shape_rec(cont, out);
             }
         }
-        Subroutine { body, .. } => {
+        Subroutine { body: Some(body), .. } => {
             extract_shape_rec(body, out);
         }
         FunctionCall { args, .. } => {
@@ -235,7 +235,7 @@ fn check_spans_rec(node: &Node, source_len: usize, errors: &mut Vec<String>) {
             check_spans_rec(list, source_len, errors);
             check_spans_rec(body, source_len, errors);
         }
-        Subroutine { body, .. } => {
+        Subroutine { body: Some(body), .. } => {
             check_spans_rec(body, source_len, errors);
         }
         FunctionCall { args, .. } => {
@@ -316,7 +316,7 @@ fn extract_shape_rec(node: &Node, out: &mut Vec<String>) {
             }
         }
 
-        Subroutine { body, .. } => {
+        Subroutine { body: Some(body), .. } => {
             extract_shape_rec(body, out);
         }
 
@@ -184,6 +184,79 @@ fn s_modifiers_are_captured() {
     assert_eq!(modifiers, "g");
 }
 
+#[test]
+fn tr_r_modifier_is_kept_on_bound_transliteration() -> Result<(), Box<dyn std::error::Error>> {
+    use perl_parser::ast::NodeKind;
+
+    let code = "my $y = $x =~ tr/a-z/A-Z/r;";
+    let mut parser = Parser::new(code);
+    let ast = parser.parse()?;
+
+    let mut found = None;
+    let mut stack = vec![&ast];
+    while let Some(node) = stack.pop() {
+        if let NodeKind::Transliteration { expr, modifiers, .. } = &node.kind {
+            found = Some((expr.kind.clone(), modifiers.clone()));
+        }
+        node.for_each_child(|child| stack.push(child));
+    }
+
+    let (target, modifiers) = found.ok_or("no transliteration node")?;
+    assert!(matches!(target, NodeKind::Variable { ref name, .. } if name == "x"));
+    assert_eq!(modifiers, "r");
+    Ok(())
+}
+
+#[test]
+fn quote_parser_extracts_substitution_segments_and_modifiers()
+-> Result<(), Box<dyn std::error::Error>> {
+    use perl_parser::quote_parser::QuoteParser;
+
+    let src = "$s =~ s{foo}{bar}gi;";
+    let quote = QuoteParser::new().parse_quote_like(src, 6).ok_or("no quote-like operator")?;
+
+    assert_eq!(quote.operator, "s");
+    assert_eq!((quote.open, quote.close), ('{', '}'));
+    let segments: Vec<_> = quote.segments.iter().map(|seg| seg.text).collect();
+    assert_eq!(segments, ["foo", "bar"]);
+    assert_eq!(quote.modifiers, "gi");
+    assert_eq!(&src[quote.segments[0].start..quote.segments[0].end], "foo");
+    assert_eq!(&src[quote.end..], ";");
+    Ok(())
+}
+
+#[test]
+fn quote_parser_extracts_single_regex_segment() -> Result<(), Box<dyn std::error::Error>> {
+    use perl_parser::quote_parser::QuoteParser;
+
+    let src = r"my $re = qr/\d+/x;";
+    let quote = QuoteParser::new().parse_quote_like(src, 9).ok_or("no quote-like operator")?;
+
+    assert_eq!(quote.operator, "qr");
+    assert_eq!(quote.segments.len(), 1);
+    let pattern = quote.pattern().ok_or("qr has a pattern")?;
+    assert_eq!(pattern.text, r"\d+");
+    assert_eq!(quote.modifiers, "x");
+    Ok(())
+}
+
+#[test]
+fn quote_parser_rejects_non_quotes_and_unterminated_bodies() {
+    use perl_parser::quote_parser::QuoteParser;
+
+    let parser = QuoteParser::new();
+    assert!(parser.parse_quote_like("my %h = (y => 1);", 9).is_none());
+    assert!(parser.parse_quote_like("sort @x", 0).is_none());
+    assert!(parser.parse_quote_like("s/foo/bar", 0).is_none());
+    assert!(parser.parse_quote_like("q", 5).is_none());
+
+    // Non-bracketed substitution shares the middle delimiter; tr has no pattern
+    let quote = parser.parse_quote_like("tr/a-z/A-Z/r", 0);
+    assert_eq!(quote.as_ref().map(|q| q.segments.len()), Some(2));
+    assert_eq!(quote.as_ref().map(|q| q.modifiers), Some("r"));
+    assert!(quote.is_some_and(|q| q.pattern().is_none()));
+}
+
 #[test]
 fn q_word_comparison_operators_parse() {
     // Test all word comparison operators with q()
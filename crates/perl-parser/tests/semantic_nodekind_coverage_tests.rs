@@ -109,6 +109,8 @@ EOF
             r#"
                 eval { my $v = 0; };
                 do { my $w = 1; };
+                do { my $d = 1; } while (0);
+                do { my $u = 1; } until (1);
 
                 for (my $j = 0; $j < 2; $j++) {
                     next if $j == 1;
@@ -160,6 +162,8 @@ EOF
                 use feature 'signatures';
 
                 class Builder {
+                    field $base :param = 0;
+
                     method build($left, $right = 1, @rest) {
                         return $left + $right;
                     }
@@ -0,0 +1,97 @@
+//! Tests for bodyless subroutine declarations
+//!
+//! `sub foo;` (forward declaration) and `sub foo ($$);` (prototype declaration)
+//! produce a `Subroutine` node without a body, which keeps them distinct from
+//! a definition such as `sub foo { 1 }`.
+
+use perl_parser::Parser;
+use perl_parser::ast::{Node, NodeKind};
+use perl_parser::workspace_index::WorkspaceIndex;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+/// Collect every Subroutine node at the top level of the program
+fn subroutines(ast: &Node) -> Vec<&Node> {
+    let NodeKind::Program { statements } = &ast.kind else {
+        return Vec::new();
+    };
+    statements
+        .iter()
+        .map(|stmt| match &stmt.kind {
+            NodeKind::ExpressionStatement { expression } => expression.as_ref(),
+            _ => stmt,
+        })
+        .filter(|node| matches!(node.kind, NodeKind::Subroutine { .. }))
+        .collect()
+}
+
+#[test]
+fn test_forward_declaration_has_no_body() -> TestResult {
+    let mut parser = Parser::new("sub foo;");
+    let ast = parser.parse()?;
+
+    let subs = subroutines(&ast);
+    assert_eq!(subs.len(), 1, "Expected one Subroutine node: {}", ast.to_sexp());
+    assert!(matches!(
+        &subs[0].kind,
+        NodeKind::Subroutine { name: Some(name), body: None, prototype: None, .. } if name == "foo"
+    ));
+    assert_eq!(ast.to_sexp(), "(source_file (sub foo ()))");
+
+    Ok(())
+}
+
+#[test]
+fn test_prototype_declaration_has_no_body() -> TestResult {
+    let mut parser = Parser::new("sub foo ($$);");
+    let ast = parser.parse()?;
+
+    let subs = subroutines(&ast);
+    assert_eq!(subs.len(), 1, "Expected one Subroutine node: {}", ast.to_sexp());
+    assert!(matches!(
+        &subs[0].kind,
+        NodeKind::Subroutine { name: Some(name), body: None, prototype: Some(_), .. }
+            if name == "foo"
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_definition_has_body() -> TestResult {
+    let mut parser = Parser::new("sub foo { 1 }");
+    let ast = parser.parse()?;
+
+    let subs = subroutines(&ast);
+    assert_eq!(subs.len(), 1, "Expected one Subroutine node: {}", ast.to_sexp());
+    let NodeKind::Subroutine { body, .. } = &subs[0].kind else {
+        return Err("Expected a Subroutine node".into());
+    };
+    let body = body.as_ref().ok_or("Definition should have a body")?;
+    assert!(matches!(body.kind, NodeKind::Block { .. }));
+
+    Ok(())
+}
+
+#[test]
+fn test_declaration_and_definition_are_not_duplicates() -> TestResult {
+    let source = "sub foo;\nsub foo { 1 }\nfoo();\n";
+    let mut parser = Parser::new(source);
+    let ast = parser.parse()?;
+
+    let subs = subroutines(&ast);
+    assert_eq!(subs.len(), 2);
+    assert!(matches!(subs[0].kind, NodeKind::Subroutine { body: None, .. }));
+    assert!(matches!(subs[1].kind, NodeKind::Subroutine { body: Some(_), .. }));
+
+    let index = WorkspaceIndex::new();
+    index.index_file_str("file:///decl.pl", source)?;
+
+    let symbols: Vec<_> =
+        index.file_symbols("file:///decl.pl").into_iter().filter(|s| s.name == "foo").collect();
+    assert_eq!(symbols.len(), 1, "Declaration and definition should be one symbol: {symbols:?}");
+    assert!(symbols[0].has_body, "Symbol should point at the definition");
+    assert_eq!(symbols[0].range.start.line, 1, "Definition should win over the declaration");
+
+    Ok(())
+}
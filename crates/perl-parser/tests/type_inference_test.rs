@@ -87,3 +87,80 @@ fn test_control_flow_union() -> TestResult {
     assert_eq!(x_type, Some(PerlType::Scalar(ScalarType::Undef)));
     Ok(())
 }
+
+fn sub_return_type(engine: &TypeInferenceEngine, name: &str) -> Option<PerlType> {
+    match engine.get_subroutine(name) {
+        Some(PerlType::Subroutine { returns, .. }) => returns.first().cloned(),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_return_type_int_literal_with_early_return() -> TestResult {
+    let code = r#"
+        sub answer {
+            my ($strict) = @_;
+            return 0 if $strict;
+            if (!defined $strict) {
+                return 1;
+            }
+            42;
+        }
+    "#;
+    let engine = infer(code)?;
+
+    assert_eq!(sub_return_type(&engine, "answer"), Some(PerlType::Scalar(ScalarType::Integer)));
+    Ok(())
+}
+
+#[test]
+fn test_return_type_array_variable() -> TestResult {
+    let code = r#"
+        sub names {
+            my @list = ("a", "b");
+            return @list;
+        }
+    "#;
+    let engine = infer(code)?;
+
+    assert_eq!(
+        sub_return_type(&engine, "names"),
+        Some(PerlType::Array(Box::new(PerlType::Scalar(ScalarType::String))))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_return_type_conflicting_returns_widen_to_any() -> TestResult {
+    let code = r#"
+        sub pick {
+            my ($want_list) = @_;
+            return (1, 2, 3) if $want_list;
+            return "none";
+        }
+    "#;
+    let engine = infer(code)?;
+
+    assert_eq!(sub_return_type(&engine, "pick"), Some(PerlType::Any));
+    Ok(())
+}
+
+#[test]
+fn test_return_type_keyed_by_qualified_name() -> TestResult {
+    let code = r#"
+        package My::Counter;
+        sub start { return 10; }
+
+        package main;
+        my $n = My::Counter::start();
+    "#;
+    let engine = infer(code)?;
+
+    assert_eq!(
+        sub_return_type(&engine, "My::Counter::start"),
+        Some(PerlType::Scalar(ScalarType::Integer))
+    );
+    assert_eq!(sub_return_type(&engine, "main::start"), None);
+    assert_eq!(engine.get_type_at("n"), Some(PerlType::Scalar(ScalarType::Integer)));
+    Ok(())
+}
@@ -0,0 +1,74 @@
+//! Tests for the normalized representation of `unless` and `until`
+//!
+//! `unless (C)` parses to an `If` and `until (C)` to a `While`, each with the
+//! condition wrapped in a synthetic `!` that spans the written condition, so
+//! analyses of `if`/`while` handle the negated forms without special cases.
+
+use perl_parser::Parser;
+use perl_parser::ast::{Node, NodeKind};
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+/// Parse `code` and return its first top-level statement
+fn first_statement(code: &str) -> Result<Node, Box<dyn std::error::Error>> {
+    let ast = Parser::new(code).parse()?;
+    let NodeKind::Program { statements } = ast.kind else {
+        return Err("Expected Program".into());
+    };
+    Ok(statements.into_iter().next().ok_or("Expected a statement")?)
+}
+
+/// The operand of a synthetic `!`, or an error if the node is not negated
+fn negated_operand(condition: &Node) -> Result<&Node, Box<dyn std::error::Error>> {
+    match &condition.kind {
+        NodeKind::Unary { op, operand } if op == "!" => Ok(operand),
+        _ => Err(format!("Expected negated condition, got {}", condition.to_sexp()).into()),
+    }
+}
+
+#[test]
+fn test_unless_is_negated_if() -> TestResult {
+    let code = "unless ($ready) { wait_more() }";
+    let stmt = first_statement(code)?;
+
+    let NodeKind::If { condition, elsif_branches, else_branch, .. } = &stmt.kind else {
+        return Err(format!("Expected If, got {}", stmt.to_sexp()).into());
+    };
+    let operand = negated_operand(condition)?;
+    assert!(matches!(&operand.kind, NodeKind::Variable { name, .. } if name == "ready"));
+    assert_eq!(&code[condition.location.start..condition.location.end], "$ready");
+    assert!(elsif_branches.is_empty());
+    assert!(else_branch.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_unless_with_elsif_and_else() -> TestResult {
+    let stmt = first_statement("unless ($a) { one() } elsif ($b) { two() } else { three() }")?;
+
+    let NodeKind::If { condition, elsif_branches, else_branch, .. } = &stmt.kind else {
+        return Err(format!("Expected If, got {}", stmt.to_sexp()).into());
+    };
+    negated_operand(condition)?;
+    assert_eq!(elsif_branches.len(), 1);
+    // Only the leading `unless` condition is negated
+    assert!(matches!(&elsif_branches[0].0.kind, NodeKind::Variable { name, .. } if name == "b"));
+    assert!(else_branch.is_some());
+
+    Ok(())
+}
+
+#[test]
+fn test_until_is_negated_while() -> TestResult {
+    let stmt = first_statement("until ($done) { step() } continue { tick() }")?;
+
+    let NodeKind::While { condition, continue_block, .. } = &stmt.kind else {
+        return Err(format!("Expected While, got {}", stmt.to_sexp()).into());
+    };
+    let operand = negated_operand(condition)?;
+    assert!(matches!(&operand.kind, NodeKind::Variable { name, .. } if name == "done"));
+    assert!(continue_block.is_some());
+
+    Ok(())
+}
@@ -185,3 +185,69 @@ fn test_word_not_as_terminator() -> Result<(), Box<dyn std::error::Error>> {
     assert!(sexp.contains("(call open"), "expected (call open ...), got: {sexp}");
     Ok(())
 }
+
+#[test]
+fn test_assignment_then_or_shape() -> Result<(), Box<dyn std::error::Error>> {
+    // $a = $b or $c is ($a = $b) or $c
+    let ast = Parser::new("$a = $b or $c;").parse()?;
+    assert_eq!(
+        ast.to_sexp(),
+        "(source_file (binary_or (assignment_assign (variable $ a) (variable $ b)) (variable $ c)))"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_chained_ternary_is_right_associative() -> Result<(), Box<dyn std::error::Error>> {
+    // $a ? $b : $c ? $d : $e is $a ? $b : ($c ? $d : $e)
+    let ast = Parser::new("$x = $a ? $b : $c ? $d : $e;").parse()?;
+    assert_eq!(
+        ast.to_sexp(),
+        "(source_file (assignment_assign (variable $ x) (ternary (variable $ a) (variable $ b) \
+         (ternary (variable $ c) (variable $ d) (variable $ e)))))"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_ternary_then_branch_may_assign() -> Result<(), Box<dyn std::error::Error>> {
+    let ast = Parser::new("$a ? $b = 1 : $c;").parse()?;
+    assert_eq!(
+        ast.to_sexp(),
+        "(source_file (ternary (variable $ a) (assignment_assign (variable $ b) (number 1)) \
+         (variable $ c)))"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_function_call_or_die_shape() -> Result<(), Box<dyn std::error::Error>> {
+    let ast = Parser::new("func() or die;").parse()?;
+    assert_eq!(
+        ast.to_sexp(),
+        "(source_file (binary_or (function_call_expression (function)) (call die ())))"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_bareword_filehandle_open_or_die() -> Result<(), Box<dyn std::error::Error>> {
+    // `or` binds looser than the list operator: (open FH) or die
+    let ast = Parser::new("open FH or die;").parse()?;
+    assert_eq!(
+        ast.to_sexp(),
+        "(source_file (binary_or (indirect_call open (identifier FH) ()) (call die ())))"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_declaration_initializer_stops_at_or() -> Result<(), Box<dyn std::error::Error>> {
+    // my $x = f() or die is (my $x = f()) or die
+    let ast = Parser::new("my $x = 1 or die;").parse()?;
+    assert_eq!(
+        ast.to_sexp(),
+        "(source_file (binary_or (my_declaration (variable $ x)(number 1)) (call die ())))"
+    );
+    Ok(())
+}
@@ -0,0 +1,39 @@
+//! Tests for the yada-yada statement (`...`)
+//!
+//! A bare `...` in statement position is a placeholder that compiles but dies
+//! with "Unimplemented" at runtime. Between two operands the same token is the
+//! flip-flop range operator and must stay a binary range.
+
+use perl_parser::Parser;
+
+#[test]
+fn test_yada_yada_sub_body() -> Result<(), Box<dyn std::error::Error>> {
+    let ast = Parser::new("sub f { ... }").parse()?;
+    assert_eq!(ast.to_sexp(), "(source_file (sub f ()(block (expression_statement (yada_yada)))))");
+    Ok(())
+}
+
+#[test]
+fn test_yada_yada_top_level() -> Result<(), Box<dyn std::error::Error>> {
+    let ast = Parser::new("...;").parse()?;
+    assert_eq!(ast.to_sexp(), "(source_file (yada_yada))");
+    Ok(())
+}
+
+#[test]
+fn test_two_dot_range_is_binary() -> Result<(), Box<dyn std::error::Error>> {
+    let ast = Parser::new("1..3").parse()?;
+    assert_eq!(ast.to_sexp(), "(source_file (binary_.. (number 1) (number 3)))");
+    Ok(())
+}
+
+#[test]
+fn test_three_dot_range_is_binary() -> Result<(), Box<dyn std::error::Error>> {
+    let ast = Parser::new("1...3").parse()?;
+    assert_eq!(ast.to_sexp(), "(source_file (binary_... (number 1) (number 3)))");
+
+    let ast = Parser::new("my @a = (1...3);").parse()?;
+    assert!(ast.to_sexp().contains("(binary_... (number 1) (number 3))"));
+    assert!(!ast.to_sexp().contains("yada_yada"));
+    Ok(())
+}
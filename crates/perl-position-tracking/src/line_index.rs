@@ -1,5 +1,6 @@
 //! Line index for efficient UTF-16 position calculations.
 use ropey::Rope;
+use std::ops::Range;
 #[derive(Debug, Clone)]
 pub struct LineStartsCache {
     line_starts: Vec<usize>,
@@ -124,6 +125,35 @@ impl LineIndex {
         Self { line_starts, text }
     }
 
+    /// The indexed source text
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Replace the bytes in `range` with `new_text`, updating line starts in place
+    ///
+    /// Only line starts inside the edited region are recomputed; those after it
+    /// are shifted by the change in length. The range is clamped to the text
+    /// and snapped back to character boundaries.
+    pub fn apply_edit(&mut self, range: Range<usize>, new_text: &str) {
+        let end = floor_char_boundary(&self.text, range.end.min(self.text.len()));
+        let start = floor_char_boundary(&self.text, range.start.min(end));
+
+        // A line start `p` comes from the newline at `p - 1`, so the starts
+        // owned by the replaced bytes are those in `start + 1..=end`
+        let first = self.line_starts.partition_point(|&p| p <= start);
+        let last = self.line_starts.partition_point(|&p| p <= end);
+        let inserted = new_text.match_indices('\n').map(|(i, _)| start + i + 1);
+        self.line_starts.splice(first..last, inserted);
+
+        let shifted = first + new_text.matches('\n').count();
+        for line_start in &mut self.line_starts[shifted..] {
+            *line_start = *line_start - end + start + new_text.len();
+        }
+
+        self.text.replace_range(start..end, new_text);
+    }
+
     /// Convert byte offset to position (0-based line and UTF-16 column)
     pub fn offset_to_position(&self, offset: usize) -> (u32, u32) {
         let line = self.line_starts.binary_search(&offset).unwrap_or_else(|i| i.saturating_sub(1));
@@ -204,3 +234,67 @@ impl LineIndex {
         (start_pos, end_pos)
     }
 }
+
+/// Largest character boundary in `text` at or before `offset`
+fn floor_char_boundary(text: &str, mut offset: usize) -> usize {
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Apply an edit and check the result against an index built from scratch
+    fn assert_edit_matches_rebuild(text: &str, range: Range<usize>, new_text: &str) {
+        let mut index = LineIndex::new(text.to_string());
+        index.apply_edit(range.clone(), new_text);
+
+        let mut expected_text = text.to_string();
+        expected_text.replace_range(range, new_text);
+        let expected = LineIndex::new(expected_text);
+
+        assert_eq!(index.text(), expected.text());
+        assert_eq!(index.line_starts, expected.line_starts, "edited text: {:?}", index.text());
+    }
+
+    #[test]
+    fn test_apply_edit_single_line() {
+        let text = "my $x = 1;\nmy $y = 2;\nprint $x;\n";
+        assert_edit_matches_rebuild(text, 8..9, "42");
+        assert_edit_matches_rebuild(text, 11..11, "our ");
+        assert_edit_matches_rebuild(text, 14..20, "");
+        assert_edit_matches_rebuild(text, 0..0, "#");
+        assert_edit_matches_rebuild(text, text.len()..text.len(), "1;");
+    }
+
+    #[test]
+    fn test_apply_edit_multi_line_insertion() {
+        let text = "sub f {\n}\nf();\n";
+        assert_edit_matches_rebuild(text, 8..8, "    my $a = 1;\n    return $a;\n");
+        assert_edit_matches_rebuild(text, 7..7, "\n\n\n");
+        assert_edit_matches_rebuild(text, 3..5, "g\n");
+    }
+
+    #[test]
+    fn test_apply_edit_deletion_spanning_newlines() {
+        let text = "one\ntwo\nthree\nfour\n";
+        assert_edit_matches_rebuild(text, 2..10, "");
+        assert_edit_matches_rebuild(text, 3..8, "");
+        assert_edit_matches_rebuild(text, 4..14, "2\n3");
+        assert_edit_matches_rebuild(text, 0..text.len(), "");
+    }
+
+    #[test]
+    fn test_apply_edit_keeps_positions_consistent() {
+        let mut index = LineIndex::new("a\nb\u{e9}c\nd".to_string());
+        index.apply_edit(1..2, "\n\n");
+
+        assert_eq!(index.text(), "a\n\nb\u{e9}c\nd");
+        assert_eq!(index.offset_to_position(4), (2, 1));
+        assert_eq!(index.position_to_offset(2, 2), Some(6));
+        assert_eq!(index.offset_to_position(index.text().len()), (3, 1));
+    }
+}
@@ -17,15 +17,56 @@ pub struct PragmaState {
     pub strict_refs: bool,
     /// Whether warnings are enabled
     pub warnings: bool,
+    /// Whether subroutine signatures are enabled (`use feature 'signatures'`, `use v5.36`)
+    pub signatures: bool,
+    /// Whether `try`/`catch` is a keyword (`use feature 'try'`, `use v5.40`, `use Try::Tiny`)
+    pub try_catch: bool,
+    /// Perl 5 minor version required by the last `use VERSION`, if any
+    pub version_minor: Option<u32>,
 }
 
 impl PragmaState {
     /// Create a new pragma state with all strict modes enabled
     pub fn all_strict() -> Self {
-        Self { strict_vars: true, strict_subs: true, strict_refs: true, warnings: false }
+        Self {
+            strict_vars: true,
+            strict_subs: true,
+            strict_refs: true,
+            warnings: false,
+            signatures: false,
+            try_catch: false,
+            version_minor: None,
+        }
+    }
+
+    /// Whether the defined-or operator `//` can be used here
+    ///
+    /// `//` exists from Perl 5.10 without any feature, so only an explicit
+    /// `use VERSION` older than that rules it out.
+    pub fn defined_or_available(&self) -> bool {
+        self.version_minor.is_none_or(|minor| minor >= DEFINED_OR_MINOR)
     }
 }
 
+/// First Perl 5 minor version with the defined-or operator (5.10)
+const DEFINED_OR_MINOR: u32 = 10;
+
+/// First Perl 5 minor version whose feature bundle enables signatures (5.36)
+const SIGNATURES_BUNDLE_MINOR: u32 = 36;
+
+/// First Perl 5 minor version whose feature bundle enables try/catch (5.40)
+const TRY_BUNDLE_MINOR: u32 = 40;
+
+/// Modules that export a block-taking `try`/`catch`
+const TRY_MODULES: &[&str] =
+    &["Try::Tiny", "TryCatch", "Syntax::Keyword::Try", "Feature::Compat::Try", "Try::Catch"];
+
+/// First Perl 5 minor version whose `use VERSION` implies `use strict` (5.12)
+const STRICT_BUNDLE_MINOR: u32 = 12;
+
+/// First Perl 5 minor version whose `use VERSION` implies `use warnings` (5.35)
+const WARNINGS_BUNDLE_MINOR: u32 = 35;
+
 /// Tracks pragma state throughout a Perl file
 pub struct PragmaTracker;
 
@@ -58,89 +99,104 @@ impl PragmaTracker {
         if idx > 0 { pragma_map[idx - 1].1.clone() } else { PragmaState::default() }
     }
 
-    fn build_ranges(
-        node: &Node,
-        current_state: &mut PragmaState,
-        ranges: &mut Vec<(Range<usize>, PragmaState)>,
-    ) {
-        match &node.kind {
-            NodeKind::Use { module, args, .. } => {
-                // Handle use statements
-                match module.as_str() {
-                    "strict" => {
-                        if args.is_empty() {
-                            // use strict; enables all categories
-                            current_state.strict_vars = true;
-                            current_state.strict_subs = true;
-                            current_state.strict_refs = true;
-                        } else {
-                            // Parse specific categories
-                            for arg in args {
-                                match arg.as_str() {
-                                    "vars" | "'vars'" | "\"vars\"" => {
-                                        current_state.strict_vars = true
-                                    }
-                                    "subs" | "'subs'" | "\"subs\"" => {
-                                        current_state.strict_subs = true
-                                    }
-                                    "refs" | "'refs'" | "\"refs\"" => {
-                                        current_state.strict_refs = true
-                                    }
-                                    _ => {}
-                                }
-                            }
-                        }
+    /// Apply a `use` or `no` statement to a pragma state
+    ///
+    /// Returns `true` when the node is a recognized pragma and the state was
+    /// updated. The parser calls this while parsing so that pragma-dependent
+    /// syntax (such as signatures versus prototypes) sees the state in effect.
+    pub fn apply(node: &Node, state: &mut PragmaState) -> bool {
+        let (module, args, enable) = match &node.kind {
+            NodeKind::Use { module, args, .. } => (module, args, true),
+            NodeKind::No { module, args, .. } => (module, args, false),
+            _ => return false,
+        };
 
-                        // Record the state change at this location
-                        ranges
-                            .push((node.location.start..node.location.end, current_state.clone()));
+        match module.as_str() {
+            "strict" => {
+                if args.is_empty() {
+                    // use strict; / no strict; toggles all categories
+                    state.strict_vars = enable;
+                    state.strict_subs = enable;
+                    state.strict_refs = enable;
+                } else {
+                    // Parse specific categories
+                    for arg in args {
+                        match arg.as_str() {
+                            "vars" | "'vars'" | "\"vars\"" => state.strict_vars = enable,
+                            "subs" | "'subs'" | "\"subs\"" => state.strict_subs = enable,
+                            "refs" | "'refs'" | "\"refs\"" => state.strict_refs = enable,
+                            _ => {}
+                        }
                     }
-                    "warnings" => {
-                        current_state.warnings = true;
-                        ranges
-                            .push((node.location.start..node.location.end, current_state.clone()));
+                }
+                true
+            }
+            "warnings" => {
+                state.warnings = enable;
+                true
+            }
+            "feature" | "experimental" => {
+                let mut changed = false;
+                for word in args.iter().flat_map(|arg| arg_words(arg)) {
+                    let bundle_minor = if module == "feature" {
+                        word.strip_prefix(':')
+                            .and_then(|bundle| version_minor(&format!("v{bundle}")))
+                    } else {
+                        None
+                    };
+                    let covers = |feature: &str, minor: u32| {
+                        word == feature
+                            || word == ":all"
+                            || bundle_minor.is_some_and(|m| m >= minor)
+                    };
+                    if covers("signatures", SIGNATURES_BUNDLE_MINOR) {
+                        state.signatures = enable;
+                        changed = true;
+                    }
+                    if covers("try", TRY_BUNDLE_MINOR) {
+                        state.try_catch = enable;
+                        changed = true;
                     }
-                    _ => {}
                 }
+                changed
             }
-            NodeKind::No { module, args, .. } => {
-                // Handle no statements
-                match module.as_str() {
-                    "strict" => {
-                        if args.is_empty() {
-                            // no strict; disables all categories
-                            current_state.strict_vars = false;
-                            current_state.strict_subs = false;
-                            current_state.strict_refs = false;
-                        } else {
-                            // Parse specific categories
-                            for arg in args {
-                                match arg.as_str() {
-                                    "vars" | "'vars'" | "\"vars\"" => {
-                                        current_state.strict_vars = false
-                                    }
-                                    "subs" | "'subs'" | "\"subs\"" => {
-                                        current_state.strict_subs = false
-                                    }
-                                    "refs" | "'refs'" | "\"refs\"" => {
-                                        current_state.strict_refs = false
-                                    }
-                                    _ => {}
-                                }
-                            }
-                        }
-
-                        // Record the state change at this location
-                        ranges
-                            .push((node.location.start..node.location.end, current_state.clone()));
+            module if TRY_MODULES.contains(&module) => {
+                state.try_catch = enable;
+                true
+            }
+            // use v5.36; / use 5.036; loads the feature bundle for that version,
+            // and from 5.12 (strict) and 5.35 (warnings) turns those pragmas on
+            version if enable => match version_minor(version) {
+                Some(minor) => {
+                    state.version_minor = Some(minor);
+                    state.signatures = minor >= SIGNATURES_BUNDLE_MINOR;
+                    // A bundle never turns off a `try` imported from a module
+                    state.try_catch |= minor >= TRY_BUNDLE_MINOR;
+                    if minor >= STRICT_BUNDLE_MINOR {
+                        state.strict_vars = true;
+                        state.strict_subs = true;
+                        state.strict_refs = true;
                     }
-                    "warnings" => {
-                        current_state.warnings = false;
-                        ranges
-                            .push((node.location.start..node.location.end, current_state.clone()));
+                    if minor >= WARNINGS_BUNDLE_MINOR {
+                        state.warnings = true;
                     }
-                    _ => {}
+                    true
                 }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    fn build_ranges(
+        node: &Node,
+        current_state: &mut PragmaState,
+        ranges: &mut Vec<(Range<usize>, PragmaState)>,
+    ) {
+        match &node.kind {
+            NodeKind::Use { .. } | NodeKind::No { .. } if Self::apply(node, current_state) => {
+                // Record the state change at this location
+                ranges.push((node.location.start..node.location.end, current_state.clone()));
             }
             NodeKind::Block { statements } => {
                 // Save current state
@@ -161,7 +217,7 @@ impl PragmaTracker {
                 }
             }
             // For subroutines and other container nodes, recurse into their bodies
-            NodeKind::Subroutine { body, .. } => {
+            NodeKind::Subroutine { body: Some(body), .. } => {
                 Self::build_ranges(body, current_state, ranges);
             }
             NodeKind::If { then_branch, else_branch, .. } => {
@@ -180,3 +236,84 @@ impl PragmaTracker {
         }
     }
 }
+
+/// Whether `module` names a pragma shipped with perl rather than a module
+///
+/// Shared by import grouping, unused-import detection and document links so
+/// they agree on which `use` lines are pragmas.
+pub fn is_pragma(module: &str) -> bool {
+    matches!(
+        module,
+        "attributes"
+            | "autodie"
+            | "autouse"
+            | "base"
+            | "bigint"
+            | "bignum"
+            | "bigrat"
+            | "blib"
+            | "bytes"
+            | "charnames"
+            | "constant"
+            | "diagnostics"
+            | "encoding"
+            | "feature"
+            | "fields"
+            | "filetest"
+            | "if"
+            | "integer"
+            | "less"
+            | "lib"
+            | "locale"
+            | "open"
+            | "ops"
+            | "overload"
+            | "parent"
+            | "re"
+            | "sigtrap"
+            | "sort"
+            | "strict"
+            | "subs"
+            | "threads"
+            | "utf8"
+            | "vars"
+            | "vmsish"
+            | "warnings"
+    )
+}
+
+/// Split a `use` argument into words: `qw(say signatures)`, `'signatures'`, `"signatures"`
+fn arg_words(arg: &str) -> Vec<&str> {
+    let arg = arg.trim();
+    if let Some(rest) = arg.strip_prefix("qw") {
+        let rest = rest.trim_start();
+        let inner = rest.get(1..rest.len().saturating_sub(1)).unwrap_or_default();
+        return inner.split_whitespace().collect();
+    }
+    vec![arg.trim_matches(|c| c == '\'' || c == '"')]
+}
+
+/// Perl 5 minor version of a version argument
+///
+/// Accepts `v5.36`, `v5.36.1`, `5.36.0`, `5.036` and the `v5 .36` form the
+/// parser records for a split version token.
+fn version_minor(version: &str) -> Option<u32> {
+    let version: String = version.chars().filter(|c| !c.is_whitespace()).collect();
+    let (is_vstring, digits) = match version.strip_prefix('v') {
+        Some(rest) => (true, rest.to_string()),
+        None => (version.matches('.').count() > 1, version),
+    };
+
+    let (major, rest) = digits.split_once('.').unwrap_or((digits.as_str(), ""));
+    if major != "5" || !rest.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        return None;
+    }
+
+    if is_vstring {
+        rest.split('.').next().unwrap_or_default().parse().ok().or(Some(0))
+    } else {
+        // Decimal versions use three digits per component: 5.036 == v5.36
+        let minor: String = rest.chars().chain(std::iter::repeat('0')).take(3).collect();
+        minor.parse().ok()
+    }
+}
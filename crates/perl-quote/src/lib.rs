@@ -390,6 +390,139 @@ pub fn extract_transliteration_parts(text: &str) -> (String, String, String) {
     (search, replacement, modifiers)
 }
 
+/// Quote-like operator words, longest first so `qq` wins over `q`
+const QUOTE_OPERATORS: &[&str] = &["qq", "qw", "qr", "qx", "tr", "q", "m", "s", "y"];
+
+/// One delimited body of a quote-like operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuoteSegment<'a> {
+    /// Body text between the delimiters, with escapes preserved
+    pub text: &'a str,
+    /// Byte offset of the body in the parsed input
+    pub start: usize,
+    /// Byte offset just past the body, before its closing delimiter
+    pub end: usize,
+}
+
+/// A quote-like operator split into its operator, delimiters, bodies and modifiers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuoteLike<'a> {
+    /// Operator word: `q`, `qq`, `qw`, `qx`, `qr`, `m`, `s`, `tr` or `y`
+    pub operator: &'a str,
+    /// Opening delimiter of the first segment
+    pub open: char,
+    /// Closing delimiter of the first segment
+    pub close: char,
+    /// One segment for `q`/`qq`/`qw`/`qx`/`qr`/`m`, two for `s`/`tr`/`y`
+    pub segments: Vec<QuoteSegment<'a>>,
+    /// Modifier letters as written; not validated
+    pub modifiers: &'a str,
+    /// Byte offset just past the whole construct in the parsed input
+    pub end: usize,
+}
+
+impl<'a> QuoteLike<'a> {
+    /// The regex pattern segment of `m`, `qr` and `s`; `None` for other operators
+    pub fn pattern(&self) -> Option<&QuoteSegment<'a>> {
+        match self.operator {
+            "m" | "qr" | "s" => self.segments.first(),
+            _ => None,
+        }
+    }
+}
+
+/// Delimiter-aware extraction of quote-like operators from source text
+///
+/// Unlike the `extract_*` helpers, which take a single lexer token, this
+/// locates the construct in surrounding text and reports byte offsets, so
+/// tools such as linters can validate the pattern segment in place.
+///
+/// ```
+/// use perl_quote::QuoteParser;
+///
+/// let src = "$s =~ s{foo}{bar}gi;";
+/// let quote = QuoteParser::new().parse_quote_like(src, 6).ok_or("not a quote")?;
+/// assert_eq!(quote.segments[0].text, "foo");
+/// assert_eq!(quote.segments[1].text, "bar");
+/// assert_eq!(quote.modifiers, "gi");
+/// assert_eq!(&src[quote.end..], ";");
+/// # Ok::<(), &str>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct QuoteParser;
+
+impl QuoteParser {
+    /// Create a quote parser
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Parse the quote-like operator whose operator word starts at byte `start` of `input`
+    ///
+    /// Returns `None` when no quote-like operator starts there or a body is
+    /// unterminated. The operator word must be directly followed by its
+    /// delimiter; `y => 1` and other fat-comma keys are not quotes.
+    pub fn parse_quote_like<'a>(&self, input: &'a str, start: usize) -> Option<QuoteLike<'a>> {
+        let text = input.get(start..)?;
+        let operator = QUOTE_OPERATORS.iter().copied().find(|op| {
+            text.strip_prefix(op).is_some_and(|rest| {
+                !rest.starts_with("=>")
+                    && rest
+                        .chars()
+                        .next()
+                        .is_some_and(|c| !c.is_alphanumeric() && !c.is_whitespace() && c != '_')
+            })
+        })?;
+
+        let body_at = start + operator.len();
+        let open = input[body_at..].chars().next()?;
+        let close = get_closing_delimiter(open);
+        let (first, mut rest) = delimited_segment(input, body_at, open, close)?;
+        let mut segments = vec![first];
+
+        if matches!(operator, "s" | "tr" | "y") {
+            let (second, after) = if open == close {
+                // The closing delimiter doubles as the opening one of the replacement
+                delimited_segment(input, first.end, open, close)?
+            } else {
+                // Bracketed patterns give the replacement its own delimiters: s{a} [b]
+                let trimmed = rest.trim_start();
+                let repl_open = trimmed.chars().next()?;
+                let at = input.len() - trimmed.len();
+                delimited_segment(input, at, repl_open, get_closing_delimiter(repl_open))?
+            };
+            segments.push(second);
+            rest = after;
+        }
+
+        let modifiers = if matches!(operator, "q" | "qq" | "qw" | "qx") {
+            ""
+        } else {
+            let len = rest.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(rest.len());
+            &rest[..len]
+        };
+        let end = input.len() - rest.len() + modifiers.len();
+
+        Some(QuoteLike { operator, open, close, segments, modifiers, end })
+    }
+}
+
+/// Locate one delimited body starting at the opening delimiter at byte `at`
+fn delimited_segment(
+    input: &str,
+    at: usize,
+    open: char,
+    close: char,
+) -> Option<(QuoteSegment<'_>, &str)> {
+    let (_, rest, found_closing) = extract_delimited_content_strict(&input[at..], open, close);
+    if !found_closing {
+        return None;
+    }
+    let start = at + open.len_utf8();
+    let end = input.len() - rest.len() - close.len_utf8();
+    Some((QuoteSegment { text: &input[start..end], start, end }, rest))
+}
+
 /// Get the closing delimiter for a given opening delimiter
 fn get_closing_delimiter(open: char) -> char {
     match open {
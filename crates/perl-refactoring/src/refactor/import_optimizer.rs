@@ -41,6 +41,10 @@
 //! # Ok::<(), String>(())
 //! ```
 
+use perl_parser_core::builtins::builtin_signatures_phf::is_builtin;
+use perl_parser_core::pragma_tracker::is_pragma;
+use perl_parser_core::{Node, NodeKind, Parser};
+use perl_semantic_analyzer::symbol::{SymbolExtractor, SymbolKind};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, BTreeSet};
@@ -146,36 +150,64 @@ pub enum SuggestionPriority {
 /// The optimizer currently supports:
 /// - Parsing basic `use Module qw(symbols)` statements
 /// - Detecting unused imported symbols
+/// - Trimming or removing `use` statements whose symbols are unused
+/// - Suggesting imports for calls to well-known module exports
 /// - Finding duplicate imports that can be merged
 /// - Generating consolidated import statements
-pub struct ImportOptimizer;
-
-/// Check if a module is a pragma (affects compilation, no exports)
-fn is_pragma_module(module: &str) -> bool {
-    matches!(
-        module,
-        "strict"
-            | "warnings"
-            | "utf8"
-            | "bytes"
-            | "locale"
-            | "integer"
-            | "less"
-            | "sigtrap"
-            | "subs"
-            | "vars"
-            | "feature"
-            | "autodie"
-            | "autouse"
-            | "base"
-            | "parent"
-            | "lib"
-            | "bigint"
-            | "bignum"
-            | "bigrat"
-    )
+pub struct ImportOptimizer {
+    /// Module exporting each unqualified function name, used for missing imports
+    function_modules: BTreeMap<String, String>,
 }
 
+/// Well-known module exports used to suggest imports for unqualified calls
+const WELL_KNOWN_EXPORTS: &[(&str, &[&str])] = &[
+    (
+        "List::Util",
+        &[
+            "first",
+            "sum",
+            "sum0",
+            "max",
+            "min",
+            "maxstr",
+            "minstr",
+            "reduce",
+            "shuffle",
+            "uniq",
+            "uniqnum",
+            "any",
+            "all",
+            "none",
+            "notall",
+            "product",
+            "pairs",
+            "pairkeys",
+            "pairvalues",
+            "pairmap",
+        ],
+    ),
+    (
+        "Scalar::Util",
+        &["blessed", "reftype", "looks_like_number", "weaken", "dualvar", "refaddr", "readonly"],
+    ),
+    ("Data::Dumper", &["Dumper"]),
+    ("Carp", &["croak", "confess", "carp", "cluck"]),
+    ("File::Basename", &["basename", "dirname", "fileparse"]),
+    ("File::Spec::Functions", &["catfile", "catdir", "splitdir", "rel2abs", "abs2rel"]),
+    ("File::Path", &["make_path", "remove_tree", "mkpath", "rmtree"]),
+    ("File::Temp", &["tempfile", "tempdir"]),
+    ("File::Copy", &["copy", "move"]),
+    ("Cwd", &["getcwd", "abs_path", "realpath"]),
+    ("POSIX", &["floor", "ceil", "fmod", "strftime", "INT_MAX", "DBL_MAX"]),
+    ("Time::HiRes", &["usleep", "nanosleep", "gettimeofday", "tv_interval"]),
+    ("Storable", &["store", "retrieve", "freeze", "thaw", "dclone"]),
+    ("JSON", &["encode_json", "decode_json", "to_json", "from_json"]),
+    ("Encode", &["encode", "decode", "encode_utf8", "decode_utf8"]),
+    ("MIME::Base64", &["encode_base64", "decode_base64"]),
+    ("Digest::MD5", &["md5", "md5_hex", "md5_base64"]),
+    ("URI::Escape", &["uri_escape", "uri_unescape"]),
+];
+
 /// Get known exports for popular Perl modules
 fn get_known_module_exports(module: &str) -> Option<Vec<&'static str>> {
     match module {
@@ -203,6 +235,123 @@ fn get_known_module_exports(module: &str) -> Option<Vec<&'static str>> {
     }
 }
 
+/// Whether an import brings `function` into scope
+///
+/// An explicit list imports exactly what it names; a bare `use Module;`
+/// imports the module's default exports.
+fn imports_symbol(import: &ImportEntry, function: &str) -> bool {
+    if import.symbols.is_empty() {
+        get_known_module_exports(&import.module).is_some_and(|exports| exports.contains(&function))
+    } else {
+        import.symbols.iter().any(|sym| sym.trim_start_matches('&') == function)
+    }
+}
+
+/// Whether an entry of an explicit import list names a single symbol
+///
+/// Export tags (`:all`), import options (`-norequire`) and version numbers
+/// select whole groups or change behaviour, so they are never reported.
+fn is_plain_import_symbol(symbol: &str) -> bool {
+    let name = symbol.trim_start_matches(['$', '@', '%', '&', '*']);
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Names the code refers to and defines, gathered from the parsed file
+struct FileUsage {
+    /// Every name the code refers to
+    used_names: BTreeSet<String>,
+    /// Unqualified names called as functions
+    called_functions: BTreeSet<String>,
+    /// Subroutines defined in the file
+    defined_subs: BTreeSet<String>,
+}
+
+impl FileUsage {
+    /// Collect the file's names using the semantic symbol table
+    ///
+    /// The symbol table records function calls, method names, `\&name` and
+    /// variables, including those interpolated into strings. Method names
+    /// count because an imported sub is also callable as `__PACKAGE__->name`.
+    /// Barewords such as the `first` in `first { ... } @list` and calls inside
+    /// `@{[ ... ]}` interpolation blocks are not in the table, so they are
+    /// added from the AST. Returns `None` when the content does not parse.
+    fn collect(content: &str) -> Option<Self> {
+        let ast = Parser::new(content).parse().ok()?;
+        let table = SymbolExtractor::new_with_source(content).extract(&ast);
+
+        let mut used_names: BTreeSet<String> = table.references.keys().cloned().collect();
+        collect_barewords(&ast, &mut used_names);
+
+        let mut called_functions = BTreeSet::new();
+        collect_function_calls(&ast, &mut called_functions);
+
+        let defined_subs = table
+            .symbols
+            .values()
+            .flatten()
+            .filter(|symbol| symbol.kind == SymbolKind::Subroutine)
+            .map(|symbol| symbol.name.clone())
+            .collect();
+
+        Some(Self { used_names, called_functions, defined_subs })
+    }
+}
+
+fn collect_barewords(node: &Node, names: &mut BTreeSet<String>) {
+    match &node.kind {
+        NodeKind::Use { .. } | NodeKind::No { .. } => return,
+        NodeKind::Identifier { name } => {
+            names.insert(name.clone());
+        }
+        NodeKind::String { value, interpolated: true } => {
+            for block in interpolated_code_blocks(value) {
+                names.extend(
+                    block
+                        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                        .filter(|word| is_plain_import_symbol(word))
+                        .map(str::to_string),
+                );
+            }
+        }
+        _ => {}
+    }
+    node.for_each_child(|child| collect_barewords(child, names));
+}
+
+/// Collect unqualified function names called within `node`
+///
+/// Besides ordinary calls this covers the block form `first { ... } @list`,
+/// which parses as a bareword subscripted by the block.
+fn collect_function_calls(node: &Node, names: &mut BTreeSet<String>) {
+    match &node.kind {
+        NodeKind::Use { .. } | NodeKind::No { .. } => return,
+        NodeKind::FunctionCall { name, .. } if !name.contains("::") => {
+            names.insert(name.clone());
+        }
+        NodeKind::Binary { op, left, .. } if op == "{}" => {
+            if let NodeKind::Identifier { name } = &left.kind {
+                names.insert(name.clone());
+            }
+        }
+        _ => {}
+    }
+    node.for_each_child(|child| collect_function_calls(child, names));
+}
+
+/// The code inside `@{[ ... ]}` and `${\ ... }` blocks of an interpolated string
+fn interpolated_code_blocks(value: &str) -> Vec<&str> {
+    let mut blocks = Vec::new();
+    let mut rest = value;
+    while let Some(start) = [rest.find("@{["), rest.find("${\\")].into_iter().flatten().min() {
+        let body = &rest[start + 3..];
+        let end = body.find('}').unwrap_or(body.len());
+        blocks.push(&body[..end]);
+        rest = &body[end..];
+    }
+    blocks
+}
+
 impl ImportOptimizer {
     /// Create a new import optimizer for Analyze-stage refactorings.
     ///
@@ -219,7 +368,36 @@ impl ImportOptimizer {
     /// let _ = optimizer;
     /// ```
     pub fn new() -> Self {
-        Self
+        let function_modules = WELL_KNOWN_EXPORTS
+            .iter()
+            .flat_map(|(module, functions)| {
+                functions.iter().map(|function| (function.to_string(), module.to_string()))
+            })
+            .collect();
+        Self { function_modules }
+    }
+
+    /// Register functions exported by a module for missing-import suggestions.
+    ///
+    /// Extends (or overrides) the built-in map of well-known exports, so an
+    /// unqualified call to one of `functions` suggests importing it from
+    /// `module`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use perl_parser::import_optimizer::ImportOptimizer;
+    ///
+    /// let optimizer = ImportOptimizer::new().with_module_exports("My::Utils", &["slugify"]);
+    /// let analysis = optimizer.analyze_content("print slugify($title);\n")?;
+    /// assert_eq!(analysis.missing_imports[0].module, "My::Utils");
+    /// # Ok::<(), String>(())
+    /// ```
+    pub fn with_module_exports(mut self, module: &str, functions: &[&str]) -> Self {
+        for function in functions {
+            self.function_modules.insert(function.to_string(), module.to_string());
+        }
+        self
     }
 
     /// Analyze imports in a Perl file during the Analyze stage.
@@ -316,39 +494,34 @@ impl ImportOptimizer {
         // Pre-compile regex for special Data::Dumper case
         let dumper_re = Regex::new(r"\bDumper\b").map_err(|e| e.to_string())?;
 
+        // Names referenced by the code; falls back to text matching if parsing fails
+        let usage = FileUsage::collect(content);
+
         // Determine unused symbols for each import entry
         let mut unused_imports = Vec::new();
         for imp in &imports {
             let mut unused_symbols = Vec::new();
 
-            // If there are explicit symbols (like qw()), check each one
+            // An explicit list (like qw()) replaces the module's @EXPORT defaults,
+            // so each listed symbol must be used on its own
             if !imp.symbols.is_empty() {
-                for sym in &imp.symbols {
-                    let re = Regex::new(&format!(r"\b{}\b", regex::escape(sym)))
-                        .map_err(|e| e.to_string())?;
+                for sym in imp.symbols.iter().filter(|sym| is_plain_import_symbol(sym)) {
+                    let is_used = match &usage {
+                        Some(usage) => usage
+                            .used_names
+                            .contains(sym.trim_start_matches(['$', '@', '%', '&', '*'])),
+                        None => Regex::new(&format!(r"\b{}\b", regex::escape(sym)))
+                            .map_err(|e| e.to_string())?
+                            .is_match(&non_use_content),
+                    };
 
-                    // Check if symbol is used in non-use content
-                    if !re.is_match(&non_use_content) {
+                    if !is_used {
                         unused_symbols.push(sym.clone());
                     }
                 }
             } else {
                 // Skip pragma modules like strict, warnings, etc.
-                let is_pragma = matches!(
-                    imp.module.as_str(),
-                    "strict"
-                        | "warnings"
-                        | "utf8"
-                        | "bytes"
-                        | "integer"
-                        | "locale"
-                        | "overload"
-                        | "sigtrap"
-                        | "subs"
-                        | "vars"
-                );
-
-                if !is_pragma {
+                if !is_pragma(&imp.module) {
                     // For bare imports (without qw()), check if the module or any of its known exports are used
                     let (is_known_module, known_exports) =
                         match get_known_module_exports(&imp.module) {
@@ -418,9 +591,10 @@ impl ImportOptimizer {
         let imported_modules: BTreeSet<String> =
             imports.iter().map(|imp| imp.module.clone()).collect();
 
-        // Strip strings and comments before scanning for Module::symbol patterns
+        // Strip `use` lines, strings and comments before scanning for Module::symbol
+        // patterns, so `use List::Util` itself is not read as a use of `List`
         let string_re = Regex::new("'[^']*'|\"[^\"]*\"").map_err(|e| e.to_string())?;
-        let stripped = string_re.replace_all(content, " ").to_string();
+        let stripped = string_re.replace_all(&non_use_content, " ").to_string();
         let regex_literal_re = Regex::new(r"qr/[^/]*/").map_err(|e| e.to_string())?;
         let stripped = regex_literal_re.replace_all(&stripped, " ").to_string();
         let comment_re = Regex::new(r"(?m)#.*$").map_err(|e| e.to_string())?;
@@ -437,13 +611,29 @@ impl ImportOptimizer {
                 let module = module_match.as_str().to_string();
                 let symbol = symbol_match.as_str().to_string();
 
-                if imported_modules.contains(&module) || is_pragma_module(&module) {
+                if imported_modules.contains(&module) || is_pragma(&module) {
                     continue;
                 }
 
                 usage_map.entry(module).or_default().push(symbol);
             }
         }
+
+        // Unqualified calls to well-known exports that nothing imports or defines
+        if let Some(usage) = &usage {
+            for function in &usage.called_functions {
+                if usage.defined_subs.contains(function)
+                    || is_builtin(function)
+                    || imports.iter().any(|imp| imports_symbol(imp, function))
+                {
+                    continue;
+                }
+                if let Some(module) = self.function_modules.get(function) {
+                    usage_map.entry(module.clone()).or_default().push(function.clone());
+                }
+            }
+        }
+
         let last_import_line = imports.iter().map(|i| i.line).max().unwrap_or(0);
         let missing_imports = usage_map
             .into_iter()
@@ -658,6 +848,165 @@ impl ImportOptimizer {
         }]
     }
 
+    /// Generate text edits that drop unused symbols from their `use` statements.
+    ///
+    /// Each import with unused symbols gets one edit: its `qw(...)` list is
+    /// trimmed to the symbols still in use, or the whole `use` line is removed
+    /// when nothing it imports is used. Unlike [`Self::generate_edits`], the
+    /// remaining imports are left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Original Perl source content.
+    /// * `analysis` - Import analysis results for `content`.
+    ///
+    /// # Returns
+    ///
+    /// Text edits to apply to the source document, in source order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use perl_parser::import_optimizer::ImportOptimizer;
+    ///
+    /// let content = "use List::Util qw(max min);\nprint max(1, 2);\n";
+    /// let optimizer = ImportOptimizer::new();
+    /// let analysis = optimizer.analyze_content(content)?;
+    /// let edits = optimizer.generate_unused_import_edits(content, &analysis);
+    /// assert_eq!(edits[0].new_text, "max");
+    /// # Ok::<(), String>(())
+    /// ```
+    pub fn generate_unused_import_edits(
+        &self,
+        content: &str,
+        analysis: &ImportAnalysis,
+    ) -> Vec<TextEdit> {
+        let mut edits = Vec::new();
+        for unused in &analysis.unused_imports {
+            let Some(import) = analysis
+                .imports
+                .iter()
+                .find(|imp| imp.line == unused.line && imp.module == unused.module)
+            else {
+                continue;
+            };
+
+            let line_start = self.line_offset(content, unused.line);
+            let line_end = self.line_offset(content, unused.line + 1).min(content.len());
+            let kept: Vec<&str> = import
+                .symbols
+                .iter()
+                .filter(|sym| !unused.symbols.contains(sym))
+                .map(String::as_str)
+                .collect();
+
+            if kept.is_empty() {
+                edits.push(TextEdit { range: (line_start, line_end), new_text: String::new() });
+                continue;
+            }
+
+            let line = &content[line_start..line_end];
+            if let Some(open) = line.find("qw(")
+                && let Some(close) = line[open..].find(')')
+            {
+                edits.push(TextEdit {
+                    range: (line_start + open + 3, line_start + open + close),
+                    new_text: kept.join(" "),
+                });
+            }
+        }
+        edits
+    }
+
+    /// Generate text edits that add the imports reported as missing.
+    ///
+    /// Symbols for a module that already has a `use Module qw(...)` statement
+    /// are appended to its list. Other modules get a new `use Module qw(...);`
+    /// line after the last existing `use` statement, or after the shebang line
+    /// when the file has no imports yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Original Perl source content.
+    /// * `analysis` - Import analysis results for `content`.
+    ///
+    /// # Returns
+    ///
+    /// Text edits to apply to the source document, in source order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use perl_parser::import_optimizer::ImportOptimizer;
+    ///
+    /// let content = "use strict;\nmy $x = first { $_ } @ARGV;\n";
+    /// let optimizer = ImportOptimizer::new();
+    /// let analysis = optimizer.analyze_content(content)?;
+    /// let edits = optimizer.generate_missing_import_edits(content, &analysis);
+    /// assert_eq!(edits[0].new_text, "use List::Util qw(first);\n");
+    /// # Ok::<(), String>(())
+    /// ```
+    pub fn generate_missing_import_edits(
+        &self,
+        content: &str,
+        analysis: &ImportAnalysis,
+    ) -> Vec<TextEdit> {
+        let mut edits = Vec::new();
+        let mut new_lines = Vec::new();
+
+        for missing in &analysis.missing_imports {
+            if missing.symbols.is_empty() {
+                continue;
+            }
+
+            let existing_list = analysis
+                .imports
+                .iter()
+                .filter(|imp| imp.module == missing.module && !imp.symbols.is_empty())
+                .find_map(|imp| {
+                    let line_start = self.line_offset(content, imp.line);
+                    let line = content[line_start..].lines().next().unwrap_or("");
+                    let open = line.find("qw(")?;
+                    let close = line[open..].find(')')?;
+                    Some(line_start + open + close)
+                });
+
+            match existing_list {
+                Some(close) => {
+                    let symbols = missing.symbols.join(" ");
+                    let needs_space = !content[..close].ends_with(['(', ' ']);
+                    edits.push(TextEdit {
+                        range: (close, close),
+                        new_text: if needs_space { format!(" {}", symbols) } else { symbols },
+                    });
+                }
+                None => new_lines.push(format!(
+                    "use {} qw({});",
+                    missing.module,
+                    missing.symbols.join(" ")
+                )),
+            }
+        }
+
+        if !new_lines.is_empty() {
+            let last_import_line = analysis.imports.iter().map(|i| i.line).max();
+            let insert_line = match last_import_line {
+                Some(line) => line + 1,
+                None if content.starts_with("#!") => 2,
+                None => 1,
+            };
+            let offset = self.line_offset(content, insert_line).min(content.len());
+            let mut new_text = new_lines.join("\n") + "\n";
+            if offset == content.len() && !content.is_empty() && !content.ends_with('\n') {
+                new_text.insert(0, '\n');
+            }
+            edits.push(TextEdit { range: (offset, offset), new_text });
+        }
+
+        edits.sort_by_key(|edit| edit.range.0);
+        edits
+    }
+
     fn line_offset(&self, content: &str, line: usize) -> usize {
         if line <= 1 {
             return 0;
@@ -1040,4 +1389,150 @@ my $partial = "::Function";
         assert_eq!(analysis.missing_imports[0].module, "JSON");
         Ok(())
     }
+
+    fn apply_edits(content: &str, edits: &[TextEdit]) -> String {
+        let mut result = content.to_string();
+        for edit in edits.iter().rev() {
+            result.replace_range(edit.range.0..edit.range.1, &edit.new_text);
+        }
+        result
+    }
+
+    #[test]
+    fn test_fully_unused_qw_import_is_removed() -> Result<(), Box<dyn std::error::Error>> {
+        let optimizer = ImportOptimizer::new();
+        let content = r#"use strict;
+use List::Util qw(max min);
+use Data::Dumper qw(Dumper);
+
+print Dumper([1, 2]);
+print "max and min are only words here\n";
+"#;
+
+        let analysis = optimizer.analyze_content(content)?;
+        assert_eq!(analysis.unused_imports.len(), 1);
+        assert_eq!(analysis.unused_imports[0].module, "List::Util");
+        assert_eq!(analysis.unused_imports[0].symbols, vec!["max", "min"]);
+
+        let edits = optimizer.generate_unused_import_edits(content, &analysis);
+        assert_eq!(
+            apply_edits(content, &edits),
+            r#"use strict;
+use Data::Dumper qw(Dumper);
+
+print Dumper([1, 2]);
+print "max and min are only words here\n";
+"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_partially_used_qw_import_is_trimmed() -> Result<(), Box<dyn std::error::Error>> {
+        let optimizer = ImportOptimizer::new();
+        let content = r#"use Scalar::Util qw(blessed reftype weaken :all);
+use List::Util qw(first sum);
+
+my $hit = first { $_ > 1 } @ARGV;
+print "object\n" if blessed $hit;
+$hit->weaken;
+"#;
+
+        let analysis = optimizer.analyze_content(content)?;
+        let mut unused: Vec<_> = analysis
+            .unused_imports
+            .iter()
+            .map(|u| (u.module.as_str(), u.symbols.clone()))
+            .collect();
+        unused.sort();
+        // Export tags are never reported, and a method name counts as a use
+        assert_eq!(
+            unused,
+            vec![
+                ("List::Util", vec!["sum".to_string()]),
+                ("Scalar::Util", vec!["reftype".to_string()])
+            ]
+        );
+
+        let edits = optimizer.generate_unused_import_edits(content, &analysis);
+        let result = apply_edits(content, &edits);
+        assert!(
+            result.starts_with(
+                "use Scalar::Util qw(blessed weaken :all);\nuse List::Util qw(first);\n"
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_used_in_interpolation_is_kept() -> Result<(), Box<dyn std::error::Error>> {
+        let optimizer = ImportOptimizer::new();
+        let content = r#"use My::Meta qw($VERSION %INFO render);
+
+print "version $VERSION by $INFO{author}\n";
+print "page: @{[ render('index') ]}\n";
+"#;
+
+        let analysis = optimizer.analyze_content(content)?;
+        assert!(
+            analysis.unused_imports.is_empty(),
+            "unexpected unused imports: {:?}",
+            analysis.unused_imports
+        );
+        assert!(optimizer.generate_unused_import_edits(content, &analysis).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bare_block_call_suggests_missing_import() -> Result<(), Box<dyn std::error::Error>> {
+        let optimizer = ImportOptimizer::new();
+        let content = r#"#!/usr/bin/perl
+use strict;
+use warnings;
+
+sub max { return 42 }
+
+my $hit = first { $_ > max() } sort @ARGV;
+"#;
+
+        let analysis = optimizer.analyze_content(content)?;
+        // `max` is defined locally and `sort` is a builtin, so only `first` is missing
+        assert_eq!(analysis.missing_imports.len(), 1);
+        assert_eq!(analysis.missing_imports[0].module, "List::Util");
+        assert_eq!(analysis.missing_imports[0].symbols, vec!["first"]);
+        assert_eq!(analysis.missing_imports[0].suggested_location, 4);
+
+        let edits = optimizer.generate_missing_import_edits(content, &analysis);
+        assert!(apply_edits(content, &edits).starts_with(
+            "#!/usr/bin/perl\nuse strict;\nuse warnings;\nuse List::Util qw(first);\n\nsub max"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_import_merges_into_existing_list() -> Result<(), Box<dyn std::error::Error>> {
+        let optimizer = ImportOptimizer::new().with_module_exports("My::Text", &["slugify"]);
+        let content = r#"use strict;
+use List::Util qw(max);
+
+my $top = max(@ARGV);
+my $hit = first { $_ eq $top } @ARGV;
+print sum(1, 2), slugify($hit);
+"#;
+
+        let analysis = optimizer.analyze_content(content)?;
+        let edits = optimizer.generate_missing_import_edits(content, &analysis);
+        assert_eq!(
+            apply_edits(content, &edits),
+            r#"use strict;
+use List::Util qw(max first sum);
+use My::Text qw(slugify);
+
+my $top = max(@ARGV);
+my $hit = first { $_ eq $top } @ARGV;
+print sum(1, 2), slugify($hit);
+"#
+        );
+        Ok(())
+    }
 }
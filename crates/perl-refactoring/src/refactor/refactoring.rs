@@ -1851,19 +1851,16 @@ fn visit_node(
             if let Some(sig) = signature {
                 visit_node(sig, start, end, inputs, outputs, &mut inner_scope, declared_in_range);
             }
-            visit_node(body, start, end, inputs, outputs, &mut inner_scope, declared_in_range);
+            if let Some(body) = body {
+                visit_node(body, start, end, inputs, outputs, &mut inner_scope, declared_in_range);
+            }
         }
         NodeKind::Try { body, catch_blocks, finally_block } => {
             visit_node(body, start, end, inputs, outputs, declared_in_scope, declared_in_range);
             for (var, catch_body) in catch_blocks {
                 let mut inner_scope = declared_in_scope.clone();
-                if let Some(v_name) = var {
-                    // Check if v_name has sigil, if not assume $
-                    let full_name = if v_name.starts_with(['$', '@', '%']) {
-                        v_name.clone()
-                    } else {
-                        format!("${}", v_name)
-                    };
+                if let Some(NodeKind::Variable { sigil, name }) = var.as_deref().map(|v| &v.kind) {
+                    let full_name = format!("{}{}", sigil, name);
                     if in_range {
                         declared_in_range.insert(full_name);
                     } else {
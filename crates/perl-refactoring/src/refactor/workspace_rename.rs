@@ -18,6 +18,7 @@
 //! - **Atomic operations**: All-or-nothing changes with automatic rollback
 //! - **Scope-aware**: Respects Perl package namespaces and lexical scoping
 //! - **Dual indexing**: Finds both qualified (`Package::sub`) and bare (`sub`) references
+//! - **Package rename**: Updates `use`/`require`/qualified references and moves the mirrored `.pm` file
 //! - **Progress reporting**: Real-time feedback during large operations
 //! - **Backup support**: Optional backup creation for safety
 //!
@@ -115,6 +116,18 @@ pub struct WorkspaceRenameResult {
     pub warnings: Vec<String>,
     /// Operation statistics
     pub statistics: RenameStatistics,
+    /// Files to move so their paths keep mirroring a renamed package
+    #[serde(default)]
+    pub file_renames: Vec<FileRename>,
+}
+
+/// A file move that accompanies a package rename
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileRename {
+    /// Current file path (e.g. `lib/Foo/Bar.pm`)
+    pub old_path: PathBuf,
+    /// Path mirroring the new package name (e.g. `lib/Foo/Baz.pm`)
+    pub new_path: PathBuf,
 }
 
 /// Statistics for a rename operation
@@ -219,6 +232,14 @@ pub enum WorkspaceRenameError {
         path: Option<PathBuf>,
     },
 
+    /// New name is not a valid identifier for the symbol
+    InvalidName {
+        /// Rejected name
+        name: String,
+        /// Why the name was rejected
+        reason: String,
+    },
+
     /// Feature not yet implemented
     NotImplemented {
         /// Description of unimplemented feature
@@ -264,6 +285,9 @@ impl std::fmt::Display for WorkspaceRenameError {
                     write!(f, "Security error: {}", message)
                 }
             }
+            WorkspaceRenameError::InvalidName { name, reason } => {
+                write!(f, "Invalid name '{}': {}", name, reason)
+            }
             WorkspaceRenameError::NotImplemented { feature } => {
                 write!(f, "Feature not yet implemented: {}", feature)
             }
@@ -597,6 +621,173 @@ impl WorkspaceRename {
             description: format!("Rename '{}' to '{}'", old_name, new_name),
             warnings: vec![],
             statistics: RenameStatistics { files_modified, total_changes, elapsed_ms },
+            file_renames: vec![],
+        })
+    }
+
+    /// Rename a package across the workspace
+    ///
+    /// Updates the `package` declaration and every reference to the package
+    /// name: `use`/`require`/`no` statements, class method calls such as
+    /// `Foo::Bar->new`, qualified names such as `$Foo::Bar::var`, quoted
+    /// module names (`use parent -norequire, 'Foo::Bar'`) and `Foo/Bar.pm`
+    /// paths. A match must cover whole namespace segments, so renaming
+    /// `Foo::Bar` leaves `Foo::BarBaz` and `Other::Foo::Bar` untouched.
+    ///
+    /// When a file declaring the package lives at a path ending in
+    /// `Foo/Bar.pm`, the result also lists the move to the path for the new
+    /// name in `file_renames`. Apply it with [`Self::apply_file_renames`]
+    /// after [`Self::apply_edits`].
+    ///
+    /// # Arguments
+    /// * `old_name` - Current package name (e.g. `Foo::Bar`)
+    /// * `new_name` - New package name (e.g. `Foo::Baz`)
+    ///
+    /// # Errors
+    /// * `InvalidName` - `new_name` is not a valid package name
+    /// * `NameConflict` - A package named `new_name` already exists
+    /// * `SymbolNotFound` - No indexed file mentions the package
+    /// * `Timeout` - Operation exceeded configured timeout
+    pub fn rename_package(
+        &self,
+        old_name: &str,
+        new_name: &str,
+    ) -> Result<WorkspaceRenameResult, WorkspaceRenameError> {
+        let start = Instant::now();
+        let timeout = std::time::Duration::from_secs(self.config.operation_timeout);
+
+        if !is_package_name(new_name) {
+            return Err(WorkspaceRenameError::InvalidName {
+                name: new_name.to_string(),
+                reason: "package names are '::'-separated identifiers".to_string(),
+            });
+        }
+
+        let packages: Vec<_> = self
+            .index
+            .all_symbols()
+            .into_iter()
+            .filter(|symbol| symbol.kind == crate::workspace_index::SymbolKind::Package)
+            .collect();
+
+        let conflicts: Vec<ConflictLocation> = packages
+            .iter()
+            .filter(|symbol| symbol.name == new_name)
+            .map(|symbol| ConflictLocation {
+                file: crate::workspace_index::uri_to_fs_path(&symbol.uri).unwrap_or_default(),
+                line: symbol.range.start.line,
+                column: symbol.range.start.column,
+                existing_symbol: symbol.name.clone(),
+            })
+            .collect();
+        if !conflicts.is_empty() {
+            return Err(WorkspaceRenameError::NameConflict {
+                new_name: new_name.to_string(),
+                conflicts,
+            });
+        }
+
+        let old_module_path = module_path(old_name);
+        let new_module_path = module_path(new_name);
+
+        let store = self.index.document_store();
+        let all_docs = store.all_documents();
+        let total_files = all_docs.len();
+
+        let mut edits_by_file: BTreeMap<PathBuf, Vec<TextEdit>> = BTreeMap::new();
+        for (files_processed, doc) in all_docs.iter().enumerate() {
+            if start.elapsed() > timeout {
+                return Err(WorkspaceRenameError::Timeout {
+                    elapsed_seconds: start.elapsed().as_secs(),
+                    files_processed,
+                    total_files,
+                });
+            }
+            if self.config.max_files > 0 && files_processed >= self.config.max_files {
+                break;
+            }
+
+            let Some(path) = crate::workspace_index::uri_to_fs_path(&doc.uri) else {
+                continue;
+            };
+
+            let mut edits: Vec<TextEdit> = find_package_occurrences(&doc.text, old_name)
+                .into_iter()
+                .map(|(start, end)| TextEdit { start, end, new_text: new_name.to_string() })
+                .collect();
+            edits.extend(
+                find_module_path_occurrences(&doc.text, &old_module_path)
+                    .into_iter()
+                    .map(|(start, end)| TextEdit { start, end, new_text: new_module_path.clone() }),
+            );
+
+            if !edits.is_empty() {
+                edits_by_file.entry(path).or_default().extend(edits);
+            }
+        }
+
+        if edits_by_file.is_empty() {
+            return Err(WorkspaceRenameError::SymbolNotFound {
+                symbol: old_name.to_string(),
+                file: "workspace".to_string(),
+            });
+        }
+
+        // Move files whose path mirrors the package, e.g. lib/Foo/Bar.pm for Foo::Bar
+        let mut file_renames: Vec<FileRename> = Vec::new();
+        let depth = old_name.split("::").count();
+        for symbol in packages.iter().filter(|symbol| symbol.name == old_name) {
+            let Some(old_path) = crate::workspace_index::uri_to_fs_path(&symbol.uri) else {
+                continue;
+            };
+            if !old_path.ends_with(&old_module_path) {
+                continue;
+            }
+            if let Some(root) = old_path.ancestors().nth(depth) {
+                let rename = FileRename { new_path: root.join(&new_module_path), old_path };
+                if !file_renames.contains(&rename) {
+                    file_renames.push(rename);
+                }
+            }
+        }
+
+        let mut warnings = Vec::new();
+        for rename in &file_renames {
+            if rename.new_path.exists() {
+                warnings.push(format!(
+                    "{} already exists; {} was not moved",
+                    rename.new_path.display(),
+                    rename.old_path.display()
+                ));
+            }
+        }
+        file_renames.retain(|rename| !rename.new_path.exists());
+
+        let file_edits: Vec<FileEdit> = edits_by_file
+            .into_iter()
+            .map(|(file_path, mut edits)| {
+                edits.sort_by(|a, b| b.start.cmp(&a.start));
+                FileEdit { file_path, edits }
+            })
+            .collect();
+
+        let total_changes: usize = file_edits.iter().map(|fe| fe.edits.len()).sum();
+        let files_modified = file_edits.len();
+
+        let backup_info =
+            if self.config.create_backups { self.create_backup(&file_edits).ok() } else { None };
+
+        Ok(WorkspaceRenameResult {
+            file_edits,
+            backup_info,
+            description: format!("Rename package '{}' to '{}'", old_name, new_name),
+            warnings,
+            statistics: RenameStatistics {
+                files_modified,
+                total_changes,
+                elapsed_ms: start.elapsed().as_millis() as u64,
+            },
+            file_renames,
         })
     }
 
@@ -742,6 +933,47 @@ impl WorkspaceRename {
         Ok(())
     }
 
+    /// Move the files listed in a rename result and re-index them at their new paths
+    ///
+    /// Call after [`Self::apply_edits`] so each file is moved with its edits
+    /// already applied. Missing parent directories are created.
+    pub fn apply_file_renames(
+        &self,
+        result: &WorkspaceRenameResult,
+    ) -> Result<(), WorkspaceRenameError> {
+        for rename in &result.file_renames {
+            let fs_error =
+                |operation: &str, error: std::io::Error| WorkspaceRenameError::FileSystemError {
+                    operation: operation.to_string(),
+                    file: rename.old_path.clone(),
+                    error: error.to_string(),
+                };
+
+            if let Some(parent) = rename.new_path.parent() {
+                std::fs::create_dir_all(parent).map_err(|e| fs_error("create_dir", e))?;
+            }
+            std::fs::rename(&rename.old_path, &rename.new_path)
+                .map_err(|e| fs_error("rename_file", e))?;
+
+            let index_error = |error: String| WorkspaceRenameError::IndexUpdateFailed {
+                error,
+                affected_files: vec![rename.old_path.clone(), rename.new_path.clone()],
+            };
+            if let Ok(old_uri) = crate::workspace_index::fs_path_to_uri(&rename.old_path) {
+                self.index.remove_file(&old_uri);
+            }
+            let new_uri = crate::workspace_index::fs_path_to_uri(&rename.new_path)
+                .map_err(|e| index_error(format!("URI conversion failed: {}", e)))?;
+            let url = url::Url::parse(&new_uri)
+                .map_err(|e| index_error(format!("URL parse failed: {}", e)))?;
+            let content =
+                std::fs::read_to_string(&rename.new_path).map_err(|e| fs_error("read", e))?;
+            self.index.index_file(url, content).map_err(index_error)?;
+        }
+
+        Ok(())
+    }
+
     /// Rollback files from backup
     fn rollback_from_backup(
         &self,
@@ -821,6 +1053,83 @@ fn split_qualified_name(name: &str) -> (Option<&str>, &str) {
     }
 }
 
+/// Whether `name` is a `::`-separated sequence of identifiers
+fn is_package_name(name: &str) -> bool {
+    name.split("::").all(|segment| {
+        segment.bytes().next().is_some_and(|b| b.is_ascii_alphabetic() || b == b'_')
+            && segment.bytes().all(is_identifier_char)
+    })
+}
+
+/// Relative module path for a package, e.g. `Foo/Bar.pm` for `Foo::Bar`
+fn module_path(package: &str) -> String {
+    format!("{}.pm", package.replace("::", "/"))
+}
+
+/// Byte ranges of `package` in `text` where it is used as a package name
+///
+/// The match must cover whole namespace segments and sit in a package
+/// position: the name after `package`, `use`, `require` or `no`, a `Pkg->`
+/// invocant, a `Pkg::` qualifier (including `$Pkg::var`), or the whole body
+/// of a quoted string such as `'Pkg'`. Plain barewords and variables that
+/// merely share the name, like `my $Pkg`, are left alone.
+fn find_package_occurrences(text: &str, package: &str) -> Vec<(usize, usize)> {
+    let bytes = text.as_bytes();
+    text.match_indices(package)
+        .map(|(start, _)| (start, start + package.len()))
+        .filter(|&(start, end)| {
+            let starts_segment = start == 0
+                || !(is_identifier_char(bytes[start - 1]) || text[..start].ends_with("::"));
+            let ends_segment = end >= bytes.len() || !is_identifier_char(bytes[end]);
+            starts_segment && ends_segment && is_package_position(text, start, end)
+        })
+        .collect()
+}
+
+/// Whether the name spanning `start..end` is used as a package name
+fn is_package_position(text: &str, start: usize, end: usize) -> bool {
+    let before = &text[..start];
+    let after = &text[end..];
+
+    if after.starts_with("::") {
+        return true;
+    }
+    // Variables and method names only name a package when qualified
+    if before.ends_with(['$', '@', '%', '&', '*']) || before.ends_with("->") {
+        return false;
+    }
+    if after.trim_start().starts_with("->") {
+        return true;
+    }
+    if let Some(quote) = before.chars().next_back().filter(|c| matches!(c, '\'' | '"')) {
+        return after.starts_with(quote);
+    }
+
+    let keyword_end = before.trim_end();
+    if keyword_end.len() == before.len() {
+        return false;
+    }
+    ["package", "use", "require", "no"].iter().any(|keyword| {
+        keyword_end.strip_suffix(keyword).is_some_and(|prefix| {
+            !prefix.bytes().next_back().is_some_and(|b| is_identifier_char(b) || b == b'$')
+        })
+    })
+}
+
+/// Byte ranges of a module path such as `Foo/Bar.pm` that are not part of a longer path
+fn find_module_path_occurrences(text: &str, path: &str) -> Vec<(usize, usize)> {
+    let bytes = text.as_bytes();
+    text.match_indices(path)
+        .map(|(start, _)| (start, start + path.len()))
+        .filter(|&(start, end)| {
+            let starts_path =
+                start == 0 || !(is_identifier_char(bytes[start - 1]) || bytes[start - 1] == b'/');
+            let ends_path = end >= bytes.len() || !is_identifier_char(bytes[end]);
+            starts_path && ends_path
+        })
+        .collect()
+}
+
 /// Check if a byte is a valid Perl identifier character
 fn is_identifier_char(b: u8) -> bool {
     b.is_ascii_alphanumeric() || b == b'_'
@@ -884,6 +1193,33 @@ mod tests {
         assert!(!is_identifier_char(b';'));
     }
 
+    #[test]
+    fn test_find_package_occurrences_matches_whole_segments() {
+        let text = "use Foo::Bar;\nuse Foo::BarBaz;\nOther::Foo::Bar->x;\n$Foo::Bar::var;\n";
+        let starts: Vec<usize> = find_package_occurrences(text, "Foo::Bar")
+            .into_iter()
+            .map(|(start, _)| start)
+            .collect();
+        assert_eq!(starts, vec![4, 52]);
+    }
+
+    #[test]
+    fn test_find_package_occurrences_skips_non_package_positions() {
+        let text = "package Foo;\nmy $Foo = Foo->new;\n$Foo->run(Foo => 'Foo');\nFoo::helper();\n";
+        let starts: Vec<usize> =
+            find_package_occurrences(text, "Foo").into_iter().map(|(start, _)| start).collect();
+        assert_eq!(starts, vec![8, 23, 51, 58]);
+    }
+
+    #[test]
+    fn test_is_package_name() {
+        assert!(is_package_name("Foo"));
+        assert!(is_package_name("Foo::Bar_2"));
+        assert!(!is_package_name("Foo::"));
+        assert!(!is_package_name("Foo Bar"));
+        assert!(!is_package_name("2Foo"));
+    }
+
     #[test]
     fn test_find_package_at_offset() {
         let text = "package Foo;\nsub bar { 1 }\npackage Bar;\nsub baz { 2 }\n";
@@ -405,6 +405,158 @@ fn workspace_rename_updates_dual_index() -> Result<(), Box<dyn std::error::Error
     Ok(())
 }
 
+// ============================================================================
+// Package Rename
+// ============================================================================
+
+/// Apply a rename result's edits for `file` to `content`
+fn apply_file_edits(
+    result: &perl_refactoring::workspace_rename::WorkspaceRenameResult,
+    file: &std::path::Path,
+    content: &str,
+) -> String {
+    let mut text = content.to_string();
+    for file_edit in result.file_edits.iter().filter(|fe| fe.file_path == file) {
+        for edit in &file_edit.edits {
+            text.replace_range(edit.start..edit.end, &edit.new_text);
+        }
+    }
+    text
+}
+
+#[test]
+fn workspace_rename_package_updates_references_across_files()
+-> Result<(), Box<dyn std::error::Error>> {
+    let module = "package Foo::Bar;\nour $var = 1;\nsub new { bless {}, shift }\n1;\n";
+    let main = "use Foo::Bar;\nrequire Foo::Bar;\nmy $o = Foo::Bar->new;\nprint $Foo::Bar::var;\n";
+    let child = "package Child;\nuse parent -norequire, 'Foo::Bar';\nrequire 'Foo/Bar.pm';\n1;\n";
+    let (workspace, index) =
+        setup_workspace(&[("lib/Foo/Bar.pm", module), ("main.pl", main), ("lib/Child.pm", child)])?;
+
+    let config = WorkspaceRenameConfig { create_backups: false, ..Default::default() };
+    let rename_engine = WorkspaceRename::new(index, config);
+    let result = rename_engine.rename_package("Foo::Bar", "Foo::Baz")?;
+
+    assert_eq!(result.statistics.files_modified, 3);
+    assert_eq!(result.statistics.total_changes, 7);
+    assert_eq!(
+        apply_file_edits(&result, &workspace.path().join("main.pl"), main),
+        "use Foo::Baz;\nrequire Foo::Baz;\nmy $o = Foo::Baz->new;\nprint $Foo::Baz::var;\n"
+    );
+    assert_eq!(
+        apply_file_edits(&result, &workspace.path().join("lib/Child.pm"), child),
+        "package Child;\nuse parent -norequire, 'Foo::Baz';\nrequire 'Foo/Baz.pm';\n1;\n"
+    );
+    assert!(
+        apply_file_edits(&result, &workspace.path().join("lib/Foo/Bar.pm"), module)
+            .starts_with("package Foo::Baz;")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn workspace_rename_package_skips_partial_namespace_matches()
+-> Result<(), Box<dyn std::error::Error>> {
+    let (workspace, index) = setup_workspace(&[
+        ("lib/Foo/Bar.pm", "package Foo::Bar;\n1;\n"),
+        ("lib/Foo/BarBaz.pm", "package Foo::BarBaz;\n1;\n"),
+        ("main.pl", "use Foo::BarBaz;\nuse My::Foo::Bar;\nuse Foo::Bar;\n"),
+    ])?;
+
+    let config = WorkspaceRenameConfig { create_backups: false, ..Default::default() };
+    let rename_engine = WorkspaceRename::new(index, config);
+    let result = rename_engine.rename_package("Foo::Bar", "Foo::Qux")?;
+
+    assert!(
+        !result.file_edits.iter().any(|fe| fe.file_path.ends_with("BarBaz.pm")),
+        "Foo::BarBaz must not be touched"
+    );
+    let main = "use Foo::BarBaz;\nuse My::Foo::Bar;\nuse Foo::Bar;\n";
+    assert_eq!(
+        apply_file_edits(&result, &workspace.path().join("main.pl"), main),
+        "use Foo::BarBaz;\nuse My::Foo::Bar;\nuse Foo::Qux;\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn workspace_rename_package_leaves_same_named_variables_alone()
+-> Result<(), Box<dyn std::error::Error>> {
+    let main = "use Foo;\nmy $Foo = Foo->new;\n$Foo->greet;\nprint \"Foo\\n\";\n";
+    let (workspace, index) = setup_workspace(&[
+        ("lib/Foo.pm", "package Foo;\nsub new { bless {}, shift }\n1;\n"),
+        ("main.pl", main),
+    ])?;
+
+    let config = WorkspaceRenameConfig { create_backups: false, ..Default::default() };
+    let rename_engine = WorkspaceRename::new(index, config);
+    let result = rename_engine.rename_package("Foo", "Bar")?;
+
+    assert_eq!(
+        apply_file_edits(&result, &workspace.path().join("main.pl"), main),
+        "use Bar;\nmy $Foo = Bar->new;\n$Foo->greet;\nprint \"Foo\\n\";\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn workspace_rename_package_moves_mirrored_file() -> Result<(), Box<dyn std::error::Error>> {
+    let (workspace, index) = setup_workspace(&[
+        ("lib/Foo/Bar.pm", "package Foo::Bar;\nsub hello { 1 }\n1;\n"),
+        ("main.pl", "use Foo::Bar;\nFoo::Bar::hello();\n"),
+    ])?;
+
+    let config = WorkspaceRenameConfig { create_backups: false, ..Default::default() };
+    let rename_engine = WorkspaceRename::new(index, config);
+    let result = rename_engine.rename_package("Foo::Bar", "Acme::Greeter")?;
+
+    let old_path = workspace.path().join("lib/Foo/Bar.pm");
+    let new_path = workspace.path().join("lib/Acme/Greeter.pm");
+    assert_eq!(result.file_renames.len(), 1);
+    assert!(result.file_renames[0].old_path.ends_with("lib/Foo/Bar.pm"));
+    assert!(result.file_renames[0].new_path.ends_with("lib/Acme/Greeter.pm"));
+
+    rename_engine.apply_edits(&result)?;
+    rename_engine.apply_file_renames(&result)?;
+
+    assert!(!old_path.exists());
+    assert_eq!(
+        std::fs::read_to_string(&new_path)?,
+        "package Acme::Greeter;\nsub hello { 1 }\n1;\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(workspace.path().join("main.pl"))?,
+        "use Acme::Greeter;\nAcme::Greeter::hello();\n"
+    );
+    assert!(rename_engine.index().find_definition("Acme::Greeter::hello").is_some());
+
+    Ok(())
+}
+
+#[test]
+fn workspace_rename_package_rejects_existing_package() -> Result<(), Box<dyn std::error::Error>> {
+    let (_workspace, index) = setup_workspace(&[
+        ("lib/Foo.pm", "package Foo;\n1;\n"),
+        ("lib/Bar.pm", "package Bar;\n1;\n"),
+    ])?;
+
+    let rename_engine = WorkspaceRename::new(index, WorkspaceRenameConfig::default());
+
+    assert!(matches!(
+        rename_engine.rename_package("Foo", "Bar"),
+        Err(WorkspaceRenameError::NameConflict { .. })
+    ));
+    assert!(matches!(
+        rename_engine.rename_package("Foo", "Not A Package"),
+        Err(WorkspaceRenameError::InvalidName { .. })
+    ));
+
+    Ok(())
+}
+
 // ============================================================================
 // Additional Edge Case Tests
 // ============================================================================
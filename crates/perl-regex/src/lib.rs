@@ -3,6 +3,7 @@
 //! This module provides tools to validate Perl regular expressions
 //! and detect potential security or performance issues like catastrophic backtracking.
 
+use std::borrow::Cow;
 use thiserror::Error;
 
 #[derive(Error, Debug, Clone, PartialEq)]
@@ -21,6 +22,7 @@ impl RegexError {
 pub struct RegexValidator {
     max_nesting: usize,
     max_unicode_properties: usize,
+    extended: bool,
 }
 
 impl RegexValidator {
@@ -31,16 +33,24 @@ impl RegexValidator {
             max_nesting: 10,
             // Limit from issue #460
             max_unicode_properties: 50,
+            extended: false,
         }
     }
 
+    /// Treat patterns as `/x` (extended): whitespace and `#` comments are not pattern text
+    pub fn with_extended(mut self, extended: bool) -> Self {
+        self.extended = extended;
+        self
+    }
+
     /// Validate a regex pattern for potential performance or security risks
     pub fn validate(&self, pattern: &str, start_pos: usize) -> Result<(), RegexError> {
-        self.check_complexity(pattern, start_pos)
+        self.check_complexity(&self.strip_comments(pattern), start_pos)
     }
 
     /// Check if the pattern contains embedded code constructs (?{...}) or (??{...})
     pub fn detects_code_execution(&self, pattern: &str) -> bool {
+        let pattern = self.strip_comments(pattern);
         let mut chars = pattern.char_indices().peekable();
         while let Some((_, ch)) = chars.next() {
             if ch == '\\' {
@@ -70,6 +80,7 @@ impl RegexValidator {
     /// Check for nested quantifiers that can cause catastrophic backtracking
     /// e.g. (a+)+, (a*)*, (a?)*
     pub fn detect_nested_quantifiers(&self, pattern: &str) -> bool {
+        let pattern = self.strip_comments(pattern);
         // This is a heuristic check for nested quantifiers
         // It looks for a quantifier character following a group that ends with a quantifier
         // e.g. ")+" in "...)+"
@@ -107,6 +118,8 @@ impl RegexValidator {
                         }
                     }
                 }
+                // Under /x, whitespace between a group and its quantifier is insignificant
+                c if self.extended && c.is_whitespace() => {}
                 '+' | '*' | '?' | '{' => {
                     // If we just closed a group that had a quantifier inside,
                     // and now we see another quantifier, that's a nested quantifier!
@@ -135,6 +148,50 @@ impl RegexValidator {
         false
     }
 
+    /// Blank out `/x` comments so they are not mistaken for pattern syntax
+    ///
+    /// Comments run from an unescaped `#` outside a character class to the end
+    /// of the line. They are replaced with spaces so offsets stay valid.
+    fn strip_comments<'p>(&self, pattern: &'p str) -> Cow<'p, str> {
+        if !self.extended || !pattern.contains('#') {
+            return Cow::Borrowed(pattern);
+        }
+
+        let mut out = String::with_capacity(pattern.len());
+        let mut chars = pattern.chars();
+        let mut in_class = false;
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\\' => {
+                    out.push(ch);
+                    if let Some(next) = chars.next() {
+                        out.push(next);
+                    }
+                }
+                '[' => {
+                    in_class = true;
+                    out.push(ch);
+                }
+                ']' => {
+                    in_class = false;
+                    out.push(ch);
+                }
+                '#' if !in_class => {
+                    out.push(' ');
+                    for comment_ch in chars.by_ref() {
+                        if comment_ch == '\n' {
+                            out.push('\n');
+                            break;
+                        }
+                        out.extend(std::iter::repeat_n(' ', comment_ch.len_utf8()));
+                    }
+                }
+                _ => out.push(ch),
+            }
+        }
+        Cow::Owned(out)
+    }
+
     fn check_complexity(&self, pattern: &str, start_pos: usize) -> Result<(), RegexError> {
         if self.detect_nested_quantifiers(pattern) {
             return Err(RegexError::syntax(
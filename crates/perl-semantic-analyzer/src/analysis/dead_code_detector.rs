@@ -161,10 +161,9 @@ impl DeadCodeDetector {
             }
         }
 
-        // Unused symbols across workspace
+        // Unused symbols across workspace; subroutines get their own, stricter pass
         for sym in self.workspace_index.find_unused_symbols() {
             let code_type = match sym.kind {
-                SymbolKind::Subroutine => DeadCodeType::UnusedSubroutine,
                 SymbolKind::Variable(_) => DeadCodeType::UnusedVariable,
                 SymbolKind::Constant => DeadCodeType::UnusedConstant,
                 SymbolKind::Package => DeadCodeType::UnusedPackage,
@@ -185,6 +184,25 @@ impl DeadCodeDetector {
             });
         }
 
+        // Dynamic dispatch can reach any sub by name, so this is only a heuristic
+        for sym in self.workspace_index.find_unused_subroutines() {
+            let file_path = uri_to_fs_path(&sym.uri).unwrap_or_else(|| PathBuf::from(&sym.uri));
+
+            dead_code.push(DeadCode {
+                code_type: DeadCodeType::UnusedSubroutine,
+                name: Some(sym.name.clone()),
+                file_path,
+                start_line: sym.range.start.line as usize + 1,
+                end_line: sym.range.end.line as usize + 1,
+                reason: "Subroutine is never called, exported or referenced in the workspace"
+                    .to_string(),
+                confidence: 0.6,
+                suggestion: Some(
+                    "Remove this subroutine if nothing calls it dynamically".to_string(),
+                ),
+            });
+        }
+
         // Compute stats
         let mut stats = DeadCodeStats::default();
         for item in &dead_code {
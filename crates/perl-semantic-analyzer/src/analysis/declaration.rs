@@ -365,6 +365,10 @@ impl<'a> DeclarationProvider<'a> {
     }
 
     /// Find subroutine declaration
+    ///
+    /// A sub defined in the caller's package wins over an import of the same
+    /// name, as it does at runtime. A bare name that is only imported links
+    /// to the `use` statement that brings it in.
     fn find_subroutine_declaration(
         &self,
         node: &Node,
@@ -385,17 +389,24 @@ impl<'a> DeclarationProvider<'a> {
         let mut declarations = Vec::new();
         self.collect_subroutine_declarations(&self.ast, target_name, &mut declarations);
 
-        // If we have a target package, find subs in that specific package
-        if let Some(pkg_name) = target_package {
-            if let Some(decl) =
-                declarations.iter().find(|d| self.find_current_package(d) == Some(pkg_name))
-            {
-                return Some(vec![self.create_location_link(
-                    node,
-                    decl,
-                    self.get_subroutine_name_range(decl),
-                )]);
-            }
+        // Prefer a sub in the target package (`None` is the implicit main package)
+        if let Some(decl) =
+            declarations.iter().find(|d| self.find_current_package(d) == target_package)
+        {
+            return Some(vec![self.create_location_link(
+                node,
+                decl,
+                self.get_subroutine_name_range(decl),
+            )]);
+        }
+
+        // A bare name may come from `use Module qw(name)`
+        if let Some(use_node) = self.import_for_call(node, func_name) {
+            return Some(vec![self.create_location_link(
+                node,
+                use_node,
+                self.get_import_name_range(use_node, func_name),
+            )]);
         }
 
         // Otherwise return the first match
@@ -410,6 +421,56 @@ impl<'a> DeclarationProvider<'a> {
         None
     }
 
+    /// Find the module that exports the function called at the given position
+    ///
+    /// Returns `(module, name)` when the cursor is on a bare call such as
+    /// `first(...)` that the current package does not define and a
+    /// `use Module qw(name)` statement imports, so callers can look the sub up
+    /// as `Module::name` in other files.
+    pub fn find_import(&self, offset: usize) -> Option<(String, String)> {
+        let node = self.find_node_at_offset(&self.ast, offset)?;
+        let name = match &node.kind {
+            NodeKind::FunctionCall { name, .. } | NodeKind::Identifier { name } => name,
+            _ => return None,
+        };
+        let use_node = self.import_for_call(node, name)?;
+        let NodeKind::Use { module, .. } = &use_node.kind else {
+            return None;
+        };
+        Some((module.clone(), name.clone()))
+    }
+
+    /// The `use` statement importing a bare function name the caller's package does not define
+    fn import_for_call<'b>(&'b self, node: &Node, func_name: &str) -> Option<&'b Node> {
+        if func_name.contains("::") {
+            return None;
+        }
+
+        let package = self.find_current_package(node);
+        let mut declarations = Vec::new();
+        self.collect_subroutine_declarations(&self.ast, func_name, &mut declarations);
+        if declarations.iter().any(|d| self.find_current_package(d) == package) {
+            return None;
+        }
+
+        self.find_importing_use(&self.ast, func_name)
+    }
+
+    /// First `use` statement whose import list names `name`
+    fn find_importing_use<'b>(&'b self, node: &'b Node, name: &str) -> Option<&'b Node> {
+        if let NodeKind::Use { module, args, .. } = &node.kind {
+            let args_text = args.join(" ");
+            let listed = args.iter().any(|arg| {
+                arg.trim_matches(|c| c == '\'' || c == '"').trim_start_matches('&') == name
+            });
+            if module != "constant" && (listed || self.contains_name_in_qw(&args_text, name)) {
+                return Some(node);
+            }
+        }
+
+        self.get_children(node).into_iter().find_map(|child| self.find_importing_use(child, name))
+    }
+
     /// Find method declaration with package resolution
     fn find_method_declaration(
         &self,
@@ -819,6 +880,24 @@ impl<'a> DeclarationProvider<'a> {
         }
     }
 
+    /// Range of `name` in the import list of a `use` statement, or the whole statement
+    fn get_import_name_range(&self, decl: &Node, name: &str) -> (usize, usize) {
+        let text = self.get_node_text(decl);
+        // Skip `use Module` so a module named like the import is not matched
+        let list_start = match &decl.kind {
+            NodeKind::Use { module, .. } => {
+                text.find(module.as_str()).map_or(0, |i| i + module.len())
+            }
+            _ => 0,
+        };
+        self.find_word(&text[list_start..], name).map_or(
+            (decl.location.start, decl.location.end),
+            |(start, end)| {
+                (decl.location.start + list_start + start, decl.location.start + list_start + end)
+            },
+        )
+    }
+
     fn get_package_name_range(&self, decl: &Node) -> (usize, usize) {
         if let NodeKind::Package { name_span, .. } = &decl.kind {
             (name_span.start, name_span.end)
@@ -929,7 +1008,10 @@ impl<'a> DeclarationProvider<'a> {
                 children
             }
             NodeKind::Subroutine { signature, body, .. } => {
-                let mut children = vec![body.as_ref()];
+                let mut children = Vec::new();
+                if let Some(body) = body {
+                    children.push(body.as_ref());
+                }
                 if let Some(sig) = signature {
                     children.push(sig.as_ref());
                 }
@@ -1028,7 +1110,7 @@ impl<'a> DeclarationProvider<'a> {
 /// - Package-qualified calls: `Package::function`
 /// - Bare function calls: `function` (resolved in current package)
 /// - Variable references: `$var`, `@array`, `%hash`
-/// - Method calls: `$obj->method`
+/// - Class-method calls: `Package->method`
 ///
 /// # Examples
 /// ```rust
@@ -1071,6 +1153,16 @@ pub fn symbol_at_cursor(ast: &Node, offset: usize, current_pkg: &str) -> Option<
             };
             Some(SymbolKey { pkg: pkg.into(), name: bare.into(), sigil: None, kind: SymKind::Sub })
         }
+        // Class-method call: `Pkg->name` is the sub `Pkg::name`
+        NodeKind::MethodCall { object, method, .. } => match &object.kind {
+            NodeKind::Identifier { name: pkg } => Some(SymbolKey {
+                pkg: pkg.as_str().into(),
+                name: method.as_str().into(),
+                sigil: None,
+                kind: SymKind::Sub,
+            }),
+            _ => None,
+        },
         _ => None,
     }
 }
@@ -1220,9 +1312,7 @@ pub fn get_node_children(node: &Node) -> Vec<&Node> {
         NodeKind::Assignment { lhs, rhs, .. } => vec![lhs.as_ref(), rhs.as_ref()],
         NodeKind::Binary { left, right, .. } => vec![left.as_ref(), right.as_ref()],
         NodeKind::FunctionCall { args, .. } => args.iter().collect(),
-        NodeKind::Subroutine { body, .. } => {
-            vec![body.as_ref()]
-        }
+        NodeKind::Subroutine { body, .. } => body.iter().map(|b| b.as_ref()).collect(),
         NodeKind::ExpressionStatement { expression } => vec![expression.as_ref()],
         _ => vec![],
     }
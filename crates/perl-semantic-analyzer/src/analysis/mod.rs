@@ -8,6 +8,8 @@ pub mod declaration;
 #[cfg(not(target_arch = "wasm32"))]
 /// Lightweight workspace symbol index.
 pub mod index;
+/// POD documentation association for subroutines.
+pub mod pod;
 /// Scope analysis for variable and subroutine resolution.
 #[allow(missing_docs)]
 pub mod scope_analyzer;
@@ -0,0 +1,220 @@
+//! POD documentation association for subroutines.
+//!
+//! Perl modules conventionally document each sub in a POD entry such as
+//! `=head2 name`, `=item name` or `=method name`, placed either before the sub
+//! or gathered after `__END__`. This module finds the entry for a given sub
+//! and renders its body as markdown for hover.
+
+/// A POD command paragraph (`=head2 ...`, `=item ...`) and the text under it
+struct PodEntry<'a> {
+    /// Byte offset of the command line
+    start: usize,
+    /// Subroutine name the heading documents, if any
+    name: Option<String>,
+    /// Body lines up to the next command
+    body: Vec<&'a str>,
+}
+
+/// Find the POD documentation for `sub_name`, rendered as markdown
+///
+/// The nearest matching entry before `sub_start` wins; otherwise the first
+/// matching entry after it (trailing docs) is used.
+pub fn find_sub_documentation(source: &str, sub_name: &str, sub_start: usize) -> Option<String> {
+    let entries: Vec<PodEntry<'_>> = pod_entries(source)
+        .into_iter()
+        .filter(|entry| entry.name.as_deref() == Some(sub_name))
+        .collect();
+
+    let entry = entries
+        .iter()
+        .rev()
+        .find(|entry| entry.start < sub_start)
+        .or_else(|| entries.iter().find(|entry| entry.start >= sub_start))?;
+
+    let text = entry.body.iter().map(|line| render_inline(line)).collect::<Vec<_>>().join("\n");
+    let text = text.trim();
+    if text.is_empty() { None } else { Some(text.to_string()) }
+}
+
+/// Split the POD blocks of `source` into command entries
+fn pod_entries(source: &str) -> Vec<PodEntry<'_>> {
+    let mut entries: Vec<PodEntry<'_>> = Vec::new();
+    let mut in_pod = false;
+    let mut offset = 0;
+
+    for line in source.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        let line = line.trim_end_matches(['\n', '\r']);
+
+        let is_command = line.starts_with('=') && line[1..].starts_with(char::is_alphabetic);
+        if !in_pod {
+            in_pod = is_command;
+            if !in_pod {
+                continue;
+            }
+        }
+
+        if !is_command {
+            if let Some(entry) = entries.last_mut() {
+                entry.body.push(line);
+            }
+            continue;
+        }
+
+        let (command, heading) =
+            line[1..].split_once(char::is_whitespace).unwrap_or((&line[1..], ""));
+        if command == "cut" {
+            in_pod = false;
+        }
+        let name = match command {
+            "head1" | "head2" | "head3" | "head4" | "item" | "method" | "func" => {
+                heading_name(heading)
+            }
+            _ => None,
+        };
+        entries.push(PodEntry { start, name, body: Vec::new() });
+    }
+
+    entries
+}
+
+/// Subroutine name documented by a heading such as `C<foo($x)>` or `$obj->foo`
+fn heading_name(heading: &str) -> Option<String> {
+    let plain = render_inline(heading.trim().trim_start_matches('*').trim());
+    let plain = plain.trim_matches('`').trim();
+    let plain = plain.rsplit_once("->").map_or(plain, |(_, method)| method);
+    let plain = plain.rsplit_once("::").map_or(plain, |(_, name)| name);
+    let name: String = plain.chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect();
+    if name.is_empty() { None } else { Some(name) }
+}
+
+/// Convert POD formatting codes (`C<>`, `B<>`, `I<>`, `L<>`, `E<>`) to markdown
+fn render_inline(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(pos) = rest.find(|c: char| c.is_ascii_uppercase()) {
+        let (before, code_start) = rest.split_at(pos);
+        out.push_str(before);
+        let mut chars = code_start.chars();
+        let code = chars.next().unwrap_or_default();
+        let after_code = chars.as_str();
+
+        let Some((inner, remainder)) = formatting_body(after_code) else {
+            out.push(code);
+            rest = after_code;
+            continue;
+        };
+        let inner_md = render_inline(inner);
+        match code {
+            'C' | 'F' => out.push_str(&format!("`{}`", inner.trim())),
+            'B' => out.push_str(&format!("**{inner_md}**")),
+            'I' => out.push_str(&format!("*{inner_md}*")),
+            'L' => out.push_str(inner_md.split_once('|').map_or(inner_md.as_str(), |(t, _)| t)),
+            'E' => out.push_str(match inner {
+                "lt" => "<",
+                "gt" => ">",
+                "verbar" => "|",
+                "sol" => "/",
+                other => other,
+            }),
+            'S' | 'X' | 'Z' => out.push_str(&inner_md),
+            _ => {
+                out.push(code);
+                rest = after_code;
+                continue;
+            }
+        }
+        rest = remainder;
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Split `<...>rest` or `<< ... >>rest` into the code body and the remainder
+fn formatting_body(text: &str) -> Option<(&str, &str)> {
+    let angles = text.chars().take_while(|&c| c == '<').count();
+    if angles == 0 {
+        return None;
+    }
+    if angles > 1 {
+        // `C<< $a->b >>`: the body ends at the first run of as many `>`
+        let close = ">".repeat(angles);
+        let body = &text[angles..];
+        let end = body
+            .find(&format!(" {close}"))
+            .map(|i| (i + 1, i + 1 + angles))
+            .or_else(|| body.find(&close).map(|i| (i, i + angles)))?;
+        return Some((body[..end.0].trim(), &body[end.1..]));
+    }
+
+    let mut depth = 0usize;
+    for (idx, ch) in text.char_indices() {
+        match ch {
+            '<' => depth += 1,
+            '>' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some((&text[1..idx], &text[idx + 1..]));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pod_before_sub_is_associated() {
+        let source = "=head2 greet\n\nSays B<hello> to C<$name>.\n\n=cut\n\nsub greet { }\n";
+        let start = source.find("sub greet").unwrap_or(0);
+        assert_eq!(
+            find_sub_documentation(source, "greet", start).as_deref(),
+            Some("Says **hello** to `$name`.")
+        );
+    }
+
+    #[test]
+    fn test_trailing_pod_is_associated() {
+        let source = "sub run { }\n1;\n__END__\n\n=head1 METHODS\n\n=head2 C<run()>\n\nRuns it.\n\n=head2 other\n\nNot this.\n";
+        assert_eq!(find_sub_documentation(source, "run", 0).as_deref(), Some("Runs it."));
+    }
+
+    #[test]
+    fn test_item_and_method_headings() {
+        let source = "=over\n\n=item $obj->fetch($key)\n\nFetches.\n\n=back\n\n=method store\n\nStores.\n\n=cut\n";
+        assert_eq!(
+            find_sub_documentation(source, "fetch", source.len()).as_deref(),
+            Some("Fetches.")
+        );
+        assert_eq!(
+            find_sub_documentation(source, "store", source.len()).as_deref(),
+            Some("Stores.")
+        );
+    }
+
+    #[test]
+    fn test_nearest_preceding_entry_wins() {
+        let source = "=head2 x\n\nFirst.\n\n=cut\n\n=head2 x\n\nSecond.\n\n=cut\n\nsub x { }\n";
+        let start = source.find("sub x").unwrap_or(0);
+        assert_eq!(find_sub_documentation(source, "x", start).as_deref(), Some("Second."));
+    }
+
+    #[test]
+    fn test_unrelated_heading_is_ignored() {
+        let source = "=head2 other\n\nDocs.\n\n=cut\n\nsub mine { }\n";
+        assert_eq!(find_sub_documentation(source, "mine", source.len()), None);
+    }
+
+    #[test]
+    fn test_double_angle_code() {
+        assert_eq!(render_inline("Call C<< $obj->run >> now"), "Call `$obj->run` now");
+        assert_eq!(render_inline("See L<Foo::Bar|Foo::Bar/run> and E<lt>"), "See Foo::Bar and <");
+    }
+}
@@ -67,6 +67,7 @@ pub enum IssueKind {
     UnusedParameter,
     UnquotedBareword,
     UninitializedVariable,
+    SelfReferentialDeclaration,
 }
 
 #[derive(Debug, Clone)]
@@ -448,7 +449,18 @@ impl ScopeAnalyzer {
                 // Actually Perl evaluates RHS before LHS assignment, so usages in initializer refer to OUTER scope.
                 // So we analyze initializer first.
                 if let Some(init) = initializer {
+                    let first_issue = issues.len();
                     self.analyze_node(init, scope, ancestors, issues, context);
+                    if declarator == "my" || declarator == "state" {
+                        self.check_self_references(
+                            std::slice::from_ref(variable.as_ref()),
+                            init,
+                            scope,
+                            first_issue,
+                            issues,
+                            context,
+                        );
+                    }
                 }
 
                 if let Some(issue_kind) = scope.declare_variable_parts(
@@ -487,7 +499,18 @@ impl ScopeAnalyzer {
 
                 // Analyze initializer first
                 if let Some(init) = initializer {
+                    let first_issue = issues.len();
                     self.analyze_node(init, scope, ancestors, issues, context);
+                    if declarator == "my" || declarator == "state" {
+                        self.check_self_references(
+                            variables,
+                            init,
+                            scope,
+                            first_issue,
+                            issues,
+                            context,
+                        );
+                    }
                 }
 
                 for variable in variables {
@@ -845,9 +868,11 @@ impl ScopeAnalyzer {
                     }
                 }
 
-                ancestors.push(node);
-                self.analyze_node(body, &sub_scope, ancestors, issues, context);
-                ancestors.pop();
+                if let Some(body) = body {
+                    ancestors.push(node);
+                    self.analyze_node(body, &sub_scope, ancestors, issues, context);
+                    ancestors.pop();
+                }
 
                 // Check for unused parameters
                 if let Some(sig) = signature {
@@ -946,6 +971,52 @@ impl ScopeAnalyzer {
         });
     }
 
+    /// Report initializer references to a lexical being declared in the same statement
+    ///
+    /// `my $x = $x + 1` reads an outer `$x`, because the new variable is not
+    /// visible until the next statement. Without an outer binding the
+    /// reference is almost certainly a mistake, so it is reported in place of
+    /// the generic undeclared-variable issue raised for it by `first_issue..`.
+    fn check_self_references(
+        &self,
+        variables: &[Node],
+        initializer: &Node,
+        scope: &Rc<Scope>,
+        first_issue: usize,
+        issues: &mut Vec<ScopeIssue>,
+        context: &AnalysisContext<'_>,
+    ) {
+        for variable in variables {
+            let extracted = self.extract_variable_name(variable);
+            let (sigil, name) = extracted.parts();
+            if sigil.is_empty() || scope.lookup_variable_parts(sigil, name).is_some() {
+                continue;
+            }
+
+            let mut references = Vec::new();
+            collect_variable_references(initializer, sigil, name, &mut references);
+            for reference in references {
+                let range = (reference.location.start, reference.location.end);
+                let full_name = format!("{}{}", sigil, name);
+                if let Some(pos) = issues[first_issue..].iter().position(|issue| {
+                    issue.kind == IssueKind::UndeclaredVariable && issue.range == range
+                }) {
+                    issues.remove(first_issue + pos);
+                }
+                issues.push(ScopeIssue {
+                    kind: IssueKind::SelfReferentialDeclaration,
+                    variable_name: full_name.clone(),
+                    line: context.get_line(reference.location.start),
+                    range,
+                    description: format!(
+                        "Variable '{}' is used in its own declaration, where the new variable is not yet in scope",
+                        full_name
+                    ),
+                });
+            }
+        }
+    }
+
     fn extract_variable_name<'a>(&self, node: &'a Node) -> ExtractedName<'a> {
         match &node.kind {
             NodeKind::Variable { sigil, name } => ExtractedName::Parts(sigil, name),
@@ -1113,11 +1184,33 @@ impl ScopeAnalyzer {
                 IssueKind::UninitializedVariable => {
                     format!("Initialize '{}' before use", issue.variable_name)
                 }
+                IssueKind::SelfReferentialDeclaration => {
+                    format!(
+                        "Give the new '{}' a different name or declare it before this statement",
+                        issue.variable_name
+                    )
+                }
             })
             .collect()
     }
 }
 
+/// Collect `Variable` nodes under `node` that name `sigil`+`name`
+fn collect_variable_references<'a>(
+    node: &'a Node,
+    sigil: &str,
+    name: &str,
+    references: &mut Vec<&'a Node>,
+) {
+    if let NodeKind::Variable { sigil: s, name: n } = &node.kind
+        && s == sigil
+        && n == name
+    {
+        references.push(node);
+    }
+    node.for_each_child(|child| collect_variable_references(child, sigil, name, references));
+}
+
 /// Check if a variable is a built-in Perl global variable
 fn is_builtin_global(sigil: &str, name: &str) -> bool {
     // Fast path: most user variables start with lowercase and are not built-ins
@@ -5,7 +5,7 @@
 //! and code intelligence features.
 
 use crate::SourceLocation;
-use crate::ast::{Node, NodeKind};
+use crate::ast::{Attribute, Node, NodeKind};
 use crate::symbol::{ScopeId, ScopeKind, Symbol, SymbolExtractor, SymbolKind, SymbolTable};
 use regex::Regex;
 use std::collections::HashMap;
@@ -276,6 +276,11 @@ impl SemanticAnalyzer {
         &self,
         reference: &crate::symbol::SymbolReference,
     ) -> Vec<&Symbol> {
+        // A label binds to the labeled statement enclosing the reference
+        if reference.kind == SymbolKind::Label {
+            return self.symbol_table.resolve_reference(reference).into_iter().collect();
+        }
+
         // Handle qualified names like Foo::bar
         if let Some((pkg, name)) = reference.name.rsplit_once("::") {
             if let Some(pkg_syms) = self.symbol_table.symbols.get(pkg) {
@@ -564,11 +569,12 @@ impl SemanticAnalyzer {
 
                     let hover = HoverInfo {
                         signature: signature_str,
-                        documentation: self.extract_documentation(node.location.start),
+                        documentation: self
+                            .extract_sub_documentation(sub_name, node.location.start),
                         details: if attributes.is_empty() {
                             vec![]
                         } else {
-                            vec![format!("Attributes: {}", attributes.join(", "))]
+                            vec![format!("Attributes: {}", join_attributes(attributes))]
                         },
                     };
 
@@ -594,7 +600,7 @@ impl SemanticAnalyzer {
 
                     let mut details = vec!["Anonymous subroutine (closure)".to_string()];
                     if !attributes.is_empty() {
-                        details.push(format!("Attributes: {}", attributes.join(", ")));
+                        details.push(format!("Attributes: {}", join_attributes(attributes)));
                     }
 
                     let hover = HoverInfo {
@@ -617,7 +623,9 @@ impl SemanticAnalyzer {
                         self.analyze_node(sig, sub_scope);
                     }
 
-                    self.analyze_node(body, sub_scope);
+                    if let Some(body) = body {
+                        self.analyze_node(body, sub_scope);
+                    }
                 }
             }
 
@@ -631,7 +639,7 @@ impl SemanticAnalyzer {
                 // Add hover info
                 let hover = HoverInfo {
                     signature: format!("method {}", name),
-                    documentation: self.extract_documentation(node.location.start),
+                    documentation: self.extract_sub_documentation(name, node.location.start),
                     details: if attributes.is_empty() {
                         vec![]
                     } else {
@@ -694,7 +702,7 @@ impl SemanticAnalyzer {
                 }
             }
 
-            NodeKind::Package { name, block, name_span: _ } => {
+            NodeKind::Package { name, block, .. } => {
                 self.semantic_tokens.push(SemanticToken {
                     location: node.location,
                     token_type: SemanticTokenType::Namespace,
@@ -909,8 +917,24 @@ impl SemanticAnalyzer {
                 // Handle try/catch error handling
                 self.analyze_node(body, scope_id);
 
-                for (_var, catch_body) in catch_blocks {
-                    // Note: var is just a String (variable name), not a Node
+                for (var, catch_body) in catch_blocks {
+                    if let Some(Node { kind: NodeKind::Variable { sigil, name }, location }) =
+                        var.as_deref()
+                    {
+                        self.semantic_tokens.push(SemanticToken {
+                            location: *location,
+                            token_type: SemanticTokenType::VariableDeclaration,
+                            modifiers: vec![SemanticTokenModifier::Declaration],
+                        });
+                        self.hover_info.insert(
+                            *location,
+                            HoverInfo {
+                                signature: format!("catch ({}{})", sigil, name),
+                                documentation: None,
+                                details: vec!["Caught exception".to_string()],
+                            },
+                        );
+                    }
                     self.analyze_node(catch_body, scope_id);
                 }
 
@@ -942,6 +966,17 @@ impl SemanticAnalyzer {
                 self.analyze_node(block, scope_id);
             }
 
+            NodeKind::DoWhile { block, condition } | NodeKind::DoUntil { block, condition } => {
+                // Handle post-condition loops: do { ... } while/until (...)
+                self.semantic_tokens.push(SemanticToken {
+                    location: node.location,
+                    token_type: SemanticTokenType::KeywordControl,
+                    modifiers: vec![],
+                });
+                self.analyze_node(block, scope_id);
+                self.analyze_node(condition, scope_id);
+            }
+
             NodeKind::Eval { block } => {
                 // Handle eval blocks: eval { dangerous_operation(); }
                 self.semantic_tokens.push(SemanticToken {
@@ -1152,7 +1187,7 @@ impl SemanticAnalyzer {
                 }
             }
 
-            NodeKind::Class { name, body } => {
+            NodeKind::Class { name, body, .. } => {
                 self.semantic_tokens.push(SemanticToken {
                     location: SourceLocation {
                         start: node.location.start,
@@ -1174,6 +1209,40 @@ impl SemanticAnalyzer {
                 self.analyze_node(body, class_scope);
             }
 
+            NodeKind::Field { variable, attributes, default } => {
+                self.semantic_tokens.push(SemanticToken {
+                    location: SourceLocation {
+                        start: node.location.start,
+                        end: node.location.start + 5,
+                    }, // field
+                    token_type: SemanticTokenType::Modifier,
+                    modifiers: vec![],
+                });
+
+                if let NodeKind::Variable { sigil, name } = &variable.kind {
+                    self.semantic_tokens.push(SemanticToken {
+                        location: variable.location,
+                        token_type: SemanticTokenType::VariableDeclaration,
+                        modifiers: vec![SemanticTokenModifier::Declaration],
+                    });
+
+                    let mut details = vec!["Instance field".to_string()];
+                    if !attributes.is_empty() {
+                        details.push(format!("Attributes: {}", attributes.join(", ")));
+                    }
+                    let hover = HoverInfo {
+                        signature: format!("field {}{}", sigil, name),
+                        documentation: self.extract_documentation(node.location.start),
+                        details,
+                    };
+                    self.hover_info.insert(variable.location, hover);
+                }
+
+                if let Some(default) = default {
+                    self.analyze_node(default, scope_id);
+                }
+            }
+
             NodeKind::Signature { parameters } => {
                 for param in parameters {
                     self.analyze_node(param, scope_id);
@@ -1187,7 +1256,7 @@ impl SemanticAnalyzer {
                 self.analyze_node(variable, scope_id);
             }
 
-            NodeKind::Diamond | NodeKind::Ellipsis => {
+            NodeKind::Diamond | NodeKind::YadaYada => {
                 self.semantic_tokens.push(SemanticToken {
                     location: node.location,
                     token_type: SemanticTokenType::Operator,
@@ -1310,6 +1379,12 @@ impl SemanticAnalyzer {
         }
     }
 
+    /// Documentation for a sub: its matching POD entry, else what precedes it
+    fn extract_sub_documentation(&self, name: &str, start: usize) -> Option<String> {
+        crate::pod::find_sub_documentation(&self.source, name, start)
+            .or_else(|| self.extract_documentation(start))
+    }
+
     /// Extract documentation (POD or comments) preceding a position
     fn extract_documentation(&self, start: usize) -> Option<String> {
         static POD_RE: OnceLock<Result<Regex, regex::Error>> = OnceLock::new();
@@ -1513,6 +1588,11 @@ struct BuiltinDoc {
 /// Check if a function name is a Perl built-in.
 ///
 /// Returns `true` if the name matches a known Perl built-in function.
+/// Render subroutine attributes for hover details, e.g. `lvalue, Foo(bar)`.
+fn join_attributes(attributes: &[Attribute]) -> String {
+    attributes.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+}
+
 fn is_control_keyword(name: &str) -> bool {
     matches!(name, "next" | "last" | "redo" | "goto" | "return" | "exit" | "die")
 }
@@ -1895,6 +1975,45 @@ sub documented_with_comment {
         Ok(())
     }
 
+    #[test]
+    fn test_hover_renders_matching_pod_entry() -> Result<(), Box<dyn std::error::Error>> {
+        let code = r#"package Greeter;
+
+=head2 C<greet($name)>
+
+Returns a greeting for C<$name>.
+
+=cut
+
+sub greet { return "hi $_[0]" }
+
+sub farewell { return "bye" }
+
+1;
+__END__
+
+=head2 farewell
+
+Says B<goodbye>.
+"#;
+
+        let mut parser = Parser::new(code);
+        let ast = parser.parse()?;
+        let analyzer = SemanticAnalyzer::analyze_with_source(&ast, code);
+
+        for (name, expected) in
+            [("greet", "Returns a greeting for `$name`."), ("farewell", "Says **goodbye**.")]
+        {
+            let subs =
+                analyzer.symbol_table().find_symbol(name, 0, crate::symbol::SymbolKind::Subroutine);
+            let sub = subs.first().ok_or("sub symbol not found")?;
+            assert_eq!(sub.documentation.as_deref(), Some(expected));
+            let hover = analyzer.hover_at(sub.location).ok_or("hover not found")?;
+            assert_eq!(hover.documentation.as_deref(), Some(expected));
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_empty_source_handling() -> Result<(), Box<dyn std::error::Error>> {
         let code = "";
@@ -2048,6 +2167,21 @@ my $documented = 42;
         Ok(())
     }
 
+    #[test]
+    fn test_analyzer_find_definition_loop_label() -> Result<(), Box<dyn std::error::Error>> {
+        let code = "OUTER: for (1..2) { last OUTER; }\nOUTER: while (1) { last OUTER; }\n";
+        let ast = Parser::new(code).parse()?;
+        let analyzer = SemanticAnalyzer::analyze_with_source(&ast, code);
+
+        let second_loop = code.rfind("OUTER:").ok_or("second label not found")?;
+        let ref_pos = code.rfind("last OUTER").ok_or("reference not found")? + "last O".len();
+
+        let symbol = analyzer.find_definition(ref_pos).ok_or("definition not found for label")?;
+        assert_eq!(symbol.kind, SymbolKind::Label);
+        assert_eq!(symbol.location.start, second_loop);
+        Ok(())
+    }
+
     #[test]
     fn test_semantic_model_definition_at() -> Result<(), Box<dyn std::error::Error>> {
         let code = "my $x = 1;\n$x + 2;\n";
@@ -28,6 +28,7 @@ use crate::SourceLocation;
 use crate::ast::{Node, NodeKind};
 use regex::Regex;
 use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
 
 // Re-export the unified symbol types from perl-symbol-types
 /// Symbol kind enums used during Index/Analyze workflows.
@@ -308,6 +309,37 @@ impl SymbolTable {
         results
     }
 
+    /// Resolve a reference to the declaration it binds to for Navigate/Analyze workflows.
+    ///
+    /// Walks outward from the reference's scope and returns the innermost
+    /// declaration of the same name and kind that precedes the reference, so
+    /// same-named variables in sibling or shadowing scopes stay distinct.
+    /// Labels only bind references inside the statement they label.
+    /// Returns `None` for undeclared (package or special) variables.
+    pub fn resolve_reference(&self, reference: &SymbolReference) -> Option<&Symbol> {
+        let symbols = self.symbols.get(&reference.name)?;
+        let mut current_scope_id = Some(reference.scope_id);
+
+        while let Some(scope_id) = current_scope_id {
+            let scope = self.scopes.get(&scope_id)?;
+            let innermost = symbols
+                .iter()
+                .filter(|s| {
+                    s.scope_id == scope_id
+                        && s.kind == reference.kind
+                        && s.location.start <= reference.location.start
+                        && (s.kind != SymbolKind::Label || reference.location.end <= s.location.end)
+                })
+                .max_by_key(|s| s.location.start);
+            if innermost.is_some() {
+                return innermost;
+            }
+            current_scope_id = scope.parent;
+        }
+
+        None
+    }
+
     /// Get all references to a symbol for Navigate/Analyze workflows.
     pub fn find_references(&self, symbol: &Symbol) -> Vec<&SymbolReference> {
         self.references
@@ -438,7 +470,8 @@ impl SymbolExtractor {
                     name.as_ref().map(|n| n.to_string()).unwrap_or_else(|| "<anon>".to_string());
 
                 if name.is_some() {
-                    let documentation = self.extract_leading_comment(node.location.start);
+                    let documentation =
+                        self.extract_sub_documentation(&sub_name, node.location.start);
                     let symbol = Symbol {
                         name: sub_name.clone(),
                         qualified_name: format!("{}::{}", self.table.current_package, sub_name),
@@ -447,7 +480,7 @@ impl SymbolExtractor {
                         scope_id: self.table.current_scope(),
                         declaration: None,
                         documentation,
-                        attributes: attributes.clone(),
+                        attributes: attributes.iter().map(ToString::to_string).collect(),
                     };
 
                     self.table.add_symbol(symbol);
@@ -456,14 +489,14 @@ impl SymbolExtractor {
                 // Create subroutine scope
                 self.table.push_scope(ScopeKind::Subroutine, node.location);
 
-                {
+                if let Some(body) = body {
                     self.visit_node(body);
                 }
 
                 self.table.pop_scope();
             }
 
-            NodeKind::Package { name, block, name_span: _ } => {
+            NodeKind::Package { name, block, .. } => {
                 let old_package = self.table.current_package.clone();
                 self.table.current_package = name.clone();
 
@@ -539,8 +572,18 @@ impl SymbolExtractor {
             NodeKind::Foreach { variable, list, body, continue_block: _ } => {
                 self.table.push_scope(ScopeKind::Block, node.location);
 
-                // The loop variable is implicitly declared
-                self.handle_variable_declaration("my", variable, &[], variable.location, None);
+                // The loop variable is implicitly declared; `foreach my $x` wraps it in a declaration
+                match &variable.kind {
+                    NodeKind::VariableDeclaration { declarator, variable: inner, .. } => self
+                        .handle_variable_declaration(declarator, inner, &[], inner.location, None),
+                    _ => self.handle_variable_declaration(
+                        "my",
+                        variable,
+                        &[],
+                        variable.location,
+                        None,
+                    ),
+                }
                 self.visit_node(list);
                 self.visit_node(body);
 
@@ -548,15 +591,22 @@ impl SymbolExtractor {
             }
 
             // Handle other node types by visiting children
-            NodeKind::Assignment { lhs, rhs, .. } => {
-                // Mark LHS as write reference
-                self.mark_write_reference(lhs);
-                self.visit_node(lhs);
+            NodeKind::Assignment { lhs, rhs, op } => {
+                // Plain `=` only writes its targets; compound forms like `+=` also read them
+                if op == "=" {
+                    self.mark_write_reference(lhs);
+                } else {
+                    self.visit_node(lhs);
+                }
                 self.visit_node(rhs);
             }
 
-            NodeKind::Binary { left, right, .. } => {
-                self.visit_node(left);
+            NodeKind::Binary { op, left, right } => {
+                if let Some(reference) = self.element_access_reference(op, left, right) {
+                    self.table.add_reference(reference);
+                } else {
+                    self.visit_node(left);
+                }
                 self.visit_node(right);
             }
 
@@ -565,10 +615,15 @@ impl SymbolExtractor {
             }
 
             NodeKind::FunctionCall { name, args } => {
-                // Track function call as a reference
+                // Track function call as a reference; `PI()` calls a constant
+                let kind = if self.is_known_constant(name) {
+                    SymbolKind::Constant
+                } else {
+                    SymbolKind::Subroutine
+                };
                 let reference = SymbolReference {
                     name: name.clone(),
-                    kind: SymbolKind::Subroutine,
+                    kind,
                     location: node.location,
                     scope_id: self.table.current_scope(),
                     is_write: false,
@@ -637,6 +692,18 @@ impl SymbolExtractor {
                 }
             }
 
+            NodeKind::LoopControl { label: Some(label), .. } => {
+                // The node ends with the label: `last OUTER`
+                let start = node.location.end.saturating_sub(label.len()).max(node.location.start);
+                self.table.add_reference(SymbolReference {
+                    name: label.clone(),
+                    kind: SymbolKind::Label,
+                    location: SourceLocation { start, end: node.location.end },
+                    scope_id: self.table.current_scope(),
+                    is_write: false,
+                });
+            }
+
             // Handle interpolated strings specially to extract variable references
             NodeKind::String { value, interpolated } => {
                 if *interpolated {
@@ -646,6 +713,9 @@ impl SymbolExtractor {
             }
 
             NodeKind::Use { module, args, .. } => {
+                if module == "constant" {
+                    self.extract_constant_declarations(node, args);
+                }
                 self.update_framework_context(module, args);
             }
 
@@ -667,10 +737,21 @@ impl SymbolExtractor {
                 self.visit_node(block);
             }
 
+            NodeKind::DoWhile { block, condition } | NodeKind::DoUntil { block, condition } => {
+                self.visit_node(block);
+                self.visit_node(condition);
+            }
+
             NodeKind::Try { body, catch_blocks, finally_block } => {
                 self.visit_node(body);
-                for (_, catch_block) in catch_blocks {
+                for (var, catch_block) in catch_blocks {
+                    // `catch ($e)` declares the error variable for the handler only
+                    self.table.push_scope(ScopeKind::Block, catch_block.location);
+                    if let Some(var) = var {
+                        self.handle_variable_declaration("my", var, &[], var.location, None);
+                    }
                     self.visit_node(catch_block);
+                    self.table.pop_scope();
                 }
                 if let Some(finally) = finally_block {
                     self.visit_node(finally);
@@ -691,7 +772,7 @@ impl SymbolExtractor {
                 self.visit_node(body);
             }
 
-            NodeKind::Class { name, body } => {
+            NodeKind::Class { name, body, .. } => {
                 let symbol = Symbol {
                     name: name.clone(),
                     qualified_name: name.clone(),
@@ -704,13 +785,33 @@ impl SymbolExtractor {
                 };
                 self.table.add_symbol(symbol);
 
-                self.table.push_scope(ScopeKind::Package, node.location);
-                self.visit_node(body);
-                self.table.pop_scope();
+                // `class Name;` (empty body span) applies to the rest of the file
+                let old_package = std::mem::replace(&mut self.table.current_package, name.clone());
+                if body.location.start < body.location.end {
+                    self.table.push_scope(ScopeKind::Package, node.location);
+                    self.visit_node(body);
+                    self.table.pop_scope();
+                    self.table.current_package = old_package;
+                }
+            }
+
+            NodeKind::Field { variable, attributes, default } => {
+                // Fields are instance attributes, visible to every method of the class
+                let doc = self.extract_leading_comment(node.location.start);
+                self.handle_variable_declaration(
+                    "field",
+                    variable,
+                    attributes,
+                    variable.location,
+                    doc,
+                );
+                if let Some(default) = default {
+                    self.visit_node(default);
+                }
             }
 
             NodeKind::Method { name, signature: _, attributes: _, body } => {
-                let documentation = self.extract_leading_comment(node.location.start);
+                let documentation = self.extract_sub_documentation(name, node.location.start);
                 let symbol = Symbol {
                     name: name.clone(),
                     qualified_name: format!("{}::{}", self.table.current_package, name),
@@ -756,8 +857,13 @@ impl SymbolExtractor {
             NodeKind::Substitution { expr, .. } => {
                 self.visit_node(expr);
             }
-            NodeKind::Transliteration { expr, .. } => {
-                self.visit_node(expr);
+            NodeKind::Transliteration { expr, modifiers, .. } => {
+                // `tr///r` returns a modified copy; every other form rewrites its target
+                if modifiers.contains('r') {
+                    self.visit_node(expr);
+                } else {
+                    self.mark_write_reference(expr);
+                }
             }
 
             NodeKind::IndirectCall { method, object, args } => {
@@ -780,15 +886,27 @@ impl SymbolExtractor {
                 self.visit_node(expression);
             }
 
+            NodeKind::Identifier { name } => {
+                // A bareword naming a `use constant` declaration is a use of it
+                if self.is_known_constant(name) {
+                    self.table.add_reference(SymbolReference {
+                        name: name.clone(),
+                        kind: SymbolKind::Constant,
+                        location: node.location,
+                        scope_id: self.table.current_scope(),
+                        is_write: false,
+                    });
+                }
+            }
+
             // Leaf nodes - no children to visit
             NodeKind::Number { .. }
             | NodeKind::Heredoc { .. }
             | NodeKind::Undef
             | NodeKind::Diamond
-            | NodeKind::Ellipsis
+            | NodeKind::YadaYada
             | NodeKind::Glob { .. }
             | NodeKind::Readline { .. }
-            | NodeKind::Identifier { .. }
             | NodeKind::Error { .. } => {
                 // No symbols to extract
             }
@@ -1015,6 +1133,122 @@ impl SymbolExtractor {
         true
     }
 
+    /// Record the constants declared by `use constant NAME => VALUE` or
+    /// `use constant { NAME => VALUE, ... }`, keeping each value for hover.
+    fn extract_constant_declarations(&mut self, node: &Node, args: &[String]) {
+        let scope_id = self.table.current_scope();
+        let package = self.table.current_package.clone();
+        let documentation = self.extract_leading_comment(node.location.start);
+
+        for (name, value) in Self::constant_definitions(args) {
+            let location = self.constant_name_location(node, &name);
+            self.table.add_symbol(Symbol {
+                qualified_name: format!("{package}::{name}"),
+                name,
+                kind: SymbolKind::Constant,
+                location,
+                scope_id,
+                declaration: Some("constant".to_string()),
+                documentation: documentation.clone(),
+                attributes: vec![format!("value={value}")],
+            });
+        }
+    }
+
+    /// Split `use constant` arguments into `(name, value)` pairs.
+    ///
+    /// The parser keeps the `=>` and `,` tokens inside a `{ ... }` list but
+    /// drops the `=>` of the single-pair form.
+    fn constant_definitions(args: &[String]) -> Vec<(String, String)> {
+        // Leading options such as `-nocheck`
+        let args: Vec<&str> = args
+            .iter()
+            .map(String::as_str)
+            .skip_while(|arg| arg.starts_with('-') || *arg == ",")
+            .collect();
+
+        let entries: Vec<&[&str]> = match args.as_slice() {
+            ["{", inner @ .., "}"] => {
+                let mut entries = Vec::new();
+                let mut depth = 0usize;
+                let mut entry_start = 0;
+                for (i, token) in inner.iter().enumerate() {
+                    match *token {
+                        "{" | "[" | "(" => depth += 1,
+                        "}" | "]" | ")" => depth = depth.saturating_sub(1),
+                        "," if depth == 0 => {
+                            entries.push(&inner[entry_start..i]);
+                            entry_start = i + 1;
+                        }
+                        _ => {}
+                    }
+                }
+                entries.push(&inner[entry_start..]);
+                entries
+            }
+            tokens => vec![tokens],
+        };
+
+        entries
+            .into_iter()
+            .filter_map(|entry| {
+                let (name, rest) = entry.split_first()?;
+                let name = Self::normalize_symbol_name(name)?;
+                let is_identifier = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                    && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+                if !is_identifier {
+                    return None;
+                }
+                let rest = rest.strip_prefix(&["=>"]).unwrap_or(rest);
+                Some((name, Self::join_value_tokens(rest)))
+            })
+            .collect()
+    }
+
+    /// Join value tokens back into source-like text: `[1, 2]`, `{a => 1}`.
+    fn join_value_tokens(tokens: &[&str]) -> String {
+        let mut text = String::new();
+        for token in tokens {
+            let after_opener = text.ends_with(['[', '(', '{']);
+            let closer_or_comma = matches!(*token, "]" | ")" | "}" | ",");
+            if !text.is_empty() && !after_opener && !closer_or_comma {
+                text.push(' ');
+            }
+            text.push_str(token);
+        }
+        if text.is_empty() { "undef".to_string() } else { text }
+    }
+
+    /// Span of a constant's name inside its `use constant` statement.
+    fn constant_name_location(&self, node: &Node, name: &str) -> SourceLocation {
+        let end = node.location.end.min(self.source.len());
+        let Some(statement) = self.source.get(node.location.start..end) else {
+            return node.location;
+        };
+        // Names follow the `constant` module word
+        let skip = statement.find("constant").map_or(0, |i| i + "constant".len());
+        let start = node.location.start + skip;
+        let text = &statement[skip..];
+        let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_';
+        text.match_indices(name)
+            .find(|(i, _)| {
+                !text[..*i].ends_with(is_ident) && !text[i + name.len()..].starts_with(is_ident)
+            })
+            .map_or(node.location, |(i, _)| SourceLocation {
+                start: start + i,
+                end: start + i + name.len(),
+            })
+    }
+
+    /// Whether `name` was declared by an earlier `use constant`
+    fn is_known_constant(&self, name: &str) -> bool {
+        let short = name.rsplit("::").next().unwrap_or(name);
+        self.table
+            .symbols
+            .get(short)
+            .is_some_and(|symbols| symbols.iter().any(|s| s.kind == SymbolKind::Constant))
+    }
+
     /// Update framework detection state from `use` statements.
     fn update_framework_context(&mut self, module: &str, args: &[String]) {
         if matches!(module, "Moo" | "Moose" | "Moo::Role" | "Moose::Role") {
@@ -1332,6 +1566,12 @@ impl SymbolExtractor {
         call_node.location
     }
 
+    /// Documentation for a sub: its matching POD entry, else its leading comment
+    fn extract_sub_documentation(&self, name: &str, start: usize) -> Option<String> {
+        crate::pod::find_sub_documentation(&self.source, name, start)
+            .or_else(|| self.extract_leading_comment(start))
+    }
+
     /// Extract a block of line comments immediately preceding a declaration
     fn extract_leading_comment(&self, start: usize) -> Option<String> {
         if self.source.is_empty() || start == 0 {
@@ -1417,52 +1657,116 @@ impl SymbolExtractor {
         }
     }
 
-    /// Mark a node as a write reference (used in assignments)
+    /// Record the targets of a plain assignment as write references
+    ///
+    /// Bare variables and lists of them (`($a, $b) = ...`) are writes; any
+    /// other target, such as `$h{key}` or `$$ref`, is visited normally since
+    /// it reads the container or the reference.
     fn mark_write_reference(&mut self, node: &Node) {
-        // This is a simplified version - in practice we'd need to handle
-        // more complex LHS patterns like array/hash subscripts
-        if let NodeKind::Variable { .. } = &node.kind {
-            // The reference will be marked as write when we visit it
-            // This would require passing context down through visit_node
+        match &node.kind {
+            NodeKind::Variable { sigil, name } => {
+                let kind = match sigil.as_str() {
+                    "$" => SymbolKind::scalar(),
+                    "@" => SymbolKind::array(),
+                    "%" => SymbolKind::hash(),
+                    _ => return,
+                };
+                self.table.add_reference(SymbolReference {
+                    name: name.clone(),
+                    kind,
+                    location: node.location,
+                    scope_id: self.table.current_scope(),
+                    is_write: true,
+                });
+            }
+            NodeKind::ArrayLiteral { elements } => {
+                for element in elements {
+                    self.mark_write_reference(element);
+                }
+            }
+            _ => self.visit_node(node),
+        }
+    }
+
+    /// Reference to the container of an element access or slice
+    ///
+    /// `$h{key}` and `@h{...}` refer to `%h`; `$a[0]` and `@a[...]` refer to
+    /// `@a`. Arrow dereferences (`$r->{key}`) parse the same way, so the
+    /// source text is checked and they are left as reads of the scalar.
+    fn element_access_reference(
+        &self,
+        op: &str,
+        left: &Node,
+        right: &Node,
+    ) -> Option<SymbolReference> {
+        let kind = match op {
+            "{}" => SymbolKind::hash(),
+            "[]" => SymbolKind::array(),
+            _ => return None,
+        };
+        let NodeKind::Variable { sigil, name } = &left.kind else {
+            return None;
+        };
+        let between = self.source.get(left.location.end..right.location.start)?;
+        if !(sigil == "$" || sigil == "@") || between.contains("->") {
+            return None;
         }
+
+        Some(SymbolReference {
+            name: name.clone(),
+            kind,
+            location: left.location,
+            scope_id: self.table.current_scope(),
+            is_write: false,
+        })
     }
 
     /// Extract variable references from an interpolated string
+    ///
+    /// Handles `$var`, `${var}`, `@var`, and element forms such as
+    /// `$var[0]`/`$var{key}`, which refer to the array or hash. Sigils
+    /// escaped with a backslash are skipped.
     fn extract_vars_from_string(&mut self, value: &str, string_location: SourceLocation) {
-        // Simple regex to find scalar variables in strings
-        // This handles $var, ${var}, but not arrays/hashes for now
-        let Ok(scalar_re) = Regex::new(r"\$([a-zA-Z_]\w*|\{[a-zA-Z_]\w*\})") else {
+        static VAR_RE: OnceLock<Result<Regex, regex::Error>> = OnceLock::new();
+        let Ok(var_re) = VAR_RE
+            .get_or_init(|| Regex::new(r"(\\*)([$@])(?:\{([a-zA-Z_]\w*)\}|([a-zA-Z_]\w*))([\[{])?"))
+            .as_ref()
+        else {
             return; // Skip variable extraction if regex fails
         };
 
         // The value includes quotes, so strip them
         let content = if value.len() >= 2 { &value[1..value.len() - 1] } else { value };
 
-        for cap in scalar_re.captures_iter(content) {
-            if let Some(m) = cap.get(0) {
-                let var_name = if m.as_str().starts_with("${") && m.as_str().ends_with("}") {
-                    // Handle ${var} format
-                    &m.as_str()[2..m.as_str().len() - 1]
-                } else {
-                    // Handle $var format
-                    &m.as_str()[1..]
-                };
+        for cap in var_re.captures_iter(content) {
+            // An odd run of backslashes escapes the sigil
+            if cap.get(1).is_some_and(|m| m.as_str().len() % 2 == 1) {
+                continue;
+            }
+            let Some(name) = cap.get(3).or_else(|| cap.get(4)) else {
+                continue;
+            };
 
-                // Calculate the location within the original string
-                // This is approximate - in the actual string location
-                let start_offset = string_location.start + 1 + m.start(); // +1 for opening quote
-                let end_offset = start_offset + m.len();
+            let subscript = cap.get(5).map(|m| m.as_str());
+            let kind = match (&cap[2], subscript) {
+                ("@", Some("{")) | ("$", Some("{")) => SymbolKind::hash(),
+                ("@", _) | ("$", Some("[")) => SymbolKind::array(),
+                _ => SymbolKind::scalar(),
+            };
 
-                let reference = SymbolReference {
-                    name: var_name.to_string(),
-                    kind: SymbolKind::scalar(),
-                    location: SourceLocation { start: start_offset, end: end_offset },
-                    scope_id: self.table.current_scope(),
-                    is_write: false,
-                };
+            // Location of the sigil and name, past the opening quote
+            let sigil_start = cap.get(2).map_or(0, |m| m.start());
+            let start_offset = string_location.start + 1 + sigil_start;
+            let end_offset =
+                string_location.start + 1 + name.end() + usize::from(cap.get(3).is_some());
 
-                self.table.add_reference(reference);
-            }
+            self.table.add_reference(SymbolReference {
+                name: name.as_str().to_string(),
+                kind,
+                location: SourceLocation { start: start_offset, end: end_offset },
+                scope_id: self.table.current_scope(),
+                is_write: false,
+            });
         }
     }
 }
@@ -1471,7 +1775,7 @@ impl SymbolExtractor {
 mod tests {
     use super::*;
     use crate::parser::Parser;
-    use perl_tdd_support::must;
+    use perl_tdd_support::{must, must_some};
 
     #[test]
     fn test_symbol_extraction() {
@@ -1510,4 +1814,172 @@ sub bar {
         assert_eq!(bar_symbols.len(), 1);
         assert_eq!(bar_symbols[0].kind, SymbolKind::Subroutine);
     }
+
+    #[test]
+    fn test_transliteration_target_is_write_unless_r_modifier() {
+        let code = "my $x = 'abc';\n$x =~ tr/a/b/;\nmy $y = $x =~ tr/a/b/r;\n";
+
+        let mut parser = Parser::new(code);
+        let ast = must(parser.parse());
+        let table = SymbolExtractor::new_with_source(code).extract(&ast);
+
+        let mut refs: Vec<_> =
+            table.references.get("x").map(|r| r.as_slice()).unwrap_or_default().iter().collect();
+        refs.sort_by_key(|r| r.location.start);
+        let writes: Vec<bool> = refs.iter().map(|r| r.is_write).collect();
+        assert_eq!(writes, [true, false]);
+    }
+
+    #[test]
+    fn test_class_fields_are_symbols_resolved_from_methods() {
+        let code = r#"
+class Point {
+    field $x :param = 0;
+    field @items;
+    method move ($dx) { $x += $dx; push @items, $x; }
+}
+"#;
+
+        let mut parser = Parser::new(code);
+        let ast = must(parser.parse());
+        let table = SymbolExtractor::new_with_source(code).extract(&ast);
+
+        let x = &table.symbols["x"][0];
+        assert_eq!(x.declaration.as_deref(), Some("field"));
+        assert_eq!(x.attributes, ["param"]);
+        assert_eq!(table.symbols["items"][0].kind, SymbolKind::array());
+        assert_eq!(table.symbols["move"][0].qualified_name, "Point::move");
+
+        // Uses inside the method body bind to the field declarations
+        let uses: Vec<_> =
+            table.references.get("x").map(|r| r.as_slice()).unwrap_or_default().iter().collect();
+        assert!(!uses.is_empty());
+        for reference in uses {
+            let symbol = table.resolve_reference(reference);
+            assert_eq!(symbol.and_then(|s| s.declaration.as_deref()), Some("field"));
+        }
+    }
+
+    #[test]
+    fn test_loop_control_labels_resolve_to_enclosing_labeled_loop() {
+        let code = r#"
+OUTER: for my $i (1..3) {
+    INNER: for my $j (1..3) {
+        next INNER if $j == 2;
+        last OUTER if $i == $j;
+    }
+}
+OUTER: while (1) { last OUTER; }
+"#;
+
+        let mut parser = Parser::new(code);
+        let ast = must(parser.parse());
+        let table = SymbolExtractor::new_with_source(code).extract(&ast);
+
+        let outer: Vec<_> = table.symbols["OUTER"].iter().map(|s| s.location.start).collect();
+        assert_eq!(outer.len(), 2);
+        assert!(table.symbols["INNER"].iter().all(|s| s.kind == SymbolKind::Label));
+
+        let last_outer = must_some(code.find("last OUTER"));
+        let mut refs: Vec<_> = table.references["OUTER"].iter().collect();
+        refs.sort_by_key(|r| r.location.start);
+        assert_eq!(refs.len(), 2);
+        assert_eq!(&code[refs[0].location.start..refs[0].location.end], "OUTER");
+        assert_eq!(refs[0].location.start, last_outer + "last ".len());
+
+        // Each `last OUTER` binds to the loop it sits in, not a same-named sibling
+        let targets: Vec<_> =
+            refs.iter().map(|r| table.resolve_reference(r).map(|s| s.location.start)).collect();
+        assert_eq!(targets, [Some(outer[0]), Some(outer[1])]);
+
+        let next_inner = &table.references["INNER"][0];
+        assert_eq!(
+            table.resolve_reference(next_inner).map(|s| s.location.start),
+            table.symbols["INNER"].first().map(|s| s.location.start)
+        );
+    }
+
+    #[test]
+    fn test_use_constant_single_pair_form() {
+        let code =
+            "use constant PI => 3.14159;\nsub area { my $r = shift; return PI * $r ** 2; }\n";
+
+        let mut parser = Parser::new(code);
+        let ast = must(parser.parse());
+        let table = SymbolExtractor::new_with_source(code).extract(&ast);
+
+        let pi = &table.symbols["PI"][0];
+        assert_eq!(pi.kind, SymbolKind::Constant);
+        assert_eq!(pi.qualified_name, "main::PI");
+        assert_eq!(pi.declaration.as_deref(), Some("constant"));
+        assert_eq!(pi.attributes, ["value=3.14159"]);
+        assert_eq!(&code[pi.location.start..pi.location.end], "PI");
+
+        let uses = &table.references["PI"];
+        assert_eq!(uses.len(), 1);
+        assert_eq!(uses[0].kind, SymbolKind::Constant);
+        assert_eq!(table.resolve_reference(&uses[0]).map(|s| s.location), Some(pi.location));
+    }
+
+    #[test]
+    fn test_use_constant_hash_form() {
+        let code = r#"package Math;
+use constant { E => 2.718, PHI => 1.618, PRIMES => [2, 3, 5] };
+my $golden = PHI + E();
+"#;
+
+        let mut parser = Parser::new(code);
+        let ast = must(parser.parse());
+        let table = SymbolExtractor::new_with_source(code).extract(&ast);
+
+        let constants: Vec<(&str, &str)> = ["E", "PHI", "PRIMES"]
+            .iter()
+            .map(|name| {
+                let symbol = &table.symbols[*name][0];
+                assert_eq!(symbol.kind, SymbolKind::Constant);
+                assert_eq!(symbol.qualified_name, format!("Math::{name}"));
+                (*name, symbol.attributes[0].as_str())
+            })
+            .collect();
+        assert_eq!(
+            constants,
+            [("E", "value=2.718"), ("PHI", "value=1.618"), ("PRIMES", "value=[2, 3, 5]")]
+        );
+
+        // Both the bareword and the call form resolve to the declaration
+        for name in ["PHI", "E"] {
+            let reference = &table.references[name][0];
+            assert_eq!(reference.kind, SymbolKind::Constant);
+            let declared = table.resolve_reference(reference).map(|s| s.location.start);
+            assert_eq!(declared, must_some(code.find(&format!(" {name} =>"))).checked_add(1));
+        }
+    }
+
+    #[test]
+    fn test_catch_variable_resolves_inside_catch_block() {
+        let code = r#"
+use feature 'try';
+my $e = "outer";
+try { die "boom" } catch ($e) { warn $e; } finally { print $e; }
+"#;
+
+        let mut parser = Parser::new(code);
+        let ast = must(parser.parse());
+        let table = SymbolExtractor::new_with_source(code).extract(&ast);
+
+        let catch_start = code.find("catch").unwrap_or_default();
+        let declaration_of = |offset: usize| {
+            table.references["e"]
+                .iter()
+                .find(|r| r.location.start == offset)
+                .and_then(|r| table.resolve_reference(r))
+                .map(|s| s.location.start)
+        };
+
+        // `warn $e` binds to the catch variable, `print $e` to the outer `my`
+        let warn_use = code.find("warn $e").unwrap_or_default() + 5;
+        let print_use = code.find("print $e").unwrap_or_default() + 6;
+        assert_eq!(declaration_of(warn_use), Some(catch_start + 7));
+        assert!(declaration_of(print_use).is_some_and(|start| start < catch_start));
+    }
 }
@@ -54,6 +54,17 @@ pub enum ScalarType {
     Mixed,
 }
 
+/// Context an expression is evaluated in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExpressionContext {
+    /// A single value is expected, so arrays yield their element count
+    Scalar,
+    /// A list of values is expected, so arrays yield their elements
+    List,
+    /// The value is discarded
+    Void,
+}
+
 /// Type constraint for type checking
 #[derive(Debug, Clone)]
 pub struct TypeConstraint {
@@ -145,6 +156,12 @@ pub struct TypeInferenceEngine {
     builtins: HashMap<String, PerlType>,
     /// Type aliases from use statements
     _type_aliases: HashMap<String, PerlType>,
+    /// Inferred type of each declaration, keyed by the declared variable's start offset
+    declaration_types: HashMap<usize, PerlType>,
+    /// Package in effect at the node being inferred
+    current_package: String,
+    /// Evaluation context of each expression whose context is known, as `(start, end, context)`
+    expression_contexts: Vec<(usize, usize, ExpressionContext)>,
 }
 
 impl Default for TypeInferenceEngine {
@@ -161,6 +178,9 @@ impl TypeInferenceEngine {
             constraints: Vec::new(),
             builtins: HashMap::new(),
             _type_aliases: HashMap::new(),
+            declaration_types: HashMap::new(),
+            current_package: "main".to_string(),
+            expression_contexts: Vec::new(),
         };
 
         // Initialize built-in function types
@@ -258,6 +278,9 @@ impl TypeInferenceEngine {
         // Solution: Temporarily take `global_env` out of `self`, use it, then put it back?
         // Or change `infer_node` signature?
 
+        self.expression_contexts.clear();
+        record_contexts(ast, None, &mut self.expression_contexts);
+
         // For now, let's just make `infer` work by swapping.
         let mut env = std::mem::take(&mut self.global_env);
         let result = self.infer_node(ast, &mut env);
@@ -444,6 +467,15 @@ impl TypeInferenceEngine {
                 }
             }
 
+            // `scalar @list` is the element count; `scalar $x` is just `$x`
+            NodeKind::FunctionCall { name, args } if name == "scalar" && args.len() == 1 => {
+                match self.infer_node(&args[0], env)? {
+                    Array(_) | Hash { .. } => Ok(Scalar(Integer)),
+                    ty @ Scalar(_) => Ok(ty),
+                    _ => Ok(Any),
+                }
+            }
+
             NodeKind::FunctionCall { name, args: _ } => {
                 let func_name = name.clone();
 
@@ -460,8 +492,11 @@ impl TypeInferenceEngine {
                     }
                 }
 
-                // Check user-defined functions
-                if let Some(ty) = env.get_subroutine(&func_name) {
+                // Check user-defined functions, preferring the current package's
+                let user_sub = env
+                    .get_subroutine(&self.qualify(&func_name))
+                    .or_else(|| env.get_subroutine(&func_name));
+                if let Some(ty) = user_sub {
                     if let Subroutine { returns, .. } = ty {
                         if returns.len() == 1 {
                             return Ok(returns[0].clone());
@@ -477,6 +512,12 @@ impl TypeInferenceEngine {
                 Ok(Any)
             }
 
+            // `Class->new(...)` constructs an instance of `Class`
+            NodeKind::MethodCall { object, method, .. } if method == "new" => match &object.kind {
+                NodeKind::Identifier { name } => Ok(Object(name.clone())),
+                _ => Ok(Any),
+            },
+
             NodeKind::Subroutine { name, body, .. } => {
                 // Create new scope for subroutine
                 let mut sub_env = TypeEnvironment::with_parent(env.clone());
@@ -484,29 +525,36 @@ impl TypeInferenceEngine {
                 // Default to accepting any parameters for now
                 let param_types = vec![Any];
 
-                // Infer return type from body
-                // In Perl, the return value is the value of the last statement
-                // or explicit return statement.
-                // Our `infer_node` for Block already returns the type of the last statement.
-                // We should also scan for `Return` nodes, but for now we just use the block result
-                // which handles the implicit return of the last statement.
-                // Note: explicit returns inside control flow (if/else) are tricky to unify without
-                // a full control flow graph, but `infer_node` recurses so it should pick up types.
-                // Wait, `infer_node` for `Program`/`Block` returns the type of the *last* statement.
-                // It does NOT unify types from intermediate `return` statements.
-
-                // For better accuracy, we should probably scan the body for `Return` nodes?
-                // But `infer_node(body)` calls `infer_node` recursively.
-                // If we want to support explicit returns not at the end, we need to handle `Return` node
-                // to return a special type or track returns in `TypeInferenceEngine`.
-
-                // For now, let's trust `infer_node(body)` to return the implicit return type of the block.
-                let return_type = self.infer_node(body, &mut sub_env)?;
+                // The return type unifies every `return` in the body with the
+                // implicit return of the last statement. A forward declaration
+                // (`sub foo;`) has no body to infer from.
+                let return_type = match body {
+                    Some(body) => {
+                        let implicit = self.infer_node(body, &mut sub_env)?;
+
+                        let mut returns = Vec::new();
+                        collect_returns(body, &mut returns);
+                        let mut types = Vec::with_capacity(returns.len() + 1);
+                        for value in returns {
+                            types.push(match value {
+                                Some(value) => self.infer_node(value, &mut sub_env)?,
+                                None => Void,
+                            });
+                        }
+                        if !ends_with_return(body) {
+                            types.push(implicit);
+                        }
+                        self.unify_return_types(&types)
+                    }
+                    None => Any,
+                };
 
                 let sub_type = Subroutine { params: param_types, returns: vec![return_type] };
 
-                // Register subroutine in environment
+                // Register under the bare name for same-package calls and under
+                // the qualified name for calls from other packages
                 if let Some(sub_name) = name {
+                    env.set_subroutine(self.qualify(sub_name), sub_type.clone());
                     env.set_subroutine(sub_name.clone(), sub_type.clone());
                 }
 
@@ -578,6 +626,7 @@ impl TypeInferenceEngine {
                     // Store in both environments using the name WITHOUT sigil
                     self.global_env.set_variable(clean_name.to_string(), inferred_type.clone());
                     env.set_variable(clean_name.to_string(), inferred_type.clone());
+                    self.declaration_types.insert(variable.location.start, inferred_type.clone());
 
                     inferred_type
                 } else {
@@ -618,6 +667,19 @@ impl TypeInferenceEngine {
                 }
             }
 
+            NodeKind::Package { name, block, .. } => {
+                match block {
+                    Some(block) => {
+                        let outer = std::mem::replace(&mut self.current_package, name.clone());
+                        let result = self.infer_node(block, env);
+                        self.current_package = outer;
+                        result?;
+                    }
+                    None => self.current_package = name.clone(),
+                }
+                Ok(Void)
+            }
+
             NodeKind::Block { statements } => {
                 let mut last_type = Void;
                 for stmt in statements {
@@ -642,6 +704,32 @@ impl TypeInferenceEngine {
         Ok(vec![PerlType::Any])
     }
 
+    /// Qualify a subroutine name with the current package unless it already is
+    fn qualify(&self, name: &str) -> String {
+        if name.contains("::") {
+            name.to_string()
+        } else {
+            format!("{}::{name}", self.current_package)
+        }
+    }
+
+    /// Unify the types a subroutine can return
+    ///
+    /// A bare `return;` yields `undef` or an empty list, which fits any other
+    /// return type, so it only counts when nothing else is returned.
+    /// Incompatible types widen to `Any` rather than a union.
+    fn unify_return_types(&self, types: &[PerlType]) -> PerlType {
+        let values: Vec<PerlType> =
+            types.iter().filter(|t| **t != PerlType::Void).cloned().collect();
+        if values.is_empty() {
+            return PerlType::Void;
+        }
+        match self.unify_types(&values) {
+            PerlType::Union(_) => PerlType::Any,
+            ty => ty,
+        }
+    }
+
     /// Extract variable name from a node
     fn extract_var_name(&self, node: &Node) -> String {
         match &node.kind {
@@ -797,7 +885,30 @@ impl TypeInferenceEngine {
         self.global_env.get_variable(name).cloned()
     }
 
+    /// Gets the inferred type of the declaration whose variable starts at `offset`
+    ///
+    /// Unlike [`Self::get_type_at`], this distinguishes redeclarations of the
+    /// same name in different scopes.
+    pub fn get_declaration_type(&self, offset: usize) -> Option<&PerlType> {
+        self.declaration_types.get(&offset)
+    }
+
+    /// Gets the evaluation context of the innermost expression containing `offset`
+    ///
+    /// Returns `None` where the context is decided by a caller, such as the
+    /// value a subroutine returns.
+    pub fn context_at(&self, offset: usize) -> Option<ExpressionContext> {
+        self.expression_contexts
+            .iter()
+            .filter(|(start, end, _)| *start <= offset && offset < *end)
+            .min_by_key(|(start, end, _)| end - start)
+            .map(|(_, _, context)| *context)
+    }
+
     /// Gets the inferred type signature for a subroutine
+    ///
+    /// Accepts a bare name (`get_int`) or a package-qualified one
+    /// (`main::get_int`, `My::Module::new`).
     pub fn get_subroutine(&self, name: &str) -> Option<PerlType> {
         self.global_env.get_subroutine(name).cloned()
     }
@@ -812,6 +923,210 @@ impl TypeInferenceEngine {
     }
 }
 
+/// Builtins whose arguments are each evaluated in scalar context
+const SCALAR_ARGUMENT_BUILTINS: &[&str] = &[
+    "scalar", "defined", "ref", "length", "lc", "uc", "lcfirst", "ucfirst", "chr", "ord", "abs",
+    "int", "sqrt", "hex", "oct",
+];
+
+/// Record the evaluation context of `node` and of its subexpressions
+///
+/// `context` is `None` where it cannot be decided locally.
+fn record_contexts(
+    node: &Node,
+    context: Option<ExpressionContext>,
+    out: &mut Vec<(usize, usize, ExpressionContext)>,
+) {
+    use ExpressionContext::*;
+
+    let structural = matches!(
+        node.kind,
+        NodeKind::Program { .. } | NodeKind::Block { .. } | NodeKind::ExpressionStatement { .. }
+    );
+    if let Some(context) = context
+        && !structural
+    {
+        out.push((node.location.start, node.location.end, context));
+    }
+
+    match &node.kind {
+        NodeKind::Program { statements } | NodeKind::Block { statements } => {
+            for statement in statements {
+                record_contexts(statement, Some(Void), out);
+            }
+        }
+        NodeKind::ExpressionStatement { expression } => record_contexts(expression, context, out),
+        NodeKind::Subroutine { body: Some(body), .. } | NodeKind::Method { body, .. } => {
+            record_sub_body_contexts(body, out);
+        }
+        NodeKind::VariableDeclaration { variable, initializer: Some(init), .. } => {
+            record_contexts(init, Some(assignment_context(variable)), out);
+        }
+        NodeKind::VariableListDeclaration { initializer: Some(init), .. } => {
+            record_contexts(init, Some(List), out);
+        }
+        NodeKind::Assignment { lhs, rhs, op } => {
+            let rhs_context = if op == "=" { assignment_context(lhs) } else { Scalar };
+            record_contexts(rhs, Some(rhs_context), out);
+            record_contexts(lhs, None, out);
+        }
+        NodeKind::If { condition, then_branch, elsif_branches, else_branch } => {
+            record_contexts(condition, Some(Scalar), out);
+            record_contexts(then_branch, None, out);
+            for (condition, branch) in elsif_branches {
+                record_contexts(condition, Some(Scalar), out);
+                record_contexts(branch, None, out);
+            }
+            if let Some(branch) = else_branch {
+                record_contexts(branch, None, out);
+            }
+        }
+        NodeKind::While { condition, body, continue_block } => {
+            record_contexts(condition, Some(Scalar), out);
+            record_contexts(body, None, out);
+            if let Some(block) = continue_block {
+                record_contexts(block, None, out);
+            }
+        }
+        NodeKind::DoWhile { block, condition } | NodeKind::DoUntil { block, condition } => {
+            record_contexts(block, None, out);
+            record_contexts(condition, Some(Scalar), out);
+        }
+        NodeKind::For { init, condition, update, body, continue_block } => {
+            if let Some(init) = init {
+                record_contexts(init, Some(Void), out);
+            }
+            if let Some(condition) = condition {
+                record_contexts(condition, Some(Scalar), out);
+            }
+            if let Some(update) = update {
+                record_contexts(update, Some(Void), out);
+            }
+            record_contexts(body, None, out);
+            if let Some(block) = continue_block {
+                record_contexts(block, None, out);
+            }
+        }
+        NodeKind::Foreach { list, body, continue_block, .. } => {
+            record_contexts(list, Some(List), out);
+            record_contexts(body, None, out);
+            if let Some(block) = continue_block {
+                record_contexts(block, None, out);
+            }
+        }
+        NodeKind::StatementModifier { statement, modifier, condition } => {
+            record_contexts(statement, context, out);
+            let condition_context =
+                if modifier == "for" || modifier == "foreach" { List } else { Scalar };
+            record_contexts(condition, Some(condition_context), out);
+        }
+        NodeKind::Ternary { condition, then_expr, else_expr } => {
+            record_contexts(condition, Some(Scalar), out);
+            record_contexts(then_expr, context, out);
+            record_contexts(else_expr, context, out);
+        }
+        NodeKind::Binary { op, left, right } => match op.as_str() {
+            "[]" | "{}" => {
+                let slice = matches!(&left.kind, NodeKind::Variable { sigil, .. } if sigil != "$");
+                record_contexts(left, None, out);
+                record_contexts(right, Some(if slice { List } else { Scalar }), out);
+            }
+            "&&" | "||" | "//" | "and" | "or" => {
+                record_contexts(left, Some(Scalar), out);
+                record_contexts(right, context, out);
+            }
+            _ => {
+                record_contexts(left, Some(Scalar), out);
+                record_contexts(right, Some(Scalar), out);
+            }
+        },
+        NodeKind::Unary { op, operand } => {
+            // `\@a` takes a reference without evaluating the array
+            let operand_context = if op == "\\" { None } else { Some(Scalar) };
+            record_contexts(operand, operand_context, out);
+        }
+        NodeKind::FunctionCall { name, args } => {
+            let arg_context =
+                if SCALAR_ARGUMENT_BUILTINS.contains(&name.as_str()) { Scalar } else { List };
+            for arg in args {
+                record_contexts(arg, Some(arg_context), out);
+            }
+        }
+        NodeKind::MethodCall { object, args, .. } => {
+            record_contexts(object, Some(Scalar), out);
+            for arg in args {
+                record_contexts(arg, Some(List), out);
+            }
+        }
+        NodeKind::IndirectCall { object, args, .. } => {
+            record_contexts(object, None, out);
+            for arg in args {
+                record_contexts(arg, Some(List), out);
+            }
+        }
+        NodeKind::Return { value: Some(value) } => record_contexts(value, None, out),
+        NodeKind::ArrayLiteral { elements } => {
+            for element in elements {
+                record_contexts(element, context, out);
+            }
+        }
+        NodeKind::HashLiteral { .. } => {
+            node.for_each_child(|child| record_contexts(child, Some(List), out));
+        }
+        _ => node.for_each_child(|child| record_contexts(child, None, out)),
+    }
+}
+
+/// Record contexts in a subroutine body, whose last statement is the return value
+fn record_sub_body_contexts(body: &Node, out: &mut Vec<(usize, usize, ExpressionContext)>) {
+    if let NodeKind::Block { statements } = &body.kind
+        && let Some((last, rest)) = statements.split_last()
+    {
+        for statement in rest {
+            record_contexts(statement, Some(ExpressionContext::Void), out);
+        }
+        record_contexts(last, None, out);
+    } else {
+        record_contexts(body, None, out);
+    }
+}
+
+/// Context an assignment to `lhs` imposes on its right-hand side
+fn assignment_context(lhs: &Node) -> ExpressionContext {
+    match &lhs.kind {
+        NodeKind::Variable { sigil, .. } if sigil == "$" => ExpressionContext::Scalar,
+        NodeKind::Variable { .. }
+        | NodeKind::VariableListDeclaration { .. }
+        | NodeKind::ArrayLiteral { .. } => ExpressionContext::List,
+        NodeKind::VariableDeclaration { variable, .. } => assignment_context(variable),
+        NodeKind::Binary { op, left, .. } if op == "[]" || op == "{}" => match &left.kind {
+            NodeKind::Variable { sigil, .. } if sigil != "$" => ExpressionContext::List,
+            _ => ExpressionContext::Scalar,
+        },
+        _ => ExpressionContext::Scalar,
+    }
+}
+
+/// Collect the value of every `return` under `node`, skipping nested subroutines
+fn collect_returns<'a>(node: &'a Node, out: &mut Vec<Option<&'a Node>>) {
+    match &node.kind {
+        NodeKind::Return { value } => out.push(value.as_deref()),
+        NodeKind::Subroutine { .. } | NodeKind::Method { .. } => return,
+        _ => {}
+    }
+    node.for_each_child(|child| collect_returns(child, out));
+}
+
+/// Whether the last statement of a subroutine body is a `return`
+fn ends_with_return(body: &Node) -> bool {
+    match &body.kind {
+        NodeKind::Block { statements } => statements.last().is_some_and(ends_with_return),
+        NodeKind::ExpressionStatement { expression } => ends_with_return(expression),
+        NodeKind::Return { .. } => true,
+        _ => false,
+    }
+}
+
 /// Type-based code completion suggestions
 pub struct TypeBasedCompletion {
     /// Shared reference to the type inference engine
@@ -1103,4 +1418,83 @@ mod tests {
         assert!(hash_completions.iter().any(|c| c.label == "keys"));
         assert!(hash_completions.iter().any(|c| c.label == "values"));
     }
+
+    #[test]
+    fn test_declaration_types_by_offset() {
+        let mut engine = TypeInferenceEngine::new();
+
+        let code = "my @items = (1, 2); my $count = scalar @items; my $x = 1; { my $x = 'a'; }";
+        let ast = must(Parser::new(code).parse());
+        let _result = engine.infer(&ast);
+
+        let offset = |needle: &str| code.find(needle).map_or(usize::MAX, |i| i + 3);
+        assert_eq!(
+            engine.get_declaration_type(offset("my $count")),
+            Some(&PerlType::Scalar(ScalarType::Integer))
+        );
+        assert_eq!(
+            engine.get_declaration_type(offset("my $x = 1")),
+            Some(&PerlType::Scalar(ScalarType::Integer))
+        );
+        assert_eq!(
+            engine.get_declaration_type(offset("my $x = 'a'")),
+            Some(&PerlType::Scalar(ScalarType::String))
+        );
+    }
+
+    #[test]
+    fn test_array_in_scalar_assignment_has_scalar_context() {
+        let mut engine = TypeInferenceEngine::new();
+
+        let code = "my @a = (1, 2); my $n = @a;";
+        let ast = must(Parser::new(code).parse());
+        let _result = engine.infer(&ast);
+
+        let offset = code.rfind("@a").unwrap_or(usize::MAX);
+        assert_eq!(engine.context_at(offset), Some(ExpressionContext::Scalar));
+    }
+
+    #[test]
+    fn test_array_in_list_assignment_has_list_context() {
+        let mut engine = TypeInferenceEngine::new();
+
+        let code = "my @a = (1, 2); my @b = @a; my ($first) = @a;";
+        let ast = must(Parser::new(code).parse());
+        let _result = engine.infer(&ast);
+
+        let copy = code.find("= @a").map_or(usize::MAX, |i| i + 2);
+        let unpack = code.rfind("@a").unwrap_or(usize::MAX);
+        assert_eq!(engine.context_at(copy), Some(ExpressionContext::List));
+        assert_eq!(engine.context_at(unpack), Some(ExpressionContext::List));
+    }
+
+    #[test]
+    fn test_expression_statement_has_void_context() {
+        let mut engine = TypeInferenceEngine::new();
+
+        let code = "my @a = (1, 2);\n@a;\nif (@a) { print scalar(@a); }";
+        let ast = must(Parser::new(code).parse());
+        let _result = engine.infer(&ast);
+
+        let offset = |needle: &str| code.find(needle).map_or(usize::MAX, |i| i + 1);
+        assert_eq!(engine.context_at(offset("\n@a;")), Some(ExpressionContext::Void));
+        assert_eq!(engine.context_at(offset("(@a)")), Some(ExpressionContext::Scalar));
+        assert_eq!(engine.context_at(offset("(@a);")), Some(ExpressionContext::Scalar));
+    }
+
+    #[test]
+    fn test_constructor_call_infers_object() {
+        let mut engine = TypeInferenceEngine::new();
+
+        let code = "my $dog = Animal::Dog->new(name => 'Rex'); my $obj = $dog->clone;";
+        let ast = must(Parser::new(code).parse());
+        let _result = engine.infer(&ast);
+
+        let offset = |needle: &str| code.find(needle).map_or(usize::MAX, |i| i + 3);
+        assert_eq!(
+            engine.get_declaration_type(offset("my $dog")),
+            Some(&PerlType::Object("Animal::Dog".to_string()))
+        );
+        assert_eq!(engine.get_declaration_type(offset("my $obj")), Some(&PerlType::Any));
+    }
 }
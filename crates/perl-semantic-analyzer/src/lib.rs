@@ -55,6 +55,7 @@ pub use analysis::dead_code_detector;
 pub use analysis::declaration;
 #[cfg(not(target_arch = "wasm32"))]
 pub use analysis::index;
+pub use analysis::pod;
 pub use analysis::scope_analyzer;
 pub use analysis::semantic;
 pub use analysis::symbol;
@@ -179,7 +179,7 @@ impl RefactoringAnalyzer {
         suggestions: &mut Vec<RefactoringSuggestion>,
     ) {
         match &node.kind {
-            NodeKind::Subroutine { name, signature, body, .. } => {
+            NodeKind::Subroutine { name, signature, body: Some(body), .. } => {
                 let sub_name = name.clone().unwrap_or_else(|| "anonymous".to_string());
 
                 // Check parameter count
@@ -468,10 +468,10 @@ mod tests {
                         prototype: None,
                         signature: None,
                         attributes: vec![],
-                        body: Box::new(Node::new(
+                        body: Some(Box::new(Node::new(
                             NodeKind::Block { statements: vec![] },
                             SourceLocation { start: 0, end: 0 },
-                        )),
+                        ))),
                     },
                     SourceLocation { start: 0, end: 0 },
                 )],
@@ -576,10 +576,10 @@ mod tests {
                     SourceLocation { start: 0, end: 0 },
                 ))),
                 attributes: vec![],
-                body: Box::new(Node::new(
+                body: Some(Box::new(Node::new(
                     NodeKind::Block { statements: vec![] },
                     SourceLocation { start: 0, end: 0 },
-                )),
+                ))),
             },
             SourceLocation { start: 0, end: 0 },
         );
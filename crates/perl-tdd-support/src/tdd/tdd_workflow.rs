@@ -618,10 +618,10 @@ mod tests {
                 name: Some("multiply".to_string()),
                 name_span: Some(SourceLocation { start: 4, end: 12 }),
                 signature: None,
-                body: Box::new(Node::new(
+                body: Some(Box::new(Node::new(
                     NodeKind::Block { statements: vec![] },
                     SourceLocation { start: 0, end: 0 },
-                )),
+                ))),
                 attributes: vec![],
                 prototype: None,
             },
@@ -681,10 +681,10 @@ mod tests {
                     NodeKind::Signature { parameters },
                     SourceLocation { start: 0, end: 0 },
                 ))),
-                body: Box::new(Node::new(
+                body: Some(Box::new(Node::new(
                     NodeKind::Block { statements: vec![] },
                     SourceLocation { start: 0, end: 0 },
-                )),
+                ))),
                 attributes: vec![],
                 prototype: None,
             },
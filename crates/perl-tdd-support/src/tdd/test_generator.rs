@@ -1120,10 +1120,10 @@ mod tests {
                     },
                     crate::ast::SourceLocation { start: 0, end: 0 },
                 ))),
-                body: Box::new(Node::new(
+                body: Some(Box::new(Node::new(
                     NodeKind::Block { statements: vec![] },
                     crate::ast::SourceLocation { start: 0, end: 0 },
-                )),
+                ))),
                 attributes: vec![],
                 prototype: None,
             },
@@ -1166,7 +1166,7 @@ mod tests {
                     },
                     crate::ast::SourceLocation { start: 0, end: 0 },
                 ))),
-                body: Box::new(Node::new(
+                body: Some(Box::new(Node::new(
                     NodeKind::Block {
                         statements: vec![
                             // Add some if statements to increase complexity
@@ -1191,7 +1191,7 @@ mod tests {
                         ],
                     },
                     crate::ast::SourceLocation { start: 0, end: 0 },
-                )),
+                ))),
                 attributes: vec![],
                 prototype: None,
             },
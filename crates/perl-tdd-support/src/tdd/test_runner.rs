@@ -82,6 +82,150 @@ impl TestStatus {
     }
 }
 
+/// One `ok`/`not ok` line of a TAP stream
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestCase {
+    /// Test number from the TAP line
+    pub number: u32,
+    /// Description after the number, without the leading `- `
+    pub description: String,
+    /// `Passed` for `ok`, `Failed` for `not ok`, `Skipped` for a `# SKIP` directive
+    pub status: TestStatus,
+    /// Whether the line carries a `# TODO` directive
+    pub todo: bool,
+    /// `#` diagnostic lines reported after the test, without the `#` prefix
+    pub diagnostics: Vec<String>,
+    /// File named by a `# at FILE line N.` diagnostic
+    pub failure_file: Option<String>,
+    /// 1-based line named by a `# at FILE line N.` diagnostic
+    pub failure_line: Option<u32>,
+}
+
+/// Summary of a TAP stream produced by `prove` or a Test::More script
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TestRunResult {
+    /// Tests that passed, excluding skips and TODOs
+    pub passed: usize,
+    /// Tests that failed, excluding TODOs
+    pub failed: usize,
+    /// Tests with a `# SKIP` directive
+    pub skipped: usize,
+    /// Tests with a `# TODO` directive, passing or not
+    pub todo: usize,
+    /// Reason given by `Bail out!`, if the run bailed
+    pub bailed: Option<String>,
+    /// Number of tests announced by the `1..N` plan
+    pub planned: Option<u32>,
+    /// Top-level test cases in order
+    pub cases: Vec<TestCase>,
+}
+
+/// Parse a TAP stream into a [`TestRunResult`]
+///
+/// Only top-level lines are read: indented lines belong to subtests, whose
+/// outcome is reported by the unindented line that closes them. A
+/// `# at FILE line N.` diagnostic is attached to the earliest failed case
+/// that has no location yet, so diagnostics printed to stderr after all of
+/// stdout still reach the right case.
+pub fn parse_tap(output: &str) -> TestRunResult {
+    let mut result = TestRunResult::default();
+    let mut last_case: Option<usize> = None;
+
+    for line in output.lines() {
+        if line.starts_with(char::is_whitespace) {
+            continue;
+        }
+        let line = line.trim_end();
+
+        if let Some(reason) = line.strip_prefix("Bail out!") {
+            result.bailed = Some(reason.trim().to_string());
+        } else if let Some(comment) = line.strip_prefix('#') {
+            if let Some((file, line_num)) = parse_failure_location(comment) {
+                let unlocated = result.cases.iter_mut().find(|case| {
+                    case.status == TestStatus::Failed && !case.todo && case.failure_line.is_none()
+                });
+                if let Some(case) = unlocated {
+                    case.failure_file = Some(file.to_string());
+                    case.failure_line = Some(line_num);
+                }
+            }
+            if let Some(case) = last_case.and_then(|index| result.cases.get_mut(index)) {
+                case.diagnostics.push(comment.trim().to_string());
+            }
+        } else if let Some(case) = parse_test_line(line) {
+            match case.status {
+                _ if case.todo => result.todo += 1,
+                TestStatus::Passed => result.passed += 1,
+                TestStatus::Skipped => result.skipped += 1,
+                TestStatus::Failed | TestStatus::Errored => result.failed += 1,
+            }
+            last_case = Some(result.cases.len());
+            result.cases.push(case);
+        } else if let Some(plan) = line.split(" .. ").last().and_then(|l| l.strip_prefix("1..")) {
+            let count = plan.split_whitespace().next().unwrap_or("");
+            result.planned = count.parse().ok().or(result.planned);
+        }
+    }
+
+    result
+}
+
+/// Parse an `ok N - description # DIRECTIVE` line
+fn parse_test_line(line: &str) -> Option<TestCase> {
+    let (passed, rest) = if let Some(rest) = line.strip_prefix("not ok") {
+        (false, rest)
+    } else if let Some(rest) = line.strip_prefix("ok") {
+        (true, rest)
+    } else {
+        return None;
+    };
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+
+    let rest = rest.trim_start();
+    let (number, rest) = match rest.split_once(' ') {
+        Some((number, rest)) => (number, rest),
+        None => (rest, ""),
+    };
+    let number = number.parse().ok()?;
+
+    let (description, directive) = match rest.split_once('#') {
+        Some((description, directive)) => (description, directive.trim()),
+        None => (rest, ""),
+    };
+    let description = description.trim();
+    let description = description.strip_prefix("- ").unwrap_or(description).trim();
+    let directive = directive.to_ascii_lowercase();
+
+    let todo = directive.starts_with("todo");
+    let status = if directive.starts_with("skip") {
+        TestStatus::Skipped
+    } else if passed {
+        TestStatus::Passed
+    } else {
+        TestStatus::Failed
+    };
+
+    Some(TestCase {
+        number,
+        description: description.to_string(),
+        status,
+        todo,
+        diagnostics: Vec::new(),
+        failure_file: None,
+        failure_line: None,
+    })
+}
+
+/// File and line from a Test::More `at FILE line N.` diagnostic
+fn parse_failure_location(comment: &str) -> Option<(&str, u32)> {
+    let location = comment.trim().strip_prefix("at ")?;
+    let (file, line_num) = location.rsplit_once(" line ")?;
+    let line_num = line_num.trim_end_matches('.').trim().parse().ok()?;
+    Some((file, line_num))
+}
+
 /// Test Runner for Perl tests
 pub struct TestRunner {
     /// Source code content of the test file
@@ -229,7 +373,7 @@ impl TestRunner {
                 }
             }
 
-            NodeKind::Subroutine { name, body, .. } => {
+            NodeKind::Subroutine { name, body: Some(body), .. } => {
                 if let Some(func_name) = name {
                     if self.is_test_function(func_name) {
                         let test_item = TestItem {
@@ -407,17 +551,24 @@ impl TestRunner {
     }
 
     /// Run a test and return results
+    ///
+    /// `test_id` is a file URI, optionally followed by `::` and a test name.
+    /// For a `.t` file whose TAP output reports a subtest with that name,
+    /// only the subtest's result is returned.
     pub fn run_test(&self, test_id: &str) -> Vec<TestResult> {
         let mut results = Vec::new();
 
-        // Extract file path from URI
-        let file_path = test_id.split("::").next().unwrap_or(test_id);
+        // Extract file path and test name from the ID
+        let (file_path, test_name) = match test_id.split_once("::") {
+            Some((file_path, test_name)) => (file_path, Some(test_name)),
+            None => (test_id, None),
+        };
         let file_path = file_path.strip_prefix("file://").unwrap_or(file_path);
 
         // Determine how to run the test
         if file_path.ends_with(".t") {
             // Run as a test file
-            results.extend(self.run_test_file(file_path));
+            results.extend(self.run_test_file(file_path, test_name));
         } else {
             // Run as a Perl script with prove or perl
             results.extend(self.run_perl_test(file_path));
@@ -427,7 +578,7 @@ impl TestRunner {
     }
 
     /// Run a .t test file
-    fn run_test_file(&self, file_path: &str) -> Vec<TestResult> {
+    fn run_test_file(&self, file_path: &str, test_name: Option<&str>) -> Vec<TestResult> {
         let start_time = std::time::Instant::now();
 
         // Try to run with prove first, fall back to perl
@@ -462,10 +613,17 @@ impl TestRunner {
         };
 
         let duration = start_time.elapsed().as_millis() as u64;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        if let Some(result) =
+            test_name.and_then(|name| self.subtest_result(&stdout, file_path, name, duration))
+        {
+            return vec![result];
+        }
 
         // Parse TAP output
         self.parse_tap_output(
-            &String::from_utf8_lossy(&output.stdout),
+            &stdout,
             &String::from_utf8_lossy(&output.stderr),
             output.status.success(),
             duration,
@@ -513,6 +671,54 @@ impl TestRunner {
         }]
     }
 
+    /// Find the outcome of the subtest `name` in TAP output
+    ///
+    /// Test::More cannot run a single subtest, so the whole file runs and the
+    /// subtest's outcome is read from the `ok N - name` line that closes it,
+    /// which is indented when the subtest is nested. Returns `None` when no
+    /// line reports `name`, e.g. when it names a test function.
+    fn subtest_result(
+        &self,
+        stdout: &str,
+        file_path: &str,
+        name: &str,
+        duration: u64,
+    ) -> Option<TestResult> {
+        let mut status = None;
+        let mut message = None;
+
+        for line in stdout.lines().map(str::trim_start) {
+            let (passed, rest) = if let Some(rest) = line.strip_prefix("not ok ") {
+                (false, rest)
+            } else if let Some(rest) = line.strip_prefix("ok ") {
+                (true, rest)
+            } else {
+                continue;
+            };
+
+            let description = rest.split_once(' ').map(|(_, d)| d).unwrap_or("");
+            let description = description.strip_prefix("- ").unwrap_or(description);
+            let description = description.split(" # ").next().unwrap_or(description);
+            if description != name {
+                continue;
+            }
+
+            if passed && status != Some(TestStatus::Failed) {
+                status = Some(TestStatus::Passed);
+            } else {
+                status = Some(TestStatus::Failed);
+                message = Some(line.to_string());
+            }
+        }
+
+        status.map(|status| TestResult {
+            test_id: format!("{}::{}", file_path, name),
+            status,
+            message,
+            duration: Some(duration),
+        })
+    }
+
     /// Parse TAP (Test Anything Protocol) output
     fn parse_tap_output(
         &self,
@@ -719,4 +925,99 @@ done_testing();
         assert!(!runner.is_test_file("file:///lib/Module.pm"));
         assert!(!runner.is_test_file("file:///script.pl"));
     }
+
+    #[test]
+    fn test_subtest_result_from_tap() {
+        let runner = TestRunner::new("".to_string(), "".to_string());
+        let tap = "# Subtest: outer
+    # Subtest: inner
+        ok 1
+        1..1
+    ok 1 - inner
+    not ok 2 - sibling # TODO later
+    1..2
+not ok 1 - outer
+1..1
+";
+
+        let inner = runner.subtest_result(tap, "/t/basic.t", "inner", 5);
+        assert_eq!(inner.as_ref().map(|r| &r.status), Some(&TestStatus::Passed));
+        assert_eq!(inner.map(|r| r.test_id), Some("/t/basic.t::inner".to_string()));
+
+        let outer = runner.subtest_result(tap, "/t/basic.t", "outer", 5);
+        assert_eq!(outer.as_ref().map(|r| &r.status), Some(&TestStatus::Failed));
+        assert_eq!(outer.and_then(|r| r.message), Some("not ok 1 - outer".to_string()));
+
+        assert_eq!(
+            runner.subtest_result(tap, "/t/basic.t", "sibling", 5).map(|r| r.status),
+            Some(TestStatus::Failed)
+        );
+        assert!(runner.subtest_result(tap, "/t/basic.t", "test_missing", 5).is_none());
+    }
+
+    #[test]
+    fn test_parse_tap_sample() {
+        let tap = "\
+1..5
+ok 1 - loads module
+not ok 2 - computes total
+#   Failed test 'computes total'
+#   at t/basic.t line 12.
+#          got: '3'
+#     expected: '4'
+ok 3 # skip no network
+not ok 4 - handles unicode # TODO not implemented
+#   Failed (TODO) test 'handles unicode'
+#   at t/basic.t line 20.
+    # Subtest: nested
+    ok 1 - inner
+ok 5 - nested
+# Looks like you failed 1 test of 5.
+";
+        let result = parse_tap(tap);
+
+        assert_eq!(result.planned, Some(5));
+        assert_eq!((result.passed, result.failed, result.skipped, result.todo), (2, 1, 1, 1));
+        assert_eq!(result.bailed, None);
+        assert_eq!(result.cases.len(), 5);
+
+        let failure = &result.cases[1];
+        assert_eq!(failure.description, "computes total");
+        assert_eq!(failure.status, TestStatus::Failed);
+        assert_eq!(failure.failure_file.as_deref(), Some("t/basic.t"));
+        assert_eq!(failure.failure_line, Some(12));
+        assert_eq!(failure.diagnostics[2], "got: '3'");
+
+        assert_eq!(result.cases[2].status, TestStatus::Skipped);
+        assert_eq!(result.cases[2].description, "");
+
+        let todo = &result.cases[3];
+        assert!(todo.todo);
+        assert_eq!(todo.description, "handles unicode");
+        assert_eq!(todo.failure_line, None);
+
+        assert_eq!(result.cases[4].description, "nested");
+        assert_eq!(result.cases[4].status, TestStatus::Passed);
+    }
+
+    #[test]
+    fn test_parse_tap_bail_out_and_stderr_diagnostics() {
+        // stdout followed by stderr, as when the streams are captured separately
+        let tap = "\
+ok 1 - first
+not ok 2 - second
+not ok 3 - third
+Bail out! database unavailable
+#   Failed test 'second'
+#   at t/db.t line 7.
+#   Failed test 'third'
+#   at t/db.t line 9.
+";
+        let result = parse_tap(tap);
+
+        assert_eq!(result.bailed.as_deref(), Some("database unavailable"));
+        assert_eq!(result.failed, 2);
+        assert_eq!(result.cases[1].failure_line, Some(7));
+        assert_eq!(result.cases[2].failure_line, Some(9));
+    }
 }
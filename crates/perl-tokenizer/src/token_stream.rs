@@ -250,8 +250,6 @@ impl<'a> TokenStream<'a> {
                 "//=" => TokenKind::DefinedOrAssign,
                 "==" => TokenKind::Equal,
                 "!=" => TokenKind::NotEqual,
-                "=~" => TokenKind::Match,
-                "!~" => TokenKind::NotMatch,
                 "~~" => TokenKind::SmartMatch,
                 "<" => TokenKind::Less,
                 ">" => TokenKind::Greater,
@@ -296,6 +294,10 @@ impl<'a> TokenStream<'a> {
             LexerTokenType::Semicolon => TokenKind::Semicolon,
             LexerTokenType::Comma => TokenKind::Comma,
 
+            // Regex binding operators
+            LexerTokenType::Bind => TokenKind::Match,
+            LexerTokenType::NotBind => TokenKind::NotMatch,
+
             // Division operator (important to handle before other tokens)
             LexerTokenType::Division => TokenKind::Slash,
 
@@ -304,9 +306,11 @@ impl<'a> TokenStream<'a> {
             LexerTokenType::StringLiteral | LexerTokenType::InterpolatedString(_) => {
                 TokenKind::String
             }
-            LexerTokenType::RegexMatch | LexerTokenType::QuoteRegex => TokenKind::Regex,
-            LexerTokenType::Substitution => TokenKind::Substitution,
-            LexerTokenType::Transliteration => TokenKind::Transliteration,
+            LexerTokenType::RegexMatch { .. } | LexerTokenType::QuoteRegex { .. } => {
+                TokenKind::Regex
+            }
+            LexerTokenType::Substitution { .. } => TokenKind::Substitution,
+            LexerTokenType::Transliteration { .. } => TokenKind::Transliteration,
             LexerTokenType::QuoteSingle => TokenKind::QuoteSingle,
             LexerTokenType::QuoteDouble => TokenKind::QuoteDouble,
             LexerTokenType::QuoteWords => TokenKind::QuoteWords,
@@ -50,6 +50,10 @@ pub mod workspace;
 
 /// Workspace document storage and cache management.
 pub use workspace::document_store;
+/// `@INC`-style module search roots.
+pub use workspace::include_paths;
+/// Inheritance declarations recognized across `use parent`, `@ISA` and `class :isa`.
+pub use workspace::inheritance;
 /// Workspace-wide symbol index and lookup utilities.
 pub use workspace::workspace_index;
 /// Workspace rename operations for cross-file symbol changes.
@@ -4,7 +4,9 @@
 //! versions and content without relying on filesystem state.
 
 use crate::line_index::LineIndex;
+use perl_parser_core::SourceLocation;
 use std::collections::HashMap;
+use std::fmt;
 use std::sync::{Arc, RwLock};
 
 /// A document in the store
@@ -18,23 +20,65 @@ pub struct Document {
     pub text: String,
     /// Line index for position calculations
     pub line_index: LineIndex,
+    /// Span of the `__DATA__`/`__END__` section content, if the parsed text has one
+    pub data_section: Option<SourceLocation>,
 }
 
 impl Document {
     /// Create a new document
     pub fn new(uri: String, version: i32, text: String) -> Self {
         let line_index = LineIndex::new(text.clone());
-        Self { uri, version, text, line_index }
+        Self { uri, version, text, line_index, data_section: None }
     }
 
     /// Update the document content
+    ///
+    /// The data section span is cleared until the new text has been parsed.
     pub fn update(&mut self, version: i32, text: String) {
         self.version = version;
         self.text = text.clone();
         self.line_index = LineIndex::new(text);
+        self.data_section = None;
+    }
+
+    /// Text of the `__DATA__`/`__END__` section, if one was recorded
+    pub fn data_section_text(&self) -> Option<&str> {
+        self.data_section.and_then(|span| self.text.get(span.start..span.end))
+    }
+}
+
+/// Why [`DocumentStore::apply_change`] refused a change
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangeError {
+    /// The document is not open in the store
+    NotOpen {
+        /// The document URI
+        uri: String,
+    },
+    /// The change's version is not newer than the stored version
+    StaleVersion {
+        /// The document URI
+        uri: String,
+        /// Version currently stored
+        current: i32,
+        /// Version carried by the rejected change
+        received: i32,
+    },
+}
+
+impl fmt::Display for ChangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChangeError::NotOpen { uri } => write!(f, "document {uri} is not open"),
+            ChangeError::StaleVersion { uri, current, received } => {
+                write!(f, "stale change to {uri}: version {received} is not newer than {current}")
+            }
+        }
     }
 }
 
+impl std::error::Error for ChangeError {}
+
 /// Thread-safe document store
 #[derive(Debug, Clone)]
 pub struct DocumentStore {
@@ -78,6 +122,33 @@ impl DocumentStore {
         }
     }
 
+    /// Apply an edited version of a document, rejecting out-of-order changes
+    ///
+    /// Unlike [`update`](Self::update), the change is only applied when
+    /// `version` is strictly greater than the stored version; otherwise the
+    /// document is left untouched and [`ChangeError::StaleVersion`] is returned.
+    pub fn apply_change(&self, uri: &str, version: i32, text: String) -> Result<(), ChangeError> {
+        let key = Self::uri_key(uri);
+        let not_open = || ChangeError::NotOpen { uri: uri.to_string() };
+
+        let mut docs = self.documents.write().map_err(|_| not_open())?;
+        let doc = docs.get_mut(&key).ok_or_else(not_open)?;
+        Self::check_version(uri, doc.version, version)?;
+        doc.update(version, text);
+        Ok(())
+    }
+
+    /// Check that a change carrying `received` may replace a document at `current`
+    ///
+    /// This is the ordering rule [`apply_change`](Self::apply_change) enforces,
+    /// exposed for callers that keep their own per-document state.
+    pub fn check_version(uri: &str, current: i32, received: i32) -> Result<(), ChangeError> {
+        if received <= current {
+            return Err(ChangeError::StaleVersion { uri: uri.to_string(), current, received });
+        }
+        Ok(())
+    }
+
     /// Close a document
     pub fn close(&self, uri: &str) -> bool {
         let key = Self::uri_key(uri);
@@ -99,6 +170,29 @@ impl DocumentStore {
         self.get(uri).map(|doc| doc.text)
     }
 
+    /// Record the data section span parsed from a document's current text
+    pub fn set_data_section(&self, uri: &str, data_section: Option<SourceLocation>) -> bool {
+        let key = Self::uri_key(uri);
+        let Ok(mut docs) = self.documents.write() else {
+            return false;
+        };
+        if let Some(doc) = docs.get_mut(&key) {
+            doc.data_section = data_section;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Get the `__DATA__`/`__END__` section text of a document
+    ///
+    /// Returns `None` if the document is not open or has no data section.
+    pub fn data_section_text(&self, uri: &str) -> Option<String> {
+        let key = Self::uri_key(uri);
+        let docs = self.documents.read().ok()?;
+        docs.get(&key)?.data_section_text().map(str::to_string)
+    }
+
     /// Get all open documents
     pub fn all_documents(&self) -> Vec<Document> {
         let Ok(docs) = self.documents.read() else {
@@ -134,7 +228,8 @@ impl Default for DocumentStore {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use perl_tdd_support::must_some;
+    use perl_parser_core::Parser;
+    use perl_tdd_support::{must, must_some};
 
     #[test]
     fn test_document_lifecycle() {
@@ -163,6 +258,48 @@ mod tests {
         assert_eq!(store.count(), 0);
     }
 
+    #[test]
+    fn test_apply_change_accepts_newer_versions() {
+        let store = DocumentStore::new();
+        let uri = "file:///edit.pl";
+        store.open(uri.to_string(), 1, "my $x;".to_string());
+
+        assert_eq!(store.apply_change(uri, 2, "my $x = 1;".to_string()), Ok(()));
+        assert_eq!(store.apply_change(uri, 5, "my $x = 2;".to_string()), Ok(()));
+
+        let doc = must_some(store.get(uri));
+        assert_eq!(doc.version, 5);
+        assert_eq!(doc.text, "my $x = 2;");
+    }
+
+    #[test]
+    fn test_apply_change_rejects_stale_versions() {
+        let store = DocumentStore::new();
+        let uri = "file:///edit.pl";
+        store.open(uri.to_string(), 3, "my $x = 3;".to_string());
+
+        for version in [3, 2] {
+            let result = store.apply_change(uri, version, "my $x = 0;".to_string());
+            assert_eq!(
+                result,
+                Err(ChangeError::StaleVersion {
+                    uri: uri.to_string(),
+                    current: 3,
+                    received: version
+                })
+            );
+        }
+
+        let doc = must_some(store.get(uri));
+        assert_eq!(doc.version, 3);
+        assert_eq!(doc.text, "my $x = 3;");
+
+        assert_eq!(
+            store.apply_change("file:///closed.pl", 4, String::new()),
+            Err(ChangeError::NotOpen { uri: "file:///closed.pl".to_string() })
+        );
+    }
+
     #[test]
     fn test_uri_drive_letter_normalization() {
         let uri1 = "file:///C:/test.pl";
@@ -212,4 +349,37 @@ mod tests {
         let doc = must_some(store.get(&uri));
         assert_eq!(doc.text, "# test");
     }
+
+    #[test]
+    fn test_data_section_text() {
+        let store = DocumentStore::new();
+        let uri = "file:///data.pl";
+        let text = "print while <DATA>;\n__DATA__\nline one\nline two\n";
+        store.open(uri.to_string(), 1, text.to_string());
+
+        let ast = must(Parser::new(text).parse());
+        let span = must_some(ast.data_section());
+        assert_eq!(span.start, must_some(text.find("line one")));
+        assert_eq!(span.end, text.len());
+
+        assert!(store.set_data_section(uri, Some(span)));
+        assert_eq!(store.data_section_text(uri), Some("line one\nline two\n".to_string()));
+
+        // Updating the text drops the stale span until it is re-parsed
+        assert!(store.update(uri, 2, "print 1;\n".to_string()));
+        assert_eq!(store.data_section_text(uri), None);
+    }
+
+    #[test]
+    fn test_data_section_absent() {
+        let store = DocumentStore::new();
+        let uri = "file:///no_data.pl";
+        let text = "print 'no data here';\n";
+        store.open(uri.to_string(), 1, text.to_string());
+
+        let ast = must(Parser::new(text).parse());
+        assert_eq!(ast.data_section(), None);
+        assert!(store.set_data_section(uri, ast.data_section()));
+        assert_eq!(store.data_section_text(uri), None);
+    }
 }
@@ -0,0 +1,92 @@
+//! `@INC`-style module search roots for the workspace index.
+//!
+//! Module names resolve the way `require` does: `Foo::Bar` becomes
+//! `Foo/Bar.pm` and each root is tried in order, the first existing file
+//! winning. Roots built by [`IncludePaths::for_workspace`] are searched in
+//! this order:
+//!
+//! 1. `<workspace>/lib`
+//! 2. `<workspace>/blib/lib`
+//! 3. user-configured paths, in the order given
+//!
+//! Duplicate roots keep their first position, so precedence never depends on
+//! how often a directory was configured.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use perl_workspace_index::workspace::include_paths::IncludePaths;
+//! use std::path::{Path, PathBuf};
+//!
+//! let paths = IncludePaths::for_workspace(Path::new("/proj"), &[PathBuf::from("/opt/perl")]);
+//! assert_eq!(
+//!     paths.roots(),
+//!     &[PathBuf::from("/proj/lib"), PathBuf::from("/proj/blib/lib"), PathBuf::from("/opt/perl")]
+//! );
+//! assert_eq!(IncludePaths::module_relative_path("Foo::Bar"), Some(PathBuf::from("Foo/Bar.pm")));
+//! ```
+
+use std::path::{Path, PathBuf};
+
+/// Ordered list of directories searched when resolving module names
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IncludePaths {
+    roots: Vec<PathBuf>,
+}
+
+impl IncludePaths {
+    /// Create search roots from an explicit list, searched in the given order
+    pub fn new(roots: impl IntoIterator<Item = PathBuf>) -> Self {
+        let mut paths = Self::default();
+        for root in roots {
+            paths.push(root);
+        }
+        paths
+    }
+
+    /// Default roots for a workspace: `lib/`, `blib/lib/`, then `extra`
+    pub fn for_workspace(workspace_root: &Path, extra: &[PathBuf]) -> Self {
+        let mut paths = Self::new([workspace_root.join("lib"), workspace_root.join("blib/lib")]);
+        for root in extra {
+            paths.push(root.clone());
+        }
+        paths
+    }
+
+    /// Append a root with the lowest precedence; duplicates are ignored
+    pub fn push(&mut self, root: PathBuf) {
+        if !self.roots.contains(&root) {
+            self.roots.push(root);
+        }
+    }
+
+    /// Search roots in precedence order
+    pub fn roots(&self) -> &[PathBuf] {
+        &self.roots
+    }
+
+    /// Relative file path for a module name (`A::B` -> `A/B.pm`)
+    ///
+    /// The legacy `'` package separator is accepted. Returns `None` for names
+    /// that cannot be a Perl package, such as empty segments or path syntax.
+    pub fn module_relative_path(name: &str) -> Option<PathBuf> {
+        let normalized = name.replace('\'', "::");
+        let mut path = PathBuf::new();
+        for segment in normalized.split("::") {
+            let valid = segment.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_')
+                && segment.chars().all(|c| c.is_alphanumeric() || c == '_');
+            if !valid {
+                return None;
+            }
+            path.push(segment);
+        }
+        path.set_extension("pm");
+        Some(path)
+    }
+
+    /// Resolve a module name to a file under the first root that contains it
+    pub fn resolve_module(&self, name: &str) -> Option<PathBuf> {
+        let relative = Self::module_relative_path(name)?;
+        self.roots.iter().map(|root| root.join(&relative)).find(|candidate| candidate.is_file())
+    }
+}
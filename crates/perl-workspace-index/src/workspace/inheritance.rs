@@ -0,0 +1,181 @@
+//! Inheritance declarations shared by the workspace index and hierarchy providers.
+//!
+//! Perl declares parent classes in several ways; this module recognizes them on
+//! individual AST nodes so every consumer agrees on the same set of edges:
+//!
+//! - `use parent 'Base';`, `use parent -norequire, 'A', 'B';`
+//! - `use base qw(Base);`
+//! - `our @ISA = ('Base');`, `@ISA = qw(A B);`
+//! - `push @ISA, 'Base';`, `unshift @ISA, 'Base';`
+//! - `class Foo :isa(Base) { ... }` (Perl 5.38+)
+//!
+//! # Examples
+//!
+//! ```rust
+//! use perl_parser_core::Parser;
+//! use perl_parser_core::ast::NodeKind;
+//! use perl_workspace_index::workspace::inheritance::parent_declaration;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let ast = Parser::new("use parent -norequire, 'Base', 'Mixin';").parse()?;
+//! if let NodeKind::Program { statements } = &ast.kind {
+//!     let decl = parent_declaration(&statements[0]).ok_or("no declaration")?;
+//!     assert_eq!(decl.class, None);
+//!     assert_eq!(decl.parents, vec!["Base", "Mixin"]);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use perl_parser_core::ast::{Node, NodeKind};
+
+/// Parent classes declared by a single AST node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParentDeclaration<'a> {
+    /// Class the parents belong to; `None` means the enclosing package
+    pub class: Option<&'a str>,
+    /// Parent class names in declaration order
+    pub parents: Vec<String>,
+}
+
+/// Recognize an inheritance declaration on `node`
+///
+/// Only the node itself is inspected; callers walk the tree and track the
+/// enclosing package for declarations whose `class` is `None`.
+pub fn parent_declaration(node: &Node) -> Option<ParentDeclaration<'_>> {
+    let (class, parents) =
+        match &node.kind {
+            NodeKind::Use { module, args, .. } if module == "parent" || module == "base" => {
+                (None, args.iter().flat_map(|arg| parent_names(arg)).collect())
+            }
+            NodeKind::VariableDeclaration {
+                declarator, variable, initializer: Some(init), ..
+            } if declarator == "our" && is_isa_array(variable) => (None, isa_parents(init)),
+            NodeKind::VariableListDeclaration {
+                declarator,
+                variables,
+                initializer: Some(init),
+                ..
+            } if declarator == "our" && variables.len() == 1 && is_isa_array(&variables[0]) => {
+                (None, isa_parents(init))
+            }
+            NodeKind::Assignment { lhs, rhs, op } if op == "=" && is_isa_array(lhs) => {
+                (None, isa_parents(rhs))
+            }
+            NodeKind::FunctionCall { name, args } if name == "push" || name == "unshift" => {
+                // `push @ISA, 'A'` or `push(@ISA, 'A')`, which parses as a single list argument
+                let args = match args.as_slice() {
+                    [Node { kind: NodeKind::ArrayLiteral { elements }, .. }] => elements.as_slice(),
+                    args => args,
+                };
+                match args.split_first() {
+                    Some((target, rest)) if is_isa_array(target) => {
+                        (None, rest.iter().flat_map(isa_parents).collect())
+                    }
+                    _ => return None,
+                }
+            }
+            NodeKind::Class { name, attributes, .. } => {
+                let parents = attributes
+                    .iter()
+                    .filter_map(|attr| attr.strip_prefix("isa(")?.strip_suffix(')'))
+                    // `:isa(Base 1.23)` carries an optional minimum version
+                    .filter_map(|value| value.split_whitespace().next())
+                    .map(str::to_string)
+                    .collect();
+                (Some(name.as_str()), parents)
+            }
+            _ => return None,
+        };
+
+    if parents.is_empty() { None } else { Some(ParentDeclaration { class, parents }) }
+}
+
+/// Parent names from a `use parent`/`use base` argument, skipping flags like `-norequire`
+fn parent_names(arg: &str) -> Vec<String> {
+    let arg = arg.trim();
+    let list = match arg.strip_prefix("qw") {
+        Some(rest) if rest.len() >= 2 => &rest[1..rest.len() - 1],
+        _ => arg,
+    };
+    list.split(|c: char| c.is_whitespace() || c == ',')
+        .map(|word| word.trim_matches(|c| c == '\'' || c == '"'))
+        .filter(|word| !word.is_empty() && !word.starts_with('-'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Whether a node is the `@ISA` array of the current package
+fn is_isa_array(node: &Node) -> bool {
+    matches!(&node.kind, NodeKind::Variable { sigil, name } if sigil == "@" && name == "ISA")
+}
+
+/// Parent names from an expression assigned or pushed onto `@ISA`
+fn isa_parents(node: &Node) -> Vec<String> {
+    match &node.kind {
+        NodeKind::ArrayLiteral { elements } => elements.iter().flat_map(isa_parents).collect(),
+        NodeKind::String { value, .. } => parent_names(value),
+        NodeKind::Identifier { name } => vec![name.clone()],
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use perl_parser_core::Parser;
+
+    fn declarations(code: &str) -> Vec<(Option<String>, Vec<String>)> {
+        let ast = Parser::new(code).parse().unwrap();
+        let mut found = Vec::new();
+        collect(&ast, &mut found);
+        found
+    }
+
+    fn collect(node: &Node, found: &mut Vec<(Option<String>, Vec<String>)>) {
+        if let Some(decl) = parent_declaration(node) {
+            found.push((decl.class.map(str::to_string), decl.parents));
+        }
+        for child in node.children() {
+            collect(child, found);
+        }
+    }
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_use_parent_and_base() {
+        assert_eq!(
+            declarations("use parent -norequire, 'Base', \"Other\";\nuse base qw(A B);\n"),
+            vec![(None, names(&["Base", "Other"])), (None, names(&["A", "B"]))]
+        );
+        assert!(declarations("use parent -norequire;\nuse strict;\n").is_empty());
+    }
+
+    #[test]
+    fn test_isa_array_forms() {
+        assert_eq!(
+            declarations(
+                "our @ISA = ('A', 'B');\n@ISA = qw(C);\npush @ISA, 'D';\npush(@ISA, qw(E F));\nunshift @ISA, 'G';\n"
+            ),
+            vec![
+                (None, names(&["A", "B"])),
+                (None, names(&["C"])),
+                (None, names(&["D"])),
+                (None, names(&["E", "F"])),
+                (None, names(&["G"])),
+            ]
+        );
+        assert!(declarations("push @list, 'A';\nmy @ISA = ('A');\n").is_empty());
+    }
+
+    #[test]
+    fn test_class_isa_attribute() {
+        assert_eq!(
+            declarations("class Circle :isa(Shape 1.0) :does(Drawable) { }\nclass Plain { }\n"),
+            vec![(Some("Circle".to_string()), names(&["Shape"]))]
+        );
+    }
+}
@@ -2,6 +2,8 @@
 
 pub mod cache;
 pub mod document_store;
+pub mod include_paths;
+pub mod inheritance;
 pub mod production_coordinator;
 pub mod slo;
 pub mod state_machine;
@@ -13,6 +15,7 @@ pub use cache::{
     AstCacheConfig, BoundedLruCache, CacheConfig, CombinedWorkspaceCacheConfig, EstimateSize,
     SymbolCacheConfig, WorkspaceCacheConfig,
 };
+pub use include_paths::IncludePaths;
 pub use production_coordinator::{
     CoordinatorStatistics, ProductionCoordinatorConfig, ProductionIndexCoordinator,
     WorkspaceCacheManager,
@@ -64,6 +64,8 @@
 use crate::Parser;
 use crate::ast::{Node, NodeKind};
 use crate::document_store::{Document, DocumentStore};
+use crate::include_paths::IncludePaths;
+use crate::inheritance::parent_declaration;
 use crate::position::{Position, Range};
 use parking_lot::{Mutex, RwLock};
 use perl_position_tracking::{WireLocation, WirePosition, WireRange};
@@ -71,6 +73,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::hash_map::DefaultHasher;
 use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Instant;
 use url::Url;
@@ -1476,6 +1479,76 @@ fn sigil_to_var_kind(sigil: &str) -> VarKind {
     }
 }
 
+/// Subroutines invoked implicitly by Perl, which never appear as call sites
+const IMPLICIT_SUBROUTINES: &[&str] = &[
+    "BEGIN",
+    "END",
+    "INIT",
+    "CHECK",
+    "UNITCHECK",
+    "import",
+    "unimport",
+    "new",
+    "DESTROY",
+    "AUTOLOAD",
+];
+
+/// Names exported through `@EXPORT`/`@EXPORT_OK` by an assignment or `push`
+fn export_names(node: &Node) -> Option<Vec<String>> {
+    let is_export_array = |node: &Node| {
+        matches!(&node.kind, NodeKind::Variable { sigil, name }
+            if sigil == "@" && (name == "EXPORT" || name == "EXPORT_OK"))
+    };
+
+    let values: Vec<&Node> = match &node.kind {
+        NodeKind::VariableDeclaration { variable, initializer: Some(init), .. }
+            if is_export_array(variable) =>
+        {
+            vec![init.as_ref()]
+        }
+        NodeKind::Assignment { lhs, rhs, op } if op == "=" && is_export_array(lhs) => {
+            vec![rhs.as_ref()]
+        }
+        NodeKind::FunctionCall { name, args } if name == "push" => match args.split_first() {
+            Some((target, rest)) if is_export_array(target) => rest.iter().collect(),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let mut names = Vec::new();
+    for value in values {
+        collect_string_list(value, &mut names);
+    }
+    // Only subroutines matter here; `&foo` and `foo` export the same sub
+    names.retain(|name| !name.starts_with(['$', '@', '%', ':']));
+    for name in &mut names {
+        if let Some(stripped) = name.strip_prefix('&') {
+            *name = stripped.to_string();
+        }
+    }
+    Some(names)
+}
+
+/// Words from a string literal, `qw()` list or list of either
+fn collect_string_list(node: &Node, names: &mut Vec<String>) {
+    match &node.kind {
+        NodeKind::ArrayLiteral { elements } => {
+            for element in elements {
+                collect_string_list(element, names);
+            }
+        }
+        NodeKind::String { value, .. } => names.extend(
+            value
+                .split_whitespace()
+                .map(|word| word.trim_matches(|c| c == '\'' || c == '"'))
+                .filter(|word| !word.is_empty())
+                .map(str::to_string),
+        ),
+        _ => {}
+    }
+}
+
 #[derive(Debug, Clone)]
 /// Reference to a symbol for Navigate/Analyze workflows.
 pub struct SymbolReference {
@@ -1542,6 +1615,10 @@ struct FileIndex {
     references: HashMap<String, Vec<SymbolReference>>,
     /// Dependencies (modules this file imports)
     dependencies: HashSet<String>,
+    /// Inheritance edges declared in this file (child package, parent package)
+    parents: Vec<(String, String)>,
+    /// Qualified names listed in `@EXPORT`/`@EXPORT_OK`
+    exports: HashSet<String>,
     /// Content hash for early-exit optimization
     content_hash: u64,
 }
@@ -1554,6 +1631,8 @@ pub struct WorkspaceIndex {
     symbols: Arc<RwLock<HashMap<String, String>>>,
     /// Document store for in-memory text
     document_store: DocumentStore,
+    /// Ordered module search roots used by [`WorkspaceIndex::resolve_module`]
+    include_paths: Arc<RwLock<IncludePaths>>,
 }
 
 impl WorkspaceIndex {
@@ -1576,6 +1655,7 @@ impl WorkspaceIndex {
             files: Arc::new(RwLock::new(HashMap::new())),
             symbols: Arc::new(RwLock::new(HashMap::new())),
             document_store: DocumentStore::new(),
+            include_paths: Arc::new(RwLock::new(IncludePaths::default())),
         }
     }
 
@@ -1617,28 +1697,10 @@ impl WorkspaceIndex {
     pub fn index_file(&self, uri: Url, text: String) -> Result<(), String> {
         let uri_str = uri.to_string();
 
-        // Compute content hash for early-exit optimization
-        let mut hasher = DefaultHasher::new();
-        text.hash(&mut hasher);
-        let content_hash = hasher.finish();
-
         // Check if content is unchanged (early-exit optimization)
-        let key = DocumentStore::uri_key(&uri_str);
-        {
-            let files = self.files.read();
-            if let Some(existing_index) = files.get(&key) {
-                if existing_index.content_hash == content_hash {
-                    // Content unchanged, skip re-indexing
-                    return Ok(());
-                }
-            }
-        }
-
-        // Update document store
-        if self.document_store.is_open(&uri_str) {
-            self.document_store.update(&uri_str, 1, text.clone());
-        } else {
-            self.document_store.open(uri_str.clone(), 1, text.clone());
+        let content_hash = Self::content_hash(&text);
+        if self.is_unchanged(&uri_str, content_hash) {
+            return Ok(());
         }
 
         // Parse the file
@@ -1648,36 +1710,139 @@ impl WorkspaceIndex {
             Err(e) => return Err(format!("Parse error: {}", e)),
         };
 
+        self.store_file(&uri_str, text, &ast, content_hash)
+    }
+
+    /// Re-index one file from an AST the caller already parsed
+    ///
+    /// The file's previous symbols and references are swapped out for the new
+    /// ones in place; every other file's entries are left untouched. `text`
+    /// must be the source `ast` was parsed from, and supplies line positions.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - File URI identifying the document
+    /// * `text` - Full Perl source text the AST was parsed from
+    /// * `ast` - Parsed AST for `text`
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` when the index was updated (or the content is unchanged).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document store cannot be updated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use perl_parser::Parser;
+    /// use perl_parser::workspace_index::WorkspaceIndex;
+    /// use url::Url;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let index = WorkspaceIndex::new();
+    /// let uri = Url::parse("file:///example.pl")?;
+    /// let text = "sub hello { return 1; }";
+    /// let ast = Parser::new(text).parse()?;
+    /// index.update_file(uri, text.to_string(), &ast)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update_file(&self, uri: Url, text: String, ast: &Node) -> Result<(), String> {
+        let uri_str = uri.to_string();
+        let content_hash = Self::content_hash(&text);
+        if self.is_unchanged(&uri_str, content_hash) {
+            return Ok(());
+        }
+
+        self.store_file(&uri_str, text, ast, content_hash)
+    }
+
+    fn content_hash(text: &str) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        text.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Whether the file is already indexed with this exact content
+    fn is_unchanged(&self, uri: &str, content_hash: u64) -> bool {
+        let key = DocumentStore::uri_key(uri);
+        self.files.read().get(&key).is_some_and(|existing| existing.content_hash == content_hash)
+    }
+
+    /// Extract `ast`'s symbols and references and swap them in for the file
+    fn store_file(
+        &self,
+        uri_str: &str,
+        text: String,
+        ast: &Node,
+        content_hash: u64,
+    ) -> Result<(), String> {
+        // Update document store
+        if self.document_store.is_open(uri_str) {
+            self.document_store.update(uri_str, 1, text);
+        } else {
+            self.document_store.open(uri_str.to_string(), 1, text);
+        }
+        self.document_store.set_data_section(uri_str, ast.data_section());
+
         // Get the document for line index
-        let mut doc = self.document_store.get(&uri_str).ok_or("Document not found")?;
+        let mut doc = self.document_store.get(uri_str).ok_or("Document not found")?;
 
         // Extract symbols and references
         let mut file_index = FileIndex { content_hash, ..Default::default() };
-        let mut visitor = IndexVisitor::new(&mut doc, uri_str.clone());
-        visitor.visit(&ast, &mut file_index);
+        let mut visitor = IndexVisitor::new(&mut doc, uri_str.to_string());
+        visitor.visit(ast, &mut file_index);
 
-        // Update the index
-        {
-            let mut files = self.files.write();
-            files.insert(key.clone(), file_index);
-        }
+        self.replace_file_index(uri_str, Some(file_index));
+        Ok(())
+    }
 
-        // Update global symbol map
-        {
-            let files = self.files.read();
-            if let Some(file_index) = files.get(&key) {
-                let mut symbols = self.symbols.write();
-                for symbol in &file_index.symbols {
-                    if let Some(ref qname) = symbol.qualified_name {
-                        symbols.insert(qname.clone(), uri_str.clone());
-                    } else {
-                        symbols.insert(symbol.name.clone(), uri_str.clone());
-                    }
+    /// Replace (or with `None`, drop) one file's index entry, keeping the
+    /// global symbol map pointing only at files that still define each name
+    fn replace_file_index(&self, uri_str: &str, new_index: Option<FileIndex>) {
+        let key = DocumentStore::uri_key(uri_str);
+        let mut files = self.files.write();
+        let old_index = match new_index {
+            Some(file_index) => files.insert(key.clone(), file_index),
+            None => files.remove(&key),
+        };
+
+        let mut symbols = self.symbols.write();
+        if let Some(old_index) = old_index {
+            // Names whose global definition pointed at this file
+            let mut orphaned: HashSet<&String> = old_index
+                .symbols
+                .iter()
+                .map(|symbol| symbol.qualified_name.as_ref().unwrap_or(&symbol.name))
+                .filter(|name| {
+                    symbols.get(*name).is_some_and(|owner| DocumentStore::uri_key(owner) == key)
+                })
+                .collect();
+            for name in &orphaned {
+                symbols.remove(*name);
+            }
+
+            // Fall back to another file that defines the same name, in one pass
+            let others = files.iter().filter(|(file_key, _)| **file_key != key);
+            for other in others.flat_map(|(_, f)| &f.symbols) {
+                if orphaned.is_empty() {
+                    break;
+                }
+                let name = other.qualified_name.as_ref().unwrap_or(&other.name);
+                if orphaned.remove(name) {
+                    symbols.insert(name.clone(), other.uri.clone());
                 }
             }
         }
 
-        Ok(())
+        if let Some(file_index) = files.get(&key) {
+            for symbol in &file_index.symbols {
+                let name = symbol.qualified_name.as_ref().unwrap_or(&symbol.name);
+                symbols.insert(name.clone(), uri_str.to_string());
+            }
+        }
     }
 
     /// Remove a file from the index
@@ -1700,24 +1865,12 @@ impl WorkspaceIndex {
     /// ```
     pub fn remove_file(&self, uri: &str) {
         let uri_str = Self::normalize_uri(uri);
-        let key = DocumentStore::uri_key(&uri_str);
 
         // Remove from document store
         self.document_store.close(&uri_str);
 
-        // Remove file index
-        let mut files = self.files.write();
-        if let Some(file_index) = files.remove(&key) {
-            // Remove from global symbol map
-            let mut symbols = self.symbols.write();
-            for symbol in file_index.symbols {
-                if let Some(ref qname) = symbol.qualified_name {
-                    symbols.remove(qname);
-                } else {
-                    symbols.remove(&symbol.name);
-                }
-            }
-        }
+        // Remove file index and its global symbol entries
+        self.replace_file_index(&uri_str, None);
     }
 
     /// Remove a file from the index (URL variant for compatibility)
@@ -2022,6 +2175,32 @@ impl WorkspaceIndex {
         self.symbols.write().clear();
     }
 
+    /// Replace the module search roots, searched in the given order
+    ///
+    /// See [`IncludePaths`] for the default workspace precedence.
+    pub fn set_include_paths(&self, paths: IncludePaths) {
+        *self.include_paths.write() = paths;
+    }
+
+    /// Current module search roots
+    pub fn include_paths(&self) -> IncludePaths {
+        self.include_paths.read().clone()
+    }
+
+    /// Resolve a module name (`A::B`) to `A/B.pm` under the first matching root
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use perl_parser::workspace_index::WorkspaceIndex;
+    ///
+    /// let index = WorkspaceIndex::new();
+    /// assert!(index.resolve_module("No::Roots::Configured").is_none());
+    /// ```
+    pub fn resolve_module(&self, name: &str) -> Option<PathBuf> {
+        self.include_paths.read().resolve_module(name)
+    }
+
     /// Return the number of indexed files in the workspace
     pub fn file_count(&self) -> usize {
         let files = self.files.read();
@@ -2194,6 +2373,76 @@ impl WorkspaceIndex {
         dependents
     }
 
+    /// Find the direct parent classes of a package
+    ///
+    /// Edges come from `use parent`, `use base`, `@ISA` and `class ... :isa(...)`
+    /// declarations in every indexed file.
+    ///
+    /// # Arguments
+    ///
+    /// * `package` - Package name to look up (e.g., `My::Class`)
+    ///
+    /// # Returns
+    ///
+    /// Parent package names, in declaration order within each file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use perl_parser::workspace_index::WorkspaceIndex;
+    ///
+    /// let index = WorkspaceIndex::new();
+    /// index.index_file_str("file:///Dog.pm", "package Dog; use parent 'Animal';").ok();
+    /// assert_eq!(index.find_supertypes("Dog"), vec!["Animal".to_string()]);
+    /// ```
+    pub fn find_supertypes(&self, package: &str) -> Vec<String> {
+        let mut parents = Vec::new();
+        for (child, parent) in self.inheritance_edges() {
+            if child == package && !parents.contains(&parent) {
+                parents.push(parent);
+            }
+        }
+        parents
+    }
+
+    /// Find the packages that directly inherit from a package
+    ///
+    /// # Arguments
+    ///
+    /// * `package` - Parent package name to look up
+    ///
+    /// # Returns
+    ///
+    /// Child package names, sorted and deduplicated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use perl_parser::workspace_index::WorkspaceIndex;
+    ///
+    /// let index = WorkspaceIndex::new();
+    /// let _children = index.find_subtypes("Animal");
+    /// ```
+    pub fn find_subtypes(&self, package: &str) -> Vec<String> {
+        let mut children: Vec<String> = self
+            .inheritance_edges()
+            .into_iter()
+            .filter(|(_, parent)| parent == package)
+            .map(|(child, _)| child)
+            .collect();
+        children.sort();
+        children.dedup();
+        children
+    }
+
+    /// All inheritance edges, ordered by file URI and then by declaration
+    fn inheritance_edges(&self) -> Vec<(String, String)> {
+        let files = self.files.read();
+        let mut keys: Vec<&String> = files.keys().collect();
+        keys.sort();
+        keys.into_iter().flat_map(|key| files[key].parents.iter().cloned()).collect()
+    }
+
     /// Get the document store
     ///
     /// # Returns
@@ -2251,6 +2500,63 @@ impl WorkspaceIndex {
         unused
     }
 
+    /// Find subroutines that are never called anywhere in the workspace
+    ///
+    /// Stricter about false positives than [`find_unused_symbols`](Self::find_unused_symbols):
+    /// a subroutine counts as used when it is called directly or through any
+    /// package (covering inherited method dispatch), referenced as `\&name` or
+    /// `->can('name')`, listed in `@EXPORT`/`@EXPORT_OK`, or invoked implicitly
+    /// by Perl (`BEGIN`, `import`, `new`, `DESTROY`, ...).
+    ///
+    /// # Returns
+    ///
+    /// Subroutine symbols with no detected callers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use perl_parser::workspace_index::WorkspaceIndex;
+    ///
+    /// let index = WorkspaceIndex::new();
+    /// index.index_file_str("file:///A.pm", "package A;\nsub helper { 1 }\nsub new { 1 }\n").ok();
+    /// let unused = index.find_unused_subroutines();
+    /// assert_eq!(unused.len(), 1);
+    /// assert_eq!(unused[0].name, "helper");
+    /// ```
+    pub fn find_unused_subroutines(&self) -> Vec<WorkspaceSymbol> {
+        let files = self.files.read();
+        let mut unused = Vec::new();
+
+        for file_index in files.values() {
+            for symbol in &file_index.symbols {
+                if symbol.kind != SymbolKind::Subroutine
+                    || IMPLICIT_SUBROUTINES.contains(&symbol.name.as_str())
+                {
+                    continue;
+                }
+
+                let qualified = symbol.qualified_name.as_deref().unwrap_or(&symbol.name);
+                if files.values().any(|fi| fi.exports.contains(qualified)) {
+                    continue;
+                }
+
+                let suffix = format!("::{}", symbol.name);
+                let has_usage = files.values().any(|fi| {
+                    fi.references.iter().any(|(key, refs)| {
+                        (*key == symbol.name || key.ends_with(&suffix))
+                            && refs.iter().any(|r| r.kind != ReferenceKind::Definition)
+                    })
+                });
+
+                if !has_usage {
+                    unused.push(symbol.clone());
+                }
+            }
+        }
+
+        unused
+    }
+
     /// Get all symbols that belong to a specific package
     ///
     /// # Arguments
@@ -2427,6 +2733,18 @@ impl IndexVisitor {
     }
 
     fn visit_node(&mut self, node: &Node, file_index: &mut FileIndex) {
+        if let Some(decl) = parent_declaration(node) {
+            let child = decl.class.or(self.current_package.as_deref()).unwrap_or("main");
+            for parent in decl.parents {
+                file_index.parents.push((child.to_string(), parent));
+            }
+        }
+
+        if let Some(names) = export_names(node) {
+            let pkg = self.current_package.as_deref().unwrap_or("main");
+            file_index.exports.extend(names.into_iter().map(|name| format!("{}::{}", pkg, name)));
+        }
+
         match &node.kind {
             NodeKind::Package { name, .. } => {
                 let package_name = name.clone();
@@ -2461,7 +2779,10 @@ impl IndexVisitor {
 
                     if let Some(idx) = existing_symbol_idx {
                         // Update existing forward declaration with body
-                        file_index.symbols[idx].range = self.node_to_range(node);
+                        if body.is_some() {
+                            file_index.symbols[idx].range = self.node_to_range(node);
+                            file_index.symbols[idx].has_body = true;
+                        }
                     } else {
                         // New symbol
                         file_index.symbols.push(WorkspaceSymbol {
@@ -2472,7 +2793,7 @@ impl IndexVisitor {
                             qualified_name: Some(qualified_name),
                             documentation: None,
                             container_name: self.current_package.clone(),
-                            has_body: true, // Subroutine node always has body
+                            has_body: body.is_some(),
                         });
                     }
 
@@ -2487,7 +2808,9 @@ impl IndexVisitor {
                 }
 
                 // Visit body
-                self.visit_node(body, file_index);
+                if let Some(body) = body {
+                    self.visit_node(body, file_index);
+                }
             }
 
             NodeKind::VariableDeclaration { variable, initializer, .. } => {
@@ -2716,6 +3039,26 @@ impl IndexVisitor {
                 // Object is a read context
                 self.visit_node(object, file_index);
 
+                // `->can('name')` refers to the sub by name
+                if method == "can" {
+                    if let Some(arg @ Node { kind: NodeKind::String { value, .. }, .. }) =
+                        args.first()
+                    {
+                        let name = value.trim_matches(|c| c == '\'' || c == '"');
+                        // Point at the name itself, inside any quotes
+                        let quote = usize::from(name.len() < value.len());
+                        let start = arg.location.start + quote;
+                        let range = self.span_to_range(start, start + name.len());
+                        file_index.references.entry(name.to_string()).or_default().push(
+                            SymbolReference {
+                                uri: self.uri.clone(),
+                                range,
+                                kind: ReferenceKind::Usage,
+                            },
+                        );
+                    }
+                }
+
                 // Track method call with qualified name if applicable
                 let method_key = qualified_method.as_ref().unwrap_or(method);
                 file_index.references.entry(method_key.clone()).or_default().push(
@@ -2858,6 +3201,10 @@ impl IndexVisitor {
             NodeKind::Eval { block } | NodeKind::Do { block } => {
                 self.visit_node(block, file_index);
             }
+            NodeKind::DoWhile { block, condition } | NodeKind::DoUntil { block, condition } => {
+                self.visit_node(block, file_index);
+                self.visit_node(condition, file_index);
+            }
             NodeKind::Try { body, catch_blocks, finally_block } => {
                 self.visit_node(body, file_index);
                 for (_, block) in catch_blocks {
@@ -2895,13 +3242,17 @@ impl IndexVisitor {
     }
 
     fn node_to_range(&mut self, node: &Node) -> Range {
+        self.span_to_range(node.location.start, node.location.end)
+    }
+
+    fn span_to_range(&mut self, start: usize, end: usize) -> Range {
         // LineIndex.range returns line numbers and UTF-16 code unit columns
         let ((start_line, start_col), (end_line, end_col)) =
-            self.document.line_index.range(node.location.start, node.location.end);
-        // Use byte offsets from node.location directly
+            self.document.line_index.range(start, end);
+        // Use byte offsets directly
         Range {
-            start: Position { byte: node.location.start, line: start_line, column: start_col },
-            end: Position { byte: node.location.end, line: end_line, column: end_col },
+            start: Position { byte: start, line: start_line, column: start_col },
+            end: Position { byte: end, line: end_line, column: end_col },
         }
     }
 }
@@ -3067,6 +3418,28 @@ use Data::Dumper;
         assert!(deps.contains("Data::Dumper"));
     }
 
+    #[test]
+    fn test_inheritance_edges_across_files() {
+        let index = WorkspaceIndex::new();
+
+        must(index.index_file_str(
+            "file:///lib/Duck.pm",
+            "package Duck;\nuse parent -norequire, 'Animal', 'Swimmer';\npush @ISA, 'Flyer';\n1;\n",
+        ));
+        must(index.index_file_str("file:///lib/Dog.pm", "package Dog;\nuse base 'Animal';\n1;\n"));
+        must(index.index_file_str(
+            "file:///lib/Robot.pm",
+            "use feature 'class';\nclass Robot :isa(Machine) { }\n",
+        ));
+
+        assert_eq!(index.find_supertypes("Duck"), vec!["Animal", "Swimmer", "Flyer"]);
+        assert_eq!(index.find_subtypes("Animal"), vec!["Dog", "Duck"]);
+        assert_eq!(index.find_supertypes("Robot"), vec!["Machine"]);
+
+        index.remove_file("file:///lib/Dog.pm");
+        assert_eq!(index.find_subtypes("Animal"), vec!["Duck"]);
+    }
+
     #[test]
     fn test_uri_to_fs_path_basic() {
         // Test basic file:// URI conversion
@@ -3852,4 +4225,74 @@ Utils::process_data();
             non_def_refs.len()
         );
     }
+
+    #[test]
+    fn test_update_file_swaps_one_file_and_keeps_others() {
+        let index = WorkspaceIndex::new();
+        let utils = must(url::Url::parse("file:///lib/Utils.pm"));
+        let helpers = must(url::Url::parse("file:///lib/Helpers.pm"));
+        let app = must(url::Url::parse("file:///app.pl"));
+
+        must(index.index_file(utils.clone(), "package Utils;\nsub process { 1 }\n".into()));
+        must(index.index_file(helpers.clone(), "package Helpers;\nsub assist { 1 }\n".into()));
+        let app_v1 = "use Utils;\nUtils::process();\nHelpers::assist();\nsub old_main { 1 }\n";
+        must(index.index_file(app.clone(), app_v1.into()));
+        let snapshot = |uri: &url::Url| -> Vec<_> {
+            index
+                .file_symbols(uri.as_str())
+                .into_iter()
+                .map(|s| (s.name, s.qualified_name, s.range.start.line, s.range.start.column))
+                .collect()
+        };
+        let utils_symbols = snapshot(&utils);
+        let helpers_symbols = snapshot(&helpers);
+
+        // Swap in a new AST for app.pl only
+        let app_v2 = "use Utils;\nUtils::process();\nsub new_main { 1 }\n";
+        let ast = must(Parser::new(app_v2).parse());
+        must(index.update_file(app.clone(), app_v2.into(), &ast));
+
+        let app_symbols = index.file_symbols(app.as_str());
+        assert!(app_symbols.iter().any(|s| s.name == "new_main"));
+        assert!(!app_symbols.iter().any(|s| s.name == "old_main"));
+        assert!(index.find_definition("old_main").is_none());
+        assert_eq!(index.symbols.read().get("main::new_main"), Some(&app.to_string()));
+        assert!(!index.symbols.read().contains_key("main::old_main"));
+
+        // The other files are untouched and still resolve across files
+        assert_eq!(snapshot(&utils), utils_symbols);
+        assert_eq!(snapshot(&helpers), helpers_symbols);
+        assert_eq!(index.file_count(), 3);
+        assert_eq!(
+            index.find_definition("Utils::process").map(|loc| loc.uri),
+            Some(utils.to_string())
+        );
+        assert_eq!(index.count_usages("Utils::process"), 1);
+        assert_eq!(index.count_usages("Helpers::assist"), 0);
+        assert!(index.find_definition("Helpers::assist").is_some());
+    }
+
+    #[test]
+    fn test_remove_file_keeps_global_names_defined_elsewhere() {
+        let index = WorkspaceIndex::new();
+        let first = must(url::Url::parse("file:///a/Shared.pm"));
+        let second = must(url::Url::parse("file:///b/Shared.pm"));
+        let code = "package Shared;\nsub common { 1 }\n";
+        must(index.index_file(first.clone(), code.into()));
+        must(index.index_file(second.clone(), code.into()));
+        assert_eq!(index.symbols.read().get("Shared::common"), Some(&second.to_string()));
+
+        index.remove_file(second.as_str());
+
+        assert_eq!(index.file_count(), 1);
+        assert_eq!(index.symbols.read().get("Shared::common"), Some(&first.to_string()));
+        assert_eq!(
+            index.find_definition("Shared::common").map(|loc| loc.uri),
+            Some(first.to_string())
+        );
+
+        index.remove_file(first.as_str());
+        assert!(index.symbols.read().is_empty());
+        assert!(!index.has_symbols());
+    }
 }
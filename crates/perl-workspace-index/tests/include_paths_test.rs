@@ -0,0 +1,84 @@
+//! Tests for `@INC`-style module resolution in the workspace index.
+
+use perl_workspace_index::workspace::include_paths::IncludePaths;
+use perl_workspace_index::workspace::workspace_index::WorkspaceIndex;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+fn write_module(root: &Path, relative: &str) -> Result<PathBuf, std::io::Error> {
+    let path = root.join(relative);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, "package Placeholder;\n1;\n")?;
+    Ok(path)
+}
+
+#[test]
+fn test_first_root_wins_when_module_exists_in_several() -> TestResult {
+    let workspace = tempfile::tempdir()?;
+    let extra = tempfile::tempdir()?;
+    let in_lib = write_module(workspace.path(), "lib/Foo/Bar.pm")?;
+    write_module(workspace.path(), "blib/lib/Foo/Bar.pm")?;
+    write_module(extra.path(), "Foo/Bar.pm")?;
+
+    let index = WorkspaceIndex::new();
+    index.set_include_paths(IncludePaths::for_workspace(
+        workspace.path(),
+        &[extra.path().to_path_buf()],
+    ));
+
+    assert_eq!(index.resolve_module("Foo::Bar"), Some(in_lib));
+    Ok(())
+}
+
+#[test]
+fn test_later_root_used_when_earlier_roots_lack_module() -> TestResult {
+    let workspace = tempfile::tempdir()?;
+    let extra = tempfile::tempdir()?;
+    write_module(workspace.path(), "lib/Other.pm")?;
+    let in_blib = write_module(workspace.path(), "blib/lib/Built/Only.pm")?;
+    let in_extra = write_module(extra.path(), "Vendor/Thing.pm")?;
+
+    let index = WorkspaceIndex::new();
+    index.set_include_paths(IncludePaths::for_workspace(
+        workspace.path(),
+        &[extra.path().to_path_buf()],
+    ));
+
+    assert_eq!(index.resolve_module("Built::Only"), Some(in_blib));
+    assert_eq!(index.resolve_module("Vendor::Thing"), Some(in_extra));
+    Ok(())
+}
+
+#[test]
+fn test_unresolvable_module_returns_none() -> TestResult {
+    let workspace = tempfile::tempdir()?;
+    write_module(workspace.path(), "lib/Foo/Bar.pm")?;
+
+    let index = WorkspaceIndex::new();
+    index.set_include_paths(IncludePaths::for_workspace(workspace.path(), &[]));
+
+    assert_eq!(index.resolve_module("Foo::Missing"), None);
+    assert_eq!(index.resolve_module("Foo::..::Bar"), None);
+    assert_eq!(index.resolve_module(""), None);
+    Ok(())
+}
+
+#[test]
+fn test_duplicate_roots_keep_first_position() {
+    let paths = IncludePaths::new([
+        PathBuf::from("/a"),
+        PathBuf::from("/b"),
+        PathBuf::from("/a"),
+        PathBuf::from("/c"),
+    ]);
+    assert_eq!(paths.roots(), &[PathBuf::from("/a"), PathBuf::from("/b"), PathBuf::from("/c")]);
+}
+
+#[test]
+fn test_legacy_package_separator() {
+    assert_eq!(IncludePaths::module_relative_path("Foo'Bar"), Some(PathBuf::from("Foo/Bar.pm")));
+}
@@ -1,6 +1,6 @@
 #!/usr/bin/env perl
 # Test: Miscellaneous NodeKinds
-# NodeKinds: No, Undef, Ellipsis, VariableWithAttributes
+# NodeKinds: No, Undef, Ellipsis, VariableWithAttributes, DoWhile, DoUntil
 use strict;
 use warnings;
 
@@ -17,3 +17,12 @@ sub not_yet_implemented {
 
 # Variable with attributes (NodeKind::VariableWithAttributes)
 my $shared :shared;
+
+# Post-condition loops (NodeKind::DoWhile, NodeKind::DoUntil)
+my $tries = 0;
+do {
+    $tries++;
+} while ($tries < 3);
+do {
+    $tries--;
+} until ($tries <= 0);
@@ -97,6 +97,13 @@ my $do_while_var = 0;
     } while (0);
 }
 
+# Next in do-until (doubled braces give next a bare block to target)
+my $do_until_var = 0;
+do {{
+    next if $do_until_var % 2;
+    print "Do-until iteration $do_until_var\n";
+}} until (++$do_until_var > 3);
+
 # Complex continue with multiple statements
 my $total = 0;
 for my $i (1..5) {